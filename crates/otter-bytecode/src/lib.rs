@@ -522,6 +522,16 @@ pub enum Op {
 
     /// `r<dst> = new JsObject()`. Operand: `dst`.
     NewObject,
+    /// `r<dst> = new JsObject()` with out-of-line slot storage reserved
+    /// for `property_count` string-keyed properties. Operands:
+    /// `dst, property_count`.
+    ///
+    /// Emitted for object literals whose property count is known at
+    /// compile time, so the literal's `StoreProperty` fill sequence
+    /// appends into pre-sized storage instead of regrowing it. The hint
+    /// is storage-only: the object is observably identical to one made
+    /// by [`Op::NewObject`].
+    NewObjectWithCapacity,
     /// `r<dst> = r<obj>.<name>`. Operands: `dst, obj, name_const`.
     /// Missing property reads as `undefined`. Non-object receivers
     /// raise `TypeMismatch`.
@@ -554,6 +564,14 @@ pub enum Op {
     /// Build a fresh dense array from `elem_count` register
     /// operands. Operands: `dst, count, elem0, elem1, …`.
     NewArray,
+    /// `r<dst> = []` with dense element storage reserved for `capacity`
+    /// elements. Operands: `dst, capacity`.
+    ///
+    /// Emitted for array literals too large for the operand list of
+    /// [`Op::NewArray`] (or containing spread elements), so the
+    /// following `ArrayPush` fill sequence never reallocates for the
+    /// statically known elements. `length` starts at `0`.
+    NewArrayWithCapacity,
     /// `r<dst> = r<arr>[r<idx>]`. Operands: `dst, arr, idx`.
     /// `arr` must be `Value::Array`; `idx` must be `Value::Number`
     /// in `[0, u32::MAX]` (truncates to `u32`).
@@ -1422,12 +1440,14 @@ impl Op {
             Op::ReturnValue => "RETURN_VALUE",
             Op::ReturnUndefined => "RETURN_UNDEFINED",
             Op::NewObject => "NEW_OBJECT",
+            Op::NewObjectWithCapacity => "NEW_OBJECT_WITH_CAPACITY",
             Op::LoadProperty => "LOAD_PROPERTY",
             Op::StoreProperty => "STORE_PROPERTY",
             Op::DeleteProperty => "DELETE_PROPERTY",
             Op::GetPrototype => "GET_PROTOTYPE",
             Op::SetPrototype => "SET_PROTOTYPE",
             Op::NewArray => "NEW_ARRAY",
+            Op::NewArrayWithCapacity => "NEW_ARRAY_WITH_CAPACITY",
             Op::LoadElement => "LOAD_ELEMENT",
             Op::StoreElement => "STORE_ELEMENT",
             Op::ArrayLength => "ARRAY_LENGTH",
@@ -1647,7 +1667,9 @@ impl Op {
             | Op::GetAsyncIterator
             | Op::ArrayPush
             | Op::NewWeakRef
-            | Op::NewFinalizationRegistry => 2,
+            | Op::NewFinalizationRegistry
+            | Op::NewObjectWithCapacity
+            | Op::NewArrayWithCapacity => 2,
             Op::IteratorNext => 3,
             Op::NewCollection => 3,
             Op::CallSpread => 4,
@@ -2065,6 +2087,37 @@ pub struct Function {
     /// that guard once and the recorded shape supersedes the seed.
     #[serde(default)]
     pub class_hint_sites: Vec<ClassHintSite>,
+    /// Tiering hint from a recognized directive pragma in the function body
+    /// (`"use optimize"`, `"use noinline"`).
+    ///
+    /// Unlike the hint sites above this is a request from the author, not a
+    /// type claim: it changes only *when* the function is compiled or whether
+    /// it is inlined, never what the compiled code computes.
+    #[serde(default)]
+    pub optimization_hint: OptimizationHint,
+}
+
+impl Function {
+    /// Tiering hint recorded from the function's directive prologue.
+    #[must_use]
+    pub fn optimization_hint(&self) -> OptimizationHint {
+        self.optimization_hint
+    }
+}
+
+/// Per-function tiering hint recorded from a directive pragma.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OptimizationHint {
+    /// No pragma; the function tiers up on the normal call-count threshold.
+    #[default]
+    None,
+    /// `"use optimize"` — compile on the first call instead of waiting for
+    /// the function to become hot.
+    ForceCompile,
+    /// `"use noinline"` — never inline this function into a caller; it is
+    /// still compiled on its own when hot.
+    NeverInline,
 }
 
 /// One property site whose receiver carries a class-typed annotation.
@@ -624,6 +624,8 @@ opcode_schema! {
     (Op::LessThanImm, 0xAF),
     (Op::EqualImm, 0xB0),
     (Op::NotEqualImm, 0xB1),
+    (Op::NewObjectWithCapacity, 0xB2),
+    (Op::NewArrayWithCapacity, 0xB3),
 }
 
 /// Return the authoritative schema row for `op`.
@@ -855,6 +857,7 @@ const fn operand_shape(op: Op) -> OperandShape {
         Op::EnterTry => OperandShape::Fixed(ENTER_TRY),
         Op::EndFinally => OperandShape::Fixed(EMPTY),
         Op::NewObject => OperandShape::Fixed(WRITE),
+        Op::NewObjectWithCapacity | Op::NewArrayWithCapacity => OperandShape::Fixed(WRITE_CONST),
         Op::LoadProperty | Op::DeleteProperty => OperandShape::Fixed(WRITE_READ_CONST),
         Op::StoreProperty => OperandShape::Fixed(READ_CONST_READ_WRITE),
         Op::GetPrototype | Op::ArrayLength | Op::GetIterator | Op::GetAsyncIterator => {
@@ -0,0 +1,21 @@
+//! Optional process-global allocator for the `otter` binary.
+//!
+//! Allocation-heavy workloads (string building, JSON, module graphs) can
+//! bottleneck on the system allocator. The `mimalloc` and `jemalloc` cargo
+//! features of this crate install the named allocator as the binary's
+//! `#[global_allocator]`; library crates never pick one for their embedders.
+//!
+//! # Invariants
+//! - With both features enabled (as `--all-features` does), jemalloc wins
+//!   and mimalloc is linked but unused.
+//! - `jemalloc` also enables `otter-runtime/jemalloc-stats`, so
+//!   [`otter_runtime::allocator::allocator_stats`] reads the allocator
+//!   installed here.
+
+#[cfg(all(feature = "mimalloc", not(feature = "jemalloc")))]
+#[global_allocator]
+static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
+
+#[cfg(feature = "jemalloc")]
+#[global_allocator]
+static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
@@ -0,0 +1,563 @@
+//! `--config` file: typed schema, validation, and merge with CLI flags.
+//!
+//! # Contents
+//! - [`CliConfigFile`] — validated settings from one JSON config file.
+//! - [`ConfigPermissions`] — permission defaults in `PermissionFlags` shape.
+//!
+//! # Invariants
+//! - Validation fails on the first wrongly typed value with an
+//!   [`ConfigError::InvalidConfigFile`] naming the key path and its
+//!   line/column; unknown keys only produce warnings so newer configs keep
+//!   running on older binaries.
+//! - Every value is a default. An explicit CLI flag always wins, and
+//!   `permissions.allowAll` / `permissions.sandbox` apply only when no
+//!   permission flag was passed at all.
+//! - Relative `envFiles` resolve against the config file's directory;
+//!   permission paths keep the CLI meaning (relative to the working
+//!   directory).
+//!
+//! # Schema
+//!
+//! ```json
+//! {
+//!   "timeout": 30,
+//!   "jitless": false,
+//!   "envFiles": [".env"],
+//!   "permissions": {
+//!     "allowRead": true,
+//!     "allowNet": ["localhost:8080"],
+//!     "denyEnv": ["SECRET"],
+//!     "allowAll": false,
+//!     "sandbox": false
+//!   }
+//! }
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use otter_runtime::{ConfigError, IoErrorKind, OtterError};
+use serde_json::{Map, Value};
+
+use crate::{Cli, PermissionFlags};
+
+/// Validated settings from one config file.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct CliConfigFile {
+    /// `timeout`, in seconds. `0` disables the runtime timeout.
+    pub(crate) timeout_secs: Option<u64>,
+    /// `jitless`.
+    pub(crate) jitless: Option<bool>,
+    /// `envFiles`, resolved against the config file's directory.
+    pub(crate) env_files: Vec<PathBuf>,
+    /// `permissions`.
+    pub(crate) permissions: ConfigPermissions,
+    /// One rendered warning per ignored key.
+    pub(crate) warnings: Vec<String>,
+}
+
+/// Permission defaults, rendered as the strings the matching
+/// `--allow-*` / `--deny-*` flags would carry.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct ConfigPermissions {
+    allow_read: Option<String>,
+    deny_read: Option<String>,
+    allow_write: Option<String>,
+    deny_write: Option<String>,
+    allow_net: Option<String>,
+    deny_net: Option<String>,
+    allow_env: Option<String>,
+    deny_env: Option<String>,
+    allow_run: Option<String>,
+    deny_run: Option<String>,
+    allow_ffi: Option<String>,
+    deny_ffi: Option<String>,
+    allow_all: bool,
+    sandbox: bool,
+}
+
+const TOP_LEVEL_KEYS: &[&str] = &["$schema", "timeout", "jitless", "envFiles", "permissions"];
+
+const ALLOW_KEYS: &[&str] = &[
+    "allowRead",
+    "allowWrite",
+    "allowNet",
+    "allowEnv",
+    "allowRun",
+    "allowFfi",
+];
+
+const DENY_KEYS: &[&str] = &[
+    "denyRead",
+    "denyWrite",
+    "denyNet",
+    "denyEnv",
+    "denyRun",
+    "denyFfi",
+];
+
+impl CliConfigFile {
+    /// Read and validate the config file at `path`.
+    ///
+    /// # Errors
+    /// [`OtterError::Io`] when the file cannot be read, otherwise
+    /// [`ConfigError::InvalidConfigFile`] for a syntax or type error.
+    pub(crate) fn load(path: &Path) -> Result<Self, OtterError> {
+        let text = std::fs::read_to_string(path).map_err(|error| OtterError::Io {
+            path: path.to_path_buf(),
+            kind: IoErrorKind::from_std(error.kind()),
+            message: error.to_string(),
+        })?;
+        let base_dir = path.parent().unwrap_or_else(|| Path::new(""));
+        Self::parse(path, &text, base_dir)
+    }
+
+    /// Validate config `text`. `path` is only used in diagnostics.
+    fn parse(path: &Path, text: &str, base_dir: &Path) -> Result<Self, OtterError> {
+        let root: Value = serde_json::from_str(text).map_err(|error| OtterError::Config {
+            reason: ConfigError::InvalidConfigFile {
+                path: path.to_path_buf(),
+                line: error.line(),
+                column: error.column(),
+                message: error.to_string(),
+            },
+        })?;
+        let validator = Validator { path, text };
+        let Value::Object(root) = root else {
+            return Err(validator.error(&[], "the config must be a JSON object"));
+        };
+
+        let mut config = Self::default();
+        for (key, value) in &root {
+            match key.as_str() {
+                "$schema" => {}
+                "timeout" => {
+                    let secs = value.as_u64().ok_or_else(|| {
+                        validator.type_error(&["timeout"], "a non-negative integer", value)
+                    })?;
+                    config.timeout_secs = Some(secs);
+                }
+                "jitless" => {
+                    let jitless = value
+                        .as_bool()
+                        .ok_or_else(|| validator.type_error(&["jitless"], "a boolean", value))?;
+                    config.jitless = Some(jitless);
+                }
+                "envFiles" => {
+                    config.env_files = validator
+                        .string_list(&["envFiles"], value)?
+                        .into_iter()
+                        .map(|file| base_dir.join(file))
+                        .collect();
+                }
+                "permissions" => {
+                    let Value::Object(permissions) = value else {
+                        return Err(validator.type_error(&["permissions"], "an object", value));
+                    };
+                    config.permissions =
+                        validator.permissions(permissions, &mut config.warnings)?;
+                }
+                other => config
+                    .warnings
+                    .push(validator.unknown_key(&[other], TOP_LEVEL_KEYS)),
+            }
+        }
+        Ok(config)
+    }
+
+    /// Fill every setting the command line left unset.
+    pub(crate) fn apply_defaults(self, cli: &mut Cli) {
+        if cli.timeout_secs.is_none() {
+            cli.timeout_secs = self.timeout_secs;
+        }
+        if let Some(jitless) = self.jitless {
+            cli.jitless |= jitless;
+        }
+        // Config files load first so `--env-file` entries override them.
+        let mut env_files = self.env_files;
+        env_files.append(&mut cli.env_file);
+        cli.env_file = env_files;
+        self.permissions.apply_defaults(&mut cli.perms);
+    }
+}
+
+impl ConfigPermissions {
+    fn apply_defaults(self, flags: &mut PermissionFlags) {
+        let any_flag = flags.allow_all
+            || flags.sandbox
+            || [
+                &flags.allow_read,
+                &flags.deny_read,
+                &flags.allow_write,
+                &flags.deny_write,
+                &flags.allow_net,
+                &flags.deny_net,
+                &flags.allow_env,
+                &flags.deny_env,
+                &flags.allow_run,
+                &flags.deny_run,
+                &flags.allow_ffi,
+                &flags.deny_ffi,
+            ]
+            .iter()
+            .any(|flag| flag.is_some());
+        if !any_flag {
+            flags.allow_all = self.allow_all;
+            flags.sandbox = self.sandbox;
+        }
+        for (flag, default) in [
+            (&mut flags.allow_read, self.allow_read),
+            (&mut flags.deny_read, self.deny_read),
+            (&mut flags.allow_write, self.allow_write),
+            (&mut flags.deny_write, self.deny_write),
+            (&mut flags.allow_net, self.allow_net),
+            (&mut flags.deny_net, self.deny_net),
+            (&mut flags.allow_env, self.allow_env),
+            (&mut flags.deny_env, self.deny_env),
+            (&mut flags.allow_run, self.allow_run),
+            (&mut flags.deny_run, self.deny_run),
+            (&mut flags.allow_ffi, self.allow_ffi),
+            (&mut flags.deny_ffi, self.deny_ffi),
+        ] {
+            if flag.is_none() {
+                *flag = default;
+            }
+        }
+    }
+
+    fn slot(&mut self, key: &str) -> &mut Option<String> {
+        match key {
+            "allowRead" => &mut self.allow_read,
+            "denyRead" => &mut self.deny_read,
+            "allowWrite" => &mut self.allow_write,
+            "denyWrite" => &mut self.deny_write,
+            "allowNet" => &mut self.allow_net,
+            "denyNet" => &mut self.deny_net,
+            "allowEnv" => &mut self.allow_env,
+            "denyEnv" => &mut self.deny_env,
+            "allowRun" => &mut self.allow_run,
+            "denyRun" => &mut self.deny_run,
+            "allowFfi" => &mut self.allow_ffi,
+            "denyFfi" => &mut self.deny_ffi,
+            _ => unreachable!("permission key `{key}` is not in the schema"),
+        }
+    }
+}
+
+/// Source-aware validation helpers for one config file.
+struct Validator<'a> {
+    path: &'a Path,
+    text: &'a str,
+}
+
+impl Validator<'_> {
+    fn permissions(
+        &self,
+        permissions: &Map<String, Value>,
+        warnings: &mut Vec<String>,
+    ) -> Result<ConfigPermissions, OtterError> {
+        let mut out = ConfigPermissions::default();
+        for (key, value) in permissions {
+            let path = ["permissions", key.as_str()];
+            match key.as_str() {
+                key if ALLOW_KEYS.contains(&key) => {
+                    *out.slot(key) = match value {
+                        Value::Bool(true) => Some("*".to_string()),
+                        Value::Bool(false) => None,
+                        Value::Array(_) => joined(self.string_list(&path, value)?),
+                        _ => {
+                            return Err(self.type_error(
+                                &path,
+                                "`true`, `false`, or an array of strings",
+                                value,
+                            ));
+                        }
+                    };
+                }
+                key if DENY_KEYS.contains(&key) => {
+                    *out.slot(key) = joined(self.string_list(&path, value)?);
+                }
+                "allowAll" => {
+                    out.allow_all = value
+                        .as_bool()
+                        .ok_or_else(|| self.type_error(&path, "a boolean", value))?;
+                }
+                "sandbox" => {
+                    out.sandbox = value
+                        .as_bool()
+                        .ok_or_else(|| self.type_error(&path, "a boolean", value))?;
+                }
+                _ => {
+                    let known: Vec<&str> = ALLOW_KEYS
+                        .iter()
+                        .chain(DENY_KEYS)
+                        .chain(&["allowAll", "sandbox"])
+                        .copied()
+                        .collect();
+                    warnings.push(self.unknown_key(&path, &known));
+                }
+            }
+        }
+        if out.allow_all && out.sandbox {
+            return Err(self.error(
+                &["permissions", "sandbox"],
+                "`permissions.allowAll` and `permissions.sandbox` cannot both be true",
+            ));
+        }
+        Ok(out)
+    }
+
+    fn string_list(&self, path: &[&str], value: &Value) -> Result<Vec<String>, OtterError> {
+        let Value::Array(items) = value else {
+            return Err(self.type_error(path, "an array of strings", value));
+        };
+        items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                item.as_str().map(str::to_string).ok_or_else(|| {
+                    self.error(
+                        path,
+                        format!(
+                            "`{}[{index}]` must be a string, found {}",
+                            path.join("."),
+                            describe(item)
+                        ),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    fn type_error(&self, path: &[&str], expected: &str, found: &Value) -> OtterError {
+        self.error(
+            path,
+            format!(
+                "`{}` must be {expected}, found {}",
+                path.join("."),
+                describe(found)
+            ),
+        )
+    }
+
+    fn error(&self, path: &[&str], message: impl Into<String>) -> OtterError {
+        let (line, column) = locate_key(self.text, path);
+        OtterError::Config {
+            reason: ConfigError::InvalidConfigFile {
+                path: self.path.to_path_buf(),
+                line,
+                column,
+                message: message.into(),
+            },
+        }
+    }
+
+    fn unknown_key(&self, path: &[&str], known: &[&str]) -> String {
+        let (line, column) = locate_key(self.text, path);
+        format!(
+            "{}:{line}:{column}: unknown config key `{}` ignored (expected one of: {})",
+            self.path.display(),
+            path.join("."),
+            known.join(", ")
+        )
+    }
+}
+
+/// An empty list leaves the permission unset, like omitting the flag.
+fn joined(list: Vec<String>) -> Option<String> {
+    (!list.is_empty()).then(|| list.join(","))
+}
+
+fn describe(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Bool(value) => format!("boolean `{value}`"),
+        Value::Number(value) => format!("number `{value}`"),
+        Value::String(value) => format!("string {value:?}"),
+        Value::Array(_) => "an array".to_string(),
+        Value::Object(_) => "an object".to_string(),
+    }
+}
+
+/// 1-based line/column of the last key in `path` that was found. Falls
+/// back to the start of the file for the root.
+fn locate_key(text: &str, path: &[&str]) -> (usize, usize) {
+    let at = key_offset(text, path).unwrap_or(0);
+    let before = &text[..at];
+    let line = before.matches('\n').count() + 1;
+    let column = at - before.rfind('\n').map_or(0, |newline| newline + 1) + 1;
+    (line, column)
+}
+
+/// Byte offset of the deepest key of `path` present in `text`.
+///
+/// Each key only matches as a direct member of the object the previous key
+/// names, so the same name inside a string, a sibling object, or a deeper
+/// level never matches. `text` already parsed as JSON, so the scan only
+/// tracks strings and brackets.
+fn key_offset(text: &str, path: &[&str]) -> Option<usize> {
+    if path.is_empty() {
+        return None;
+    }
+    let bytes = text.as_bytes();
+    // Containers currently open; `true` for objects.
+    let mut open: Vec<bool> = Vec::new();
+    // The object searched for `path[matched]` sits at this depth.
+    let mut target_depth = 1;
+    let mut matched = 0;
+    let mut found = None;
+    // A key just matched and its value has not started yet.
+    let mut awaiting_value = false;
+    let mut expect_key = false;
+    let mut at = 0;
+    while at < bytes.len() {
+        let byte = bytes[at];
+        if awaiting_value && !byte.is_ascii_whitespace() && byte != b':' && byte != b'{' {
+            // The matched key's value is not an object; nothing deeper.
+            return found;
+        }
+        match byte {
+            b'{' => {
+                open.push(true);
+                awaiting_value = false;
+                expect_key = true;
+            }
+            b'[' => {
+                open.push(false);
+                expect_key = false;
+            }
+            b'}' | b']' => {
+                open.pop();
+                if open.len() < target_depth {
+                    // Left the object being searched.
+                    return found;
+                }
+                expect_key = false;
+            }
+            b',' => expect_key = open.last() == Some(&true),
+            b'"' => {
+                let end = string_end(bytes, at)?;
+                if expect_key && open.len() == target_depth {
+                    let key: String = serde_json::from_str(&text[at..end]).ok()?;
+                    if key == path[matched] {
+                        found = Some(at);
+                        matched += 1;
+                        if matched == path.len() {
+                            return found;
+                        }
+                        target_depth += 1;
+                        awaiting_value = true;
+                    }
+                }
+                expect_key = false;
+                at = end;
+                continue;
+            }
+            _ => {}
+        }
+        at += 1;
+    }
+    found
+}
+
+/// Offset just past the closing quote of the string starting at `start`.
+fn string_end(bytes: &[u8], start: usize) -> Option<usize> {
+    let mut at = start + 1;
+    while at < bytes.len() {
+        match bytes[at] {
+            b'\\' => at += 2,
+            b'"' => return Some(at + 1),
+            _ => at += 1,
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> Result<CliConfigFile, OtterError> {
+        CliConfigFile::parse(Path::new("otter.json"), text, Path::new("/project"))
+    }
+
+    fn invalid(text: &str) -> (usize, usize, String) {
+        match parse(text) {
+            Err(OtterError::Config {
+                reason:
+                    ConfigError::InvalidConfigFile {
+                        line,
+                        column,
+                        message,
+                        ..
+                    },
+            }) => (line, column, message),
+            other => panic!("expected an invalid config error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn valid_config_maps_onto_flag_shapes() {
+        let config = parse(
+            r#"{
+                "timeout": 5,
+                "envFiles": [".env", "/abs/.env"],
+                "permissions": { "allowRead": true, "allowNet": ["a:1", "b:2"], "denyEnv": [] }
+            }"#,
+        )
+        .expect("valid config");
+        assert_eq!(config.timeout_secs, Some(5));
+        assert_eq!(
+            config.env_files,
+            [PathBuf::from("/project/.env"), PathBuf::from("/abs/.env")]
+        );
+        assert_eq!(config.permissions.allow_read.as_deref(), Some("*"));
+        assert_eq!(config.permissions.allow_net.as_deref(), Some("a:1,b:2"));
+        assert_eq!(config.permissions.deny_env, None);
+        assert!(config.warnings.is_empty());
+    }
+
+    #[test]
+    fn errors_point_at_the_offending_key() {
+        let (line, column, message) =
+            invalid("{\n  \"permissions\": {\n    \"allowNet\": 8080\n  }\n}");
+        assert_eq!((line, column), (3, 5));
+        assert!(message.contains("`permissions.allowNet`"), "{message}");
+        assert!(message.contains("number `8080`"), "{message}");
+
+        let (line, column, message) = invalid("{\n  \"envFiles\": [\".env\", 1]\n}");
+        assert_eq!((line, column), (2, 3));
+        assert!(
+            message.contains("`envFiles[1]` must be a string"),
+            "{message}"
+        );
+
+        let (line, column, _) = invalid(
+            "{\n  \"note\": \"\\\"timeout\\\": 1\",\n  \"other\": { \"timeout\": 1 },\n  \"timeout\": \"x\"\n}",
+        );
+        assert_eq!(
+            (line, column),
+            (4, 3),
+            "only the top-level `timeout` member matches"
+        );
+
+        let (line, column, _) =
+            invalid("{\n  \"allowNet\": 1,\n  \"permissions\": {\n    \"allowNet\": 8080\n  }\n}");
+        assert_eq!((line, column), (4, 5), "a same-named root key is skipped");
+
+        let (line, _, _) = invalid("{\n  \"timeout\": 1,\n}");
+        assert_eq!(line, 3, "syntax errors keep serde_json's position");
+    }
+
+    #[test]
+    fn unknown_keys_warn_with_their_position() {
+        let config =
+            parse("{\n  \"timeout\": 1,\n  \"permissions\": { \"allowDisk\": true }\n}").unwrap();
+        assert_eq!(config.warnings.len(), 1);
+        assert!(
+            config.warnings[0]
+                .starts_with("otter.json:3:20: unknown config key `permissions.allowDisk`"),
+            "{}",
+            config.warnings[0]
+        );
+    }
+}
@@ -4,6 +4,9 @@
 //! - [`CliExecutionConfig`] — timeout, trace, JIT tier, and structured JIT
 //!   diagnostics captured once after argument parsing and applied to either
 //!   public runtime builder.
+//! - `process.env` defaults loaded from `--env-file` and
+//!   `--env-file-if-exists`.
+//! - `--import` preload modules, evaluated in order before the entry.
 //!
 //! # Invariants
 //! - Runtime-backed command paths receive this value explicitly. No timeout,
@@ -12,6 +15,10 @@
 //!   the template baseline compiler (interpreter plus the template tier, no
 //!   optimizing compilation) — the jitless engine, analogous to running with a
 //!   bytecode baseline instead of an optimizing JIT.
+//! - Env-file entries never shadow a variable set in the host environment,
+//!   and a later file overrides an earlier one.
+//! - Preloads run on the entry's runtime, so their globals are visible to
+//!   it; the first preload that fails aborts the run before the entry loads.
 //! - `None` keeps the runtime timeout default while `Some(Duration::ZERO)`
 //!   explicitly disables it.
 //! - Engine crates only return owned JIT reports. This outer configuration
@@ -22,8 +29,8 @@ use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use otter_runtime::{
-    JitArtifactBatch, JitDebugReport, JitDebugRequest, JitDebugTier, JitSelection, OtterBuilder,
-    RuntimeBuilder, TracerFactory,
+    IoErrorKind, JitArtifactBatch, JitDebugReport, JitDebugRequest, JitDebugTier, JitSelection,
+    Otter, OtterBuilder, OtterError, RuntimeBuilder, SourceInput, TracerFactory, parse_env_file,
 };
 
 /// Owned execution settings shared by every runtime-backed CLI command.
@@ -35,6 +42,8 @@ pub(crate) struct CliExecutionConfig {
     jit_artifacts_target: Option<String>,
     jit_selection: JitSelection,
     jit_osr_threshold: Option<u32>,
+    process_env_defaults: Vec<(String, String)>,
+    preload_imports: Vec<String>,
 }
 
 impl Default for CliExecutionConfig {
@@ -46,6 +55,8 @@ impl Default for CliExecutionConfig {
             jit_artifacts_target: None,
             jit_selection: JitSelection::ProductionTiered,
             jit_osr_threshold: None,
+            process_env_defaults: Vec::new(),
+            preload_imports: Vec::new(),
         }
     }
 }
@@ -71,14 +82,70 @@ impl CliExecutionConfig {
             jit_artifacts_target,
             jit_selection,
             jit_osr_threshold: legacy_jit_osr_threshold(),
+            process_env_defaults: Vec::new(),
+            preload_imports: Vec::new(),
         }
     }
 
+    /// Load `--env-file` entries, then `--env-file-if-exists` entries, as
+    /// `process.env` defaults. A missing required file is an I/O error; a
+    /// missing optional file is skipped.
+    pub(crate) fn with_env_files(
+        mut self,
+        required: &[PathBuf],
+        optional: &[PathBuf],
+    ) -> Result<Self, OtterError> {
+        let files = required
+            .iter()
+            .map(|path| (path, true))
+            .chain(optional.iter().map(|path| (path, false)));
+        for (path, must_exist) in files {
+            let text = match std::fs::read_to_string(path) {
+                Ok(text) => text,
+                Err(error) if !must_exist && error.kind() == io::ErrorKind::NotFound => continue,
+                Err(error) => {
+                    return Err(OtterError::Io {
+                        path: path.clone(),
+                        kind: IoErrorKind::from_std(error.kind()),
+                        message: error.to_string(),
+                    });
+                }
+            };
+            self.process_env_defaults.extend(parse_env_file(&text));
+        }
+        Ok(self)
+    }
+
+    /// Record `--import` specifiers, in command-line order.
+    pub(crate) fn with_preload_imports(mut self, specifiers: &[String]) -> Self {
+        self.preload_imports = specifiers.to_vec();
+        self
+    }
+
+    /// Evaluate each `--import` specifier as an ES module on `otter`.
+    ///
+    /// Specifiers resolve like an `import` in a module at the working
+    /// directory, so relative paths, bare packages, and `node:` builtins all
+    /// work. A preload that throws or fails to load returns its error.
+    pub(crate) async fn run_preload_imports(&self, otter: &Otter) -> Result<(), OtterError> {
+        let cwd = std::env::current_dir().unwrap_or_default();
+        for (index, specifier) in self.preload_imports.iter().enumerate() {
+            let source = SourceInput::from_javascript(format!("import {specifier:?};"));
+            let url = format!(
+                "file://{}",
+                cwd.join(format!("[import-{index}].mjs")).display()
+            );
+            otter.run_module_source(source, url).await?;
+        }
+        Ok(())
+    }
+
     /// Apply execution settings to the async-capable public runtime facade.
     pub(crate) fn apply_otter_builder(&self, builder: OtterBuilder) -> OtterBuilder {
         let mut builder = builder
             .jit_selection(self.jit_selection)
-            .jit_debug(self.jit_debug_request());
+            .jit_debug(self.jit_debug_request())
+            .process_env_defaults(self.process_env_defaults.iter().cloned());
         if let Some(threshold) = self.jit_osr_threshold {
             builder = builder.jit_osr_threshold(threshold);
         }
@@ -99,7 +166,8 @@ impl CliExecutionConfig {
     pub(crate) fn apply_runtime_builder(&self, builder: RuntimeBuilder) -> RuntimeBuilder {
         let mut builder = builder
             .jit_selection(self.jit_selection)
-            .jit_debug(self.jit_debug_request());
+            .jit_debug(self.jit_debug_request())
+            .process_env_defaults(self.process_env_defaults.iter().cloned());
         if let Some(threshold) = self.jit_osr_threshold {
             builder = builder.jit_osr_threshold(threshold);
         }
@@ -317,6 +385,8 @@ mod tests {
             jit_artifacts_target: None,
             jit_selection: JitSelection::InterpreterOnly,
             jit_osr_threshold: None,
+            process_env_defaults: Vec::new(),
+            preload_imports: Vec::new(),
         };
         let disabled = CliExecutionConfig {
             timeout: Some(Duration::ZERO),
@@ -335,6 +405,8 @@ mod tests {
             jit_artifacts_target: None,
             jit_selection: JitSelection::InterpreterOnly,
             jit_osr_threshold: None,
+            process_env_defaults: Vec::new(),
+            preload_imports: Vec::new(),
         };
         target.clear();
         assert_eq!(config.trace_target.as_deref(), Some("trace.log"));
@@ -357,6 +429,8 @@ mod tests {
             jit_artifacts_target: None,
             jit_selection: JitSelection::Template,
             jit_osr_threshold: Some(1),
+            process_env_defaults: Vec::new(),
+            preload_imports: Vec::new(),
         };
         target.clear();
         assert!(config.jit_events_enabled());
@@ -370,6 +444,8 @@ mod tests {
             jit_artifacts_target: Some("jit-artifacts".to_string()),
             jit_selection: JitSelection::Template,
             jit_osr_threshold: Some(1),
+            process_env_defaults: Vec::new(),
+            preload_imports: Vec::new(),
         };
         assert!(artifacts.jit_artifacts_enabled());
         assert!(!artifacts.jit_events_enabled());
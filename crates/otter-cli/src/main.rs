@@ -40,9 +40,12 @@ use otter_runtime::{
 use otter_web::WebApiBuilderExt;
 use semver::{Version, VersionReq};
 
+mod allocator;
+mod config_file;
 mod error_render;
 mod execution_config;
 
+use config_file::CliConfigFile;
 use error_render::emit_error;
 use execution_config::CliExecutionConfig;
 
@@ -128,6 +131,25 @@ struct Cli {
     #[arg(long, global = true)]
     jitless: bool,
 
+    /// Load `process.env` defaults from a `.env` file. Repeatable; later
+    /// files override earlier ones and host variables always win.
+    #[arg(long = "env-file", value_name = "path", global = true)]
+    env_file: Vec<PathBuf>,
+
+    /// Like `--env-file`, but a missing file is skipped instead of failing.
+    #[arg(long = "env-file-if-exists", value_name = "path", global = true)]
+    env_file_if_exists: Vec<PathBuf>,
+
+    /// Evaluate an ES module before the entry. Repeatable; modules run in
+    /// order in the entry's realm, and one that throws aborts the run.
+    #[arg(long = "import", value_name = "specifier", global = true)]
+    import: Vec<String>,
+
+    /// Read defaults from a JSON config file. Explicit flags win over
+    /// every value it sets.
+    #[arg(long = "config", value_name = "path", global = true)]
+    config: Option<PathBuf>,
+
     /// Capability flags (Deno-style).
     #[command(flatten)]
     perms: PermissionFlags,
@@ -398,6 +420,30 @@ struct RunArgs {
     /// Base file name for `--cpu-prof` artifacts.
     #[arg(long, requires = "cpu_prof")]
     cpu_prof_name: Option<String>,
+    /// Extra `--cpu-prof` artifact: `svg` also writes a flamegraph next to
+    /// the `.cpuprofile` and `.folded` files.
+    #[arg(
+        long,
+        value_name = "format",
+        value_parser = ["cpuprofile", "svg"],
+        default_value = "cpuprofile",
+        requires = "cpu_prof"
+    )]
+    cpu_prof_format: String,
+    /// Shorthand for `--cpu-prof-format svg`.
+    #[arg(long, requires = "cpu_prof")]
+    flamegraph: bool,
+    /// What `--cpu-prof` samples: `wall-clock` also records time the VM
+    /// thread spends parked as `(idle)` samples; `on-cpu` samples only
+    /// running JavaScript.
+    #[arg(
+        long,
+        value_name = "mode",
+        value_parser = ["wall-clock", "on-cpu"],
+        default_value = "wall-clock",
+        requires = "cpu_prof"
+    )]
+    cpu_prof_mode: String,
     /// GC heap cap in bytes; `0` disables the cap. Default is the runtime's
     /// built-in limit. Surfaces a catchable `RangeError` when exceeded.
     #[arg(long)]
@@ -412,6 +458,10 @@ struct CpuProfileOptions {
     dir: PathBuf,
     interval: u64,
     name: Option<String>,
+    /// Also write `<name>.svg` (`--cpu-prof-format svg`).
+    flamegraph_svg: bool,
+    /// `--cpu-prof-mode`.
+    mode: otter_runtime::SamplingMode,
 }
 
 #[derive(Debug, Args)]
@@ -435,7 +485,12 @@ struct AddArgs {
     /// Add to optionalDependencies.
     #[arg(long, conflicts_with_all = ["dev", "peer"])]
     optional: bool,
-    /// Package specs, for example `react@^19` or `@scope/pkg@1`.
+    /// Also add `@types/*` to devDependencies for added packages that ship
+    /// no TypeScript declarations.
+    #[arg(long)]
+    types: bool,
+    /// Package specs, for example `react@^19`, `@scope/pkg@next`, or
+    /// `lib@file:../lib`.
     packages: Vec<String>,
 }
 
@@ -508,8 +563,19 @@ struct TestArgs {
 #[tokio::main(flavor = "multi_thread")]
 async fn main() -> ExitCode {
     let startup_timer = CliStartupTimer::from_env();
-    let cli = Cli::parse();
+    let mut cli = Cli::parse();
     startup_timer.mark("parse_args");
+    if let Some(path) = cli.config.clone() {
+        match CliConfigFile::load(&path) {
+            Ok(config) => {
+                for warning in &config.warnings {
+                    eprintln!("warning: {warning}");
+                }
+                config.apply_defaults(&mut cli);
+            }
+            Err(err) => return exit_from_result(Err(err), cli.json),
+        }
+    }
     let execution = CliExecutionConfig::new(
         cli.timeout_secs,
         cli.trace.clone(),
@@ -518,6 +584,11 @@ async fn main() -> ExitCode {
         cli.jit_artifacts.clone(),
     );
     let json = cli.json;
+    let execution = match execution.with_env_files(&cli.env_file, &cli.env_file_if_exists) {
+        Ok(execution) => execution,
+        Err(err) => return exit_from_result(Err(err), json),
+    };
+    let execution = execution.with_preload_imports(&cli.import);
     let dump_mode = cli.dump_bytecode.clone();
     let caps = cli.perms.clone().into_capabilities();
     startup_timer.mark("build_capabilities");
@@ -591,6 +662,9 @@ async fn main() -> ExitCode {
                     cpu_prof_dir: PathBuf::from("/tmp/otter-prof"),
                     cpu_prof_interval: 1000,
                     cpu_prof_name: None,
+                    cpu_prof_format: "cpuprofile".to_string(),
+                    flamegraph: false,
+                    cpu_prof_mode: "wall-clock".to_string(),
                     max_heap_bytes: None,
                     args: forwarded_args,
                 },
@@ -727,6 +801,7 @@ async fn run_file_with_cwd(
     }
     let otter = builder.build()?;
     startup_timer.mark("runtime_build");
+    execution.run_preload_imports(&otter).await?;
     let attempt = otter.run_file_with_diagnostics(path).await;
     let result = finish_jit_debug_attempt(execution, attempt)?;
     startup_timer.mark("runtime_run_file");
@@ -779,7 +854,7 @@ async fn run_file_with_cpu_profile(
     }
     let mut runtime = builder.build()?;
     startup_timer.mark("runtime_build");
-    runtime.enable_cpu_profiler(profile_options.interval);
+    runtime.enable_cpu_profiler_with_mode(profile_options.interval, profile_options.mode);
     let attempt = runtime.run_file_with_diagnostics(path);
     let result = finish_jit_debug_attempt(execution, attempt)?;
     startup_timer.mark("runtime_run_file");
@@ -797,6 +872,9 @@ async fn run_file_with_cpu_profile(
         profile.sample_count(),
         artifacts.folded.display()
     );
+    if let Some(svg) = &artifacts.svg {
+        eprintln!("flamegraph written: {}", svg.display());
+    }
     emit_otter_stats_if_requested(&result);
     if json {
         println!(
@@ -807,7 +885,9 @@ async fn run_file_with_cpu_profile(
                 "cpuProfile": {
                     "cpuprofile": artifacts.cpuprofile,
                     "folded": artifacts.folded,
+                    "svg": artifacts.svg,
                     "samples": profile.sample_count(),
+                    "mode": profile_options.mode,
                 }
             })
         );
@@ -819,6 +899,7 @@ async fn run_file_with_cpu_profile(
 struct CpuProfileArtifacts {
     cpuprofile: PathBuf,
     folded: PathBuf,
+    svg: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -848,7 +929,18 @@ fn write_cpu_profile_artifacts(
     let folded = options.dir.join(format!("{base}.folded"));
     write_chrome_cpu_profile(&cpuprofile, profile)?;
     write_folded_cpu_profile(&folded, profile)?;
-    Ok(CpuProfileArtifacts { cpuprofile, folded })
+    let svg = if options.flamegraph_svg {
+        let svg = options.dir.join(format!("{base}.svg"));
+        std::fs::write(&svg, profile.to_flamegraph_svg()).map_err(|err| pm_io_error(&svg, err))?;
+        Some(svg)
+    } else {
+        None
+    };
+    Ok(CpuProfileArtifacts {
+        cpuprofile,
+        folded,
+        svg,
+    })
 }
 
 fn cpu_profile_base_name(entry_path: &Path, options: &CpuProfileOptions) -> String {
@@ -861,6 +953,7 @@ fn cpu_profile_base_name(entry_path: &Path, options: &CpuProfileOptions) -> Stri
     });
     raw.strip_suffix(".cpuprofile")
         .or_else(|| raw.strip_suffix(".folded"))
+        .or_else(|| raw.strip_suffix(".svg"))
         .unwrap_or(&raw)
         .to_string()
 }
@@ -1164,6 +1257,12 @@ async fn run_target(
         dir: args.cpu_prof_dir.clone(),
         interval: args.cpu_prof_interval,
         name: args.cpu_prof_name.clone(),
+        flamegraph_svg: args.flamegraph || args.cpu_prof_format == "svg",
+        mode: if args.cpu_prof_mode == "on-cpu" {
+            otter_runtime::SamplingMode::OnCpu
+        } else {
+            otter_runtime::SamplingMode::WallClock
+        },
     });
     match resolve_run_target(&project_root, &args).await? {
         RunTarget::File(path) => {
@@ -1492,8 +1591,10 @@ async fn resolve_run_bin(project_root: &Path, target: &str) -> Result<RunTarget,
         .graph;
     let bins = graph.resolve_bin(target);
     match bins {
+        // There is no npx-style fetch-and-run: a binary must come from an
+        // installed package so execution stays pinned to `otter.lock`.
         [] => Err(pm_config_error(format!(
-            "unknown local package binary `{target}`"
+            "unknown local package binary `{target}`; install the package that provides it with `otter add`"
         ))),
         [bin] => Ok(RunTarget::Bin(resolve_bin_source_path(&graph, bin))),
         many => Err(pm_config_error(format!(
@@ -1704,6 +1805,7 @@ async fn run_eval(
 ) -> Result<ExitCode, OtterError> {
     let otter = cli_otter_builder(caps, execution).build()?;
     startup_timer.mark("runtime_build");
+    execution.run_preload_imports(&otter).await?;
     let attempt = otter.eval_with_diagnostics(source).await;
     let result = finish_jit_debug_attempt(execution, attempt)?;
     startup_timer.mark("runtime_eval");
@@ -2171,9 +2273,26 @@ async fn run_pm_add(args: AddArgs, json: bool) -> Result<ExitCode, OtterError> {
     } else {
         &mut manifest.dependencies
     };
+    let cache_root = args.root.join(".otter").join("cache");
+    let metadata_cache =
+        otter_pm::FsRegistryMetadataCache::new(cache_root.join("registry-metadata"));
+    let metadata_client = otter_pm::HttpRegistryMetadataClient::new();
     let mut added = Vec::new();
     for spec in &args.packages {
-        let (name, range) = parse_package_spec(spec);
+        let (name, spec) = parse_package_spec(spec);
+        let range = otter_pm::resolve_add_spec(
+            &args.root,
+            name.as_deref().unwrap_or_default(),
+            spec.as_deref(),
+            &metadata_cache,
+            &metadata_client,
+        )
+        .await
+        .map_err(map_pm_error)?;
+        let name = match name {
+            Some(name) => name,
+            None => add_spec_package_name(&args.root, &range).await?,
+        };
         bucket.insert(name.clone(), range.clone());
         added.push(serde_json::json!({ "name": name, "range": range }));
     }
@@ -2181,16 +2300,53 @@ async fn run_pm_add(args: AddArgs, json: bool) -> Result<ExitCode, OtterError> {
         .write_to_dir(&args.root)
         .await
         .map_err(|err| pm_config_error(err.to_string()))?;
-    let cache_root = args.root.join(".otter").join("cache");
-    let report = otter_pm::install_local_project(
+    let package_store = otter_pm::FsPackageStore::new(cache_root);
+    let tarball_client = otter_pm::HttpTarballClient::new();
+    let mut report = otter_pm::install_local_project(
         &args.root,
-        &otter_pm::FsRegistryMetadataCache::new(cache_root.join("registry-metadata")),
-        &otter_pm::HttpRegistryMetadataClient::new(),
-        &otter_pm::FsPackageStore::new(cache_root),
-        &otter_pm::HttpTarballClient::new(),
+        &metadata_cache,
+        &metadata_client,
+        &package_store,
+        &tarball_client,
     )
     .await
     .map_err(map_pm_error)?;
+    if args.types {
+        let names = added
+            .iter()
+            .filter_map(|item| item["name"].as_str())
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        let companions = otter_pm::resolve_missing_type_packages(
+            &args.root,
+            names.iter().map(String::as_str),
+            &metadata_cache,
+            &metadata_client,
+        )
+        .await
+        .map_err(map_pm_error)?;
+        if !companions.is_empty() {
+            for (name, range) in companions {
+                manifest
+                    .dev_dependencies
+                    .insert(name.clone(), range.clone());
+                added.push(serde_json::json!({ "name": name, "range": range }));
+            }
+            manifest
+                .write_to_dir(&args.root)
+                .await
+                .map_err(|err| pm_config_error(err.to_string()))?;
+            report = otter_pm::install_local_project(
+                &args.root,
+                &metadata_cache,
+                &metadata_client,
+                &package_store,
+                &tarball_client,
+            )
+            .await
+            .map_err(map_pm_error)?;
+        }
+    }
     if json {
         println!(
             "{}",
@@ -2346,10 +2502,7 @@ async fn collect_outdated_packages(
             if !seen.insert(name.clone()) || !is_registry_range(range) {
                 continue;
             }
-            let metadata = cache
-                .get_or_fetch(name, client)
-                .await
-                .map_err(map_pm_error)?;
+            let metadata = cache.revalidate(name, client).await.map_err(map_pm_error)?;
             let current =
                 current_locked_version(lockfile, name).unwrap_or_else(|| "<missing>".to_string());
             let wanted =
@@ -2653,16 +2806,36 @@ fn prompt_optional(label: &str) -> Result<Option<String>, OtterError> {
     }
 }
 
-fn parse_package_spec(spec: &str) -> (String, String) {
+/// Split an `otter add` argument into its package name and the specifier
+/// after `@`. A bare specifier such as `file:../lib` or `github:user/repo`
+/// has no name of its own.
+fn parse_package_spec(spec: &str) -> (Option<String>, Option<String>) {
     let split = if let Some(rest) = spec.strip_prefix('@') {
-        rest.rfind('@').map(|index| index + 1)
+        rest.find('@').map(|index| index + 1)
     } else {
-        spec.rfind('@').filter(|index| *index > 0)
+        spec.find('@').filter(|index| *index > 0)
     };
-    match split {
-        Some(index) => (spec[..index].to_string(), spec[index + 1..].to_string()),
-        None => (spec.to_string(), "*".to_string()),
+    let name = split.map_or(spec, |index| &spec[..index]);
+    if name.contains(':') || (!name.starts_with('@') && name.contains('/')) {
+        return (None, Some(spec.to_string()));
     }
+    (
+        Some(name.to_string()),
+        split.map(|index| spec[index + 1..].to_string()),
+    )
+}
+
+/// Package name for a bare `file:` specifier, read from its manifest.
+async fn add_spec_package_name(root: &Path, spec: &str) -> Result<String, OtterError> {
+    if let Some(path) = spec.strip_prefix("file:")
+        && let Ok(manifest) = PackageManifest::read_from_dir(root.join(path)).await
+        && let Some(name) = manifest.name
+    {
+        return Ok(name);
+    }
+    Err(pm_config_error(format!(
+        "cannot infer a package name for `{spec}`; use `<name>@{spec}`"
+    )))
 }
 
 fn default_package_name(root: &Path) -> String {
@@ -2760,17 +2933,29 @@ mod tests {
 
     #[test]
     fn package_spec_parser_handles_scoped_ranges() {
+        let named =
+            |name: &str, spec: Option<&str>| (Some(name.to_string()), spec.map(str::to_string));
         assert_eq!(
             parse_package_spec("@scope/pkg@^1.2.3"),
-            ("@scope/pkg".to_string(), "^1.2.3".to_string())
+            named("@scope/pkg", Some("^1.2.3"))
         );
+        assert_eq!(parse_package_spec("@scope/pkg"), named("@scope/pkg", None));
+        assert_eq!(parse_package_spec("react@^19"), named("react", Some("^19")));
         assert_eq!(
-            parse_package_spec("@scope/pkg"),
-            ("@scope/pkg".to_string(), "*".to_string())
+            parse_package_spec("react@next"),
+            named("react", Some("next"))
         );
         assert_eq!(
-            parse_package_spec("react@^19"),
-            ("react".to_string(), "^19".to_string())
+            parse_package_spec("lib@file:../vendor/@lib"),
+            named("lib", Some("file:../vendor/@lib"))
+        );
+        assert_eq!(
+            parse_package_spec("file:../lib"),
+            (None, Some("file:../lib".to_string()))
+        );
+        assert_eq!(
+            parse_package_spec("github:user/repo#v1"),
+            (None, Some("github:user/repo#v1".to_string()))
         );
     }
 
@@ -3020,6 +3205,9 @@ integrity = "sha512-test"
             cpu_prof_dir: PathBuf::from("/tmp/otter-prof"),
             cpu_prof_interval: 1000,
             cpu_prof_name: None,
+            cpu_prof_format: "cpuprofile".to_string(),
+            flamegraph: false,
+            cpu_prof_mode: "wall-clock".to_string(),
             max_heap_bytes: None,
             args: Vec::new(),
         };
@@ -3059,6 +3247,9 @@ integrity = "sha512-test"
             cpu_prof_dir: PathBuf::from("/tmp/otter-prof"),
             cpu_prof_interval: 1000,
             cpu_prof_name: None,
+            cpu_prof_format: "cpuprofile".to_string(),
+            flamegraph: false,
+            cpu_prof_mode: "wall-clock".to_string(),
             max_heap_bytes: None,
             args: Vec::new(),
         };
@@ -3095,6 +3286,9 @@ integrity = "sha512-test"
             cpu_prof_dir: PathBuf::from("/tmp/otter-prof"),
             cpu_prof_interval: 1000,
             cpu_prof_name: None,
+            cpu_prof_format: "cpuprofile".to_string(),
+            flamegraph: false,
+            cpu_prof_mode: "wall-clock".to_string(),
             max_heap_bytes: None,
             args: Vec::new(),
         };
@@ -3156,6 +3350,9 @@ trust = "untrusted"
             cpu_prof_dir: PathBuf::from("/tmp/otter-prof"),
             cpu_prof_interval: 1000,
             cpu_prof_name: None,
+            cpu_prof_format: "cpuprofile".to_string(),
+            flamegraph: false,
+            cpu_prof_mode: "wall-clock".to_string(),
             max_heap_bytes: None,
             args: Vec::new(),
         };
@@ -3418,10 +3615,9 @@ if (process.argv[3] !== "from-cli") fail();
         .await
         .unwrap_err();
 
-        assert!(
-            err.to_string()
-                .contains("unknown local package binary `echo`")
-        );
+        let message = err.to_string();
+        assert!(message.contains("unknown local package binary `echo`"));
+        assert!(message.contains("otter add"), "{message}");
     }
 
     #[tokio::test]
@@ -3985,6 +4181,9 @@ export let value = inner;
             cpu_prof_dir: PathBuf::from("/tmp/otter-prof"),
             cpu_prof_interval: 1000,
             cpu_prof_name: None,
+            cpu_prof_format: "cpuprofile".to_string(),
+            flamegraph: false,
+            cpu_prof_mode: "wall-clock".to_string(),
             max_heap_bytes: None,
             args: Vec::new(),
         };
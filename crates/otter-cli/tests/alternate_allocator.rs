@@ -0,0 +1,70 @@
+//! The runtime under the optional `mimalloc` / `jemalloc` global allocator.
+//!
+//! Run with `cargo test -p otter-cli --features jemalloc` (or `mimalloc`);
+//! without either feature this file compiles to nothing.
+//!
+//! # Contents
+//! - The `otter` binary, built with the alternate allocator, produces the
+//!   same result for an allocation-heavy script as under the system
+//!   allocator.
+//! - Under jemalloc, an in-process runtime reports nonzero allocated and
+//!   resident bytes after the workload. This test binary installs jemalloc
+//!   itself, exactly as `src/allocator.rs` does for `otter`.
+
+#![cfg(any(feature = "mimalloc", feature = "jemalloc"))]
+
+use std::process::Command;
+
+#[cfg(feature = "jemalloc")]
+#[global_allocator]
+static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
+
+const WORKLOAD: &str = r#"
+    const rows = [];
+    for (let i = 0; i < 20000; i++) {
+        rows.push({ id: i, name: "row-" + i, tags: [i % 7, i % 11] });
+    }
+    const text = JSON.stringify(rows);
+    const parsed = JSON.parse(text);
+    parsed.reduce((sum, row) => sum + row.tags[0] + row.tags[1], 0) + ":" + parsed.length;
+"#;
+
+#[test]
+fn otter_runs_under_the_alternate_allocator() {
+    let output = Command::new(env!("CARGO_BIN_EXE_otter"))
+        .arg("-p")
+        .arg(WORKLOAD)
+        .output()
+        .expect("run otter");
+    assert!(
+        output.status.success(),
+        "otter failed with {:?}\nstdout:\n{}\nstderr:\n{}",
+        output.status.code(),
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    // Σ(i % 7) + Σ(i % 11) over 0..20000.
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim(),
+        "159988:20000"
+    );
+}
+
+#[cfg(feature = "jemalloc")]
+#[test]
+fn jemalloc_reports_stats_after_workload() {
+    use otter_runtime::allocator::allocator_stats;
+    use otter_runtime::{Runtime, SourceInput};
+
+    let mut runtime = Runtime::builder().build().expect("runtime");
+    let completion = runtime
+        .run_script(SourceInput::from_javascript(WORKLOAD), "<allocator>")
+        .expect("script")
+        .completion_string()
+        .to_string();
+    assert_eq!(completion, "159988:20000");
+
+    let stats = allocator_stats().expect("jemalloc reports stats");
+    assert!(stats.allocated_bytes > 0, "{stats:?}");
+    assert!(stats.resident_bytes >= stats.allocated_bytes, "{stats:?}");
+}
@@ -0,0 +1,136 @@
+//! CLI integration coverage for `--config`.
+//!
+//! # Contents
+//! - A valid config supplies env files and permission defaults, and an
+//!   explicit flag overrides the config.
+//! - An unknown key is reported as a warning and the run continues.
+//! - A wrongly typed value fails before execution with the file, line, and
+//!   key path in the error.
+
+use std::process::{Command, Output};
+
+fn otter_command(root: &std::path::Path) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_otter"));
+    command
+        .current_dir(root)
+        .env_remove("OTTER_CONFIG_GREETING");
+    command
+}
+
+fn stdout_of(output: &Output) -> String {
+    assert!(
+        output.status.success(),
+        "otter failed with {:?}\nstdout:\n{}\nstderr:\n{}",
+        output.status.code(),
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+const PRINT_GREETING: &str = "String(process.env.OTTER_CONFIG_GREETING)";
+
+#[test]
+fn config_defaults_apply_and_flags_win() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    std::fs::create_dir(tmp.path().join("conf")).expect("mkdir conf");
+    std::fs::write(
+        tmp.path().join("conf/otter.json"),
+        r#"{
+  "timeout": 10,
+  "envFiles": ["config.env"],
+  "permissions": { "allowEnv": true }
+}
+"#,
+    )
+    .expect("write config");
+    // Relative env files resolve against the config file's directory.
+    std::fs::write(
+        tmp.path().join("conf/config.env"),
+        "OTTER_CONFIG_GREETING=from-config\n",
+    )
+    .expect("write config.env");
+    std::fs::write(
+        tmp.path().join("cli.env"),
+        "OTTER_CONFIG_GREETING=from-flag\n",
+    )
+    .expect("write cli.env");
+
+    let output = otter_command(tmp.path())
+        .args(["--config", "conf/otter.json", "--print", PRINT_GREETING])
+        .output()
+        .expect("run with config");
+    assert_eq!(stdout_of(&output), "from-config");
+
+    let output = otter_command(tmp.path())
+        .args([
+            "--config",
+            "conf/otter.json",
+            "--env-file",
+            "cli.env",
+            "--print",
+            PRINT_GREETING,
+        ])
+        .output()
+        .expect("run with config and --env-file");
+    assert_eq!(stdout_of(&output), "from-flag");
+
+    let output = otter_command(tmp.path())
+        .args([
+            "--config",
+            "conf/otter.json",
+            "--deny-env=OTTER_CONFIG_GREETING",
+            "--print",
+            PRINT_GREETING,
+        ])
+        .output()
+        .expect("run with config and --deny-env");
+    assert_eq!(stdout_of(&output), "undefined");
+}
+
+#[test]
+fn unknown_config_key_warns_and_runs() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    std::fs::write(
+        tmp.path().join("otter.json"),
+        "{\n  \"timeout\": 10,\n  \"nodeApiProfile\": \"strict\"\n}\n",
+    )
+    .expect("write config");
+    let output = otter_command(tmp.path())
+        .args(["--config", "otter.json", "--print", "1 + 1"])
+        .output()
+        .expect("run with unknown key");
+    assert_eq!(stdout_of(&output), "2");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("warning: otter.json:3:3: unknown config key `nodeApiProfile` ignored"),
+        "missing warning in stderr:\n{stderr}"
+    );
+}
+
+#[test]
+fn wrongly_typed_config_value_is_a_clear_error() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    std::fs::write(
+        tmp.path().join("otter.json"),
+        "{\n  \"permissions\": {\n    \"allowNet\": 8080\n  }\n}\n",
+    )
+    .expect("write config");
+    let output = otter_command(tmp.path())
+        .args(["--config", "otter.json", "--print", "'ran'"])
+        .output()
+        .expect("run with bad config");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(
+        !String::from_utf8_lossy(&output.stdout).contains("ran"),
+        "script must not run with an invalid config"
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("otter.json:3:5")
+            && stderr.contains(
+                "`permissions.allowNet` must be `true`, `false`, or an array of strings, found number `8080`"
+            ),
+        "unclear error in stderr:\n{stderr}"
+    );
+}
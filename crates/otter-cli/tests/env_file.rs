@@ -0,0 +1,111 @@
+//! CLI integration coverage for `--env-file` and `--env-file-if-exists`.
+//!
+//! # Contents
+//! - Variables from a `.env` file are visible through `process.env`.
+//! - Later files override earlier ones; the host environment wins over both.
+//! - A missing `--env-file` fails the run; a missing
+//!   `--env-file-if-exists` is skipped.
+//!
+//! # Invariants
+//! - Env-file entries still pass the `--allow-env` capability check.
+
+use std::process::{Command, Output};
+
+fn otter_command(root: &std::path::Path) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_otter"));
+    command
+        .current_dir(root)
+        .env_remove("OTTER_ENV_FILE_GREETING")
+        .env_remove("OTTER_ENV_FILE_TARGET");
+    command
+}
+
+fn stdout_of(output: &Output) -> String {
+    assert!(
+        output.status.success(),
+        "otter failed with {:?}\nstdout:\n{}\nstderr:\n{}",
+        output.status.code(),
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+const PRINT_VARS: &str =
+    "[process.env.OTTER_ENV_FILE_GREETING, process.env.OTTER_ENV_FILE_TARGET].join(' ')";
+
+#[test]
+fn env_file_variables_reach_process_env() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    std::fs::write(
+        tmp.path().join(".env"),
+        "# greeting\nOTTER_ENV_FILE_GREETING=hello\nexport OTTER_ENV_FILE_TARGET=\"env file\"\n",
+    )
+    .expect("write .env");
+    let output = otter_command(tmp.path())
+        .args(["--allow-env", "--env-file", ".env", "--print", PRINT_VARS])
+        .output()
+        .expect("run with env file");
+    assert_eq!(stdout_of(&output), "hello env file");
+
+    let denied = otter_command(tmp.path())
+        .args([
+            "--env-file",
+            ".env",
+            "--print",
+            "typeof process.env.OTTER_ENV_FILE_GREETING",
+        ])
+        .output()
+        .expect("run without --allow-env");
+    assert_eq!(stdout_of(&denied), "undefined");
+}
+
+#[test]
+fn later_files_override_and_host_environment_wins() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    std::fs::write(
+        tmp.path().join("base.env"),
+        "OTTER_ENV_FILE_GREETING=base\nOTTER_ENV_FILE_TARGET=base\n",
+    )
+    .expect("write base.env");
+    std::fs::write(
+        tmp.path().join("local.env"),
+        "OTTER_ENV_FILE_GREETING=local\nOTTER_ENV_FILE_TARGET=local\n",
+    )
+    .expect("write local.env");
+    let output = otter_command(tmp.path())
+        .env("OTTER_ENV_FILE_TARGET", "host")
+        .args([
+            "--allow-env",
+            "--env-file",
+            "base.env",
+            "--env-file",
+            "local.env",
+            "--print",
+            PRINT_VARS,
+        ])
+        .output()
+        .expect("run with two env files");
+    assert_eq!(stdout_of(&output), "local host");
+}
+
+#[test]
+fn missing_env_file_fails_unless_optional() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let missing = otter_command(tmp.path())
+        .args(["--env-file", "absent.env", "--print", "1"])
+        .output()
+        .expect("run with missing env file");
+    assert!(!missing.status.success(), "missing --env-file must fail");
+    assert!(
+        String::from_utf8_lossy(&missing.stderr).contains("absent.env"),
+        "error names the missing file: {}",
+        String::from_utf8_lossy(&missing.stderr)
+    );
+
+    let optional = otter_command(tmp.path())
+        .args(["--env-file-if-exists", "absent.env", "--print", "1"])
+        .output()
+        .expect("run with missing optional env file");
+    assert_eq!(stdout_of(&optional), "1");
+}
@@ -644,3 +644,115 @@ if (total !== 19900) throw new Error("bad total");
     assert!(profile["samples"].is_array());
     assert!(folded.is_file(), "folded stack artifact exists");
 }
+
+#[test]
+fn cpu_profile_svg_format_writes_flamegraph() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let entry = tmp.path().join("entry.js");
+    let profile_dir = tmp.path().join("profiles");
+    std::fs::write(
+        &entry,
+        r#"
+function hot(n) {
+  let total = 0;
+  for (let index = 0; index < n; index = index + 1) total = total + index;
+  return total;
+}
+if (hot(500) !== 124750) throw new Error("bad total");
+"#,
+    )
+    .expect("write entry");
+
+    let output = otter_command(tmp.path())
+        .arg("--allow-read=*")
+        .arg("run")
+        .arg("entry.js")
+        .arg("--cpu-prof")
+        .arg("--cpu-prof-dir")
+        .arg(&profile_dir)
+        .arg("--cpu-prof-interval")
+        .arg("1")
+        .arg("--cpu-prof-format")
+        .arg("svg")
+        .output()
+        .expect("run CPU profile with SVG output");
+    assert_success(&output);
+
+    let svg = std::fs::read_to_string(profile_dir.join("entry.svg")).expect("read flamegraph");
+    assert!(svg.starts_with("<?xml"), "not an SVG document: {svg}");
+    assert!(svg.contains("<title>all ("), "missing root frame: {svg}");
+    assert!(svg.contains("hot"), "missing sampled function: {svg}");
+    assert!(profile_dir.join("entry.cpuprofile").is_file());
+    assert!(profile_dir.join("entry.folded").is_file());
+}
+
+#[test]
+fn flamegraph_flag_is_shorthand_for_svg_format() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let profile_dir = tmp.path().join("profiles");
+    std::fs::write(
+        tmp.path().join("entry.js"),
+        "let total = 0;\nfor (let i = 0; i < 200; i++) total += i;\n",
+    )
+    .expect("write entry");
+
+    let output = otter_command(tmp.path())
+        .arg("--allow-read=*")
+        .arg("run")
+        .arg("entry.js")
+        .arg("--cpu-prof")
+        .arg("--cpu-prof-dir")
+        .arg(&profile_dir)
+        .arg("--cpu-prof-interval")
+        .arg("1")
+        .arg("--cpu-prof-name")
+        .arg("flame")
+        .arg("--flamegraph")
+        .output()
+        .expect("run CPU profile with --flamegraph");
+    assert_success(&output);
+
+    let svg = std::fs::read_to_string(profile_dir.join("flame.svg")).expect("read flamegraph");
+    assert!(
+        svg.contains(r#"<text id="search""#),
+        "missing search control"
+    );
+    assert!(svg.trim_end().ends_with("</svg>"));
+}
+
+#[test]
+fn cpu_prof_mode_selects_on_cpu_sampling() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let profile_dir = tmp.path().join("profiles");
+    std::fs::write(
+        tmp.path().join("entry.js"),
+        "let total = 0;\nfor (let i = 0; i < 200; i++) total += i;\n",
+    )
+    .expect("write entry");
+
+    let output = otter_command(tmp.path())
+        .arg("--allow-read=*")
+        .arg("--json")
+        .arg("run")
+        .arg("entry.js")
+        .arg("--cpu-prof")
+        .arg("--cpu-prof-dir")
+        .arg(&profile_dir)
+        .arg("--cpu-prof-interval")
+        .arg("1")
+        .arg("--cpu-prof-mode")
+        .arg("on-cpu")
+        .output()
+        .expect("run on-CPU profile");
+    assert_success(&output);
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).expect("valid run JSON");
+    assert_eq!(report["cpuProfile"]["mode"], "on-cpu");
+    assert!(report["cpuProfile"]["samples"].as_u64().unwrap_or(0) > 0);
+    let folded =
+        std::fs::read_to_string(profile_dir.join("entry.folded")).expect("read folded profile");
+    assert!(
+        !folded.contains("(idle)"),
+        "on-CPU profile has idle stacks:\n{folded}"
+    );
+}
@@ -0,0 +1,101 @@
+//! CLI integration coverage for `--import` preload modules.
+//!
+//! # Contents
+//! - A preload runs before the entry in the same realm, so a global it sets
+//!   is visible to the entry; several preloads run in command-line order.
+//! - Preloads also run before `--print` code.
+//! - A throwing preload fails the run before the entry evaluates.
+
+use std::process::{Command, Output};
+
+fn otter_command(root: &std::path::Path) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_otter"));
+    command.current_dir(root);
+    command
+}
+
+fn stdout_of(output: &Output) -> String {
+    assert!(
+        output.status.success(),
+        "otter failed with {:?}\nstdout:\n{}\nstderr:\n{}",
+        output.status.code(),
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+fn write_fixtures(root: &std::path::Path) {
+    std::fs::write(
+        root.join("polyfill.mjs"),
+        "globalThis.preloaded = [\"polyfill\"];\n",
+    )
+    .expect("write polyfill");
+    std::fs::write(
+        root.join("second.mjs"),
+        "globalThis.preloaded.push(\"second\");\n",
+    )
+    .expect("write second");
+    std::fs::write(
+        root.join("throws.mjs"),
+        "throw new Error(\"preload exploded\");\n",
+    )
+    .expect("write throws");
+    std::fs::write(
+        root.join("main.mjs"),
+        "console.log(\"entry:\" + globalThis.preloaded.join(\"+\"));\n",
+    )
+    .expect("write main");
+}
+
+#[test]
+fn preloads_run_in_order_before_the_entry() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    write_fixtures(tmp.path());
+    let output = otter_command(tmp.path())
+        .args([
+            "--import",
+            "./polyfill.mjs",
+            "--import",
+            "./second.mjs",
+            "main.mjs",
+        ])
+        .output()
+        .expect("run with preloads");
+    assert_eq!(stdout_of(&output), "entry:polyfill+second");
+
+    let printed = otter_command(tmp.path())
+        .args([
+            "--import",
+            "./polyfill.mjs",
+            "--print",
+            "globalThis.preloaded.length",
+        ])
+        .output()
+        .expect("print with preload");
+    assert_eq!(stdout_of(&printed), "1");
+}
+
+#[test]
+fn throwing_preload_aborts_before_the_entry() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    write_fixtures(tmp.path());
+    let output = otter_command(tmp.path())
+        .args([
+            "--import",
+            "./throws.mjs",
+            "--import",
+            "./polyfill.mjs",
+            "main.mjs",
+        ])
+        .output()
+        .expect("run with throwing preload");
+    assert!(
+        !output.status.success(),
+        "a throwing preload must fail the run"
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains("entry:"), "entry ran: {stdout}");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("preload exploded"), "stderr: {stderr}");
+}
@@ -3,6 +3,7 @@
 //! # Contents
 //! - Local `otter install` for workspace and `file:` dependencies.
 //! - Second install no-op / byte-stable lockfile check.
+//! - `otter add` recording named and bare `file:` specifiers.
 //! - Runtime consumption of the installed graph through `otter run`.
 //!
 //! # Invariants
@@ -121,6 +122,67 @@ if (process.argv[2] !== "from-cli") fail();
     assert_success(run_bin);
 }
 
+#[test]
+fn add_file_dependency_records_spec_and_links_package() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let root = tmp.path();
+    for (dir, name, value) in [("libs/named", "named-lib", 1), ("libs/bare", "bare-lib", 2)] {
+        std::fs::create_dir_all(root.join(dir)).expect("mkdir file dep");
+        std::fs::write(
+            root.join(dir).join("package.json"),
+            format!(r#"{{"name":"{name}","version":"1.0.0","type":"module","main":"index.ts"}}"#),
+        )
+        .expect("write file package");
+        std::fs::write(
+            root.join(dir).join("index.ts"),
+            format!("export const value = {value};\n"),
+        )
+        .expect("write file index");
+    }
+    std::fs::write(
+        root.join("package.json"),
+        r#"{"name":"app","version":"0.1.0","type":"module"}"#,
+    )
+    .expect("write root package");
+    std::fs::write(
+        root.join("entry.ts"),
+        r#"
+import { value as named } from "named-lib";
+import { value as bare } from "bare-lib";
+if (named + bare !== 3) process.exit(53);
+"#,
+    )
+    .expect("write entry");
+
+    let add = otter_command(root)
+        .args(["add", "named-lib@file:libs/named", "file:libs/bare"])
+        .output()
+        .expect("add file deps");
+    assert_success(add);
+    let manifest: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(root.join("package.json")).expect("read package.json"),
+    )
+    .expect("package.json is JSON");
+    assert_eq!(manifest["dependencies"]["named-lib"], "file:libs/named");
+    assert_eq!(manifest["dependencies"]["bare-lib"], "file:libs/bare");
+
+    let run_entry = otter_command(root)
+        .arg("run")
+        .arg("entry.ts")
+        .output()
+        .expect("run entry");
+    assert_success(run_entry);
+
+    let missing = otter_command(root)
+        .args(["add", "gone@file:libs/gone"])
+        .output()
+        .expect("add missing file dep");
+    assert!(
+        !missing.status.success(),
+        "missing file: dependency must fail"
+    );
+}
+
 #[test]
 fn installed_registry_graph_feeds_run_imports_and_bins() {
     let tmp = tempfile::tempdir().expect("tempdir");
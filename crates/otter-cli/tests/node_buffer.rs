@@ -0,0 +1,129 @@
+//! CLI coverage for `Buffer` string encodings.
+//!
+//! # Contents
+//! - `Buffer.from(str, encoding)` / `buf.toString(encoding)` roundtrips for
+//!   every supported encoding and its aliases.
+//! - `base64url` output without padding, and decoding with or without it.
+//! - Lenient decoding of odd-length hex and dangling base64 characters.
+//! - UTF-8 decoding through the shared native decoder: `StringDecoder`
+//!   chunk carry-over and `Buffer#toString` replacement of invalid input.
+//!
+//! # Invariants
+//! - Malformed input truncates at the first undecodable unit instead of
+//!   throwing, matching Node's decoders.
+
+use std::process::Command;
+
+fn otter_command(root: &std::path::Path) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_otter"));
+    command.current_dir(root);
+    command
+}
+
+fn assert_success(output: std::process::Output) {
+    assert!(
+        output.status.success(),
+        "otter failed with {:?}\nstdout:\n{}\nstderr:\n{}",
+        output.status.code(),
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+fn run_main(source: &str) {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    std::fs::write(tmp.path().join("main.js"), source).expect("write main");
+    let output = otter_command(tmp.path())
+        .arg("run")
+        .arg("main.js")
+        .output()
+        .expect("run node buffer");
+    assert_success(output);
+}
+
+#[test]
+fn buffer_string_encodings_roundtrip() {
+    run_main(
+        r#"
+const assert = require('node:assert');
+const { Buffer } = require('node:buffer');
+
+const text = 'héllo, wörld ✓ 😀';
+for (const encoding of ['utf8', 'utf-8', 'utf16le', 'ucs2', 'ucs-2', 'base64', 'base64url', 'hex']) {
+  const bytes = Buffer.from(text, encoding === 'hex' || encoding.startsWith('base64') ? 'utf8' : encoding);
+  const encoded = bytes.toString(encoding);
+  const decoded = Buffer.from(encoded, encoding);
+  assert.deepStrictEqual(decoded, bytes, encoding);
+}
+
+const latin = 'café ÿ\u0000';
+for (const encoding of ['latin1', 'binary']) {
+  assert.strictEqual(Buffer.from(latin, encoding).toString(encoding), latin, encoding);
+}
+assert.strictEqual(Buffer.from('plain ascii', 'ascii').toString('ascii'), 'plain ascii');
+assert.strictEqual(Buffer.from([0xe9]).toString('ascii'), 'i');
+
+assert.strictEqual(Buffer.from('hi').toString('hex'), '6869');
+assert.strictEqual(Buffer.from('6869', 'hex').toString(), 'hi');
+assert.strictEqual(Buffer.from('hi', 'utf16le').toString('hex'), '68006900');
+"#,
+    );
+}
+
+#[test]
+fn base64url_emits_no_padding_and_accepts_either_form() {
+    run_main(
+        r#"
+const assert = require('node:assert');
+
+const bytes = Buffer.from([0xfb, 0xff, 0xbf, 0x01]);
+assert.strictEqual(bytes.toString('base64'), '+/+/AQ==');
+assert.strictEqual(bytes.toString('base64url'), '-_-_AQ');
+
+assert.deepStrictEqual(Buffer.from('-_-_AQ', 'base64url'), bytes);
+assert.deepStrictEqual(Buffer.from('-_-_AQ==', 'base64url'), bytes);
+assert.deepStrictEqual(Buffer.from('+/+/AQ', 'base64'), bytes);
+assert.strictEqual(Buffer.byteLength('-_-_AQ', 'base64url'), 4);
+"#,
+    );
+}
+
+#[test]
+fn malformed_hex_and_base64_truncate_like_node() {
+    run_main(
+        r#"
+const assert = require('node:assert');
+
+assert.deepStrictEqual([...Buffer.from('abc', 'hex')], [0xab]);
+assert.deepStrictEqual([...Buffer.from('a', 'hex')], []);
+assert.deepStrictEqual([...Buffer.from('12zz34', 'hex')], [0x12]);
+assert.deepStrictEqual([...Buffer.from('AAAAA', 'base64')], [0, 0, 0]);
+"#,
+    );
+}
+
+#[test]
+fn utf8_decoding_carries_split_sequences_and_replaces_invalid_bytes() {
+    run_main(
+        r#"
+const assert = require('node:assert');
+const { StringDecoder } = require('node:string_decoder');
+
+const text = 'ascii é ✓ 😀 '.repeat(5000);
+const bytes = Buffer.from(text);
+const decoder = new StringDecoder('utf8');
+let out = '';
+for (let at = 0; at < bytes.length; at += 7) out += decoder.write(bytes.subarray(at, at + 7));
+out += decoder.end();
+assert.strictEqual(out, text);
+
+const tail = new StringDecoder('utf8');
+assert.strictEqual(tail.write(Buffer.from([0x61, 0xf0, 0x9f])), 'a');
+assert.strictEqual(tail.end(), '\ufffd');
+
+assert.strictEqual(Buffer.from([0x61, 0xc0, 0xaf, 0x62]).toString(), 'a\ufffd\ufffdb');
+assert.strictEqual(Buffer.from([0xed, 0xa0, 0x80]).toString('utf8'), '\ufffd\ufffd\ufffd');
+assert.strictEqual(Buffer.from('x€y').toString('utf8', 1, 4), '€');
+"#,
+    );
+}
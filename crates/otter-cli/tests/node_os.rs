@@ -0,0 +1,44 @@
+//! CLI coverage for the pool-sizing `node:os` queries.
+//!
+//! # Contents
+//! - `os.availableParallelism()` returns a positive integer no larger than
+//!   the host CPU list.
+//! - `os.machine()` returns a non-empty architecture string.
+
+use std::process::Command;
+
+#[test]
+fn node_os_reports_parallelism_and_machine() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    std::fs::write(
+        tmp.path().join("main.js"),
+        r#"
+const assert = require('node:assert');
+const os = require('node:os');
+
+const parallelism = os.availableParallelism();
+assert.ok(Number.isInteger(parallelism), `not an integer: ${parallelism}`);
+assert.ok(parallelism >= 1, `parallelism below 1: ${parallelism}`);
+assert.ok(parallelism <= Math.max(os.cpus().length, 1), 'parallelism exceeds host CPUs');
+
+const machine = os.machine();
+assert.strictEqual(typeof machine, 'string');
+assert.ok(machine.length > 0, 'machine() is empty');
+"#,
+    )
+    .expect("write main");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_otter"))
+        .current_dir(tmp.path())
+        .arg("run")
+        .arg("main.js")
+        .output()
+        .expect("run node os");
+    assert!(
+        output.status.success(),
+        "otter failed with {:?}\nstdout:\n{}\nstderr:\n{}",
+        output.status.code(),
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -0,0 +1,112 @@
+//! CLI coverage for `node:timers/promises` over the global timer API.
+//!
+//! # Contents
+//! - An awaited `setTimeout` / `setImmediate` resolving with its value.
+//! - `AbortSignal` rejecting a pending timer with an `AbortError`.
+//! - The `setInterval` async iterator yielding until its signal aborts.
+//!
+//! # Invariants
+//! - Abort rejections are Node-shaped: `name: 'AbortError'`,
+//!   `code: 'ABORT_ERR'`, and the signal's reason as `cause`.
+
+use std::process::Command;
+
+fn otter_command(root: &std::path::Path) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_otter"));
+    command.current_dir(root);
+    command
+}
+
+fn run_main(source: &str) -> std::process::Output {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    std::fs::write(tmp.path().join("main.js"), source).expect("write main");
+    otter_command(tmp.path())
+        .arg("run")
+        .arg("main.js")
+        .output()
+        .expect("run node timers/promises")
+}
+
+fn assert_success(output: &std::process::Output) {
+    assert!(
+        output.status.success(),
+        "otter failed with {:?}\nstdout:\n{}\nstderr:\n{}",
+        output.status.code(),
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn awaited_timers_resolve_with_their_value() {
+    let output = run_main(
+        r#"
+const assert = require('node:assert');
+const timers = require('node:timers/promises');
+
+(async () => {
+  assert.strictEqual(await timers.setTimeout(5, 'late'), 'late');
+  assert.strictEqual(await timers.setImmediate('soon'), 'soon');
+  assert.strictEqual(await timers.scheduler.wait(1), undefined);
+  console.log('resolved');
+})().catch((error) => { console.error(error); process.exit(1); });
+"#,
+    );
+    assert_success(&output);
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "resolved");
+}
+
+#[test]
+fn abort_rejects_a_pending_timer_with_abort_error() {
+    let output = run_main(
+        r#"
+const assert = require('node:assert');
+const { setTimeout: sleep } = require('node:timers/promises');
+
+(async () => {
+  const controller = new AbortController();
+  const pending = sleep(60_000, 'never', { signal: controller.signal });
+  controller.abort('stop');
+  await assert.rejects(pending, (error) => {
+    assert.strictEqual(error.name, 'AbortError');
+    assert.strictEqual(error.code, 'ABORT_ERR');
+    assert.strictEqual(error.cause, 'stop');
+    return true;
+  });
+
+  await assert.rejects(
+    sleep(1, 'x', { signal: AbortSignal.abort() }),
+    { name: 'AbortError' },
+  );
+  console.log('aborted');
+})().catch((error) => { console.error(error); process.exit(1); });
+"#,
+    );
+    assert_success(&output);
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "aborted");
+}
+
+#[test]
+fn set_interval_iterator_yields_until_aborted() {
+    let output = run_main(
+        r#"
+const assert = require('node:assert');
+const { setInterval } = require('node:timers/promises');
+
+(async () => {
+  const controller = new AbortController();
+  const seen = [];
+  await assert.rejects(async () => {
+    for await (const value of setInterval(2, 'tick', { signal: controller.signal })) {
+      seen.push(value);
+      if (seen.length === 3) controller.abort();
+    }
+  }, { name: 'AbortError' });
+  assert.deepStrictEqual(seen, ['tick', 'tick', 'tick']);
+  console.log(seen.length);
+})().catch((error) => { console.error(error); process.exit(1); });
+"#,
+    );
+    assert_success(&output);
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "3");
+}
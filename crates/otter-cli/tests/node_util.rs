@@ -5,6 +5,8 @@
 //! - Dotenv parsing and USV-string normalization.
 //! - ANSI named, nested, and hexadecimal style composition.
 //! - Deterministic non-TTY stream behavior.
+//! - `promisify` / `callbackify` conversions, including the
+//!   `util.promisify.custom` override and falsy rejections.
 //!
 //! # Invariants
 //! - Hosted aliases share one cached util export per runtime.
@@ -53,3 +55,72 @@ if (util.styleText('red', 'x', { stream }) !== 'x') throw new Error('non-tty col
         String::from_utf8_lossy(&output.stderr)
     );
 }
+
+#[test]
+fn node_util_promisify_and_callbackify_round_trip() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    std::fs::write(
+        tmp.path().join("main.js"),
+        r#"
+const assert = require('node:assert');
+const util = require('node:util');
+
+function readValue(key, cb) {
+  setTimeout(() => (key === 'bad' ? cb(new Error('missing ' + key)) : cb(null, key + '!', 'extra')), 0);
+}
+
+function custom(cb) { cb(null, 'callback path'); }
+custom[util.promisify.custom] = () => Promise.resolve(['a', 'b']);
+
+async function double(x) {
+  if (x < 0) throw new RangeError('negative');
+  if (x === 0) return Promise.reject(null);
+  return x * 2;
+}
+
+(async () => {
+  const read = util.promisify(readValue);
+  assert.strictEqual(await read('ok'), 'ok!');
+  await assert.rejects(read('bad'), { message: 'missing bad' });
+  assert.strictEqual(util.promisify(read), read);
+
+  const promisedCustom = util.promisify(custom);
+  assert.strictEqual(promisedCustom, custom[util.promisify.custom]);
+  assert.deepStrictEqual(await promisedCustom(), ['a', 'b']);
+  assert.strictEqual(util.promisify.custom, Symbol.for('nodejs.util.promisify.custom'));
+
+  const doubleCb = util.callbackify(double);
+  assert.strictEqual(doubleCb.name, 'doubleCallbackified');
+  assert.strictEqual(doubleCb.length, 2);
+  const outcome = (arg) => new Promise((resolve) => doubleCb(arg, (err, value) => resolve([err, value])));
+
+  assert.deepStrictEqual(await outcome(21), [null, 42]);
+  const [rangeError] = await outcome(-1);
+  assert.ok(rangeError instanceof RangeError);
+  assert.strictEqual(rangeError.message, 'negative');
+  const [falsy] = await outcome(0);
+  assert.strictEqual(falsy.code, 'ERR_FALSY_VALUE_REJECTION');
+  assert.strictEqual(falsy.reason, null);
+
+  assert.throws(() => util.promisify(42), { code: 'ERR_INVALID_ARG_TYPE' });
+  assert.throws(() => doubleCb(1), { code: 'ERR_INVALID_ARG_TYPE' });
+  console.log('ok');
+})().catch((error) => { console.error(error); process.exit(1); });
+"#,
+    )
+    .expect("write util fixture");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_otter"))
+        .current_dir(tmp.path())
+        .arg("run")
+        .arg("main.js")
+        .output()
+        .expect("run util fixture");
+    assert!(
+        output.status.success(),
+        "otter failed\nstdout:\n{}\nstderr:\n{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "ok");
+}
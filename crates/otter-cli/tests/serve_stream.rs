@@ -0,0 +1,253 @@
+//! CLI coverage for streamed `Otter.serve` responses.
+//!
+//! # Contents
+//! - A handler returns a `ReadableStream` body that enqueues three
+//!   server-sent events on a timer; a raw TCP client decodes the chunked
+//!   framing and checks each event arrives as it is written.
+//! - A client that stops reading holds back a pull-based stream: the server
+//!   reads only a bounded amount ahead, then delivers the whole body once
+//!   the client drains it.
+//!
+//! # Invariants
+//! - A stream body is sent with `Transfer-Encoding: chunked` and no
+//!   `Content-Length`.
+//! - Chunks are flushed per stream read, not buffered until the stream
+//!   closes.
+//! - The server never pulls from a body stream faster than the client
+//!   reads, beyond a fixed channel and the socket buffers.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+
+#[test]
+fn serve_streams_server_sent_events_chunk_by_chunk() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    std::fs::write(
+        tmp.path().join("main.js"),
+        r#"
+const encoder = new TextEncoder();
+const server = Otter.serve({
+  port: 0,
+  fetch() {
+    let sent = 0;
+    const body = new ReadableStream({
+      start(controller) {
+        const timer = setInterval(() => {
+          sent += 1;
+          controller.enqueue(encoder.encode(`data: event ${sent}\n\n`));
+          if (sent === 3) {
+            clearInterval(timer);
+            controller.close();
+            setTimeout(() => server.stop(), 100);
+          }
+        }, 150);
+      },
+    });
+    return new Response(body, {
+      headers: { "content-type": "text/event-stream", "cache-control": "no-cache" },
+    });
+  },
+});
+console.log(server.url);
+"#,
+    )
+    .expect("write main");
+    let (child, authority) = start_server(tmp.path());
+
+    let mut socket = TcpStream::connect(&authority).expect("connect");
+    socket
+        .set_read_timeout(Some(Duration::from_secs(10)))
+        .expect("read timeout");
+    write!(
+        socket,
+        "GET /events HTTP/1.1\r\nHost: {authority}\r\nConnection: close\r\n\r\n"
+    )
+    .expect("send request");
+    let mut reader = BufReader::new(socket);
+
+    let head = read_head(&mut reader);
+    assert!(head[0].starts_with("http/1.1 200"), "status line: {head:?}");
+    assert!(
+        head.iter().any(|line| line == "transfer-encoding: chunked"),
+        "missing chunked framing: {head:?}"
+    );
+    assert!(
+        !head.iter().any(|line| line.starts_with("content-length:")),
+        "streamed body must not carry a length: {head:?}"
+    );
+    assert!(
+        head.iter()
+            .any(|line| line == "content-type: text/event-stream"),
+        "missing event-stream type: {head:?}"
+    );
+
+    let mut events = Vec::new();
+    while let Some(data) = read_chunk(&mut reader) {
+        events.push((
+            Instant::now(),
+            String::from_utf8(data).expect("utf-8 chunk"),
+        ));
+    }
+
+    let payloads: Vec<&str> = events.iter().map(|(_, text)| text.as_str()).collect();
+    assert_eq!(
+        payloads,
+        [
+            "data: event 1\n\n",
+            "data: event 2\n\n",
+            "data: event 3\n\n"
+        ]
+    );
+    // The handler waits 150 ms between events. A buffered body would land
+    // all three at once; a flushed stream spreads them out.
+    let spread = events[2].0.duration_since(events[0].0);
+    assert!(
+        spread >= Duration::from_millis(150),
+        "events arrived together ({spread:?}); chunks were not flushed"
+    );
+
+    wait_for_exit(child);
+}
+
+#[test]
+fn serve_body_stream_waits_for_a_slow_reader() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    std::fs::write(
+        tmp.path().join("main.js"),
+        r#"
+const CHUNKS = 1000;
+const CHUNK_SIZE = 64 * 1024;
+let pulled = 0;
+const server = Otter.serve({
+  port: 0,
+  fetch(req) {
+    if (new URL(req.url).pathname === "/pulled") {
+      return new Response(String(pulled));
+    }
+    const body = new ReadableStream({
+      pull(controller) {
+        pulled += 1;
+        controller.enqueue(new Uint8Array(CHUNK_SIZE));
+        if (pulled === CHUNKS) {
+          controller.close();
+          setTimeout(() => server.stop(), 100);
+        }
+      },
+    });
+    return new Response(body);
+  },
+});
+console.log(server.url);
+"#,
+    )
+    .expect("write main");
+    let (child, authority) = start_server(tmp.path());
+
+    let mut socket = TcpStream::connect(&authority).expect("connect");
+    socket
+        .set_read_timeout(Some(Duration::from_secs(30)))
+        .expect("read timeout");
+    write!(
+        socket,
+        "GET /body HTTP/1.1\r\nHost: {authority}\r\nConnection: close\r\n\r\n"
+    )
+    .expect("send request");
+    let mut reader = BufReader::new(socket);
+    let head = read_head(&mut reader);
+    assert!(head[0].starts_with("http/1.1 200"), "status line: {head:?}");
+
+    // Stall the body connection. Without backpressure the pump would pull
+    // all 1000 chunks (64 MiB) into memory within this window.
+    std::thread::sleep(Duration::from_millis(750));
+    let mut probe = TcpStream::connect(&authority).expect("connect probe");
+    probe
+        .set_read_timeout(Some(Duration::from_secs(10)))
+        .expect("probe read timeout");
+    write!(
+        probe,
+        "GET /pulled HTTP/1.1\r\nHost: {authority}\r\nConnection: close\r\n\r\n"
+    )
+    .expect("send probe");
+    let mut probe_response = String::new();
+    probe
+        .read_to_string(&mut probe_response)
+        .expect("read probe");
+    let pulled: usize = probe_response
+        .rsplit("\r\n\r\n")
+        .next()
+        .and_then(|body| body.trim().parse().ok())
+        .unwrap_or_else(|| panic!("bad probe response {probe_response:?}"));
+    assert!(
+        pulled < 300,
+        "pulled {pulled} chunks while the client was not reading"
+    );
+
+    let mut total = 0;
+    while let Some(data) = read_chunk(&mut reader) {
+        total += data.len();
+    }
+    assert_eq!(total, 1000 * 64 * 1024);
+    wait_for_exit(child);
+}
+
+/// Run `main.js` in `dir` under `otter run --allow-net` and return the child
+/// with the `host:port` of the server URL it prints.
+fn start_server(dir: &std::path::Path) -> (Child, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_otter"))
+        .current_dir(dir)
+        .arg("--allow-net")
+        .arg("run")
+        .arg("main.js")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("spawn otter");
+    let mut url = String::new();
+    BufReader::new(child.stdout.take().expect("stdout"))
+        .read_line(&mut url)
+        .expect("read server url");
+    let authority = url
+        .trim()
+        .strip_prefix("http://")
+        .unwrap_or_else(|| panic!("unexpected server url {url:?}"))
+        .to_string();
+    (child, authority)
+}
+
+/// Read the status line and headers, lowercased, up to the blank line.
+fn read_head(reader: &mut impl BufRead) -> Vec<String> {
+    let mut head = Vec::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).expect("read header line");
+        if line == "\r\n" || line.is_empty() {
+            return head;
+        }
+        head.push(line.trim_end().to_ascii_lowercase());
+    }
+}
+
+/// Decode one chunk of a chunked body, or `None` at the terminating chunk.
+fn read_chunk(reader: &mut impl BufRead) -> Option<Vec<u8>> {
+    let mut size_line = String::new();
+    reader.read_line(&mut size_line).expect("read chunk size");
+    let size = usize::from_str_radix(size_line.trim_end(), 16)
+        .unwrap_or_else(|_| panic!("bad chunk size line {size_line:?}"));
+    let mut data = vec![0; size + 2];
+    reader.read_exact(&mut data).expect("read chunk");
+    assert_eq!(&data[size..], b"\r\n", "chunk must end with CRLF");
+    data.truncate(size);
+    (size > 0).then_some(data)
+}
+
+fn wait_for_exit(child: Child) {
+    let output = child.wait_with_output().expect("wait otter");
+    assert!(
+        output.status.success(),
+        "otter failed with {:?}\nstderr:\n{}",
+        output.status.code(),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -298,6 +298,14 @@ fn compile_binary_to(
 ) -> Result<u16, CompileError> {
     let _ = span;
     let span = (b.span.start, b.span.end);
+    // Two literal operands compare to a boolean known at compile time; no
+    // coercion can reach user code, so the whole expression is one load.
+    if let Some(value) = crate::expr::fold::fold_comparison(b) {
+        let dst = destination.unwrap_or_else(|| cx.alloc_scratch());
+        return Ok(crate::expr::fold::emit_boolean_constant(
+            cx, value, span, dst,
+        ));
+    }
     // Read the annotation-derived hint before lowering the operands: the
     // bindings it consults are the ones in scope at the source position.
     let number_typed_operands = expr_number_typed(cx, &b.left) && expr_number_typed(cx, &b.right);
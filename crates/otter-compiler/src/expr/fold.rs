@@ -0,0 +1,154 @@
+//! Compile-time folding of operators whose operands are literals.
+//!
+//! # Contents
+//! - [`typeof_literal`] — the static `typeof` result of a literal operand.
+//! - [`fold_comparison`] — the boolean result of an equality or relational
+//!   operator between two literals.
+//! - [`constant_boolean`] — the boolean an expression provably evaluates to,
+//!   used to fold `!`.
+//! - [`emit_boolean_constant`] — loads a folded boolean.
+//!
+//! # Invariants
+//! - Only literal operands fold. A literal has no side effect and its
+//!   operators cannot reach user code (no `valueOf`, no `[Symbol.toPrimitive]`),
+//!   so replacing the operator with its result is unobservable.
+//! - Identifiers never fold: `typeof undeclared` and a shadowed `undefined`
+//!   carry runtime meaning.
+//! - Operand pairs whose comparison would need a cross-type coercion are left
+//!   to the runtime operator.
+//!
+//! # See also
+//! - [`super::unary`] and [`super::binary`] — the lowerings that consult it.
+//! - <https://tc39.es/ecma262/#sec-typeof-operator>
+//! - <https://tc39.es/ecma262/#sec-islessthan>
+
+use crate::*;
+use oxc_ast::ast::{BinaryExpression, Expression, UnaryOperator};
+use std::cmp::Ordering;
+
+/// A primitive literal operand, after stripping parentheses.
+enum LiteralValue<'a> {
+    Number(f64),
+    String(&'a str),
+    Boolean(bool),
+    Null,
+}
+
+fn literal_value<'a>(expr: &'a Expression<'_>) -> Option<LiteralValue<'a>> {
+    match expr {
+        Expression::ParenthesizedExpression(p) => literal_value(&p.expression),
+        Expression::NumericLiteral(lit) => Some(LiteralValue::Number(lit.value)),
+        Expression::UnaryExpression(u) if matches!(u.operator, UnaryOperator::UnaryNegation) => {
+            match &u.argument {
+                Expression::NumericLiteral(lit) => Some(LiteralValue::Number(-lit.value)),
+                _ => None,
+            }
+        }
+        // A string carrying lone surrogates is stored as raw UTF-16; leave it
+        // to the runtime rather than compare its lossy `str` form.
+        Expression::StringLiteral(lit) if !lit.lone_surrogates => {
+            Some(LiteralValue::String(lit.value.as_str()))
+        }
+        Expression::BooleanLiteral(lit) => Some(LiteralValue::Boolean(lit.value)),
+        Expression::NullLiteral(_) => Some(LiteralValue::Null),
+        _ => None,
+    }
+}
+
+/// §13.5.3 Table 41 — the `typeof` result of a literal operand, or `None`
+/// when the operand is not a literal.
+pub(crate) fn typeof_literal(expr: &Expression<'_>) -> Option<&'static str> {
+    match expr {
+        Expression::ParenthesizedExpression(p) => typeof_literal(&p.expression),
+        Expression::StringLiteral(_) => Some("string"),
+        Expression::BigIntLiteral(_) => Some("bigint"),
+        _ => match literal_value(expr)? {
+            LiteralValue::Number(_) => Some("number"),
+            LiteralValue::Boolean(_) => Some("boolean"),
+            LiteralValue::String(_) => Some("string"),
+            // §13.5.3 — `typeof null` is "object".
+            LiteralValue::Null => Some("object"),
+        },
+    }
+}
+
+/// The result of an equality or relational operator whose operands are both
+/// literals, or `None` when the pair is not foldable.
+///
+/// Strict equality folds for every literal pair (different types are never
+/// strictly equal). Loose equality folds only between same-typed operands,
+/// where it coincides with strict equality. Relational operators fold between
+/// two numbers (IEEE comparison, so `NaN` is unordered) or two strings
+/// (§7.2.13 compares UTF-16 code units, not scalar values).
+pub(crate) fn fold_comparison(b: &BinaryExpression<'_>) -> Option<bool> {
+    let left = literal_value(&b.left)?;
+    let right = literal_value(&b.right)?;
+    let same_type = same_type_equal(&left, &right);
+    match b.operator {
+        BinaryOperator::StrictEquality => Some(same_type.unwrap_or(false)),
+        BinaryOperator::StrictInequality => Some(!same_type.unwrap_or(false)),
+        BinaryOperator::Equality => same_type,
+        BinaryOperator::Inequality => same_type.map(|equal| !equal),
+        BinaryOperator::LessThan => compare(&left, &right, Ordering::is_lt),
+        BinaryOperator::LessEqualThan => compare(&left, &right, Ordering::is_le),
+        BinaryOperator::GreaterThan => compare(&left, &right, Ordering::is_gt),
+        BinaryOperator::GreaterEqualThan => compare(&left, &right, Ordering::is_ge),
+        _ => None,
+    }
+}
+
+/// `Some(equal)` for two literals of the same type, `None` otherwise.
+fn same_type_equal(left: &LiteralValue<'_>, right: &LiteralValue<'_>) -> Option<bool> {
+    match (left, right) {
+        (LiteralValue::Number(a), LiteralValue::Number(b)) => Some(a == b),
+        (LiteralValue::String(a), LiteralValue::String(b)) => Some(a == b),
+        (LiteralValue::Boolean(a), LiteralValue::Boolean(b)) => Some(a == b),
+        (LiteralValue::Null, LiteralValue::Null) => Some(true),
+        _ => None,
+    }
+}
+
+fn compare(
+    left: &LiteralValue<'_>,
+    right: &LiteralValue<'_>,
+    accept: fn(Ordering) -> bool,
+) -> Option<bool> {
+    match (left, right) {
+        (LiteralValue::Number(a), LiteralValue::Number(b)) => {
+            Some(a.partial_cmp(b).is_some_and(accept))
+        }
+        (LiteralValue::String(a), LiteralValue::String(b)) => {
+            Some(accept(a.encode_utf16().cmp(b.encode_utf16())))
+        }
+        _ => None,
+    }
+}
+
+/// The boolean `expr` provably evaluates to: a boolean literal, a foldable
+/// literal comparison, or `!` of either.
+pub(crate) fn constant_boolean(expr: &Expression<'_>) -> Option<bool> {
+    match expr {
+        Expression::ParenthesizedExpression(p) => constant_boolean(&p.expression),
+        Expression::BooleanLiteral(lit) => Some(lit.value),
+        Expression::UnaryExpression(u) if matches!(u.operator, UnaryOperator::LogicalNot) => {
+            constant_boolean(&u.argument).map(|value| !value)
+        }
+        Expression::BinaryExpression(b) => fold_comparison(b),
+        _ => None,
+    }
+}
+
+/// Load a folded boolean into `destination`.
+pub(crate) fn emit_boolean_constant(
+    cx: &mut Compiler,
+    value: bool,
+    span: (u32, u32),
+    destination: u16,
+) -> u16 {
+    cx.emit(
+        if value { Op::LoadTrue } else { Op::LoadFalse },
+        [Operand::Register(destination)],
+        span,
+    );
+    destination
+}
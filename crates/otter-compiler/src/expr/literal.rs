@@ -11,6 +11,7 @@
 //! # Invariants
 //! - Each successful literal lowering writes exactly one result register.
 //! - BigInt and RegExp payloads are validated before their load is emitted.
+//! - BigInt constants are interned by canonical decimal value.
 //!
 //! # See also
 //! - [`super`] — expression dispatch and shared helpers.
@@ -80,6 +81,8 @@ pub(crate) fn compile_bigint_literal_into(
 ) -> Result<u16, CompileError> {
     let _ = span;
     let span = (lit.span.start, lit.span.end);
+    // The parser already renders every spelling (`0x10n`, `1_6n`) as its
+    // base-10 value, so interning `decimal` keys the pool on the value.
     let decimal = lit.value.as_str().to_string();
     // Compile-time syntactic validation so the runtime
     // parse path can stay strict (treats failure as
@@ -162,11 +165,35 @@ pub(crate) fn compile_regexp_literal_into(
             span,
         });
     }
-    if let Err(e) = otter_regex::Regex::with_flags(pattern_text, engine_flags) {
-        return Err(CompileError::Unsupported {
-            node: format!("RegExpLiteral `/{pattern_text}/{flags_str}` rejected: {e}"),
-            span,
-        });
+    match otter_regex::Regex::with_flags(pattern_text, engine_flags) {
+        Ok(_) => {}
+        // §22.2.1 early errors are SyntaxErrors; point at the offending
+        // pattern position rather than the whole literal.
+        Err(otter_regex::RegexError::Syntax { message, offset }) => {
+            let message = format!(
+                "SyntaxError: invalid regular expression `/{pattern_text}/{flags_str}`: {message}"
+            );
+            return Err(CompileError::Syntax {
+                messages: vec![message.clone()],
+                diagnostics: vec![SyntaxDiagnostic {
+                    code: "SYNTAX_ERROR".to_string(),
+                    message,
+                    range: Some(regexp_error_range(
+                        lit.span.start,
+                        span,
+                        pattern_text,
+                        offset,
+                    )),
+                    help: None,
+                }],
+            });
+        }
+        Err(e) => {
+            return Err(CompileError::Unsupported {
+                node: format!("RegExpLiteral `/{pattern_text}/{flags_str}` rejected: {e}"),
+                span,
+            });
+        }
     }
     let pattern_utf16: Vec<u16> = pattern_text.encode_utf16().collect();
     let const_idx = cx.intern_regexp_constant(&pattern_utf16, &flags_str);
@@ -181,6 +208,31 @@ pub(crate) fn compile_regexp_literal_into(
     Ok(destination)
 }
 
+/// Absolute source range of the pattern character at `offset` (UTF-16 code
+/// units into `pattern`) inside a regex literal starting at `literal_start`.
+/// An unpositioned or out-of-range offset falls back to `literal_span`.
+fn regexp_error_range(
+    literal_start: u32,
+    literal_span: (u32, u32),
+    pattern: &str,
+    offset: usize,
+) -> (u32, u32) {
+    let mut units = 0;
+    for (byte, ch) in pattern.char_indices() {
+        if units >= offset {
+            // `+ 1` skips the opening `/`.
+            let start = literal_start + 1 + byte as u32;
+            return (start, start + ch.len_utf8() as u32);
+        }
+        units += ch.len_utf16();
+    }
+    if units == offset {
+        let end = literal_start + 1 + pattern.len() as u32;
+        return (end, end + 1);
+    }
+    literal_span
+}
+
 pub(crate) fn compile_numeric_literal(
     cx: &mut Compiler,
     lit: &NumericLiteral<'_>,
@@ -291,3 +343,20 @@ pub(crate) fn compile_boolean_literal_into(
     );
     Ok(destination)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::regexp_error_range;
+
+    #[test]
+    fn regexp_error_range_maps_code_units_to_source_bytes() {
+        // `x = /a€(/` — the literal starts at byte 4 and `€` is three bytes
+        // but a single UTF-16 unit, so unit offset 2 is the `(` at byte 9.
+        assert_eq!(regexp_error_range(4, (4, 11), "a€(", 2), (9, 10));
+        // An error detected at the end of the pattern points at the
+        // closing `/`.
+        assert_eq!(regexp_error_range(4, (4, 11), "a€(", 3), (10, 11));
+        // Unpositioned errors keep the whole literal.
+        assert_eq!(regexp_error_range(4, (4, 11), "a€(", usize::MAX), (4, 11));
+    }
+}
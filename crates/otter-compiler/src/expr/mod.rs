@@ -11,6 +11,7 @@
 //! - [`literal`] — literal expression lowering.
 //! - [`unary`] — unary and update expression lowering.
 //! - [`binary`] — binary, logical, and private-in lowering.
+//! - [`fold`] — compile-time folding of literal-operand operators.
 //! - [`member`] — member and private-field access lowering.
 //! - [`construct`] — `new` expression lowering.
 //! - [`object_array`] — object and array literal lowering.
@@ -28,6 +29,7 @@
 mod async_ops;
 mod binary;
 mod construct;
+mod fold;
 pub(crate) mod identifier;
 mod import_meta;
 mod jsx;
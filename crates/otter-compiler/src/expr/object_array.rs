@@ -60,17 +60,34 @@ pub(crate) fn compile_array_literal(
         cx.emit(Op::NewArray, operands, span);
         Ok(dst)
     } else {
-        // Spread path: materialise an empty array, then
+        // Push path: materialise an empty array, then
         // append each element (or each iterator step for
-        // spread elements). Slightly less efficient than
-        // the dense `NewArray` form, but only paid for
-        // literals that actually contain `...`.
+        // spread elements). Every non-spread element is
+        // statically known, so pre-size dense storage for
+        // them with `NewArrayWithCapacity`; the `ArrayPush`
+        // fill sequence then only regrows for spread steps.
+        let known_elements = arr
+            .elements
+            .iter()
+            .filter(|el| !matches!(el, oxc_ast::ast::ArrayExpressionElement::SpreadElement(_)))
+            .count();
         let dst = cx.alloc_scratch();
-        cx.emit(
-            Op::NewArray,
-            [Operand::Register(dst), Operand::ConstIndex(0)],
-            span,
-        );
+        if known_elements == 0 {
+            cx.emit(
+                Op::NewArray,
+                [Operand::Register(dst), Operand::ConstIndex(0)],
+                span,
+            );
+        } else {
+            cx.emit(
+                Op::NewArrayWithCapacity,
+                [
+                    Operand::Register(dst),
+                    Operand::ConstIndex(known_elements as u32),
+                ],
+                span,
+            );
+        }
         for el in &arr.elements {
             match el {
                 oxc_ast::ast::ArrayExpressionElement::SpreadElement(s) => {
@@ -112,8 +129,29 @@ pub(crate) fn compile_object_literal(
 ) -> Result<u16, CompileError> {
     let _ = span;
     let span = (obj.span.start, obj.span.end);
+    // Small literals keep the plain `NewObject` form: their properties fit
+    // the VM's inline slab, and `NewObject` has the inline JIT allocation
+    // fast path. Larger literals reserve slab storage for every statically
+    // known property so the fill sequence below never regrows it.
+    const PRESIZED_OBJECT_MIN_PROPERTIES: usize = 4;
+    let known_properties = obj
+        .properties
+        .iter()
+        .filter(|prop| matches!(prop, oxc_ast::ast::ObjectPropertyKind::ObjectProperty(_)))
+        .count();
     let dst = cx.alloc_scratch();
-    cx.emit(Op::NewObject, [Operand::Register(dst)], span);
+    if known_properties >= PRESIZED_OBJECT_MIN_PROPERTIES {
+        cx.emit(
+            Op::NewObjectWithCapacity,
+            [
+                Operand::Register(dst),
+                Operand::ConstIndex(known_properties as u32),
+            ],
+            span,
+        );
+    } else {
+        cx.emit(Op::NewObject, [Operand::Register(dst)], span);
+    }
 
     // §13.2.5.5 PropertyDefinitionEvaluation — concise method, getter
     // and setter definitions in an object literal receive
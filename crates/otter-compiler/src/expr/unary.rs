@@ -174,6 +174,29 @@ pub(crate) fn compile_unary(
         cx.emit(Op::LoadUndefined, [Operand::Register(dst)], span);
         return Ok(dst);
     }
+    // A literal operand's `typeof` is fixed by its syntax, and `!` of a
+    // provably-boolean operand is the opposite boolean; neither can reach
+    // user code, so both lower to a single constant load.
+    if matches!(u.operator, UnaryOperator::Typeof)
+        && let Some(type_name) = crate::expr::fold::typeof_literal(&u.argument)
+    {
+        let dst = cx.alloc_scratch();
+        let name_idx = cx.intern_string_constant(type_name);
+        cx.emit(
+            Op::LoadString,
+            [Operand::Register(dst), Operand::ConstIndex(name_idx)],
+            span,
+        );
+        return Ok(dst);
+    }
+    if matches!(u.operator, UnaryOperator::LogicalNot)
+        && let Some(value) = crate::expr::fold::constant_boolean(&u.argument)
+    {
+        let dst = cx.alloc_scratch();
+        return Ok(crate::expr::fold::emit_boolean_constant(
+            cx, !value, span, dst,
+        ));
+    }
     // §13.5.3 `typeof Identifier` — IsUnresolvableReference
     // returns `"undefined"` rather than throwing
     // ReferenceError. Re-route the global-fallback step to
@@ -53,19 +53,18 @@ pub(crate) fn compile_for_of_statement(
 
     let iterable_reg = compile_expr(cx, &s.right, span)?;
     let iter_reg = cx.alloc_scratch();
-    if is_for_await {
-        cx.emit(
-            Op::GetAsyncIterator,
-            [Operand::Register(iter_reg), Operand::Register(iterable_reg)],
-            span,
-        );
+    // `for await` records whether GetIterator(obj, async) fell back to
+    // the sync iterator; `None` for plain `for…of`.
+    let sync_flag_reg = if is_for_await {
+        Some(emit_get_async_iterator(cx, iter_reg, iterable_reg, span))
     } else {
         cx.emit(
             Op::GetIterator,
             [Operand::Register(iter_reg), Operand::Register(iterable_reg)],
             span,
         );
-    }
+        None
+    };
 
     let value_reg = cx.alloc_scratch();
     let done_reg = cx.alloc_scratch();
@@ -79,30 +78,36 @@ pub(crate) fn compile_for_of_statement(
     cx.push_loop_frame(LoopFrame::iteration());
     // §7.4.9 — register this iterator so abrupt completions (`break`,
     // labelled `continue`, `return`) that exit the loop emit
-    // IteratorClose at the jump site. Async iterators need
-    // AsyncIteratorClose (await on the result) which the synchronous
-    // close path cannot perform, so leave `for await` to the existing
-    // break-only handling below.
-    if !is_for_await && let Some(frame) = cx.loops.last_mut() {
+    // IteratorClose at the jump site. `for await` sites emit the
+    // awaited AsyncIteratorClose instead (see `emit_for_of_close`).
+    if let Some(frame) = cx.loops.last_mut() {
         frame.iterator_close_reg = Some(iter_reg);
+        frame.iterator_sync_flag_reg = sync_flag_reg;
     }
     // §7.4.9 — open the iterator's close region so a throw inside the
     // body runs its `[[return]]` during unwind (`IteratorCloseEnd` at
     // the loop exit closes the region on normal / `break` completion;
     // an exhausted iterator is already done and must not be re-closed).
     // `break` / `continue` / `return` close inline at the jump site, so
-    // this region only covers the dynamic throw-unwind path.
+    // this region only covers the dynamic throw-unwind path. `for await`
+    // opens the region per iteration instead, after the step, because a
+    // rejected `next()` must not close the iterator.
     if !is_for_await {
         cx.emit(Op::IteratorCloseStart, [Operand::Register(iter_reg)], span);
     }
     let loop_top = cx.next_pc();
+    if is_for_await {
+        cx.emit(Op::IteratorCloseEnd, [Operand::Register(iter_reg)], span);
+    }
     // §14.7.5.6 — materialise fresh per-iteration cells for a captured
     // `let`/`const` head before the next value binds, so each
     // iteration's closures capture distinct bindings.
     for &idx in &per_iter_upvalues {
         cx.emit(Op::FreshUpvalue, [Operand::Imm32(idx as i32)], span);
     }
-    if is_for_await {
+    let mut exit_jmps = Vec::new();
+    if let Some(sync_flag_reg) = sync_flag_reg {
+        let to_sync_step = cx.emit_branch_placeholder(Op::JumpIfTrue, Some(sync_flag_reg), span);
         let result_reg = cx.alloc_scratch();
         let awaited_reg = cx.alloc_scratch();
         let next_name = cx.intern_string_constant("next");
@@ -125,7 +130,32 @@ pub(crate) fn compile_for_of_statement(
             span,
         );
         cx.emit_load_property(done_reg, awaited_reg, "done", span);
+        exit_jmps.push(cx.emit_branch_placeholder(Op::JumpIfTrue, Some(done_reg), span));
         cx.emit_load_property(value_reg, awaited_reg, "value", span);
+        cx.emit(Op::IteratorCloseStart, [Operand::Register(iter_reg)], span);
+        let to_bind = cx.emit_branch_placeholder(Op::Jump, None, span);
+
+        // §27.1.6.4 AsyncFromSyncIteratorContinuation — a sync iterator
+        // steps synchronously and each value is awaited. A rejected value
+        // closes the sync iterator, so the region opens before the await.
+        cx.patch_branch_to_here(to_sync_step);
+        cx.emit(
+            Op::IteratorNext,
+            vec![
+                Operand::Register(value_reg),
+                Operand::Register(done_reg),
+                Operand::Register(iter_reg),
+            ],
+            span,
+        );
+        exit_jmps.push(cx.emit_branch_placeholder(Op::JumpIfTrue, Some(done_reg), span));
+        cx.emit(Op::IteratorCloseStart, [Operand::Register(iter_reg)], span);
+        cx.emit(
+            Op::Await,
+            [Operand::Register(value_reg), Operand::Register(value_reg)],
+            span,
+        );
+        cx.patch_branch_to_here(to_bind);
     } else {
         cx.emit(
             Op::IteratorNext,
@@ -137,11 +167,13 @@ pub(crate) fn compile_for_of_statement(
             span,
         );
     }
-    let exit_jmp = cx.emit_branch_placeholder(Op::JumpIfTrue, Some(done_reg), span);
+    if !is_for_await {
+        exit_jmps.push(cx.emit_branch_placeholder(Op::JumpIfTrue, Some(done_reg), span));
+    }
 
-    // §14.7.5.6 step `for await … of` — async iterators already
-    // produced an awaited result record above; ordinary `for-of`
-    // uses the synchronous `IteratorNext` value directly.
+    // §14.7.5.6 step `for await … of` — both branches above leave the
+    // awaited value in `value_reg`; ordinary `for-of` uses the
+    // synchronous `IteratorNext` value directly.
     // <https://tc39.es/ecma262/#sec-for-in-and-for-of-statements>
     let bind_source = value_reg;
 
@@ -170,28 +202,21 @@ pub(crate) fn compile_for_of_statement(
         cx.patch_branch(pc, loop_top);
     }
     // §14.7.5.6 ForIn/OfBodyEvaluation — a `break` is an abrupt
-    // completion that must run IteratorClose. For synchronous `for…of`
-    // the close is emitted at the `break` / labelled-`continue` /
-    // `return` site (see `compile_break_with_iterator_close` and
-    // friends), so the break target here just lands at the exit. The
-    // exhausted-iterator exit (`done` true) must NOT close. `for await`
-    // still closes at the target (its sites are not annotated, pending
-    // AsyncIteratorClose support).
-    let had_breaks = !frame.break_patches.is_empty();
+    // completion that must run IteratorClose. The close is emitted at
+    // the `break` / labelled-`continue` / `return` site (see
+    // `emit_for_of_close`), so the break target here just lands at the
+    // exit. The exhausted-iterator exit (`done` true) must NOT close.
     for pc in frame.break_patches {
         cx.patch_branch_to_here(pc);
     }
-    if is_for_await && had_breaks {
-        cx.emit(Op::IteratorClose, [Operand::Register(iter_reg)], span);
+    for pc in exit_jmps {
+        cx.patch_branch_to_here(pc);
     }
-    cx.patch_branch_to_here(exit_jmp);
     // Close the throw-unwind region: both the exhausted-iterator exit
     // and a `break` reach here. Removing the registration prevents an
     // already-finished (or inline-closed) iterator from being closed a
     // second time by a later throw further up the same frame.
-    if !is_for_await {
-        cx.emit(Op::IteratorCloseEnd, [Operand::Register(iter_reg)], span);
-    }
+    cx.emit(Op::IteratorCloseEnd, [Operand::Register(iter_reg)], span);
     // Close the head-binding scope opened for the per-iteration
     // `let`/`const` cell — leaving it pushed would leak the head name
     // into the enclosing scope's redeclaration checks.
@@ -201,6 +226,118 @@ pub(crate) fn compile_for_of_statement(
     Ok(completion_reg)
 }
 
+/// §7.4.3 GetIterator(obj, async) for `for await`: prefer
+/// `obj[@@asyncIterator]`, otherwise fall back to the sync iterator.
+///
+/// ```text
+///   method = obj[@@asyncIterator]
+///   sync = false
+///   JumpIfNullish method -> fallback
+///   iter = Call(method, obj)
+///   Jump -> done
+///   fallback:
+///   iter = GetIterator(obj)
+///   sync = true
+///   done:
+/// ```
+///
+/// Returns the register holding `sync`. Instead of materializing
+/// CreateAsyncFromSyncIterator, the loop steps a sync iterator with
+/// `IteratorNext` and awaits each value, which is the observable part of
+/// the wrapper.
+fn emit_get_async_iterator(
+    cx: &mut Compiler,
+    iter_reg: u16,
+    iterable_reg: u16,
+    span: (u32, u32),
+) -> u16 {
+    let sync_flag_reg = cx.alloc_scratch();
+    let sym_reg = cx.alloc_scratch();
+    let sym_name = cx.intern_string_constant("asyncIterator");
+    cx.emit(
+        Op::SymbolLoad,
+        [Operand::Register(sym_reg), Operand::ConstIndex(sym_name)],
+        span,
+    );
+    let method_reg = cx.alloc_scratch();
+    cx.emit(
+        Op::LoadElement,
+        vec![
+            Operand::Register(method_reg),
+            Operand::Register(iterable_reg),
+            Operand::Register(sym_reg),
+        ],
+        span,
+    );
+    cx.emit(Op::LoadFalse, [Operand::Register(sync_flag_reg)], span);
+    let to_fallback = cx.emit_branch_placeholder(Op::JumpIfNullish, Some(method_reg), span);
+    cx.emit(
+        Op::CallWithThis,
+        vec![
+            Operand::Register(iter_reg),
+            Operand::Register(method_reg),
+            Operand::Register(iterable_reg),
+            Operand::ConstIndex(0),
+        ],
+        span,
+    );
+    let to_done = cx.emit_branch_placeholder(Op::Jump, None, span);
+    cx.patch_branch_to_here(to_fallback);
+    cx.emit(
+        Op::GetIterator,
+        [Operand::Register(iter_reg), Operand::Register(iterable_reg)],
+        span,
+    );
+    cx.emit(Op::LoadTrue, [Operand::Register(sync_flag_reg)], span);
+    cx.patch_branch_to_here(to_done);
+    sync_flag_reg
+}
+
+/// Close a `for…of` iterator on an abrupt exit from the loop.
+///
+/// Plain `for…of` (`sync_flag_reg == None`) and `for await` over a sync
+/// fallback emit `IteratorClose`. A real async iterator gets §7.4.11
+/// AsyncIteratorClose: its region is dropped first (so a throwing
+/// `return` is not closed twice), then `return()` is called and its
+/// result awaited.
+pub(crate) fn emit_for_of_close(
+    cx: &mut Compiler,
+    iter_reg: u16,
+    sync_flag_reg: Option<u16>,
+    span: (u32, u32),
+) {
+    let Some(sync_flag_reg) = sync_flag_reg else {
+        cx.emit(Op::IteratorClose, [Operand::Register(iter_reg)], span);
+        return;
+    };
+    let to_sync_close = cx.emit_branch_placeholder(Op::JumpIfTrue, Some(sync_flag_reg), span);
+    cx.emit(Op::IteratorCloseEnd, [Operand::Register(iter_reg)], span);
+    let return_reg = cx.alloc_scratch();
+    cx.emit_load_property(return_reg, iter_reg, "return", span);
+    let to_no_return = cx.emit_branch_placeholder(Op::JumpIfNullish, Some(return_reg), span);
+    let result_reg = cx.alloc_scratch();
+    cx.emit(
+        Op::CallWithThis,
+        vec![
+            Operand::Register(result_reg),
+            Operand::Register(return_reg),
+            Operand::Register(iter_reg),
+            Operand::ConstIndex(0),
+        ],
+        span,
+    );
+    cx.emit(
+        Op::Await,
+        [Operand::Register(result_reg), Operand::Register(result_reg)],
+        span,
+    );
+    let to_done = cx.emit_branch_placeholder(Op::Jump, None, span);
+    cx.patch_branch_to_here(to_sync_close);
+    cx.emit(Op::IteratorClose, [Operand::Register(iter_reg)], span);
+    cx.patch_branch_to_here(to_no_return);
+    cx.patch_branch_to_here(to_done);
+}
+
 /// If `head` is a `for (let x of …)` / `for (const x of …)` single
 /// identifier binding, return `(name, is_const)`. Such heads route
 /// through a per-iteration upvalue cell (§14.7.5.6) that also provides
@@ -326,6 +326,9 @@ pub(crate) fn compile_function_full(
     slot.direct_eval_bindings = direct_eval_meta;
     slot.contains_direct_eval = contains_direct_eval;
     slot.number_hint_sites = child.number_hint_sites;
+    slot.optimization_hint = body
+        .as_deref()
+        .map_or(OptimizationHint::None, optimization_hint);
     let class_hint_sites = child.class_hint_sites;
     slot.code = child.code.finish();
     slot.spans = child.spans;
@@ -431,6 +434,22 @@ fn arrow_binds_arguments(arrow: &oxc_ast::ast::ArrowFunctionExpression<'_>) -> b
     names.iter().any(|name| name == "arguments")
 }
 
+/// Tiering hint named by a function body's directive prologue.
+///
+/// Matched on the raw directive text, like `"use strict"` (§11.2.1): an
+/// escaped spelling is an ordinary string and carries no hint. The first
+/// recognized pragma wins.
+fn optimization_hint(body: &oxc_ast::ast::FunctionBody<'_>) -> OptimizationHint {
+    body.directives
+        .iter()
+        .find_map(|directive| match directive.directive.as_str() {
+            "use optimize" => Some(OptimizationHint::ForceCompile),
+            "use noinline" => Some(OptimizationHint::NeverInline),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
 /// Compile an arrow function. Two body shapes share the same
 /// lowering:
 ///
@@ -441,8 +460,11 @@ fn arrow_binds_arguments(arrow: &oxc_ast::ast::ArrowFunctionExpression<'_>) -> b
 ///
 /// Captures from the enclosing scope flow through the same
 /// upvalue mechanism as nested function declarations — see
-/// [`capture`]. The arrow has no `this` of its own (foundation
-/// slice doesn't model `this` yet — task 23).
+/// [`capture`]. The arrow has no `this`, `arguments`, or
+/// `new.target` of its own (§10.2.11): `this` and `new.target` are
+/// snapshotted from the creating frame by `MakeClosure`, which is why
+/// arrows never lower to the capture-free `MakeFunction`, and
+/// `arguments` resolves through the ordinary upvalue chain.
 pub(crate) fn compile_arrow_function(
     parent: &mut Compiler,
     arrow: &oxc_ast::ast::ArrowFunctionExpression<'_>,
@@ -609,6 +631,7 @@ pub(crate) fn compile_arrow_function(
     slot.direct_eval_bindings = direct_eval_meta;
     slot.contains_direct_eval = contains_direct_eval;
     slot.number_hint_sites = child.number_hint_sites;
+    slot.optimization_hint = optimization_hint(&arrow.body);
     let class_hint_sites = child.class_hint_sites;
     slot.code = child.code.finish();
     slot.spans = child.spans;
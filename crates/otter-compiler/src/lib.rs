@@ -120,8 +120,8 @@ pub(crate) use with_statement::*;
 
 pub(crate) use otter_bytecode::{
     ArgumentBindingStorage, ArgumentsObjectKind, BytecodeModule, Constant, Function,
-    FunctionCodeBuilder, MappedArgumentBinding, Op, Operand, SourceKind as BytecodeSourceKind,
-    SpanEntry,
+    FunctionCodeBuilder, MappedArgumentBinding, Op, Operand, OptimizationHint,
+    SourceKind as BytecodeSourceKind, SpanEntry,
 };
 pub(crate) use otter_syntax::{
     SourceKind as SyntaxSourceKind, SyntaxDiagnostic, SyntaxError, with_program,
@@ -1024,10 +1024,51 @@ mod tests {
 
     #[test]
     fn strict_equals_compiles_to_eq() {
-        let module = compile_script_src("\"a\" === \"a\";");
+        let module = compile_script_src("let a = \"a\"; a === \"a\";");
         assert!(module.main().code.iter().any(|i| i.op == Op::Equal));
     }
 
+    #[test]
+    fn repeated_bigint_literals_share_one_constant() {
+        let module = compile_script_src(
+            "const a = 16n; function f() { return [16n, 0x10n, 1_6n]; } switch (a) { case 16n: case 0o20n: break; }",
+        );
+        let bigints: Vec<&str> = module
+            .constants
+            .iter()
+            .filter_map(|constant| match constant {
+                Constant::BigInt { decimal } => Some(decimal.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(bigints, ["16"]);
+    }
+
+    #[test]
+    fn typeof_literal_folds_to_type_string() {
+        let module = compile_script_src("typeof 5;");
+        assert!(!module.main().code.iter().any(|i| i.op == Op::TypeOf));
+        assert!(string_constants(&module).iter().any(|s| s == "number"));
+    }
+
+    #[test]
+    fn literal_comparison_folds_to_boolean() {
+        let module = compile_script_src("5 > 3;");
+        let ops: Vec<Op> = module.main().code.iter().map(|i| i.op).collect();
+        assert!(ops.contains(&Op::LoadTrue));
+        assert!(!ops.contains(&Op::GreaterThan));
+        let module = compile_script_src("!true;");
+        let ops: Vec<Op> = module.main().code.iter().map(|i| i.op).collect();
+        assert!(ops.contains(&Op::LoadFalse));
+        assert!(!ops.contains(&Op::LogicalNot));
+    }
+
+    #[test]
+    fn typeof_identifier_stays_a_runtime_op() {
+        let module = compile_script_src("typeof someIdentifier;");
+        assert!(module.main().code.iter().any(|i| i.op == Op::TypeOf));
+    }
+
     #[test]
     fn numeric_literal_smi_compiles_to_load_int32() {
         let module = compile_script_src("(42);");
@@ -1072,9 +1113,60 @@ mod tests {
         assert!(module.main().code.iter().any(|i| i.op == Op::LoadString));
     }
 
+    #[test]
+    fn large_literals_lower_to_presized_allocations() {
+        let elements = vec!["0"; 300].join(",");
+        let module = compile_script_src(&format!("[{elements}]; ({{ a: 1, b: 2, c: 3, d: 4 }});"));
+        let code = &module.main().code;
+        let array = code
+            .iter()
+            .find(|i| i.op == Op::NewArrayWithCapacity)
+            .expect("presized array allocation");
+        assert_eq!(array.operands[1], Operand::ConstIndex(300));
+        assert_eq!(code.iter().filter(|i| i.op == Op::ArrayPush).count(), 300);
+        let object = code
+            .iter()
+            .find(|i| i.op == Op::NewObjectWithCapacity)
+            .expect("presized object allocation");
+        assert_eq!(object.operands[1], Operand::ConstIndex(4));
+
+        let module = compile_script_src("[1, 2, 3]; ({ a: 1 });");
+        let ops: Vec<Op> = module.main().code.iter().map(|i| i.op).collect();
+        assert!(ops.contains(&Op::NewArray));
+        assert!(ops.contains(&Op::NewObject));
+        assert!(!ops.contains(&Op::NewArrayWithCapacity));
+        assert!(!ops.contains(&Op::NewObjectWithCapacity));
+    }
+
     #[test]
     fn duplicate_string_literals_share_constant() {
         let module = compile_script_src("(\"abc\"); (\"abc\");");
         assert_eq!(module.constants.len(), 1);
     }
+
+    #[test]
+    fn directive_pragmas_record_optimization_hints() {
+        let module = compile_script_src(
+            "function hot() { \"use optimize\"; return 1; }\n\
+             function huge() { \"use strict\"; \"use noinline\"; return 2; }\n\
+             const arrow = () => { \"use optimize\"; };\n\
+             function escaped() { \"use optimi\\x7ae\"; }\n\
+             function late() { f(); \"use optimize\"; }",
+        );
+        let hint = |name: &str| {
+            module
+                .functions
+                .iter()
+                .find(|function| function.name == name)
+                .unwrap_or_else(|| panic!("function `{name}` is compiled"))
+                .optimization_hint()
+        };
+        assert_eq!(hint("hot"), OptimizationHint::ForceCompile);
+        assert_eq!(hint("huge"), OptimizationHint::NeverInline);
+        assert_eq!(hint("<arrow>"), OptimizationHint::ForceCompile);
+        // Only the raw prologue spelling is a directive.
+        assert_eq!(hint("escaped"), OptimizationHint::None);
+        assert_eq!(hint("late"), OptimizationHint::None);
+        assert_eq!(module.main().optimization_hint(), OptimizationHint::None);
+    }
 }
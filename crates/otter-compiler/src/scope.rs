@@ -103,6 +103,10 @@ pub(crate) struct LoopFrame {
     /// completion (`break` / labelled `continue` / `return`) exits the
     /// loop. `None` for every other loop / switch frame.
     pub(crate) iterator_close_reg: Option<u16>,
+    /// For a `for await…of` frame, the register that is `true` when the
+    /// iterator is a sync fallback. Selects between IteratorClose and the
+    /// awaited AsyncIteratorClose at each exit site.
+    pub(crate) iterator_sync_flag_reg: Option<u16>,
     /// Runtime try-handler-stack depth in effect when this frame was
     /// entered. A `break`/`continue` targeting this frame must run
     /// every `finally` pushed since (handlers above this floor).
@@ -125,6 +129,7 @@ impl LoopFrame {
             label: None,
             is_real_loop: true,
             iterator_close_reg: None,
+            iterator_sync_flag_reg: None,
             handler_floor: 0,
             finally_floor: 0,
             finally_body_floor: 0,
@@ -138,6 +143,7 @@ impl LoopFrame {
             label: None,
             is_real_loop: false,
             iterator_close_reg: None,
+            iterator_sync_flag_reg: None,
             handler_floor: 0,
             finally_floor: 0,
             finally_body_floor: 0,
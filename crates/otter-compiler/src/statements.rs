@@ -654,13 +654,16 @@ pub(crate) fn compile_statement(
             // §7.4.9 — `break` exits every frame from the innermost up
             // to and including the target, so close each crossed
             // `for…of` iterator innermost-first before jumping.
-            let close_regs: Vec<u16> = cx.loops[target_idx..]
+            let close_regs: Vec<(u16, Option<u16>)> = cx.loops[target_idx..]
                 .iter()
                 .rev()
-                .filter_map(|f| f.iterator_close_reg)
+                .filter_map(|f| {
+                    f.iterator_close_reg
+                        .map(|reg| (reg, f.iterator_sync_flag_reg))
+                })
                 .collect();
-            for reg in close_regs {
-                cx.emit(Op::IteratorClose, [Operand::Register(reg)], span);
+            for (reg, sync_flag_reg) in close_regs {
+                emit_for_of_close(cx, reg, sync_flag_reg, span);
             }
             let pc = emit_loop_exit_jump(cx, target_idx, span);
             cx.loops[target_idx].break_patches.push(pc);
@@ -762,11 +765,14 @@ pub(crate) fn compile_statement(
             let span = (r.span.start, r.span.end);
             match &r.argument {
                 Some(arg) => {
-                    let close_regs: Vec<u16> = cx
+                    let close_regs: Vec<(u16, Option<u16>)> = cx
                         .loops
                         .iter()
                         .rev()
-                        .filter_map(|f| f.iterator_close_reg)
+                        .filter_map(|f| {
+                            f.iterator_close_reg
+                                .map(|reg| (reg, f.iterator_sync_flag_reg))
+                        })
                         .collect();
                     // §15.10.3 — a strict-mode `return <call>` with no
                     // pending `for…of` iterator close (which would have to
@@ -779,21 +785,24 @@ pub(crate) fn compile_statement(
                         // enclosing `for…of` iterator (§7.4.9) innermost-
                         // first before the abrupt return propagates.
                         let reg = compile_expr(cx, arg, span)?;
-                        for creg in close_regs {
-                            cx.emit(Op::IteratorClose, [Operand::Register(creg)], span);
+                        for (creg, sync_flag_reg) in close_regs {
+                            emit_for_of_close(cx, creg, sync_flag_reg, span);
                         }
                         cx.emit(Op::ReturnValue, [Operand::Register(reg)], span);
                     }
                 }
                 None => {
-                    let close_regs: Vec<u16> = cx
+                    let close_regs: Vec<(u16, Option<u16>)> = cx
                         .loops
                         .iter()
                         .rev()
-                        .filter_map(|f| f.iterator_close_reg)
+                        .filter_map(|f| {
+                            f.iterator_close_reg
+                                .map(|reg| (reg, f.iterator_sync_flag_reg))
+                        })
                         .collect();
-                    for creg in close_regs {
-                        cx.emit(Op::IteratorClose, [Operand::Register(creg)], span);
+                    for (creg, sync_flag_reg) in close_regs {
+                        emit_for_of_close(cx, creg, sync_flag_reg, span);
                     }
                     cx.emit(Op::ReturnUndefined, [], span);
                 }
@@ -1197,13 +1206,16 @@ pub(crate) fn compile_statement(
             // §7.4.9 — `continue` exits the frames inside the target
             // (the target loop itself re-iterates and is not closed),
             // so close each crossed `for…of` iterator innermost-first.
-            let close_regs: Vec<u16> = cx.loops[target_idx + 1..]
+            let close_regs: Vec<(u16, Option<u16>)> = cx.loops[target_idx + 1..]
                 .iter()
                 .rev()
-                .filter_map(|f| f.iterator_close_reg)
+                .filter_map(|f| {
+                    f.iterator_close_reg
+                        .map(|reg| (reg, f.iterator_sync_flag_reg))
+                })
                 .collect();
-            for reg in close_regs {
-                cx.emit(Op::IteratorClose, [Operand::Register(reg)], span);
+            for (reg, sync_flag_reg) in close_regs {
+                emit_for_of_close(cx, reg, sync_flag_reg, span);
             }
             let pc = emit_loop_exit_jump(cx, target_idx, span);
             cx.loops[target_idx].continue_patches.push(pc);
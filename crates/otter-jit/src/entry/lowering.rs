@@ -741,7 +741,9 @@ impl BaselinePlan {
                 | Op::NewPrivateName
                 | Op::ImportNamespace
                 | Op::ImportNamespaceDeferred
-                | Op::ModuleNamespaceObject => LoweredOperands::Constant(ConstantOperands {
+                | Op::ModuleNamespaceObject
+                | Op::NewObjectWithCapacity
+                | Op::NewArrayWithCapacity => LoweredOperands::Constant(ConstantOperands {
                     dst: reg(operands, 0)?,
                     constant: const_index(operands, 1)?,
                 }),
@@ -1367,6 +1367,15 @@ impl TemplatePlan {
                         arg2: 0,
                     }
                 }
+                Op::NewObjectWithCapacity | Op::NewArrayWithCapacity => {
+                    let operands = lowered.constant_operands()?;
+                    TemplateOp::ConstructOp {
+                        opcode: lowered.op as u8,
+                        arg0: u64::from(operands.dst),
+                        arg1: u64::from(operands.constant),
+                        arg2: 0,
+                    }
+                }
                 Op::NewBuiltinError | Op::NewCollection => {
                     let operands = lowered.global_store_operands()?;
                     TemplateOp::ConstructOp {
@@ -3,9 +3,44 @@
 //! The active slice provides an owned Rust `KvStore` and a static namespace spec
 //! for the hosted-module loader. File-backed stores persist a JSON object and
 //! enforce runtime read/write capabilities before opening or mutating paths.
+//!
+//! # Expiry
+//! `set(key, value, { ttlMs })` attaches a deadline measured on the monotonic
+//! clock, so wall-clock jumps never shorten or extend a TTL inside a process;
+//! `{ ttl }` is accepted as an equivalent spelling. A TTL of zero means "never
+//! expire", as for a plain `set`, and so does a TTL too long for the clock to
+//! represent. Expired keys read as absent immediately. The store is
+//! isolate-owned host data, so there is no reaper thread: store access reaps
+//! expired keys at most once per [`REAP_INTERVAL`], and
+//! [`KvStore::reap_expired`] forces a pass.
+//! File-backed stores persist deadlines as Unix milliseconds in a
+//! `<file>.ttl.json` sidecar when the capabilities cover it; otherwise TTLs
+//! are process-local.
+//!
+//! # Secondary indexes
+//! `createIndex(name, path)` maps the canonical JSON of the field at `path`
+//! (`$.a.b` or `a.b`; numeric segments index arrays) to the primary keys that
+//! hold it. Every mutation updates indexes in the same `&mut` call that edits
+//! `entries`, so a query never observes a half-applied write. Indexes are
+//! derived data: they are rebuilt from entries on creation and are not
+//! persisted with the backing file.
+//!
+//! # Conditional and batched writes
+//! [`KvStore::compare_and_swap`] and [`KvStore::batch`] apply their whole
+//! change to memory inside one `&mut` call and flush the backing file once,
+//! so no reader (in-memory or file-backed) sees part of a swap or batch. A
+//! compare-and-swap mismatch is `Ok(false)`, not an error. Values compare
+//! by canonical JSON, so `1` matches `1.0`.
+//!
+//! # Scans
+//! [`KvStore::scan_prefix`] and [`KvStore::scan_range`] walk only the
+//! matching span of the ordered key map instead of filtering every key, and
+//! return live keys in sorted order. Ranges are half-open `[start, end)`.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Bound;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use otter_runtime::CapabilitySet;
 use otter_runtime::{
@@ -34,19 +69,136 @@ pub enum KvError {
     /// Serialization error.
     #[error("serialization error: {0}")]
     Serialization(String),
+    /// Index definition or lookup error.
+    #[error("index error: {0}")]
+    Index(String),
+    /// Scan bounds were inverted.
+    #[error("invalid range: {0}")]
+    Range(String),
 }
 
 /// Result alias for `otter:kv`.
 pub type KvResult<T> = Result<T, KvError>;
 
+/// Minimum time between opportunistic reaping passes.
+pub const REAP_INTERVAL: Duration = Duration::from_secs(1);
+
+/// One write in a [`KvStore::batch`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum KvOp {
+    /// Store `value` under `key`, clearing any TTL.
+    Set {
+        /// Key to write.
+        key: String,
+        /// New value.
+        value: JsonValue,
+    },
+    /// Remove `key`.
+    Delete {
+        /// Key to remove.
+        key: String,
+    },
+}
+
 /// Permission-gated key/value store.
 #[derive(Debug, Clone)]
 pub struct KvStore {
     path: Option<PathBuf>,
+    ttl_path: Option<PathBuf>,
     entries: BTreeMap<String, JsonValue>,
+    expiries: BTreeMap<String, Instant>,
+    last_reap: Instant,
+    indexes: BTreeMap<String, KvIndex>,
     can_write: bool,
 }
 
+/// Secondary index from an extracted field value to primary keys.
+#[derive(Debug, Clone)]
+struct KvIndex {
+    path: Vec<String>,
+    /// Canonical JSON of the indexed value -> primary keys holding it.
+    postings: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl KvIndex {
+    fn index_key(&self, value: &JsonValue) -> Option<String> {
+        let mut current = value;
+        for segment in &self.path {
+            current = match current {
+                JsonValue::Object(map) => map.get(segment)?,
+                JsonValue::Array(items) => items.get(segment.parse::<usize>().ok()?)?,
+                _ => return None,
+            };
+        }
+        Some(canonical_json(current))
+    }
+
+    fn insert(&mut self, key: &str, value: &JsonValue) {
+        if let Some(index_key) = self.index_key(value) {
+            self.postings
+                .entry(index_key)
+                .or_default()
+                .insert(key.to_string());
+        }
+    }
+
+    fn remove(&mut self, key: &str, value: &JsonValue) {
+        let Some(index_key) = self.index_key(value) else {
+            return;
+        };
+        if let Some(keys) = self.postings.get_mut(&index_key) {
+            keys.remove(key);
+            if keys.is_empty() {
+                self.postings.remove(&index_key);
+            }
+        }
+    }
+}
+
+/// Index key for a JSON value. JS numbers arrive as floats, so integral
+/// numbers are rendered without a fraction to match Rust-side integers.
+fn canonical_json(value: &JsonValue) -> String {
+    fn normalize(value: &JsonValue) -> JsonValue {
+        match value {
+            JsonValue::Number(number) => number
+                .as_f64()
+                .filter(|n| n.fract() == 0.0 && n.abs() < 9_007_199_254_740_992.0)
+                .map_or_else(|| value.clone(), |n| JsonValue::from(n as i64)),
+            JsonValue::Array(items) => JsonValue::Array(items.iter().map(normalize).collect()),
+            JsonValue::Object(map) => JsonValue::Object(
+                map.iter()
+                    .map(|(key, value)| (key.clone(), normalize(value)))
+                    .collect(),
+            ),
+            _ => value.clone(),
+        }
+    }
+    normalize(value).to_string()
+}
+
+/// Split `$.a.b` / `a.b` into path segments.
+fn parse_index_path(path: &str) -> KvResult<Vec<String>> {
+    let trimmed = path.strip_prefix('$').unwrap_or(path);
+    let trimmed = trimmed.strip_prefix('.').unwrap_or(trimmed);
+    if trimmed.is_empty() {
+        return Err(KvError::Index(format!(
+            "index path `{path}` names no field"
+        )));
+    }
+    trimmed
+        .split('.')
+        .map(|segment| {
+            if segment.is_empty() {
+                Err(KvError::Index(format!(
+                    "index path `{path}` has an empty segment"
+                )))
+            } else {
+                Ok(segment.to_string())
+            }
+        })
+        .collect()
+}
+
 impl otter_runtime::RuntimeHostObjectData for KvStore {}
 
 impl KvStore {
@@ -55,7 +207,11 @@ impl KvStore {
     pub fn memory() -> Self {
         Self {
             path: None,
+            ttl_path: None,
             entries: BTreeMap::new(),
+            expiries: BTreeMap::new(),
+            last_reap: Instant::now(),
+            indexes: BTreeMap::new(),
             can_write: true,
         }
     }
@@ -78,63 +234,310 @@ impl KvStore {
         } else {
             BTreeMap::new()
         };
+        let ttl_path = ttl_sidecar_path(&path);
+        let ttl_path = (capabilities.read.matches_path(&ttl_path)
+            && capabilities.write.matches_path(&ttl_path))
+        .then_some(ttl_path);
+        let expiries = match &ttl_path {
+            Some(ttl_path) if ttl_path.exists() => read_expiries(ttl_path)?,
+            _ => BTreeMap::new(),
+        };
         Ok(Self {
             path: Some(path),
+            ttl_path,
             entries,
+            expiries,
+            last_reap: Instant::now(),
+            indexes: BTreeMap::new(),
             can_write: true,
         })
     }
 
-    /// Store a JSON value under `key`.
+    /// Store a JSON value under `key`, clearing any previous TTL.
     pub fn set(&mut self, key: impl Into<String>, value: JsonValue) -> KvResult<()> {
         self.require_write()?;
-        self.entries.insert(key.into(), value);
+        let key = key.into();
+        self.expiries.remove(&key);
+        self.put(key, value);
         self.flush()
     }
 
-    /// Return a cloned JSON value for `key`.
+    /// Store a JSON value under `key` that expires after `ttl`. A zero `ttl`,
+    /// or one too long for the monotonic clock to represent, never expires,
+    /// like [`Self::set`].
+    pub fn set_with_ttl(
+        &mut self,
+        key: impl Into<String>,
+        value: JsonValue,
+        ttl: Duration,
+    ) -> KvResult<()> {
+        let deadline = Instant::now().checked_add(ttl);
+        let Some(deadline) = deadline.filter(|_| !ttl.is_zero()) else {
+            return self.set(key, value);
+        };
+        self.require_write()?;
+        let key = key.into();
+        self.expiries.insert(key.clone(), deadline);
+        self.put(key, value);
+        self.flush()
+    }
+
+    /// Return a cloned JSON value for `key`; expired keys read as absent.
     #[must_use]
     pub fn get(&self, key: &str) -> Option<JsonValue> {
-        self.entries.get(key).cloned()
+        self.entries
+            .get(key)
+            .filter(|_| self.is_live(key, Instant::now()))
+            .cloned()
     }
 
-    /// Return whether `key` exists.
+    /// Return whether `key` exists and has not expired.
     #[must_use]
     pub fn has(&self, key: &str) -> bool {
-        self.entries.contains_key(key)
+        self.entries.contains_key(key) && self.is_live(key, Instant::now())
     }
 
-    /// Delete `key`, returning whether it existed.
+    /// Remaining lifetime of `key`, or `None` when it has no TTL or is absent.
+    #[must_use]
+    pub fn ttl(&self, key: &str) -> Option<Duration> {
+        let now = Instant::now();
+        self.expiries
+            .get(key)
+            .filter(|_| self.has(key))
+            .map(|deadline| deadline.saturating_duration_since(now))
+    }
+
+    /// Delete `key`, returning whether a live entry existed.
     pub fn delete(&mut self, key: &str) -> KvResult<bool> {
         self.require_write()?;
-        let existed = self.entries.remove(key).is_some();
+        let existed = self.has(key);
+        self.remove_entry(key);
         self.flush()?;
         Ok(existed)
     }
 
+    /// Replace the value of `key` only if its live value is `expected`
+    /// (`None`: the key is absent). `new` of `None` deletes the key; a stored
+    /// value loses its TTL. Returns `Ok(false)` without writing on mismatch.
+    pub fn compare_and_swap(
+        &mut self,
+        key: &str,
+        expected: Option<JsonValue>,
+        new: Option<JsonValue>,
+    ) -> KvResult<bool> {
+        self.require_write()?;
+        let current = self.get(key);
+        let matches = match (&current, &expected) {
+            (None, None) => true,
+            (Some(current), Some(expected)) => canonical_json(current) == canonical_json(expected),
+            _ => false,
+        };
+        if !matches {
+            return Ok(false);
+        }
+        self.apply(match new {
+            Some(value) => KvOp::Set {
+                key: key.to_string(),
+                value,
+            },
+            None => KvOp::Delete {
+                key: key.to_string(),
+            },
+        });
+        self.flush()?;
+        Ok(true)
+    }
+
+    /// Apply every op in order as one write with a single flush.
+    pub fn batch(&mut self, ops: Vec<KvOp>) -> KvResult<()> {
+        self.require_write()?;
+        for op in ops {
+            self.apply(op);
+        }
+        self.flush()
+    }
+
     /// Clear every key.
     pub fn clear(&mut self) -> KvResult<()> {
         self.require_write()?;
         self.entries.clear();
+        self.expiries.clear();
+        for index in self.indexes.values_mut() {
+            index.postings.clear();
+        }
         self.flush()
     }
 
-    /// Keys in deterministic order.
+    /// Live keys in deterministic order.
     #[must_use]
     pub fn keys(&self) -> Vec<String> {
-        self.entries.keys().cloned().collect()
+        let now = Instant::now();
+        self.entries
+            .keys()
+            .filter(|key| self.is_live(key, now))
+            .cloned()
+            .collect()
     }
 
-    /// Entry count.
+    /// Live keys starting with `prefix`, in sorted order. An empty prefix
+    /// lists every live key, like [`Self::keys`].
+    pub fn scan_prefix(&self, prefix: &str) -> KvResult<Vec<String>> {
+        let now = Instant::now();
+        Ok(self
+            .entries
+            .range::<str, _>((Bound::Included(prefix), Bound::Unbounded))
+            .map(|(key, _)| key)
+            .take_while(|key| key.starts_with(prefix))
+            .filter(|key| self.is_live(key, now))
+            .cloned()
+            .collect())
+    }
+
+    /// Live keys in the half-open range `[start, end)`, in sorted order.
+    ///
+    /// `start == end` is empty; `start > end` is an error.
+    pub fn scan_range(&self, start: &str, end: &str) -> KvResult<Vec<String>> {
+        if start > end {
+            return Err(KvError::Range(format!(
+                "start `{start}` sorts after end `{end}`"
+            )));
+        }
+        let now = Instant::now();
+        Ok(self
+            .entries
+            .range::<str, _>((Bound::Included(start), Bound::Excluded(end)))
+            .map(|(key, _)| key)
+            .filter(|key| self.is_live(key, now))
+            .cloned()
+            .collect())
+    }
+
+    /// Live entry count.
     #[must_use]
     pub fn len(&self) -> usize {
-        self.entries.len()
+        let now = Instant::now();
+        self.entries
+            .keys()
+            .filter(|key| self.is_live(key, now))
+            .count()
     }
 
-    /// Whether the store is empty.
+    /// Whether the store has no live entries.
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.entries.is_empty()
+        self.len() == 0
+    }
+
+    /// Remove expired entries from memory and storage, returning how many
+    /// were purged.
+    pub fn reap_expired(&mut self) -> KvResult<usize> {
+        let now = Instant::now();
+        self.last_reap = now;
+        let expired = self
+            .expiries
+            .iter()
+            .filter(|(_, deadline)| **deadline <= now)
+            .map(|(key, _)| key.clone())
+            .collect::<Vec<_>>();
+        if expired.is_empty() {
+            return Ok(0);
+        }
+        for key in &expired {
+            self.remove_entry(key);
+        }
+        if self.can_write {
+            self.flush()?;
+        }
+        Ok(expired.len())
+    }
+
+    /// Reap when [`REAP_INTERVAL`] has passed since the last pass.
+    pub fn maybe_reap(&mut self) -> KvResult<usize> {
+        if self.last_reap.elapsed() < REAP_INTERVAL {
+            return Ok(0);
+        }
+        self.reap_expired()
+    }
+
+    /// Create (or rebuild) a secondary index over the field at `path`.
+    ///
+    /// Re-creating an index with the same path is a no-op; a different path
+    /// under an existing name is an error.
+    pub fn create_index(&mut self, name: impl Into<String>, path: &str) -> KvResult<()> {
+        let name = name.into();
+        let segments = parse_index_path(path)?;
+        if let Some(existing) = self.indexes.get(&name) {
+            if existing.path == segments {
+                return Ok(());
+            }
+            return Err(KvError::Index(format!(
+                "index `{name}` already exists with a different path"
+            )));
+        }
+        let mut index = KvIndex {
+            path: segments,
+            postings: BTreeMap::new(),
+        };
+        for (key, value) in &self.entries {
+            index.insert(key, value);
+        }
+        self.indexes.insert(name, index);
+        Ok(())
+    }
+
+    /// Live entries whose indexed field equals `value`, in key order.
+    pub fn by_index(&self, name: &str, value: &JsonValue) -> KvResult<Vec<(String, JsonValue)>> {
+        let index = self
+            .indexes
+            .get(name)
+            .ok_or_else(|| KvError::Index(format!("unknown index `{name}`")))?;
+        let now = Instant::now();
+        Ok(index
+            .postings
+            .get(&canonical_json(value))
+            .into_iter()
+            .flatten()
+            .filter(|key| self.is_live(key, now))
+            .filter_map(|key| Some((key.clone(), self.entries.get(key)?.clone())))
+            .collect())
+    }
+
+    /// Apply one write to memory; callers flush.
+    fn apply(&mut self, op: KvOp) {
+        match op {
+            KvOp::Set { key, value } => {
+                self.expiries.remove(&key);
+                self.put(key, value);
+            }
+            KvOp::Delete { key } => self.remove_entry(&key),
+        }
+    }
+
+    /// Insert an entry and keep every index in step with it.
+    fn put(&mut self, key: String, value: JsonValue) {
+        for index in self.indexes.values_mut() {
+            if let Some(previous) = self.entries.get(&key) {
+                index.remove(&key, previous);
+            }
+            index.insert(&key, &value);
+        }
+        self.entries.insert(key, value);
+    }
+
+    /// Remove an entry, its expiry, and its index postings.
+    fn remove_entry(&mut self, key: &str) {
+        self.expiries.remove(key);
+        if let Some(previous) = self.entries.remove(key) {
+            for index in self.indexes.values_mut() {
+                index.remove(key, &previous);
+            }
+        }
+    }
+
+    fn is_live(&self, key: &str, now: Instant) -> bool {
+        self.expiries
+            .get(key)
+            .is_none_or(|deadline| *deadline > now)
     }
 
     fn require_write(&self) -> KvResult<()> {
@@ -158,10 +561,64 @@ impl KvStore {
         }
         let text = serde_json::to_string_pretty(&self.entries)
             .map_err(|err| KvError::Serialization(err.to_string()))?;
-        std::fs::write(path, text).map_err(|err| KvError::Io(err.to_string()))
+        std::fs::write(path, text).map_err(|err| KvError::Io(err.to_string()))?;
+        let Some(ttl_path) = &self.ttl_path else {
+            return Ok(());
+        };
+        if self.expiries.is_empty() {
+            return match std::fs::remove_file(ttl_path) {
+                Err(err) if err.kind() != std::io::ErrorKind::NotFound => {
+                    Err(KvError::Io(err.to_string()))
+                }
+                _ => Ok(()),
+            };
+        }
+        let now = Instant::now();
+        let wall_now = unix_millis(SystemTime::now());
+        let deadlines = self
+            .expiries
+            .iter()
+            .map(|(key, deadline)| {
+                let remaining = deadline.saturating_duration_since(now).as_millis();
+                (key.clone(), wall_now.saturating_add(remaining))
+            })
+            .collect::<BTreeMap<_, _>>();
+        let text = serde_json::to_string_pretty(&deadlines)
+            .map_err(|err| KvError::Serialization(err.to_string()))?;
+        std::fs::write(ttl_path, text).map_err(|err| KvError::Io(err.to_string()))
     }
 }
 
+fn ttl_sidecar_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".ttl.json");
+    PathBuf::from(name)
+}
+
+fn unix_millis(time: SystemTime) -> u128 {
+    time.duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_millis())
+}
+
+/// Load persisted Unix-millisecond deadlines as monotonic instants. A
+/// deadline past what the monotonic clock can represent is dropped, so the
+/// key never expires, matching [`KvStore::set_with_ttl`].
+fn read_expiries(path: &Path) -> KvResult<BTreeMap<String, Instant>> {
+    let text = std::fs::read_to_string(path).map_err(|err| KvError::Io(err.to_string()))?;
+    let deadlines: BTreeMap<String, u64> =
+        serde_json::from_str(&text).map_err(|err| KvError::Serialization(err.to_string()))?;
+    let now = Instant::now();
+    let wall_now = unix_millis(SystemTime::now());
+    Ok(deadlines
+        .into_iter()
+        .filter_map(|(key, deadline)| {
+            let remaining =
+                u64::try_from(u128::from(deadline).saturating_sub(wall_now)).unwrap_or(u64::MAX);
+            Some((key, now.checked_add(Duration::from_millis(remaining))?))
+        })
+        .collect())
+}
+
 fn json_object_to_map(value: JsonValue) -> KvResult<BTreeMap<String, JsonValue>> {
     match value {
         JsonValue::Object(map) => Ok(map.into_iter().collect()),
@@ -204,12 +661,17 @@ fn build_store_object<'scope>(
     let object = scope.host_object(store)?;
     let attrs = Attr::builtin_function().to_flags();
     for (name, length, call) in [
-        ("set", 2, method_set as otter_runtime::RuntimeNativeFastFn),
+        ("set", 3, method_set as otter_runtime::RuntimeNativeFastFn),
         ("get", 1, method_get),
         ("has", 1, method_has),
         ("delete", 1, method_delete),
         ("keys", 0, method_keys),
+        ("list", 1, method_list),
         ("clear", 0, method_clear),
+        ("createIndex", 2, method_create_index),
+        ("byIndex", 2, method_by_index),
+        ("cas", 3, method_cas),
+        ("batch", 1, method_batch),
     ] {
         let method = scope.native_method(name, length, call)?;
         scope.define(object, name, method, attrs)?;
@@ -228,21 +690,134 @@ fn host_error(name: &'static str, err: RuntimeHostObjectError) -> NativeError {
 fn method_set(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
     let object = store_receiver(ctx, "KvStore.set")?;
     let key = crate::arg_string(args, 0, "KvStore.set", ctx.heap())?;
-    let value = args
-        .get(1)
-        .map(|v| crate::value_to_json(v, ctx.heap()))
-        .transpose()?
-        .unwrap_or(JsonValue::Null);
-    let result =
-        runtime_with_host_data_mut::<KvStore, _>(ctx, object, |store| store.set(key, value))
-            .map_err(|err| host_error("KvStore.set", err))?;
+    let value = js_to_json(ctx, args.get(1).copied(), "KvStore.set")?;
+    let ttl = ttl_option(ctx, args.get(2).copied())?;
+    let result = runtime_with_host_data_mut::<KvStore, _>(ctx, object, |store| {
+        store.maybe_reap()?;
+        match ttl {
+            Some(ttl) => store.set_with_ttl(key, value, ttl),
+            None => store.set(key, value),
+        }
+    })
+    .map_err(|err| host_error("KvStore.set", err))?;
     result.map_err(|err| crate::type_error("KvStore.set", err.to_string()))?;
     Ok(Value::undefined())
 }
 
+/// Nesting limit for structured values crossing the JS boundary.
+const MAX_JSON_DEPTH: usize = 64;
+
+/// Convert a JS value to JSON, walking plain objects and arrays so stored
+/// blobs keep their structure (and stay indexable).
+fn js_to_json(
+    ctx: &mut NativeCtx<'_>,
+    value: Option<Value>,
+    name: &'static str,
+) -> Result<JsonValue, NativeError> {
+    let Some(value) = value else {
+        return Ok(JsonValue::Null);
+    };
+    if !value.is_object_type() {
+        return crate::value_to_json(&value, ctx.heap());
+    }
+    ctx.scope(|mut scope| {
+        let value = scope.value(value);
+        scoped_js_to_json(&mut scope, value, name, 0)
+    })
+}
+
+fn scoped_js_to_json(
+    scope: &mut NativeScope<'_, '_>,
+    value: Local<'_>,
+    name: &'static str,
+    depth: usize,
+) -> Result<JsonValue, NativeError> {
+    if scope.is_undefined(value) || scope.is_null(value) {
+        return Ok(JsonValue::Null);
+    }
+    if scope.is_string(value) {
+        return scope.string_value(value).map(JsonValue::String);
+    }
+    if let Ok(boolean) = scope.boolean_value(value) {
+        return Ok(JsonValue::Bool(boolean));
+    }
+    if let Ok(number) = scope.number_value(value) {
+        return serde_json::Number::from_f64(number)
+            .map(JsonValue::Number)
+            .ok_or_else(|| crate::type_error(name, "number is not finite JSON"));
+    }
+    if !scope.is_object(value) || scope.is_callable(value) {
+        return Err(crate::type_error(
+            name,
+            "value must be JSON-compatible (null, boolean, number, string, array or object)",
+        ));
+    }
+    if depth >= MAX_JSON_DEPTH {
+        return Err(crate::type_error(name, "value nests too deeply"));
+    }
+    if scope.is_array(value)? {
+        let length = scope.array_length(value)?;
+        let mut items = Vec::with_capacity(length);
+        for index in 0..length {
+            let item = scope.index(value, index)?;
+            items.push(scoped_js_to_json(scope, item, name, depth + 1)?);
+        }
+        return Ok(JsonValue::Array(items));
+    }
+    let mut map = serde_json::Map::new();
+    for key in scope.enumerable_own_string_keys(value)? {
+        let field = scope.get(value, &key)?;
+        if scope.is_undefined(field) {
+            continue;
+        }
+        let field = scoped_js_to_json(scope, field, name, depth + 1)?;
+        map.insert(key, field);
+    }
+    Ok(JsonValue::Object(map))
+}
+
+/// Read `{ ttlMs }` or `{ ttl }`, in milliseconds, from `set`'s optional
+/// third argument. Zero means no expiry; a TTL longer than [`Duration`] can
+/// hold saturates to [`Duration::MAX`], which never expires either.
+fn ttl_option(
+    ctx: &mut NativeCtx<'_>,
+    options: Option<Value>,
+) -> Result<Option<Duration>, NativeError> {
+    let Some(options) = options.filter(|options| !options.is_nullish()) else {
+        return Ok(None);
+    };
+    let ttl = ctx.scope(|mut scope| {
+        let options = scope.value(options);
+        let mut ttl = scope.get(options, "ttlMs")?;
+        if scope.is_undefined(ttl) {
+            ttl = scope.get(options, "ttl")?;
+        }
+        if scope.is_undefined(ttl) || scope.is_null(ttl) {
+            return Ok(None);
+        }
+        scope.number_value(ttl).map(Some)
+    })?;
+    match ttl {
+        None => Ok(None),
+        Some(ms) if ms.is_finite() && ms >= 0.0 => Ok(Some(
+            Duration::try_from_secs_f64(ms / 1000.0).unwrap_or(Duration::MAX),
+        )),
+        Some(_) => Err(crate::type_error(
+            "KvStore.set",
+            "ttl must be a non-negative number of milliseconds",
+        )),
+    }
+}
+
+/// Opportunistic reaping for read paths; failures surface on the next write.
+fn reap_on_access(ctx: &mut NativeCtx<'_>, object: JsObject) {
+    let _ = runtime_with_host_data_mut::<KvStore, _>(ctx, object, KvStore::maybe_reap);
+}
+
 fn method_get(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
     let object = store_receiver(ctx, "KvStore.get")?;
     let key = crate::arg_string(args, 0, "KvStore.get", ctx.heap())?;
+    reap_on_access(ctx, object);
     let value = runtime_with_host_data::<KvStore, _>(ctx, object, |store| {
         store.get(&key).unwrap_or(JsonValue::Null)
     })
@@ -253,6 +828,7 @@ fn method_get(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeEr
 fn method_has(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
     let object = store_receiver(ctx, "KvStore.has")?;
     let key = crate::arg_string(args, 0, "KvStore.has", ctx.heap())?;
+    reap_on_access(ctx, object);
     let has = runtime_with_host_data::<KvStore, _>(ctx, object, |store| store.has(&key))
         .map_err(|err| host_error("KvStore.has", err))?;
     Ok(Value::boolean(has))
@@ -269,8 +845,69 @@ fn method_delete(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, Nativ
 
 fn method_keys(ctx: &mut NativeCtx<'_>, _args: &[Value]) -> Result<Value, NativeError> {
     let object = store_receiver(ctx, "KvStore.keys")?;
+    reap_on_access(ctx, object);
     let keys = runtime_with_host_data::<KvStore, _>(ctx, object, KvStore::keys)
         .map_err(|err| host_error("KvStore.keys", err))?;
+    keys_to_array(ctx, &keys)
+}
+
+/// `list({ prefix })` / `list({ start, end })`; no options lists every key.
+fn method_list(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
+    const NAME: &str = "KvStore.list";
+    let object = store_receiver(ctx, NAME)?;
+    let scan = list_options(ctx, args.first().copied())?;
+    reap_on_access(ctx, object);
+    let keys = runtime_with_host_data::<KvStore, _>(ctx, object, |store| match &scan {
+        KeyScan::Prefix(prefix) => store.scan_prefix(prefix),
+        KeyScan::Range { start, end } => store.scan_range(start, end),
+    })
+    .map_err(|err| host_error(NAME, err))?
+    .map_err(|err| crate::type_error(NAME, err.to_string()))?;
+    keys_to_array(ctx, &keys)
+}
+
+/// Key selection parsed from `list`'s options.
+enum KeyScan {
+    Prefix(String),
+    Range { start: String, end: String },
+}
+
+fn list_options(ctx: &mut NativeCtx<'_>, options: Option<Value>) -> Result<KeyScan, NativeError> {
+    const NAME: &str = "KvStore.list";
+    let Some(options) = options.filter(|options| !options.is_nullish()) else {
+        return Ok(KeyScan::Prefix(String::new()));
+    };
+    ctx.scope(|mut scope| {
+        let options = scope.value(options);
+        if !scope.is_object(options) {
+            return Err(crate::type_error(NAME, "options must be an object"));
+        }
+        let mut field = |name: &str| -> Result<Option<String>, NativeError> {
+            let value = scope.get(options, name)?;
+            if scope.is_undefined(value) {
+                Ok(None)
+            } else if scope.is_string(value) {
+                scope.string_value(value).map(Some)
+            } else {
+                Err(crate::type_error(NAME, format!("{name} must be a string")))
+            }
+        };
+        let prefix = field("prefix")?;
+        let start = field("start")?;
+        let end = field("end")?;
+        match (prefix, start, end) {
+            (prefix, None, None) => Ok(KeyScan::Prefix(prefix.unwrap_or_default())),
+            (None, Some(start), Some(end)) => Ok(KeyScan::Range { start, end }),
+            (Some(_), _, _) => Err(crate::type_error(
+                NAME,
+                "prefix cannot be combined with start/end",
+            )),
+            (None, _, _) => Err(crate::type_error(NAME, "a range needs both start and end")),
+        }
+    })
+}
+
+fn keys_to_array(ctx: &mut NativeCtx<'_>, keys: &[String]) -> Result<Value, NativeError> {
     // Each key string and the backing array are separate allocations. Collecting
     // the strings into a `Vec` first left every earlier `JsString` unrooted
     // across the later allocations; fill the array through the scope instead so
@@ -292,3 +929,122 @@ fn method_clear(ctx: &mut NativeCtx<'_>, _args: &[Value]) -> Result<Value, Nativ
     result.map_err(|err| crate::type_error("KvStore.clear", err.to_string()))?;
     Ok(Value::undefined())
 }
+
+fn method_create_index(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
+    let object = store_receiver(ctx, "KvStore.createIndex")?;
+    let name = crate::arg_string(args, 0, "KvStore.createIndex", ctx.heap())?;
+    let path = crate::arg_string(args, 1, "KvStore.createIndex", ctx.heap())?;
+    let result = runtime_with_host_data_mut::<KvStore, _>(ctx, object, |store| {
+        store.create_index(name, &path)
+    })
+    .map_err(|err| host_error("KvStore.createIndex", err))?;
+    result.map_err(|err| crate::type_error("KvStore.createIndex", err.to_string()))?;
+    Ok(Value::undefined())
+}
+
+fn method_by_index(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
+    let object = store_receiver(ctx, "KvStore.byIndex")?;
+    let name = crate::arg_string(args, 0, "KvStore.byIndex", ctx.heap())?;
+    let value = js_to_json(ctx, args.get(1).copied(), "KvStore.byIndex")?;
+    reap_on_access(ctx, object);
+    let entries =
+        runtime_with_host_data::<KvStore, _>(ctx, object, |store| store.by_index(&name, &value))
+            .map_err(|err| host_error("KvStore.byIndex", err))?
+            .map_err(|err| crate::type_error("KvStore.byIndex", err.to_string()))?;
+    let rows = entries
+        .into_iter()
+        .map(|(key, value)| serde_json::json!({ "key": key, "value": value }))
+        .collect();
+    crate::json_to_value(ctx, JsonValue::Array(rows))
+}
+
+fn method_cas(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
+    let object = store_receiver(ctx, "KvStore.cas")?;
+    let key = crate::arg_string(args, 0, "KvStore.cas", ctx.heap())?;
+    let expected = optional_json(ctx, args.get(1).copied(), "KvStore.cas")?;
+    let new = optional_json(ctx, args.get(2).copied(), "KvStore.cas")?;
+    reap_on_access(ctx, object);
+    let swapped = runtime_with_host_data_mut::<KvStore, _>(ctx, object, |store| {
+        store.compare_and_swap(&key, expected, new)
+    })
+    .map_err(|err| host_error("KvStore.cas", err))?
+    .map_err(|err| crate::type_error("KvStore.cas", err.to_string()))?;
+    Ok(Value::boolean(swapped))
+}
+
+/// `null` / `undefined` stand for an absent key.
+fn optional_json(
+    ctx: &mut NativeCtx<'_>,
+    value: Option<Value>,
+    name: &'static str,
+) -> Result<Option<JsonValue>, NativeError> {
+    match value.filter(|value| !value.is_nullish()) {
+        Some(value) => js_to_json(ctx, Some(value), name).map(Some),
+        None => Ok(None),
+    }
+}
+
+fn method_batch(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
+    let object = store_receiver(ctx, "KvStore.batch")?;
+    // Every op is validated before the store is touched, so a malformed
+    // entry rejects the whole batch.
+    let ops = batch_ops(ctx, args.first().copied())?;
+    let result = runtime_with_host_data_mut::<KvStore, _>(ctx, object, |store| store.batch(ops))
+        .map_err(|err| host_error("KvStore.batch", err))?;
+    result.map_err(|err| crate::type_error("KvStore.batch", err.to_string()))?;
+    Ok(Value::undefined())
+}
+
+/// Read `[{ type: "set", key, value } | { type: "delete", key }, ...]`.
+fn batch_ops(ctx: &mut NativeCtx<'_>, ops: Option<Value>) -> Result<Vec<KvOp>, NativeError> {
+    const NAME: &str = "KvStore.batch";
+    let Some(ops) = ops else {
+        return Err(crate::type_error(NAME, "expected an array of operations"));
+    };
+    ctx.scope(|mut scope| {
+        let ops = scope.value(ops);
+        if !scope.is_object(ops) || !scope.is_array(ops)? {
+            return Err(crate::type_error(NAME, "expected an array of operations"));
+        }
+        let length = scope.array_length(ops)?;
+        let mut parsed = Vec::with_capacity(length);
+        for index in 0..length {
+            let op = scope.index(ops, index)?;
+            if !scope.is_object(op) {
+                return Err(crate::type_error(
+                    NAME,
+                    format!("operation {index} must be an object"),
+                ));
+            }
+            let kind = scope.get(op, "type")?;
+            let kind = if scope.is_string(kind) {
+                scope.string_value(kind)?
+            } else {
+                String::new()
+            };
+            let key = scope.get(op, "key")?;
+            if !scope.is_string(key) {
+                return Err(crate::type_error(
+                    NAME,
+                    format!("operation {index} needs a string key"),
+                ));
+            }
+            let key = scope.string_value(key)?;
+            parsed.push(match kind.as_str() {
+                "set" => {
+                    let value = scope.get(op, "value")?;
+                    let value = scoped_js_to_json(&mut scope, value, NAME, 0)?;
+                    KvOp::Set { key, value }
+                }
+                "delete" => KvOp::Delete { key },
+                _ => {
+                    return Err(crate::type_error(
+                        NAME,
+                        format!("operation {index} type must be \"set\" or \"delete\""),
+                    ));
+                }
+            });
+        }
+        Ok(parsed)
+    })
+}
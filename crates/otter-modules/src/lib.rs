@@ -27,9 +27,10 @@ pub mod serve;
 pub mod sql;
 
 use otter_runtime::{
-    HostedModule, OtterBuilder, RuntimeBuilder, RuntimeNativeCtx as NativeCtx,
-    RuntimeNativeError as NativeError, RuntimeNumberValue as NumberValue, RuntimeValue as Value,
-    runtime_arg_to_string, runtime_string_value, runtime_type_error,
+    HostedModule, OtterBuilder, RuntimeBuilder, RuntimeLocal as Local,
+    RuntimeNativeCtx as NativeCtx, RuntimeNativeError as NativeError,
+    RuntimeNativeScope as NativeScope, RuntimeValue as Value, runtime_arg_to_string,
+    runtime_type_error,
 };
 use serde_json::{Number as JsonNumber, Value as JsonValue};
 
@@ -81,19 +82,43 @@ fn arg_string(
     Ok(runtime_arg_to_string(args, index, heap))
 }
 
-fn string_value(ctx: &mut NativeCtx<'_>, value: &str) -> Result<Value, NativeError> {
-    runtime_string_value(ctx, value)
+/// Build a JS value from JSON, materializing objects and arrays. Every node is
+/// parked in the scope as it is created, so later allocations cannot strand
+/// earlier siblings.
+fn json_to_value(ctx: &mut NativeCtx<'_>, value: JsonValue) -> Result<Value, NativeError> {
+    ctx.scope(|mut scope| {
+        let value = scoped_json_to_value(&mut scope, value)?;
+        Ok::<Value, NativeError>(scope.finish(value))
+    })
 }
 
-fn json_to_value(ctx: &mut NativeCtx<'_>, value: JsonValue) -> Result<Value, NativeError> {
+/// Scoped counterpart of [`json_to_value`] for callers already holding a
+/// scope (row objects, argument lists): the result is parked in scope `s`.
+fn scoped_json_to_value<'s>(
+    scope: &mut NativeScope<'s, '_>,
+    value: JsonValue,
+) -> Result<Local<'s>, NativeError> {
     match value {
-        JsonValue::Null => Ok(Value::null()),
-        JsonValue::Bool(value) => Ok(Value::boolean(value)),
-        JsonValue::Number(value) => Ok(Value::number(NumberValue::from_f64(
-            value.as_f64().unwrap_or(f64::NAN),
-        ))),
-        JsonValue::String(value) => string_value(ctx, &value),
-        other => string_value(ctx, &other.to_string()),
+        JsonValue::Null => Ok(scope.null()),
+        JsonValue::Bool(b) => Ok(scope.boolean(b)),
+        JsonValue::Number(n) => Ok(scope.number(n.as_f64().unwrap_or(f64::NAN))),
+        JsonValue::String(text) => scope.string(&text),
+        JsonValue::Array(items) => {
+            let array = scope.array(items.len())?;
+            for (index, item) in items.into_iter().enumerate() {
+                let item = scoped_json_to_value(scope, item)?;
+                scope.set_index(array, index, item)?;
+            }
+            Ok(array)
+        }
+        JsonValue::Object(map) => {
+            let object = scope.object()?;
+            for (key, field) in map {
+                let field = scoped_json_to_value(scope, field)?;
+                scope.set(object, &key, field)?;
+            }
+            Ok(object)
+        }
     }
 }
 
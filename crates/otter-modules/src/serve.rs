@@ -7,6 +7,8 @@
 //! - Hosted module registration for the bare `"otter"` specifier.
 //! - Global `Otter` namespace installer.
 //! - The `serve` native entry point.
+//! - `Router`, a method + path dispatcher whose `fetch` plugs into `serve`
+//!   (see [`router`]).
 //!
 //! # Invariants
 //! - Otter-specific APIs live in `otter-modules`, not in `otter-web` or
@@ -19,10 +21,10 @@
 //! - [`crate::hosted_modules`]
 
 mod body;
+mod router;
 
-use body::ServeBody;
-use bytes::Bytes;
-use http_body_util::{BodyExt, Full};
+use body::{ServeBody, ServeBodyChunk};
+use http_body_util::BodyExt;
 use hyper::body::Incoming;
 use hyper::server::conn::http1;
 use hyper::service::service_fn;
@@ -41,7 +43,12 @@ use std::sync::{
     Arc, Mutex,
     atomic::{AtomicBool, AtomicU64, Ordering},
 };
-use tokio::sync::{Notify, oneshot};
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::sync::{Notify, mpsc, oneshot};
+
+/// Response body chunks buffered between the isolate and a connection. A
+/// full channel pauses the `pumpBody` loop until the client reads.
+const BODY_CHANNEL_CAPACITY: usize = 8;
 
 /// Per-server table of in-flight replies keyed by a monotonic token. A request
 /// registers its oneshot sender, then the `deliver`/`deliverError` natives —
@@ -93,6 +100,13 @@ pub fn install_otter_module<'scope, 'rt>(
         serve_call,
         Attr::builtin_function().to_flags(),
     )?;
+    let router_call = scope.native_method("Router", 0, router::create_router)?;
+    scope.define(
+        namespace,
+        "Router",
+        router_call,
+        Attr::builtin_function().to_flags(),
+    )?;
     Ok(namespace)
 }
 
@@ -112,13 +126,20 @@ fn install_global_otter(runtime: &mut RuntimeExtensionContext<'_>) -> Result<(),
         1,
         RuntimeNativeCall::Dynamic(serve_call),
     )?;
+    runtime.install_native_global_call(
+        "__otterRouter",
+        0,
+        RuntimeNativeCall::Static(router::create_router),
+    )?;
     runtime
         .install_script(SourceInput::from_javascript(
             r#"
             (function (g) {
               'use strict';
               var serve = g.__otterServe;
+              var router = g.__otterRouter;
               delete g.__otterServe;
+              delete g.__otterRouter;
               var ns = g.Otter;
               if (ns == null || (typeof ns !== 'object' && typeof ns !== 'function')) {
                 ns = {};
@@ -129,6 +150,12 @@ fn install_global_otter(runtime: &mut RuntimeExtensionContext<'_>) -> Result<(),
                 enumerable: true,
                 configurable: true,
               });
+              Object.defineProperty(ns, 'Router', {
+                value: router,
+                writable: true,
+                enumerable: true,
+                configurable: true,
+              });
               Object.defineProperty(g, 'Otter', {
                 value: ns,
                 writable: true,
@@ -167,6 +194,44 @@ fn install_global_otter(runtime: &mut RuntimeExtensionContext<'_>) -> Result<(),
                       function (err) { deliverError(token, err); }
                     );
                   },
+                  // Streaming tail: drain a Response body stream into the
+                  // native connection one read at a time, so each chunk is
+                  // flushed as soon as the handler enqueues it. `push` returns
+                  // true when the chunk is queued and false once the client
+                  // has gone away. While the connection is backed up it
+                  // returns undefined and calls `resume(accepted)` after the
+                  // chunk is queued, so the stream is not read ahead of the
+                  // client.
+                  pumpBody: function (stream, push, finish) {
+                    var reader = stream.getReader();
+                    function fail(err) {
+                      finish(String(err));
+                    }
+                    function resume(accepted) {
+                      if (!accepted) {
+                        finish();
+                        reader.cancel().then(undefined, fail);
+                        return;
+                      }
+                      reader.read().then(step).then(undefined, fail);
+                    }
+                    function step(result) {
+                      if (result.done) {
+                        finish();
+                        return undefined;
+                      }
+                      var queued = push(result.value, resume);
+                      if (queued === false) {
+                        finish();
+                        return reader.cancel();
+                      }
+                      if (queued === undefined) {
+                        return undefined;
+                      }
+                      return reader.read().then(step);
+                    }
+                    reader.read().then(step).then(undefined, fail);
+                  },
                 }),
                 writable: false,
                 enumerable: false,
@@ -226,12 +291,17 @@ fn serve(
         smallvec::smallvec![],
     )?;
     let slots = ServeSlots::resolve(ctx, slots_value)?;
+    let pump_body = ctx.persistent_root_insert(options.fns.pump_body);
     let deliver = {
         let registry = registry.clone();
+        let pump = BodyPump {
+            trampoline: pump_body,
+            spawner: task_spawner.clone(),
+        };
         ctx.native_value(
             "serve.deliver",
             smallvec::smallvec![],
-            move |ctx, args, _captures| deliver_reply(ctx, &registry, slots, args),
+            move |ctx, args, _captures| deliver_reply(ctx, &registry, slots, &pump, args),
         )
         .map_err(|err| crate::type_error("serve", err.to_string()))?
     };
@@ -250,6 +320,7 @@ fn serve(
         async_deliver: ctx.persistent_root_insert(options.fns.async_deliver),
         deliver: ctx.persistent_root_insert(deliver),
         deliver_error: ctx.persistent_root_insert(deliver_error),
+        pump_body,
         slots,
     };
     let context = ctx
@@ -284,6 +355,7 @@ fn serve(
 struct ServeFns {
     fetch_slots: Value,
     async_deliver: Value,
+    pump_body: Value,
 }
 
 struct ServeOptions {
@@ -370,6 +442,15 @@ impl ServeSlots {
     }
 }
 
+/// What [`extract_response`] needs to stream a body: the `pumpBody`
+/// trampoline, and the spawner that resumes it once a backed-up connection
+/// drains.
+#[derive(Clone)]
+struct BodyPump {
+    trampoline: RuntimePersistentRootId,
+    spawner: RuntimeTaskSpawner,
+}
+
 #[derive(Clone, Copy)]
 struct ServeRoots {
     fetch: RuntimePersistentRootId,
@@ -377,6 +458,7 @@ struct ServeRoots {
     async_deliver: RuntimePersistentRootId,
     deliver: RuntimePersistentRootId,
     deliver_error: RuntimePersistentRootId,
+    pump_body: RuntimePersistentRootId,
     slots: ServeSlots,
 }
 
@@ -411,6 +493,7 @@ struct HttpResponse {
 
 struct ServeRequestTask {
     context: otter_runtime::RuntimeExecutionContext,
+    task_spawner: RuntimeTaskSpawner,
     roots: ServeRoots,
     request: HttpRequest,
     reply: oneshot::Sender<Result<HttpResponse, String>>,
@@ -421,11 +504,16 @@ impl RuntimeTask for ServeRequestTask {
     fn run(self: Box<Self>, runtime: &mut Runtime) -> Result<(), OtterError> {
         let ServeRequestTask {
             context,
+            task_spawner,
             roots,
             request,
             reply,
             registry,
         } = *self;
+        let pump = BodyPump {
+            trampoline: roots.pump_body,
+            spawner: task_spawner,
+        };
         // Park the reply under a token, then call the user handler directly.
         // A synchronous Response is extracted and settled inline; a thenable
         // result is handed to `asyncDeliver`, whose reaction settles the token
@@ -457,7 +545,7 @@ impl RuntimeTask for ServeRequestTask {
                     ],
                 )?;
             } else {
-                let response = extract_response(ctx, options.slots, outcome);
+                let response = extract_response(ctx, options.slots, &pump, outcome);
                 if let Some(reply) = registry_for_event.take(token) {
                     let _ = reply.send(response.map_err(|err| err.to_string()));
                 }
@@ -477,6 +565,7 @@ fn deliver_reply(
     ctx: &mut NativeCtx<'_>,
     registry: &ReplyRegistry,
     slots: ServeSlots,
+    pump: &BodyPump,
     args: &[Value],
 ) -> Result<Value, NativeError> {
     let Some(token) = token_arg(args) else {
@@ -486,7 +575,7 @@ fn deliver_reply(
     // headers, and body straight out of the private symbol slots in Rust — no
     // `responseParts` JS call, intermediate arrays, or header string.
     let response = args.get(1).copied().unwrap_or_else(Value::null);
-    let result = extract_response(ctx, slots, response);
+    let result = extract_response(ctx, slots, pump, response);
     if let Some(reply) = registry.take(token) {
         let _ = reply.send(result.map_err(|err| err.to_string()));
     }
@@ -612,7 +701,7 @@ async fn serve_one(
     roots: ServeRoots,
     registry: Arc<ReplyRegistry>,
     req: HyperRequest<Incoming>,
-) -> Result<HyperResponse<Full<Bytes>>, std::convert::Infallible> {
+) -> Result<HyperResponse<ServeBody>, std::convert::Infallible> {
     match dispatch_request(task_spawner, context, roots, registry, req).await {
         Ok(response) => Ok(build_hyper_response(response)),
         Err(err) => Ok(error_response(&err)),
@@ -632,6 +721,7 @@ async fn dispatch_request(
         .enqueue(
             ServeRequestTask {
                 context,
+                task_spawner: task_spawner.clone(),
                 roots,
                 request,
                 reply,
@@ -692,7 +782,7 @@ async fn read_hyper_request(req: HyperRequest<Incoming>) -> Result<HttpRequest,
     })
 }
 
-fn build_hyper_response(response: HttpResponse) -> HyperResponse<Full<Bytes>> {
+fn build_hyper_response(response: HttpResponse) -> HyperResponse<ServeBody> {
     let mut builder = HyperResponse::builder().status(response.status);
     let mut has_content_type = false;
     for (name, value) in &response.headers {
@@ -704,19 +794,18 @@ fn build_hyper_response(response: HttpResponse) -> HyperResponse<Full<Bytes>> {
     if !has_content_type {
         builder = builder.header("content-type", "text/plain;charset=UTF-8");
     }
-    let bytes = Bytes::from(response.body.as_buffered_bytes().to_vec());
     builder
-        .body(Full::new(bytes))
+        .body(response.body)
         .unwrap_or_else(|_| error_response("failed to build response"))
 }
 
-fn error_response(message: &str) -> HyperResponse<Full<Bytes>> {
+fn error_response(message: &str) -> HyperResponse<ServeBody> {
     HyperResponse::builder()
         .status(500)
         .header("content-type", "text/plain;charset=UTF-8")
-        .body(Full::new(Bytes::from(format!(
-            "Internal Server Error\n{message}"
-        ))))
+        .body(ServeBody::from_bytes(
+            format!("Internal Server Error\n{message}").into_bytes(),
+        ))
         .expect("static 500 response is always valid")
 }
 
@@ -779,6 +868,7 @@ fn server_stop(ctx: &mut NativeCtx<'_>, _args: &[Value]) -> Result<Value, Native
         let _ = ctx.persistent_root_remove(roots.async_deliver);
         let _ = ctx.persistent_root_remove(roots.deliver);
         let _ = ctx.persistent_root_remove(roots.deliver_error);
+        let _ = ctx.persistent_root_remove(roots.pump_body);
         roots.slots.remove(ctx);
     }
     Ok(Value::undefined())
@@ -842,6 +932,9 @@ fn parse_options(
     let async_deliver = object::get(internals_obj, ctx.heap(), "asyncDeliver")
         .filter(|value| value.is_callable())
         .ok_or_else(|| crate::type_error("serve", "missing async deliver trampoline"))?;
+    let pump_body = object::get(internals_obj, ctx.heap(), "pumpBody")
+        .filter(|value| value.is_callable())
+        .ok_or_else(|| crate::type_error("serve", "missing body pump trampoline"))?;
     Ok(ServeOptions {
         hostname,
         port,
@@ -850,6 +943,7 @@ fn parse_options(
         fns: ServeFns {
             fetch_slots,
             async_deliver,
+            pump_body,
         },
     })
 }
@@ -953,9 +1047,14 @@ fn make_request(
 /// once at `serve()` time. The HTTP/1.1 reason phrase is not observable to a
 /// Fetch client and hyper derives the canonical phrase from the status code, so
 /// `statusText` is intentionally not carried onto the wire.
+///
+/// A `ReadableStream` body (including the streams behind `Blob` and
+/// `FormData` bodies) is not buffered: the response is returned with a
+/// [`ServeBody::Stream`] and `pump` feeds it chunk by chunk.
 fn extract_response(
     ctx: &mut NativeCtx<'_>,
     slots: ServeSlots,
+    pump: &BodyPump,
     response: Value,
 ) -> Result<HttpResponse, NativeError> {
     let Some(obj) = response.as_object() else {
@@ -974,6 +1073,7 @@ fn extract_response(
     let header_list_sym = symbol(ctx, slots.header_list)?;
     let body_text_sym = symbol(ctx, slots.body_text)?;
     let body_bytes_sym = symbol(ctx, slots.body_bytes)?;
+    let body_stream_sym = symbol(ctx, slots.body_stream)?;
 
     let status = object::get_own_symbol(obj, ctx.heap(), status_sym)
         .and_then(|value| value.as_number())
@@ -1024,7 +1124,17 @@ fn extract_response(
             object::get_own_symbol(obj, ctx.heap(), body_bytes_sym).unwrap_or_else(Value::null)
         }
     };
-    let body = ServeBody::from_js_value(ctx, body_value)?;
+    let body = if body_value.is_null() || body_value.is_undefined() {
+        let stream =
+            object::get_own_symbol(obj, ctx.heap(), body_stream_sym).unwrap_or_else(Value::null);
+        if stream.is_null() || stream.is_undefined() {
+            ServeBody::Empty
+        } else {
+            start_body_pump(ctx, pump, stream)?
+        }
+    } else {
+        ServeBody::from_js_value(ctx, body_value)?
+    };
     Ok(HttpResponse {
         status,
         headers,
@@ -1032,6 +1142,152 @@ fn extract_response(
     })
 }
 
+/// Hand a `Response` body stream to the `pumpBody` trampoline and return the
+/// receiving end. The `push`/`finish` natives capture only the channel
+/// sender, so no VM value outlives this call in host state.
+///
+/// The channel holds [`BODY_CHANNEL_CAPACITY`] chunks. When it is full,
+/// `push` parks the chunk in an I/O task that waits for capacity and then
+/// calls the trampoline's `resume` callback through a [`BodyResumeTask`], so
+/// a slow client holds back the stream instead of growing a buffer.
+fn start_body_pump(
+    ctx: &mut NativeCtx<'_>,
+    pump: &BodyPump,
+    stream: Value,
+) -> Result<ServeBody, NativeError> {
+    let Some(context) = ctx.execution_context().cloned() else {
+        return Err(crate::type_error(
+            "serve.pumpBody",
+            "missing execution context",
+        ));
+    };
+    let io_handle = pump
+        .spawner
+        .io_handle()
+        .ok_or_else(|| crate::type_error("serve.pumpBody", "missing runtime event loop"))?;
+    let trampoline = ctx
+        .persistent_root_get(pump.trampoline)
+        .ok_or_else(|| crate::type_error("serve", "server pumpBody root is closed"))?;
+    let (tx, rx) = mpsc::channel::<ServeBodyChunk>(BODY_CHANNEL_CAPACITY);
+    let sender = Arc::new(Mutex::new(Some(tx)));
+    let push_sender = sender.clone();
+    let spawner = pump.spawner.clone();
+    let finish_handle = io_handle.clone();
+    ctx.scope(|mut scope| {
+        // Park the stream and trampoline before the closures allocate.
+        let trampoline = scope.value(trampoline);
+        let stream = scope.value(stream);
+        let push = scope.native_closure(
+            "serve.pushBodyChunk",
+            2,
+            &[],
+            move |ctx, args, _captures| {
+                let chunk = args.first().copied().unwrap_or_else(Value::undefined);
+                let bytes = ServeBody::from_js_value(ctx, chunk)?.into_buffered_bytes();
+                let Some(tx) = push_sender
+                    .lock()
+                    .expect("serve body sender poisoned")
+                    .clone()
+                else {
+                    return Ok(Value::boolean(false));
+                };
+                // A zero-length chunk would read as the chunked terminator.
+                if bytes.is_empty() {
+                    return Ok(Value::boolean(!tx.is_closed()));
+                }
+                let chunk = match tx.try_send(Ok(bytes.into())) {
+                    Ok(()) => return Ok(Value::boolean(true)),
+                    Err(TrySendError::Closed(_)) => return Ok(Value::boolean(false)),
+                    Err(TrySendError::Full(chunk)) => chunk,
+                };
+                let resume = args.get(1).copied().unwrap_or_else(Value::undefined);
+                if !resume.is_callable() {
+                    return Err(crate::type_error(
+                        "serve.pushBodyChunk",
+                        "resume must be a function",
+                    ));
+                }
+                let callback = ctx.persistent_root_insert(resume);
+                let hold = spawner.retain_keep_alive(RuntimeLiveness::Ref);
+                let spawner = spawner.clone();
+                let context = context.clone();
+                io_handle.spawn(async move {
+                    let accepted = tx.send(chunk).await.is_ok();
+                    let task = BodyResumeTask {
+                        context,
+                        callback,
+                        hold,
+                        accepted,
+                    };
+                    let _ = spawner.enqueue(task, RuntimeLiveness::Unref);
+                });
+                Ok(Value::undefined())
+            },
+        )?;
+        let finish =
+            scope.native_closure("serve.finishBody", 1, &[], move |ctx, args, _captures| {
+                let Some(tx) = sender.lock().expect("serve body sender poisoned").take() else {
+                    return Ok(Value::undefined());
+                };
+                // An error aborts the connection instead of writing the
+                // terminating chunk, so the client sees a truncated body.
+                if let Some(message) = args
+                    .first()
+                    .and_then(|value| value.as_string(ctx.heap()))
+                    .map(|value| value.to_lossy_string(ctx.heap()))
+                    && let Err(TrySendError::Full(chunk)) = tx.try_send(Err(message))
+                {
+                    finish_handle.spawn(async move {
+                        let _ = tx.send(chunk).await;
+                    });
+                }
+                Ok(Value::undefined())
+            })?;
+        let undefined = scope.undefined();
+        scope
+            .call(trampoline, undefined, &[stream, push, finish])
+            .map_err(|error| serve_reentry_error("serve.pumpBody", error))?;
+        Ok::<(), NativeError>(())
+    })?;
+    Ok(ServeBody::Stream(rx))
+}
+
+/// Resumes a `pumpBody` loop on the isolate thread once a chunk that found
+/// the body channel full has been queued (`accepted`), or the client has
+/// gone away.
+struct BodyResumeTask {
+    context: otter_runtime::RuntimeExecutionContext,
+    callback: RuntimePersistentRootId,
+    hold: RuntimeKeepAlive,
+    accepted: bool,
+}
+
+impl RuntimeTask for BodyResumeTask {
+    fn run(self: Box<Self>, runtime: &mut Runtime) -> Result<(), OtterError> {
+        let BodyResumeTask {
+            context,
+            callback,
+            hold,
+            accepted,
+        } = *self;
+        let result = runtime.run_native_event(&context, move |ctx| {
+            let Some(resume) = ctx.persistent_root_get(callback) else {
+                return Ok(Value::undefined());
+            };
+            let _ = ctx.persistent_root_remove(callback);
+            call_js(
+                ctx,
+                "serve.resumeBody",
+                resume,
+                Value::undefined(),
+                smallvec::smallvec![Value::boolean(accepted)],
+            )
+        });
+        hold.close();
+        result
+    }
+}
+
 fn call_js(
     ctx: &mut NativeCtx<'_>,
     name: &'static str,
@@ -6,28 +6,44 @@
 //!
 //! # Contents
 //! - [`ServeBody`] - request/response body payload passed across serve tasks.
+//!   It is also the hyper response body, so a streamed `Response` goes out
+//!   with `Transfer-Encoding: chunked` framing, one chunk per stream read.
 //!
 //! # Invariants
 //! - Body data crossing worker/runtime boundaries is owned and `Send`.
-//! - Native code does not store VM values inside body transport state.
+//! - Native code does not store VM values inside body transport state; a
+//!   streamed body crosses as owned byte chunks on a channel.
 //! - JS-visible request bodies are exposed as bytes, not lossy UTF-8 strings.
 //!
 //! # See also
 //! - [`crate::serve`]
 
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use hyper::body::{Body, Frame, SizeHint};
 use otter_runtime::{
     RuntimeNativeCtx as NativeCtx, RuntimeNativeError as NativeError, RuntimeValue as Value,
     runtime_type_error,
 };
+use tokio::sync::mpsc;
+
+/// One chunk of a streamed response body, or the error that aborted it.
+pub(crate) type ServeBodyChunk = Result<Bytes, String>;
 
 /// Owned HTTP body payload for server request/response dispatch.
-#[derive(Clone, Debug, Default)]
+#[derive(Debug, Default)]
 pub(crate) enum ServeBody {
     /// No body bytes are present.
     #[default]
     Empty,
     /// Fully buffered body bytes.
     Buffered(Vec<u8>),
+    /// Response body chunks produced by the isolate while the response is
+    /// already on the wire. The channel is bounded, so the producer waits
+    /// for the client. The stream ends when every sender is dropped.
+    Stream(mpsc::Receiver<ServeBodyChunk>),
 }
 
 impl ServeBody {
@@ -41,12 +57,12 @@ impl ServeBody {
         }
     }
 
-    /// Borrow the buffered request/response bytes.
+    /// Take the buffered request/response bytes. A streamed body has none.
     #[must_use]
-    pub(crate) fn as_buffered_bytes(&self) -> &[u8] {
+    pub(crate) fn into_buffered_bytes(self) -> Vec<u8> {
         match self {
-            Self::Empty => &[],
             Self::Buffered(bytes) => bytes,
+            Self::Empty | Self::Stream(_) => Vec::new(),
         }
     }
 
@@ -55,7 +71,7 @@ impl ServeBody {
     /// `null` when the request carried no body.
     pub(crate) fn to_js_body(&self, ctx: &mut NativeCtx<'_>) -> Result<Value, NativeError> {
         match self {
-            Self::Empty => Ok(Value::null()),
+            Self::Empty | Self::Stream(_) => Ok(Value::null()),
             Self::Buffered(bytes) => bytes_to_uint8_array(ctx, bytes.clone(), "serve.request"),
         }
     }
@@ -91,11 +107,49 @@ impl ServeBody {
         }
         Err(runtime_type_error(
             "serve",
-            "Response body streams are not supported yet; return a buffered Response body",
+            "Response body chunks must be strings or BufferSources",
         ))
     }
 }
 
+impl Body for ServeBody {
+    type Data = Bytes;
+    type Error = std::io::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Bytes>, std::io::Error>>> {
+        let this = self.get_mut();
+        match this {
+            Self::Empty => Poll::Ready(None),
+            Self::Buffered(bytes) => {
+                let bytes = Bytes::from(std::mem::take(bytes));
+                *this = Self::Empty;
+                Poll::Ready(Some(Ok(Frame::data(bytes))))
+            }
+            // A `Pending` here is what makes hyper flush the chunks written
+            // so far, so each stream read reaches the client promptly.
+            Self::Stream(chunks) => chunks.poll_recv(cx).map(|chunk| {
+                chunk.map(|chunk| chunk.map(Frame::data).map_err(std::io::Error::other))
+            }),
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        matches!(self, Self::Empty)
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        match self {
+            Self::Empty => SizeHint::with_exact(0),
+            Self::Buffered(bytes) => SizeHint::with_exact(bytes.len() as u64),
+            // No exact length: hyper frames HTTP/1.1 with chunked encoding.
+            Self::Stream(_) => SizeHint::default(),
+        }
+    }
+}
+
 fn bytes_to_uint8_array(
     ctx: &mut NativeCtx<'_>,
     bytes: Vec<u8>,
@@ -0,0 +1,369 @@
+//! Method + path routing for `Otter.serve` handlers.
+//!
+//! `Otter.Router()` returns a router object whose `get`/`post`/… methods
+//! register handlers against path patterns, and whose `fetch` property is a
+//! composed handler that can be passed straight to `Otter.serve`.
+//!
+//! # Contents
+//! - [`RouteTable`] - the pure matcher: patterns are `/`-separated segments
+//!   where `:name` captures one segment and a trailing `*` captures the rest.
+//! - [`RouteMatch`] - a dispatch decision (handler + params, 405, or 404).
+//! - [`create_router`] - the JS entry point installed as `Router` on the
+//!   bare `"otter"` module and the global `Otter` namespace.
+//!
+//! # Invariants
+//! - Overlapping routes resolve most-specific-first: segments compare
+//!   static > `:param` > `*` left to right, so `/users/me` beats
+//!   `/users/:id`, which beats `/users/*`. Equal patterns keep registration
+//!   order.
+//! - A path that matches some route but none for the request method answers
+//!   `405` with an `Allow` header; a path that matches nothing answers `404`.
+//! - Handler callables live in one traced host-data slot (a JS array indexed
+//!   by route id); the Rust route table holds only owned strings.
+//!
+//! # See also
+//! - [`crate::serve`]
+
+use otter_runtime::{
+    RuntimeAttr as Attr, RuntimeHostDataTracer, RuntimeHostValueSlot, RuntimeLocal,
+    RuntimeNativeCtx as NativeCtx, RuntimeNativeError as NativeError, RuntimeNativeScope,
+    RuntimeTracedHostObjectData, RuntimeValue as Value, runtime_this_object,
+};
+
+/// Registration methods and the HTTP method each one matches (`None` = any).
+const ROUTE_METHODS: &[(&str, Option<&str>)] = &[
+    ("get", Some("GET")),
+    ("post", Some("POST")),
+    ("put", Some("PUT")),
+    ("patch", Some("PATCH")),
+    ("delete", Some("DELETE")),
+    ("head", Some("HEAD")),
+    ("options", Some("OPTIONS")),
+    ("all", None),
+];
+
+/// One parsed pattern segment.
+#[derive(Debug)]
+enum Segment {
+    Static(String),
+    Param(String),
+    Wildcard,
+}
+
+impl Segment {
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Static(_) => 0,
+            Self::Param(_) => 1,
+            Self::Wildcard => 2,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Route {
+    method: Option<String>,
+    segments: Vec<Segment>,
+}
+
+/// Outcome of resolving one request against a [`RouteTable`].
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum RouteMatch {
+    /// Route `id` matched; `params` are the decoded captures in pattern order.
+    Found {
+        id: usize,
+        params: Vec<(String, String)>,
+    },
+    /// The path matched, but only under these other methods.
+    MethodNotAllowed { allow: Vec<String> },
+    /// No route matched the path.
+    NotFound,
+}
+
+/// Ordered route patterns; route ids are registration indexes.
+#[derive(Debug, Default)]
+pub(crate) struct RouteTable {
+    routes: Vec<Route>,
+}
+
+impl RouteTable {
+    /// Parse and append `pattern`, returning its route id.
+    pub(crate) fn add(&mut self, method: Option<&str>, pattern: &str) -> Result<usize, String> {
+        let mut segments = Vec::new();
+        let parts: Vec<&str> = path_segments(pattern).collect();
+        for (index, part) in parts.iter().enumerate() {
+            segments.push(if *part == "*" {
+                if index + 1 != parts.len() {
+                    return Err(format!("wildcard must be the last segment in `{pattern}`"));
+                }
+                Segment::Wildcard
+            } else if let Some(name) = part.strip_prefix(':') {
+                if name.is_empty() {
+                    return Err(format!("empty parameter name in `{pattern}`"));
+                }
+                Segment::Param(name.to_string())
+            } else {
+                Segment::Static((*part).to_string())
+            });
+        }
+        self.routes.push(Route {
+            method: method.map(str::to_ascii_uppercase),
+            segments,
+        });
+        Ok(self.routes.len() - 1)
+    }
+
+    /// Resolve `method` + `path` (no query string) to the most specific route.
+    pub(crate) fn resolve(&self, method: &str, path: &str) -> RouteMatch {
+        let parts: Vec<&str> = path_segments(path).collect();
+        let mut best: Option<(usize, Vec<(String, String)>)> = None;
+        let mut allow: Vec<String> = Vec::new();
+        for (id, route) in self.routes.iter().enumerate() {
+            let Some(params) = match_segments(&route.segments, &parts) else {
+                continue;
+            };
+            let method_ok = route
+                .method
+                .as_deref()
+                .is_none_or(|m| m.eq_ignore_ascii_case(method));
+            if !method_ok {
+                if let Some(m) = &route.method
+                    && !allow.contains(m)
+                {
+                    allow.push(m.clone());
+                }
+                continue;
+            }
+            let better = best
+                .as_ref()
+                .is_none_or(|(current, _)| self.more_specific(id, *current));
+            if better {
+                best = Some((id, params));
+            }
+        }
+        match best {
+            Some((id, params)) => RouteMatch::Found { id, params },
+            None if !allow.is_empty() => RouteMatch::MethodNotAllowed { allow },
+            None => RouteMatch::NotFound,
+        }
+    }
+
+    /// Whether route `a` outranks route `b` (strictly; ties keep `b`).
+    fn more_specific(&self, a: usize, b: usize) -> bool {
+        let ranks = |id: usize| {
+            self.routes[id]
+                .segments
+                .iter()
+                .map(Segment::rank)
+                .collect::<Vec<_>>()
+        };
+        let (a, b) = (ranks(a), ranks(b));
+        for (x, y) in a.iter().zip(&b) {
+            if x != y {
+                return x < y;
+            }
+        }
+        // Same prefix: the longer pattern only adds a trailing wildcard that
+        // matched nothing, so the shorter one is more specific.
+        a.len() < b.len()
+    }
+}
+
+fn path_segments(path: &str) -> impl Iterator<Item = &str> {
+    path.split('/').filter(|part| !part.is_empty())
+}
+
+fn match_segments(segments: &[Segment], parts: &[&str]) -> Option<Vec<(String, String)>> {
+    let mut params = Vec::new();
+    for (index, segment) in segments.iter().enumerate() {
+        match segment {
+            Segment::Wildcard => {
+                let rest = parts.get(index..).unwrap_or_default();
+                params.push(("*".to_string(), percent_decode(&rest.join("/"))));
+                return Some(params);
+            }
+            Segment::Param(name) => {
+                params.push((name.clone(), percent_decode(parts.get(index)?)));
+            }
+            Segment::Static(text) => {
+                if *parts.get(index)? != text.as_str() {
+                    return None;
+                }
+            }
+        }
+    }
+    (parts.len() == segments.len()).then_some(params)
+}
+
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%'
+            && let Some(hex) = text.get(index + 1..index + 3)
+            && let Ok(byte) = u8::from_str_radix(hex, 16)
+        {
+            out.push(byte);
+            index += 3;
+        } else {
+            out.push(bytes[index]);
+            index += 1;
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Path component of a request URL: scheme/authority, query and fragment
+/// stripped.
+fn request_path(url: &str) -> &str {
+    let rest = match url.find("://") {
+        Some(scheme_end) => {
+            let after = &url[scheme_end + 3..];
+            after.find('/').map_or("/", |slash| &after[slash..])
+        }
+        None => url,
+    };
+    let end = rest.find(['?', '#']).unwrap_or(rest.len());
+    &rest[..end]
+}
+
+/// JS-facing payload behind a router object.
+struct RouterHandle {
+    table: RouteTable,
+    /// JS array of handler callables indexed by route id.
+    handlers: RuntimeHostValueSlot,
+}
+
+impl RuntimeTracedHostObjectData for RouterHandle {
+    fn trace_gc_slots(&mut self, tracer: &mut RuntimeHostDataTracer<'_>) {
+        tracer.trace(&mut self.handlers);
+    }
+}
+
+/// `Otter.Router()`: build an empty router object.
+pub(crate) fn create_router(
+    ctx: &mut NativeCtx<'_>,
+    _args: &[Value],
+) -> Result<Value, NativeError> {
+    ctx.scope(|mut scope| {
+        let router = build_router_object(&mut scope)?;
+        Ok(scope.finish(router))
+    })
+}
+
+fn build_router_object<'scope>(
+    scope: &mut RuntimeNativeScope<'scope, '_>,
+) -> Result<RuntimeLocal<'scope>, NativeError> {
+    let object = scope.traced_host_object(RouterHandle {
+        table: RouteTable::default(),
+        handlers: RuntimeHostValueSlot::empty(),
+    })?;
+    let handlers = scope.array(0)?;
+    scope.set_host_data_value::<RouterHandle>(object, handlers, |handle| &mut handle.handlers)?;
+    let attrs = Attr::builtin_function().to_flags();
+    for &(name, method) in ROUTE_METHODS {
+        let call = scope.native_closure(name, 2, &[], move |ctx, args, _captures| {
+            add_route(ctx, args, method)
+        })?;
+        scope.define(object, name, call, attrs)?;
+    }
+    // `fetch` closes over the router so it survives being detached, as in
+    // `Otter.serve({ fetch: router.fetch })`.
+    let fetch = scope.native_closure("fetch", 1, &[object], |ctx, args, captures| {
+        dispatch(ctx, args, captures[0])
+    })?;
+    scope.define(object, "fetch", fetch, attrs)?;
+    Ok(object)
+}
+
+fn add_route(
+    ctx: &mut NativeCtx<'_>,
+    args: &[Value],
+    method: Option<&str>,
+) -> Result<Value, NativeError> {
+    const NAME: &str = "Router.route";
+    let object = runtime_this_object(ctx, NAME, "Router")?;
+    let pattern = crate::arg_string(args, 0, NAME, ctx.heap())?;
+    let handler = args.get(1).copied().unwrap_or_else(Value::undefined);
+    ctx.scope(|mut scope| {
+        let host = scope.value(Value::object(object));
+        let handler = scope.value(handler);
+        if !scope.is_callable(handler) {
+            return Err(crate::type_error(NAME, "handler must be a function"));
+        }
+        let id = scope
+            .with_host_data_mut::<RouterHandle, _>(host, |handle| {
+                handle.table.add(method, &pattern)
+            })?
+            .map_err(|reason| crate::type_error(NAME, reason))?;
+        let handlers = scope.host_data_value::<RouterHandle>(host, |handle| &handle.handlers)?;
+        scope.set_index(handlers, id, handler)?;
+        Ok(scope.finish(host))
+    })
+}
+
+/// The composed handler: route `request`, expose `request.params`, and call
+/// the matched handler with the original arguments.
+fn dispatch(ctx: &mut NativeCtx<'_>, args: &[Value], router: Value) -> Result<Value, NativeError> {
+    const NAME: &str = "Router.fetch";
+    let request = args.first().copied().unwrap_or_else(Value::undefined);
+    ctx.scope(|mut scope| {
+        let host = scope.value(router);
+        let request = scope.value(request);
+        let method = scope.get(request, "method")?;
+        let method = scope.display_string(method);
+        let url = scope.get(request, "url")?;
+        let url = scope.display_string(url);
+        let decision = scope.with_host_data::<RouterHandle, _>(host, |handle| {
+            handle.table.resolve(&method, request_path(&url))
+        })?;
+        let result = match decision {
+            RouteMatch::Found { id, params } => {
+                let object = scope.object()?;
+                for (key, value) in &params {
+                    let value = scope.string(value)?;
+                    scope.set(object, key, value)?;
+                }
+                scope.set(request, "params", object)?;
+                let handlers =
+                    scope.host_data_value::<RouterHandle>(host, |handle| &handle.handlers)?;
+                let handler = scope.get(handlers, &id.to_string())?;
+                let receiver = scope.undefined();
+                let mut call_args = vec![request];
+                for arg in args.iter().skip(1) {
+                    call_args.push(scope.value(*arg));
+                }
+                scope.call(handler, receiver, &call_args)?
+            }
+            RouteMatch::MethodNotAllowed { allow } => {
+                status_response(&mut scope, NAME, 405, Some(&allow.join(", ")))?
+            }
+            RouteMatch::NotFound => status_response(&mut scope, NAME, 404, None)?,
+        };
+        Ok(scope.finish(result))
+    })
+}
+
+/// `new Response(null, { status, headers: { allow } })`.
+fn status_response<'scope>(
+    scope: &mut RuntimeNativeScope<'scope, '_>,
+    name: &'static str,
+    status: u16,
+    allow: Option<&str>,
+) -> Result<RuntimeLocal<'scope>, NativeError> {
+    let constructor = scope
+        .global("Response")
+        .ok_or_else(|| crate::type_error(name, "Otter.Router requires Web Fetch globals"))?;
+    let init = scope.object()?;
+    let status = scope.number(f64::from(status));
+    scope.set(init, "status", status)?;
+    if let Some(allow) = allow {
+        let headers = scope.object()?;
+        let allow = scope.string(allow)?;
+        scope.set(headers, "allow", allow)?;
+        scope.set(init, "headers", headers)?;
+    }
+    let body = scope.null();
+    scope.construct(constructor, &[body, init])
+}
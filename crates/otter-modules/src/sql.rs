@@ -1,16 +1,63 @@
 //! `otter:sql` SQLite host access.
 //!
 //! This slice keeps SQLite state as owned Rust data and checks filesystem
-//! capabilities before opening a database path.
+//! capabilities before opening a database path. The bundled SQLite build
+//! ships the JSON1 functions (`json_extract`, `json_each`, ...).
+//!
+//! # User-defined functions
+//! `db.function(name, fn)` registers a variadic scalar SQL function backed by
+//! a JS callable. SQLite invokes scalar functions synchronously from inside
+//! `sqlite3_step`, so once a database has functions its statements run on a
+//! worker thread while the isolate thread services each call over a channel.
+//! The first `db.function` starts that worker, and it serves every later
+//! statement until the database object is dropped. The VM never leaves its
+//! thread; only the connection moves.
+//! Values cross with the same JSON marshalling as params and rows, so SQL
+//! `NULL` and JS `null`/`undefined` map to each other. A throwing function
+//! aborts the statement with a SQL error.
+//!
+//! # Cursors
+//! `db.iterate(sql, ...params)` returns an async iterator that steps one
+//! prepared statement in batches instead of materializing every row. The
+//! statement runs on a dedicated worker thread that owns the connection until
+//! the iterator is exhausted, fails, is closed with `return()`, or is
+//! collected; every other statement on that database reports busy meanwhile,
+//! so a nested `iterate` fails fast instead of interleaving two statements.
+//! A SQLite error while stepping rejects the pending `next()` and releases
+//! the statement.
+//!
+//! # Parameters
+//! Params bind positionally (`db.query(sql, a, b)` for `?` / `?NNN`) or by
+//! name when the only param is a plain object (`db.query(sql, { id })` for
+//! `:id`, `$id`, or `@id`). Named binding is strict: a placeholder without a
+//! matching key, a key no placeholder uses, and a positional `?` in a named
+//! statement are all errors. Both forms bind `null`/`undefined` as `NULL`,
+//! booleans as `0`/`1`, integral numbers as `INTEGER`, other numbers as
+//! `REAL`, strings as `TEXT`, and typed arrays or `ArrayBuffer`s as `BLOB`
+//! (on the Rust side, an array of byte numbers, the same shape blob columns
+//! read back as).
+//!
+//! # Statement cache
+//! Statements are prepared through a per-connection LRU cache keyed on the
+//! trimmed SQL text, so a query run in a loop binds fresh parameters to one
+//! compiled statement. Evicted statements are finalized. SQLite re-prepares
+//! a cached statement whose schema went stale when it next steps, but column
+//! names read before that step would still be the old ones, so a
+//! `CREATE`/`DROP`/`ALTER` flushes the cache. `openSql(path,
+//! { statementCacheSize })` sets the capacity; `0` disables caching.
 
+use std::collections::VecDeque;
+use std::panic::AssertUnwindSafe;
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, mpsc};
 
 use otter_runtime::CapabilitySet;
 use otter_runtime::{
-    RuntimeAttr as Attr, RuntimeHostObjectError, RuntimeJsObject as JsObject, RuntimeLocal,
-    RuntimeNativeCtx as NativeCtx, RuntimeNativeError as NativeError, RuntimeNativeScope,
-    RuntimeValue as Value, runtime_this_object, runtime_with_host_data_mut,
+    RuntimeAttr as Attr, RuntimeHostDataTracer, RuntimeHostValueSlot, RuntimeJsObject as JsObject,
+    RuntimeLocal, RuntimeNativeCtx as NativeCtx, RuntimeNativeError as NativeError,
+    RuntimeNativeScope, RuntimeTracedHostObjectData, RuntimeValue as Value, runtime_this_object,
 };
+use rusqlite::functions::FunctionFlags;
 use rusqlite::types::{ToSqlOutput, Value as SqliteValue, ValueRef};
 use rusqlite::{Connection, OpenFlags};
 use serde_json::{Map as JsonMap, Number as JsonNumber, Value as JsonValue};
@@ -27,14 +74,34 @@ pub enum SqlError {
     /// SQLite error.
     #[error("sqlite error: {0}")]
     Sqlite(String),
-    /// Query parameters must be scalar JSON values.
+    /// Query parameters must be scalar JSON values or byte arrays.
     #[error("unsupported SQL parameter")]
     UnsupportedParam,
+    /// A named placeholder has no matching key in the params object.
+    #[error("missing value for SQL parameter `{0}`")]
+    MissingNamedParam(String),
+    /// A params-object key matches no placeholder in the statement.
+    #[error("SQL parameter `{0}` is not used by the statement")]
+    UnusedNamedParam(String),
+    /// A named-params statement also has an anonymous `?` placeholder.
+    #[error("positional `?` placeholder in a statement bound by name")]
+    PositionalInNamedQuery,
+    /// The connection is checked out by a statement that is still running,
+    /// for example when a user-defined function queries its own database.
+    #[error("database is busy running another statement")]
+    Busy,
+    /// The thread that runs statements for user-defined functions could not
+    /// be started.
+    #[error("failed to start the statement worker: {0}")]
+    Worker(String),
 }
 
 /// Result alias for `otter:sql`.
 pub type SqlResult<T> = Result<T, SqlError>;
 
+/// Prepared statements a new connection keeps cached.
+pub const DEFAULT_STATEMENT_CACHE_CAPACITY: usize = 16;
+
 /// Permission-gated SQLite database.
 #[derive(Debug)]
 pub struct SqlDatabase {
@@ -80,60 +147,267 @@ impl SqlDatabase {
         self.path.as_deref()
     }
 
+    /// Set how many prepared statements stay cached. `0` disables the cache
+    /// and finalizes everything it held.
+    pub fn set_statement_cache_capacity(&mut self, capacity: usize) {
+        self.conn.set_prepared_statement_cache_capacity(capacity);
+        if capacity == 0 {
+            self.conn.flush_prepared_statement_cache();
+        }
+    }
+
     /// Execute SQL and return affected rows.
     pub fn execute(&mut self, sql: &str, params: &[JsonValue]) -> SqlResult<u64> {
-        let params = convert_params(params)?;
-        let refs: Vec<&dyn rusqlite::ToSql> = params
-            .iter()
-            .map(|param| param as &dyn rusqlite::ToSql)
-            .collect();
-        self.conn
-            .execute(sql, refs.as_slice())
-            .map(|rows| rows as u64)
-            .map_err(sqlite_error)
+        self.execute_bound(sql, Bindings::Positional(params))
+    }
+
+    /// Execute SQL with `:name` / `$name` / `@name` placeholders bound from
+    /// `params` and return affected rows.
+    pub fn execute_named(
+        &mut self,
+        sql: &str,
+        params: &JsonMap<String, JsonValue>,
+    ) -> SqlResult<u64> {
+        self.execute_bound(sql, Bindings::Named(params))
     }
 
     /// Query rows as JSON objects.
     pub fn query(&mut self, sql: &str, params: &[JsonValue]) -> SqlResult<Vec<JsonValue>> {
-        let params = convert_params(params)?;
-        let refs: Vec<&dyn rusqlite::ToSql> = params
-            .iter()
-            .map(|param| param as &dyn rusqlite::ToSql)
-            .collect();
-        let mut stmt = self.conn.prepare(sql).map_err(sqlite_error)?;
-        let names: Vec<String> = stmt
-            .column_names()
-            .iter()
-            .map(|name| name.to_string())
-            .collect();
-        let count = names.len();
-        let rows = stmt
-            .query_map(refs.as_slice(), |row| {
-                let mut out = JsonMap::new();
-                for (idx, name) in names.iter().enumerate().take(count) {
-                    out.insert(name.clone(), sqlite_value_to_json(row.get_ref(idx)?));
-                }
-                Ok(JsonValue::Object(out))
+        self.query_bound(sql, Bindings::Positional(params))
+    }
+
+    /// Query rows as JSON objects, binding placeholders by name.
+    pub fn query_named(
+        &mut self,
+        sql: &str,
+        params: &JsonMap<String, JsonValue>,
+    ) -> SqlResult<Vec<JsonValue>> {
+        self.query_bound(sql, Bindings::Named(params))
+    }
+
+    /// Query one row.
+    pub fn query_one(&mut self, sql: &str, params: &[JsonValue]) -> SqlResult<Option<JsonValue>> {
+        Ok(self.query(sql, params)?.into_iter().next())
+    }
+
+    /// Query one row, binding placeholders by name.
+    pub fn query_one_named(
+        &mut self,
+        sql: &str,
+        params: &JsonMap<String, JsonValue>,
+    ) -> SqlResult<Option<JsonValue>> {
+        Ok(self.query_named(sql, params)?.into_iter().next())
+    }
+
+    fn execute_bound(&mut self, sql: &str, params: Bindings<'_>) -> SqlResult<u64> {
+        let sql = sql.trim();
+        let affected = self
+            .conn
+            .prepare_cached(sql)
+            .map_err(sqlite_error)
+            .and_then(|mut stmt| {
+                bind_params(&mut stmt, params)?;
+                stmt.raw_execute().map_err(sqlite_error)
             })
-            .map_err(sqlite_error)?;
+            .map(|rows| rows as u64);
+        if changes_schema(sql) {
+            self.conn.flush_prepared_statement_cache();
+        }
+        affected
+    }
+
+    fn query_bound(&mut self, sql: &str, params: Bindings<'_>) -> SqlResult<Vec<JsonValue>> {
+        let mut stmt = self.conn.prepare_cached(sql.trim()).map_err(sqlite_error)?;
+        bind_params(&mut stmt, params)?;
+        let names = column_names(&stmt);
+        let mut rows = stmt.raw_query();
         let mut out = Vec::new();
-        for row in rows {
-            out.push(row.map_err(sqlite_error)?);
+        while let Some(row) = rows.next().map_err(sqlite_error)? {
+            out.push(row_to_json(row, &names).map_err(sqlite_error)?);
         }
         Ok(out)
     }
 
-    /// Query one row.
-    pub fn query_one(&mut self, sql: &str, params: &[JsonValue]) -> SqlResult<Option<JsonValue>> {
-        Ok(self.query(sql, params)?.into_iter().next())
+    /// Stream query rows in batches of at most `batch_rows`.
+    ///
+    /// The database moves into the returned stream for as long as the
+    /// statement is open; [`SqlRowStream::finish`] hands it back. Preparation
+    /// and stepping errors are reported by [`SqlRowStream::next_batch`].
+    #[must_use]
+    pub fn query_stream(self, sql: &str, params: &[JsonValue], batch_rows: usize) -> SqlRowStream {
+        let params = OwnedBindings::Positional(params.to_vec());
+        SqlRowStream::spawn(self, sql.to_string(), params, batch_rows.max(1))
+    }
+
+    /// [`Self::query_stream`] with placeholders bound by name.
+    #[must_use]
+    pub fn query_stream_named(
+        self,
+        sql: &str,
+        params: &JsonMap<String, JsonValue>,
+        batch_rows: usize,
+    ) -> SqlRowStream {
+        let params = OwnedBindings::Named(params.clone());
+        SqlRowStream::spawn(self, sql.to_string(), params, batch_rows.max(1))
+    }
+
+    /// Register a variadic scalar SQL function.
+    ///
+    /// Arguments use the row marshalling (SQL `NULL` is `null`, blobs are
+    /// byte arrays). A `null` result is SQL `NULL`, booleans become integers,
+    /// and arrays or objects are returned as JSON text. `Err` aborts the
+    /// statement with that message.
+    pub fn create_function<F>(&mut self, name: &str, function: F) -> SqlResult<()>
+    where
+        F: FnMut(&[JsonValue]) -> Result<JsonValue, String> + Send + 'static,
+    {
+        let mut function = AssertUnwindSafe(function);
+        self.conn
+            .create_scalar_function(name, -1, FunctionFlags::SQLITE_UTF8, move |ctx| {
+                let args = (0..ctx.len())
+                    .map(|index| sqlite_value_to_json(ctx.get_raw(index)))
+                    .collect::<Vec<_>>();
+                let function = &mut function;
+                (function.0)(&args)
+                    .map(|value| json_to_sqlite_result(&value))
+                    .map_err(|message| rusqlite::Error::UserFunctionError(message.into()))
+            })
+            .map_err(sqlite_error)
+    }
+}
+
+/// Open statement stepped on a worker thread, one batch per request.
+///
+/// The worker owns the database and the prepared statement. It steps only
+/// when [`Self::next_batch`] asks, so at most one batch of rows is buffered.
+/// Dropping the stream closes the request channel; the worker then finalizes
+/// the statement and exits.
+#[derive(Debug)]
+pub struct SqlRowStream {
+    requests: Option<mpsc::Sender<()>>,
+    batches: mpsc::Receiver<SqlResult<Vec<JsonValue>>>,
+    worker: Option<std::thread::JoinHandle<SqlDatabase>>,
+}
+
+impl SqlRowStream {
+    fn spawn(db: SqlDatabase, sql: String, params: OwnedBindings, batch_rows: usize) -> Self {
+        let (requests, requested) = mpsc::channel();
+        let (batch_sender, batches) = mpsc::channel();
+        let worker = std::thread::spawn(move || {
+            if requested.recv().is_ok()
+                && let Err(err) = stream_rows(
+                    &db,
+                    &sql,
+                    params.borrowed(),
+                    batch_rows,
+                    &requested,
+                    &batch_sender,
+                )
+            {
+                let _ = batch_sender.send(Err(err));
+            }
+            db
+        });
+        Self {
+            requests: Some(requests),
+            batches,
+            worker: Some(worker),
+        }
+    }
+
+    /// Step the next batch. `None` once the rows are exhausted or after an
+    /// error has been reported; a returned batch is never empty.
+    pub fn next_batch(&mut self) -> Option<SqlResult<Vec<JsonValue>>> {
+        self.requests.as_ref()?.send(()).ok()?;
+        self.batches.recv().ok()
+    }
+
+    /// Finalize the statement and return the database. `None` only if the
+    /// worker panicked, which takes the connection with it.
+    #[must_use]
+    pub fn finish(mut self) -> Option<SqlDatabase> {
+        self.shutdown()
+    }
+
+    fn shutdown(&mut self) -> Option<SqlDatabase> {
+        self.requests.take();
+        self.worker.take()?.join().ok()
+    }
+}
+
+impl Drop for SqlRowStream {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// Worker half of [`SqlRowStream`]: answer each request with one batch until
+/// the rows run out or the stream is dropped.
+fn stream_rows(
+    db: &SqlDatabase,
+    sql: &str,
+    params: Bindings<'_>,
+    batch_rows: usize,
+    requested: &mpsc::Receiver<()>,
+    batches: &mpsc::Sender<SqlResult<Vec<JsonValue>>>,
+) -> SqlResult<()> {
+    let mut stmt = db.conn.prepare_cached(sql.trim()).map_err(sqlite_error)?;
+    bind_params(&mut stmt, params)?;
+    let names = column_names(&stmt);
+    let mut rows = stmt.raw_query();
+    loop {
+        let mut batch = Vec::with_capacity(batch_rows);
+        let mut exhausted = false;
+        while batch.len() < batch_rows {
+            match rows.next().map_err(sqlite_error)? {
+                Some(row) => batch.push(row_to_json(row, &names).map_err(sqlite_error)?),
+                None => {
+                    exhausted = true;
+                    break;
+                }
+            }
+        }
+        if batch.is_empty() || batches.send(Ok(batch)).is_err() {
+            return Ok(());
+        }
+        if exhausted || requested.recv().is_err() {
+            return Ok(());
+        }
+    }
+}
+
+fn column_names(stmt: &rusqlite::Statement<'_>) -> Vec<String> {
+    stmt.column_names()
+        .iter()
+        .map(|name| name.to_string())
+        .collect()
+}
+
+fn row_to_json(row: &rusqlite::Row<'_>, names: &[String]) -> rusqlite::Result<JsonValue> {
+    let mut out = JsonMap::new();
+    for (idx, name) in names.iter().enumerate() {
+        out.insert(name.clone(), sqlite_value_to_json(row.get_ref(idx)?));
     }
+    Ok(JsonValue::Object(out))
 }
 
 fn configure(conn: &Connection) -> SqlResult<()> {
+    conn.set_prepared_statement_cache_capacity(DEFAULT_STATEMENT_CACHE_CAPACITY);
     conn.execute_batch("PRAGMA foreign_keys = ON;")
         .map_err(sqlite_error)
 }
 
+/// Whether `sql` opens with a schema-changing keyword.
+fn changes_schema(sql: &str) -> bool {
+    let keyword = sql
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .next()
+        .unwrap_or_default();
+    ["CREATE", "DROP", "ALTER"]
+        .iter()
+        .any(|ddl| keyword.eq_ignore_ascii_case(ddl))
+}
+
 fn sqlite_error(error: rusqlite::Error) -> SqlError {
     SqlError::Sqlite(error.to_string())
 }
@@ -147,25 +421,112 @@ impl rusqlite::ToSql for Param {
     }
 }
 
-fn convert_params(params: &[JsonValue]) -> SqlResult<Vec<Param>> {
-    params
-        .iter()
-        .map(|value| match value {
-            JsonValue::Null => Ok(Param(SqliteValue::Null)),
-            JsonValue::Bool(value) => Ok(Param(SqliteValue::Integer(i64::from(*value)))),
-            JsonValue::Number(value) => {
-                if let Some(i) = value.as_i64() {
-                    Ok(Param(SqliteValue::Integer(i)))
-                } else if let Some(f) = value.as_f64() {
-                    Ok(Param(SqliteValue::Real(f)))
-                } else {
-                    Err(SqlError::UnsupportedParam)
+/// Statement params as passed to [`SqlDatabase`].
+#[derive(Debug, Clone, Copy)]
+enum Bindings<'a> {
+    Positional(&'a [JsonValue]),
+    Named(&'a JsonMap<String, JsonValue>),
+}
+
+/// [`Bindings`] moved onto a cursor's worker thread.
+#[derive(Debug)]
+enum OwnedBindings {
+    Positional(Vec<JsonValue>),
+    Named(JsonMap<String, JsonValue>),
+}
+
+impl OwnedBindings {
+    fn borrowed(&self) -> Bindings<'_> {
+        match self {
+            Self::Positional(params) => Bindings::Positional(params),
+            Self::Named(params) => Bindings::Named(params),
+        }
+    }
+}
+
+/// Bind every placeholder of `stmt`. A positional count mismatch reports
+/// SQLite's own parameter-count error.
+fn bind_params(stmt: &mut rusqlite::Statement<'_>, params: Bindings<'_>) -> SqlResult<()> {
+    let expected = stmt.parameter_count();
+    match params {
+        Bindings::Positional(params) => {
+            if params.len() != expected {
+                return Err(sqlite_error(rusqlite::Error::InvalidParameterCount(
+                    params.len(),
+                    expected,
+                )));
+            }
+            for (index, value) in params.iter().enumerate() {
+                stmt.raw_bind_parameter(index + 1, convert_param(value)?)
+                    .map_err(sqlite_error)?;
+            }
+        }
+        Bindings::Named(params) => {
+            let mut used = Vec::with_capacity(expected);
+            for index in 1..=expected {
+                let placeholder = stmt
+                    .parameter_name(index)
+                    .ok_or(SqlError::PositionalInNamedQuery)?;
+                // `?NNN` is numbered, not named; everything else carries a
+                // one-character `:`/`$`/`@` sigil.
+                if placeholder.starts_with('?') {
+                    return Err(SqlError::PositionalInNamedQuery);
                 }
+                let name = &placeholder[1..];
+                let value = params
+                    .get(name)
+                    .ok_or_else(|| SqlError::MissingNamedParam(name.to_string()))?;
+                let param = convert_param(value)?;
+                used.push(name.to_string());
+                stmt.raw_bind_parameter(index, param)
+                    .map_err(sqlite_error)?;
             }
-            JsonValue::String(value) => Ok(Param(SqliteValue::Text(value.clone()))),
-            JsonValue::Array(_) | JsonValue::Object(_) => Err(SqlError::UnsupportedParam),
-        })
-        .collect()
+            if let Some(unused) = params.keys().find(|key| !used.contains(key)) {
+                return Err(SqlError::UnusedNamedParam(unused.clone()));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn convert_param(value: &JsonValue) -> SqlResult<Param> {
+    match value {
+        JsonValue::Null => Ok(Param(SqliteValue::Null)),
+        JsonValue::Bool(value) => Ok(Param(SqliteValue::Integer(i64::from(*value)))),
+        JsonValue::Number(value) => {
+            if let Some(i) = value.as_i64() {
+                Ok(Param(SqliteValue::Integer(i)))
+            } else if let Some(f) = value.as_f64() {
+                Ok(Param(SqliteValue::Real(f)))
+            } else {
+                Err(SqlError::UnsupportedParam)
+            }
+        }
+        JsonValue::String(value) => Ok(Param(SqliteValue::Text(value.clone()))),
+        JsonValue::Array(items) => items
+            .iter()
+            .map(|item| {
+                item.as_u64()
+                    .and_then(|byte| u8::try_from(byte).ok())
+                    .ok_or(SqlError::UnsupportedParam)
+            })
+            .collect::<SqlResult<Vec<u8>>>()
+            .map(|bytes| Param(SqliteValue::Blob(bytes))),
+        JsonValue::Object(_) => Err(SqlError::UnsupportedParam),
+    }
+}
+
+fn json_to_sqlite_result(value: &JsonValue) -> SqliteValue {
+    match value {
+        JsonValue::Null => SqliteValue::Null,
+        JsonValue::Bool(value) => SqliteValue::Integer(i64::from(*value)),
+        JsonValue::Number(value) => match value.as_i64() {
+            Some(i) => SqliteValue::Integer(i),
+            None => value.as_f64().map_or(SqliteValue::Null, SqliteValue::Real),
+        },
+        JsonValue::String(value) => SqliteValue::Text(value.clone()),
+        JsonValue::Array(_) | JsonValue::Object(_) => SqliteValue::Text(value.to_string()),
+    }
 }
 
 fn sqlite_value_to_json(value: ValueRef<'_>) -> JsonValue {
@@ -201,23 +562,140 @@ fn open_sql(
     capabilities: &CapabilitySet,
 ) -> Result<Value, NativeError> {
     let path = crate::arg_string(args, 0, "openSql", ctx.heap())?;
-    let db = if path.is_empty() || path == ":memory:" {
+    let cache_capacity = statement_cache_option(ctx, args.get(1).copied())?;
+    let mut db = if path.is_empty() || path == ":memory:" {
         SqlDatabase::memory().map_err(|err| crate::type_error("openSql", err.to_string()))?
     } else {
         SqlDatabase::open(&path, capabilities)
             .map_err(|err| crate::type_error("openSql", err.to_string()))?
     };
+    if let Some(capacity) = cache_capacity {
+        db.set_statement_cache_capacity(capacity);
+    }
     ctx.scope(|mut scope| {
         let object = build_database_object(&mut scope, db)?;
         Ok(scope.finish(object))
     })
 }
 
+/// `statementCacheSize` from the `openSql` options bag, if given.
+fn statement_cache_option(
+    ctx: &mut NativeCtx<'_>,
+    options: Option<Value>,
+) -> Result<Option<usize>, NativeError> {
+    let Some(options) = options.filter(|options| !options.is_nullish()) else {
+        return Ok(None);
+    };
+    let size = ctx.scope(|mut scope| {
+        let options = scope.value(options);
+        let size = scope.get(options, "statementCacheSize")?;
+        if scope.is_undefined(size) || scope.is_null(size) {
+            return Ok(None);
+        }
+        scope.number_value(size).map(Some)
+    })?;
+    match size {
+        None => Ok(None),
+        Some(size) if size.is_finite() && size >= 0.0 && size.fract() == 0.0 => {
+            Ok(Some(size as usize))
+        }
+        Some(_) => Err(crate::type_error(
+            "openSql",
+            "statementCacheSize must be a non-negative integer",
+        )),
+    }
+}
+
+/// JS-facing payload behind a database object.
+///
+/// `functions` holds a plain object mapping UDF names to callables. `db` is
+/// empty while a statement runs on `worker` or a cursor is open.
+struct SqlHandle {
+    db: DbSlot,
+    functions: RuntimeHostValueSlot,
+    has_functions: bool,
+    worker: Option<SqlWorker>,
+    bridge: UdfBridge,
+}
+
+impl RuntimeTracedHostObjectData for SqlHandle {
+    fn trace_gc_slots(&mut self, tracer: &mut RuntimeHostDataTracer<'_>) {
+        tracer.trace(&mut self.functions);
+    }
+}
+
+/// Home of a database connection. A cursor moves the connection out for its
+/// lifetime and puts it back when it closes, including when the cursor object
+/// is collected and no JS code can reach the database handle.
+type DbSlot = Arc<Mutex<Option<SqlDatabase>>>;
+
+fn take_database(slot: &DbSlot) -> Option<SqlDatabase> {
+    slot.lock().ok()?.take()
+}
+
+fn restore_database(slot: &DbSlot, db: SqlDatabase) {
+    if let Ok(mut slot) = slot.lock() {
+        *slot = Some(db);
+    }
+}
+
+/// Long-lived thread that runs a UDF-enabled database's statements while the
+/// isolate thread answers their UDF calls. It exits once every handle to it,
+/// and so the database object, is dropped.
+#[derive(Clone)]
+struct SqlWorker {
+    jobs: mpsc::Sender<SqlJob>,
+}
+
+type SqlJob = Box<dyn FnOnce() + Send>;
+
+impl SqlWorker {
+    fn spawn() -> SqlResult<Self> {
+        let (jobs, queue) = mpsc::channel::<SqlJob>();
+        std::thread::Builder::new()
+            .name("otter-sql".to_string())
+            .spawn(move || {
+                for job in queue {
+                    job();
+                }
+            })
+            .map_err(|err| SqlError::Worker(err.to_string()))?;
+        Ok(Self { jobs })
+    }
+}
+
+/// Sender for UDF calls, present only while a bridged statement runs.
+type UdfBridge = Arc<Mutex<Option<mpsc::Sender<UdfCall>>>>;
+
+struct UdfCall {
+    name: String,
+    args: Vec<JsonValue>,
+    reply: mpsc::Sender<Result<JsonValue, String>>,
+}
+
+/// Clears the bridge when the worker finishes, even by panic, so the isolate
+/// thread's receive loop always ends.
+struct BridgeGuard(UdfBridge);
+
+impl Drop for BridgeGuard {
+    fn drop(&mut self) {
+        if let Ok(mut sender) = self.0.lock() {
+            sender.take();
+        }
+    }
+}
+
 fn build_database_object<'scope>(
     scope: &mut RuntimeNativeScope<'scope, '_>,
     db: SqlDatabase,
 ) -> Result<RuntimeLocal<'scope>, NativeError> {
-    let object = scope.host_object(db)?;
+    let object = scope.traced_host_object(SqlHandle {
+        db: Arc::new(Mutex::new(Some(db))),
+        functions: RuntimeHostValueSlot::empty(),
+        has_functions: false,
+        worker: None,
+        bridge: UdfBridge::default(),
+    })?;
     let attrs = Attr::builtin_function().to_flags();
     for (name, length, call) in [
         (
@@ -227,6 +705,8 @@ fn build_database_object<'scope>(
         ),
         ("query", 1, method_query),
         ("queryOne", 1, method_query_one),
+        ("iterate", 1, method_iterate),
+        ("function", 2, method_function),
     ] {
         let method = scope.native_method(name, length, call)?;
         scope.define(object, name, method, attrs)?;
@@ -238,8 +718,144 @@ fn database_receiver(ctx: &NativeCtx<'_>, name: &'static str) -> Result<JsObject
     runtime_this_object(ctx, name, "SqlDatabase")
 }
 
-fn host_error(name: &'static str, err: RuntimeHostObjectError) -> NativeError {
-    crate::type_error(name, err.to_string())
+fn with_handle<R>(
+    ctx: &mut NativeCtx<'_>,
+    object: JsObject,
+    f: impl FnOnce(&mut SqlHandle) -> R,
+) -> Result<R, NativeError> {
+    ctx.scope(|mut scope| {
+        let host = scope.value(Value::object(object));
+        scope.with_host_data_mut::<SqlHandle, _>(host, f)
+    })
+}
+
+/// Run `op` against the receiver's database: inline when it has no UDFs,
+/// otherwise through [`run_bridged`].
+fn with_database<R: Send + 'static>(
+    ctx: &mut NativeCtx<'_>,
+    name: &'static str,
+    op: impl FnOnce(&mut SqlDatabase) -> SqlResult<R> + Send + 'static,
+) -> Result<R, NativeError> {
+    let object = database_receiver(ctx, name)?;
+    let bridged = with_handle(ctx, object, |handle| {
+        handle
+            .worker
+            .clone()
+            .map(|worker| (take_database(&handle.db), worker, handle.bridge.clone()))
+    })?;
+    let result = match bridged {
+        None => with_handle(ctx, object, |handle| match handle.db.lock() {
+            Ok(mut db) => db.as_mut().map_or(Err(SqlError::Busy), op),
+            Err(_) => Err(SqlError::Busy),
+        })?,
+        Some((None, _, _)) => Err(SqlError::Busy),
+        Some((Some(db), worker, bridge)) => {
+            let (db, result) = run_bridged(ctx, object, db, &worker, &bridge, op);
+            if let Some(db) = db {
+                with_handle(ctx, object, |handle| restore_database(&handle.db, db))?;
+            }
+            result?
+        }
+    };
+    result.map_err(|err| crate::type_error(name, err.to_string()))
+}
+
+/// Run `op` on the database's [`SqlWorker`] and answer its UDF calls here
+/// until it finishes. `target` is the database itself, or an open cursor's
+/// row stream; it moves to the worker for the call and comes back with the
+/// result. It is `None` only if the worker is gone, which drops it.
+fn run_bridged<T: Send + 'static, R: Send + 'static>(
+    ctx: &mut NativeCtx<'_>,
+    object: JsObject,
+    mut target: T,
+    worker: &SqlWorker,
+    bridge: &UdfBridge,
+    op: impl FnOnce(&mut T) -> SqlResult<R> + Send + 'static,
+) -> (Option<T>, Result<SqlResult<R>, NativeError>) {
+    let (sender, calls) = mpsc::channel();
+    if let Ok(mut slot) = bridge.lock() {
+        *slot = Some(sender);
+    }
+    let (done, finished) = mpsc::channel();
+    let guard = BridgeGuard(bridge.clone());
+    let job: SqlJob = Box::new(move || {
+        let _guard = guard;
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| op(&mut target)));
+        let _ = done.send((target, result));
+    });
+    if worker.jobs.send(job).is_err() {
+        let error = crate::type_error("SqlDatabase", "statement worker stopped");
+        return (None, Err(error));
+    }
+    for call in calls {
+        let result = call_function(ctx, object, &call.name, call.args);
+        let _ = call.reply.send(result);
+    }
+    match finished.recv() {
+        Ok((target, Ok(result))) => (Some(target), Ok(result)),
+        Ok((target, Err(_))) => (
+            Some(target),
+            Err(crate::type_error(
+                "SqlDatabase",
+                "statement worker panicked",
+            )),
+        ),
+        Err(_) => (
+            None,
+            Err(crate::type_error("SqlDatabase", "statement worker stopped")),
+        ),
+    }
+}
+
+/// SQLite-side half of a JS UDF: forward the call to the isolate thread and
+/// wait for its answer.
+fn bridged_function(
+    bridge: UdfBridge,
+    name: String,
+) -> impl FnMut(&[JsonValue]) -> Result<JsonValue, String> + Send + 'static {
+    move |args| {
+        let sender = bridge
+            .lock()
+            .ok()
+            .and_then(|slot| slot.as_ref().cloned())
+            .ok_or_else(|| format!("{name}() called outside an otter:sql statement"))?;
+        let (reply, response) = mpsc::channel();
+        sender
+            .send(UdfCall {
+                name: name.clone(),
+                args: args.to_vec(),
+                reply,
+            })
+            .map_err(|err| err.to_string())?;
+        drop(sender);
+        response.recv().map_err(|err| err.to_string())?
+    }
+}
+
+/// Invoke the registered JS callable for `name` on the isolate thread.
+fn call_function(
+    ctx: &mut NativeCtx<'_>,
+    object: JsObject,
+    name: &str,
+    args: Vec<JsonValue>,
+) -> Result<JsonValue, String> {
+    let result = ctx.scope(|mut scope| {
+        let host = scope.value(Value::object(object));
+        let functions = scope.host_data_value::<SqlHandle>(host, |handle| &handle.functions)?;
+        let callee = scope.get(functions, name)?;
+        let mut locals = Vec::with_capacity(args.len());
+        for arg in args {
+            locals.push(scoped_sql_value(&mut scope, arg)?);
+        }
+        let receiver = scope.undefined();
+        let result = scope.call(callee, receiver, &locals)?;
+        Ok::<Value, NativeError>(scope.finish(result))
+    });
+    match result {
+        Ok(value) => crate::value_to_json(&value, ctx.heap()).map_err(|err| err.to_string()),
+        Err(NativeError::Thrown { message, .. }) => Err(message),
+        Err(err) => Err(err.to_string()),
+    }
 }
 
 fn rest_args(args: &[Value]) -> &[Value] {
@@ -247,36 +863,32 @@ fn rest_args(args: &[Value]) -> &[Value] {
 }
 
 fn method_execute(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
-    let object = database_receiver(ctx, "SqlDatabase.execute")?;
-    let sql = crate::arg_string(args, 0, "SqlDatabase.execute", ctx.heap())?;
-    let params = js_params(rest_args(args), ctx.heap())?;
-    let result =
-        runtime_with_host_data_mut::<SqlDatabase, _>(ctx, object, |db| db.execute(&sql, &params))
-            .map_err(|err| host_error("SqlDatabase.execute", err))?;
-    let affected =
-        result.map_err(|err| crate::type_error("SqlDatabase.execute", err.to_string()))?;
+    const NAME: &str = "SqlDatabase.execute";
+    let sql = crate::arg_string(args, 0, NAME, ctx.heap())?;
+    let params = js_params(ctx, rest_args(args), NAME)?;
+    let affected = with_database(ctx, NAME, move |db| {
+        db.execute_bound(&sql, params.borrowed())
+    })?;
     Ok(Value::number_f64(affected as f64))
 }
 
 fn method_query(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
-    let object = database_receiver(ctx, "SqlDatabase.query")?;
-    let sql = crate::arg_string(args, 0, "SqlDatabase.query", ctx.heap())?;
-    let params = js_params(rest_args(args), ctx.heap())?;
-    let result =
-        runtime_with_host_data_mut::<SqlDatabase, _>(ctx, object, |db| db.query(&sql, &params))
-            .map_err(|err| host_error("SqlDatabase.query", err))?;
-    let rows = result.map_err(|err| crate::type_error("SqlDatabase.query", err.to_string()))?;
+    const NAME: &str = "SqlDatabase.query";
+    let sql = crate::arg_string(args, 0, NAME, ctx.heap())?;
+    let params = js_params(ctx, rest_args(args), NAME)?;
+    let rows = with_database(ctx, NAME, move |db| db.query_bound(&sql, params.borrowed()))?;
     json_rows_to_array(ctx, rows)
 }
 
 fn method_query_one(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
-    let object = database_receiver(ctx, "SqlDatabase.queryOne")?;
-    let sql = crate::arg_string(args, 0, "SqlDatabase.queryOne", ctx.heap())?;
-    let params = js_params(rest_args(args), ctx.heap())?;
-    let result =
-        runtime_with_host_data_mut::<SqlDatabase, _>(ctx, object, |db| db.query_one(&sql, &params))
-            .map_err(|err| host_error("SqlDatabase.queryOne", err))?;
-    match result.map_err(|err| crate::type_error("SqlDatabase.queryOne", err.to_string()))? {
+    const NAME: &str = "SqlDatabase.queryOne";
+    let sql = crate::arg_string(args, 0, NAME, ctx.heap())?;
+    let params = js_params(ctx, rest_args(args), NAME)?;
+    let row = with_database(ctx, NAME, move |db| {
+        db.query_bound(&sql, params.borrowed())
+            .map(|rows| rows.into_iter().next())
+    })?;
+    match row {
         Some(row) => ctx.scope(|mut scope| {
             let object = scoped_json_row_to_object(&mut scope, row)?;
             Ok::<Value, NativeError>(scope.finish(object))
@@ -285,16 +897,301 @@ fn method_query_one(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, Na
     }
 }
 
+/// Rows fetched from the worker per cursor step.
+const ITERATE_BATCH_ROWS: usize = 256;
+
+/// JS-facing payload behind a `db.iterate` cursor.
+///
+/// `database` keeps the owning database object reachable for UDF calls made
+/// while stepping. `home` is that database's connection slot, so closing the
+/// cursor (or collecting it) returns the connection without touching the
+/// heap.
+struct SqlCursor {
+    stream: Option<SqlRowStream>,
+    rows: VecDeque<JsonValue>,
+    home: DbSlot,
+    database: RuntimeHostValueSlot,
+}
+
+impl SqlCursor {
+    fn close(&mut self) {
+        self.rows.clear();
+        if let Some(db) = self.stream.take().and_then(SqlRowStream::finish) {
+            restore_database(&self.home, db);
+        }
+    }
+}
+
+impl Drop for SqlCursor {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+impl RuntimeTracedHostObjectData for SqlCursor {
+    fn trace_gc_slots(&mut self, tracer: &mut RuntimeHostDataTracer<'_>) {
+        tracer.trace(&mut self.database);
+    }
+}
+
+fn method_iterate(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
+    const NAME: &str = "SqlDatabase.iterate";
+    let object = database_receiver(ctx, NAME)?;
+    let sql = crate::arg_string(args, 0, NAME, ctx.heap())?;
+    let params = js_params(ctx, rest_args(args), NAME)?;
+    let (db, home) = with_handle(ctx, object, |handle| {
+        (take_database(&handle.db), handle.db.clone())
+    })?;
+    let db = db.ok_or_else(|| crate::type_error(NAME, SqlError::Busy.to_string()))?;
+    let cursor = SqlCursor {
+        stream: Some(SqlRowStream::spawn(db, sql, params, ITERATE_BATCH_ROWS)),
+        rows: VecDeque::new(),
+        home,
+        database: RuntimeHostValueSlot::empty(),
+    };
+    ctx.scope(|mut scope| {
+        let database = scope.value(Value::object(object));
+        let cursor = scope.traced_host_object(cursor)?;
+        scope.set_host_data_value::<SqlCursor>(cursor, database, |cursor| &mut cursor.database)?;
+        let attrs = Attr::builtin_function().to_flags();
+        for (name, call) in [
+            ("next", cursor_next as otter_runtime::RuntimeNativeFastFn),
+            ("return", cursor_return),
+        ] {
+            let method = scope.native_method(name, 0, call)?;
+            scope.define(cursor, name, method, attrs)?;
+        }
+        let symbol = scope
+            .global("Symbol")
+            .ok_or_else(|| crate::type_error(NAME, "Symbol is unavailable"))?;
+        let async_iterator = scope.get(symbol, "asyncIterator")?;
+        let method = scope.native_method("[Symbol.asyncIterator]", 0, cursor_async_iterator)?;
+        scope.define_symbol(cursor, async_iterator, method, attrs)?;
+        Ok(scope.finish(cursor))
+    })
+}
+
+fn with_cursor<R>(
+    ctx: &mut NativeCtx<'_>,
+    object: JsObject,
+    f: impl FnOnce(&mut SqlCursor) -> R,
+) -> Result<R, NativeError> {
+    ctx.scope(|mut scope| {
+        let host = scope.value(Value::object(object));
+        scope.with_host_data_mut::<SqlCursor, _>(host, f)
+    })
+}
+
+/// Next buffered row, stepping one batch when the buffer is empty. Closes the
+/// cursor once the rows run out or stepping fails.
+fn cursor_advance(
+    ctx: &mut NativeCtx<'_>,
+    cursor: JsObject,
+) -> Result<SqlResult<Option<JsonValue>>, NativeError> {
+    let (row, stream) = with_cursor(ctx, cursor, |cursor| match cursor.rows.pop_front() {
+        Some(row) => (Some(row), None),
+        None => (None, cursor.stream.take()),
+    })?;
+    if row.is_some() {
+        return Ok(Ok(row));
+    }
+    let Some(mut stream) = stream else {
+        return Ok(Ok(None));
+    };
+    let database = ctx
+        .scope(|mut scope| {
+            let host = scope.value(Value::object(cursor));
+            let database = scope.host_data_value::<SqlCursor>(host, |cursor| &cursor.database)?;
+            Ok::<Value, NativeError>(scope.finish(database))
+        })?
+        .as_object()
+        .ok_or_else(|| crate::type_error("SqlCursor.next", "cursor lost its database"))?;
+    let bridge = with_handle(ctx, database, |handle| {
+        handle
+            .worker
+            .clone()
+            .map(|worker| (worker, handle.bridge.clone()))
+    })?;
+    let (stream, batch) = match bridge {
+        None => {
+            let batch = stream.next_batch().transpose();
+            (Some(stream), Ok(batch))
+        }
+        Some((worker, bridge)) => run_bridged(ctx, database, stream, &worker, &bridge, |stream| {
+            stream.next_batch().transpose()
+        }),
+    };
+    with_cursor(ctx, cursor, |cursor| cursor.stream = stream)?;
+    let batch = batch?;
+    with_cursor(ctx, cursor, move |cursor| match batch {
+        Ok(Some(rows)) if !rows.is_empty() => {
+            cursor.rows.extend(rows);
+            Ok(cursor.rows.pop_front())
+        }
+        Ok(_) => {
+            cursor.close();
+            Ok(None)
+        }
+        Err(err) => {
+            cursor.close();
+            Err(err)
+        }
+    })
+}
+
+fn cursor_receiver(ctx: &NativeCtx<'_>, name: &'static str) -> Result<JsObject, NativeError> {
+    runtime_this_object(ctx, name, "SqlCursor")
+}
+
+fn cursor_next(ctx: &mut NativeCtx<'_>, _args: &[Value]) -> Result<Value, NativeError> {
+    const NAME: &str = "SqlCursor.next";
+    let cursor = cursor_receiver(ctx, NAME)?;
+    let outcome = cursor_advance(ctx, cursor)?;
+    ctx.scope(|mut scope| {
+        let promise = match outcome {
+            Ok(Some(row)) => {
+                let row = scoped_json_row_to_object(&mut scope, row)?;
+                let result = scope.iterator_result(row, false)?;
+                scope.promise_fulfilled(result)?
+            }
+            Ok(None) => {
+                let value = scope.undefined();
+                let result = scope.iterator_result(value, true)?;
+                scope.promise_fulfilled(result)?
+            }
+            Err(err) => {
+                let constructor = scope
+                    .global("TypeError")
+                    .ok_or_else(|| crate::type_error(NAME, "TypeError is unavailable"))?;
+                let message = scope.string(&format!("{NAME}: {err}"))?;
+                let reason = scope.construct(constructor, &[message])?;
+                scope.promise_rejected(reason)?
+            }
+        };
+        Ok(scope.finish(promise))
+    })
+}
+
+fn cursor_return(ctx: &mut NativeCtx<'_>, _args: &[Value]) -> Result<Value, NativeError> {
+    let cursor = cursor_receiver(ctx, "SqlCursor.return")?;
+    with_cursor(ctx, cursor, SqlCursor::close)?;
+    ctx.scope(|mut scope| {
+        let value = scope.undefined();
+        let result = scope.iterator_result(value, true)?;
+        let promise = scope.promise_fulfilled(result)?;
+        Ok(scope.finish(promise))
+    })
+}
+
+fn cursor_async_iterator(ctx: &mut NativeCtx<'_>, _args: &[Value]) -> Result<Value, NativeError> {
+    Ok(*ctx.this_value())
+}
+
+fn method_function(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
+    const NAME: &str = "SqlDatabase.function";
+    let object = database_receiver(ctx, NAME)?;
+    let name = crate::arg_string(args, 0, NAME, ctx.heap())?;
+    let callee = args.get(1).copied().unwrap_or_else(Value::undefined);
+    ctx.scope(|mut scope| {
+        let host = scope.value(Value::object(object));
+        let callee = scope.value(callee);
+        if !scope.is_callable(callee) {
+            return Err(crate::type_error(NAME, "implementation must be a function"));
+        }
+        let has_functions =
+            scope.with_host_data::<SqlHandle, _>(host, |handle| handle.has_functions)?;
+        let functions = if has_functions {
+            scope.host_data_value::<SqlHandle>(host, |handle| &handle.functions)?
+        } else {
+            let functions = scope.object()?;
+            scope.set_host_data_value::<SqlHandle>(host, functions, |handle| {
+                &mut handle.functions
+            })?;
+            functions
+        };
+        scope.set(functions, &name, callee)?;
+        scope
+            .with_host_data_mut::<SqlHandle, _>(host, |handle| {
+                let bridge = handle.bridge.clone();
+                let mut db = handle.db.lock().map_err(|_| SqlError::Busy)?;
+                let db = db.as_mut().ok_or(SqlError::Busy)?;
+                if handle.worker.is_none() {
+                    handle.worker = Some(SqlWorker::spawn()?);
+                }
+                db.create_function(&name, bridged_function(bridge, name.clone()))?;
+                handle.has_functions = true;
+                Ok::<(), SqlError>(())
+            })?
+            .map_err(|err| crate::type_error(NAME, err.to_string()))?;
+        Ok(Value::undefined())
+    })
+}
+
+/// Marshal statement params: one plain object binds by name, anything else
+/// binds positionally.
 fn js_params(
+    ctx: &mut NativeCtx<'_>,
     values: &[Value],
-    heap: &otter_runtime::otter_gc::GcHeap,
-) -> Result<Vec<JsonValue>, NativeError> {
-    values
-        .iter()
-        .map(|v| crate::value_to_json(v, heap))
-        .collect()
+    name: &'static str,
+) -> Result<OwnedBindings, NativeError> {
+    ctx.scope(|mut scope| {
+        if let [single] = values {
+            let object = scope.value(*single);
+            if scope.is_ordinary_object(object) {
+                let mut params = JsonMap::new();
+                for key in scope.enumerable_own_string_keys(object)? {
+                    let value = scope.get(object, &key)?;
+                    params.insert(key, scoped_param_to_json(&scope, value, name)?);
+                }
+                return Ok(OwnedBindings::Named(params));
+            }
+        }
+        let mut params = Vec::with_capacity(values.len());
+        for value in values {
+            let value = scope.value(*value);
+            params.push(scoped_param_to_json(&scope, value, name)?);
+        }
+        Ok(OwnedBindings::Positional(params))
+    })
 }
 
+/// One param value in the JSON shape [`convert_param`] binds. Integral
+/// numbers become JSON integers so they bind as `INTEGER`; byte sources
+/// become byte arrays so they bind as `BLOB`.
+fn scoped_param_to_json(
+    scope: &RuntimeNativeScope<'_, '_>,
+    value: RuntimeLocal<'_>,
+    name: &'static str,
+) -> Result<JsonValue, NativeError> {
+    if scope.is_undefined(value) || scope.is_null(value) {
+        return Ok(JsonValue::Null);
+    }
+    if scope.is_string(value) {
+        return scope.string_value(value).map(JsonValue::String);
+    }
+    if let Ok(boolean) = scope.boolean_value(value) {
+        return Ok(JsonValue::Bool(boolean));
+    }
+    if let Ok(number) = scope.number_value(value) {
+        if number.fract() == 0.0 && number.abs() <= MAX_SAFE_INTEGER {
+            return Ok(JsonValue::from(number as i64));
+        }
+        return JsonNumber::from_f64(number)
+            .map(JsonValue::Number)
+            .ok_or_else(|| crate::type_error(name, "number is not finite JSON"));
+    }
+    if let Some(bytes) = scope.buffer_source_bytes(value) {
+        return Ok(JsonValue::from(bytes));
+    }
+    Err(crate::type_error(
+        name,
+        SqlError::UnsupportedParam.to_string(),
+    ))
+}
+
+/// Largest integer a JS number holds exactly.
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
 fn json_rows_to_array(ctx: &mut NativeCtx<'_>, rows: Vec<JsonValue>) -> Result<Value, NativeError> {
     // Each row object and the backing array are separate allocations, and each
     // row itself allocates per field. Collecting the row objects into a `Vec`
@@ -329,25 +1226,21 @@ fn scoped_json_row_to_object<'s>(
         ));
     };
     for (name, value) in map {
-        let value = scoped_json_to_value(scope, value)?;
+        let value = scoped_sql_value(scope, value)?;
         scope.set(object, &name, value)?;
     }
     Ok(object)
 }
 
-/// Scoped counterpart of [`crate::json_to_value`]: convert a JSON scalar to a JS
-/// value parked in scope `s`. Only the string arm allocates; parking keeps every
-/// arm reading like an ordinary scoped creation so the caller never holds a raw
-/// handle across a sibling allocation.
-fn scoped_json_to_value<'s>(
+/// Convert one SQL value to a JS scalar parked in scope `s`. SQL values are
+/// scalars except BLOBs, which arrive as JSON byte arrays and reach JS as
+/// that array's JSON text.
+fn scoped_sql_value<'s>(
     scope: &mut RuntimeNativeScope<'s, '_>,
     value: JsonValue,
 ) -> Result<RuntimeLocal<'s>, NativeError> {
     match value {
-        JsonValue::Null => Ok(scope.null()),
-        JsonValue::Bool(b) => Ok(scope.boolean(b)),
-        JsonValue::Number(n) => Ok(scope.number(n.as_f64().unwrap_or(f64::NAN))),
-        JsonValue::String(text) => scope.string(&text),
-        other => scope.string(&other.to_string()),
+        JsonValue::Array(_) | JsonValue::Object(_) => scope.string(&value.to_string()),
+        scalar => crate::scoped_json_to_value(scope, scalar),
     }
 }
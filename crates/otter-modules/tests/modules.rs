@@ -1,10 +1,11 @@
 use std::collections::BTreeMap;
+use std::time::Duration;
 
 use otter_modules::ffi::{FfiSignature, FfiType};
-use otter_modules::kv::KvStore;
+use otter_modules::kv::{KvOp, KvStore};
 use otter_modules::sql::SqlDatabase;
 use otter_modules::{OtterModulesBuilderExt, hosted_modules};
-use otter_runtime::{CapabilitySet, Permission, Runtime};
+use otter_runtime::{CapabilitySet, Permission, Runtime, SourceInput};
 use serde_json::json;
 
 #[test]
@@ -54,6 +55,414 @@ fn sql_memory_queries_json_rows() {
     assert_eq!(rows, vec![json!({"id": 1, "name": "Ada"})]);
 }
 
+#[test]
+fn sql_rust_functions_and_json1_are_available() {
+    let mut db = SqlDatabase::memory().unwrap();
+    db.create_function("add_or_null", |args| {
+        Ok(match (args[0].as_i64(), args[1].as_i64()) {
+            (Some(a), Some(b)) => json!(a + b),
+            _ => json!(null),
+        })
+    })
+    .unwrap();
+    db.create_function("fail", |_| Err("nope".to_string()))
+        .unwrap();
+    let rows = db
+        .query(
+            "SELECT add_or_null(2, 3) AS sum, add_or_null(NULL, 3) AS missing, \
+             json_extract('{\"a\":{\"b\":7}}', '$.a.b') AS b",
+            &[],
+        )
+        .unwrap();
+    assert_eq!(rows, vec![json!({"sum": 5, "missing": null, "b": 7})]);
+    let items = db
+        .query(
+            "SELECT value FROM json_each(?) ORDER BY key",
+            &[json!("[1,2,3]")],
+        )
+        .unwrap();
+    assert_eq!(
+        items,
+        vec![
+            json!({"value": 1}),
+            json!({"value": 2}),
+            json!({"value": 3})
+        ]
+    );
+    let err = db.query("SELECT fail()", &[]).unwrap_err();
+    assert!(err.to_string().contains("nope"), "{err}");
+}
+
+#[test]
+fn otter_sql_js_functions_run_inside_queries() {
+    let dir = tempfile::tempdir().unwrap();
+    let main = dir.path().join("main.mjs");
+    std::fs::write(
+        &main,
+        r#"
+            import { openSql } from "otter:sql";
+            const db = openSql(":memory:");
+            db.function("shout", (text, suffix) =>
+                text === null ? null : text.toUpperCase() + (suffix ?? "!"));
+            db.function("boom", () => { throw new Error("udf exploded"); });
+            db.execute("CREATE TABLE t (name TEXT, meta TEXT)");
+            db.execute("INSERT INTO t VALUES (?, ?)", "ada", '{"lang":"en"}');
+            db.execute("INSERT INTO t VALUES (NULL, NULL)");
+            const rows = db.query(
+                "SELECT shout(name, NULL) AS loud, json_extract(meta, '$.lang') AS lang FROM t ORDER BY rowid");
+            if (rows[0].loud !== "ADA!" || rows[0].lang !== "en") {
+                throw new Error("udf row: " + JSON.stringify(rows[0]));
+            }
+            if (rows[1].loud !== null || rows[1].lang !== null) {
+                throw new Error("null row: " + JSON.stringify(rows[1]));
+            }
+            let caught = "";
+            try {
+                db.query("SELECT boom()");
+            } catch (error) {
+                caught = String(error && error.message);
+            }
+            if (!caught.includes("udf exploded")) {
+                throw new Error("throwing udf surfaced as: " + caught);
+            }
+            if (db.queryOne("SELECT shout('ok', '?') AS v").v !== "OK?") {
+                throw new Error("database unusable after udf error");
+            }
+        "#,
+    )
+    .unwrap();
+
+    let mut runtime = Runtime::builder().with_otter_modules().build().unwrap();
+    runtime.run_module(&main).unwrap();
+}
+
+#[test]
+fn sql_query_stream_steps_rows_in_batches() {
+    let mut db = SqlDatabase::memory().unwrap();
+    db.execute(
+        "CREATE TABLE n AS WITH RECURSIVE c(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM c WHERE x < 1000) SELECT x FROM c",
+        &[],
+    )
+    .unwrap();
+    let mut stream = db.query_stream("SELECT x FROM n WHERE x > ? ORDER BY x", &[json!(10)], 100);
+    let mut sizes = Vec::new();
+    let mut last = 10;
+    while let Some(batch) = stream.next_batch() {
+        let batch = batch.unwrap();
+        for row in &batch {
+            assert_eq!(row["x"], json!(last + 1));
+            last += 1;
+        }
+        sizes.push(batch.len());
+    }
+    assert_eq!(last, 1000);
+    assert_eq!(sizes.len(), 10);
+    assert_eq!(sizes.last(), Some(&90));
+    assert!(stream.next_batch().is_none());
+
+    // The connection comes back usable once the statement is finalized.
+    let mut db = stream.finish().unwrap();
+    assert_eq!(
+        db.query_one("SELECT count(*) AS c FROM n", &[]).unwrap(),
+        Some(json!({"c": 1000}))
+    );
+
+    // A stepping error surfaces after the rows before it, then ends the stream.
+    db.create_function("fail_at", |args| match args[0].as_i64() {
+        Some(250) => Err("row 250 is cursed".to_string()),
+        _ => Ok(args[0].clone()),
+    })
+    .unwrap();
+    let mut stream = db.query_stream("SELECT fail_at(x) AS x FROM n ORDER BY x", &[], 100);
+    assert_eq!(stream.next_batch().unwrap().unwrap().len(), 100);
+    assert_eq!(stream.next_batch().unwrap().unwrap().len(), 100);
+    let err = stream.next_batch().unwrap().unwrap_err();
+    assert!(err.to_string().contains("row 250 is cursed"), "{err}");
+    assert!(stream.next_batch().is_none());
+
+    // A bad statement fails on the first step; dropping the stream early is fine.
+    let db = stream.finish().unwrap();
+    let mut stream = db.query_stream("SELECT * FROM missing", &[], 10);
+    assert!(stream.next_batch().unwrap().is_err());
+    let db = stream.finish().unwrap();
+    drop(db.query_stream("SELECT x FROM n", &[], 10));
+}
+
+#[test]
+fn otter_sql_iterate_is_an_async_cursor() {
+    let dir = tempfile::tempdir().unwrap();
+    let main = dir.path().join("main.mjs");
+    std::fs::write(
+        &main,
+        r#"
+            import { openSql } from "otter:sql";
+            const db = openSql(":memory:");
+            db.execute(
+                "CREATE TABLE n AS WITH RECURSIVE c(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM c WHERE x < 600) SELECT x FROM c");
+            const seen = [];
+
+            let sum = 0;
+            let count = 0;
+            for await (const row of db.iterate("SELECT x FROM n WHERE x > ?", 0)) {
+                sum += row.x;
+                count++;
+            }
+            seen.push(count, sum);
+
+            // A nested statement is rejected while the cursor owns the connection.
+            const cursor = db.iterate("SELECT x FROM n ORDER BY x");
+            const first = await cursor.next();
+            seen.push(first.value.x, first.done);
+            for (const nested of [() => db.iterate("SELECT 1"), () => db.query("SELECT 1")]) {
+                try {
+                    nested();
+                    seen.push("nested ran");
+                } catch (err) {
+                    seen.push(err instanceof TypeError && /busy/.test(err.message));
+                }
+            }
+            // Breaking out of the loop closes the cursor and frees the connection.
+            for await (const row of cursor) {
+                if (row.x === 3) break;
+            }
+            seen.push(db.queryOne("SELECT count(*) AS c FROM n").c);
+            seen.push((await cursor.next()).done);
+
+            // A failing statement rejects the pending `next()`.
+            db.function("failAt", (x) => {
+                if (x === 300) throw new Error("cursed row");
+                return x;
+            });
+            let rows = 0;
+            try {
+                for await (const row of db.iterate("SELECT failAt(x) AS x FROM n ORDER BY x")) rows++;
+                seen.push("no error");
+            } catch (err) {
+                seen.push(rows, /cursed row/.test(err.message));
+            }
+            seen.push(db.queryOne("SELECT failAt(7) AS v").v);
+            globalThis.sqlIterateResult = seen.join(",");
+        "#,
+    )
+    .unwrap();
+
+    let mut runtime = Runtime::builder().with_otter_modules().build().unwrap();
+    runtime.run_module(&main).unwrap();
+    let result = runtime
+        .run_script(
+            SourceInput::from_javascript("globalThis.sqlIterateResult"),
+            "<check>",
+        )
+        .unwrap();
+    assert_eq!(
+        result.completion_string(),
+        "600,180300,1,false,true,true,600,true,256,true,7"
+    );
+}
+
+#[test]
+fn sql_statement_cache_reuses_and_survives_ddl() {
+    let mut db = SqlDatabase::memory().unwrap();
+    db.execute("CREATE TABLE t (a INTEGER)", &[]).unwrap();
+    for a in 0..50 {
+        db.execute("INSERT INTO t (a) VALUES (?)", &[json!(a)])
+            .unwrap();
+    }
+    // Surrounding whitespace keys the same cached statement.
+    assert_eq!(
+        db.query_one("  SELECT count(*) AS c, sum(a) AS s FROM t\n", &[])
+            .unwrap(),
+        Some(json!({"c": 50, "s": 1225}))
+    );
+
+    // A cached `SELECT *` sees columns added by a later ALTER.
+    let all = "SELECT * FROM t WHERE a = ?";
+    assert_eq!(db.query(all, &[json!(1)]).unwrap(), vec![json!({"a": 1})]);
+    db.execute("alter table t ADD COLUMN b TEXT DEFAULT 'x'", &[])
+        .unwrap();
+    assert_eq!(
+        db.query(all, &[json!(1)]).unwrap(),
+        vec![json!({"a": 1, "b": "x"})]
+    );
+    db.execute("DROP TABLE t", &[]).unwrap();
+    assert!(db.query(all, &[json!(1)]).is_err());
+    db.execute("CREATE TABLE t (z TEXT)", &[]).unwrap();
+    db.execute("INSERT INTO t (z) VALUES ('new')", &[]).unwrap();
+    assert_eq!(
+        db.query("SELECT * FROM t", &[]).unwrap(),
+        vec![json!({"z": "new"})]
+    );
+
+    // A zero capacity disables caching without breaking statements.
+    db.set_statement_cache_capacity(0);
+    for _ in 0..3 {
+        assert_eq!(db.query("SELECT * FROM t", &[]).unwrap().len(), 1);
+    }
+}
+
+#[test]
+fn otter_sql_open_accepts_statement_cache_size() {
+    let dir = tempfile::tempdir().unwrap();
+    let main = dir.path().join("main.mjs");
+    std::fs::write(
+        &main,
+        r#"
+            import { openSql } from "otter:sql";
+            const seen = [];
+            for (const options of [undefined, null, {}, { statementCacheSize: 0 }, { statementCacheSize: 2 }]) {
+                const db = openSql(":memory:", options);
+                db.execute("CREATE TABLE t (a INTEGER)");
+                for (let i = 0; i < 5; i++) db.execute("INSERT INTO t (a) VALUES (?)", i);
+                seen.push(db.queryOne("SELECT sum(a) AS s FROM t").s);
+            }
+            for (const statementCacheSize of [-1, 1.5, NaN, "big"]) {
+                try {
+                    openSql(":memory:", { statementCacheSize });
+                    seen.push("accepted");
+                } catch (err) {
+                    seen.push(err instanceof TypeError);
+                }
+            }
+            globalThis.sqlCacheResult = seen.join(",");
+        "#,
+    )
+    .unwrap();
+
+    let mut runtime = Runtime::builder().with_otter_modules().build().unwrap();
+    runtime.run_module(&main).unwrap();
+    let result = runtime
+        .run_script(
+            SourceInput::from_javascript("globalThis.sqlCacheResult"),
+            "<check>",
+        )
+        .unwrap();
+    assert_eq!(
+        result.completion_string(),
+        "10,10,10,10,10,true,true,true,true"
+    );
+}
+
+#[test]
+fn sql_named_params_bind_by_name_and_reject_mismatches() {
+    let mut db = SqlDatabase::memory().unwrap();
+    db.execute("CREATE TABLE t (id INTEGER, name TEXT, data BLOB)", &[])
+        .unwrap();
+    let row = |id: i64, name: &str| {
+        json!({"id": id, "name": name, "data": [1, 2, 255]})
+            .as_object()
+            .unwrap()
+            .clone()
+    };
+    for (id, name) in [(1, "one"), (2, "two")] {
+        db.execute_named(
+            "INSERT INTO t (id, name, data) VALUES (:id, $name, @data)",
+            &row(id, name),
+        )
+        .unwrap();
+    }
+    let by_id = json!({"id": 2}).as_object().unwrap().clone();
+    assert_eq!(
+        db.query_named(
+            "SELECT name, typeof(data) AS kind FROM t WHERE id = :id",
+            &by_id
+        )
+        .unwrap(),
+        vec![json!({"name": "two", "kind": "blob"})]
+    );
+    // A name repeated in the SQL binds one value.
+    assert_eq!(
+        db.query_one_named("SELECT :id + :id AS twice", &by_id)
+            .unwrap(),
+        Some(json!({"twice": 4}))
+    );
+    assert_eq!(
+        db.query("SELECT data FROM t WHERE id = ?", &[json!(1)])
+            .unwrap(),
+        vec![json!({"data": [1, 2, 255]})]
+    );
+
+    let missing = db
+        .query_named("SELECT * FROM t WHERE id = :id AND name = :name", &by_id)
+        .unwrap_err();
+    assert!(missing.to_string().contains("`name`"), "{missing}");
+    let extra = json!({"id": 1, "nmae": "typo"})
+        .as_object()
+        .unwrap()
+        .clone();
+    let unused = db
+        .query_named("SELECT * FROM t WHERE id = :id", &extra)
+        .unwrap_err();
+    assert!(unused.to_string().contains("`nmae`"), "{unused}");
+    assert!(
+        db.query_named("SELECT * FROM t WHERE id = ?", &by_id)
+            .is_err()
+    );
+    let bad_byte = json!({"id": [1, 256]}).as_object().unwrap().clone();
+    assert!(db.query_named("SELECT :id", &bad_byte).is_err());
+    // Positional binding still checks the placeholder count.
+    assert!(db.query("SELECT ?, ?", &[json!(1)]).is_err());
+}
+
+#[test]
+fn otter_sql_binds_a_params_object_by_name() {
+    let dir = tempfile::tempdir().unwrap();
+    let main = dir.path().join("main.mjs");
+    std::fs::write(
+        &main,
+        r#"
+            import { openSql } from "otter:sql";
+            const db = openSql(":memory:");
+            db.execute("CREATE TABLE t (id INTEGER, name TEXT, data BLOB)");
+            db.execute("INSERT INTO t VALUES (:id, :name, :data)", {
+                id: 5,
+                name: "five",
+                data: new Uint8Array([1, 2, 255]),
+            });
+            db.execute("INSERT INTO t VALUES (?, ?, ?)", 6, "six", new Uint8Array([7]).buffer);
+            const seen = [];
+            seen.push(db.query("SELECT name FROM t WHERE id = $id", { id: 5 })[0].name);
+            const blob = db.queryOne(
+                "SELECT hex(data) AS h, typeof(id) AS t FROM t WHERE name = @name",
+                { name: "six" },
+            );
+            seen.push(blob.h, blob.t);
+            seen.push(db.queryOne("SELECT hex(data) AS h FROM t WHERE id = :id", { id: 5 }).h);
+            let names = [];
+            for await (const row of db.iterate("SELECT name FROM t WHERE id >= :min ORDER BY id", { min: 0 })) {
+                names.push(row.name);
+            }
+            seen.push(names.join("+"));
+            for (const [sql, params] of [
+                ["SELECT * FROM t WHERE id = :id AND name = :name", { id: 5 }],
+                ["SELECT * FROM t WHERE id = :id", { id: 5, extra: 1 }],
+                ["SELECT * FROM t WHERE id = ?", { id: 5 }],
+            ]) {
+                try {
+                    db.query(sql, params);
+                    seen.push("accepted");
+                } catch (err) {
+                    seen.push(err instanceof TypeError ? err.message.match(/`(\w+)`|positional/)[0] : "?");
+                }
+            }
+            globalThis.sqlNamedResult = seen.join(",");
+        "#,
+    )
+    .unwrap();
+
+    let mut runtime = Runtime::builder().with_otter_modules().build().unwrap();
+    runtime.run_module(&main).unwrap();
+    let result = runtime
+        .run_script(
+            SourceInput::from_javascript("globalThis.sqlNamedResult"),
+            "<check>",
+        )
+        .unwrap();
+    assert_eq!(
+        result.completion_string(),
+        "five,07,integer,0102FF,five+six,`name`,`extra`,positional"
+    );
+}
+
 #[test]
 fn ffi_signature_parses_known_types() {
     let signature = FfiSignature::parse(&["cstring", "i32"], "void").unwrap();
@@ -142,6 +551,90 @@ fn otter_global_installs_serve() {
         .unwrap();
 }
 
+/// Minimal `Response` stand-in: otter-modules tests run without Web Fetch.
+const ROUTER_PRELUDE: &str = r#"
+    globalThis.Response = class {
+        constructor(body, init) {
+            this.status = init.status;
+            this.allow = init.headers ? init.headers.allow : "";
+        }
+    };
+    const req = (method, path) => ({ method, url: "http://localhost:3000" + path + "?q=1" });
+    const show = (out) => (typeof out === "string" ? out : `${out.status}:${out.allow}`);
+"#;
+
+#[test]
+fn otter_router_extracts_params_most_specific_first() {
+    let mut runtime = Runtime::builder().with_otter_modules().build().unwrap();
+    let result = runtime
+        .run_script(
+            SourceInput::from_javascript(format!(
+                r#"{ROUTER_PRELUDE}
+                const router = Otter.Router();
+                router
+                    .get("/users/:id", (r) => `user ${{r.params.id}}`)
+                    .get("/users/me", () => "me")
+                    .get("/users/:id/posts/:post", (r) => `${{r.params.id}}/${{r.params.post}}`)
+                    .get("/files/*", (r) => `file ${{r.params["*"]}}`);
+                const {{ fetch }} = router;
+                [
+                    show(fetch(req("GET", "/users/42"))),
+                    show(fetch(req("GET", "/users/me"))),
+                    show(fetch(req("GET", "/users/a%20b/posts/7"))),
+                    show(fetch(req("GET", "/files/css/site.css"))),
+                    show(fetch(req("GET", "/nope"))),
+                ].join(",");
+                "#
+            )),
+            "<router-params>",
+        )
+        .unwrap();
+    assert_eq!(
+        result.completion_string(),
+        "user 42,me,a b/7,file css/site.css,404:"
+    );
+}
+
+#[test]
+fn otter_router_reports_405_and_falls_through_to_wildcards() {
+    let dir = tempfile::tempdir().unwrap();
+    let main = dir.path().join("main.mjs");
+    std::fs::write(
+        &main,
+        format!(
+            r#"{ROUTER_PRELUDE}
+            import {{ Router }} from "otter";
+            const api = Router();
+            api.get("/items", () => "list").post("/items", () => "created");
+            const site = Router();
+            site.get("/items/:id", (r) => `item ${{r.params.id}}`)
+                .all("/*", (r) => `fallback ${{r.params["*"]}}`);
+            globalThis.routerResult = [
+                show(api.fetch(req("POST", "/items"))),
+                show(api.fetch(req("DELETE", "/items"))),
+                show(site.fetch(req("GET", "/items/9"))),
+                show(site.fetch(req("PUT", "/items/9"))),
+                show(site.fetch(req("GET", "/"))),
+            ].join(",");
+            "#
+        ),
+    )
+    .unwrap();
+
+    let mut runtime = Runtime::builder().with_otter_modules().build().unwrap();
+    runtime.run_module(&main).unwrap();
+    let result = runtime
+        .run_script(
+            SourceInput::from_javascript("globalThis.routerResult"),
+            "<check>",
+        )
+        .unwrap();
+    assert_eq!(
+        result.completion_string(),
+        "created,405:GET, POST,item 9,fallback items/9,fallback "
+    );
+}
+
 #[test]
 fn hosted_namespace_is_cached_across_runs_and_loaders() {
     let dir = tempfile::tempdir().unwrap();
@@ -189,3 +682,413 @@ fn duplicate_hosted_specifier_is_a_build_error() {
         "unexpected error: {message}"
     );
 }
+
+#[test]
+fn kv_ttl_keys_expire_while_plain_keys_persist() {
+    let mut store = KvStore::memory();
+    store
+        .set_with_ttl("session", json!("token"), Duration::from_millis(20))
+        .unwrap();
+    store.set("config", json!({"theme": "dark"})).unwrap();
+    assert_eq!(store.get("session"), Some(json!("token")));
+    assert!(store.ttl("session").is_some());
+    assert_eq!(store.ttl("config"), None);
+
+    std::thread::sleep(Duration::from_millis(40));
+    assert_eq!(store.get("session"), None);
+    assert!(!store.has("session"));
+    assert_eq!(store.keys(), vec!["config".to_string()]);
+    assert_eq!(store.get("config"), Some(json!({"theme": "dark"})));
+
+    store
+        .set_with_ttl("session", json!("again"), Duration::from_secs(60))
+        .unwrap();
+    store.set("session", json!("forever")).unwrap();
+    assert_eq!(store.ttl("session"), None);
+}
+
+#[test]
+fn kv_zero_ttl_never_expires() {
+    let mut store = KvStore::memory();
+    store
+        .set_with_ttl("pinned", json!(1), Duration::from_secs(60))
+        .unwrap();
+    store
+        .set_with_ttl("pinned", json!(2), Duration::ZERO)
+        .unwrap();
+    std::thread::sleep(Duration::from_millis(5));
+    assert_eq!(store.ttl("pinned"), None);
+    assert_eq!(store.get("pinned"), Some(json!(2)));
+    assert_eq!(store.reap_expired().unwrap(), 0);
+    assert_eq!(store.scan_prefix("pin").unwrap(), vec!["pinned"]);
+}
+
+#[test]
+fn kv_reaper_removes_expired_keys_from_backing_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("store.json");
+    let caps = CapabilitySet {
+        read: Permission::allow([dir.path().to_path_buf()]),
+        write: Permission::allow([dir.path().to_path_buf()]),
+        ..CapabilitySet::sandbox()
+    };
+    let mut store = KvStore::open(&path, &caps).unwrap();
+    store
+        .set_with_ttl("short", json!(1), Duration::from_millis(20))
+        .unwrap();
+    store.set("kept", json!(2)).unwrap();
+    assert!(dir.path().join("store.json.ttl.json").exists());
+
+    let reopened = KvStore::open(&path, &caps).unwrap();
+    assert!(reopened.ttl("short").is_some());
+
+    std::thread::sleep(Duration::from_millis(40));
+    assert_eq!(store.reap_expired().unwrap(), 1);
+    let on_disk: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(on_disk, json!({"kept": 2}));
+    assert!(!dir.path().join("store.json.ttl.json").exists());
+}
+
+#[test]
+fn otter_kv_set_accepts_ttl_option() {
+    let dir = tempfile::tempdir().unwrap();
+    let main = dir.path().join("main.mjs");
+    std::fs::write(
+        &main,
+        r#"
+            import { openKv } from "otter:kv";
+            const store = openKv(":memory:");
+            store.set("a", 1, { ttl: 60000 });
+            store.set("b", 2, null);
+            if (store.get("a") !== 1 || store.get("b") !== 2) {
+                throw new Error("kv ttl set failed");
+            }
+            store.set("c", 3, { ttlMs: 60000 });
+            store.set("kept", 3, { ttl: 0 });
+            store.set("keptMs", 3, { ttlMs: 0 });
+            if (store.get("c") !== 3 || !store.has("kept") || !store.has("keptMs")) {
+                throw new Error("zero ttl key should never expire");
+            }
+            let caught = false;
+            try {
+                store.set("bad", 4, { ttl: -1 });
+            } catch {
+                caught = true;
+            }
+            if (!caught) {
+                throw new Error("negative ttl accepted");
+            }
+        "#,
+    )
+    .unwrap();
+
+    let mut runtime = Runtime::builder().with_otter_modules().build().unwrap();
+    runtime.run_module(&main).unwrap();
+}
+
+#[test]
+fn kv_ttl_beyond_the_clock_never_expires() {
+    let mut store = KvStore::memory();
+    store
+        .set_with_ttl("forever", json!(1), Duration::MAX)
+        .unwrap();
+    assert_eq!(store.get("forever"), Some(json!(1)));
+    assert_eq!(store.ttl("forever"), None);
+
+    let dir = tempfile::tempdir().unwrap();
+    let main = dir.path().join("main.mjs");
+    std::fs::write(
+        &main,
+        r#"
+            import { openKv } from "otter:kv";
+            const store = openKv(":memory:");
+            store.set("huge", 1, { ttl: Number.MAX_VALUE });
+            store.set("hugeMs", 2, { ttlMs: Number.MAX_VALUE });
+            if (store.get("huge") !== 1 || store.get("hugeMs") !== 2) {
+                throw new Error("huge ttl key missing");
+            }
+        "#,
+    )
+    .unwrap();
+
+    let mut runtime = Runtime::builder().with_otter_modules().build().unwrap();
+    runtime.run_module(&main).unwrap();
+}
+
+#[test]
+fn kv_secondary_index_tracks_updates_and_deletes() {
+    let mut store = KvStore::memory();
+    store
+        .set("u1", json!({"name": "Ada", "team": {"id": 1}}))
+        .unwrap();
+    store
+        .set("u2", json!({"name": "Lin", "team": {"id": 2}}))
+        .unwrap();
+    store.create_index("by_team", "$.team.id").unwrap();
+    store
+        .set("u3", json!({"name": "Bo", "team": {"id": 1}}))
+        .unwrap();
+    store.set("u4", json!("no team")).unwrap();
+
+    let keys = |store: &KvStore, team: serde_json::Value| {
+        store
+            .by_index("by_team", &team)
+            .unwrap()
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(keys(&store, json!(1)), vec!["u1", "u3"]);
+    assert_eq!(keys(&store, json!(1.0)), vec!["u1", "u3"]);
+
+    store
+        .set("u1", json!({"name": "Ada", "team": {"id": 2}}))
+        .unwrap();
+    assert_eq!(keys(&store, json!(1)), vec!["u3"]);
+    assert_eq!(keys(&store, json!(2)), vec!["u1", "u2"]);
+
+    assert!(store.delete("u2").unwrap());
+    assert_eq!(keys(&store, json!(2)), vec!["u1"]);
+    let (_, value) = &store.by_index("by_team", &json!(2)).unwrap()[0];
+    assert_eq!(value["name"], json!("Ada"));
+
+    store.create_index("by_team", "team.id").unwrap();
+    assert!(store.create_index("by_team", "name").is_err());
+    assert!(store.by_index("missing", &json!(1)).is_err());
+    store.clear().unwrap();
+    assert!(keys(&store, json!(1)).is_empty());
+}
+
+#[test]
+fn otter_kv_indexes_are_queryable_from_js() {
+    let dir = tempfile::tempdir().unwrap();
+    let main = dir.path().join("main.mjs");
+    std::fs::write(
+        &main,
+        r#"
+            import { openKv } from "otter:kv";
+            const store = openKv(":memory:");
+            store.createIndex("byCity", "$.city");
+            store.set("a", { city: "Oslo", n: 1 });
+            store.set("b", { city: "Rome", n: 2 });
+            store.set("c", { city: "Oslo", n: 3 });
+            store.delete("a");
+            const rows = store.byIndex("byCity", "Oslo");
+            if (rows.length !== 1 || rows[0].key !== "c" || rows[0].value.n !== 3) {
+                throw new Error("byIndex: " + JSON.stringify(rows));
+            }
+        "#,
+    )
+    .unwrap();
+
+    let mut runtime = Runtime::builder().with_otter_modules().build().unwrap();
+    runtime.run_module(&main).unwrap();
+}
+
+#[test]
+fn kv_compare_and_swap_only_writes_on_match() {
+    let mut store = KvStore::memory();
+    assert!(store.compare_and_swap("n", None, Some(json!(1))).unwrap());
+    assert!(!store.compare_and_swap("n", None, Some(json!(9))).unwrap());
+    assert!(
+        !store
+            .compare_and_swap("n", Some(json!(5)), Some(json!(9)))
+            .unwrap()
+    );
+    assert_eq!(store.get("n"), Some(json!(1)));
+
+    // JS numbers arrive as floats; canonical comparison still matches.
+    assert!(
+        store
+            .compare_and_swap("n", Some(json!(1.0)), Some(json!(2)))
+            .unwrap()
+    );
+    assert_eq!(store.get("n"), Some(json!(2)));
+    assert!(store.compare_and_swap("n", Some(json!(2)), None).unwrap());
+    assert!(!store.has("n"));
+
+    store
+        .set_with_ttl("t", json!("old"), Duration::from_secs(60))
+        .unwrap();
+    assert!(
+        store
+            .compare_and_swap("t", Some(json!("old")), Some(json!("new")))
+            .unwrap()
+    );
+    assert_eq!(store.ttl("t"), None);
+}
+
+#[test]
+fn kv_batch_applies_ops_in_order_with_one_flush() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("store.json");
+    let caps = CapabilitySet {
+        read: Permission::allow([dir.path().to_path_buf()]),
+        write: Permission::allow([dir.path().to_path_buf()]),
+        ..CapabilitySet::sandbox()
+    };
+    let mut store = KvStore::open(&path, &caps).unwrap();
+    store.set("stale", json!(0)).unwrap();
+    store.create_index("by_kind", "kind").unwrap();
+    store
+        .batch(vec![
+            KvOp::Set {
+                key: "a".into(),
+                value: json!({"kind": "x"}),
+            },
+            KvOp::Set {
+                key: "b".into(),
+                value: json!({"kind": "x"}),
+            },
+            KvOp::Delete {
+                key: "stale".into(),
+            },
+            KvOp::Set {
+                key: "b".into(),
+                value: json!({"kind": "y"}),
+            },
+        ])
+        .unwrap();
+    assert_eq!(store.keys(), vec!["a".to_string(), "b".to_string()]);
+    assert_eq!(store.by_index("by_kind", &json!("x")).unwrap().len(), 1);
+
+    let on_disk: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(on_disk, json!({"a": {"kind": "x"}, "b": {"kind": "y"}}));
+    assert!(
+        KvStore::open(&path, &caps)
+            .unwrap()
+            .compare_and_swap("a", Some(json!({"kind": "x"})), None)
+            .unwrap()
+    );
+}
+
+#[test]
+fn otter_kv_cas_and_batch_from_js() {
+    let dir = tempfile::tempdir().unwrap();
+    let main = dir.path().join("main.mjs");
+    std::fs::write(
+        &main,
+        r#"
+            import { openKv } from "otter:kv";
+            const store = openKv(":memory:");
+            const bump = () => {
+                for (;;) {
+                    const current = store.get("counter");
+                    const next = (current ?? 0) + 1;
+                    if (store.cas("counter", current, next)) return next;
+                }
+            };
+            bump();
+            bump();
+            if (store.get("counter") !== 2) {
+                throw new Error("cas counter: " + store.get("counter"));
+            }
+            if (store.cas("counter", 1, 10) !== false || store.get("counter") !== 2) {
+                throw new Error("stale cas must not write");
+            }
+            if (!store.cas("counter", 2, undefined) || store.has("counter")) {
+                throw new Error("cas with undefined must delete");
+            }
+
+            store.set("old", 1);
+            store.batch([
+                { type: "set", key: "a", value: { n: 1 } },
+                { type: "delete", key: "old" },
+                { type: "set", key: "b", value: [1, 2] },
+            ]);
+            if (store.keys().join() !== "a,b" || store.get("a").n !== 1) {
+                throw new Error("batch: " + store.keys().join());
+            }
+
+            let caught = false;
+            try {
+                store.batch([
+                    { type: "set", key: "c", value: 1 },
+                    { type: "rename", key: "a" },
+                ]);
+            } catch (err) {
+                caught = err instanceof TypeError;
+            }
+            if (!caught || store.has("c")) {
+                throw new Error("malformed batch must not apply any op");
+            }
+        "#,
+    )
+    .unwrap();
+
+    let mut runtime = Runtime::builder().with_otter_modules().build().unwrap();
+    runtime.run_module(&main).unwrap();
+}
+
+#[test]
+fn kv_prefix_and_range_scans_return_sorted_live_keys() {
+    let mut store = KvStore::memory();
+    for key in ["user:2", "user:10", "team:1", "user:1", "users", "user;"] {
+        store.set(key, json!(key)).unwrap();
+    }
+    store
+        .set_with_ttl("user:3", json!("gone"), Duration::from_millis(1))
+        .unwrap();
+    std::thread::sleep(Duration::from_millis(10));
+
+    assert_eq!(
+        store.scan_prefix("user:").unwrap(),
+        vec!["user:1", "user:10", "user:2"]
+    );
+    assert_eq!(store.scan_prefix("").unwrap(), store.keys());
+    assert!(store.scan_prefix("zzz").unwrap().is_empty());
+
+    assert_eq!(
+        store.scan_range("user:1", "user:2").unwrap(),
+        vec!["user:1", "user:10"]
+    );
+    assert_eq!(
+        store.scan_range("team:", "user;").unwrap(),
+        vec!["team:1", "user:1", "user:10", "user:2"]
+    );
+    assert!(store.scan_range("user:1", "user:1").unwrap().is_empty());
+    assert!(store.scan_range("b", "a").is_err());
+}
+
+#[test]
+fn otter_kv_list_scans_from_js() {
+    let dir = tempfile::tempdir().unwrap();
+    let main = dir.path().join("main.mjs");
+    std::fs::write(
+        &main,
+        r#"
+            import { openKv } from "otter:kv";
+            const store = openKv(":memory:");
+            for (const key of ["doc:b", "doc:a", "note:1", "doc:c"]) {
+                store.set(key, 1);
+            }
+            const check = (label, actual, expected) => {
+                if (actual.join() !== expected) {
+                    throw new Error(label + ": " + actual.join());
+                }
+            };
+            check("prefix", store.list({ prefix: "doc:" }), "doc:a,doc:b,doc:c");
+            check("range", store.list({ start: "doc:b", end: "note:1" }), "doc:b,doc:c");
+            check("empty prefix", store.list({ prefix: "" }), store.keys().join());
+            check("no options", store.list(), store.keys().join());
+
+            for (const bad of [{ prefix: "doc:", start: "a", end: "b" }, { start: "a" }, { prefix: 1 }]) {
+                let caught = false;
+                try {
+                    store.list(bad);
+                } catch (err) {
+                    caught = err instanceof TypeError;
+                }
+                if (!caught) {
+                    throw new Error("expected TypeError for " + JSON.stringify(bad));
+                }
+            }
+        "#,
+    )
+    .unwrap();
+
+    let mut runtime = Runtime::builder().with_otter_modules().build().unwrap();
+    runtime.run_module(&main).unwrap();
+}
@@ -196,28 +196,12 @@ if (!Buffer) {
     return n;
   }
 
+  // Shared native UTF-8 decoder (also behind `TextDecoder` and
+  // `StringDecoder`): invalid sequences become U+FFFD per maximal subpart.
+  const decodeUtf8 = require('__utf8native');
   function utf8Slice(buf, start, end) {
-    let res = '';
-    let i = start;
-    while (i < end) {
-      const b0 = buf[i];
-      let cp; let size;
-      if (b0 < 0x80) { cp = b0; size = 1; }
-      else if ((b0 & 0xe0) === 0xc0) { cp = b0 & 0x1f; size = 2; }
-      else if ((b0 & 0xf0) === 0xe0) { cp = b0 & 0x0f; size = 3; }
-      else if ((b0 & 0xf8) === 0xf0) { cp = b0 & 0x07; size = 4; }
-      else { res += '�'; i += 1; continue; }
-      if (i + size > end) { res += '�'; break; }
-      for (let j = 1; j < size; j++) cp = (cp << 6) | (buf[i + j] & 0x3f);
-      if (cp > 0xffff) {
-        cp -= 0x10000;
-        res += String.fromCharCode(0xd800 + (cp >> 10), 0xdc00 + (cp & 0x3ff));
-      } else {
-        res += String.fromCharCode(cp);
-      }
-      i += size;
-    }
-    return res;
+    if (end <= start) return '';
+    return decodeUtf8(new Uint8Array(buf.buffer, buf.byteOffset + start, end - start), false, false)[0];
   }
 
   const hexChars = '0123456789abcdef';
@@ -255,6 +239,9 @@ if (!Buffer) {
       else if (ch === '-') s += '+';
       else if (ch === '_') s += '/';
     }
+    // A lone trailing sextet carries no whole byte; Node drops it where a
+    // strict `atob` would reject the whole input.
+    if (s.length % 4 === 1) s = s.slice(0, -1);
     const bin = (typeof atob === 'function') ? atob(s) : '';
     const out = new Array(bin.length);
     for (let i = 0; i < bin.length; i++) out[i] = bin.charCodeAt(i) & 0xff;
@@ -702,6 +689,8 @@ if (!Buffer) {
       `The "${name}" argument must be ${expected}.${argTypeReceived(value)}`);
   }
 
+  const nativeSet = Uint8Array.prototype.set;
+  const nativeSubarray = Uint8Array.prototype.subarray;
   const nativeIndexOf = Uint8Array.prototype.indexOf;
   const nativeLastIndexOf = Uint8Array.prototype.lastIndexOf;
 
@@ -1074,14 +1063,16 @@ if (!Buffer) {
           throw outOfRange('length', `>= 0 && <= ${kMaxLength}`, totalLength);
         }
       }
+      // The result is zero-initialized, so a `totalLength` past the sum
+      // leaves a zero-filled tail; a shorter one truncates the last copy.
+      // Each source lands with one native block copy.
       const out = new Buffer(totalLength);
       let pos = 0;
       for (const b of list) {
         if (pos >= totalLength) break;
-        const blen = b.byteLength;
-        const n = Math.min(blen, totalLength - pos);
-        for (let i = 0; i < n; i++) out[pos + i] = b[i];
-        pos += blen;
+        const n = Math.min(b.byteLength, totalLength - pos);
+        nativeSet.call(out, n === b.byteLength ? b : nativeSubarray.call(b, 0, n), pos);
+        pos += n;
       }
       return out;
     },
@@ -3,6 +3,10 @@
 // (`__cpnative.spawnSyncRaw`). The async surface runs the same primitive and
 // replays its output through EventEmitter/stream, which is sufficient for the
 // common "spawn a child, collect its output, observe exit" pattern.
+// `timeout` and `maxBuffer` are enforced natively: the child is killed and the
+// result carries `killed` (`ETIMEDOUT` / `ERR_CHILD_PROCESS_STDIO_MAXBUFFER`).
+
+const DEFAULT_MAX_BUFFER = 1024 * 1024;
 
 const native = require('__cpnative');
 const { Buffer } = require('buffer');
@@ -24,6 +28,8 @@ function rawSpawn(command, args, options) {
     cwd: options.cwd ? String(options.cwd) : undefined,
     input,
     env: options.env,
+    timeout: typeof options.timeout === 'number' ? options.timeout : undefined,
+    maxBuffer: typeof options.maxBuffer === 'number' ? options.maxBuffer : undefined,
   });
 }
 
@@ -37,6 +43,18 @@ function buildError(raw, command) {
   return e;
 }
 
+// Error for a child the native core killed (timeout or maxBuffer overflow).
+function killError(raw, command, args) {
+  const e = raw.killed === 'ERR_CHILD_PROCESS_STDIO_MAXBUFFER'
+    ? new RangeError(raw.error)
+    : new Error(raw.error);
+  e.code = raw.killed;
+  e.syscall = `spawnSync ${command}`;
+  e.path = command;
+  e.spawnargs = args;
+  return e;
+}
+
 function spawnSync(command, args, options) {
   const n = normalizeArgs(command, args, options);
   const raw = rawSpawn(n.command, n.args, n.options);
@@ -45,8 +63,8 @@ function spawnSync(command, args, options) {
     const b = Buffer.from(s, 'latin1');
     return enc && enc !== 'buffer' ? b.toString(enc) : b;
   };
-  const stdout = raw.error ? null : decode(raw.stdout);
-  const stderr = raw.error ? null : decode(raw.stderr);
+  const stdout = raw.pid === null ? null : decode(raw.stdout);
+  const stderr = raw.pid === null ? null : decode(raw.stderr);
   const result = {
     pid: raw.pid,
     output: [null, stdout, stderr],
@@ -55,7 +73,8 @@ function spawnSync(command, args, options) {
     status: raw.status,
     signal: raw.signal,
   };
-  if (raw.error) result.error = buildError(raw, n.command);
+  if (raw.killed) result.error = killError(raw, n.command, n.args);
+  else if (raw.error) result.error = buildError(raw, n.command);
   return result;
 }
 
@@ -108,7 +127,7 @@ class ChildProcess extends EventEmitter {
     setTimeout(() => {
       const raw = rawSpawn(command, args, options);
       this.pid = raw.pid;
-      if (raw.error) {
+      if (raw.error && !raw.killed) {
         this.emit('error', buildError(raw, command));
         this.stdout.push(null);
         this.stderr.push(null);
@@ -121,6 +140,10 @@ class ChildProcess extends EventEmitter {
       this.stderr.push(null);
       this.exitCode = raw.status;
       this.signalCode = raw.signal;
+      if (raw.killed) {
+        this.killed = true;
+        this._killReason = raw;
+      }
       this.emit('exit', raw.status, raw.signal);
       setTimeout(() => this.emit('close', raw.status, raw.signal));
     }, 0);
@@ -134,7 +157,7 @@ function spawn(command, args, options) {
   return cp;
 }
 
-function collect(cp, options, cb) {
+function collect(cp, cmd, options, cb) {
   const enc = options.encoding === undefined ? 'utf8' : options.encoding;
   const out = []; const err = [];
   cp.stdout.on('data', (d) => out.push(Buffer.isBuffer(d) ? d : Buffer.from(d)));
@@ -143,9 +166,15 @@ function collect(cp, options, cb) {
   cp.on('close', (status, signal) => {
     if (!cb) return;
     const stdout = decodeAll(out, enc); const stderr = decodeAll(err, enc);
-    if (status !== 0 && status !== null) {
-      const e = new Error(`Command failed`);
-      e.code = status; e.killed = false; e.signal = signal;
+    const reason = cp._killReason;
+    if (reason && reason.killed === 'ERR_CHILD_PROCESS_STDIO_MAXBUFFER') {
+      const e = new RangeError(reason.error);
+      e.code = reason.killed;
+      e.cmd = cmd;
+      cb(e, stdout, stderr);
+    } else if (status !== 0) {
+      const e = new Error(`Command failed: ${cmd}\n${stderr}`);
+      e.code = status; e.killed = cp.killed; e.signal = signal; e.cmd = cmd;
       cb(e, stdout, stderr);
     } else {
       cb(null, stdout, stderr);
@@ -162,8 +191,11 @@ function execFile(file, args, options, cb) {
   if (typeof args === 'function') { cb = args; args = []; options = {}; }
   else if (typeof options === 'function') { cb = options; options = {}; }
   const n = normalizeArgs(file, args, options || {});
-  const cp = spawn(n.command, n.args, n.options);
-  collect(cp, n.options, cb);
+  const opts = { ...n.options };
+  if (typeof opts.maxBuffer !== 'number') opts.maxBuffer = DEFAULT_MAX_BUFFER;
+  if (opts.maxBuffer === Infinity) opts.maxBuffer = undefined;
+  const cp = spawn(n.command, n.args, opts);
+  collect(cp, [n.command, ...n.args].join(' '), opts, cb);
   return cp;
 }
 
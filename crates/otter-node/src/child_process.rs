@@ -5,13 +5,25 @@
 //! `child_process.js`. Process output crosses the boundary as latin1 strings
 //! (the same bridge the `fs` core uses), so the JS layer can present Buffers.
 //!
+//! # Contents
+//! - [`exec_file`] runs a binary directly (no shell) and buffers its output,
+//!   enforcing [`ExecFileOptions::timeout`] and
+//!   [`ExecFileOptions::max_buffer`]; `spawnSyncRaw` is its JS binding.
+//! - [`ChildProcessError`] covers children that never started.
+//!
 //! # Invariants
 //! - The `run` (subprocess) capability is checked before any process starts.
+//! - A timed-out child gets `SIGTERM`, then `SIGKILL` after [`KILL_GRACE`]; a
+//!   child that overflows `maxBuffer` is killed the same way and its output is
+//!   truncated to the limit.
 //! - Explicit child environments are enumerated through JavaScript internal
 //!   methods, so filtered `process.env` proxies cannot leak hidden host values.
 //! - No VM state is retained across the spawn.
 
-use std::process::{Command, Stdio};
+use std::io::Read;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
 
 use otter_runtime::{
     CapabilitySet, RuntimeLocal as Local, RuntimeNativeCtx as NativeCtx,
@@ -22,6 +34,11 @@ use otter_vm::object;
 
 const SHIM: &str = include_str!("child_process.js");
 
+/// How long a timed-out child has to exit after `SIGTERM` before `SIGKILL`.
+pub const KILL_GRACE: Duration = Duration::from_millis(500);
+/// Poll interval while waiting on a child whose pipes are already closed.
+const WAIT_POLL: Duration = Duration::from_millis(5);
+
 /// CommonJS export: the `child_process` namespace built by `child_process.js`.
 pub fn child_process_cjs_value<'scope>(
     scope: &mut NativeScope<'scope, '_>,
@@ -94,6 +111,11 @@ fn opt_string(ctx: &mut NativeCtx<'_>, opts: Option<Value>, key: &str) -> Option
     }
 }
 
+fn opt_number(ctx: &mut NativeCtx<'_>, opts: Option<Value>, key: &str) -> Option<f64> {
+    let obj = opts?.as_object()?;
+    object::get(obj, ctx.heap(), key)?.as_f64()
+}
+
 fn opt_env(
     ctx: &mut NativeCtx<'_>,
     opts: Option<Value>,
@@ -151,13 +173,6 @@ fn spawn_sync_raw(
     if command.is_empty() {
         return Err(crate::type_error("child_process", "command is required"));
     }
-    if !caps.run.matches(&command) {
-        return Err(NativeError::Coded {
-            kind: otter_vm::ErrorKind::Error,
-            code: "EACCES",
-            message: format!("EACCES: subprocess capability denied for '{command}'"),
-        });
-    }
 
     let mut argv = args
         .get(1)
@@ -165,62 +180,45 @@ fn spawn_sync_raw(
         .map(|v| read_string_array(ctx, v))
         .unwrap_or_default();
     let opts = args.get(2).copied();
-    let cwd = opt_string(ctx, opts, "cwd");
-    let input = opt_string(ctx, opts, "input");
-    let env = opt_env(ctx, opts)?;
-    if should_propagate_allow_all(ctx, &command, caps) {
-        argv.insert(0, "--allow-all".to_string());
-    }
-
-    let mut cmd = Command::new(&command);
-    cmd.args(&argv);
-    if let Some(dir) = &cwd {
-        cmd.current_dir(dir);
-    }
-    if let Some(env) = env {
-        cmd.env_clear();
-        cmd.envs(env);
-    }
-    cmd.stdin(if input.is_some() {
-        Stdio::piped()
-    } else {
-        Stdio::null()
-    });
-    cmd.stdout(Stdio::piped());
-    cmd.stderr(Stdio::piped());
-
-    let spawn_result = cmd.spawn();
-    let mut child = match spawn_result {
-        Ok(child) => child,
-        Err(err) => return spawn_error_result(ctx, &command, &err),
+    let options = ExecFileOptions {
+        cwd: opt_string(ctx, opts, "cwd"),
+        input: opt_string(ctx, opts, "input").map(|s| latin1_to_bytes(&s)),
+        env: opt_env(ctx, opts)?,
+        timeout: opt_number(ctx, opts, "timeout")
+            .filter(|ms| *ms > 0.0)
+            .map(|ms| Duration::from_millis(ms.ceil() as u64)),
+        max_buffer: opt_number(ctx, opts, "maxBuffer")
+            .filter(|n| n.is_finite() && *n >= 0.0)
+            .map(|n| n as usize),
     };
-    let pid = child.id();
-
-    if let (Some(input), Some(mut stdin)) = (&input, child.stdin.take()) {
-        use std::io::Write;
-        let _ = stdin.write_all(&latin1_to_bytes(input));
+    if caps.run.matches(&command) && should_propagate_allow_all(ctx, &command, caps) {
+        argv.insert(0, "--allow-all".to_string());
     }
 
-    let output = match child.wait_with_output() {
-        Ok(output) => output,
-        Err(err) => return spawn_error_result(ctx, &command, &err),
+    let result = match exec_file(&command, &argv, &options, caps) {
+        Ok(result) => result,
+        Err(err @ ChildProcessError::PermissionDenied { .. }) => {
+            return Err(NativeError::Coded {
+                kind: otter_vm::ErrorKind::Error,
+                code: err.code(),
+                message: err.to_string(),
+            });
+        }
+        Err(err) => return spawn_error_result(ctx, &err),
     };
-
-    let status_code = output.status.code();
-    let signal = exit_signal(&output.status);
-    let stdout = bytes_to_latin1(&output.stdout);
-    let stderr = bytes_to_latin1(&output.stderr);
+    let stdout = bytes_to_latin1(&result.stdout);
+    let stderr = bytes_to_latin1(&result.stderr);
 
     ctx.scope(|mut scope| {
         let object = scope.object()?;
-        set_number(&mut scope, object, "pid", f64::from(pid))?;
-        match status_code {
+        set_number(&mut scope, object, "pid", f64::from(result.pid))?;
+        match result.status {
             Some(code) => set_number(&mut scope, object, "status", f64::from(code))?,
             None => set_null(&mut scope, object, "status")?,
         }
-        match signal {
+        match &result.signal {
             Some(signal) => {
-                let signal = scope.string(&signal)?;
+                let signal = scope.string(signal)?;
                 scope.set(object, "signal", signal)?;
             }
             None => set_null(&mut scope, object, "signal")?,
@@ -229,22 +227,28 @@ fn spawn_sync_raw(
         scope.set(object, "stdout", stdout)?;
         let stderr = scope.string(&stderr)?;
         scope.set(object, "stderr", stderr)?;
-        set_null(&mut scope, object, "error")?;
+        match result.killed {
+            Some(reason) => {
+                let killed = scope.string(reason.code())?;
+                scope.set(object, "killed", killed)?;
+                let error = scope.string(&reason.message(&command))?;
+                scope.set(object, "error", error)?;
+            }
+            None => {
+                set_null(&mut scope, object, "killed")?;
+                set_null(&mut scope, object, "error")?;
+            }
+        }
         Ok(scope.finish(object))
     })
 }
 
 fn spawn_error_result(
     ctx: &mut NativeCtx<'_>,
-    command: &str,
-    err: &std::io::Error,
+    err: &ChildProcessError,
 ) -> Result<Value, NativeError> {
-    let code = if err.kind() == std::io::ErrorKind::NotFound {
-        "ENOENT"
-    } else {
-        "EIO"
-    };
-    let message = format!("{code}: spawn {command} {err}");
+    let code = err.code();
+    let message = err.to_string();
     ctx.scope(|mut scope| {
         let object = scope.object()?;
         set_null(&mut scope, object, "pid")?;
@@ -254,6 +258,7 @@ fn spawn_error_result(
         scope.set(object, "stdout", stdout)?;
         let stderr = scope.string("")?;
         scope.set(object, "stderr", stderr)?;
+        set_null(&mut scope, object, "killed")?;
         let error = scope.string(&message)?;
         scope.set(object, "error", error)?;
         let error_code = scope.string(code)?;
@@ -262,6 +267,274 @@ fn spawn_error_result(
     })
 }
 
+/// Errors that stop a child process from running at all.
+#[derive(Debug, thiserror::Error)]
+pub enum ChildProcessError {
+    /// The `run` capability does not cover the command.
+    #[error("EACCES: subprocess capability denied for '{command}'")]
+    PermissionDenied {
+        /// Command that was rejected.
+        command: String,
+    },
+    /// The OS refused to start the process.
+    #[error("{code}: spawn {command} {message}")]
+    Spawn {
+        /// Command that failed to start.
+        command: String,
+        /// Node error code (`ENOENT`, `EIO`).
+        code: &'static str,
+        /// Underlying OS error message.
+        message: String,
+    },
+}
+
+impl ChildProcessError {
+    /// Node-compatible `err.code` for this error.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::PermissionDenied { .. } => "EACCES",
+            Self::Spawn { code, .. } => code,
+        }
+    }
+}
+
+/// Options for [`exec_file`], mirroring the `execFile`/`spawnSync` option bag.
+#[derive(Debug, Clone, Default)]
+pub struct ExecFileOptions {
+    /// Working directory for the child.
+    pub cwd: Option<String>,
+    /// Bytes written to the child's stdin before it is closed.
+    pub input: Option<Vec<u8>>,
+    /// Replacement environment; `None` inherits the host environment.
+    pub env: Option<Vec<(String, String)>>,
+    /// Wall-clock limit; the child gets `SIGTERM`, then `SIGKILL` after
+    /// [`KILL_GRACE`].
+    pub timeout: Option<Duration>,
+    /// Largest number of bytes kept per output stream; exceeding it kills
+    /// the child.
+    pub max_buffer: Option<usize>,
+}
+
+/// One of the child's captured output streams.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StdioStream {
+    /// Standard output.
+    Stdout,
+    /// Standard error.
+    Stderr,
+}
+
+/// Why [`exec_file`] killed the child before it exited on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KillReason {
+    /// The `timeout` elapsed.
+    Timeout,
+    /// A stream produced more than `max_buffer` bytes.
+    MaxBuffer(StdioStream),
+}
+
+impl KillReason {
+    /// Node error code reported for this kill.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Timeout => "ETIMEDOUT",
+            Self::MaxBuffer(_) => "ERR_CHILD_PROCESS_STDIO_MAXBUFFER",
+        }
+    }
+
+    fn message(self, command: &str) -> String {
+        match self {
+            Self::Timeout => format!("spawnSync {command} ETIMEDOUT"),
+            Self::MaxBuffer(StdioStream::Stdout) => "stdout maxBuffer length exceeded".to_string(),
+            Self::MaxBuffer(StdioStream::Stderr) => "stderr maxBuffer length exceeded".to_string(),
+        }
+    }
+}
+
+/// Outcome of a child that started: exit status plus captured output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpawnSyncResult {
+    /// OS process id.
+    pub pid: u32,
+    /// Exit code, or `None` when a signal ended the child.
+    pub status: Option<i32>,
+    /// Terminating signal name (`SIGTERM`, …).
+    pub signal: Option<String>,
+    /// Captured stdout, truncated to `max_buffer`.
+    pub stdout: Vec<u8>,
+    /// Captured stderr, truncated to `max_buffer`.
+    pub stderr: Vec<u8>,
+    /// Set when the child was killed for a timeout or output overflow.
+    pub killed: Option<KillReason>,
+}
+
+/// Run `file` directly with `args` — no shell, so arguments are never
+/// re-parsed — and collect its output (`child_process.execFile` semantics).
+pub fn exec_file(
+    file: &str,
+    args: &[String],
+    options: &ExecFileOptions,
+    caps: &CapabilitySet,
+) -> Result<SpawnSyncResult, ChildProcessError> {
+    if !caps.run.matches(file) {
+        return Err(ChildProcessError::PermissionDenied {
+            command: file.to_string(),
+        });
+    }
+
+    let mut cmd = Command::new(file);
+    cmd.args(args);
+    if let Some(dir) = &options.cwd {
+        cmd.current_dir(dir);
+    }
+    if let Some(env) = &options.env {
+        cmd.env_clear();
+        cmd.envs(env.iter().map(|(k, v)| (k, v)));
+    }
+    cmd.stdin(if options.input.is_some() {
+        Stdio::piped()
+    } else {
+        Stdio::null()
+    });
+    cmd.stdout(Stdio::piped());
+    cmd.stderr(Stdio::piped());
+
+    let mut child = cmd.spawn().map_err(|err| ChildProcessError::Spawn {
+        command: file.to_string(),
+        code: if err.kind() == std::io::ErrorKind::NotFound {
+            "ENOENT"
+        } else {
+            "EIO"
+        },
+        message: err.to_string(),
+    })?;
+    let pid = child.id();
+
+    if let (Some(input), Some(mut stdin)) = (options.input.clone(), child.stdin.take()) {
+        // A separate writer keeps a child that fills its stdout before
+        // draining stdin from deadlocking against us.
+        std::thread::spawn(move || {
+            use std::io::Write;
+            let _ = stdin.write_all(&input);
+        });
+    }
+
+    let (tx, rx) = mpsc::channel();
+    if let Some(stdout) = child.stdout.take() {
+        pump_output(stdout, StdioStream::Stdout, tx.clone());
+    }
+    if let Some(stderr) = child.stderr.take() {
+        pump_output(stderr, StdioStream::Stderr, tx);
+    }
+
+    // A timeout past what the clock can represent (`timeout: Infinity`)
+    // never fires.
+    let deadline = options
+        .timeout
+        .and_then(|timeout| Instant::now().checked_add(timeout));
+    let mut stdout = Vec::new();
+    let mut stderr = Vec::new();
+    let mut killed = None;
+    let mut open_streams = 2;
+    while open_streams > 0 && killed.is_none() {
+        let next = match deadline {
+            Some(deadline) => rx.recv_timeout(deadline.saturating_duration_since(Instant::now())),
+            None => rx.recv().map_err(|_| mpsc::RecvTimeoutError::Disconnected),
+        };
+        match next {
+            Ok((stream, Some(bytes))) => {
+                let buffer = match stream {
+                    StdioStream::Stdout => &mut stdout,
+                    StdioStream::Stderr => &mut stderr,
+                };
+                buffer.extend_from_slice(&bytes);
+                if let Some(max) = options.max_buffer
+                    && buffer.len() > max
+                {
+                    buffer.truncate(max);
+                    killed = Some(KillReason::MaxBuffer(stream));
+                }
+            }
+            Ok((_, None)) => open_streams -= 1,
+            Err(mpsc::RecvTimeoutError::Timeout) => killed = Some(KillReason::Timeout),
+            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+        }
+    }
+
+    // Both pipes closed, but the child may still be running.
+    let status = loop {
+        if killed.is_some() {
+            break terminate(&mut child);
+        }
+        match child.try_wait() {
+            Ok(Some(status)) => break Ok(status),
+            Ok(None) if deadline.is_some_and(|d| Instant::now() >= d) => {
+                killed = Some(KillReason::Timeout);
+            }
+            Ok(None) => std::thread::sleep(WAIT_POLL),
+            Err(err) => break Err(err),
+        }
+    };
+    let status = status.map_err(|err| ChildProcessError::Spawn {
+        command: file.to_string(),
+        code: "EIO",
+        message: err.to_string(),
+    })?;
+
+    Ok(SpawnSyncResult {
+        pid,
+        status: status.code(),
+        signal: exit_signal(&status),
+        stdout,
+        stderr,
+        killed,
+    })
+}
+
+/// Read `reader` to EOF on its own thread, forwarding chunks tagged with
+/// `stream`; `None` marks EOF.
+fn pump_output(
+    mut reader: impl Read + Send + 'static,
+    stream: StdioStream,
+    tx: mpsc::Sender<(StdioStream, Option<Vec<u8>>)>,
+) {
+    std::thread::spawn(move || {
+        let mut buf = [0u8; 8192];
+        loop {
+            match reader.read(&mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(n) => {
+                    if tx.send((stream, Some(buf[..n].to_vec()))).is_err() {
+                        return;
+                    }
+                }
+            }
+        }
+        let _ = tx.send((stream, None));
+    });
+}
+
+/// `SIGTERM`, then `SIGKILL` if the child outlives [`KILL_GRACE`].
+fn terminate(child: &mut Child) -> std::io::Result<ExitStatus> {
+    #[cfg(unix)]
+    {
+        // SAFETY: `kill` has no memory-safety preconditions; the pid is our
+        // own unreaped child, so it cannot have been recycled.
+        unsafe {
+            libc::kill(child.id() as libc::pid_t, libc::SIGTERM);
+        }
+        let grace = Instant::now() + KILL_GRACE;
+        while Instant::now() < grace {
+            if let Some(status) = child.try_wait()? {
+                return Ok(status);
+            }
+            std::thread::sleep(WAIT_POLL);
+        }
+    }
+    child.kill()?;
+    child.wait()
+}
+
 #[cfg(unix)]
 fn exit_signal(status: &std::process::ExitStatus) -> Option<String> {
     use std::os::unix::process::ExitStatusExt;
@@ -16,19 +16,36 @@ function toLatin1(data, enc) {
   return Buffer.from(String(data), 'utf8').toString('latin1');
 }
 
+function hashFinalized() {
+  const err = new Error('Digest already called');
+  err.code = 'ERR_CRYPTO_HASH_FINALIZED';
+  return err;
+}
+
 // Hash/Hmac are legacy Transform-ish streams in Node: write/end feed data and
 // read() returns the digest. We implement the synchronous subset (update/digest
-// + write/end/read + pipe) on EventEmitter so both styles work.
+// + write/end/read + pipe) on EventEmitter so both styles work. A Hash feeds
+// each chunk straight into a native incremental state (hashOpen/hashUpdate/
+// hashFinal), so unsupported algorithms throw from createHash and long inputs
+// are never buffered in JS.
+const kHandle = Symbol('kHandle');
+
 class Hash extends EventEmitter {
-  constructor(algorithm) { super(); this._algo = String(algorithm); this._chunks = []; this._done = false; this._digest = null; }
+  constructor(algorithm, options) {
+    super();
+    this._algo = String(algorithm);
+    this._handle = options && options[kHandle] !== undefined ? options[kHandle] : native.hashOpen(this._algo);
+    this._done = false;
+    this._digest = null;
+  }
   update(data, inputEncoding) {
-    if (this._done) throw new Error('Digest already called');
-    this._chunks.push(toLatin1(data, inputEncoding));
+    if (this._done) throw hashFinalized();
+    native.hashUpdate(this._handle, toLatin1(data, inputEncoding));
     return this;
   }
-  _compute() { return Buffer.from(native.hashDigest(this._algo, this._chunks.join('')), 'latin1'); }
+  _compute() { return Buffer.from(native.hashFinal(this._handle), 'latin1'); }
   digest(encoding) {
-    if (this._done) throw new Error('Digest already called');
+    if (this._done) throw hashFinalized();
     this._done = true;
     const buf = this._compute();
     return encoding && encoding !== 'buffer' ? buf.toString(encoding) : buf;
@@ -47,7 +64,10 @@ class Hash extends EventEmitter {
   read() { if (!this._done) { this._done = true; this._digest = this._compute(); } const d = this._digest; this._digest = null; return d; }
   pipe(dest) { this.on('end', () => { if (this._digest) dest.write(this._digest); dest.end(); }); return dest; }
   setEncoding() { return this; }
-  copy() { const h = new Hash(this._algo); h._chunks = this._chunks.slice(); return h; }
+  copy() {
+    if (this._done) throw hashFinalized();
+    return new Hash(this._algo, { [kHandle]: native.hashCopy(this._handle) });
+  }
 }
 
 class Hmac extends EventEmitter {
@@ -60,13 +80,13 @@ class Hmac extends EventEmitter {
     this._digest = null;
   }
   update(data, inputEncoding) {
-    if (this._done) throw new Error('Digest already called');
+    if (this._done) throw hashFinalized();
     this._chunks.push(toLatin1(data, inputEncoding));
     return this;
   }
   _compute() { return Buffer.from(native.hmacDigest(this._algo, this._key, this._chunks.join('')), 'latin1'); }
   digest(encoding) {
-    if (this._done) throw new Error('Digest already called');
+    if (this._done) throw hashFinalized();
     this._done = true;
     const buf = this._compute();
     return encoding && encoding !== 'buffer' ? buf.toString(encoding) : buf;
@@ -122,7 +142,35 @@ function randomFill(buffer, offset, size, cb) {
   }, 0);
 }
 
+const RANDOM_VALUES_QUOTA = 65536;
+
+function domException(message, name) {
+  if (typeof DOMException === 'function') return new DOMException(message, name);
+  const err = new Error(message);
+  err.name = name;
+  return err;
+}
+
+function isIntegerView(value) {
+  return value instanceof Int8Array || value instanceof Uint8Array ||
+    value instanceof Uint8ClampedArray || value instanceof Int16Array ||
+    value instanceof Uint16Array || value instanceof Int32Array ||
+    value instanceof Uint32Array || value instanceof BigInt64Array ||
+    value instanceof BigUint64Array;
+}
+
+// Web Crypto semantics: integer TypedArrays only, at most 65536 bytes, filled
+// in place from the CSPRNG and returned.
 function getRandomValues(typedArray) {
+  if (!isIntegerView(typedArray)) {
+    throw domException(
+      'The data argument must be an integer-type TypedArray', 'TypeMismatchError');
+  }
+  if (typedArray.byteLength > RANDOM_VALUES_QUOTA) {
+    throw domException(
+      `The ArrayBufferView's byte length (${typedArray.byteLength}) exceeds the number of bytes of entropy available via this API (${RANDOM_VALUES_QUOTA})`,
+      'QuotaExceededError');
+  }
   const view = new Uint8Array(typedArray.buffer, typedArray.byteOffset, typedArray.byteLength);
   const rnd = Buffer.from(native.randomBytes(view.length), 'latin1');
   for (let i = 0; i < view.length; i++) view[i] = rnd[i];
@@ -132,36 +180,15 @@ function getRandomValues(typedArray) {
 function randomInt(min, max, cb) {
   if (typeof max === 'function') { cb = max; max = min; min = 0; }
   if (max === undefined) { max = min; min = 0; }
-  const range = max - min;
-  if (range <= 0 || !Number.isSafeInteger(range)) {
-    const err = new RangeError('The value of "max" is out of range.');
-    err.code = 'ERR_OUT_OF_RANGE';
-    if (cb) { setTimeout(() => cb(err), 0); return undefined; }
-    throw err;
-  }
-  const compute = () => {
-    const bytes = Math.ceil(Math.log2(range) / 8) || 1;
-    let value;
-    do {
-      const rnd = Buffer.from(native.randomBytes(bytes + 2), 'latin1');
-      value = 0;
-      for (let i = 0; i < bytes + 2; i++) value = value * 256 + rnd[i];
-    } while (value >= Math.floor((256 ** (bytes + 2)) / range) * range);
-    return min + (value % range);
-  };
-  if (typeof cb === 'function') { setTimeout(() => cb(null, compute()), 0); return undefined; }
-  return compute();
+  // Bounds are validated synchronously even when a callback is given.
+  const value = native.randomInt(min, max);
+  if (typeof cb === 'function') { setTimeout(() => cb(null, value), 0); return undefined; }
+  return value;
 }
 
-function randomUUID() {
-  const b = Buffer.from(native.randomBytes(16), 'latin1');
-  b[6] = (b[6] & 0x0f) | 0x40;
-  b[8] = (b[8] & 0x3f) | 0x80;
-  const h = b.toString('hex');
-  return `${h.slice(0, 8)}-${h.slice(8, 12)}-${h.slice(12, 16)}-${h.slice(16, 20)}-${h.slice(20)}`;
-}
+function randomUUID() { return native.randomUUID(); }
 
-function getHashes() { return ['sha1', 'sha224', 'sha256', 'sha384', 'sha512', 'md5']; }
+function getHashes() { return native.getHashes(); }
 function getCiphers() { return []; }
 function getFips() { return 0; }
 function setFips() {}
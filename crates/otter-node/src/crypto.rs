@@ -1,19 +1,268 @@
-//! `node:crypto` native core — hashing (SHA-2 family), HMAC, and CSPRNG bytes.
+//! `node:crypto` native core — hashing (SHA-2 family), HMAC, and CSPRNG bytes,
+//! UUIDs, and integers.
 //!
 //! Public-key / cipher operations are out of scope for this slice; the focus is
 //! the high-frequency `createHash`/`createHmac`/`randomBytes` surface. Bytes
 //! cross the native/JS boundary as latin1 strings (the same bridge `fs` uses).
 //! The CommonJS namespace is assembled inside one rooted native scope.
+//!
+//! `createHash` is incremental: each `crypto.Hash` holds a host object that
+//! owns a native [`Hash`] state, fed chunk by chunk and consumed on `digest`.
+//! The state is GC-owned, so a hash that is never digested is freed with its
+//! `crypto.Hash`.
+//! [`HashAlgorithm::ALL`] is the single source for `crypto.getHashes()`.
 
 use otter_runtime::{
     CapabilitySet, RuntimeLocal as Local, RuntimeNativeCtx as NativeCtx,
     RuntimeNativeError as NativeError, RuntimeNativeScope as NativeScope, RuntimeTaskSpawner,
     RuntimeValue as Value, runtime_arg_to_string,
 };
-use sha2::{Digest, Sha224, Sha256, Sha384, Sha512};
+use sha2::{Digest, Sha224, Sha256, Sha384, Sha512, Sha512_224, Sha512_256};
 
 const SHIM: &str = include_str!("crypto.js");
 
+/// Largest `max - min` span [`random_int`] accepts, matching Node (2^48 - 1).
+pub const RANDOM_INT_MAX_RANGE: i64 = 0xFFFF_FFFF_FFFF;
+
+/// Largest integer a JS number represents exactly (2^53 - 1).
+const MAX_SAFE_INTEGER: i64 = (1 << 53) - 1;
+
+/// Digest algorithms accepted by `createHash`, `createHmac` and `hash`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    /// MD5.
+    Md5,
+    /// SHA-1.
+    Sha1,
+    /// SHA-224.
+    Sha224,
+    /// SHA-256.
+    Sha256,
+    /// SHA-384.
+    Sha384,
+    /// SHA-512.
+    Sha512,
+    /// SHA-512/224.
+    Sha512_224,
+    /// SHA-512/256.
+    Sha512_256,
+}
+
+impl HashAlgorithm {
+    /// Every supported algorithm, in `crypto.getHashes()` order.
+    pub const ALL: [Self; 8] = [
+        Self::Md5,
+        Self::Sha1,
+        Self::Sha224,
+        Self::Sha256,
+        Self::Sha384,
+        Self::Sha512,
+        Self::Sha512_224,
+        Self::Sha512_256,
+    ];
+
+    /// Parse a Node algorithm name; case and dashes are ignored (`SHA-256`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        let normalized = normalize_algo(name);
+        Self::ALL
+            .into_iter()
+            .find(|algorithm| normalize_algo(algorithm.name()) == normalized)
+    }
+
+    /// Canonical name, as listed by `crypto.getHashes()`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Md5 => "md5",
+            Self::Sha1 => "sha1",
+            Self::Sha224 => "sha224",
+            Self::Sha256 => "sha256",
+            Self::Sha384 => "sha384",
+            Self::Sha512 => "sha512",
+            Self::Sha512_224 => "sha512-224",
+            Self::Sha512_256 => "sha512-256",
+        }
+    }
+
+    /// HMAC block size in bytes.
+    fn block_size(self) -> usize {
+        match self {
+            Self::Md5 | Self::Sha1 | Self::Sha224 | Self::Sha256 => 64,
+            Self::Sha384 | Self::Sha512 | Self::Sha512_224 | Self::Sha512_256 => 128,
+        }
+    }
+}
+
+/// Incremental digest state for one [`HashAlgorithm`].
+#[derive(Clone)]
+pub enum Hash {
+    /// MD5 state.
+    Md5(md5::Md5),
+    /// SHA-1 state.
+    Sha1(sha1::Sha1),
+    /// SHA-224 state.
+    Sha224(Sha224),
+    /// SHA-256 state.
+    Sha256(Sha256),
+    /// SHA-384 state.
+    Sha384(Sha384),
+    /// SHA-512 state.
+    Sha512(Sha512),
+    /// SHA-512/224 state.
+    Sha512_224(Sha512_224),
+    /// SHA-512/256 state.
+    Sha512_256(Sha512_256),
+}
+
+macro_rules! each_hash {
+    ($hash:expr, $state:ident => $body:expr) => {
+        match $hash {
+            Hash::Md5($state) => $body,
+            Hash::Sha1($state) => $body,
+            Hash::Sha224($state) => $body,
+            Hash::Sha256($state) => $body,
+            Hash::Sha384($state) => $body,
+            Hash::Sha512($state) => $body,
+            Hash::Sha512_224($state) => $body,
+            Hash::Sha512_256($state) => $body,
+        }
+    };
+}
+
+impl Hash {
+    /// Fresh state for `algorithm`.
+    pub fn new(algorithm: HashAlgorithm) -> Self {
+        match algorithm {
+            HashAlgorithm::Md5 => Self::Md5(md5::Md5::new()),
+            HashAlgorithm::Sha1 => Self::Sha1(sha1::Sha1::new()),
+            HashAlgorithm::Sha224 => Self::Sha224(Sha224::new()),
+            HashAlgorithm::Sha256 => Self::Sha256(Sha256::new()),
+            HashAlgorithm::Sha384 => Self::Sha384(Sha384::new()),
+            HashAlgorithm::Sha512 => Self::Sha512(Sha512::new()),
+            HashAlgorithm::Sha512_224 => Self::Sha512_224(Sha512_224::new()),
+            HashAlgorithm::Sha512_256 => Self::Sha512_256(Sha512_256::new()),
+        }
+    }
+
+    /// Feed one chunk.
+    pub fn update(&mut self, data: &[u8]) {
+        each_hash!(self, state => Digest::update(state, data));
+    }
+
+    /// Consume the state and return the digest bytes.
+    pub fn finalize(self) -> Vec<u8> {
+        each_hash!(self, state => state.finalize().to_vec())
+    }
+}
+
+/// Host-object payload behind a `crypto.Hash` handle; `None` once digested.
+struct HashState(Option<Hash>);
+
+impl otter_runtime::RuntimeHostObjectData for HashState {}
+
+/// Errors from the CSPRNG-backed helpers.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum CryptoError {
+    /// The operating system's random source failed.
+    #[error("random source failed: {0}")]
+    RandomSource(String),
+    /// A bound is not a safe integer.
+    #[error("The \"{name}\" argument must be a safe integer. Received {value}")]
+    UnsafeInteger {
+        /// Argument name (`min` or `max`).
+        name: &'static str,
+        /// The offending value.
+        value: String,
+    },
+    /// `max` is not greater than `min`.
+    #[error(
+        "The value of \"max\" is out of range. It must be greater than the value of \"min\" ({min}). Received {max}"
+    )]
+    EmptyRange {
+        /// Inclusive lower bound.
+        min: i64,
+        /// Exclusive upper bound.
+        max: i64,
+    },
+    /// `max - min` exceeds [`RANDOM_INT_MAX_RANGE`].
+    #[error(
+        "The value of \"max - min\" is out of range. It must be <= {RANDOM_INT_MAX_RANGE}. Received {0}"
+    )]
+    RangeTooLarge(i64),
+}
+
+impl CryptoError {
+    fn into_native(self) -> NativeError {
+        let (kind, code) = match &self {
+            Self::RandomSource(_) => (otter_vm::ErrorKind::Error, "ERR_CRYPTO_OPERATION_FAILED"),
+            Self::UnsafeInteger { .. } => (otter_vm::ErrorKind::TypeError, "ERR_INVALID_ARG_TYPE"),
+            Self::EmptyRange { .. } | Self::RangeTooLarge(_) => {
+                (otter_vm::ErrorKind::RangeError, "ERR_OUT_OF_RANGE")
+            }
+        };
+        NativeError::Coded {
+            kind,
+            code,
+            message: self.to_string(),
+        }
+    }
+}
+
+fn fill_random(buf: &mut [u8]) -> Result<(), CryptoError> {
+    getrandom::fill(buf).map_err(|err| CryptoError::RandomSource(err.to_string()))
+}
+
+/// RFC 4122 version-4 UUID from the same CSPRNG as `randomBytes`, in
+/// lowercase hyphenated form.
+pub fn random_uuid() -> Result<String, CryptoError> {
+    let mut bytes = [0u8; 16];
+    fill_random(&mut bytes)?;
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let mut out = String::with_capacity(36);
+    for (index, byte) in bytes.iter().enumerate() {
+        if matches!(index, 4 | 6 | 8 | 10) {
+            out.push('-');
+        }
+        out.push_str(&format!("{byte:02x}"));
+    }
+    Ok(out)
+}
+
+/// Uniform integer in `[min, max)`.
+///
+/// Draws 48-bit values and rejects those past the last whole multiple of
+/// the span, so every result is equally likely (no modulo bias). Both bounds
+/// must be safe integers, `max > min`, and the span at most
+/// [`RANDOM_INT_MAX_RANGE`].
+pub fn random_int(min: i64, max: i64) -> Result<i64, CryptoError> {
+    for (name, value) in [("min", min), ("max", max)] {
+        if !(-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&value) {
+            return Err(CryptoError::UnsafeInteger {
+                name,
+                value: value.to_string(),
+            });
+        }
+    }
+    if max <= min {
+        return Err(CryptoError::EmptyRange { min, max });
+    }
+    let span = max - min;
+    if span > RANDOM_INT_MAX_RANGE {
+        return Err(CryptoError::RangeTooLarge(span));
+    }
+    let span = span as u64;
+    let draws = RANDOM_INT_MAX_RANGE as u64 + 1;
+    let limit = draws - draws % span;
+    loop {
+        let mut bytes = [0u8; 8];
+        fill_random(&mut bytes[..6])?;
+        let draw = u64::from_le_bytes(bytes);
+        if draw < limit {
+            return Ok(min + (draw % span) as i64);
+        }
+    }
+}
+
 /// CommonJS export: the `crypto` namespace built by `crypto.js`.
 pub fn crypto_cjs_value<'scope>(
     scope: &mut NativeScope<'scope, '_>,
@@ -46,7 +295,14 @@ fn native_value<'scope>(scope: &mut NativeScope<'scope, '_>) -> Result<Local<'sc
     }
 
     m!("randomBytes", 1, random_bytes);
+    m!("randomUUID", 0, random_uuid_native);
+    m!("randomInt", 2, random_int_native);
     m!("hashDigest", 2, hash_digest);
+    m!("hashOpen", 1, hash_open);
+    m!("hashUpdate", 2, hash_update);
+    m!("hashFinal", 1, hash_final);
+    m!("hashCopy", 1, hash_copy);
+    m!("getHashes", 0, get_hashes);
     m!("hmacDigest", 3, hmac_digest);
 
     Ok(object)
@@ -83,50 +339,132 @@ fn random_bytes(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, Native
     crate::string_value(ctx, &bytes_to_latin1(&buf))
 }
 
+/// `randomUUID()` — see [`random_uuid`].
+fn random_uuid_native(ctx: &mut NativeCtx<'_>, _args: &[Value]) -> Result<Value, NativeError> {
+    let uuid = random_uuid().map_err(CryptoError::into_native)?;
+    crate::string_value(ctx, &uuid)
+}
+
+/// `randomInt(min, max)` — see [`random_int`]. Non-integral or non-number
+/// bounds are rejected as unsafe integers.
+fn random_int_native(_ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
+    let bound = |index: usize, name: &'static str| match args.get(index).and_then(|v| v.as_f64()) {
+        Some(n) if n.fract() == 0.0 && n.abs() <= MAX_SAFE_INTEGER as f64 => Ok(n as i64),
+        other => {
+            let value = other.map_or_else(|| "a non-number".to_string(), |n| n.to_string());
+            Err(CryptoError::UnsafeInteger { name, value }.into_native())
+        }
+    };
+    let (min, max) = (bound(0, "min")?, bound(1, "max")?);
+    let value = random_int(min, max).map_err(CryptoError::into_native)?;
+    Ok(Value::number_f64(value as f64))
+}
+
 /// `hashDigest(algorithm, dataLatin1)` — one-shot digest as latin1.
 fn hash_digest(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
-    let algo = normalize_algo(&runtime_arg_to_string(args, 0, ctx.heap()));
+    let algorithm = algorithm_arg(ctx, args)?;
     let data = latin1_to_bytes(&runtime_arg_to_string(args, 1, ctx.heap()));
-    let digest = match algo.as_str() {
-        "sha1" => sha1::Sha1::digest(&data).to_vec(),
-        "md5" => md5::Md5::digest(&data).to_vec(),
-        "sha224" => Sha224::digest(&data).to_vec(),
-        "sha256" => Sha256::digest(&data).to_vec(),
-        "sha384" => Sha384::digest(&data).to_vec(),
-        "sha512" => Sha512::digest(&data).to_vec(),
-        other => {
-            return Err(NativeError::Coded {
-                kind: otter_vm::ErrorKind::Error,
-                code: "ERR_OSSL_EVP_UNSUPPORTED",
-                message: format!("Digest method not supported: {other}"),
-            });
+    let mut hash = Hash::new(algorithm);
+    hash.update(&data);
+    crate::string_value(ctx, &bytes_to_latin1(&hash.finalize()))
+}
+
+/// `hashOpen(algorithm)` → handle object owning a fresh incremental [`Hash`].
+fn hash_open(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
+    let algorithm = algorithm_arg(ctx, args)?;
+    hash_handle(ctx, Hash::new(algorithm))
+}
+
+/// `hashUpdate(handle, dataLatin1)`: feed one chunk into an open hash.
+fn hash_update(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
+    let data = latin1_to_bytes(&runtime_arg_to_string(args, 1, ctx.heap()));
+    with_hash_state(ctx, args, |state| match state {
+        Some(hash) => {
+            hash.update(&data);
+            Ok(Value::undefined())
         }
-    };
-    crate::string_value(ctx, &bytes_to_latin1(&digest))
+        None => Err(hash_finalized()),
+    })?
+}
+
+/// `hashFinal(handle)` → the digest as latin1; the state is consumed.
+fn hash_final(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
+    let hash = with_hash_state(ctx, args, Option::take)?.ok_or_else(hash_finalized)?;
+    crate::string_value(ctx, &bytes_to_latin1(&hash.finalize()))
+}
+
+/// `hashCopy(handle)` → handle object owning an independent copy of the state.
+fn hash_copy(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
+    let copy = with_hash_state(ctx, args, |state| state.clone())?.ok_or_else(hash_finalized)?;
+    hash_handle(ctx, copy)
+}
+
+/// `getHashes()` → the names in [`HashAlgorithm::ALL`].
+fn get_hashes(ctx: &mut NativeCtx<'_>, _args: &[Value]) -> Result<Value, NativeError> {
+    ctx.scope(|mut scope| {
+        let array = scope.array(HashAlgorithm::ALL.len())?;
+        for (index, algorithm) in HashAlgorithm::ALL.into_iter().enumerate() {
+            let name = scope.string(algorithm.name())?;
+            scope.set_index(array, index, name)?;
+        }
+        Ok(scope.finish(array))
+    })
+}
+
+fn hash_handle(ctx: &mut NativeCtx<'_>, hash: Hash) -> Result<Value, NativeError> {
+    ctx.scope(|mut scope| {
+        let handle = scope.host_object(HashState(Some(hash)))?;
+        Ok(scope.finish(handle))
+    })
+}
+
+/// Run `f` against the state behind the handle object in `args[0]`.
+fn with_hash_state<R>(
+    ctx: &mut NativeCtx<'_>,
+    args: &[Value],
+    f: impl FnOnce(&mut Option<Hash>) -> R,
+) -> Result<R, NativeError> {
+    let handle = args.first().copied().unwrap_or_else(Value::undefined);
+    ctx.scope(|mut scope| {
+        let handle = scope.value(handle);
+        scope.with_host_data_mut::<HashState, _>(handle, |state| f(&mut state.0))
+    })
+}
+
+fn algorithm_arg(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<HashAlgorithm, NativeError> {
+    let name = runtime_arg_to_string(args, 0, ctx.heap());
+    HashAlgorithm::from_name(&name).ok_or_else(|| NativeError::Coded {
+        kind: otter_vm::ErrorKind::Error,
+        code: "ERR_OSSL_EVP_UNSUPPORTED",
+        message: format!("Digest method not supported: {}", normalize_algo(&name)),
+    })
+}
+
+fn hash_finalized() -> NativeError {
+    NativeError::Coded {
+        kind: otter_vm::ErrorKind::Error,
+        code: "ERR_CRYPTO_HASH_FINALIZED",
+        message: "Digest already called".to_string(),
+    }
 }
 
 /// `hmacDigest(algorithm, keyLatin1, dataLatin1)` — HMAC as latin1. Implemented
-/// directly (RFC 2104) over the SHA-2 family so no extra crate is needed.
+/// directly (RFC 2104) over the supported digests so no extra crate is needed.
 fn hmac_digest(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
-    let algo = normalize_algo(&runtime_arg_to_string(args, 0, ctx.heap()));
+    let algorithm = algorithm_arg(ctx, args)?;
     let key = latin1_to_bytes(&runtime_arg_to_string(args, 1, ctx.heap()));
     let data = latin1_to_bytes(&runtime_arg_to_string(args, 2, ctx.heap()));
-    let (block, digest): (usize, Vec<u8>) = match algo.as_str() {
-        "sha1" => (64, hmac::<sha1::Sha1>(&key, &data, 64)),
-        "md5" => (64, hmac::<md5::Md5>(&key, &data, 64)),
-        "sha224" => (64, hmac::<Sha224>(&key, &data, 64)),
-        "sha256" => (64, hmac::<Sha256>(&key, &data, 64)),
-        "sha384" => (128, hmac::<Sha384>(&key, &data, 128)),
-        "sha512" => (128, hmac::<Sha512>(&key, &data, 128)),
-        other => {
-            return Err(NativeError::Coded {
-                kind: otter_vm::ErrorKind::Error,
-                code: "ERR_OSSL_EVP_UNSUPPORTED",
-                message: format!("Digest method not supported: {other}"),
-            });
-        }
+    let block = algorithm.block_size();
+    let digest = match algorithm {
+        HashAlgorithm::Md5 => hmac::<md5::Md5>(&key, &data, block),
+        HashAlgorithm::Sha1 => hmac::<sha1::Sha1>(&key, &data, block),
+        HashAlgorithm::Sha224 => hmac::<Sha224>(&key, &data, block),
+        HashAlgorithm::Sha256 => hmac::<Sha256>(&key, &data, block),
+        HashAlgorithm::Sha384 => hmac::<Sha384>(&key, &data, block),
+        HashAlgorithm::Sha512 => hmac::<Sha512>(&key, &data, block),
+        HashAlgorithm::Sha512_224 => hmac::<Sha512_224>(&key, &data, block),
+        HashAlgorithm::Sha512_256 => hmac::<Sha512_256>(&key, &data, block),
     };
-    let _ = block;
     crate::string_value(ctx, &bytes_to_latin1(&digest))
 }
 
@@ -149,3 +487,120 @@ fn hmac<D: Digest>(key: &[u8], data: &[u8], block_size: usize) -> Vec<u8> {
     outer.update(&inner_digest);
     outer.finalize().to_vec()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn random_uuid_is_version_4() {
+        let first = random_uuid().expect("uuid");
+        let second = random_uuid().expect("uuid");
+        assert_ne!(first, second);
+        for uuid in [first, second] {
+            let groups: Vec<&str> = uuid.split('-').collect();
+            assert_eq!(
+                groups.iter().map(|g| g.len()).collect::<Vec<_>>(),
+                [8, 4, 4, 4, 12]
+            );
+            assert!(
+                uuid.chars()
+                    .all(|c| c == '-' || c.is_ascii_digit() || ('a'..='f').contains(&c))
+            );
+            assert!(groups[2].starts_with('4'), "{uuid}");
+            assert!(matches!(&groups[3][..1], "8" | "9" | "a" | "b"), "{uuid}");
+        }
+    }
+
+    #[test]
+    fn random_int_stays_in_range_and_covers_it() {
+        let mut seen = [false; 6];
+        for _ in 0..600 {
+            let value = random_int(-3, 3).expect("in range");
+            assert!((-3..3).contains(&value));
+            seen[(value + 3) as usize] = true;
+        }
+        assert!(seen.iter().all(|&hit| hit), "{seen:?}");
+        assert_eq!(random_int(7, 8), Ok(7));
+        let top = MAX_SAFE_INTEGER;
+        assert!(random_int(top - RANDOM_INT_MAX_RANGE, top).is_ok());
+    }
+
+    #[test]
+    fn random_int_is_roughly_uniform() {
+        // 60k draws over 6 buckets: expected 10k each, sigma ~91, so a 10%
+        // band is far outside chance yet catches a skewed sampler.
+        let mut counts = [0u32; 6];
+        for _ in 0..60_000 {
+            counts[random_int(0, 6).expect("in range") as usize] += 1;
+        }
+        for count in counts {
+            assert!((9_000..=11_000).contains(&count), "{counts:?}");
+        }
+    }
+
+    #[test]
+    fn chunked_hash_updates_match_one_shot_digests() {
+        let data: Vec<u8> = (0..10_000u32).map(|i| (i * 31 % 251) as u8).collect();
+        for algorithm in HashAlgorithm::ALL {
+            let mut one_shot = Hash::new(algorithm);
+            one_shot.update(&data);
+            let mut chunked = Hash::new(algorithm);
+            for chunk in data.chunks(97) {
+                chunked.update(chunk);
+            }
+            assert_eq!(
+                chunked.finalize(),
+                one_shot.finalize(),
+                "{}",
+                algorithm.name()
+            );
+        }
+        let mut sha256 = Hash::new(HashAlgorithm::Sha256);
+        sha256.update(b"abc");
+        assert_eq!(
+            bytes_to_hex(&sha256.finalize()),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn hash_algorithm_names_round_trip() {
+        for algorithm in HashAlgorithm::ALL {
+            assert_eq!(HashAlgorithm::from_name(algorithm.name()), Some(algorithm));
+        }
+        assert_eq!(
+            HashAlgorithm::from_name("SHA-256"),
+            Some(HashAlgorithm::Sha256)
+        );
+        assert_eq!(
+            HashAlgorithm::from_name("SHA512-256"),
+            Some(HashAlgorithm::Sha512_256)
+        );
+        assert_eq!(HashAlgorithm::from_name("sha3-256"), None);
+    }
+
+    fn bytes_to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn random_int_rejects_bad_bounds() {
+        assert_eq!(
+            random_int(5, 5),
+            Err(CryptoError::EmptyRange { min: 5, max: 5 })
+        );
+        assert!(matches!(
+            random_int(2, 1),
+            Err(CryptoError::EmptyRange { .. })
+        ));
+        assert_eq!(
+            random_int(0, RANDOM_INT_MAX_RANGE + 1),
+            Err(CryptoError::RangeTooLarge(RANDOM_INT_MAX_RANGE + 1))
+        );
+        assert!(matches!(
+            random_int(0, MAX_SAFE_INTEGER + 1),
+            Err(CryptoError::UnsafeInteger { name: "max", .. })
+        ));
+    }
+}
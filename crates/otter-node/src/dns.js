@@ -0,0 +1,176 @@
+'use strict';
+// `node:dns` — TXT / MX / PTR queries backed by the native resolver core
+// (`__dnsnative`). Each query runs on the native worker pool and reports
+// back through a callback on the event loop; the callback API and
+// `dns.promises` share that path. Resolver failures surface as Node-shaped
+// errors: `err.code` (`ENOTFOUND`, `ESERVFAIL`, …), `err.syscall`,
+// `err.hostname`. `dns.setServers` / `getServers` configure the default
+// resolver shared by both APIs; `new dns.Resolver({ timeout })` gets its own
+// servers and per-exchange timeout (`ETIMEOUT` when a server never answers).
+
+const native = require('__dnsnative');
+
+const SYSCALLS = {
+  resolveTxt: 'queryTxt',
+  resolveMx: 'queryMx',
+  reverse: 'getHostByAddr',
+};
+
+function argTypeError(name, value, type = 'of type string') {
+  const e = new TypeError(
+    `The "${name}" argument must be ${type}.` +
+      (value === null || value === undefined
+        ? ` Received ${value}`
+        : ` Received type ${typeof value} (${String(value)})`)
+  );
+  e.code = 'ERR_INVALID_ARG_TYPE';
+  return e;
+}
+
+// Resolver state: `servers` is the normalized `setServers` list (null for
+// the system resolver), `timeout` is milliseconds (-1 for the default).
+function resolverState(options) {
+  const timeout = options && options.timeout !== undefined ? options.timeout : -1;
+  if (!Number.isInteger(timeout) || timeout < -1) {
+    const e = new RangeError(
+      `The value of "options.timeout" is out of range. It must be >= -1. Received ${timeout}`
+    );
+    e.code = 'ERR_OUT_OF_RANGE';
+    throw e;
+  }
+  return { servers: null, timeout };
+}
+
+function getServers(state) {
+  return state.servers === null ? native.systemServers() : state.servers.slice();
+}
+
+function setServers(state, servers) {
+  if (!Array.isArray(servers)) throw argTypeError('servers', servers, 'an instance of Array');
+  if (servers.length === 0) {
+    const e = new TypeError("The argument 'servers' must not be empty. Received []");
+    e.code = 'ERR_INVALID_ARG_VALUE';
+    throw e;
+  }
+  state.servers = servers.map((server, i) => {
+    if (typeof server !== 'string') throw argTypeError(`servers[${i}]`, server);
+    return native.normalizeServer(server);
+  });
+}
+
+// Start one native query; `done(err, result)` runs on a later event-loop
+// turn. Resolver errors carry the fields Node sets.
+function query(state, method, name, done) {
+  const servers = state.servers === null ? '' : state.servers.join(',');
+  native.query(method, name, (code, message, result) => {
+    if (code === null) {
+      done(null, result);
+      return;
+    }
+    const err = new Error(message);
+    err.code = code;
+    if (code !== 'EACCES') {
+      err.syscall = SYSCALLS[method];
+      err.hostname = name;
+    }
+    done(err);
+  }, servers, state.timeout);
+}
+
+function callbackForm(method, argName, fixedState) {
+  return function (name, callback) {
+    if (typeof name !== 'string') throw argTypeError(argName, name);
+    if (typeof callback !== 'function') {
+      const e = new TypeError(
+        `The "callback" argument must be of type function. Received ${typeof callback}`
+      );
+      e.code = 'ERR_INVALID_ARG_TYPE';
+      throw e;
+    }
+    query(fixedState || this._state, method, name, callback);
+  };
+}
+
+function promiseForm(method, argName, fixedState) {
+  return function (name) {
+    if (typeof name !== 'string') return Promise.reject(argTypeError(argName, name));
+    const state = fixedState || this._state;
+    return new Promise((resolve, reject) => {
+      query(state, method, name, (err, result) => (err ? reject(err) : resolve(result)));
+    });
+  };
+}
+
+const METHODS = [
+  ['resolveTxt', 'hostname'],
+  ['resolveMx', 'hostname'],
+  ['reverse', 'ip'],
+];
+
+// One default resolver backs `dns.*` and `dns.promises.*`, as in Node.
+const defaultState = resolverState();
+
+function resolverClass(form) {
+  class Resolver {
+    constructor(options) {
+      this._state = resolverState(options);
+    }
+
+    getServers() { return getServers(this._state); }
+    setServers(servers) { setServers(this._state, servers); }
+  }
+  for (const [method, argName] of METHODS) {
+    Resolver.prototype[method] = form(method, argName);
+  }
+  return Resolver;
+}
+
+function surface(form) {
+  const api = {
+    Resolver: resolverClass(form),
+    getServers: () => getServers(defaultState),
+    setServers: (servers) => setServers(defaultState, servers),
+  };
+  for (const [method, argName] of METHODS) {
+    api[method] = form(method, argName, defaultState);
+  }
+  return api;
+}
+
+const codes = {
+  NODATA: 'ENODATA',
+  FORMERR: 'EFORMERR',
+  SERVFAIL: 'ESERVFAIL',
+  NOTFOUND: 'ENOTFOUND',
+  NOTIMP: 'ENOTIMP',
+  REFUSED: 'EREFUSED',
+  BADQUERY: 'EBADQUERY',
+  BADNAME: 'EBADNAME',
+  BADFAMILY: 'EBADFAMILY',
+  BADRESP: 'EBADRESP',
+  CONNREFUSED: 'ECONNREFUSED',
+  TIMEOUT: 'ETIMEOUT',
+  EOF: 'EOF',
+  FILE: 'EFILE',
+  NOMEM: 'ENOMEM',
+  DESTRUCTION: 'EDESTRUCTION',
+  BADSTR: 'EBADSTR',
+  BADFLAGS: 'EBADFLAGS',
+  NONAME: 'ENONAME',
+  BADHINTS: 'EBADHINTS',
+  NOTINITIALIZED: 'ENOTINITIALIZED',
+  LOADIPHLPAPI: 'ELOADIPHLPAPI',
+  ADDRGETNETWORKPARAMS: 'EADDRGETNETWORKPARAMS',
+  CANCELLED: 'ECANCELLED',
+};
+
+const promises = {
+  ...surface(promiseForm),
+  ...codes,
+};
+
+module.exports = {
+  ...surface(callbackForm),
+  promises,
+  ...codes,
+};
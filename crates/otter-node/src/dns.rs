@@ -0,0 +1,1030 @@
+//! `node:dns` native core — TXT / MX / PTR queries over a minimal DNS client.
+//!
+//! # Contents
+//! - [`dns_cjs_value`] builds the CommonJS `dns` namespace (native core +
+//!   `dns.js` shim); the bare `dns` specifier and [`dns_promises_cjs_value`]
+//!   re-export it, so every specifier shares one default resolver.
+//! - [`resolve_txt`], [`resolve_mx`] and [`reverse`] are the typed Rust entry
+//!   points behind `dns.resolveTxt`, `dns.resolveMx` and `dns.reverse` (and
+//!   their `dns.promises` / `Resolver` forms). Failures are [`NetError`]s.
+//! - [`ResolverConfig`] carries a resolver's `setServers` list and
+//!   `timeout`; [`parse_server`] / [`format_server`] are the address forms
+//!   `setServers` accepts and `getServers` returns.
+//! - [`encode_query`] / [`parse_response`] are the wire codec (RFC 1035),
+//!   kept pure so they are testable without a network.
+//!
+//! # Invariants
+//! - The `net` capability is checked against the queried name (the IP for
+//!   [`reverse`]) before any socket opens; a denial is
+//!   [`NetError::PermissionDenied`].
+//! - Resolver status codes map onto Node's c-ares codes: NXDOMAIN →
+//!   `ENOTFOUND`, SERVFAIL → `ESERVFAIL`, REFUSED → `EREFUSED`, FORMERR →
+//!   `EFORMERR`, an answer without matching records → `ENODATA`. The code is
+//!   part of the error string (`queryTxt ENOTFOUND example.invalid`).
+//! - Nameservers come from `setServers`, else `/etc/resolv.conf` (falling
+//!   back to `127.0.0.1`). Servers set from JavaScript need `net` capability
+//!   for their IP or `ip:port`, like the queried name.
+//! - Queries go over UDP and retry over TCP when the reply is truncated.
+//!   Each exchange waits at most the resolver's timeout (5 s by default,
+//!   rounded up to whole milliseconds); a server that never answers is
+//!   `ETIMEOUT`. UDP datagrams whose source or query ID do not match are
+//!   ignored while the exchange keeps waiting.
+//! - Queries run on a small process-wide pool of [`WORKER_THREADS`] threads
+//!   and never block the isolate; queries beyond that wait in the pool's
+//!   queue, so a burst of lookups cannot spawn unbounded threads. The
+//!   answer reaches JavaScript as a [`RuntimeTask`] that calls the query's
+//!   callback (held in a persistent root); a [`RuntimeKeepAlive`] keeps the
+//!   event loop alive until it has been delivered.
+//! - Query IDs are drawn from the OS random source.
+//!
+//! # See also
+//! - `dns.js` — the JS surface (callback API, `dns.promises`, error fields).
+//! - `net.rs` — same persistent-root + task-spawner delivery for socket
+//!   events.
+
+use std::io::{Read, Write};
+use std::net::{IpAddr, SocketAddr, TcpStream, UdpSocket};
+use std::sync::{Arc, Mutex, OnceLock, PoisonError, mpsc};
+use std::time::{Duration, Instant};
+
+use otter_runtime::{
+    CapabilitySet, OtterError, Runtime, RuntimeExecutionContext, RuntimeKeepAlive, RuntimeLiveness,
+    RuntimeLocal as Local, RuntimeNativeCtx as NativeCtx, RuntimeNativeError as NativeError,
+    RuntimeNativeScope as NativeScope, RuntimePersistentRootId, RuntimeTask, RuntimeTaskSpawner,
+    RuntimeValue as Value, runtime_arg_to_string,
+};
+
+const SHIM: &str = include_str!("dns.js");
+const ALIAS_SHIM: &str = "'use strict';\nmodule.exports = require('node:dns');\n";
+const PROMISES_SHIM: &str = "'use strict';\nmodule.exports = require('node:dns').promises;\n";
+
+const RESOLV_CONF: &str = "/etc/resolv.conf";
+const DNS_PORT: u16 = 53;
+const QUERY_TIMEOUT: Duration = Duration::from_secs(5);
+const MAX_UDP_PAYLOAD: usize = 4096;
+/// Threads in the shared lookup pool; further queries wait in its queue.
+const WORKER_THREADS: usize = 4;
+/// Upper bound on compression-pointer hops while decoding one name.
+const MAX_POINTER_HOPS: usize = 64;
+
+const TYPE_PTR: u16 = 12;
+const TYPE_MX: u16 = 15;
+const TYPE_TXT: u16 = 16;
+const CLASS_IN: u16 = 1;
+
+/// Errors produced by the native `node:dns` core.
+#[derive(Debug, thiserror::Error)]
+pub enum NetError {
+    /// Network permission denied for the queried name.
+    #[error("network permission denied for `{target}`")]
+    PermissionDenied {
+        /// Name or address that was rejected.
+        target: String,
+    },
+    /// The resolver answered with an error status, or the query failed.
+    #[error("{syscall} {code} {hostname}")]
+    Resolver {
+        /// Node syscall label (`queryTxt`, `queryMx`, `getHostByAddr`).
+        syscall: &'static str,
+        /// Node error code (`ENOTFOUND`, `ESERVFAIL`, …).
+        code: &'static str,
+        /// Name or address that was queried.
+        hostname: String,
+    },
+}
+
+impl NetError {
+    /// Node-compatible `err.code` for this error.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::PermissionDenied { .. } => "EACCES",
+            Self::Resolver { code, .. } => code,
+        }
+    }
+
+    fn into_native(self) -> NativeError {
+        NativeError::Coded {
+            kind: otter_vm::ErrorKind::Error,
+            code: self.code(),
+            message: self.to_string(),
+        }
+    }
+}
+
+/// Result alias for `node:dns`.
+pub type NetResult<T> = Result<T, NetError>;
+
+/// One `MX` answer, shaped like Node's `{ priority, exchange }`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MxRecord {
+    /// Preference value; lower is preferred.
+    pub priority: u16,
+    /// Mail exchanger host name.
+    pub exchange: String,
+}
+
+/// Decoded answer data for the record types this module queries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Record {
+    /// `TXT` character-strings, in wire order.
+    Txt(Vec<String>),
+    /// `MX` preference + exchange.
+    Mx(MxRecord),
+    /// `PTR` target name.
+    Ptr(String),
+}
+
+/// One query kind exposed to `dns.js`, named after its JS method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lookup {
+    /// `resolveTxt`.
+    Txt,
+    /// `resolveMx`.
+    Mx,
+    /// `reverse`.
+    Reverse,
+}
+
+impl Lookup {
+    /// Parse a `dns.js` method name.
+    pub fn from_method(method: &str) -> Option<Self> {
+        match method {
+            "resolveTxt" => Some(Self::Txt),
+            "resolveMx" => Some(Self::Mx),
+            "reverse" => Some(Self::Reverse),
+            _ => None,
+        }
+    }
+
+    /// Node syscall label reported in this lookup's errors.
+    pub fn syscall(self) -> &'static str {
+        match self {
+            Self::Txt => "queryTxt",
+            Self::Mx => "queryMx",
+            Self::Reverse => "getHostByAddr",
+        }
+    }
+
+    /// Run the query for `name` on the calling thread.
+    pub fn run(
+        self,
+        name: &str,
+        config: &ResolverConfig,
+        caps: &CapabilitySet,
+    ) -> NetResult<Answer> {
+        match self {
+            Self::Txt => resolve_txt(name, config, caps).map(Answer::Txt),
+            Self::Mx => resolve_mx(name, config, caps).map(Answer::Mx),
+            Self::Reverse => reverse(name, config, caps).map(Answer::Names),
+        }
+    }
+}
+
+/// The result of one [`Lookup`], in the shape its JS method returns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    /// `resolveTxt`: one row of character-strings per record.
+    Txt(Vec<Vec<String>>),
+    /// `resolveMx`: `{ priority, exchange }` records.
+    Mx(Vec<MxRecord>),
+    /// `reverse`: host names.
+    Names(Vec<String>),
+}
+
+/// Nameservers and timeout for one resolver (`dns.setServers`,
+/// `new dns.Resolver({ timeout })`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResolverConfig {
+    /// Servers from `setServers`; `None` reads `/etc/resolv.conf`.
+    pub servers: Option<Vec<SocketAddr>>,
+    /// Per-exchange timeout; `None` uses the 5 s default.
+    pub timeout: Option<Duration>,
+}
+
+/// Parse one `setServers` entry: `1.2.3.4`, `1.2.3.4:1053`, `::1`, `[::1]` or
+/// `[::1]:1053`. The port defaults to 53.
+pub fn parse_server(server: &str) -> Option<SocketAddr> {
+    if let Ok(addr) = server.parse::<SocketAddr>() {
+        return Some(addr);
+    }
+    let ip = server
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .unwrap_or(server);
+    ip.parse::<IpAddr>()
+        .ok()
+        .map(|ip| SocketAddr::new(ip, DNS_PORT))
+}
+
+/// Format `server` the way `dns.getServers()` reports it: the bare IP on
+/// port 53, `ip:port` / `[ip]:port` otherwise.
+pub fn format_server(server: SocketAddr) -> String {
+    if server.port() == DNS_PORT {
+        server.ip().to_string()
+    } else {
+        server.to_string()
+    }
+}
+
+/// Resolve the `TXT` records of `hostname` (`dns.resolveTxt`).
+pub fn resolve_txt(
+    hostname: &str,
+    config: &ResolverConfig,
+    caps: &CapabilitySet,
+) -> NetResult<Vec<Vec<String>>> {
+    let records = query(hostname, hostname, TYPE_TXT, "queryTxt", config, caps)?;
+    Ok(records
+        .into_iter()
+        .filter_map(|record| match record {
+            Record::Txt(chunks) => Some(chunks),
+            _ => None,
+        })
+        .collect())
+}
+
+/// Resolve the `MX` records of `hostname` (`dns.resolveMx`).
+pub fn resolve_mx(
+    hostname: &str,
+    config: &ResolverConfig,
+    caps: &CapabilitySet,
+) -> NetResult<Vec<MxRecord>> {
+    let records = query(hostname, hostname, TYPE_MX, "queryMx", config, caps)?;
+    Ok(records
+        .into_iter()
+        .filter_map(|record| match record {
+            Record::Mx(mx) => Some(mx),
+            _ => None,
+        })
+        .collect())
+}
+
+/// Reverse-resolve `ip` to host names through a `PTR` query (`dns.reverse`).
+pub fn reverse(ip: &str, config: &ResolverConfig, caps: &CapabilitySet) -> NetResult<Vec<String>> {
+    let addr: IpAddr = ip.parse().map_err(|_| NetError::Resolver {
+        syscall: "getHostByAddr",
+        code: "EINVAL",
+        hostname: ip.to_string(),
+    })?;
+    let records = query(
+        ip,
+        &reverse_name(addr),
+        TYPE_PTR,
+        "getHostByAddr",
+        config,
+        caps,
+    )?;
+    Ok(records
+        .into_iter()
+        .filter_map(|record| match record {
+            Record::Ptr(name) => Some(name),
+            _ => None,
+        })
+        .collect())
+}
+
+/// The `in-addr.arpa` / `ip6.arpa` name for `addr`.
+pub fn reverse_name(addr: IpAddr) -> String {
+    match addr {
+        IpAddr::V4(v4) => {
+            let [a, b, c, d] = v4.octets();
+            format!("{d}.{c}.{b}.{a}.in-addr.arpa")
+        }
+        IpAddr::V6(v6) => {
+            let mut name = String::with_capacity(72);
+            for byte in v6.octets().iter().rev() {
+                name.push_str(&format!("{:x}.{:x}.", byte & 0x0f, byte >> 4));
+            }
+            name.push_str("ip6.arpa");
+            name
+        }
+    }
+}
+
+/// Run one query for `name`. `target` is what the capability check and error
+/// strings report (the caller's hostname or IP).
+fn query(
+    target: &str,
+    name: &str,
+    qtype: u16,
+    syscall: &'static str,
+    config: &ResolverConfig,
+    caps: &CapabilitySet,
+) -> NetResult<Vec<Record>> {
+    if !caps.net.matches(target) {
+        return Err(NetError::PermissionDenied {
+            target: target.to_string(),
+        });
+    }
+    let servers = match &config.servers {
+        Some(servers) => {
+            if let Some(denied) = servers.iter().find(|server| {
+                !caps.net.matches(&server.ip().to_string())
+                    && !caps.net.matches(&server.to_string())
+            }) {
+                return Err(NetError::PermissionDenied {
+                    target: denied.to_string(),
+                });
+            }
+            servers.clone()
+        }
+        None => nameservers(),
+    };
+    let timeout = config.timeout.unwrap_or(QUERY_TIMEOUT);
+    let fail = |code: &'static str| NetError::Resolver {
+        syscall,
+        code,
+        hostname: target.to_string(),
+    };
+    let id = query_id().map_err(fail)?;
+    let packet = encode_query(id, name, qtype).map_err(fail)?;
+    let mut last = "ECONNREFUSED";
+    for server in servers {
+        let reply = match exchange_udp(server, id, &packet, timeout) {
+            Ok(reply) if is_truncated(&reply) => exchange_tcp(server, &packet, timeout),
+            other => other,
+        };
+        match reply {
+            Ok(reply) => return parse_response(id, &reply, qtype).map_err(fail),
+            Err(code) => last = code,
+        }
+    }
+    Err(fail(last))
+}
+
+/// Encode a recursive `IN` query for `name` / `qtype`.
+pub fn encode_query(id: u16, name: &str, qtype: u16) -> Result<Vec<u8>, &'static str> {
+    let mut packet = Vec::with_capacity(18 + name.len());
+    packet.extend_from_slice(&id.to_be_bytes());
+    packet.extend_from_slice(&0x0100u16.to_be_bytes()); // RD
+    packet.extend_from_slice(&1u16.to_be_bytes()); // QDCOUNT
+    packet.extend_from_slice(&[0; 6]); // AN/NS/AR counts
+    let name = name.strip_suffix('.').unwrap_or(name);
+    if name.is_empty() || name.len() > 253 {
+        return Err("EBADNAME");
+    }
+    for label in name.split('.') {
+        if label.is_empty() || label.len() > 63 {
+            return Err("EBADNAME");
+        }
+        packet.push(label.len() as u8);
+        packet.extend_from_slice(label.as_bytes());
+    }
+    packet.push(0);
+    packet.extend_from_slice(&qtype.to_be_bytes());
+    packet.extend_from_slice(&CLASS_IN.to_be_bytes());
+    Ok(packet)
+}
+
+/// Decode the answers of type `qtype` from a reply to query `id`, mapping the
+/// header status onto a Node error code.
+pub fn parse_response(id: u16, reply: &[u8], qtype: u16) -> Result<Vec<Record>, &'static str> {
+    if reply.len() < 12 || u16::from_be_bytes([reply[0], reply[1]]) != id {
+        return Err("EBADRESP");
+    }
+    match reply[3] & 0x0f {
+        0 => {}
+        1 => return Err("EFORMERR"),
+        2 => return Err("ESERVFAIL"),
+        3 => return Err("ENOTFOUND"),
+        4 => return Err("ENOTIMP"),
+        5 => return Err("EREFUSED"),
+        _ => return Err("EBADRESP"),
+    }
+    let qdcount = u16::from_be_bytes([reply[4], reply[5]]);
+    let ancount = u16::from_be_bytes([reply[6], reply[7]]);
+    let mut pos = 12;
+    for _ in 0..qdcount {
+        pos = skip_name(reply, pos)? + 4;
+    }
+    let mut records = Vec::new();
+    for _ in 0..ancount {
+        pos = skip_name(reply, pos)?;
+        let header = reply.get(pos..pos + 10).ok_or("EBADRESP")?;
+        let rtype = u16::from_be_bytes([header[0], header[1]]);
+        let rdlen = u16::from_be_bytes([header[8], header[9]]) as usize;
+        let start = pos + 10;
+        let end = start + rdlen;
+        let rdata = reply.get(start..end).ok_or("EBADRESP")?;
+        pos = end;
+        if rtype != qtype {
+            continue; // CNAME chain links and other extras.
+        }
+        records.push(match rtype {
+            TYPE_TXT => Record::Txt(decode_txt(rdata)?),
+            TYPE_MX => {
+                let pref = rdata.get(..2).ok_or("EBADRESP")?;
+                Record::Mx(MxRecord {
+                    priority: u16::from_be_bytes([pref[0], pref[1]]),
+                    exchange: read_name(reply, start + 2)?,
+                })
+            }
+            _ => Record::Ptr(read_name(reply, start)?),
+        });
+    }
+    if records.is_empty() {
+        return Err("ENODATA");
+    }
+    Ok(records)
+}
+
+fn decode_txt(rdata: &[u8]) -> Result<Vec<String>, &'static str> {
+    let mut chunks = Vec::new();
+    let mut pos = 0;
+    while pos < rdata.len() {
+        let len = rdata[pos] as usize;
+        let chunk = rdata.get(pos + 1..pos + 1 + len).ok_or("EBADRESP")?;
+        chunks.push(String::from_utf8_lossy(chunk).into_owned());
+        pos += 1 + len;
+    }
+    Ok(chunks)
+}
+
+/// Offset just past the (possibly compressed) name starting at `pos`.
+fn skip_name(msg: &[u8], mut pos: usize) -> Result<usize, &'static str> {
+    loop {
+        let len = *msg.get(pos).ok_or("EBADRESP")?;
+        match len & 0xc0 {
+            0xc0 => return Ok(pos + 2),
+            0x00 if len == 0 => return Ok(pos + 1),
+            0x00 => pos += 1 + len as usize,
+            _ => return Err("EBADRESP"),
+        }
+    }
+}
+
+/// Decode the name at `pos`, following compression pointers.
+fn read_name(msg: &[u8], mut pos: usize) -> Result<String, &'static str> {
+    let mut name = String::new();
+    for _ in 0..MAX_POINTER_HOPS {
+        loop {
+            let len = *msg.get(pos).ok_or("EBADRESP")?;
+            if len & 0xc0 == 0xc0 {
+                let low = *msg.get(pos + 1).ok_or("EBADRESP")?;
+                pos = (usize::from(len & 0x3f) << 8) | usize::from(low);
+                break;
+            }
+            if len & 0xc0 != 0 {
+                return Err("EBADRESP");
+            }
+            if len == 0 {
+                return Ok(name);
+            }
+            let label = msg.get(pos + 1..pos + 1 + len as usize).ok_or("EBADRESP")?;
+            if !name.is_empty() {
+                name.push('.');
+            }
+            name.push_str(&String::from_utf8_lossy(label));
+            pos += 1 + len as usize;
+        }
+    }
+    Err("EBADRESP")
+}
+
+fn is_truncated(reply: &[u8]) -> bool {
+    reply.len() > 2 && reply[2] & 0x02 != 0
+}
+
+/// Send `packet` to `server` and wait up to `timeout` for the reply to query
+/// `id`. Datagrams from another address or carrying another ID (late replies
+/// to earlier queries, spoofing attempts) are skipped, not treated as the
+/// answer.
+fn exchange_udp(
+    server: SocketAddr,
+    id: u16,
+    packet: &[u8],
+    timeout: Duration,
+) -> Result<Vec<u8>, &'static str> {
+    let bind: SocketAddr = if server.is_ipv4() {
+        ([0, 0, 0, 0], 0).into()
+    } else {
+        ([0u16; 8], 0).into()
+    };
+    let socket = UdpSocket::bind(bind).map_err(|_| "ECONNREFUSED")?;
+    socket.connect(server).map_err(|_| "ECONNREFUSED")?;
+    socket.send(packet).map_err(|_| "ECONNREFUSED")?;
+    let deadline = Instant::now().checked_add(timeout);
+    let mut buf = vec![0; MAX_UDP_PAYLOAD];
+    loop {
+        let remaining = match deadline {
+            Some(deadline) => deadline.saturating_duration_since(Instant::now()),
+            None => timeout,
+        };
+        if remaining.is_zero() {
+            return Err("ETIMEOUT");
+        }
+        socket
+            .set_read_timeout(Some(remaining))
+            .map_err(|_| "ECONNREFUSED")?;
+        let (len, from) = socket.recv_from(&mut buf).map_err(io_code)?;
+        if from == server && len >= 2 && u16::from_be_bytes([buf[0], buf[1]]) == id {
+            buf.truncate(len);
+            return Ok(buf);
+        }
+    }
+}
+
+fn exchange_tcp(
+    server: SocketAddr,
+    packet: &[u8],
+    timeout: Duration,
+) -> Result<Vec<u8>, &'static str> {
+    let mut stream = TcpStream::connect_timeout(&server, timeout).map_err(io_code)?;
+    stream
+        .set_read_timeout(Some(timeout))
+        .map_err(|_| "ECONNREFUSED")?;
+    let mut framed = (packet.len() as u16).to_be_bytes().to_vec();
+    framed.extend_from_slice(packet);
+    stream.write_all(&framed).map_err(io_code)?;
+    let mut len = [0; 2];
+    stream.read_exact(&mut len).map_err(io_code)?;
+    let mut buf = vec![0; u16::from_be_bytes(len) as usize];
+    stream.read_exact(&mut buf).map_err(io_code)?;
+    Ok(buf)
+}
+
+fn io_code(err: std::io::Error) -> &'static str {
+    match err.kind() {
+        std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut => "ETIMEOUT",
+        _ => "ECONNREFUSED",
+    }
+}
+
+/// Nameservers listed in `/etc/resolv.conf`, in file order.
+fn nameservers() -> Vec<SocketAddr> {
+    let conf = std::fs::read_to_string(RESOLV_CONF).unwrap_or_default();
+    let mut servers: Vec<SocketAddr> = conf
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("nameserver"))
+        .filter_map(|rest| rest.trim().split('%').next()?.parse::<IpAddr>().ok())
+        .map(|ip| SocketAddr::new(ip, DNS_PORT))
+        .collect();
+    if servers.is_empty() {
+        servers.push(SocketAddr::new([127, 0, 0, 1].into(), DNS_PORT));
+    }
+    servers
+}
+
+/// A random transaction ID, so replies cannot be matched by guessing it.
+fn query_id() -> Result<u16, &'static str> {
+    let mut id = [0; 2];
+    getrandom::fill(&mut id).map_err(|_| "EBADQUERY")?;
+    Ok(u16::from_ne_bytes(id))
+}
+
+// ---- Worker pool -------------------------------------------------------------
+
+/// A lookup queued for the worker pool.
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Queue `job` on the process-wide DNS worker pool, starting the pool on
+/// first use. Returns the job back when no worker thread could be started.
+fn submit(job: Job) -> Result<(), Job> {
+    static POOL: OnceLock<Option<mpsc::Sender<Job>>> = OnceLock::new();
+    let pool = POOL.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let started = (0..WORKER_THREADS)
+            .filter(|index| {
+                let receiver = Arc::clone(&receiver);
+                std::thread::Builder::new()
+                    .name(format!("otter-dns-{index}"))
+                    .spawn(move || {
+                        loop {
+                            // The guard drops at the end of the statement, so
+                            // other workers can take jobs while this one runs.
+                            let job = receiver
+                                .lock()
+                                .unwrap_or_else(PoisonError::into_inner)
+                                .recv();
+                            let Ok(job) = job else { return };
+                            job();
+                        }
+                    })
+                    .is_ok()
+            })
+            .count();
+        (started > 0).then_some(sender)
+    });
+    match pool {
+        Some(sender) => sender.send(job).map_err(|err| err.0),
+        None => Err(job),
+    }
+}
+
+// ---- Answer delivery ---------------------------------------------------------
+
+/// Delivers one finished query to its callback on the isolate thread.
+struct DnsQueryTask {
+    context: RuntimeExecutionContext,
+    callback: RuntimePersistentRootId,
+    hold: RuntimeKeepAlive,
+    answer: NetResult<Answer>,
+}
+
+impl RuntimeTask for DnsQueryTask {
+    fn run(self: Box<Self>, runtime: &mut Runtime) -> Result<(), OtterError> {
+        let DnsQueryTask {
+            context,
+            callback,
+            hold,
+            answer,
+        } = *self;
+        let result = runtime.run_native_event(&context, move |ctx| {
+            let Some(callback_value) = ctx.persistent_root_get(callback) else {
+                return Ok(Value::undefined());
+            };
+            let _ = ctx.persistent_root_remove(callback);
+            ctx.scope(|mut scope| {
+                let callback = scope.value(callback_value);
+                let this = scope.undefined();
+                let args = match &answer {
+                    Ok(answer) => {
+                        let code = scope.null();
+                        let message = scope.null();
+                        [code, message, answer_value(&mut scope, answer)?]
+                    }
+                    Err(err) => {
+                        let code = scope.string(err.code())?;
+                        let message = scope.string(&err.to_string())?;
+                        [code, message, scope.undefined()]
+                    }
+                };
+                scope.call(callback, this, &args)?;
+                let done = scope.undefined();
+                Ok(scope.finish(done))
+            })
+        });
+        hold.close();
+        result
+    }
+}
+
+fn answer_value<'scope>(
+    scope: &mut NativeScope<'scope, '_>,
+    answer: &Answer,
+) -> Result<Local<'scope>, NativeError> {
+    match answer {
+        Answer::Txt(records) => {
+            let array = scope.array(records.len())?;
+            for (index, chunks) in records.iter().enumerate() {
+                scope.scope(|mut row_scope| {
+                    let row = row_scope.array(chunks.len())?;
+                    for (i, chunk) in chunks.iter().enumerate() {
+                        let value = row_scope.string(chunk)?;
+                        row_scope.set_index(row, i, value)?;
+                    }
+                    row_scope.set_index(array, index, row)
+                })?;
+            }
+            Ok(array)
+        }
+        Answer::Mx(records) => {
+            let array = scope.array(records.len())?;
+            for (index, mx) in records.iter().enumerate() {
+                scope.scope(|mut row_scope| {
+                    let row = row_scope.object()?;
+                    let exchange = row_scope.string(&mx.exchange)?;
+                    row_scope.set(row, "exchange", exchange)?;
+                    let priority = row_scope.number(f64::from(mx.priority));
+                    row_scope.set(row, "priority", priority)?;
+                    row_scope.set_index(array, index, row)
+                })?;
+            }
+            Ok(array)
+        }
+        Answer::Names(names) => {
+            let array = scope.array(names.len())?;
+            for (index, name) in names.iter().enumerate() {
+                scope.scope(|mut item_scope| {
+                    let value = item_scope.string(name)?;
+                    item_scope.set_index(array, index, value)
+                })?;
+            }
+            Ok(array)
+        }
+    }
+}
+
+// ---- CommonJS surface --------------------------------------------------------
+
+/// CommonJS export: the `dns` namespace built by `dns.js`.
+pub fn dns_cjs_value<'scope>(
+    scope: &mut NativeScope<'scope, '_>,
+    _caps: &CapabilitySet,
+    _runtime_task_spawner: Option<RuntimeTaskSpawner>,
+    module: Local<'scope>,
+    require: Local<'scope>,
+) -> Result<Local<'scope>, NativeError> {
+    otter_runtime::run_builtin_cjs_shim(scope, "node:dns", SHIM, module, require)
+}
+
+/// CommonJS export: bare `dns`, sharing the `node:dns` instance (and with it
+/// the default resolver's servers).
+pub fn dns_alias_cjs_value<'scope>(
+    scope: &mut NativeScope<'scope, '_>,
+    _caps: &CapabilitySet,
+    _runtime_task_spawner: Option<RuntimeTaskSpawner>,
+    module: Local<'scope>,
+    require: Local<'scope>,
+) -> Result<Local<'scope>, NativeError> {
+    otter_runtime::run_builtin_cjs_shim(scope, "dns", ALIAS_SHIM, module, require)
+}
+
+/// CommonJS export: `dns/promises`, i.e. `require('node:dns').promises`.
+pub fn dns_promises_cjs_value<'scope>(
+    scope: &mut NativeScope<'scope, '_>,
+    _caps: &CapabilitySet,
+    _runtime_task_spawner: Option<RuntimeTaskSpawner>,
+    module: Local<'scope>,
+    require: Local<'scope>,
+) -> Result<Local<'scope>, NativeError> {
+    otter_runtime::run_builtin_cjs_shim(scope, "node:dns/promises", PROMISES_SHIM, module, require)
+}
+
+/// Hidden CommonJS row that supplies the capability-gated native `dns` core.
+pub fn dns_native_cjs_value<'scope>(
+    scope: &mut NativeScope<'scope, '_>,
+    caps: &CapabilitySet,
+    runtime_task_spawner: Option<RuntimeTaskSpawner>,
+    _module: Local<'scope>,
+    _require: Local<'scope>,
+) -> Result<Local<'scope>, NativeError> {
+    native_value(scope, caps, runtime_task_spawner)
+}
+
+/// Build the raw core: `{ query, normalizeServer, systemServers }`. `query`
+/// captures the capability set and the task spawner.
+fn native_value<'scope>(
+    scope: &mut NativeScope<'scope, '_>,
+    caps: &CapabilitySet,
+    runtime_task_spawner: Option<RuntimeTaskSpawner>,
+) -> Result<Local<'scope>, NativeError> {
+    let object = scope.object()?;
+    let caps = caps.clone();
+    let method = scope.native_closure(
+        "query",
+        5,
+        &[],
+        move |ctx: &mut NativeCtx<'_>, args: &[Value], _captures: &[Value]| {
+            dns_query(ctx, args, &caps, runtime_task_spawner.as_ref())
+        },
+    )?;
+    scope.set(object, "query", method)?;
+    let method = scope.native_method("normalizeServer", 1, normalize_server)?;
+    scope.set(object, "normalizeServer", method)?;
+    let method = scope.native_method("systemServers", 0, system_servers)?;
+    scope.set(object, "systemServers", method)?;
+    Ok(object)
+}
+
+/// `normalizeServer(server)` — the `getServers` form of one `setServers`
+/// entry; throws `ERR_INVALID_IP_ADDRESS` for anything else.
+fn normalize_server(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
+    let server = runtime_arg_to_string(args, 0, ctx.heap());
+    let Some(addr) = parse_server(&server) else {
+        return Err(NativeError::Coded {
+            kind: otter_vm::ErrorKind::TypeError,
+            code: "ERR_INVALID_IP_ADDRESS",
+            message: format!("Invalid IP address: {server}"),
+        });
+    };
+    ctx.scope(|mut scope| {
+        let value = scope.string(&format_server(addr))?;
+        Ok(scope.finish(value))
+    })
+}
+
+/// `systemServers()` — the `/etc/resolv.conf` nameservers, as `getServers`
+/// reports them before any `setServers`.
+fn system_servers(ctx: &mut NativeCtx<'_>, _args: &[Value]) -> Result<Value, NativeError> {
+    let servers = nameservers();
+    ctx.scope(|mut scope| {
+        let array = scope.array(servers.len())?;
+        for (index, server) in servers.into_iter().enumerate() {
+            let value = scope.string(&format_server(server))?;
+            scope.set_index(array, index, value)?;
+        }
+        Ok(scope.finish(array))
+    })
+}
+
+/// `query(method, name, callback, servers, timeoutMs)` — queue one lookup on
+/// the worker pool and later call `callback(code, message, result)`: `code`
+/// / `message` are `null` on success, `result` is `undefined` on failure.
+/// `servers` is a comma-separated `setServers` list (empty for the system
+/// resolver); a non-positive `timeoutMs` keeps the default.
+fn dns_query(
+    ctx: &mut NativeCtx<'_>,
+    args: &[Value],
+    caps: &CapabilitySet,
+    spawner: Option<&RuntimeTaskSpawner>,
+) -> Result<Value, NativeError> {
+    let method = runtime_arg_to_string(args, 0, ctx.heap());
+    let Some(lookup) = Lookup::from_method(&method) else {
+        return Err(crate::type_error("dns.query", "unknown query method"));
+    };
+    let name = runtime_arg_to_string(args, 1, ctx.heap());
+    let callback = args.get(2).copied().unwrap_or_else(Value::undefined);
+    if !callback.is_callable() {
+        return Err(crate::type_error(
+            "dns.query",
+            "callback must be a function",
+        ));
+    }
+    let (Some(spawner), Some(context)) = (spawner.cloned(), ctx.execution_context().cloned())
+    else {
+        return Err(NativeError::Coded {
+            kind: otter_vm::ErrorKind::Error,
+            code: "ERR_INVALID_STATE",
+            message: "dns queries need a runtime event loop".to_string(),
+        });
+    };
+    let config = resolver_config(ctx, args)?;
+    let hold = spawner.retain_keep_alive(RuntimeLiveness::Ref);
+    let callback = ctx.persistent_root_insert(callback);
+    let caps = caps.clone();
+    let hostname = name.clone();
+    let submitted = submit(Box::new(move || {
+        let answer = lookup.run(&name, &config, &caps);
+        let task = DnsQueryTask {
+            context,
+            callback,
+            hold,
+            answer,
+        };
+        let _ = spawner.enqueue(task, RuntimeLiveness::Unref);
+    }));
+    if submitted.is_err() {
+        let _ = ctx.persistent_root_remove(callback);
+        return Err(NetError::Resolver {
+            syscall: lookup.syscall(),
+            code: "ENOMEM",
+            hostname,
+        }
+        .into_native());
+    }
+    Ok(Value::undefined())
+}
+
+fn resolver_config(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<ResolverConfig, NativeError> {
+    let list = runtime_arg_to_string(args, 3, ctx.heap());
+    let servers = if matches!(list.as_str(), "" | "undefined") {
+        None
+    } else {
+        let servers = list
+            .split(',')
+            .map(parse_server)
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| crate::type_error("dns.query", "invalid server list"))?;
+        Some(servers)
+    };
+    let timeout = args
+        .get(4)
+        .and_then(|v| v.as_f64())
+        .filter(|ms| ms.is_finite() && *ms > 0.0)
+        .map(|ms| Duration::from_millis((ms.ceil() as u64).max(1)));
+    Ok(ResolverConfig { servers, timeout })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a reply to `query` carrying `answers` as `(type, rdata)` pairs,
+    /// each owner name a pointer back to the question.
+    fn reply(query: &[u8], rcode: u8, answers: &[(u16, Vec<u8>)]) -> Vec<u8> {
+        let mut msg = query.to_vec();
+        msg[2] = 0x81;
+        msg[3] = 0x80 | rcode;
+        msg[6..8].copy_from_slice(&(answers.len() as u16).to_be_bytes());
+        for (rtype, rdata) in answers {
+            msg.extend_from_slice(&[0xc0, 12]);
+            msg.extend_from_slice(&rtype.to_be_bytes());
+            msg.extend_from_slice(&CLASS_IN.to_be_bytes());
+            msg.extend_from_slice(&300u32.to_be_bytes());
+            msg.extend_from_slice(&(rdata.len() as u16).to_be_bytes());
+            msg.extend_from_slice(rdata);
+        }
+        msg
+    }
+
+    #[test]
+    fn parses_txt_mx_and_compressed_names() {
+        let q = encode_query(7, "example.com", TYPE_TXT).unwrap();
+        let txt = reply(&q, 0, &[(TYPE_TXT, b"\x05hello\x05world".to_vec())]);
+        assert_eq!(
+            parse_response(7, &txt, TYPE_TXT).unwrap(),
+            vec![Record::Txt(vec!["hello".into(), "world".into()])]
+        );
+
+        let q = encode_query(8, "example.com.", TYPE_MX).unwrap();
+        // priority 10, exchange "mail" + pointer to "example.com" in the question.
+        let mx = reply(&q, 0, &[(TYPE_MX, b"\x00\x0a\x04mail\xc0\x0c".to_vec())]);
+        assert_eq!(
+            parse_response(8, &mx, TYPE_MX).unwrap(),
+            vec![Record::Mx(MxRecord {
+                priority: 10,
+                exchange: "mail.example.com".into(),
+            })]
+        );
+    }
+
+    #[test]
+    fn maps_status_codes_to_node_errors() {
+        let q = encode_query(1, "nope.invalid", TYPE_TXT).unwrap();
+        assert_eq!(
+            parse_response(1, &reply(&q, 3, &[]), TYPE_TXT),
+            Err("ENOTFOUND")
+        );
+        assert_eq!(
+            parse_response(1, &reply(&q, 2, &[]), TYPE_TXT),
+            Err("ESERVFAIL")
+        );
+        assert_eq!(
+            parse_response(1, &reply(&q, 0, &[]), TYPE_TXT),
+            Err("ENODATA")
+        );
+        assert_eq!(
+            parse_response(2, &reply(&q, 0, &[]), TYPE_TXT),
+            Err("EBADRESP")
+        );
+        assert_eq!(encode_query(1, "a..b", TYPE_TXT), Err("EBADNAME"));
+    }
+
+    #[test]
+    fn reverse_names_and_permission() {
+        assert_eq!(
+            reverse_name("192.0.2.1".parse().unwrap()),
+            "1.2.0.192.in-addr.arpa"
+        );
+        assert!(reverse_name("2001:db8::1".parse().unwrap()).starts_with("1.0.0.0.0.0.0.0."));
+        let config = ResolverConfig::default();
+        let err = resolve_txt("example.com", &config, &CapabilitySet::default()).unwrap_err();
+        assert_eq!(err.code(), "EACCES");
+        let err = reverse("not-an-ip", &config, &CapabilitySet::default()).unwrap_err();
+        assert_eq!(err.to_string(), "getHostByAddr EINVAL not-an-ip");
+        let err = Lookup::from_method("reverse")
+            .unwrap()
+            .run("not-an-ip", &config, &CapabilitySet::default())
+            .unwrap_err();
+        assert_eq!(err.code(), "EINVAL");
+        assert_eq!(Lookup::from_method("resolveAny"), None);
+    }
+
+    #[test]
+    fn server_addresses_round_trip_through_get_servers_form() {
+        for (input, output) in [
+            ("8.8.8.8", "8.8.8.8"),
+            ("8.8.8.8:53", "8.8.8.8"),
+            ("8.8.8.8:1053", "8.8.8.8:1053"),
+            ("2001:4860:4860::8888", "2001:4860:4860::8888"),
+            ("[2001:4860:4860::8888]", "2001:4860:4860::8888"),
+            ("[::1]:1053", "[::1]:1053"),
+        ] {
+            let addr = parse_server(input).unwrap_or_else(|| panic!("{input} should parse"));
+            assert_eq!(format_server(addr), output);
+        }
+        for input in ["", "dns.google", "8.8.8.8:port", "1.2.3.4.5", "[::1"] {
+            assert_eq!(parse_server(input), None, "{input}");
+        }
+    }
+
+    #[test]
+    fn mismatched_udp_replies_are_skipped() {
+        let server = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let addr = server.local_addr().unwrap();
+        let responder = std::thread::spawn(move || {
+            let mut buf = [0; 512];
+            let (len, from) = server.recv_from(&mut buf).unwrap();
+            let query = &buf[..len];
+            let answer = reply(query, 0, &[(TYPE_TXT, b"\x02ok".to_vec())]);
+            let mut stale = answer.clone();
+            stale[1] = stale[1].wrapping_add(1);
+            server.send_to(&stale, from).unwrap();
+            server.send_to(&answer, from).unwrap();
+        });
+        let config = ResolverConfig {
+            servers: Some(vec![addr]),
+            timeout: Some(Duration::from_secs(5)),
+        };
+        let records = resolve_txt("example.com", &config, &CapabilitySet::allow_all()).unwrap();
+        assert_eq!(records, vec![vec!["ok".to_string()]]);
+        responder.join().unwrap();
+    }
+
+    #[test]
+    fn silent_server_times_out() {
+        let silent = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let config = ResolverConfig {
+            servers: Some(vec![silent.local_addr().unwrap()]),
+            timeout: Some(Duration::from_millis(50)),
+        };
+        let err = resolve_txt("example.com", &config, &CapabilitySet::allow_all()).unwrap_err();
+        assert_eq!(err.to_string(), "queryTxt ETIMEOUT example.com");
+
+        let mut caps = CapabilitySet::default();
+        caps.net = otter_runtime::Permission::allow(["example.com".to_string()]);
+        let err = resolve_txt("example.com", &config, &caps).unwrap_err();
+        assert_eq!(err.code(), "EACCES");
+    }
+}
@@ -27,6 +27,7 @@ pub mod buffer;
 pub mod child_process;
 pub mod crypto;
 pub mod diagnostics_channel;
+pub mod dns;
 pub mod events;
 pub mod fs;
 pub mod globals;
@@ -34,6 +35,7 @@ pub mod internal_errors_ext;
 pub mod internal_test_binding_ext;
 pub mod misc_modules;
 pub mod napi;
+pub mod net;
 pub mod node_test;
 pub mod os;
 pub mod path;
@@ -90,6 +92,7 @@ pub const HOSTED_MODULES: &[HostedModule] = &[
     HostedModule::new_with_cjs_value("os", os::install_os_module, os::os_cjs_value),
     HostedModule::cjs_only("node:test", node_test::node_test_cjs_value),
     HostedModule::cjs_only("test", node_test::node_test_cjs_value),
+    HostedModule::cjs_only("internal/test_runner/expect", node_test::expect_cjs_value),
     HostedModule::cjs_only("node:stream", stream::stream_cjs_value),
     HostedModule::cjs_only("node:stream/web", stream::stream_web_cjs_value),
     HostedModule::cjs_only("stream/web", stream::stream_web_cjs_value),
@@ -113,6 +116,11 @@ pub const HOSTED_MODULES: &[HostedModule] = &[
     HostedModule::cjs_only("node:zlib", zlib::zlib_cjs_value),
     HostedModule::cjs_only("zlib", zlib::zlib_cjs_value),
     HostedModule::cjs_only("__zlibnative", zlib::zlib_native_cjs_value),
+    HostedModule::cjs_only("node:dns", dns::dns_cjs_value),
+    HostedModule::cjs_only("dns", dns::dns_alias_cjs_value),
+    HostedModule::cjs_only("node:dns/promises", dns::dns_promises_cjs_value),
+    HostedModule::cjs_only("dns/promises", dns::dns_promises_cjs_value),
+    HostedModule::cjs_only("__dnsnative", dns::dns_native_cjs_value),
     HostedModule::cjs_only("node:perf_hooks", misc_modules::perf_hooks_cjs_value),
     HostedModule::cjs_only("perf_hooks", misc_modules::perf_hooks_cjs_value),
     HostedModule::cjs_only("node:v8", misc_modules::v8_cjs_value),
@@ -141,8 +149,9 @@ pub const HOSTED_MODULES: &[HostedModule] = &[
     HostedModule::cjs_only("util/types", util::util_types_cjs_value),
     HostedModule::cjs_only("node:tty", tty::tty_cjs_value),
     HostedModule::cjs_only("tty", tty::tty_cjs_value),
-    HostedModule::new("node:net", stubs::install_net),
-    HostedModule::new("net", stubs::install_net),
+    HostedModule::cjs_only("node:net", net::net_cjs_value),
+    HostedModule::cjs_only("net", net::net_alias_cjs_value),
+    HostedModule::cjs_only("__netnative", net::net_native_cjs_value),
     HostedModule::new("node:worker_threads", stubs::install_worker_threads),
     HostedModule::new("worker_threads", stubs::install_worker_threads),
     HostedModule::cjs_only("node:buffer", buffer::buffer_cjs_value),
@@ -152,6 +161,7 @@ pub const HOSTED_MODULES: &[HostedModule] = &[
     HostedModule::cjs_only("node:child_process", child_process::child_process_cjs_value),
     HostedModule::cjs_only("child_process", child_process::child_process_cjs_value),
     HostedModule::cjs_only("__cpnative", child_process::child_process_native_cjs_value),
+    HostedModule::cjs_only("__utf8native", string_decoder::utf8_native_cjs_value),
 ];
 
 /// Return active Node hosted module installers.
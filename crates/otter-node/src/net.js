@@ -0,0 +1,416 @@
+'use strict';
+// `node:net` — Unix domain socket and TCP clients and servers backed by the
+// native socket core (`__netnative`). `net.connect({ path })` or
+// `net.connect(port[, host])` returns a Duplex `Socket`;
+// `net.createServer().listen(...)` emits accepted connections as Sockets.
+// Native socket threads report through one dispatcher (bound on first use)
+// keyed by resource id; bytes cross the boundary as latin1 strings. TCP
+// connects settle asynchronously (writes queue until 'connect'); endpoint
+// addresses are read once the socket is open. On platforms without Unix
+// domain sockets, path forms fail with ERR_FEATURE_UNAVAILABLE_ON_PLATFORM.
+
+const EventEmitter = require('events');
+const { Buffer } = require('buffer');
+const { Duplex } = require('stream');
+const native = require('__netnative');
+
+const handles = new Map();
+let bound = false;
+
+function ensureBound() {
+  if (bound) return;
+  native.bind((id, kind, a, b) => {
+    const handle = handles.get(id);
+    if (handle) handle._onNative(kind, a, b);
+  });
+  bound = true;
+}
+
+function nextTick(fn, ...args) {
+  queueMicrotask(() => fn(...args));
+}
+
+function decorate(err, syscall, target) {
+  if (err && typeof err === 'object' && typeof err.code === 'string' && err.code[0] === 'E') {
+    err.syscall = syscall;
+    if (!target) return err;
+    if (target.path !== undefined) {
+      err.address = target.path;
+    } else {
+      err.address = target.host;
+      err.port = target.port;
+    }
+  }
+  return err;
+}
+
+function validatePort(port) {
+  const n = typeof port === 'string' && port.trim() !== '' ? Number(port) : port;
+  if (typeof n !== 'number' || !Number.isInteger(n) || n < 0 || n > 65535) {
+    const e = new RangeError(`Port should be >= 0 and < 65536. Received ${String(port)}.`);
+    e.code = 'ERR_SOCKET_BAD_PORT';
+    throw e;
+  }
+  return n;
+}
+
+function missingArgs() {
+  const e = new TypeError('The "options" or "port" or "path" argument must be specified');
+  e.code = 'ERR_MISSING_ARGS';
+  return e;
+}
+
+// Accept `path`, `port[, host]`, or `{ path }` / `{ port, host }`; returns
+// `{ path }` or `{ host, port }`.
+function endpoint(args, defaultHost, what) {
+  const [first, second] = args;
+  if (typeof first === 'string' && !/^\d+$/.test(first)) return { path: first };
+  if (typeof first === 'number' || typeof first === 'string') {
+    return {
+      host: typeof second === 'string' ? second : defaultHost,
+      port: validatePort(first),
+    };
+  }
+  if (first && typeof first === 'object') {
+    if (typeof first.path === 'string') return { path: first.path };
+    if (first.port !== undefined) {
+      return {
+        host: typeof first.host === 'string' ? first.host : defaultHost,
+        port: validatePort(first.port),
+      };
+    }
+  }
+  if (first === undefined && what === 'listen') return { host: defaultHost, port: 0 };
+  throw missingArgs();
+}
+
+class Socket extends Duplex {
+  constructor(options = {}) {
+    super(options);
+    this._id = null;
+    this._path = undefined;
+    this._target = null;
+    this._addresses = null;
+    this._pending = [];
+    this._closeCb = null;
+    this._readError = null;
+    this.connecting = false;
+    this.allowHalfOpen = !!options.allowHalfOpen;
+    this.bytesRead = 0;
+    this.bytesWritten = 0;
+  }
+
+  connect(...args) {
+    const cb = typeof args[args.length - 1] === 'function' ? args.pop() : undefined;
+    const target = endpoint(args, 'localhost', 'connect');
+    if (cb) this.once('connect', cb);
+    this._target = target;
+    this._path = target.path;
+    this.connecting = true;
+    let id;
+    try {
+      ensureBound();
+      id = target.path !== undefined
+        ? native.connectUnix(target.path)
+        : native.connectTcp(target.host, target.port);
+    } catch (err) {
+      this.connecting = false;
+      nextTick(() => this.destroy(decorate(err, 'connect', target)));
+      return this;
+    }
+    this._attach(id);
+    // A Unix connect is already established; TCP waits for the native event.
+    if (target.path !== undefined) nextTick(() => this._onConnect());
+    return this;
+  }
+
+  _attach(id) {
+    this._id = id;
+    handles.set(id, this);
+  }
+
+  _onConnect() {
+    if (this._id === null) return;
+    this.connecting = false;
+    this._cacheAddresses();
+    const pending = this._pending;
+    this._pending = [];
+    for (const run of pending) run();
+    this.emit('connect');
+    this.emit('ready');
+  }
+
+  _cacheAddresses() {
+    try {
+      this._addresses = native.socketAddress(this._id);
+    } catch (_) {
+      this._addresses = null;
+    }
+  }
+
+  _onNative(kind, a, b) {
+    switch (kind) {
+      case 'connect':
+        this._onConnect();
+        break;
+      case 'data': {
+        const chunk = Buffer.from(a, 'latin1');
+        this.bytesRead += chunk.length;
+        this.push(chunk);
+        break;
+      }
+      case 'end':
+        this.push(null);
+        if (!this.allowHalfOpen) this.end();
+        break;
+      case 'error': {
+        const err = new Error(b);
+        err.code = a;
+        this._readError = this.connecting ? decorate(err, 'connect', this._target) : err;
+        break;
+      }
+      case 'close': {
+        this.connecting = false;
+        this._pending = [];
+        handles.delete(this._id);
+        this._id = null;
+        const done = this._closeCb;
+        this._closeCb = null;
+        if (done) done();
+        else this.destroy(this._readError);
+        break;
+      }
+    }
+  }
+
+  _read() {}
+
+  _write(chunk, encoding, cb) {
+    if (this.connecting) {
+      this._pending.push(() => this._write(chunk, encoding, cb));
+      return;
+    }
+    if (this._id === null) {
+      const err = new Error('Socket is closed');
+      err.code = 'ERR_SOCKET_CLOSED';
+      cb(err);
+      return;
+    }
+    const buf = Buffer.isBuffer(chunk) ? chunk : Buffer.from(chunk, encoding);
+    try {
+      native.write(this._id, buf.toString('latin1'));
+    } catch (err) {
+      cb(decorate(err, 'write', this._target));
+      return;
+    }
+    this.bytesWritten += buf.length;
+    cb();
+  }
+
+  _final(cb) {
+    if (this.connecting) {
+      this._pending.push(() => this._final(cb));
+      return;
+    }
+    if (this._id !== null) {
+      try {
+        native.end(this._id);
+      } catch (_) {
+        // Already closed natively; the pending 'close' settles the socket.
+      }
+    }
+    cb();
+  }
+
+  // Native close is asynchronous: hold the stream's destroy callback until
+  // the socket's 'close' event arrives.
+  _destroy(err, cb) {
+    if (this._id === null) {
+      cb(err);
+      return;
+    }
+    this._closeCb = () => cb(err);
+    native.destroy(this._id);
+  }
+
+  address() {
+    if (this._path !== undefined) return this._path;
+    const a = this._addresses;
+    if (!a || a.localAddress === undefined) return {};
+    return { address: a.localAddress, family: a.localFamily, port: a.localPort };
+  }
+
+  get localAddress() { return this._addresses ? this._addresses.localAddress : undefined; }
+  get localPort() { return this._addresses ? this._addresses.localPort : undefined; }
+  get localFamily() { return this._addresses ? this._addresses.localFamily : undefined; }
+  get remoteAddress() { return this._addresses ? this._addresses.remoteAddress : undefined; }
+  get remotePort() { return this._addresses ? this._addresses.remotePort : undefined; }
+  get remoteFamily() { return this._addresses ? this._addresses.remoteFamily : undefined; }
+  get pending() { return this._id === null && !this.destroyed; }
+  get readyState() {
+    if (this.connecting) return 'opening';
+    if (this._id === null) return 'closed';
+    return 'open';
+  }
+
+  ref() {
+    if (this._id !== null) native.setRef(this._id, true);
+    return this;
+  }
+
+  unref() {
+    if (this._id !== null) native.setRef(this._id, false);
+    return this;
+  }
+
+  setNoDelay() { return this; }
+  setKeepAlive() { return this; }
+  setTimeout(_ms, cb) {
+    if (typeof cb === 'function') this.once('timeout', cb);
+    return this;
+  }
+}
+
+class Server extends EventEmitter {
+  constructor(options, connectionListener) {
+    super();
+    if (typeof options === 'function') {
+      connectionListener = options;
+      options = {};
+    }
+    this._options = options || {};
+    this._id = null;
+    this._path = null;
+    this._target = null;
+    this._connections = new Set();
+    this._closing = false;
+    this.listening = false;
+    if (typeof connectionListener === 'function') this.on('connection', connectionListener);
+  }
+
+  listen(...args) {
+    const cb = typeof args[args.length - 1] === 'function' ? args.pop() : undefined;
+    // Node's default host is the unspecified IPv6 address (dual-stack where
+    // available); the native side falls back to 0.0.0.0 without IPv6.
+    const target = endpoint(args, '::', 'listen');
+    if (cb) this.once('listening', cb);
+    let id;
+    try {
+      ensureBound();
+      id = target.path !== undefined
+        ? native.listenUnix(target.path)
+        : native.listenTcp(target.host, target.port);
+    } catch (err) {
+      nextTick(() => this.emit('error', decorate(err, 'listen', target)));
+      return this;
+    }
+    this._id = id;
+    this._path = target.path !== undefined ? target.path : null;
+    this._target = target;
+    this._closing = false;
+    this.listening = true;
+    handles.set(id, this);
+    nextTick(() => this.emit('listening'));
+    return this;
+  }
+
+  _onNative(kind, a, b) {
+    switch (kind) {
+      case 'connection': {
+        const socket = new Socket({ allowHalfOpen: this._options.allowHalfOpen });
+        socket._attach(a);
+        if (this._path !== null) socket._path = this._path;
+        socket._target = this._target;
+        socket._cacheAddresses();
+        this._connections.add(socket);
+        socket.once('close', () => {
+          this._connections.delete(socket);
+          this._maybeClose();
+        });
+        this.emit('connection', socket);
+        break;
+      }
+      case 'error': {
+        const err = new Error(b);
+        err.code = a;
+        this.emit('error', err);
+        break;
+      }
+      case 'close':
+        handles.delete(this._id);
+        this._id = null;
+        this._maybeClose();
+        break;
+    }
+  }
+
+  // Like Node, 'close' waits for the listener and every accepted connection.
+  _maybeClose() {
+    if (this._closing && this._id === null && this._connections.size === 0) {
+      this._closing = false;
+      this.emit('close');
+    }
+  }
+
+  close(cb) {
+    if (this._id === null || this._closing) {
+      if (typeof cb === 'function') {
+        const err = new Error('Server is not running.');
+        err.code = 'ERR_SERVER_NOT_RUNNING';
+        nextTick(cb, err);
+      }
+      return this;
+    }
+    if (typeof cb === 'function') this.once('close', cb);
+    this._closing = true;
+    this.listening = false;
+    native.closeListener(this._id);
+    return this;
+  }
+
+  address() {
+    if (!this.listening || this._id === null) return null;
+    if (this._path !== null) return this._path;
+    return native.listenerAddress(this._id);
+  }
+
+  getConnections(cb) {
+    nextTick(cb, null, this._connections.size);
+    return this;
+  }
+
+  ref() {
+    if (this._id !== null) native.setRef(this._id, true);
+    return this;
+  }
+
+  unref() {
+    if (this._id !== null) native.setRef(this._id, false);
+    return this;
+  }
+}
+
+function createServer(options, connectionListener) {
+  return new Server(options, connectionListener);
+}
+
+function connect(...args) {
+  const options = args[0];
+  const socket = new Socket(typeof options === 'object' && options !== null ? options : {});
+  return socket.connect(...args);
+}
+
+let autoSelectFamilyAttemptTimeout = 10;
+
+module.exports = {
+  Server,
+  Socket,
+  Stream: Socket,
+  connect,
+  createConnection: connect,
+  createServer,
+  getDefaultAutoSelectFamilyAttemptTimeout() { return autoSelectFamilyAttemptTimeout; },
+  setDefaultAutoSelectFamilyAttemptTimeout(value) {
+    if (typeof value === 'number' && value > 0) autoSelectFamilyAttemptTimeout = Math.max(10, value);
+  },
+};
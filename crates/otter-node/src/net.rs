@@ -0,0 +1,1351 @@
+//! `node:net` native core — Unix domain socket and TCP clients and servers.
+//!
+//! # Contents
+//! - [`net_cjs_value`] builds the CommonJS `net` namespace (native core +
+//!   `net.js` shim); the bare `net` specifier re-exports `node:net`.
+//! - [`NetManager`] owns open sockets and listeners.
+//!   [`NetManager::connect_unix`] / [`NetManager::listen_unix`] and
+//!   [`NetManager::connect_tcp`] / [`NetManager::listen_tcp`] open them, and
+//!   every socket and listener reports through one [`NetEventSink`] as a
+//!   stream of [`NetEvent`]s keyed by resource id.
+//!   [`NetManager::socket_addresses`] / [`NetManager::listener_address`]
+//!   back `socket.address()` and `server.address()`.
+//! - [`SocketError`] is the typed failure surface, with Node error codes.
+//!
+//! # Invariants
+//! - A socket path needs both `read` and `write` capability before it is
+//!   connected to or bound; a denial is [`SocketError::PermissionDenied`].
+//!   A TCP endpoint needs `net` capability for its host or `host:port`; a
+//!   denial is [`SocketError::NetworkDenied`].
+//! - Reads and accepts run on one blocking thread per socket / listener.
+//!   Events reach JavaScript only as [`RuntimeTask`]s on the isolate thread,
+//!   where the handler is reacquired from a persistent root. Writes are
+//!   synchronous on the calling thread. A TCP connect resolves and connects
+//!   on its own thread and reports `Connect` (or `Error` + `Close`).
+//! - A socket emits `Close` exactly once: when both directions have finished
+//!   (peer EOF + local `end`) or when it is destroyed. A listener emits
+//!   `Close` once its accept loop exits.
+//! - Each open socket and listener holds a [`RuntimeKeepAlive`] until its
+//!   `Close` event has been delivered.
+//! - `listen_unix` on a path whose socket file refuses connections unlinks
+//!   the stale file and binds again; a live listener is `EADDRINUSE` (and
+//!   sees the probe as one short-lived connection). Closing a listener
+//!   unlinks its socket file.
+//! - On non-Unix targets the Unix entry points fail with
+//!   `ERR_FEATURE_UNAVAILABLE_ON_PLATFORM`; TCP works everywhere.
+//!
+//! # See also
+//! - `net.js` — `net.Socket` / `net.Server` over the native core.
+//! - `napi.rs` — same persistent-root + task-spawner delivery for thread-safe
+//!   functions.
+
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, TcpListener, TcpStream};
+#[cfg(unix)]
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use otter_runtime::{
+    CapabilitySet, OtterError, Runtime, RuntimeExecutionContext, RuntimeKeepAlive, RuntimeLiveness,
+    RuntimeLocal as Local, RuntimeNativeCtx as NativeCtx, RuntimeNativeError as NativeError,
+    RuntimeNativeScope as NativeScope, RuntimePersistentRootId, RuntimeTask, RuntimeTaskSpawner,
+    RuntimeValue as Value, runtime_arg_to_string,
+};
+
+const SHIM: &str = include_str!("net.js");
+const ALIAS_SHIM: &str = "'use strict';\nmodule.exports = require('node:net');\n";
+
+/// Bytes requested per socket read.
+const READ_CHUNK: usize = 64 * 1024;
+
+/// Errors produced by the native `node:net` core.
+#[derive(Debug, thiserror::Error)]
+pub enum SocketError {
+    /// Filesystem permission denied for the socket path.
+    #[error("permission denied for socket `{}`", path.display())]
+    PermissionDenied {
+        /// Socket path that was rejected.
+        path: PathBuf,
+    },
+    /// Network permission denied for a TCP endpoint.
+    #[error("network permission denied for `{target}`")]
+    NetworkDenied {
+        /// `host:port` that was rejected.
+        target: String,
+    },
+    /// Unix domain sockets are unavailable on this platform.
+    #[error("Unix domain sockets are not supported on this platform")]
+    Unsupported,
+    /// The socket or listener id is not open.
+    #[error("socket {id} is closed")]
+    Closed {
+        /// Resource id that was looked up.
+        id: u64,
+    },
+    /// A socket system call failed.
+    #[error("{syscall} {code} {address}")]
+    Io {
+        /// Node syscall label (`connect`, `listen`, `write`, …).
+        syscall: &'static str,
+        /// Node error code (`ENOENT`, `ECONNREFUSED`, …).
+        code: &'static str,
+        /// Socket path or `host:port` involved in the call.
+        address: String,
+    },
+}
+
+impl SocketError {
+    /// Node-compatible `err.code` for this error.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::PermissionDenied { .. } | Self::NetworkDenied { .. } => "EACCES",
+            Self::Unsupported => "ERR_FEATURE_UNAVAILABLE_ON_PLATFORM",
+            Self::Closed { .. } => "ERR_SOCKET_CLOSED",
+            Self::Io { code, .. } => code,
+        }
+    }
+
+    fn io(syscall: &'static str, address: impl std::fmt::Display, err: &std::io::Error) -> Self {
+        Self::Io {
+            syscall,
+            code: io_code(err),
+            address: address.to_string(),
+        }
+    }
+
+    fn into_native(self) -> NativeError {
+        NativeError::Coded {
+            kind: otter_vm::ErrorKind::Error,
+            code: self.code(),
+            message: self.to_string(),
+        }
+    }
+}
+
+/// Result alias for `node:net`.
+pub type SocketResult<T> = Result<T, SocketError>;
+
+/// One event reported by a socket or listener.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetEvent {
+    /// An outgoing TCP connection was established.
+    Connect,
+    /// A listener accepted a connection; `socket` is the new socket's id.
+    Connection {
+        /// Id of the accepted socket.
+        socket: u64,
+    },
+    /// Bytes read from a socket.
+    Data(Vec<u8>),
+    /// The peer finished writing.
+    End,
+    /// A connect, read or accept failed.
+    Error {
+        /// Node error code.
+        code: &'static str,
+        /// Human-readable description.
+        message: String,
+    },
+    /// The socket or listener is closed; its id is retired.
+    Close,
+}
+
+/// Receiver for every [`NetEvent`], called from socket threads.
+pub type NetEventSink = Arc<dyn Fn(u64, NetEvent) + Send + Sync>;
+
+/// Local and remote endpoints of a socket. Unix domain sockets have neither.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SocketAddresses {
+    /// The socket's own endpoint.
+    pub local: Option<SocketAddr>,
+    /// The peer's endpoint.
+    pub remote: Option<SocketAddr>,
+}
+
+/// Registry of open sockets and listeners, Unix domain and TCP.
+#[derive(Clone)]
+pub struct NetManager {
+    inner: Arc<NetInner>,
+}
+
+struct NetInner {
+    sink: NetEventSink,
+    next_id: AtomicU64,
+    sockets: Mutex<HashMap<u64, SocketEntry>>,
+    listeners: Mutex<HashMap<u64, ListenerEntry>>,
+    /// TCP connects still in flight, mapped to whether they were destroyed.
+    /// Lock before `sockets` when both are needed.
+    connecting: Mutex<HashMap<u64, bool>>,
+}
+
+/// A connected socket of either family.
+enum Stream {
+    #[cfg(unix)]
+    Unix(UnixStream),
+    Tcp(TcpStream),
+}
+
+impl Stream {
+    fn try_clone(&self) -> std::io::Result<Self> {
+        match self {
+            #[cfg(unix)]
+            Self::Unix(stream) => stream.try_clone().map(Self::Unix),
+            Self::Tcp(stream) => stream.try_clone().map(Self::Tcp),
+        }
+    }
+
+    fn shutdown(&self, how: Shutdown) -> std::io::Result<()> {
+        match self {
+            #[cfg(unix)]
+            Self::Unix(stream) => stream.shutdown(how),
+            Self::Tcp(stream) => stream.shutdown(how),
+        }
+    }
+
+    fn addresses(&self) -> SocketAddresses {
+        match self {
+            #[cfg(unix)]
+            Self::Unix(_) => SocketAddresses::default(),
+            Self::Tcp(stream) => SocketAddresses {
+                local: stream.local_addr().ok(),
+                remote: stream.peer_addr().ok(),
+            },
+        }
+    }
+}
+
+impl Read for Stream {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            #[cfg(unix)]
+            Self::Unix(stream) => stream.read(buf),
+            Self::Tcp(stream) => stream.read(buf),
+        }
+    }
+}
+
+impl Write for Stream {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            #[cfg(unix)]
+            Self::Unix(stream) => stream.write(buf),
+            Self::Tcp(stream) => stream.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            #[cfg(unix)]
+            Self::Unix(stream) => stream.flush(),
+            Self::Tcp(stream) => stream.flush(),
+        }
+    }
+}
+
+/// A bound listener of either family.
+enum Listener {
+    #[cfg(unix)]
+    Unix(UnixListener),
+    Tcp(TcpListener),
+}
+
+impl Listener {
+    fn accept(&self) -> std::io::Result<Stream> {
+        match self {
+            #[cfg(unix)]
+            Self::Unix(listener) => listener.accept().map(|(stream, _)| Stream::Unix(stream)),
+            Self::Tcp(listener) => listener.accept().map(|(stream, _)| Stream::Tcp(stream)),
+        }
+    }
+}
+
+/// Where a listener is bound; closing it connects here to wake the accept.
+enum ListenerAddress {
+    #[cfg(unix)]
+    Path(PathBuf),
+    Inet(SocketAddr),
+}
+
+struct SocketEntry {
+    stream: Stream,
+    address: String,
+    read_done: bool,
+    write_done: bool,
+}
+
+struct ListenerEntry {
+    bound: ListenerAddress,
+    closed: Arc<AtomicBool>,
+}
+
+impl NetManager {
+    /// Create a manager whose sockets report to `sink`.
+    pub fn new(sink: NetEventSink) -> Self {
+        Self {
+            inner: Arc::new(NetInner {
+                sink,
+                next_id: AtomicU64::new(1),
+                sockets: Mutex::new(HashMap::new()),
+                listeners: Mutex::new(HashMap::new()),
+                connecting: Mutex::new(HashMap::new()),
+            }),
+        }
+    }
+
+    /// Connect to the Unix domain socket at `path`; returns the socket id.
+    ///
+    /// # Errors
+    /// [`SocketError::PermissionDenied`] without read+write access to `path`,
+    /// [`SocketError::Io`] when the connection fails.
+    #[cfg(unix)]
+    pub fn connect_unix(&self, path: &Path, caps: &CapabilitySet) -> SocketResult<u64> {
+        require_path(path, caps)?;
+        let stream = UnixStream::connect(path)
+            .map_err(|e| SocketError::io("connect", path.display(), &e))?;
+        let id = self.insert_socket(Stream::Unix(stream), path.display().to_string());
+        self.start_reader(id)?;
+        Ok(id)
+    }
+
+    /// Bind and listen on the Unix domain socket at `path`; returns the
+    /// listener id. A stale socket file left by a dead listener is unlinked
+    /// and the bind retried.
+    ///
+    /// # Errors
+    /// [`SocketError::PermissionDenied`] without read+write access to `path`,
+    /// [`SocketError::Io`] (`EADDRINUSE` for a live listener) when binding
+    /// fails.
+    #[cfg(unix)]
+    pub fn listen_unix(&self, path: &Path, caps: &CapabilitySet) -> SocketResult<u64> {
+        require_path(path, caps)?;
+        let fail = |e: std::io::Error| SocketError::io("listen", path.display(), &e);
+        let listener = match UnixListener::bind(path) {
+            Ok(listener) => listener,
+            Err(e) if e.kind() == std::io::ErrorKind::AddrInUse && is_stale(path) => {
+                std::fs::remove_file(path).map_err(fail)?;
+                UnixListener::bind(path).map_err(fail)?
+            }
+            Err(e) => return Err(fail(e)),
+        };
+        self.start_listener(
+            Listener::Unix(listener),
+            ListenerAddress::Path(path.to_path_buf()),
+            path.display().to_string(),
+        )
+    }
+
+    /// Start connecting to `host:port` over TCP; returns the socket id at
+    /// once. Name resolution and the connect run on a worker thread, which
+    /// reports [`NetEvent::Connect`], or an `Error` and `Close`.
+    ///
+    /// # Errors
+    /// [`SocketError::NetworkDenied`] when neither `host` nor `host:port` is
+    /// allowed by the `net` capability.
+    pub fn connect_tcp(&self, host: &str, port: u16, caps: &CapabilitySet) -> SocketResult<u64> {
+        require_net(host, port, caps)?;
+        let id = self.next_id();
+        lock(&self.inner.connecting).insert(id, false);
+        let manager = self.clone();
+        let target = (host.to_string(), port);
+        std::thread::Builder::new()
+            .name("otter-net-connect".to_string())
+            .spawn(move || manager.finish_connect(id, &target.0, target.1))
+            .map_err(|e| {
+                lock(&self.inner.connecting).remove(&id);
+                SocketError::io("connect", format!("{host}:{port}"), &e)
+            })?;
+        Ok(id)
+    }
+
+    /// Bind and listen on `host:port` over TCP; returns the listener id.
+    /// Port `0` picks an ephemeral port (see [`Self::listener_address`]).
+    /// The unspecified IPv6 host `::` falls back to `0.0.0.0` when IPv6 is
+    /// unavailable.
+    ///
+    /// # Errors
+    /// [`SocketError::NetworkDenied`] when neither `host` nor `host:port` is
+    /// allowed by the `net` capability, [`SocketError::Io`] when binding
+    /// fails.
+    pub fn listen_tcp(&self, host: &str, port: u16, caps: &CapabilitySet) -> SocketResult<u64> {
+        require_net(host, port, caps)?;
+        let address = format!("{host}:{port}");
+        let listener = match TcpListener::bind((host, port)) {
+            Err(e) if host == "::" && e.kind() != std::io::ErrorKind::AddrInUse => {
+                TcpListener::bind((Ipv4Addr::UNSPECIFIED, port))
+            }
+            other => other,
+        }
+        .map_err(|e| SocketError::io("listen", &address, &e))?;
+        let local = listener
+            .local_addr()
+            .map_err(|e| SocketError::io("listen", &address, &e))?;
+        self.start_listener(
+            Listener::Tcp(listener),
+            ListenerAddress::Inet(local),
+            address,
+        )
+    }
+
+    /// Write `data` to socket `id`, blocking until it is fully written.
+    ///
+    /// # Errors
+    /// [`SocketError::Closed`] for an unknown id, [`SocketError::Io`] when
+    /// the write fails.
+    pub fn write(&self, id: u64, data: &[u8]) -> SocketResult<()> {
+        let (mut stream, address) = {
+            let sockets = lock(&self.inner.sockets);
+            let entry = sockets.get(&id).ok_or(SocketError::Closed { id })?;
+            let stream = entry
+                .stream
+                .try_clone()
+                .map_err(|e| SocketError::io("write", &entry.address, &e))?;
+            (stream, entry.address.clone())
+        };
+        stream
+            .write_all(data)
+            .map_err(|e| SocketError::io("write", &address, &e))
+    }
+
+    /// Finish the writing side of socket `id`. The socket closes once the
+    /// peer has also finished.
+    ///
+    /// # Errors
+    /// [`SocketError::Closed`] for an unknown id.
+    pub fn end(&self, id: u64) -> SocketResult<()> {
+        let mut sockets = lock(&self.inner.sockets);
+        let entry = sockets.get_mut(&id).ok_or(SocketError::Closed { id })?;
+        let _ = entry.stream.shutdown(Shutdown::Write);
+        entry.write_done = true;
+        if entry.read_done {
+            sockets.remove(&id);
+            (self.inner.sink)(id, NetEvent::Close);
+        }
+        Ok(())
+    }
+
+    /// Close socket `id` in both directions. A TCP connect still in flight
+    /// is abandoned and reports `Close` when it settles. Unknown ids are
+    /// ignored.
+    pub fn destroy(&self, id: u64) {
+        let mut connecting = lock(&self.inner.connecting);
+        if let Some(destroyed) = connecting.get_mut(&id) {
+            *destroyed = true;
+            return;
+        }
+        let removed = lock(&self.inner.sockets).remove(&id);
+        drop(connecting);
+        if let Some(entry) = removed {
+            let _ = entry.stream.shutdown(Shutdown::Both);
+            (self.inner.sink)(id, NetEvent::Close);
+        }
+    }
+
+    /// Stop listener `id`; a Unix listener also unlinks its socket file. The
+    /// listener's `Close` event follows once its accept loop exits. Unknown
+    /// ids are ignored.
+    pub fn close_listener(&self, id: u64) {
+        let removed = lock(&self.inner.listeners).remove(&id);
+        if let Some(entry) = removed {
+            entry.closed.store(true, Ordering::Release);
+            // Wake the blocking accept so the loop observes the flag.
+            match entry.bound {
+                #[cfg(unix)]
+                ListenerAddress::Path(path) => {
+                    let _ = UnixStream::connect(&path);
+                    let _ = std::fs::remove_file(&path);
+                }
+                ListenerAddress::Inet(address) => {
+                    let _ = TcpStream::connect(wake_address(address));
+                }
+            }
+        }
+    }
+
+    /// Local and remote endpoints of socket `id`.
+    ///
+    /// # Errors
+    /// [`SocketError::Closed`] for an unknown id.
+    pub fn socket_addresses(&self, id: u64) -> SocketResult<SocketAddresses> {
+        lock(&self.inner.sockets)
+            .get(&id)
+            .map(|entry| entry.stream.addresses())
+            .ok_or(SocketError::Closed { id })
+    }
+
+    /// The bound address of TCP listener `id` (with the assigned port when
+    /// it listened on port `0`); `None` for a Unix listener.
+    ///
+    /// # Errors
+    /// [`SocketError::Closed`] for an unknown id.
+    pub fn listener_address(&self, id: u64) -> SocketResult<Option<SocketAddr>> {
+        let listeners = lock(&self.inner.listeners);
+        let entry = listeners.get(&id).ok_or(SocketError::Closed { id })?;
+        Ok(match entry.bound {
+            #[cfg(unix)]
+            ListenerAddress::Path(_) => None,
+            ListenerAddress::Inet(address) => Some(address),
+        })
+    }
+
+    fn finish_connect(&self, id: u64, host: &str, port: u16) {
+        let sink = &self.inner.sink;
+        let result = TcpStream::connect((host, port));
+        let mut connecting = lock(&self.inner.connecting);
+        let destroyed = connecting.remove(&id).unwrap_or(true);
+        match result {
+            Ok(stream) if !destroyed => {
+                let address = stream
+                    .peer_addr()
+                    .map_or_else(|_| format!("{host}:{port}"), |peer| peer.to_string());
+                self.insert_socket_at(id, Stream::Tcp(stream), address);
+                drop(connecting);
+                sink(id, NetEvent::Connect);
+                if let Err(err) = self.start_reader(id) {
+                    sink(
+                        id,
+                        NetEvent::Error {
+                            code: err.code(),
+                            message: err.to_string(),
+                        },
+                    );
+                    self.destroy(id);
+                }
+            }
+            Ok(_) => {
+                drop(connecting);
+                sink(id, NetEvent::Close);
+            }
+            Err(e) => {
+                drop(connecting);
+                if !destroyed {
+                    let err = SocketError::io("connect", format!("{host}:{port}"), &e);
+                    sink(
+                        id,
+                        NetEvent::Error {
+                            code: err.code(),
+                            message: err.to_string(),
+                        },
+                    );
+                }
+                sink(id, NetEvent::Close);
+            }
+        }
+    }
+
+    fn start_listener(
+        &self,
+        listener: Listener,
+        bound: ListenerAddress,
+        address: String,
+    ) -> SocketResult<u64> {
+        let id = self.next_id();
+        let closed = Arc::new(AtomicBool::new(false));
+        lock(&self.inner.listeners).insert(
+            id,
+            ListenerEntry {
+                bound,
+                closed: closed.clone(),
+            },
+        );
+        let manager = self.clone();
+        let label = address.clone();
+        std::thread::Builder::new()
+            .name("otter-net-accept".to_string())
+            .spawn(move || manager.accept_loop(id, listener, &address, &closed))
+            .map_err(|e| {
+                lock(&self.inner.listeners).remove(&id);
+                SocketError::io("listen", label, &e)
+            })?;
+        Ok(id)
+    }
+
+    fn insert_socket(&self, stream: Stream, address: String) -> u64 {
+        let id = self.next_id();
+        self.insert_socket_at(id, stream, address);
+        id
+    }
+
+    fn insert_socket_at(&self, id: u64, stream: Stream, address: String) {
+        lock(&self.inner.sockets).insert(
+            id,
+            SocketEntry {
+                stream,
+                address,
+                read_done: false,
+                write_done: false,
+            },
+        );
+    }
+
+    fn start_reader(&self, id: u64) -> SocketResult<()> {
+        let (stream, address) = {
+            let sockets = lock(&self.inner.sockets);
+            let entry = sockets.get(&id).ok_or(SocketError::Closed { id })?;
+            let stream = entry
+                .stream
+                .try_clone()
+                .map_err(|e| SocketError::io("read", &entry.address, &e))?;
+            (stream, entry.address.clone())
+        };
+        let manager = self.clone();
+        std::thread::Builder::new()
+            .name("otter-net-read".to_string())
+            .spawn(move || manager.read_loop(id, stream))
+            .map_err(|e| SocketError::io("read", &address, &e))?;
+        Ok(())
+    }
+
+    fn read_loop(&self, id: u64, mut stream: Stream) {
+        let sink = &self.inner.sink;
+        let mut buf = vec![0u8; READ_CHUNK];
+        loop {
+            match stream.read(&mut buf) {
+                Ok(0) => {
+                    let mut sockets = lock(&self.inner.sockets);
+                    let Some(entry) = sockets.get_mut(&id) else {
+                        return;
+                    };
+                    entry.read_done = true;
+                    sink(id, NetEvent::End);
+                    if entry.write_done {
+                        sockets.remove(&id);
+                        sink(id, NetEvent::Close);
+                    }
+                    return;
+                }
+                Ok(n) => sink(id, NetEvent::Data(buf[..n].to_vec())),
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(e) => {
+                    let removed = lock(&self.inner.sockets).remove(&id);
+                    // A destroyed socket already reported its close.
+                    if removed.is_some() {
+                        sink(
+                            id,
+                            NetEvent::Error {
+                                code: io_code(&e),
+                                message: format!("read {} {e}", io_code(&e)),
+                            },
+                        );
+                        sink(id, NetEvent::Close);
+                    }
+                    return;
+                }
+            }
+        }
+    }
+
+    fn accept_loop(&self, id: u64, listener: Listener, address: &str, closed: &AtomicBool) {
+        let sink = &self.inner.sink;
+        loop {
+            let conn = listener.accept();
+            if closed.load(Ordering::Acquire) {
+                break;
+            }
+            match conn {
+                Ok(stream) => {
+                    let peer = stream
+                        .addresses()
+                        .remote
+                        .map_or_else(|| address.to_string(), |peer| peer.to_string());
+                    let socket = self.insert_socket(stream, peer);
+                    // Announce the socket before its reader can emit data.
+                    sink(id, NetEvent::Connection { socket });
+                    if let Err(err) = self.start_reader(socket) {
+                        sink(
+                            socket,
+                            NetEvent::Error {
+                                code: err.code(),
+                                message: err.to_string(),
+                            },
+                        );
+                        self.destroy(socket);
+                    }
+                }
+                Err(e) => sink(
+                    id,
+                    NetEvent::Error {
+                        code: io_code(&e),
+                        message: format!("accept {} {e}", io_code(&e)),
+                    },
+                ),
+            }
+        }
+        sink(id, NetEvent::Close);
+    }
+
+    #[cfg(not(unix))]
+    pub fn connect_unix(&self, _path: &Path, _caps: &CapabilitySet) -> SocketResult<u64> {
+        Err(SocketError::Unsupported)
+    }
+
+    #[cfg(not(unix))]
+    pub fn listen_unix(&self, _path: &Path, _caps: &CapabilitySet) -> SocketResult<u64> {
+        Err(SocketError::Unsupported)
+    }
+
+    fn next_id(&self) -> u64 {
+        self.inner.next_id.fetch_add(1, Ordering::Relaxed)
+    }
+}
+
+impl std::fmt::Debug for NetManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NetManager").finish_non_exhaustive()
+    }
+}
+
+#[cfg(unix)]
+fn require_path(path: &Path, caps: &CapabilitySet) -> SocketResult<()> {
+    if caps.read.matches_path(path) && caps.write.matches_path(path) {
+        Ok(())
+    } else {
+        Err(SocketError::PermissionDenied {
+            path: path.to_path_buf(),
+        })
+    }
+}
+
+/// The `net` capability grants a TCP endpoint by host or by `host:port`,
+/// like `fetch`.
+fn require_net(host: &str, port: u16, caps: &CapabilitySet) -> SocketResult<()> {
+    let target = format!("{host}:{port}");
+    if caps.net.matches(host) || caps.net.matches(&target) {
+        Ok(())
+    } else {
+        Err(SocketError::NetworkDenied { target })
+    }
+}
+
+/// A socket file is stale when nothing accepts connections on it.
+#[cfg(unix)]
+fn is_stale(path: &Path) -> bool {
+    matches!(
+        UnixStream::connect(path),
+        Err(e) if e.kind() == std::io::ErrorKind::ConnectionRefused
+    )
+}
+
+/// Loopback stand-in for a listener bound to the unspecified address.
+fn wake_address(mut address: SocketAddr) -> SocketAddr {
+    if address.ip().is_unspecified() {
+        address.set_ip(match address {
+            SocketAddr::V4(_) => Ipv4Addr::LOCALHOST.into(),
+            SocketAddr::V6(_) => Ipv6Addr::LOCALHOST.into(),
+        });
+    }
+    address
+}
+
+fn io_code(err: &std::io::Error) -> &'static str {
+    use std::io::ErrorKind;
+    match err.kind() {
+        ErrorKind::NotFound => "ENOENT",
+        ErrorKind::PermissionDenied => "EACCES",
+        ErrorKind::ConnectionRefused => "ECONNREFUSED",
+        ErrorKind::ConnectionReset => "ECONNRESET",
+        ErrorKind::AddrInUse => "EADDRINUSE",
+        ErrorKind::AddrNotAvailable => "EADDRNOTAVAIL",
+        ErrorKind::BrokenPipe => "EPIPE",
+        ErrorKind::NotConnected => "ENOTCONN",
+        ErrorKind::InvalidInput => "EINVAL",
+        ErrorKind::TimedOut => "ETIMEDOUT",
+        _ => "EIO",
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn bytes_to_latin1(bytes: &[u8]) -> String {
+    bytes.iter().map(|&b| b as char).collect()
+}
+
+fn latin1_to_bytes(s: &str) -> Vec<u8> {
+    s.chars().map(|c| c as u32 as u8).collect()
+}
+
+// ---- Event delivery ----------------------------------------------------------
+
+/// Isolate-side target for one bound [`NetManager`].
+struct NetTarget {
+    spawner: RuntimeTaskSpawner,
+    context: RuntimeExecutionContext,
+    handler: RuntimePersistentRootId,
+    holds: Mutex<HashMap<u64, RuntimeKeepAlive>>,
+}
+
+impl NetTarget {
+    fn retain(&self, id: u64) {
+        let hold = self.spawner.retain_keep_alive(RuntimeLiveness::Ref);
+        lock(&self.holds).insert(id, hold);
+    }
+}
+
+struct NetEventTask {
+    target: Arc<NetTarget>,
+    id: u64,
+    event: NetEvent,
+}
+
+impl RuntimeTask for NetEventTask {
+    fn run(self: Box<Self>, runtime: &mut Runtime) -> Result<(), OtterError> {
+        let NetEventTask { target, id, event } = *self;
+        let closes = event == NetEvent::Close;
+        let context = target.context.clone();
+        let handler = target.handler;
+        let result = runtime.run_native_event(&context, move |ctx| {
+            let Some(handler) = ctx.persistent_root_get(handler) else {
+                return Ok(Value::undefined());
+            };
+            ctx.scope(|mut scope| {
+                let handler = scope.value(handler);
+                let this = scope.undefined();
+                let id = scope.number(id as f64);
+                let (kind, first, second) = match event {
+                    NetEvent::Connect => ("connect", scope.undefined(), scope.undefined()),
+                    NetEvent::Connection { socket } => {
+                        ("connection", scope.number(socket as f64), scope.undefined())
+                    }
+                    NetEvent::Data(bytes) => {
+                        let data = scope.string(&bytes_to_latin1(&bytes))?;
+                        ("data", data, scope.undefined())
+                    }
+                    NetEvent::End => ("end", scope.undefined(), scope.undefined()),
+                    NetEvent::Error { code, message } => {
+                        let code = scope.string(code)?;
+                        let message = scope.string(&message)?;
+                        ("error", code, message)
+                    }
+                    NetEvent::Close => ("close", scope.undefined(), scope.undefined()),
+                };
+                let kind = scope.string(kind)?;
+                scope.call(handler, this, &[id, kind, first, second])?;
+                let done = scope.undefined();
+                Ok(scope.finish(done))
+            })
+        });
+        if closes && let Some(hold) = lock(&target.holds).remove(&id) {
+            hold.close();
+        }
+        result
+    }
+}
+
+/// Per-realm native state: the manager is created when `net.js` binds its
+/// event handler.
+struct NetBinding {
+    caps: CapabilitySet,
+    spawner: Option<RuntimeTaskSpawner>,
+    bound: OnceLock<(NetManager, Arc<NetTarget>)>,
+}
+
+impl NetBinding {
+    fn bound(&self) -> Result<&(NetManager, Arc<NetTarget>), NativeError> {
+        self.bound.get().ok_or_else(|| NativeError::Coded {
+            kind: otter_vm::ErrorKind::Error,
+            code: "ERR_INVALID_STATE",
+            message: "net event handler is not bound".to_string(),
+        })
+    }
+}
+
+// ---- CommonJS surface --------------------------------------------------------
+
+/// CommonJS export: the `net` namespace built by `net.js`.
+pub fn net_cjs_value<'scope>(
+    scope: &mut NativeScope<'scope, '_>,
+    _caps: &CapabilitySet,
+    _runtime_task_spawner: Option<RuntimeTaskSpawner>,
+    module: Local<'scope>,
+    require: Local<'scope>,
+) -> Result<Local<'scope>, NativeError> {
+    otter_runtime::run_builtin_cjs_shim(scope, "node:net", SHIM, module, require)
+}
+
+/// CommonJS export: bare `net`, sharing the `node:net` instance (and with it
+/// the single native event binding).
+pub fn net_alias_cjs_value<'scope>(
+    scope: &mut NativeScope<'scope, '_>,
+    _caps: &CapabilitySet,
+    _runtime_task_spawner: Option<RuntimeTaskSpawner>,
+    module: Local<'scope>,
+    require: Local<'scope>,
+) -> Result<Local<'scope>, NativeError> {
+    otter_runtime::run_builtin_cjs_shim(scope, "net", ALIAS_SHIM, module, require)
+}
+
+/// Hidden CommonJS row that supplies the capability-gated native `net` core.
+pub fn net_native_cjs_value<'scope>(
+    scope: &mut NativeScope<'scope, '_>,
+    caps: &CapabilitySet,
+    runtime_task_spawner: Option<RuntimeTaskSpawner>,
+    _module: Local<'scope>,
+    _require: Local<'scope>,
+) -> Result<Local<'scope>, NativeError> {
+    native_value(scope, caps, runtime_task_spawner)
+}
+
+/// Build the raw core: `{ bind, connectUnix, listenUnix, connectTcp,
+/// listenTcp, write, end, destroy, closeListener, socketAddress,
+/// listenerAddress, setRef }`. Every method captures the shared binding.
+fn native_value<'scope>(
+    scope: &mut NativeScope<'scope, '_>,
+    caps: &CapabilitySet,
+    runtime_task_spawner: Option<RuntimeTaskSpawner>,
+) -> Result<Local<'scope>, NativeError> {
+    let binding = Arc::new(NetBinding {
+        caps: caps.clone(),
+        spawner: runtime_task_spawner,
+        bound: OnceLock::new(),
+    });
+    let object = scope.object()?;
+    macro_rules! m {
+        ($name:literal, $len:expr, $f:ident) => {{
+            let binding = binding.clone();
+            let method = scope.native_closure(
+                $name,
+                $len,
+                &[],
+                move |ctx: &mut NativeCtx<'_>, args: &[Value], _captures: &[Value]| {
+                    $f(ctx, args, &binding)
+                },
+            )?;
+            scope.set(object, $name, method)?;
+        }};
+    }
+
+    m!("bind", 1, bind);
+    m!("connectUnix", 1, connect_unix);
+    m!("listenUnix", 1, listen_unix);
+    m!("connectTcp", 2, connect_tcp);
+    m!("listenTcp", 2, listen_tcp);
+    m!("write", 2, write);
+    m!("end", 1, end);
+    m!("destroy", 1, destroy);
+    m!("closeListener", 1, close_listener);
+    m!("socketAddress", 1, socket_address);
+    m!("listenerAddress", 1, listener_address);
+    m!("setRef", 2, set_ref);
+
+    Ok(object)
+}
+
+/// `bind(handler)` — install `handler(id, kind, a, b)` as the receiver for
+/// every socket event.
+fn bind(
+    ctx: &mut NativeCtx<'_>,
+    args: &[Value],
+    binding: &NetBinding,
+) -> Result<Value, NativeError> {
+    let handler = args.first().copied().unwrap_or_else(Value::undefined);
+    if !handler.is_callable() {
+        return Err(crate::type_error("net.bind", "handler must be a function"));
+    }
+    let (Some(spawner), Some(context)) =
+        (binding.spawner.clone(), ctx.execution_context().cloned())
+    else {
+        return Err(SocketError::Unsupported.into_native());
+    };
+    if binding.bound.get().is_some() {
+        return Err(NativeError::Coded {
+            kind: otter_vm::ErrorKind::Error,
+            code: "ERR_INVALID_STATE",
+            message: "net event handler is already bound".to_string(),
+        });
+    }
+    let target = Arc::new(NetTarget {
+        spawner,
+        context,
+        handler: ctx.persistent_root_insert(handler),
+        holds: Mutex::new(HashMap::new()),
+    });
+    let sink_target = target.clone();
+    let sink: NetEventSink = Arc::new(move |id, event| {
+        if let NetEvent::Connection { socket } = event {
+            sink_target.retain(socket);
+        }
+        let task = NetEventTask {
+            target: sink_target.clone(),
+            id,
+            event,
+        };
+        let _ = sink_target.spawner.enqueue(task, RuntimeLiveness::Unref);
+    });
+    let _ = binding.bound.set((NetManager::new(sink), target));
+    Ok(Value::undefined())
+}
+
+fn connect_unix(
+    ctx: &mut NativeCtx<'_>,
+    args: &[Value],
+    binding: &NetBinding,
+) -> Result<Value, NativeError> {
+    let path = PathBuf::from(runtime_arg_to_string(args, 0, ctx.heap()));
+    let (manager, target) = binding.bound()?;
+    let id = manager
+        .connect_unix(&path, &binding.caps)
+        .map_err(SocketError::into_native)?;
+    target.retain(id);
+    Ok(Value::number_f64(id as f64))
+}
+
+fn listen_unix(
+    ctx: &mut NativeCtx<'_>,
+    args: &[Value],
+    binding: &NetBinding,
+) -> Result<Value, NativeError> {
+    let path = PathBuf::from(runtime_arg_to_string(args, 0, ctx.heap()));
+    let (manager, target) = binding.bound()?;
+    let id = manager
+        .listen_unix(&path, &binding.caps)
+        .map_err(SocketError::into_native)?;
+    target.retain(id);
+    Ok(Value::number_f64(id as f64))
+}
+
+/// `connectTcp(host, port)` — start a TCP connect; the socket's `connect`
+/// (or `error` + `close`) event follows.
+fn connect_tcp(
+    ctx: &mut NativeCtx<'_>,
+    args: &[Value],
+    binding: &NetBinding,
+) -> Result<Value, NativeError> {
+    let host = runtime_arg_to_string(args, 0, ctx.heap());
+    let port = port_arg(args)?;
+    let (manager, target) = binding.bound()?;
+    let id = manager
+        .connect_tcp(&host, port, &binding.caps)
+        .map_err(SocketError::into_native)?;
+    target.retain(id);
+    Ok(Value::number_f64(id as f64))
+}
+
+fn listen_tcp(
+    ctx: &mut NativeCtx<'_>,
+    args: &[Value],
+    binding: &NetBinding,
+) -> Result<Value, NativeError> {
+    let host = runtime_arg_to_string(args, 0, ctx.heap());
+    let port = port_arg(args)?;
+    let (manager, target) = binding.bound()?;
+    let id = manager
+        .listen_tcp(&host, port, &binding.caps)
+        .map_err(SocketError::into_native)?;
+    target.retain(id);
+    Ok(Value::number_f64(id as f64))
+}
+
+fn write(
+    ctx: &mut NativeCtx<'_>,
+    args: &[Value],
+    binding: &NetBinding,
+) -> Result<Value, NativeError> {
+    let id = id_arg(args);
+    let data = latin1_to_bytes(&runtime_arg_to_string(args, 1, ctx.heap()));
+    let (manager, _) = binding.bound()?;
+    manager.write(id, &data).map_err(SocketError::into_native)?;
+    Ok(Value::undefined())
+}
+
+fn end(
+    _ctx: &mut NativeCtx<'_>,
+    args: &[Value],
+    binding: &NetBinding,
+) -> Result<Value, NativeError> {
+    let (manager, _) = binding.bound()?;
+    manager
+        .end(id_arg(args))
+        .map_err(SocketError::into_native)?;
+    Ok(Value::undefined())
+}
+
+fn destroy(
+    _ctx: &mut NativeCtx<'_>,
+    args: &[Value],
+    binding: &NetBinding,
+) -> Result<Value, NativeError> {
+    let (manager, _) = binding.bound()?;
+    manager.destroy(id_arg(args));
+    Ok(Value::undefined())
+}
+
+fn close_listener(
+    _ctx: &mut NativeCtx<'_>,
+    args: &[Value],
+    binding: &NetBinding,
+) -> Result<Value, NativeError> {
+    let (manager, _) = binding.bound()?;
+    manager.close_listener(id_arg(args));
+    Ok(Value::undefined())
+}
+
+/// `socketAddress(id)` — `{ localAddress, localPort, localFamily,
+/// remoteAddress, remotePort, remoteFamily }`; fields are `undefined` for a
+/// Unix domain socket.
+fn socket_address(
+    ctx: &mut NativeCtx<'_>,
+    args: &[Value],
+    binding: &NetBinding,
+) -> Result<Value, NativeError> {
+    let (manager, _) = binding.bound()?;
+    let addresses = manager
+        .socket_addresses(id_arg(args))
+        .map_err(SocketError::into_native)?;
+    ctx.scope(|mut scope| {
+        let object = scope.object()?;
+        for (prefix, address) in [("local", addresses.local), ("remote", addresses.remote)] {
+            let Some(address) = address else {
+                continue;
+            };
+            let ip = scope.string(&address.ip().to_string())?;
+            scope.set(object, &format!("{prefix}Address"), ip)?;
+            let port = scope.number(f64::from(address.port()));
+            scope.set(object, &format!("{prefix}Port"), port)?;
+            let family = scope.string(family(&address))?;
+            scope.set(object, &format!("{prefix}Family"), family)?;
+        }
+        Ok(scope.finish(object))
+    })
+}
+
+/// `listenerAddress(id)` — `{ address, family, port }` for a TCP listener,
+/// `null` for a Unix one.
+fn listener_address(
+    ctx: &mut NativeCtx<'_>,
+    args: &[Value],
+    binding: &NetBinding,
+) -> Result<Value, NativeError> {
+    let (manager, _) = binding.bound()?;
+    let Some(address) = manager
+        .listener_address(id_arg(args))
+        .map_err(SocketError::into_native)?
+    else {
+        return Ok(Value::null());
+    };
+    ctx.scope(|mut scope| {
+        let object = scope.object()?;
+        let ip = scope.string(&address.ip().to_string())?;
+        scope.set(object, "address", ip)?;
+        let family = scope.string(family(&address))?;
+        scope.set(object, "family", family)?;
+        let port = scope.number(f64::from(address.port()));
+        scope.set(object, "port", port)?;
+        Ok(scope.finish(object))
+    })
+}
+
+/// `setRef(id, ref)` — move a socket's or listener's keep-alive between
+/// ref and unref.
+fn set_ref(
+    _ctx: &mut NativeCtx<'_>,
+    args: &[Value],
+    binding: &NetBinding,
+) -> Result<Value, NativeError> {
+    let (_, target) = binding.bound()?;
+    let referenced = args.get(1).and_then(|v| v.as_boolean()).unwrap_or(false);
+    if let Some(hold) = lock(&target.holds).get(&id_arg(args)) {
+        if referenced {
+            hold.ref_();
+        } else {
+            hold.unref();
+        }
+    }
+    Ok(Value::undefined())
+}
+
+fn family(address: &SocketAddr) -> &'static str {
+    if address.is_ipv4() { "IPv4" } else { "IPv6" }
+}
+
+fn port_arg(args: &[Value]) -> Result<u16, NativeError> {
+    args.get(1)
+        .and_then(|v| v.as_f64())
+        .filter(|n| n.fract() == 0.0 && (0.0..=65535.0).contains(n))
+        .map(|n| n as u16)
+        .ok_or_else(|| NativeError::Coded {
+            kind: otter_vm::ErrorKind::RangeError,
+            code: "ERR_SOCKET_BAD_PORT",
+            message: "port must be an integer >= 0 and < 65536".to_string(),
+        })
+}
+
+fn id_arg(args: &[Value]) -> u64 {
+    args.first()
+        .and_then(|v| v.as_f64())
+        .filter(|n| n.is_finite() && *n >= 0.0)
+        .map(|n| n as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    fn channel_manager() -> (NetManager, mpsc::Receiver<(u64, NetEvent)>) {
+        let (tx, rx) = mpsc::channel();
+        let tx = Mutex::new(tx);
+        let sink: NetEventSink = Arc::new(move |id, event| {
+            let _ = lock(&tx).send((id, event));
+        });
+        (NetManager::new(sink), rx)
+    }
+
+    fn next(rx: &mpsc::Receiver<(u64, NetEvent)>) -> (u64, NetEvent) {
+        rx.recv_timeout(Duration::from_secs(5)).expect("net event")
+    }
+
+    #[test]
+    fn unix_socket_round_trip_reports_events_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("echo.sock");
+        let caps = CapabilitySet::allow_all();
+        let (manager, rx) = channel_manager();
+
+        let listener = manager.listen_unix(&path, &caps).unwrap();
+        let client = manager.connect_unix(&path, &caps).unwrap();
+        let (id, event) = next(&rx);
+        assert_eq!(id, listener);
+        let NetEvent::Connection { socket: server } = event else {
+            panic!("expected connection, got {event:?}");
+        };
+
+        manager.write(client, b"ping").unwrap();
+        manager.end(client).unwrap();
+        let mut received = Vec::new();
+        loop {
+            match next(&rx) {
+                (id, NetEvent::Data(bytes)) if id == server => received.extend(bytes),
+                (id, NetEvent::End) if id == server => break,
+                other => panic!("unexpected event {other:?}"),
+            }
+        }
+        assert_eq!(received, b"ping");
+
+        manager.end(server).unwrap();
+        let mut closed = Vec::new();
+        while closed.len() < 2 {
+            match next(&rx) {
+                (id, NetEvent::Close) => closed.push(id),
+                (id, NetEvent::End) if id == client => {}
+                other => panic!("unexpected event {other:?}"),
+            }
+        }
+        closed.sort_unstable();
+        assert_eq!(closed, vec![client, server]);
+
+        manager.close_listener(listener);
+        assert_eq!(next(&rx), (listener, NetEvent::Close));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn listen_unlinks_stale_socket_but_not_a_live_one() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stale.sock");
+        let caps = CapabilitySet::allow_all();
+        drop(UnixListener::bind(&path).unwrap());
+        assert!(path.exists());
+
+        let (manager, rx) = channel_manager();
+        let listener = manager.listen_unix(&path, &caps).unwrap();
+        let err = manager.listen_unix(&path, &caps).unwrap_err();
+        assert_eq!(err.code(), "EADDRINUSE");
+
+        // The liveness probe shows up as one accepted connection.
+        manager.close_listener(listener);
+        loop {
+            match next(&rx) {
+                (id, NetEvent::Close) if id == listener => break,
+                (id, NetEvent::Connection { socket }) if id == listener => manager.destroy(socket),
+                _ => {}
+            }
+        }
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn tcp_round_trip_reports_connect_and_addresses() {
+        let caps = CapabilitySet::allow_all();
+        let (manager, rx) = channel_manager();
+
+        let listener = manager.listen_tcp("127.0.0.1", 0, &caps).unwrap();
+        let bound = manager.listener_address(listener).unwrap().unwrap();
+        assert!(bound.is_ipv4());
+        assert_ne!(bound.port(), 0);
+
+        let client = manager
+            .connect_tcp("127.0.0.1", bound.port(), &caps)
+            .unwrap();
+        let mut server = None;
+        let mut connected = false;
+        while server.is_none() || !connected {
+            match next(&rx) {
+                (id, NetEvent::Connection { socket }) if id == listener => server = Some(socket),
+                (id, NetEvent::Connect) if id == client => connected = true,
+                other => panic!("unexpected event {other:?}"),
+            }
+        }
+        let server = server.unwrap();
+
+        let outbound = manager.socket_addresses(client).unwrap();
+        let inbound = manager.socket_addresses(server).unwrap();
+        assert_eq!(outbound.remote, Some(bound));
+        assert_eq!(inbound.remote, outbound.local);
+
+        manager.write(client, b"ping").unwrap();
+        match next(&rx) {
+            (id, NetEvent::Data(bytes)) if id == server => assert_eq!(bytes, b"ping"),
+            other => panic!("unexpected event {other:?}"),
+        }
+        manager.destroy(client);
+        manager.destroy(server);
+        manager.close_listener(listener);
+        let mut closed = Vec::new();
+        while closed.len() < 3 {
+            match next(&rx) {
+                (id, NetEvent::Close) => closed.push(id),
+                (_, NetEvent::End | NetEvent::Connection { .. }) => {}
+                other => panic!("unexpected event {other:?}"),
+            }
+        }
+        closed.sort_unstable();
+        assert_eq!(closed, vec![listener, client, server]);
+    }
+
+    #[test]
+    fn tcp_endpoints_require_net_capability() {
+        let (manager, _rx) = channel_manager();
+        let err = manager
+            .listen_tcp("127.0.0.1", 0, &CapabilitySet::default())
+            .unwrap_err();
+        assert_eq!(err.code(), "EACCES");
+        let err = manager
+            .connect_tcp("127.0.0.1", 9, &CapabilitySet::default())
+            .unwrap_err();
+        assert_eq!(err.code(), "EACCES");
+    }
+
+    #[test]
+    fn socket_paths_require_read_and_write_capability() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("denied.sock");
+        let (manager, _rx) = channel_manager();
+        let err = manager
+            .listen_unix(&path, &CapabilitySet::default())
+            .unwrap_err();
+        assert_eq!(err.code(), "EACCES");
+        assert!(!path.exists());
+        let err = manager
+            .connect_unix(&path, &CapabilitySet::default())
+            .unwrap_err();
+        assert_eq!(err.code(), "EACCES");
+    }
+}
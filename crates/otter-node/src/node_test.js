@@ -3,21 +3,41 @@
 // `test/parallel` files that drive their assertions through `node:test`.
 //
 // Semantics that matter for conformance:
-// - `test(name, fn)` runs `fn` immediately (sync) or attaches handlers (async).
+// - `test(name, fn)` / `describe(name, fn)` register into the enclosing
+//   scope; a scope runs its entries in order once its registrations are
+//   done (the top level on the next microtask, a suite right after its
+//   body). Sub-tests made through a context (`t.test`) run immediately so
+//   `await t.test(...)` inside an async test works.
 // - A thrown error or a rejected promise marks the run failed and sets
 //   `process.exitCode = 1`, which the conformance harness reads as failure.
 // - All-pass leaves the exit code untouched (0).
+// - `.skip` and `.todo` entries never run and are counted separately; a
+//   `.only` entry skips every sibling without `.only`. `.each(table)`
+//   registers one test per row.
+// - When the top-level queue drains, a TAP-style summary (`# tests`,
+//   `# pass`, `# fail`, `# skipped`, `# todo`) is logged.
 //
 // The test context `t` exposes the `assert` surface plus sub-`test`, so files
 // written as `test('x', (t) => { t.assert.ok(...) })` and the older
-// `test('x', () => { assert.ok(...) })` both work.
+// `test('x', () => { assert.ok(...) })` both work. Jest-style `expect()`
+// matchers (see node_test_expect.js) are exported as `expect`.
 
 const assert = require('assert');
+const { expect } = require('internal/test_runner/expect');
 
-let failures = 0;
+const counts = { tests: 0, suites: 0, pass: 0, fail: 0, skipped: 0, todo: 0 };
+let reported = 0;
+
+function log(line) {
+  try {
+    console.log(line);
+  } catch {
+    // console may be unavailable; counts and the exit code still apply.
+  }
+}
 
 function fail(name, err) {
-  failures += 1;
+  counts.fail += 1;
   if (typeof process !== 'undefined') process.exitCode = 1;
   const label = name ? `not ok - ${name}` : 'not ok';
   const detail = err && err.stack ? err.stack : String(err);
@@ -28,6 +48,11 @@ function fail(name, err) {
   }
 }
 
+function report(status, name, directive) {
+  reported += 1;
+  log(`${status} ${reported} - ${name}${directive ? ` # ${directive}` : ''}`);
+}
+
 function isThenable(v) {
   return v != null && typeof v.then === 'function';
 }
@@ -70,75 +95,219 @@ function normalize(args) {
   // (name?, options?, fn?) in any of Node's accepted orders.
   let name;
   let fn;
+  let options = {};
   for (const a of args) {
     if (typeof a === 'string') name = a;
     else if (typeof a === 'function') fn = a;
-    // options object is ignored
+    else if (a !== null && typeof a === 'object') options = a;
   }
-  return { name: name || (fn && fn.name) || '<anonymous>', fn };
+  return { name: name || (fn && fn.name) || '<anonymous>', fn, options };
 }
 
-function runOne(name, fn) {
-  if (typeof fn !== 'function') return undefined; // pending/todo with no body
-  const ctx = makeContext(name);
+// Run `body` and settle to `undefined` or the thrown / rejected error.
+function settle(body) {
   try {
-    const result = fn(ctx);
+    const result = body();
+    if (isThenable(result)) return result.then(() => undefined, (err) => err || new Error(String(err)));
+  } catch (err) {
+    return err || new Error(String(err));
+  }
+  return undefined;
+}
+
+function finishTest(name, outcome) {
+  if (outcome === undefined) {
+    counts.pass += 1;
+    report('ok', name);
+  } else {
+    report('not ok', name);
+    fail(name, outcome);
+  }
+}
+
+function runTest(name, fn) {
+  counts.tests += 1;
+  if (typeof fn !== 'function') {
+    // Pending test with no body.
+    counts.pass += 1;
+    report('ok', name);
+    return undefined;
+  }
+  const outcome = settle(() => fn(makeContext(name)));
+  if (isThenable(outcome)) return outcome.then((err) => finishTest(name, err));
+  finishTest(name, outcome);
+  return undefined;
+}
+
+// --- Scopes ----------------------------------------------------------------
+
+function createScope() {
+  return { entries: [], next: 0 };
+}
+
+const rootScope = createScope();
+let currentScope = rootScope;
+let rootRunning = false;
+
+function register(entry) {
+  const scope = currentScope;
+  let resolve;
+  const done = new Promise((r) => { resolve = r; });
+  scope.entries.push({ ...entry, resolve });
+  if (scope === rootScope && !rootRunning) {
+    rootRunning = true;
+    Promise.resolve().then(runRoot);
+  }
+  return done;
+}
+
+function skipEntry(entry, directive) {
+  counts.tests += 1;
+  if (directive === 'TODO') counts.todo += 1;
+  else counts.skipped += 1;
+  report('ok', entry.name, directive);
+}
+
+function runEntry(scope, entry) {
+  const hasOnly = scope.entries.some((e) => e.only);
+  if (entry.todo) return skipEntry(entry, 'TODO');
+  if (entry.skip || (hasOnly && !entry.only)) return skipEntry(entry, 'SKIP');
+  if (entry.suite) return runSuite(entry);
+  return runTest(entry.name, entry.fn);
+}
+
+// Run `scope`'s entries in order, awaiting async ones; entries registered
+// while it runs are picked up too.
+function drain(scope) {
+  while (scope.next < scope.entries.length) {
+    const entry = scope.entries[scope.next++];
+    const result = runEntry(scope, entry);
     if (isThenable(result)) {
-      return result.then(
-        () => undefined,
-        (err) => fail(name, err),
-      );
+      return result.then(() => {
+        entry.resolve();
+        return drain(scope);
+      });
     }
-  } catch (err) {
-    fail(name, err);
+    entry.resolve();
   }
   return undefined;
 }
 
+function runSuite(entry) {
+  counts.suites += 1;
+  const scope = createScope();
+  const previous = currentScope;
+  currentScope = scope;
+  let outcome;
+  try {
+    outcome = typeof entry.fn === 'function' ? settle(() => entry.fn(makeContext(entry.name))) : undefined;
+  } finally {
+    currentScope = previous;
+  }
+  // Registrations after an async suite body's first `await` still land here.
+  const after = (err) => {
+    if (err !== undefined) fail(entry.name, err);
+    return drain(scope);
+  };
+  return isThenable(outcome) ? outcome.then(after) : after(outcome);
+}
+
+function printSummary() {
+  log(`1..${reported}`);
+  for (const key of ['tests', 'suites', 'pass', 'fail', 'skipped', 'todo']) {
+    log(`# ${key} ${counts[key]}`);
+  }
+}
+
+function runRoot() {
+  const finished = () => {
+    rootRunning = false;
+    printSummary();
+  };
+  const result = drain(rootScope);
+  if (isThenable(result)) return result.then(finished);
+  return finished();
+}
+
+// --- Public API ------------------------------------------------------------
+
+// `t.test` inside a running test: runs now, returns a promise for its end.
 function subtest(...args) {
   const { name, fn } = normalize(args);
-  return runOne(name, fn);
+  return Promise.resolve(runTest(name, fn));
 }
 
-// `describe`/`suite` group sub-tests; the body registers them by calling
-// `test`/`it`, which run inline, so we just invoke the body.
-function describe(...args) {
-  const { name, fn } = normalize(args);
-  if (typeof fn === 'function') {
-    const ctx = makeContext(name);
-    try {
-      const r = fn(ctx);
-      if (isThenable(r)) return r.catch((err) => fail(name, err));
-    } catch (err) {
-      fail(name, err);
+function registrar(suite, flags) {
+  return function define(...args) {
+    const { name, fn, options } = normalize(args);
+    return register({
+      name,
+      fn,
+      suite,
+      skip: Boolean(flags.skip || options.skip),
+      todo: Boolean(flags.todo || options.todo),
+      only: Boolean(flags.only || options.only),
+    });
+  };
+}
+
+// `%s`/`%d`/`%i`/`%f`/`%j`/`%o`/`%#`/`%%` interpolate positional row values;
+// `$key` reads an object row.
+function formatEachName(template, row, index) {
+  const values = Array.isArray(row) ? row.slice() : [row];
+  let name = String(template).replace(/%([sdifjo#%])/g, (match, spec) => {
+    if (spec === '%') return '%';
+    if (spec === '#') return String(index);
+    if (values.length === 0) return match;
+    const value = values.shift();
+    switch (spec) {
+      case 'd':
+      case 'i': return String(spec === 'i' ? Math.trunc(Number(value)) : Number(value));
+      case 'f': return String(Number(value));
+      case 'j':
+      case 'o': return JSON.stringify(value);
+      default: return String(value);
     }
+  });
+  if (row !== null && typeof row === 'object' && !Array.isArray(row)) {
+    name = name.replace(/\$([A-Za-z_$][\w$]*)/g, (match, key) => (key in row ? String(row[key]) : match));
   }
-  return undefined;
+  return name;
+}
+
+function eachOf(define) {
+  return function each(table) {
+    return function defineEach(template, fn) {
+      const rows = Array.from(table);
+      return Promise.all(rows.map((row, index) => {
+        const args = Array.isArray(row) ? row : [row];
+        const body = typeof fn === 'function' ? function eachCase() { return fn(...args); } : undefined;
+        return define(formatEachName(template, row, index), body);
+      }));
+    };
+  };
 }
 
-function test(...args) {
-  return subtest(...args);
+function withVariants(suite) {
+  const define = registrar(suite, {});
+  define.skip = registrar(suite, { skip: true });
+  define.todo = registrar(suite, { todo: true });
+  define.only = registrar(suite, { only: true });
+  define.each = eachOf(define);
+  define.skip.each = eachOf(define.skip);
+  define.only.each = eachOf(define.only);
+  return define;
 }
 
-// Variants — `.skip`/`.todo` register but do not run (counted as pass);
-// `.only` runs.
-test.skip = function skip() {};
-test.todo = function todo() {};
-test.only = function only(...args) { return subtest(...args); };
+const test = withVariants(false);
+const describe = withVariants(true);
+const it = test;
+
 test.test = test;
-test.it = test;
+test.it = it;
 test.describe = describe;
 test.suite = describe;
 
-const it = test;
-it.skip = test.skip;
-it.todo = test.todo;
-it.only = test.only;
-
-describe.skip = function skip() {};
-describe.todo = function todo() {};
-describe.only = describe;
-
 // Top-level lifecycle hooks — best-effort no-ops (suite-scoped hooks are run
 // by the body in this flat model).
 function noop() {}
@@ -148,6 +317,7 @@ module.exports.test = test;
 module.exports.it = it;
 module.exports.describe = describe;
 module.exports.suite = describe;
+module.exports.expect = expect;
 module.exports.before = noop;
 module.exports.after = noop;
 module.exports.beforeEach = noop;
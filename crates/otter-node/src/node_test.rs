@@ -4,20 +4,28 @@
 //! `node:test` (`const { test } = require('node:test')`). The runner itself is
 //! naturally expressed in JavaScript, so it ships as an embedded CommonJS shim
 //! ([`SHIM`]) executed through [`otter_runtime::run_builtin_cjs_shim`]. The shim
-//! depends only on `assert`, resolved through the shared CommonJS loader.
+//! depends only on `assert` and its own `expect` matchers, resolved through the
+//! shared CommonJS loader.
 //!
 //! # Contents
 //! - [`node_test_cjs_value`] - run the shim with the canonical `require`.
+//! - [`expect_cjs_value`] - `internal/test_runner/expect`, the jest-compatible
+//!   `expect()` matchers re-exported as `require('node:test').expect`.
 //!
 //! # Invariants
 //! - A failing test sets `process.exitCode = 1`; the conformance harness reads
 //!   the process exit code, so all-pass leaves it at 0.
+//! - Top-level and suite tests are queued and run in registration order, so a
+//!   `.only` sibling can filter the rest; `.skip`/`.todo` tests never run and
+//!   are reported as `skipped`/`todo`, never as failures.
 
 use otter_runtime::{CapabilitySet, RuntimeNativeError as NativeError, RuntimeTaskSpawner};
 use otter_vm::{Local, NativeScope};
 
 /// Embedded `node:test` runner implementation.
 const SHIM: &str = include_str!("node_test.js");
+/// Embedded `expect()` matcher set.
+const EXPECT_JS: &str = include_str!("node_test_expect.js");
 
 /// CommonJS export: the `test` function with `it`/`describe`/`suite`/hooks.
 pub fn node_test_cjs_value<'scope>(
@@ -29,3 +37,20 @@ pub fn node_test_cjs_value<'scope>(
 ) -> Result<Local<'scope>, NativeError> {
     otter_runtime::run_builtin_cjs_shim(scope, "node:test", SHIM, module, require)
 }
+
+/// CommonJS export for `internal/test_runner/expect`.
+pub fn expect_cjs_value<'scope>(
+    scope: &mut NativeScope<'scope, '_>,
+    _caps: &CapabilitySet,
+    _runtime_task_spawner: Option<RuntimeTaskSpawner>,
+    module: Local<'scope>,
+    require: Local<'scope>,
+) -> Result<Local<'scope>, NativeError> {
+    otter_runtime::run_builtin_cjs_shim(
+        scope,
+        "internal/test_runner/expect",
+        EXPECT_JS,
+        module,
+        require,
+    )
+}
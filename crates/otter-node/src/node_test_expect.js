@@ -0,0 +1,446 @@
+'use strict';
+// `expect()` matchers for the `node:test` shim (`internal/test_runner/expect`).
+//
+// A jest-compatible subset: `expect(value).toBe(...)` and friends, `.not`,
+// and the async `.resolves` / `.rejects` forms, which return a promise that
+// settles once the matcher has run on the settled value.
+//
+// Semantics that matter:
+// - A failing matcher throws an `AssertionError` (code `ERR_ASSERTION`) whose
+//   message starts with a jest-style hint line, e.g.
+//   `expect(received).toBe(expected) // Object.is equality`, followed by
+//   `Expected:` / `Received:` lines.
+// - `toEqual` compares recursively, ignoring `undefined` properties and
+//   prototypes; `toStrictEqual` checks both. Cycles are handled.
+// - `toThrow(expected)` matches a message substring (string), the message
+//   (RegExp), the error class, or an error's message. Under `.rejects` the
+//   rejection reason is the "thrown" value.
+// - `expect.any(Class)` / `expect.anything()` work inside equality matchers;
+//   `expect.extend({ name(received, ...args) { return { pass, message } } })`
+//   adds matchers.
+
+const { AssertionError } = require('assert');
+const { inspect } = require('util');
+
+function fmt(value) {
+  return inspect(value, { depth: 6, breakLength: Infinity, compact: 3 });
+}
+
+// --- Asymmetric matchers -----------------------------------------------------
+
+const kAsymmetric = Symbol('kAsymmetric');
+
+function asymmetric(describe, asymmetricMatch) {
+  return {
+    [kAsymmetric]: true,
+    asymmetricMatch,
+    [inspect.custom]() { return describe; },
+    toString() { return describe; },
+  };
+}
+
+function isAsymmetric(value) {
+  return value != null && value[kAsymmetric] === true;
+}
+
+function any(Ctor) {
+  if (typeof Ctor !== 'function') {
+    throw new TypeError('expect.any() expects a constructor function');
+  }
+  return asymmetric(`Any<${Ctor.name}>`, (other) => {
+    if (other == null) return false;
+    switch (Ctor) {
+      case String: return typeof other === 'string' || other instanceof String;
+      case Number: return typeof other === 'number' || other instanceof Number;
+      case Boolean: return typeof other === 'boolean' || other instanceof Boolean;
+      case BigInt: return typeof other === 'bigint';
+      case Symbol: return typeof other === 'symbol';
+      case Function: return typeof other === 'function';
+      case Object: return typeof other === 'object';
+      default: return other instanceof Ctor;
+    }
+  });
+}
+
+function anything() {
+  return asymmetric('Anything', (other) => other != null);
+}
+
+// --- Equality ----------------------------------------------------------------
+
+const toTag = (value) => Object.prototype.toString.call(value);
+
+function ownKeys(obj, strict) {
+  const keys = Object.keys(obj).filter((key) => strict || obj[key] !== undefined);
+  for (const sym of Object.getOwnPropertySymbols(obj)) {
+    if (Object.prototype.propertyIsEnumerable.call(obj, sym)) keys.push(sym);
+  }
+  return keys;
+}
+
+// Jest's `equals`: `strict` adds prototype, `undefined`-property and array
+// sparseness checks (`toStrictEqual`).
+function equals(a, b, strict, seen = []) {
+  if (isAsymmetric(b)) return b.asymmetricMatch(a);
+  if (isAsymmetric(a)) return a.asymmetricMatch(b);
+  if (Object.is(a, b)) return true;
+  if (a === null || b === null || typeof a !== 'object' || typeof b !== 'object') {
+    return false;
+  }
+  const tag = toTag(a);
+  if (tag !== toTag(b)) return false;
+  if (strict && Object.getPrototypeOf(a) !== Object.getPrototypeOf(b)) return false;
+  switch (tag) {
+    case '[object Date]':
+    case '[object Number]':
+    case '[object String]':
+    case '[object Boolean]':
+      return Object.is(a.valueOf(), b.valueOf());
+    case '[object RegExp]':
+      return String(a) === String(b);
+    case '[object Error]':
+      return a.name === b.name && a.message === b.message;
+    default:
+      break;
+  }
+  for (const [left, right] of seen) {
+    if (left === a) return right === b;
+  }
+  seen.push([a, b]);
+  try {
+    if (a instanceof Map) {
+      if (a.size !== b.size) return false;
+      for (const [key, value] of a) {
+        if (!b.has(key) || !equals(value, b.get(key), strict, seen)) return false;
+      }
+      return true;
+    }
+    if (a instanceof Set) {
+      if (a.size !== b.size) return false;
+      const rest = [...b];
+      for (const value of a) {
+        const index = rest.findIndex((other) => equals(value, other, strict, seen));
+        if (index === -1) return false;
+        rest.splice(index, 1);
+      }
+      return true;
+    }
+    if (Array.isArray(a) && a.length !== b.length) return false;
+    // Array holes are missing keys, so `[, 1]` and `[undefined, 1]` differ
+    // only for toStrictEqual.
+    const keysA = ownKeys(a, strict);
+    const keysB = ownKeys(b, strict);
+    if (keysA.length !== keysB.length) return false;
+    for (const key of keysA) {
+      if (!Object.prototype.hasOwnProperty.call(b, key)) {
+        if (strict || b[key] !== undefined) return false;
+      }
+      if (!equals(a[key], b[key], strict, seen)) return false;
+    }
+    return true;
+  } finally {
+    seen.pop();
+  }
+}
+
+// `toMatchObject`: every property of `expected` is present and matches;
+// nested objects match as subsets, arrays element-wise.
+function matchesSubset(received, expected, seen = new Set()) {
+  if (isAsymmetric(expected)) return expected.asymmetricMatch(received);
+  if (expected === null || typeof expected !== 'object' ||
+      received === null || typeof received !== 'object') {
+    return equals(received, expected, false);
+  }
+  if (Array.isArray(expected)) {
+    return Array.isArray(received) && received.length === expected.length &&
+      expected.every((item, i) => matchesSubset(received[i], item, seen));
+  }
+  if (seen.has(expected)) return true;
+  seen.add(expected);
+  try {
+    return ownKeys(expected, true).every((key) =>
+      key in Object(received) && matchesSubset(received[key], expected[key], seen));
+  } finally {
+    seen.delete(expected);
+  }
+}
+
+// --- Matchers ----------------------------------------------------------------
+
+// Each matcher returns `{ pass, message }`; `message()` renders the lines
+// after the hint. `this` is `{ isNot, promise }`.
+function expectedReceived(isNot, expected, received) {
+  return `Expected: ${isNot ? 'not ' : ''}${fmt(expected)}\nReceived: ${fmt(received)}`;
+}
+
+function compare(op, test) {
+  return function compareMatcher(received, expected) {
+    return {
+      pass: test(received, expected),
+      message: () => `Expected: ${this.isNot ? 'not ' : ''}${op} ${fmt(expected)}\n` +
+        `Received:${' '.repeat(op.length + 1)}${fmt(received)}`,
+    };
+  };
+}
+
+function thrownMessage(thrown) {
+  return thrown != null && typeof thrown === 'object' && 'message' in thrown
+    ? String(thrown.message)
+    : String(thrown);
+}
+
+const matchers = {
+  toBe(received, expected) {
+    return {
+      pass: Object.is(received, expected),
+      hint: 'Object.is equality',
+      message: () => expectedReceived(this.isNot, expected, received),
+    };
+  },
+  toEqual(received, expected) {
+    return {
+      pass: equals(received, expected, false),
+      hint: 'deep equality',
+      message: () => expectedReceived(this.isNot, expected, received),
+    };
+  },
+  toStrictEqual(received, expected) {
+    return {
+      pass: equals(received, expected, true),
+      hint: 'deep equality',
+      message: () => expectedReceived(this.isNot, expected, received),
+    };
+  },
+  toMatchObject(received, expected) {
+    if (expected === null || typeof expected !== 'object') {
+      throw new TypeError('toMatchObject() expects a non-null object');
+    }
+    return {
+      pass: matchesSubset(received, expected),
+      message: () => expectedReceived(this.isNot, expected, received),
+    };
+  },
+  toBeTruthy(received) {
+    return { pass: Boolean(received), message: () => `Received: ${fmt(received)}` };
+  },
+  toBeFalsy(received) {
+    return { pass: !received, message: () => `Received: ${fmt(received)}` };
+  },
+  toBeNull(received) {
+    return { pass: received === null, message: () => `Received: ${fmt(received)}` };
+  },
+  toBeUndefined(received) {
+    return { pass: received === undefined, message: () => `Received: ${fmt(received)}` };
+  },
+  toBeDefined(received) {
+    return { pass: received !== undefined, message: () => `Received: ${fmt(received)}` };
+  },
+  toBeNaN(received) {
+    return { pass: Number.isNaN(received), message: () => `Received: ${fmt(received)}` };
+  },
+  toBeInstanceOf(received, Ctor) {
+    return {
+      pass: received != null && received instanceof Ctor,
+      message: () => `Expected constructor: ${this.isNot ? 'not ' : ''}${Ctor && Ctor.name}\n` +
+        `Received value: ${fmt(received)}`,
+    };
+  },
+  toBeGreaterThan: compare('>', (a, b) => a > b),
+  toBeGreaterThanOrEqual: compare('>=', (a, b) => a >= b),
+  toBeLessThan: compare('<', (a, b) => a < b),
+  toBeLessThanOrEqual: compare('<=', (a, b) => a <= b),
+  toBeCloseTo(received, expected, digits = 2) {
+    const pass = received === expected ||
+      Math.abs(expected - received) < 10 ** -digits / 2;
+    return { pass, message: () => expectedReceived(this.isNot, expected, received) };
+  },
+  toContain(received, item) {
+    const pass = typeof received === 'string'
+      ? received.includes(String(item))
+      : received != null && Array.from(received).some((value) => Object.is(value, item));
+    return {
+      pass,
+      message: () => `Expected value: ${this.isNot ? 'not ' : ''}${fmt(item)}\n` +
+        `Received: ${fmt(received)}`,
+    };
+  },
+  toContainEqual(received, item) {
+    const pass = received != null &&
+      Array.from(received).some((value) => equals(value, item, false));
+    return {
+      pass,
+      message: () => `Expected value: ${this.isNot ? 'not ' : ''}${fmt(item)}\n` +
+        `Received: ${fmt(received)}`,
+    };
+  },
+  toHaveLength(received, length) {
+    const actual = received != null ? received.length : undefined;
+    return {
+      pass: actual === length,
+      message: () => `Expected length: ${this.isNot ? 'not ' : ''}${length}\n` +
+        `Received length: ${actual}`,
+    };
+  },
+  toHaveProperty(received, path, ...rest) {
+    const parts = Array.isArray(path) ? path : String(path).split('.');
+    let target = received;
+    let found = target != null;
+    for (const part of parts) {
+      if (!found || target == null || !(part in Object(target))) {
+        found = false;
+        break;
+      }
+      target = target[part];
+    }
+    const pass = found && (rest.length === 0 || equals(target, rest[0], false));
+    return {
+      pass,
+      message: () => `Expected path: ${fmt(path)}` +
+        (rest.length ? `\nExpected value: ${this.isNot ? 'not ' : ''}${fmt(rest[0])}` : '') +
+        `\nReceived: ${found ? fmt(target) : `no property at ${fmt(path)}`}`,
+    };
+  },
+  toMatch(received, pattern) {
+    const pass = typeof received === 'string' &&
+      (typeof pattern === 'string' ? received.includes(pattern) : new RegExp(pattern).test(received));
+    return {
+      pass,
+      message: () => `Expected pattern: ${this.isNot ? 'not ' : ''}${fmt(pattern)}\n` +
+        `Received string:  ${fmt(received)}`,
+    };
+  },
+  toThrow(received, expected) {
+    let threw = false;
+    let thrown;
+    if (this.promise === 'rejects') {
+      threw = true;
+      thrown = received;
+    } else {
+      if (typeof received !== 'function') {
+        throw new TypeError(`expect(received).toThrow() expects a function. Received: ${fmt(received)}`);
+      }
+      try {
+        received();
+      } catch (err) {
+        threw = true;
+        thrown = err;
+      }
+    }
+    let pass = threw;
+    let label = 'Expected: a thrown error';
+    if (threw && expected !== undefined) {
+      const message = thrownMessage(thrown);
+      if (expected instanceof RegExp) {
+        pass = expected.test(message);
+        label = `Expected pattern: ${this.isNot ? 'not ' : ''}${fmt(expected)}`;
+      } else if (typeof expected === 'string') {
+        pass = message.includes(expected);
+        label = `Expected substring: ${this.isNot ? 'not ' : ''}${fmt(expected)}`;
+      } else if (isAsymmetric(expected)) {
+        pass = expected.asymmetricMatch(thrown);
+        label = `Expected: ${fmt(expected)}`;
+      } else if (typeof expected === 'function') {
+        pass = thrown instanceof expected;
+        label = `Expected constructor: ${this.isNot ? 'not ' : ''}${expected.name}`;
+      } else if (expected !== null && typeof expected === 'object') {
+        pass = message === thrownMessage(expected);
+        label = `Expected message: ${this.isNot ? 'not ' : ''}${fmt(thrownMessage(expected))}`;
+      }
+    }
+    return {
+      pass,
+      message: () => threw
+        ? `${label}\nReceived message: ${fmt(thrownMessage(thrown))}`
+        : `${label}\nReceived function did not throw`,
+    };
+  },
+};
+matchers.toThrowError = matchers.toThrow;
+
+// --- expect() ----------------------------------------------------------------
+
+function hintLine(name, flags, hasExpected, hint) {
+  return `expect(received)${flags.promise ? `.${flags.promise}` : ''}` +
+    `${flags.isNot ? '.not' : ''}.${name}(${hasExpected ? 'expected' : ''})` +
+    (hint && !flags.isNot ? ` // ${hint}` : '');
+}
+
+function runMatcher(name, received, args, flags, stackStartFn) {
+  const result = matchers[name].call(flags, received, ...args);
+  if (Boolean(result.pass) === flags.isNot) {
+    const details = typeof result.message === 'function' ? result.message() : result.message;
+    throw new AssertionError({
+      message: `${hintLine(name, flags, args.length > 0, result.hint)}\n\n${details || ''}`,
+      actual: received,
+      expected: args[0],
+      operator: name,
+      stackStartFn,
+    });
+  }
+}
+
+function makeMatchers(received, flags) {
+  const out = {};
+  for (const name of Object.keys(matchers)) {
+    out[name] = function matcher(...args) {
+      if (!flags.promise) return runMatcher(name, received, args, flags, matcher);
+      const wantResolve = flags.promise === 'resolves';
+      if (received == null || typeof received.then !== 'function') {
+        return Promise.reject(new AssertionError({
+          message: `${hintLine(name, flags, args.length > 0)}\n\n` +
+            `Received value must be a promise\nReceived: ${fmt(received)}`,
+          actual: received,
+          operator: name,
+        }));
+      }
+      return Promise.resolve(received).then(
+        (value) => {
+          if (!wantResolve) {
+            throw new AssertionError({
+              message: `${hintLine(name, flags, args.length > 0)}\n\n` +
+                `Received promise resolved instead of rejected\nResolved to value: ${fmt(value)}`,
+              actual: value,
+              operator: name,
+            });
+          }
+          return runMatcher(name, value, args, flags, matcher);
+        },
+        (reason) => {
+          if (wantResolve) {
+            throw new AssertionError({
+              message: `${hintLine(name, flags, args.length > 0)}\n\n` +
+                `Received promise rejected instead of resolved\nRejected to value: ${fmt(reason)}`,
+              actual: reason,
+              operator: name,
+            });
+          }
+          return runMatcher(name, reason, args, flags, matcher);
+        },
+      );
+    };
+  }
+  return out;
+}
+
+function expect(received) {
+  const api = makeMatchers(received, { isNot: false, promise: '' });
+  api.not = makeMatchers(received, { isNot: true, promise: '' });
+  api.resolves = makeMatchers(received, { isNot: false, promise: 'resolves' });
+  api.resolves.not = makeMatchers(received, { isNot: true, promise: 'resolves' });
+  api.rejects = makeMatchers(received, { isNot: false, promise: 'rejects' });
+  api.rejects.not = makeMatchers(received, { isNot: true, promise: 'rejects' });
+  return api;
+}
+
+expect.any = any;
+expect.anything = anything;
+expect.extend = function extend(extra) {
+  for (const [name, fn] of Object.entries(extra)) {
+    if (typeof fn !== 'function') {
+      throw new TypeError(`expect.extend: "${name}" is not a valid matcher`);
+    }
+    matchers[name] = fn;
+  }
+};
+
+module.exports = { expect };
@@ -286,7 +286,7 @@ fn os_available_parallelism(
     ctx: &mut NativeCtx<'_>,
     _args: &[Value],
 ) -> Result<Value, NativeError> {
-    number_value(ctx, num_cpus().max(1) as f64)
+    number_value(ctx, available_parallelism() as f64)
 }
 
 // ---- arrays / objects ----
@@ -492,6 +492,16 @@ struct UserInfo {
     shell: String,
 }
 
+/// CPUs this process may actually use. `std` already accounts for the
+/// scheduler affinity mask and, on Linux, the cgroup CPU quota, so a
+/// container limited to two CPUs on a 64-core host reports 2 instead of 64.
+fn available_parallelism() -> usize {
+    std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or_else(|_| num_cpus())
+        .max(1)
+}
+
 // ============================ unix FFI ============================
 
 #[cfg(unix)]
@@ -3,6 +3,7 @@
 // keeping incomplete multi-byte sequences buffered until completed.
 
 const { Buffer } = require('buffer');
+const decodeUtf8 = require('__utf8native');
 
 function normalizeEncoding(enc) {
   const e = String(enc || 'utf8').toLowerCase();
@@ -17,15 +18,6 @@ function normalizeEncoding(enc) {
   }
 }
 
-// How many bytes a leading byte announces for a UTF-8 sequence (0 if continuation/invalid).
-function utf8SeqLen(byte) {
-  if (byte <= 0x7f) return 1;
-  if ((byte & 0xe0) === 0xc0) return 2;
-  if ((byte & 0xf0) === 0xe0) return 3;
-  if ((byte & 0xf8) === 0xf0) return 4;
-  return 0;
-}
-
 class StringDecoder {
   constructor(encoding) {
     this.encoding = normalizeEncoding(encoding);
@@ -38,17 +30,11 @@ class StringDecoder {
     const data = this._pending.length ? Buffer.concat([this._pending, buffer]) : Buffer.from(buffer);
 
     if (this.encoding === 'utf8') {
-      let completeEnd = data.length;
-      // Walk back over up to 3 trailing continuation bytes to find an incomplete tail.
-      for (let i = 1; i <= 3 && i <= data.length; i++) {
-        const pos = data.length - i;
-        const need = utf8SeqLen(data[pos]);
-        if (need === 0) continue; // continuation byte; keep scanning back
-        if (need > i) { completeEnd = pos; } // sequence starts here but is incomplete
-        break;
-      }
-      this._pending = data.slice(completeEnd);
-      return data.slice(0, completeEnd).toString('utf8');
+      // Stream mode leaves a cut-off trailing sequence unconsumed; it is
+      // carried to the next write (or flushed as U+FFFD by `end`).
+      const [text, consumed] = decodeUtf8(data, false, true);
+      this._pending = data.slice(consumed);
+      return text;
     }
 
     if (this.encoding === 'utf16le') {
@@ -1,5 +1,9 @@
 //! `node:string_decoder` / `string_decoder` hosted module — boundary-safe
 //! Buffer-to-string decoding, implemented as a JS shim over `buffer`.
+//!
+//! Also hosts the private `__utf8native` module: the shared UTF-8 decoder
+//! ([`otter_runtime::runtime_decode_utf8`]) that both this shim and
+//! `Buffer#toString('utf8')` decode through.
 
 use otter_runtime::{CapabilitySet, RuntimeNativeError as NativeError, RuntimeTaskSpawner};
 use otter_vm::{Local, NativeScope};
@@ -16,3 +20,19 @@ pub fn string_decoder_cjs_value<'scope>(
 ) -> Result<Local<'scope>, NativeError> {
     otter_runtime::run_builtin_cjs_shim(scope, "node:string_decoder", SHIM, module, require)
 }
+
+/// CommonJS export: the private `__utf8native` decoder function,
+/// `(bytes, fatal, stream) => [text, consumed] | null`.
+pub fn utf8_native_cjs_value<'scope>(
+    scope: &mut NativeScope<'scope, '_>,
+    _caps: &CapabilitySet,
+    _runtime_task_spawner: Option<RuntimeTaskSpawner>,
+    _module: Local<'scope>,
+    _require: Local<'scope>,
+) -> Result<Local<'scope>, NativeError> {
+    scope.native_call(
+        "decodeUtf8",
+        3,
+        otter_runtime::runtime_native_static(otter_runtime::runtime_decode_utf8),
+    )
+}
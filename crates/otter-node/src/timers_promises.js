@@ -1,26 +1,40 @@
 'use strict';
 // `node:timers/promises` — promise-based timers over the global timer API.
 
+// Node rejects with an `AbortError` (`code: 'ABORT_ERR'`) carrying the
+// signal's reason as `cause`, rather than rejecting with the reason itself.
 function aborted(signal) {
-  const reason = signal && signal.reason;
-  const err = reason instanceof Error ? reason : new Error('The operation was aborted');
-  if (!(reason instanceof Error)) err.name = 'AbortError';
+  const err = new Error('The operation was aborted', { cause: signal && signal.reason });
+  err.name = 'AbortError';
+  err.code = 'ABORT_ERR';
   return err;
 }
 
+function listen(signal, onAbort) {
+  if (signal && typeof signal.addEventListener === 'function') {
+    signal.addEventListener('abort', onAbort, { once: true });
+  }
+}
+
+function unlisten(signal, onAbort) {
+  if (signal && typeof signal.removeEventListener === 'function') {
+    signal.removeEventListener('abort', onAbort);
+  }
+}
+
 function setTimeout(delay = 1, value, options = {}) {
   const signal = options.signal;
   return new Promise((resolve, reject) => {
     if (signal && signal.aborted) return reject(aborted(signal));
     const t = globalThis.setTimeout(() => {
-      if (signal && typeof signal.removeEventListener === 'function') signal.removeEventListener('abort', onAbort);
+      unlisten(signal, onAbort);
       resolve(value);
     }, delay);
     function onAbort() {
       globalThis.clearTimeout(t);
       reject(aborted(signal));
     }
-    if (signal && typeof signal.addEventListener === 'function') signal.addEventListener('abort', onAbort, { once: true });
+    listen(signal, onAbort);
   });
 }
 
@@ -28,10 +42,20 @@ function setImmediate(value, options = {}) {
   const signal = options.signal;
   return new Promise((resolve, reject) => {
     if (signal && signal.aborted) return reject(aborted(signal));
-    globalThis.setImmediate(() => resolve(value));
+    const t = globalThis.setImmediate(() => {
+      unlisten(signal, onAbort);
+      resolve(value);
+    });
+    function onAbort() {
+      globalThis.clearImmediate(t);
+      reject(aborted(signal));
+    }
+    listen(signal, onAbort);
   });
 }
 
+// Each tick waits one `delay` after the consumer pulls, so a slow consumer
+// sees spaced ticks rather than a burst. Aborting rejects the pending pull.
 async function* setInterval(delay = 1, value, options = {}) {
   const signal = options.signal;
   while (true) {
@@ -685,39 +685,85 @@ function compareSet(a, b, strict, memo, skipProto) {
 
 // ---------- promisify / callbackify ----------
 const kCustomPromisify = Symbol.for('nodejs.util.promisify.custom');
+// Node's internal `customPromisifyArgs`: hosted shims whose callback yields
+// several results (e.g. `fs.read`) tag the function with the result names so
+// the promise resolves with an object instead of only the first value.
+const kCustomPromisifyArgs = Symbol.for('nodejs.util.promisify.customArgs');
+
+function markPromisified(fn) {
+  return Object.defineProperty(fn, kCustomPromisify, {
+    value: fn, enumerable: false, writable: false, configurable: true,
+  });
+}
+
 function promisify(original) {
   if (typeof original !== 'function') {
-    const err = new TypeError('The "original" argument must be of type function.');
-    err.code = 'ERR_INVALID_ARG_TYPE';
-    throw err;
+    throw argTypeError('original', 'argument must be of type function', original);
+  }
+  if (original[kCustomPromisify]) {
+    const fn = original[kCustomPromisify];
+    if (typeof fn !== 'function') {
+      throw argTypeError('util.promisify.custom', 'argument must be of type function', fn);
+    }
+    return markPromisified(fn);
   }
-  if (original[kCustomPromisify]) return original[kCustomPromisify];
+  const argumentNames = original[kCustomPromisifyArgs];
   function fn(...args) {
     return new Promise((resolve, reject) => {
-      original.call(this, ...args, (err, ...values) => {
+      args.push((err, ...values) => {
         if (err) return reject(err);
-        resolve(values[0]);
+        if (argumentNames !== undefined && values.length > 1) {
+          const obj = {};
+          for (let i = 0; i < argumentNames.length; i++) obj[argumentNames[i]] = values[i];
+          resolve(obj);
+        } else {
+          resolve(values[0]);
+        }
       });
+      Reflect.apply(original, this, args);
     });
   }
   Object.setPrototypeOf(fn, Object.getPrototypeOf(original));
-  return fn;
+  markPromisified(fn);
+  return Object.defineProperties(fn, Object.getOwnPropertyDescriptors(original));
 }
 promisify.custom = kCustomPromisify;
 
+function callbackifyOnRejected(reason, cb) {
+  // A falsy rejection cannot travel as the error-first argument, so Node wraps
+  // it and keeps the original value on `reason`.
+  if (!reason) {
+    const err = new Error('Promise was rejected with falsy value');
+    err.code = 'ERR_FALSY_VALUE_REJECTION';
+    err.reason = reason;
+    reason = err;
+  }
+  return cb(reason);
+}
+
 function callbackify(original) {
   if (typeof original !== 'function') {
-    const err = new TypeError('The "original" argument must be of type function.');
-    err.code = 'ERR_INVALID_ARG_TYPE';
-    throw err;
+    throw argTypeError('original', 'argument must be of type function', original);
   }
-  function fn(...args) {
-    const cb = args.pop();
-    original.apply(this, args).then(
-      (ret) => queueMicrotask(() => cb(null, ret)),
-      (err) => queueMicrotask(() => cb(err || new Error('Promise was rejected with a falsy value'))));
-  }
-  return fn;
+  function callbackified(...args) {
+    const maybeCb = args.pop();
+    if (typeof maybeCb !== 'function') {
+      throw argTypeError('last argument', 'argument must be of type function', maybeCb);
+    }
+    const cb = maybeCb.bind(this);
+    const defer = typeof process === 'object' && process && typeof process.nextTick === 'function'
+      ? (f, ...a) => process.nextTick(f, ...a)
+      : (f, ...a) => queueMicrotask(() => f(...a));
+    Reflect.apply(original, this, args).then(
+      (ret) => defer(cb, null, ret),
+      (rej) => defer(callbackifyOnRejected, rej, cb));
+  }
+  const descriptors = Object.getOwnPropertyDescriptors(original);
+  if (descriptors.length && typeof descriptors.length.value === 'number') descriptors.length.value++;
+  if (descriptors.name && typeof descriptors.name.value === 'string') descriptors.name.value += 'Callbackified';
+  Object.setPrototypeOf(callbackified, Object.getPrototypeOf(original));
+  Object.defineProperties(callbackified, descriptors);
+  return callbackified;
 }
 
 // ---------- inherits ----------
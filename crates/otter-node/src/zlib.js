@@ -6,13 +6,17 @@
 // - One-shot *Sync codecs (deflate/inflate/gzip/gunzip/deflateRaw/inflateRaw/unzip)
 //   and their async callback counterparts (defer via setTimeout(...,0) → cb).
 // - Stream classes (Deflate/Inflate/Gzip/Gunzip/DeflateRaw/InflateRaw/Unzip) as
-//   Transform streams that buffer all input and codec it on _flush. This is a
-//   whole-buffer approximation of Node's incremental zlib streams — enough for
-//   create/instanceof/round-trip coverage, not flush-boundary fidelity.
+//   Transform streams over an incremental native codec: each chunk is
+//   (de)compressed as it is written, and flush(Z_SYNC_FLUSH / Z_FULL_FLUSH)
+//   emits a decodable boundary without ending the stream.
+// - Brotli: brotliCompress/brotliDecompress (+Sync) and the
+//   BrotliCompress/BrotliDecompress streams. The `params` bag honours
+//   BROTLI_PARAM_QUALITY, BROTLI_PARAM_LGWIN and BROTLI_PARAM_SIZE_HINT; other
+//   known parameters are accepted and ignored.
 // - zlib.crc32(data, value).
 //
 // Bytes reach the native layer as latin1 strings (1 byte ↔ 1 char); see zlib.rs.
-// Brotli/zstd are intentionally not implemented (separate native backend).
+// zstd is intentionally not implemented (separate native backend).
 
 const native = require('__zlibnative');
 const { Buffer } = require('buffer');
@@ -76,6 +80,20 @@ const constants = {
   Z_MIN_CHUNK: 64, Z_MAX_CHUNK: Infinity, Z_DEFAULT_CHUNK: 16384,
   Z_MIN_MEMLEVEL: 1, Z_MAX_MEMLEVEL: 9, Z_DEFAULT_MEMLEVEL: 8,
   Z_MIN_LEVEL: -1, Z_MAX_LEVEL: 9, Z_DEFAULT_LEVEL: -1,
+  BROTLI_DECODE: 8, BROTLI_ENCODE: 9,
+  BROTLI_OPERATION_PROCESS: 0, BROTLI_OPERATION_FLUSH: 1,
+  BROTLI_OPERATION_FINISH: 2, BROTLI_OPERATION_EMIT_METADATA: 3,
+  BROTLI_PARAM_MODE: 0, BROTLI_MODE_GENERIC: 0, BROTLI_MODE_TEXT: 1,
+  BROTLI_MODE_FONT: 2, BROTLI_DEFAULT_MODE: 0,
+  BROTLI_PARAM_QUALITY: 1, BROTLI_MIN_QUALITY: 0, BROTLI_MAX_QUALITY: 11,
+  BROTLI_DEFAULT_QUALITY: 11,
+  BROTLI_PARAM_LGWIN: 2, BROTLI_MIN_WINDOW_BITS: 10, BROTLI_MAX_WINDOW_BITS: 24,
+  BROTLI_LARGE_MAX_WINDOW_BITS: 30, BROTLI_DEFAULT_WINDOW: 22,
+  BROTLI_PARAM_LGBLOCK: 3, BROTLI_MIN_INPUT_BLOCK_BITS: 16,
+  BROTLI_MAX_INPUT_BLOCK_BITS: 24,
+  BROTLI_PARAM_DISABLE_LITERAL_CONTEXT_MODELING: 4,
+  BROTLI_PARAM_SIZE_HINT: 5, BROTLI_PARAM_LARGE_WINDOW: 6,
+  BROTLI_PARAM_NPOSTFIX: 7, BROTLI_PARAM_NDIRECT: 8,
 };
 Object.freeze(constants);
 
@@ -216,16 +234,34 @@ function validateOptions(opts, gzipFlavor) {
 
 // ---- stream classes ---------------------------------------------------------
 
-// Whole-buffer Transform: collect chunks, codec on _flush. objectMode writes of
+// Flush requests travel through write() as empty chunks tagged with their flush
+// kind, so they stay ordered behind data that is still buffered.
+const kFlushFlag = Symbol('kFlushFlag');
+
+// Incremental Transform over a native stream handle (streamOpen/streamWrite/
+// streamClose): every chunk is fed through the codec as it arrives and
+// whatever it produces is pushed right away. objectMode writes of
 // non-buffer/string values throw ERR_INVALID_ARG_TYPE like Node's zlib streams.
+// Default flush kinds: `full` for flush(), `finish` for end().
+const ZLIB_FLUSH_FLAGS = { full: constants.Z_FULL_FLUSH, finish: constants.Z_FINISH };
+const BROTLI_FLUSH_FLAGS = {
+  full: constants.BROTLI_OPERATION_FLUSH,
+  finish: constants.BROTLI_OPERATION_FINISH,
+};
+
 class ZlibBase extends Transform {
-  constructor(opts, nativeFn, gzipFlavor) {
+  constructor(opts, mode, gzipFlavor, flushFlags = ZLIB_FLUSH_FLAGS) {
     const o = opts || {};
     validateOptions(o, gzipFlavor);
     super(o);
-    this._zlibNative = nativeFn;
+    this._zlibMode = mode;
     this._zlibOpts = o;
-    this._zlibChunks = [];
+    this._handle = null;
+    this._defaultFlushFlag =
+      o.flush === undefined ? constants.Z_NO_FLUSH : o.flush;
+    this._fullFlushFlag = flushFlags.full;
+    this._finishFlushFlag =
+      o.finishFlush === undefined ? flushFlags.finish : o.finishFlush;
     this.bytesWritten = 0;
     // _level/_strategy mirror Node's resolved settings (NaN → default).
     this._level = validateLevelLike(o, 'level', 'options.level', -1, 9, -1);
@@ -233,7 +269,28 @@ class ZlibBase extends Transform {
       validateLevelLike(o, 'strategy', 'options.strategy', 0, 4, 0);
   }
 
+  _openHandle() {
+    return native.streamOpen(this._zlibMode, this._level);
+  }
+
+  // Open lazily so params() before the first write picks the level.
+  _zlibWrite(latin1, flushFlag) {
+    if (this._handle === null) this._handle = this._openHandle();
+    const out = native.streamWrite(this._handle, latin1, flushFlag);
+    if (out.length > 0) this.push(Buffer.from(out, 'latin1'));
+  }
+
+  _closeHandle() {
+    if (this._handle !== null) {
+      native.streamClose(this._handle);
+      this._handle = null;
+    }
+  }
+
   _transform(chunk, encoding, callback) {
+    const flushFlag = chunk != null && chunk[kFlushFlag] !== undefined
+      ? chunk[kFlushFlag]
+      : this._defaultFlushFlag;
     if (typeof chunk === 'string') {
       chunk = Buffer.from(chunk, encoding === 'buffer' ? undefined : encoding);
     } else if (!Buffer.isBuffer(chunk) && !ArrayBuffer.isView(chunk)) {
@@ -242,35 +299,55 @@ class ZlibBase extends Transform {
     } else if (!Buffer.isBuffer(chunk)) {
       chunk = Buffer.from(chunk.buffer, chunk.byteOffset, chunk.byteLength);
     }
-    this._zlibChunks.push(chunk);
+    try {
+      this._zlibWrite(chunk.toString('latin1'), flushFlag);
+    } catch (err) {
+      this._closeHandle();
+      callback(err);
+      return;
+    }
     this.bytesWritten += chunk.length;
     callback();
   }
 
   _flush(callback) {
-    let out;
     try {
-      const input = Buffer.concat(this._zlibChunks);
-      out = this._zlibNative(input.toString('latin1'), this._level);
+      this._zlibWrite('', this._finishFlushFlag);
     } catch (err) {
       callback(err);
       return;
+    } finally {
+      this._closeHandle();
     }
-    this.push(Buffer.from(out, 'latin1'));
     callback();
   }
 
-  // flush()/params()/reset()/close() exist so callers don't hit a missing
-  // method; this whole-buffer codec has no incremental flush boundaries, so
-  // these are lightweight (params still validates like Node).
+  _destroy(err, callback) {
+    this._closeHandle();
+    callback(err);
+  }
+
+  // flush(kind = Z_FULL_FLUSH, or BROTLI_OPERATION_FLUSH for brotli): emit
+  // everything written so far up to a byte boundary without ending the
+  // stream; `callback` runs once those bytes have been pushed.
   flush(kind, callback) {
     if (typeof kind === 'function') {
       callback = kind;
+      kind = undefined;
     }
-    if (typeof callback === 'function') setTimeout(callback, 0);
+    if (kind === undefined) kind = this._fullFlushFlag;
+    if (this._writableState.ended) {
+      if (typeof callback === 'function') setTimeout(callback, 0);
+      return this;
+    }
+    const marker = Buffer.alloc(0);
+    marker[kFlushFlag] = kind;
+    this.write(marker, callback);
     return this;
   }
 
+  // params() validates like Node; the new level takes effect for the next
+  // native stream (first write, or the first write after reset()).
   params(level, strategy, callback) {
     if (typeof level !== 'number') {
       throw invalidArgTypeProp('level', 'argument', 'of type number', level);
@@ -299,7 +376,9 @@ class ZlibBase extends Transform {
     return this;
   }
 
+  // Drop the codec state; the next write starts a fresh stream.
   reset() {
+    this._closeHandle();
     return this;
   }
 
@@ -311,25 +390,96 @@ class ZlibBase extends Transform {
 }
 
 class Deflate extends ZlibBase {
-  constructor(opts) { super(opts, native.deflate, false); }
+  constructor(opts) { super(opts, constants.DEFLATE, false); }
 }
 class Inflate extends ZlibBase {
-  constructor(opts) { super(opts, native.inflate, false); }
+  constructor(opts) { super(opts, constants.INFLATE, false); }
 }
 class Gzip extends ZlibBase {
-  constructor(opts) { super(opts, native.gzip, true); }
+  constructor(opts) { super(opts, constants.GZIP, true); }
 }
 class Gunzip extends ZlibBase {
-  constructor(opts) { super(opts, native.gunzip, true); }
+  constructor(opts) { super(opts, constants.GUNZIP, true); }
 }
 class DeflateRaw extends ZlibBase {
-  constructor(opts) { super(opts, native.deflateRaw, false); }
+  constructor(opts) { super(opts, constants.DEFLATERAW, false); }
 }
 class InflateRaw extends ZlibBase {
-  constructor(opts) { super(opts, native.inflateRaw, false); }
+  constructor(opts) { super(opts, constants.INFLATERAW, false); }
 }
 class Unzip extends ZlibBase {
-  constructor(opts) { super(opts, native.unzip, true); }
+  constructor(opts) { super(opts, constants.UNZIP, true); }
+}
+
+// ---- brotli -------------------------------------------------------------------
+
+const BROTLI_PARAM_IDS = new Set([
+  constants.BROTLI_PARAM_MODE, constants.BROTLI_PARAM_QUALITY,
+  constants.BROTLI_PARAM_LGWIN, constants.BROTLI_PARAM_LGBLOCK,
+  constants.BROTLI_PARAM_DISABLE_LITERAL_CONTEXT_MODELING,
+  constants.BROTLI_PARAM_SIZE_HINT, constants.BROTLI_PARAM_LARGE_WINDOW,
+  constants.BROTLI_PARAM_NPOSTFIX, constants.BROTLI_PARAM_NDIRECT,
+]);
+
+// opts.params → { quality, lgwin, sizeHint }. Unknown keys throw
+// ERR_BROTLI_INVALID_PARAM like Node; value ranges are checked natively.
+function brotliParams(opts) {
+  const out = {
+    quality: constants.BROTLI_DEFAULT_QUALITY,
+    lgwin: constants.BROTLI_DEFAULT_WINDOW,
+    sizeHint: 0,
+  };
+  const params = opts && opts.params;
+  if (params === undefined) return out;
+  if (params === null || typeof params !== 'object') {
+    throw invalidArgTypeProp('options.params', 'property', 'of type object', params);
+  }
+  for (const key of Object.keys(params)) {
+    const id = Number(key);
+    if (!BROTLI_PARAM_IDS.has(id)) {
+      const e = new RangeError(`${key} is not a valid Brotli parameter`);
+      e.code = 'ERR_BROTLI_INVALID_PARAM';
+      throw e;
+    }
+    let value = params[key];
+    if (typeof value === 'boolean') value = Number(value);
+    if (typeof value !== 'number') {
+      throw invalidArgTypeProp('options.params[key]', 'property', 'of type number', value);
+    }
+    if (id === constants.BROTLI_PARAM_QUALITY) out.quality = value;
+    else if (id === constants.BROTLI_PARAM_LGWIN) out.lgwin = value;
+    else if (id === constants.BROTLI_PARAM_SIZE_HINT) out.sizeHint = value;
+  }
+  return out;
+}
+
+class BrotliCompress extends ZlibBase {
+  constructor(opts) {
+    super(opts, constants.BROTLI_ENCODE, false, BROTLI_FLUSH_FLAGS);
+    this._brotliParams = brotliParams(opts);
+  }
+
+  _openHandle() {
+    const { quality, lgwin, sizeHint } = this._brotliParams;
+    return native.streamOpen(this._zlibMode, quality, lgwin, sizeHint);
+  }
+}
+class BrotliDecompress extends ZlibBase {
+  constructor(opts) {
+    super(opts, constants.BROTLI_DECODE, false, BROTLI_FLUSH_FLAGS);
+  }
+}
+
+function brotliCompressSync(input, opts) {
+  const buf = toBuffer(input);
+  const { quality, lgwin, sizeHint } = brotliParams(opts);
+  const out = native.brotliCompress(buf.toString('latin1'), quality, lgwin, sizeHint);
+  return withInfo(Buffer.from(out, 'latin1'), opts, BrotliCompress);
+}
+
+function brotliDecompressSync(input, opts) {
+  const out = native.brotliDecompress(toBuffer(input).toString('latin1'));
+  return withInfo(Buffer.from(out, 'latin1'), opts, BrotliDecompress);
 }
 
 // Node lets the codec classes be invoked with OR without `new`. ES classes
@@ -352,6 +502,8 @@ const GunzipC = callable(Gunzip);
 const DeflateRawC = callable(DeflateRaw);
 const InflateRawC = callable(InflateRaw);
 const UnzipC = callable(Unzip);
+const BrotliCompressC = callable(BrotliCompress);
+const BrotliDecompressC = callable(BrotliDecompress);
 
 // ---- crc32 ------------------------------------------------------------------
 
@@ -373,6 +525,7 @@ const exportsObj = {
 
   Deflate: DeflateC, Inflate: InflateC, Gzip: GzipC, Gunzip: GunzipC,
   DeflateRaw: DeflateRawC, InflateRaw: InflateRawC, Unzip: UnzipC,
+  BrotliCompress: BrotliCompressC, BrotliDecompress: BrotliDecompressC,
 
   createDeflate: (o) => new Deflate(o),
   createInflate: (o) => new Inflate(o),
@@ -381,6 +534,8 @@ const exportsObj = {
   createDeflateRaw: (o) => new DeflateRaw(o),
   createInflateRaw: (o) => new InflateRaw(o),
   createUnzip: (o) => new Unzip(o),
+  createBrotliCompress: (o) => new BrotliCompress(o),
+  createBrotliDecompress: (o) => new BrotliDecompress(o),
 
   deflateSync: makeSync(native.deflate, Deflate),
   inflateSync: makeSync(native.inflate, Inflate),
@@ -389,6 +544,8 @@ const exportsObj = {
   deflateRawSync: makeSync(native.deflateRaw, DeflateRaw),
   inflateRawSync: makeSync(native.inflateRaw, InflateRaw),
   unzipSync: makeSync(native.unzip, Unzip),
+  brotliCompressSync,
+  brotliDecompressSync,
 };
 
 exportsObj.deflate = makeAsync(exportsObj.deflateSync);
@@ -398,6 +555,8 @@ exportsObj.gunzip = makeAsync(exportsObj.gunzipSync);
 exportsObj.deflateRaw = makeAsync(exportsObj.deflateRawSync);
 exportsObj.inflateRaw = makeAsync(exportsObj.inflateRawSync);
 exportsObj.unzip = makeAsync(exportsObj.unzipSync);
+exportsObj.brotliCompress = makeAsync(brotliCompressSync);
+exportsObj.brotliDecompress = makeAsync(brotliDecompressSync);
 
 // constants/codes are immutable top-level properties (zlib.codes = {...} throws).
 Object.defineProperty(exportsObj, 'constants', {
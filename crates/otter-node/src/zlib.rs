@@ -1,10 +1,22 @@
-//! `node:zlib` native core — DEFLATE / zlib / gzip (de)compression + CRC-32.
+//! `node:zlib` native core — DEFLATE / zlib / gzip / brotli (de)compression +
+//! CRC-32.
 //!
 //! # Contents
 //! - [`zlib_cjs_value`] builds the CommonJS `zlib` namespace (native core +
 //!   `zlib.js` shim with `buffer` and `stream` deps).
 //! - [`native_value`] exposes the raw codecs the shim drives: `deflateRaw`,
-//!   `inflateRaw`, `deflate`, `inflate`, `gzip`, `gunzip`, `unzip`, `crc32`.
+//!   `inflateRaw`, `deflate`, `inflate`, `gzip`, `gunzip`, `unzip`,
+//!   `brotliCompress`, `brotliDecompress`, `crc32`, plus the
+//!   `streamOpen`/`streamWrite`/`streamClose` handle API.
+//! - [`DeflateStream`] / [`InflateStream`] are the incremental codecs behind
+//!   `zlib.createGzip()`, `zlib.createGunzip()` and the other stream classes:
+//!   each `write` returns the bytes produced so far.
+//! - [`unzip_auto`] backs `unzip`/`unzipSync`: it sniffs the header magic
+//!   ([`sniff_format`]) and decodes gzip, zlib or raw DEFLATE.
+//! - [`BrotliCompressStream`] / [`BrotliDecompressStream`] do the same for
+//!   `zlib.createBrotliCompress()` / `createBrotliDecompress()`, configured by
+//!   [`BrotliOptions`]; [`brotli_compress`] / [`brotli_decompress`] are the
+//!   one-shot forms.
 //!
 //! # Invariants
 //! - Bytes cross the native/JS boundary as **latin1 strings** (1 byte ↔ 1
@@ -16,6 +28,15 @@
 //! - The backend is `flate2`'s default (`miniz_oxide`, pure Rust). Round-trips
 //!   are byte-exact; a few Node tests that pin an exact compressed byte string
 //!   (produced by zlib-ng) may differ — round-trip coverage is the bulk.
+//!   Brotli uses the pure-Rust `brotli` crate.
+//! - Brotli options are validated before an encoder is built: quality
+//!   `0..=11`, `lgwin` `10..=24` (no large-window extension).
+//! - A `Z_SYNC_FLUSH` / `Z_FULL_FLUSH` write ends on a byte boundary (the empty
+//!   stored block `00 00 ff ff`) without ending the stream; only `Z_FINISH`
+//!   writes the final block and, for gzip, the CRC-32/ISIZE trailer.
+//! - Stream handles are GC-owned host objects, so a stream that is never
+//!   ended is freed with its JS wrapper; `zlib.js` still closes its handle on
+//!   end, `reset()` and destroy to drop the codec early.
 //!
 //! # See also
 //! - `zlib.js` — the JS surface (classes, async wrappers, constants, codes).
@@ -23,9 +44,11 @@
 
 use std::io::{Read, Write};
 
-use flate2::Compression;
-use flate2::read::{DeflateDecoder, GzDecoder, MultiGzDecoder, ZlibDecoder};
+use brotli::enc::BrotliEncoderParams;
+use brotli::{CompressorWriter, DecompressorWriter};
+use flate2::read::{DeflateDecoder, MultiGzDecoder, ZlibDecoder};
 use flate2::write::{DeflateEncoder, GzEncoder, ZlibEncoder};
+use flate2::{Compress, Compression, Decompress, FlushCompress, FlushDecompress, Status};
 use otter_runtime::{
     CapabilitySet, RuntimeLocal as Local, RuntimeNativeCtx as NativeCtx,
     RuntimeNativeError as NativeError, RuntimeNativeScope as NativeScope, RuntimeTaskSpawner,
@@ -73,6 +96,11 @@ fn native_value<'scope>(scope: &mut NativeScope<'scope, '_>) -> Result<Local<'sc
     m!("gunzip", 1, gunzip);
     m!("unzip", 1, unzip);
     m!("crc32", 2, crc32);
+    m!("brotliCompress", 4, brotli_compress_native);
+    m!("brotliDecompress", 1, brotli_decompress_native);
+    m!("streamOpen", 2, stream_open);
+    m!("streamWrite", 3, stream_write);
+    m!("streamClose", 1, stream_close);
 
     Ok(object)
 }
@@ -155,21 +183,55 @@ fn gunzip(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError>
     crate::string_value(ctx, &bytes_to_latin1(&out))
 }
 
-/// `unzip` auto-detects the wrapper: gzip magic (0x1f 0x8b) → gunzip, else
-/// treat as a zlib stream. Mirrors Node's `Unzip`.
+/// `unzip` / `unzipSync`: see [`unzip_auto`].
 fn unzip(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
     let data = latin1_to_bytes(&runtime_arg_to_string(args, 0, ctx.heap()));
+    let out = unzip_auto(&data).map_err(ZlibStreamError::into_native)?;
+    crate::string_value(ctx, &bytes_to_latin1(&out))
+}
+
+/// Wrapper [`unzip_auto`] infers from the first two bytes of its input.
+pub fn sniff_format(data: &[u8]) -> ZlibFormat {
+    match data {
+        [0x1f, 0x8b, ..] => ZlibFormat::Gzip,
+        // RFC 1950: CM = 8 (deflate), CINFO <= 7, and the header is a
+        // multiple of 31 (the FCHECK bits), e.g. `78 9c`.
+        [cmf, flg, ..]
+            if cmf & 0x0f == 8
+                && cmf >> 4 <= 7
+                && (u16::from(*cmf) << 8 | u16::from(*flg)) % 31 == 0 =>
+        {
+            ZlibFormat::Zlib
+        }
+        _ => ZlibFormat::Raw,
+    }
+}
+
+/// Decompress gzip (`1f 8b`), zlib (`78 xx`) or, failing both magics, raw
+/// DEFLATE input — Node's `unzipSync`, plus the raw fallback. Input that is
+/// none of these is a [`ZlibStreamError::Data`] naming the guessed format.
+pub fn unzip_auto(data: &[u8]) -> Result<Vec<u8>, ZlibStreamError> {
+    if data.is_empty() {
+        return Err(ZlibStreamError::UnexpectedEnd);
+    }
+    let format = sniff_format(data);
     let mut out = Vec::new();
-    if data.len() >= 2 && data[0] == 0x1f && data[1] == 0x8b {
-        GzDecoder::new(&data[..])
-            .read_to_end(&mut out)
-            .map_err(|e| comp_err("unzip", e))?;
-    } else {
-        ZlibDecoder::new(&data[..])
-            .read_to_end(&mut out)
-            .map_err(|e| comp_err("unzip", e))?;
+    let result = match format {
+        // MultiGzDecoder consumes consecutive gzip members, like `gunzip`.
+        ZlibFormat::Gzip => MultiGzDecoder::new(data).read_to_end(&mut out),
+        ZlibFormat::Zlib => ZlibDecoder::new(data).read_to_end(&mut out),
+        ZlibFormat::Raw => DeflateDecoder::new(data).read_to_end(&mut out),
+    };
+    match result {
+        Ok(_) => Ok(out),
+        Err(err) if err.kind() == std::io::ErrorKind::UnexpectedEof => {
+            Err(ZlibStreamError::UnexpectedEnd)
+        }
+        Err(err) if format == ZlibFormat::Raw => Err(ZlibStreamError::Data(format!(
+            "unknown compression format: no gzip or zlib header and not raw deflate ({err})"
+        ))),
+        Err(err) => Err(ZlibStreamError::Data(err.to_string())),
     }
-    crate::string_value(ctx, &bytes_to_latin1(&out))
 }
 
 /// `crc32(dataLatin1, initialCrc)` → updated CRC-32 as a JS number.
@@ -193,3 +255,861 @@ fn crc32_with_seed(seed: u32, data: &[u8]) -> u32 {
     }
     !crc
 }
+
+// ---- streaming codecs ----
+
+/// Output space reserved per `compress_vec` / `decompress_vec` round.
+const STREAM_CHUNK: usize = 16 * 1024;
+
+/// Fixed gzip member header: deflate, no flags, no mtime, unknown OS (the same
+/// fields flate2's `GzEncoder` writes for the one-shot `gzip`).
+const GZIP_HEADER: [u8; 10] = [0x1f, 0x8b, 8, 0, 0, 0, 0, 0, 0, 0xff];
+
+/// Wrapper around the DEFLATE data in a stream.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ZlibFormat {
+    /// Bare DEFLATE (`DeflateRaw` / `InflateRaw`).
+    Raw,
+    /// RFC 1950 zlib header and Adler-32 trailer (`Deflate` / `Inflate`).
+    Zlib,
+    /// RFC 1952 gzip header and CRC-32/ISIZE trailer (`Gzip` / `Gunzip`).
+    Gzip,
+}
+
+/// Node's flush kinds (`zlib.constants.Z_*_FLUSH`, `Z_FINISH`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ZlibFlush {
+    /// `Z_NO_FLUSH`: the codec may hold input back.
+    None,
+    /// `Z_PARTIAL_FLUSH`.
+    Partial,
+    /// `Z_SYNC_FLUSH`: emit everything so far, aligned to a byte boundary.
+    Sync,
+    /// `Z_FULL_FLUSH`: a sync flush that also resets the match window.
+    Full,
+    /// `Z_FINISH`: end the stream.
+    Finish,
+}
+
+impl ZlibFlush {
+    /// Map a Node flush constant. `Z_BLOCK` / `Z_TREES` have no `miniz_oxide`
+    /// counterpart and fall back to a partial flush.
+    pub fn from_code(code: i32) -> Self {
+        match code {
+            1 | 5 | 6 => Self::Partial,
+            2 => Self::Sync,
+            3 => Self::Full,
+            4 => Self::Finish,
+            _ => Self::None,
+        }
+    }
+
+    /// Map a `BROTLI_OPERATION_*` constant: `FLUSH` (1) becomes a sync
+    /// flush, `FINISH` (2) ends the stream, `PROCESS` / `EMIT_METADATA` just
+    /// feed input.
+    pub fn from_brotli_operation(code: i32) -> Self {
+        match code {
+            1 => Self::Sync,
+            2 => Self::Finish,
+            _ => Self::None,
+        }
+    }
+
+    fn compress_mode(self) -> FlushCompress {
+        match self {
+            Self::None => FlushCompress::None,
+            Self::Partial => FlushCompress::Partial,
+            Self::Sync => FlushCompress::Sync,
+            Self::Full => FlushCompress::Full,
+            Self::Finish => FlushCompress::Finish,
+        }
+    }
+}
+
+/// Failure of a streaming codec.
+#[derive(Debug, thiserror::Error)]
+pub enum ZlibStreamError {
+    /// Corrupt or mismatched input (`Z_DATA_ERROR`).
+    #[error("{0}")]
+    Data(String),
+    /// `Z_FINISH` arrived before the compressed stream was complete.
+    #[error("unexpected end of file")]
+    UnexpectedEnd,
+    /// Write to a finished encoder or an unknown handle.
+    #[error("zlib binding closed")]
+    Closed,
+    /// An encoder option outside its valid range (e.g. brotli `lgwin`).
+    #[error(
+        "The value of \"{name}\" is out of range. It must be >= {min} and <= {max}. Received {value}"
+    )]
+    OutOfRange {
+        /// Option name.
+        name: &'static str,
+        /// Smallest accepted value.
+        min: u32,
+        /// Largest accepted value.
+        max: u32,
+        /// The offending value, as the caller wrote it.
+        value: String,
+    },
+}
+
+impl ZlibStreamError {
+    fn into_native(self) -> NativeError {
+        let (kind, code) = match &self {
+            Self::Data(_) => (otter_vm::ErrorKind::Error, "Z_DATA_ERROR"),
+            Self::UnexpectedEnd => (otter_vm::ErrorKind::Error, "Z_BUF_ERROR"),
+            Self::Closed => (otter_vm::ErrorKind::Error, "ERR_ZLIB_BINDING_CLOSED"),
+            Self::OutOfRange { .. } => (otter_vm::ErrorKind::RangeError, "ERR_OUT_OF_RANGE"),
+        };
+        NativeError::Coded {
+            kind,
+            code,
+            message: self.to_string(),
+        }
+    }
+}
+
+/// Incremental compressor: raw DEFLATE, zlib, or gzip.
+pub struct DeflateStream {
+    format: ZlibFormat,
+    compress: Compress,
+    header_pending: bool,
+    crc: u32,
+    size: u32,
+    finished: bool,
+}
+
+impl DeflateStream {
+    /// Compressor for `format` at `level`.
+    pub fn new(format: ZlibFormat, level: Compression) -> Self {
+        Self {
+            format,
+            compress: Compress::new(level, format == ZlibFormat::Zlib),
+            header_pending: format == ZlibFormat::Gzip,
+            crc: 0,
+            size: 0,
+            finished: false,
+        }
+    }
+
+    /// Gzip compressor (`zlib.createGzip()`).
+    pub fn gzip(level: Compression) -> Self {
+        Self::new(ZlibFormat::Gzip, level)
+    }
+
+    /// Compress `chunk`, then apply `flush`; returns the bytes produced.
+    pub fn write(&mut self, chunk: &[u8], flush: ZlibFlush) -> Result<Vec<u8>, ZlibStreamError> {
+        if self.finished {
+            return Err(ZlibStreamError::Closed);
+        }
+        let mut out = Vec::new();
+        if std::mem::take(&mut self.header_pending) {
+            out.extend_from_slice(&GZIP_HEADER);
+        }
+        if self.format == ZlibFormat::Gzip {
+            self.crc = crc32_with_seed(self.crc, chunk);
+            self.size = self.size.wrapping_add(chunk.len() as u32);
+        }
+        let start_in = self.compress.total_in();
+        loop {
+            out.reserve(STREAM_CHUNK);
+            let consumed = (self.compress.total_in() - start_in) as usize;
+            let status = self
+                .compress
+                .compress_vec(&chunk[consumed..], &mut out, flush.compress_mode())
+                .map_err(|e| ZlibStreamError::Data(e.to_string()))?;
+            let consumed = (self.compress.total_in() - start_in) as usize;
+            // Output space left over means the codec had nothing more to say
+            // for this flush mode; `Z_FINISH` runs until the stream ends.
+            let drained = consumed == chunk.len() && out.len() < out.capacity();
+            if status == Status::StreamEnd || (flush != ZlibFlush::Finish && drained) {
+                break;
+            }
+        }
+        if flush == ZlibFlush::Finish {
+            self.finished = true;
+            if self.format == ZlibFormat::Gzip {
+                out.extend_from_slice(&self.crc.to_le_bytes());
+                out.extend_from_slice(&self.size.to_le_bytes());
+            }
+        }
+        Ok(out)
+    }
+
+    /// `Z_SYNC_FLUSH`: everything written so far becomes decodable.
+    pub fn flush(&mut self) -> Result<Vec<u8>, ZlibStreamError> {
+        self.write(&[], ZlibFlush::Sync)
+    }
+
+    /// `Z_FINISH`: the final block plus the format's trailer.
+    pub fn finish(&mut self) -> Result<Vec<u8>, ZlibStreamError> {
+        self.write(&[], ZlibFlush::Finish)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum InflateState {
+    Header,
+    Body,
+    Trailer,
+    Done,
+}
+
+/// Incremental decompressor: raw DEFLATE, zlib, gzip (consecutive members
+/// included), or `unzip`'s sniffed gzip-or-zlib.
+pub struct InflateStream {
+    /// `None` until `unzip` has seen the first two bytes.
+    format: Option<ZlibFormat>,
+    decompress: Decompress,
+    state: InflateState,
+    /// Input not consumed yet: a partial header or trailer, or deflate data
+    /// the codec has not taken.
+    pending: Vec<u8>,
+    crc: u32,
+    size: u32,
+}
+
+impl InflateStream {
+    /// Decompressor for `format`.
+    pub fn new(format: ZlibFormat) -> Self {
+        Self::with_format(Some(format))
+    }
+
+    /// Gzip decompressor (`zlib.createGunzip()`).
+    pub fn gunzip() -> Self {
+        Self::new(ZlibFormat::Gzip)
+    }
+
+    /// `zlib.createUnzip()`: gzip when the input starts with the gzip magic,
+    /// zlib otherwise.
+    pub fn unzip() -> Self {
+        Self::with_format(None)
+    }
+
+    fn with_format(format: Option<ZlibFormat>) -> Self {
+        Self {
+            format,
+            decompress: Decompress::new(format == Some(ZlibFormat::Zlib)),
+            state: InflateState::Header,
+            pending: Vec::new(),
+            crc: 0,
+            size: 0,
+        }
+    }
+
+    /// Decompress `chunk`; returns the bytes produced. Output is never held
+    /// back, so `flush` only matters as `Z_FINISH`, which fails when the
+    /// stream is incomplete.
+    pub fn write(&mut self, chunk: &[u8], flush: ZlibFlush) -> Result<Vec<u8>, ZlibStreamError> {
+        self.pending.extend_from_slice(chunk);
+        let mut out = Vec::new();
+        loop {
+            match self.state {
+                InflateState::Header => {
+                    let format = match self.format {
+                        Some(format) => format,
+                        None if self.pending.len() < 2 => break,
+                        None => {
+                            let format = if self.pending.starts_with(&GZIP_HEADER[..2]) {
+                                ZlibFormat::Gzip
+                            } else {
+                                ZlibFormat::Zlib
+                            };
+                            self.format = Some(format);
+                            self.decompress = Decompress::new(format == ZlibFormat::Zlib);
+                            format
+                        }
+                    };
+                    if format == ZlibFormat::Gzip {
+                        match gzip_header_len(&self.pending)? {
+                            Some(len) => {
+                                self.pending.drain(..len);
+                            }
+                            None => break,
+                        }
+                    }
+                    self.state = InflateState::Body;
+                }
+                InflateState::Body => {
+                    let before = out.len();
+                    let (consumed, ended) =
+                        inflate_some(&mut self.decompress, &self.pending, &mut out)?;
+                    self.pending.drain(..consumed);
+                    if self.format == Some(ZlibFormat::Gzip) {
+                        self.crc = crc32_with_seed(self.crc, &out[before..]);
+                        self.size = self.size.wrapping_add((out.len() - before) as u32);
+                    }
+                    if !ended {
+                        break;
+                    }
+                    self.state = if self.format == Some(ZlibFormat::Gzip) {
+                        InflateState::Trailer
+                    } else {
+                        InflateState::Done
+                    };
+                }
+                InflateState::Trailer => {
+                    let Some(trailer) = self.pending.get(..8) else {
+                        break;
+                    };
+                    let crc = u32::from_le_bytes([trailer[0], trailer[1], trailer[2], trailer[3]]);
+                    let size = u32::from_le_bytes([trailer[4], trailer[5], trailer[6], trailer[7]]);
+                    if crc != self.crc {
+                        return Err(ZlibStreamError::Data("incorrect data check".to_string()));
+                    }
+                    if size != self.size {
+                        return Err(ZlibStreamError::Data("incorrect length check".to_string()));
+                    }
+                    self.pending.drain(..8);
+                    self.state = InflateState::Done;
+                }
+                InflateState::Done => {
+                    // Another gzip member continues the stream (Node
+                    // concatenates them); anything else is trailing garbage.
+                    let gzip = self.format == Some(ZlibFormat::Gzip);
+                    match self.pending.get(..2) {
+                        _ if !gzip || self.pending.is_empty() => {}
+                        None if self.pending[0] == GZIP_HEADER[0] => break,
+                        Some(magic) if magic == &GZIP_HEADER[..2] => {
+                            self.decompress.reset(false);
+                            self.crc = 0;
+                            self.size = 0;
+                            self.state = InflateState::Header;
+                            continue;
+                        }
+                        _ => {}
+                    }
+                    self.pending.clear();
+                    break;
+                }
+            }
+        }
+        if flush == ZlibFlush::Finish && self.state != InflateState::Done {
+            return Err(ZlibStreamError::UnexpectedEnd);
+        }
+        Ok(out)
+    }
+}
+
+/// Length of the complete gzip member header at the start of `buf`, or
+/// `None` while more bytes are needed.
+fn gzip_header_len(buf: &[u8]) -> Result<Option<usize>, ZlibStreamError> {
+    const FHCRC: u8 = 0x02;
+    const FEXTRA: u8 = 0x04;
+    const FNAME: u8 = 0x08;
+    const FCOMMENT: u8 = 0x10;
+    if buf.len() < GZIP_HEADER.len() {
+        return Ok(None);
+    }
+    if buf[..2] != GZIP_HEADER[..2] {
+        return Err(ZlibStreamError::Data("incorrect header check".to_string()));
+    }
+    if buf[2] != 8 {
+        return Err(ZlibStreamError::Data(
+            "unknown compression method".to_string(),
+        ));
+    }
+    let flags = buf[3];
+    let mut len = GZIP_HEADER.len();
+    if flags & FEXTRA != 0 {
+        let Some(xlen) = buf.get(len..len + 2) else {
+            return Ok(None);
+        };
+        len += 2 + u16::from_le_bytes([xlen[0], xlen[1]]) as usize;
+    }
+    for field in [FNAME, FCOMMENT] {
+        if flags & field != 0 {
+            match buf
+                .get(len..)
+                .and_then(|rest| rest.iter().position(|&b| b == 0))
+            {
+                Some(nul) => len += nul + 1,
+                None => return Ok(None),
+            }
+        }
+    }
+    if flags & FHCRC != 0 {
+        len += 2;
+    }
+    Ok((buf.len() >= len).then_some(len))
+}
+
+/// Feed `input` to `decompress`, appending to `out`. Returns the bytes
+/// consumed and whether the deflate stream ended.
+fn inflate_some(
+    decompress: &mut Decompress,
+    input: &[u8],
+    out: &mut Vec<u8>,
+) -> Result<(usize, bool), ZlibStreamError> {
+    let start_in = decompress.total_in();
+    loop {
+        out.reserve(STREAM_CHUNK);
+        let consumed = (decompress.total_in() - start_in) as usize;
+        let before_out = decompress.total_out();
+        let status = decompress
+            .decompress_vec(&input[consumed..], out, FlushDecompress::None)
+            .map_err(|e| ZlibStreamError::Data(e.to_string()))?;
+        let now_consumed = (decompress.total_in() - start_in) as usize;
+        if status == Status::StreamEnd {
+            return Ok((now_consumed, true));
+        }
+        let stalled = now_consumed == consumed && decompress.total_out() == before_out;
+        let drained = now_consumed == input.len() && out.len() < out.capacity();
+        if stalled || drained {
+            return Ok((now_consumed, false));
+        }
+    }
+}
+
+// ---- brotli ----
+
+/// Smallest brotli window (`BROTLI_MIN_WINDOW_BITS`).
+pub const BROTLI_MIN_WINDOW_BITS: u32 = 10;
+/// Largest brotli window without the large-window extension
+/// (`BROTLI_MAX_WINDOW_BITS`).
+pub const BROTLI_MAX_WINDOW_BITS: u32 = 24;
+/// Highest brotli quality (`BROTLI_MAX_QUALITY`).
+pub const BROTLI_MAX_QUALITY: u32 = 11;
+
+/// Brotli encoder settings: the `BROTLI_PARAM_QUALITY`, `BROTLI_PARAM_LGWIN`
+/// and `BROTLI_PARAM_SIZE_HINT` entries of Node's `params` option bag.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BrotliOptions {
+    /// Compression quality, `0..=11`.
+    pub quality: u32,
+    /// Base-2 log of the sliding window, `10..=24`.
+    pub lgwin: u32,
+    /// Expected input size in bytes; `0` when unknown.
+    pub size_hint: usize,
+}
+
+impl Default for BrotliOptions {
+    /// Node's defaults: `BROTLI_DEFAULT_QUALITY` (11) and
+    /// `BROTLI_DEFAULT_WINDOW` (22).
+    fn default() -> Self {
+        Self {
+            quality: BROTLI_MAX_QUALITY,
+            lgwin: 22,
+            size_hint: 0,
+        }
+    }
+}
+
+impl BrotliOptions {
+    /// Reject a quality above 11 or a window outside `10..=24`.
+    pub fn validate(&self) -> Result<(), ZlibStreamError> {
+        if self.quality > BROTLI_MAX_QUALITY {
+            return Err(ZlibStreamError::OutOfRange {
+                name: "quality",
+                min: 0,
+                max: BROTLI_MAX_QUALITY,
+                value: self.quality.to_string(),
+            });
+        }
+        if !(BROTLI_MIN_WINDOW_BITS..=BROTLI_MAX_WINDOW_BITS).contains(&self.lgwin) {
+            return Err(ZlibStreamError::OutOfRange {
+                name: "lgwin",
+                min: BROTLI_MIN_WINDOW_BITS,
+                max: BROTLI_MAX_WINDOW_BITS,
+                value: self.lgwin.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    fn encoder_params(&self) -> BrotliEncoderParams {
+        BrotliEncoderParams {
+            quality: self.quality as i32,
+            lgwin: self.lgwin as i32,
+            size_hint: self.size_hint,
+            ..BrotliEncoderParams::default()
+        }
+    }
+}
+
+/// One-shot brotli compression; `None` uses [`BrotliOptions::default`].
+pub fn brotli_compress(
+    data: &[u8],
+    options: Option<BrotliOptions>,
+) -> Result<Vec<u8>, ZlibStreamError> {
+    let options = options.unwrap_or_default();
+    options.validate()?;
+    let mut out = Vec::new();
+    brotli::BrotliCompress(&mut &data[..], &mut out, &options.encoder_params())
+        .map_err(|e| ZlibStreamError::Data(e.to_string()))?;
+    Ok(out)
+}
+
+/// One-shot brotli decompression.
+pub fn brotli_decompress(data: &[u8]) -> Result<Vec<u8>, ZlibStreamError> {
+    let mut out = Vec::new();
+    brotli::BrotliDecompress(&mut &data[..], &mut out)
+        .map_err(|e| ZlibStreamError::Data(e.to_string()))?;
+    Ok(out)
+}
+
+/// Incremental brotli encoder (`zlib.createBrotliCompress()`).
+pub struct BrotliCompressStream {
+    /// `None` once finished.
+    writer: Option<CompressorWriter<Vec<u8>>>,
+}
+
+impl BrotliCompressStream {
+    /// Encoder with validated `options`.
+    pub fn new(options: BrotliOptions) -> Result<Self, ZlibStreamError> {
+        options.validate()?;
+        Ok(Self {
+            writer: Some(CompressorWriter::with_params(
+                Vec::new(),
+                STREAM_CHUNK,
+                &options.encoder_params(),
+            )),
+        })
+    }
+
+    /// Compress `chunk`, then apply `flush`; returns the bytes produced. Any
+    /// flush short of `Finish` is `BROTLI_OPERATION_FLUSH`.
+    pub fn write(&mut self, chunk: &[u8], flush: ZlibFlush) -> Result<Vec<u8>, ZlibStreamError> {
+        let writer = self.writer.as_mut().ok_or(ZlibStreamError::Closed)?;
+        writer
+            .write_all(chunk)
+            .map_err(|e| ZlibStreamError::Data(e.to_string()))?;
+        match flush {
+            ZlibFlush::None => Ok(std::mem::take(writer.get_mut())),
+            ZlibFlush::Finish => Ok(self
+                .writer
+                .take()
+                .map(CompressorWriter::into_inner)
+                .unwrap_or_default()),
+            _ => {
+                writer
+                    .flush()
+                    .map_err(|e| ZlibStreamError::Data(e.to_string()))?;
+                Ok(std::mem::take(writer.get_mut()))
+            }
+        }
+    }
+}
+
+/// Incremental brotli decoder (`zlib.createBrotliDecompress()`).
+pub struct BrotliDecompressStream {
+    writer: DecompressorWriter<Vec<u8>>,
+}
+
+impl BrotliDecompressStream {
+    /// A fresh decoder.
+    pub fn new() -> Self {
+        Self {
+            writer: DecompressorWriter::new(Vec::new(), STREAM_CHUNK),
+        }
+    }
+
+    /// Decompress `chunk`; returns the bytes produced. `Finish` fails when
+    /// the brotli stream is incomplete.
+    pub fn write(&mut self, chunk: &[u8], flush: ZlibFlush) -> Result<Vec<u8>, ZlibStreamError> {
+        self.writer
+            .write_all(chunk)
+            .map_err(|e| ZlibStreamError::Data(e.to_string()))?;
+        if flush == ZlibFlush::Finish {
+            self.writer
+                .close()
+                .map_err(|_| ZlibStreamError::UnexpectedEnd)?;
+        }
+        Ok(std::mem::take(self.writer.get_mut()))
+    }
+}
+
+impl Default for BrotliDecompressStream {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Read `[quality, lgwin, sizeHint]` from `args[index..]`; missing entries
+/// keep [`BrotliOptions::default`].
+fn brotli_options(args: &[Value], index: usize) -> Result<BrotliOptions, ZlibStreamError> {
+    let defaults = BrotliOptions::default();
+    let field = |offset: usize, name: &'static str, default: u32| match args
+        .get(index + offset)
+        .and_then(|v| v.as_f64())
+    {
+        None => Ok(default),
+        Some(n) if n >= 0.0 && n.fract() == 0.0 && n <= u32::MAX as f64 => Ok(n as u32),
+        Some(n) => Err(ZlibStreamError::OutOfRange {
+            name,
+            min: 0,
+            max: u32::MAX,
+            value: n.to_string(),
+        }),
+    };
+    Ok(BrotliOptions {
+        quality: field(0, "quality", defaults.quality)?,
+        lgwin: field(1, "lgwin", defaults.lgwin)?,
+        size_hint: field(2, "sizeHint", 0)? as usize,
+    })
+}
+
+/// `brotliCompress(dataLatin1, quality, lgwin, sizeHint)`.
+fn brotli_compress_native(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
+    let data = latin1_to_bytes(&runtime_arg_to_string(args, 0, ctx.heap()));
+    let out = brotli_options(args, 1)
+        .and_then(|options| brotli_compress(&data, Some(options)))
+        .map_err(ZlibStreamError::into_native)?;
+    crate::string_value(ctx, &bytes_to_latin1(&out))
+}
+
+/// `brotliDecompress(dataLatin1)`.
+fn brotli_decompress_native(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
+    let data = latin1_to_bytes(&runtime_arg_to_string(args, 0, ctx.heap()));
+    let out = brotli_decompress(&data).map_err(ZlibStreamError::into_native)?;
+    crate::string_value(ctx, &bytes_to_latin1(&out))
+}
+
+// ---- stream handles ----
+
+enum StreamHandle {
+    Deflate(DeflateStream),
+    Inflate(InflateStream),
+    BrotliCompress(BrotliCompressStream),
+    BrotliDecompress(BrotliDecompressStream),
+}
+
+/// Host-object payload behind a stream handle; `None` once closed.
+struct StreamState(Option<StreamHandle>);
+
+impl otter_runtime::RuntimeHostObjectData for StreamState {}
+
+/// `streamOpen(mode, level)` → handle object. `mode` is Node's
+/// `zlib.constants.DEFLATE` … `UNZIP`, `BROTLI_DECODE` or `BROTLI_ENCODE`;
+/// brotli encoders read `(mode, quality, lgwin, sizeHint)` instead.
+fn stream_open(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
+    let level = compression(args, 1);
+    let handle = match args.first().and_then(|v| v.as_f64()).unwrap_or(0.0) as i32 {
+        1 => StreamHandle::Deflate(DeflateStream::new(ZlibFormat::Zlib, level)),
+        2 => StreamHandle::Inflate(InflateStream::new(ZlibFormat::Zlib)),
+        3 => StreamHandle::Deflate(DeflateStream::gzip(level)),
+        4 => StreamHandle::Inflate(InflateStream::gunzip()),
+        5 => StreamHandle::Deflate(DeflateStream::new(ZlibFormat::Raw, level)),
+        6 => StreamHandle::Inflate(InflateStream::new(ZlibFormat::Raw)),
+        7 => StreamHandle::Inflate(InflateStream::unzip()),
+        8 => StreamHandle::BrotliDecompress(BrotliDecompressStream::new()),
+        9 => StreamHandle::BrotliCompress(
+            brotli_options(args, 1)
+                .and_then(BrotliCompressStream::new)
+                .map_err(ZlibStreamError::into_native)?,
+        ),
+        mode => {
+            return Err(NativeError::Coded {
+                kind: otter_vm::ErrorKind::TypeError,
+                code: "ERR_INVALID_ARG_VALUE",
+                message: format!("Bad argument: unknown zlib mode {mode}"),
+            });
+        }
+    };
+    ctx.scope(|mut scope| {
+        let state = scope.host_object(StreamState(Some(handle)))?;
+        Ok(scope.finish(state))
+    })
+}
+
+/// `streamWrite(handle, dataLatin1, flush)` → the latin1 bytes produced.
+/// `flush` is a `Z_*` flush constant, or a `BROTLI_OPERATION_*` for brotli
+/// handles.
+fn stream_write(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
+    let data = latin1_to_bytes(&runtime_arg_to_string(args, 1, ctx.heap()));
+    let code = args.get(2).and_then(|v| v.as_f64()).unwrap_or(0.0) as i32;
+    let flush = ZlibFlush::from_code(code);
+    let operation = ZlibFlush::from_brotli_operation(code);
+    let out = with_stream_state(ctx, args, |state| match state {
+        Some(StreamHandle::Deflate(stream)) => stream.write(&data, flush),
+        Some(StreamHandle::Inflate(stream)) => stream.write(&data, flush),
+        Some(StreamHandle::BrotliCompress(stream)) => stream.write(&data, operation),
+        Some(StreamHandle::BrotliDecompress(stream)) => stream.write(&data, operation),
+        None => Err(ZlibStreamError::Closed),
+    })?
+    .map_err(ZlibStreamError::into_native)?;
+    crate::string_value(ctx, &bytes_to_latin1(&out))
+}
+
+/// `streamClose(handle)`: drop the codec; closing twice is a no-op.
+fn stream_close(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
+    with_stream_state(ctx, args, |state| *state = None)?;
+    Ok(Value::undefined())
+}
+
+/// Run `f` against the codec behind the handle object in `args[0]`.
+fn with_stream_state<R>(
+    ctx: &mut NativeCtx<'_>,
+    args: &[Value],
+    f: impl FnOnce(&mut Option<StreamHandle>) -> R,
+) -> Result<R, NativeError> {
+    let handle = args.first().copied().unwrap_or_else(Value::undefined);
+    ctx.scope(|mut scope| {
+        let handle = scope.value(handle);
+        scope.with_host_data_mut::<StreamState, _>(handle, |state| f(&mut state.0))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use flate2::read::GzDecoder;
+
+    use super::*;
+
+    const SYNC_MARKER: [u8; 4] = [0, 0, 0xff, 0xff];
+
+    #[test]
+    fn sync_flush_emits_a_decodable_boundary_without_ending() {
+        let mut gzip = DeflateStream::gzip(Compression::default());
+        let mut gunzip = InflateStream::gunzip();
+
+        let mut first = gzip.write(b"hello ", ZlibFlush::None).unwrap();
+        first.extend(gzip.flush().unwrap());
+        assert!(first.ends_with(&SYNC_MARKER), "{first:?}");
+        assert_eq!(gunzip.write(&first, ZlibFlush::Sync).unwrap(), b"hello ");
+
+        let rest = gzip.write(b"world", ZlibFlush::Finish).unwrap();
+        assert_eq!(gunzip.write(&rest, ZlibFlush::Finish).unwrap(), b"world");
+        assert!(matches!(
+            gzip.write(b"!", ZlibFlush::None),
+            Err(ZlibStreamError::Closed)
+        ));
+
+        let mut whole = Vec::new();
+        GzDecoder::new(&[first, rest].concat()[..])
+            .read_to_end(&mut whole)
+            .unwrap();
+        assert_eq!(whole, b"hello world");
+    }
+
+    #[test]
+    fn inflate_accepts_input_split_at_every_byte() {
+        let member = |text: &[u8]| {
+            let mut enc = GzEncoder::new(Vec::new(), Compression::default());
+            enc.write_all(text).unwrap();
+            enc.finish().unwrap()
+        };
+        let input = [member(b"first "), member(b"second")].concat();
+        let mut gunzip = InflateStream::gunzip();
+        let mut out = Vec::new();
+        for byte in &input {
+            out.extend(
+                gunzip
+                    .write(std::slice::from_ref(byte), ZlibFlush::None)
+                    .unwrap(),
+            );
+        }
+        out.extend(gunzip.write(&[], ZlibFlush::Finish).unwrap());
+        assert_eq!(out, b"first second");
+
+        for format in [ZlibFormat::Raw, ZlibFormat::Zlib] {
+            let mut deflate = DeflateStream::new(format, Compression::best());
+            let compressed = deflate.write(b"abcabcabc", ZlibFlush::Finish).unwrap();
+            let mut inflate = InflateStream::new(format);
+            let mut out = Vec::new();
+            for piece in compressed.chunks(3) {
+                out.extend(inflate.write(piece, ZlibFlush::None).unwrap());
+            }
+            assert_eq!(out, b"abcabcabc", "{format:?}");
+        }
+    }
+
+    #[test]
+    fn finish_before_the_stream_ends_is_an_error() {
+        let mut gzip = DeflateStream::gzip(Compression::fast());
+        let compressed = gzip.write(b"truncated", ZlibFlush::Finish).unwrap();
+        let mut gunzip = InflateStream::gunzip();
+        let cut = &compressed[..compressed.len() - 4];
+        assert!(matches!(
+            gunzip.write(cut, ZlibFlush::Finish),
+            Err(ZlibStreamError::UnexpectedEnd)
+        ));
+        assert!(matches!(
+            InflateStream::unzip().write(b"not zlib data", ZlibFlush::None),
+            Err(ZlibStreamError::Data(_))
+        ));
+    }
+
+    #[test]
+    fn brotli_stream_flush_is_decodable_before_finish() {
+        let options = BrotliOptions {
+            quality: 5,
+            lgwin: BROTLI_MIN_WINDOW_BITS,
+            size_hint: 11,
+        };
+        let mut encoder = BrotliCompressStream::new(options).unwrap();
+        let mut decoder = BrotliDecompressStream::new();
+
+        let first = encoder.write(b"hello ", ZlibFlush::Sync).unwrap();
+        assert!(!first.is_empty());
+        assert_eq!(decoder.write(&first, ZlibFlush::None).unwrap(), b"hello ");
+
+        let rest = encoder.write(b"brotli", ZlibFlush::Finish).unwrap();
+        assert_eq!(decoder.write(&rest, ZlibFlush::Finish).unwrap(), b"brotli");
+        assert!(matches!(
+            encoder.write(b"!", ZlibFlush::None),
+            Err(ZlibStreamError::Closed)
+        ));
+
+        let mut truncated = BrotliDecompressStream::new();
+        assert!(matches!(
+            truncated.write(&first, ZlibFlush::Finish),
+            Err(ZlibStreamError::UnexpectedEnd)
+        ));
+    }
+
+    #[test]
+    fn brotli_options_reject_out_of_range_window_and_quality() {
+        for lgwin in [9, 25] {
+            let options = BrotliOptions {
+                lgwin,
+                ..BrotliOptions::default()
+            };
+            let err = BrotliCompressStream::new(options).err().unwrap();
+            assert!(
+                matches!(err, ZlibStreamError::OutOfRange { name: "lgwin", .. }),
+                "{err}"
+            );
+            assert!(brotli_compress(b"x", Some(options)).is_err());
+        }
+        let options = BrotliOptions {
+            quality: 12,
+            ..BrotliOptions::default()
+        };
+        assert!(options.validate().is_err());
+
+        let compressed = brotli_compress(b"default options", None).unwrap();
+        assert_eq!(brotli_decompress(&compressed).unwrap(), b"default options");
+    }
+
+    #[test]
+    fn unzip_auto_detects_gzip_zlib_and_raw_deflate() {
+        let text = b"auto-detected payload, auto-detected payload";
+        let compress = |format| {
+            let mut stream = DeflateStream::new(format, Compression::default());
+            stream.write(text, ZlibFlush::Finish).unwrap()
+        };
+        for (format, magic) in [
+            (ZlibFormat::Gzip, Some(0x1f)),
+            (ZlibFormat::Zlib, Some(0x78)),
+            (ZlibFormat::Raw, None),
+        ] {
+            let packed = compress(format);
+            if let Some(magic) = magic {
+                assert_eq!(packed[0], magic);
+            }
+            assert_eq!(sniff_format(&packed), format);
+            assert_eq!(unzip_auto(&packed).unwrap(), text);
+        }
+
+        assert!(matches!(
+            unzip_auto(b"not zlib data"),
+            Err(ZlibStreamError::Data(message)) if message.starts_with("unknown compression format")
+        ));
+        assert!(matches!(
+            unzip_auto(b""),
+            Err(ZlibStreamError::UnexpectedEnd)
+        ));
+    }
+}
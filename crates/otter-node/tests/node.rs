@@ -1,5 +1,5 @@
 use otter_node::{NodeApiBuilderExt, hosted_modules};
-use otter_runtime::{CapabilitySet, Permission, Runtime};
+use otter_runtime::{CapabilitySet, Permission, Runtime, SourceInput};
 
 #[test]
 fn hosted_node_module_specs_are_static_and_ordered() {
@@ -79,3 +79,677 @@ fn node_fs_read_write_round_trips_with_permissions() {
         .unwrap();
     runtime.run_module(&main).unwrap();
 }
+
+#[test]
+fn buffer_concat_sizes_to_total_length() {
+    let mut runtime = Runtime::builder().with_node_apis().build().unwrap();
+    let result = runtime
+        .run_script(
+            SourceInput::from_javascript(
+                r#"
+                const a = Buffer.from([1, 2, 3]);
+                const b = new Uint8Array([4, 5]);
+                const view = new Uint8Array([9, 6, 7, 9]).subarray(1, 3);
+                [
+                    Buffer.concat([]).length,
+                    Buffer.concat([], 4).length,
+                    Buffer.isBuffer(Buffer.concat([])),
+                    Buffer.concat([a, b, view]).join(""),
+                    Buffer.concat([a, b], 4).join(""),
+                    Buffer.concat([a, b], 8).join(""),
+                    Buffer.concat([a, b], 0).length,
+                    Buffer.concat([a], 3) !== a,
+                ].join(",");
+                "#,
+            ),
+            "<buffer-concat>",
+        )
+        .unwrap();
+    assert_eq!(
+        result.completion_string(),
+        "0,0,true,1234567,1234,12345000,0,true"
+    );
+}
+
+#[test]
+fn crypto_hmac_streams_updates_across_algorithms() {
+    let dir = tempfile::tempdir().unwrap();
+    let main = dir.path().join("main.mjs");
+    std::fs::write(
+        &main,
+        r#"
+            import { createHash, createHmac, getHashes } from "node:crypto";
+            const seen = [];
+            const streamed = createHmac("sha256", "key");
+            for (const chunk of ["The quick ", "brown fox ", Buffer.from("jumps over the lazy dog")]) {
+                streamed.update(chunk);
+            }
+            const raw = streamed.digest();
+            seen.push(Buffer.isBuffer(raw), raw.toString("hex"));
+            const oneShot = createHmac("sha256", "key")
+                .update("The quick brown fox jumps over the lazy dog");
+            seen.push(oneShot.digest("base64") === raw.toString("base64"));
+            seen.push(createHmac("SHA512-256", "k").update("a").update("b").digest("base64url")
+                === createHmac("sha512-256", "k").update("ab").digest().toString("base64url"));
+            try {
+                streamed.update("late");
+                seen.push("accepted");
+            } catch (err) {
+                seen.push(err.code);
+            }
+            for (const algo of ["sha224", "sha384", "sha512-256"]) {
+                seen.push(createHash(algo).update("a").update("bc").digest("hex"));
+            }
+            seen.push(getHashes().includes("sha512-256"));
+            globalThis.cryptoResult = seen.join(",");
+        "#,
+    )
+    .unwrap();
+
+    let mut runtime = Runtime::builder().with_node_apis().build().unwrap();
+    runtime.run_module(&main).unwrap();
+    let result = runtime
+        .run_script(
+            SourceInput::from_javascript("globalThis.cryptoResult"),
+            "<check>",
+        )
+        .unwrap();
+    assert_eq!(
+        result.completion_string(),
+        [
+            "true",
+            "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8",
+            "true",
+            "true",
+            "ERR_CRYPTO_HASH_FINALIZED",
+            "23097d223405d8228642a477bda255b32aadbce4bda0b3f7e36c9da7",
+            "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed\
+             8086072ba1e7cc2358baeca134c825a7",
+            "53048e2681941ef99b2e29b76b4c7dabe4c2d0c634fc6d46e4e2f13107e7af23",
+            "true",
+        ]
+        .join(",")
+    );
+}
+
+#[test]
+fn crypto_create_hash_streams_chunks_and_lists_algorithms() {
+    let dir = tempfile::tempdir().unwrap();
+    let main = dir.path().join("main.mjs");
+    std::fs::write(
+        &main,
+        r#"
+            import crypto, { createHash, getHashes } from "node:crypto";
+            const seen = [];
+            const input = "x".repeat(5000) + "tail";
+            for (const algo of ["md5", "sha1", "sha256", "sha384", "sha512"]) {
+                const chunked = createHash(algo);
+                for (let i = 0; i < input.length; i += 37) chunked.update(input.slice(i, i + 37));
+                seen.push(chunked.digest("hex") === crypto.hash(algo, input));
+            }
+            const partial = createHash("sha256").update("ab");
+            const copy = partial.copy();
+            seen.push(partial.update("c").digest("hex") === crypto.hash("sha256", "abc"));
+            seen.push(copy.update("d").digest("hex") === crypto.hash("sha256", "abd"));
+            seen.push(getHashes().join(" "));
+            const done = createHash("sha1").update("x");
+            done.digest();
+            for (const late of [() => done.update("y"), () => done.digest(), () => createHash("sha3-999")]) {
+                try {
+                    late();
+                    seen.push("accepted");
+                } catch (err) {
+                    seen.push(err.code);
+                }
+            }
+            globalThis.hashResult = seen.join(",");
+        "#,
+    )
+    .unwrap();
+
+    let mut runtime = Runtime::builder().with_node_apis().build().unwrap();
+    runtime.run_module(&main).unwrap();
+    let result = runtime
+        .run_script(
+            SourceInput::from_javascript("globalThis.hashResult"),
+            "<check>",
+        )
+        .unwrap();
+    assert_eq!(
+        result.completion_string(),
+        "true,true,true,true,true,true,true,\
+         md5 sha1 sha224 sha256 sha384 sha512 sha512-224 sha512-256,\
+         ERR_CRYPTO_HASH_FINALIZED,ERR_CRYPTO_HASH_FINALIZED,ERR_OSSL_EVP_UNSUPPORTED"
+    );
+}
+
+#[test]
+fn crypto_random_uuid_and_int_use_native_csprng() {
+    let dir = tempfile::tempdir().unwrap();
+    let main = dir.path().join("main.mjs");
+    std::fs::write(
+        &main,
+        r#"
+            import { randomInt, randomUUID } from "node:crypto";
+            const seen = [];
+            seen.push(/^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$/.test(randomUUID()));
+            seen.push(randomUUID() !== randomUUID());
+            const values = new Set();
+            for (let i = 0; i < 200; i++) values.add(randomInt(10, 13));
+            seen.push([...values].sort().join("/"));
+            seen.push(randomInt(1) === 0);
+            for (const args of [[5, 5], [0, 2 ** 48], [0.5, 2], [0, 2 ** 53]]) {
+                try {
+                    randomInt(...args);
+                    seen.push("accepted");
+                } catch (err) {
+                    seen.push(err.name + ":" + err.code);
+                }
+            }
+            globalThis.randomResult = seen.join(",");
+        "#,
+    )
+    .unwrap();
+
+    let mut runtime = Runtime::builder().with_node_apis().build().unwrap();
+    runtime.run_module(&main).unwrap();
+    let result = runtime
+        .run_script(
+            SourceInput::from_javascript("globalThis.randomResult"),
+            "<check>",
+        )
+        .unwrap();
+    assert_eq!(
+        result.completion_string(),
+        "true,true,10/11/12,true,RangeError:ERR_OUT_OF_RANGE,RangeError:ERR_OUT_OF_RANGE,\
+         TypeError:ERR_INVALID_ARG_TYPE,TypeError:ERR_INVALID_ARG_TYPE"
+    );
+}
+
+#[test]
+fn zlib_gzip_stream_emits_output_per_write_and_on_sync_flush() {
+    let dir = tempfile::tempdir().unwrap();
+    let main = dir.path().join("main.mjs");
+    std::fs::write(
+        &main,
+        r#"
+            import zlib from "node:zlib";
+            const gzip = zlib.createGzip();
+            const gunzip = zlib.createGunzip();
+            const compressed = [];
+            const plain = [];
+            gzip.on("data", (chunk) => compressed.push(chunk));
+            gunzip.on("data", (chunk) => plain.push(chunk.toString()));
+
+            gzip.write("hello ");
+            await new Promise((resolve) => gzip.flush(zlib.constants.Z_SYNC_FLUSH, resolve));
+            const flushed = Buffer.concat(compressed);
+            await new Promise((resolve) => gunzip.write(flushed, resolve));
+            const seen = [flushed.subarray(-4).join("/"), plain.join("")];
+
+            const ended = new Promise((resolve) => gzip.on("end", resolve));
+            gzip.end("world");
+            await ended;
+            const rest = Buffer.concat(compressed).subarray(flushed.length);
+            const done = new Promise((resolve) => gunzip.on("end", resolve));
+            gunzip.end(rest);
+            await done;
+            seen.push(plain.join(""));
+            seen.push(zlib.gunzipSync(Buffer.concat(compressed)).toString());
+
+            const broken = zlib.createGunzip();
+            const error = new Promise((resolve) => broken.on("error", (err) => resolve(err.code)));
+            broken.end(flushed);
+            seen.push(await error);
+            globalThis.zlibResult = seen.join(",");
+        "#,
+    )
+    .unwrap();
+
+    let mut runtime = Runtime::builder().with_node_apis().build().unwrap();
+    runtime.run_module(&main).unwrap();
+    let result = runtime
+        .run_script(
+            SourceInput::from_javascript("globalThis.zlibResult"),
+            "<check>",
+        )
+        .unwrap();
+    assert_eq!(
+        result.completion_string(),
+        "0/0/255/255,hello ,hello world,hello world,Z_BUF_ERROR"
+    );
+}
+
+#[test]
+fn zlib_brotli_honours_params_and_streams_flushes() {
+    let dir = tempfile::tempdir().unwrap();
+    let main = dir.path().join("main.mjs");
+    std::fs::write(
+        &main,
+        r#"
+            import zlib from "node:zlib";
+            const { constants } = zlib;
+            const text = "brotli ".repeat(200);
+            const params = {
+                [constants.BROTLI_PARAM_QUALITY]: 4,
+                [constants.BROTLI_PARAM_LGWIN]: 16,
+                [constants.BROTLI_PARAM_SIZE_HINT]: text.length,
+            };
+            const packed = zlib.brotliCompressSync(text, { params });
+            const seen = [
+                packed.length < text.length,
+                zlib.brotliDecompressSync(packed).toString() === text,
+            ];
+            for (const bad of [{ [constants.BROTLI_PARAM_LGWIN]: 25 }, { 99: 1 }]) {
+                try {
+                    zlib.brotliCompressSync(text, { params: bad });
+                    seen.push("accepted");
+                } catch (err) {
+                    seen.push(err.code);
+                }
+            }
+
+            const encoder = zlib.createBrotliCompress({ params });
+            const decoder = zlib.createBrotliDecompress();
+            const plain = [];
+            encoder.on("data", (chunk) => decoder.write(chunk));
+            decoder.on("data", (chunk) => plain.push(chunk.toString()));
+            encoder.write("first ");
+            await new Promise((resolve) => encoder.flush(resolve));
+            await new Promise((resolve) => setTimeout(resolve, 0));
+            seen.push(plain.join(""));
+            const done = new Promise((resolve) => decoder.on("finish", resolve));
+            encoder.on("end", () => decoder.end());
+            encoder.end("second");
+            await done;
+            seen.push(plain.join(""));
+            globalThis.brotliResult = seen.join(",");
+        "#,
+    )
+    .unwrap();
+
+    let mut runtime = Runtime::builder().with_node_apis().build().unwrap();
+    runtime.run_module(&main).unwrap();
+    let result = runtime
+        .run_script(
+            SourceInput::from_javascript("globalThis.brotliResult"),
+            "<check>",
+        )
+        .unwrap();
+    assert_eq!(
+        result.completion_string(),
+        "true,true,ERR_OUT_OF_RANGE,ERR_BROTLI_INVALID_PARAM,first ,first second"
+    );
+}
+
+#[test]
+fn dns_queries_require_net_permission() {
+    let dir = tempfile::tempdir().unwrap();
+    let main = dir.path().join("main.mjs");
+    std::fs::write(
+        &main,
+        r#"
+            import dns from "node:dns";
+            const out = [];
+            try {
+                await dns.promises.resolveTxt("example.com");
+            } catch (err) {
+                out.push(err.code);
+            }
+            await new Promise((resolve) => {
+                let sync = true;
+                dns.resolveMx("example.com", (err) => {
+                    out.push(`${err.code}:${sync}`);
+                    resolve();
+                });
+                sync = false;
+            });
+            try {
+                await dns.promises.reverse("not-an-ip");
+            } catch (err) {
+                out.push(`${err.code}:${err.syscall}`);
+            }
+            globalThis.dnsResult = out.join(",");
+        "#,
+    )
+    .unwrap();
+
+    let mut runtime = Runtime::builder().with_node_apis().build().unwrap();
+    runtime.run_module(&main).unwrap();
+    let result = runtime
+        .run_script(
+            SourceInput::from_javascript("globalThis.dnsResult"),
+            "<check>",
+        )
+        .unwrap();
+    assert_eq!(
+        result.completion_string(),
+        "EACCES,EACCES:false,EINVAL:getHostByAddr"
+    );
+}
+
+#[test]
+fn dns_set_servers_validates_and_resolver_timeout_is_etimeout() {
+    // Bound but never read: queries sent here go unanswered.
+    let silent = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+    let port = silent.local_addr().unwrap().port();
+    let dir = tempfile::tempdir().unwrap();
+    let main = dir.path().join("main.mjs");
+    std::fs::write(
+        &main,
+        format!(
+            r#"
+            import dns from "node:dns";
+            const out = [];
+            dns.setServers(["8.8.8.8", "8.8.4.4:53", "[2001:4860:4860::8888]:1053"]);
+            out.push(dns.getServers().join(" "));
+            out.push(dns.promises.getServers().length);
+            out.push((await import("dns")).default.getServers().length);
+            for (const bad of [[], ["dns.google"], "8.8.8.8"]) {{
+                try {{
+                    dns.setServers(bad);
+                }} catch (err) {{
+                    out.push(err.code);
+                }}
+            }}
+            out.push(dns.getServers().length);
+            const resolver = new dns.promises.Resolver({{ timeout: 100 }});
+            resolver.setServers(["127.0.0.1:{port}"]);
+            out.push(resolver.getServers()[0] === "127.0.0.1:{port}");
+            try {{
+                await resolver.resolveTxt("example.com");
+            }} catch (err) {{
+                out.push(`${{err.code}}:${{err.syscall}}:${{err.hostname}}`);
+            }}
+            globalThis.dnsResult = out.join(",");
+        "#
+        ),
+    )
+    .unwrap();
+
+    let mut runtime = Runtime::builder()
+        .capabilities(CapabilitySet::allow_all())
+        .with_node_apis()
+        .build()
+        .unwrap();
+    runtime.run_module(&main).unwrap();
+    let result = runtime
+        .run_script(
+            SourceInput::from_javascript("globalThis.dnsResult"),
+            "<check>",
+        )
+        .unwrap();
+    assert_eq!(
+        result.completion_string(),
+        "8.8.8.8 8.8.4.4 [2001:4860:4860::8888]:1053,3,3,ERR_INVALID_ARG_VALUE,\
+         ERR_INVALID_IP_ADDRESS,ERR_INVALID_ARG_TYPE,3,true,ETIMEOUT:queryTxt:example.com"
+    );
+    drop(silent);
+}
+
+#[test]
+fn child_process_exec_file_enforces_max_buffer_and_timeout() {
+    let dir = tempfile::tempdir().unwrap();
+    let main = dir.path().join("main.mjs");
+    std::fs::write(
+        &main,
+        r#"
+            import { execFile, execFileSync } from "node:child_process";
+            const run = (file, args, options) =>
+                new Promise((resolve) => {
+                    execFile(file, args, options, (err, stdout) => resolve({ err, stdout }));
+                });
+            const out = [];
+            const literal = await run("sh", ["-c", 'printf %s "$1"', "sh", "a b; echo $HOME"], {});
+            out.push(literal.stdout);
+            const unbounded = await run("sh", ["-c", "printf ok"], { timeout: Infinity });
+            out.push(unbounded.err === null, unbounded.stdout);
+            const flood = await run("yes", [], { maxBuffer: 100 });
+            out.push(flood.err.code, flood.err instanceof RangeError, flood.stdout.length);
+            const slow = await run("sh", ["-c", "trap '' TERM; sleep 5"], { timeout: 100 });
+            out.push(slow.err.killed, slow.err.signal);
+            try {
+                execFileSync("sh", ["-c", "sleep 5"], { timeout: 50 });
+            } catch (err) {
+                out.push(err.code);
+            }
+            globalThis.execFileResult = out.join(",");
+        "#,
+    )
+    .unwrap();
+
+    let mut runtime = Runtime::builder()
+        .capabilities(CapabilitySet::allow_all())
+        .with_node_apis()
+        .build()
+        .unwrap();
+    runtime.run_module(&main).unwrap();
+    let result = runtime
+        .run_script(
+            SourceInput::from_javascript("globalThis.execFileResult"),
+            "<check>",
+        )
+        .unwrap();
+    assert_eq!(
+        result.completion_string(),
+        "a b; echo $HOME,true,ok,ERR_CHILD_PROCESS_STDIO_MAXBUFFER,true,100,true,SIGKILL,ETIMEDOUT"
+    );
+
+    let denied = dir.path().join("denied.mjs");
+    std::fs::write(
+        &denied,
+        r#"
+            import { execFileSync } from "node:child_process";
+            try {
+                execFileSync("sh", ["-c", "true"]);
+            } catch (err) {
+                globalThis.deniedCode = err.code;
+            }
+        "#,
+    )
+    .unwrap();
+    let mut sandboxed = Runtime::builder().with_node_apis().build().unwrap();
+    sandboxed.run_module(&denied).unwrap();
+    let code = sandboxed
+        .run_script(
+            SourceInput::from_javascript("globalThis.deniedCode"),
+            "<check>",
+        )
+        .unwrap();
+    assert_eq!(code.completion_string(), "EACCES");
+}
+
+#[test]
+fn crypto_get_random_values_fills_integer_views_in_place() {
+    let dir = tempfile::tempdir().unwrap();
+    let main = dir.path().join("main.mjs");
+    std::fs::write(
+        &main,
+        r#"
+            import crypto, { getRandomValues } from "node:crypto";
+            const seen = [];
+            const bytes = new Uint8Array(4096);
+            seen.push(getRandomValues(bytes) === bytes);
+            seen.push(new Set(bytes).size > 200);
+            const words = new Uint32Array(64);
+            seen.push(crypto.webcrypto.getRandomValues(words) === words);
+            seen.push(words.some((word) => word > 0xffffff));
+            for (const bad of [new Float64Array(2), new Uint8Array(65537)]) {
+                try {
+                    getRandomValues(bad);
+                    seen.push("accepted");
+                } catch (err) {
+                    seen.push(err.name);
+                }
+            }
+            const counts = [0, 0, 0, 0];
+            for (let i = 0; i < 4000; i++) counts[crypto.randomInt(4)]++;
+            seen.push(counts.every((count) => count > 800 && count < 1200));
+            globalThis.randomValuesResult = seen.join(",");
+        "#,
+    )
+    .unwrap();
+
+    let mut runtime = Runtime::builder().with_node_apis().build().unwrap();
+    runtime.run_module(&main).unwrap();
+    let result = runtime
+        .run_script(
+            SourceInput::from_javascript("globalThis.randomValuesResult"),
+            "<check>",
+        )
+        .unwrap();
+    assert_eq!(
+        result.completion_string(),
+        "true,true,true,true,TypeMismatchError,QuotaExceededError,true"
+    );
+}
+
+#[test]
+fn net_tcp_sockets_report_server_and_endpoint_addresses() {
+    let dir = tempfile::tempdir().unwrap();
+    let main = dir.path().join("main.mjs");
+    std::fs::write(
+        &main,
+        r#"
+        import net from "node:net";
+        const out = [];
+        const server = net.createServer((socket) => {
+            socket.on("data", (chunk) => socket.end(String(chunk).toUpperCase()));
+        });
+        out.push(server.address() === null);
+        await new Promise((resolve) => server.listen(0, "127.0.0.1", resolve));
+        const info = server.address();
+        out.push(info.address, info.family, info.port > 0);
+        const inbound = new Promise((resolve) => server.once("connection", resolve));
+        const client = net.connect(info.port, "127.0.0.1");
+        out.push(client.remotePort === undefined);
+        let reply = "";
+        await new Promise((resolve, reject) => {
+            client.on("connect", () => client.write("ping"));
+            client.on("data", (chunk) => { reply += chunk; });
+            client.on("end", resolve);
+            client.on("error", reject);
+        });
+        const peer = await inbound;
+        out.push(reply);
+        out.push(client.remoteAddress, client.remotePort === info.port, client.remoteFamily);
+        out.push(peer.remotePort === client.localPort, client.address().port === client.localPort);
+        client.destroy();
+        try {
+            net.connect(70000);
+        } catch (err) {
+            out.push(err.code);
+        }
+        await new Promise((resolve) => server.close(resolve));
+        out.push(server.address() === null);
+        globalThis.netResult = out.join(",");
+    "#,
+    )
+    .unwrap();
+
+    let mut runtime = Runtime::builder()
+        .capabilities(CapabilitySet::allow_all())
+        .with_node_apis()
+        .build()
+        .unwrap();
+    runtime.run_module(&main).unwrap();
+    let result = runtime
+        .run_script(
+            SourceInput::from_javascript("globalThis.netResult"),
+            "<check>",
+        )
+        .unwrap();
+    assert_eq!(
+        result.completion_string(),
+        "true,127.0.0.1,IPv4,true,true,PING,127.0.0.1,true,IPv4,true,true,ERR_SOCKET_BAD_PORT,true"
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn net_unix_sockets_round_trip_and_relisten_over_stale_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let sock = dir.path().join("echo.sock");
+    // A socket file left behind by a listener that no longer exists.
+    drop(std::os::unix::net::UnixListener::bind(&sock).unwrap());
+    let main = dir.path().join("main.mjs");
+    std::fs::write(
+        &main,
+        format!(
+            r#"
+            import net from "node:net";
+            const path = {path:?};
+            const out = [];
+            const server = net.createServer((socket) => {{
+                socket.on("data", (chunk) => socket.write(String(chunk).toUpperCase()));
+            }});
+            await new Promise((resolve) => server.listen({{ path }}, resolve));
+            out.push(server.address() === path);
+            out.push(await new Promise((resolve, reject) => {{
+                let reply = "";
+                const client = net.connect({{ path }}, () => client.end("ping"));
+                client.on("data", (chunk) => {{ reply += chunk; }});
+                client.on("close", () => resolve(reply));
+                client.on("error", reject);
+            }}));
+            out.push(await new Promise((resolve) => {{
+                const busy = net.createServer();
+                busy.on("error", (err) => resolve(`${{err.code}}:${{err.syscall}}`));
+                busy.listen(path);
+            }}));
+            try {{
+                net.connect({{}});
+            }} catch (err) {{
+                out.push(err.code);
+            }}
+            await new Promise((resolve) => server.close(resolve));
+            out.push(server.listening);
+            globalThis.netResult = out.join(",");
+        "#,
+            path = sock.to_string_lossy()
+        ),
+    )
+    .unwrap();
+
+    let mut runtime = Runtime::builder()
+        .capabilities(CapabilitySet::allow_all())
+        .with_node_apis()
+        .build()
+        .unwrap();
+    runtime.run_module(&main).unwrap();
+    let result = runtime
+        .run_script(
+            SourceInput::from_javascript("globalThis.netResult"),
+            "<check>",
+        )
+        .unwrap();
+    assert_eq!(
+        result.completion_string(),
+        "true,PING,EADDRINUSE:listen,ERR_MISSING_ARGS,false"
+    );
+    assert!(!sock.exists());
+
+    let denied = dir.path().join("denied.mjs");
+    std::fs::write(
+        &denied,
+        format!(
+            r#"
+            import net from "node:net";
+            net.connect({{ path: {path:?} }}).on("error", (err) => {{
+                globalThis.deniedCode = err.code;
+            }});
+        "#,
+            path = sock.to_string_lossy()
+        ),
+    )
+    .unwrap();
+    let mut sandboxed = Runtime::builder().with_node_apis().build().unwrap();
+    sandboxed.run_module(&denied).unwrap();
+    let code = sandboxed
+        .run_script(
+            SourceInput::from_javascript("globalThis.deniedCode"),
+            "<check>",
+        )
+        .unwrap();
+    assert_eq!(code.completion_string(), "EACCES");
+}
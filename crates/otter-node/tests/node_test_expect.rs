@@ -0,0 +1,124 @@
+//! `expect()` matchers exported by `node:test`.
+//!
+//! # Contents
+//! - `toEqual` compares structurally and ignores `undefined` properties;
+//!   `toStrictEqual` does not.
+//! - `toThrow(/pattern/)` matches the thrown error's message.
+//! - `.resolves` / `.rejects` await the promise before matching.
+//! - Failures are `AssertionError`s with a jest-style hint line.
+
+use otter_node::NodeApiBuilderExt;
+use otter_runtime::{Runtime, SourceInput};
+
+/// Run `source` as `main.mjs` and return `globalThis.result`.
+fn run_module(source: &str) -> String {
+    let dir = tempfile::tempdir().unwrap();
+    let main = dir.path().join("main.mjs");
+    std::fs::write(&main, source).unwrap();
+    let mut runtime = Runtime::builder().with_node_apis().build().unwrap();
+    runtime.run_module(&main).unwrap();
+    runtime
+        .run_script(
+            SourceInput::from_javascript("String(globalThis.result)"),
+            "<check>",
+        )
+        .unwrap()
+        .completion_string()
+        .to_string()
+}
+
+#[test]
+fn to_equal_compares_deeply() {
+    let out = run_module(
+        r#"
+            import { expect } from "node:test";
+            const seen = [];
+            const check = (fn) => {
+                try {
+                    fn();
+                    seen.push("pass");
+                } catch (err) {
+                    seen.push(err.code + ":" + err.message.split("\n")[0]);
+                }
+            };
+            class Point { constructor(x) { this.x = x; } }
+            check(() => expect({ a: [1, { b: new Map([["k", 2]]) }], u: undefined })
+                .toEqual({ a: [1, { b: new Map([["k", 2]]) }] }));
+            check(() => expect(new Point(1)).toEqual({ x: 1 }));
+            check(() => expect(new Point(1)).toStrictEqual({ x: 1 }));
+            check(() => expect({ a: [1, 2] }).toEqual({ a: [1, 3] }));
+            check(() => expect({ n: 4, s: "x" }).toEqual({ n: expect.any(Number), s: expect.anything() }));
+            check(() => expect({ a: 1 }).not.toEqual({ a: 1 }));
+            check(() => expect({ id: 1, tags: ["a"], extra: true }).toMatchObject({ tags: ["a"] }));
+            globalThis.result = seen.join("|");
+        "#,
+    );
+    assert_eq!(
+        out,
+        "pass|pass|ERR_ASSERTION:expect(received).toStrictEqual(expected) // deep equality|\
+         ERR_ASSERTION:expect(received).toEqual(expected) // deep equality|pass|\
+         ERR_ASSERTION:expect(received).not.toEqual(expected)|pass"
+    );
+}
+
+#[test]
+fn to_throw_matches_message_pattern() {
+    let out = run_module(
+        r#"
+            import { expect } from "node:test";
+            const seen = [];
+            const boom = () => { throw new RangeError("limit 42 exceeded"); };
+            expect(boom).toThrow(/limit \d+/);
+            expect(boom).toThrow("exceeded");
+            expect(boom).toThrow(RangeError);
+            expect(() => {}).not.toThrow();
+            for (const fn of [() => expect(boom).toThrow(/^exceeded/), () => expect(() => 1).toThrow(/x/)]) {
+                try {
+                    fn();
+                    seen.push("accepted");
+                } catch (err) {
+                    seen.push(err.message.split("\n").slice(2).join(";"));
+                }
+            }
+            globalThis.result = seen.join("|");
+        "#,
+    );
+    assert_eq!(
+        out,
+        "Expected pattern: /^exceeded/;Received message: 'limit 42 exceeded'|\
+         Expected: a thrown error;Received function did not throw"
+    );
+}
+
+#[test]
+fn resolves_and_rejects_await_the_promise() {
+    let out = run_module(
+        r#"
+            import { expect } from "node:test";
+            const seen = [];
+            await expect(Promise.resolve(7)).resolves.toBe(7);
+            await expect(new Promise((resolve) => setTimeout(() => resolve({ ok: true }), 5)))
+                .resolves.toEqual({ ok: true });
+            await expect(Promise.reject(new Error("denied"))).rejects.toThrow(/denied/);
+            await expect(Promise.resolve(1)).resolves.not.toBe(2);
+            for (const pending of [
+                () => expect(Promise.resolve(1)).resolves.toBe(2),
+                () => expect(Promise.reject(new Error("no"))).resolves.toBe(1),
+                () => expect(Promise.resolve(1)).rejects.toThrow(),
+            ]) {
+                try {
+                    await pending();
+                    seen.push("accepted");
+                } catch (err) {
+                    seen.push(err.message.split("\n")[2]);
+                }
+            }
+            globalThis.result = seen.join("|");
+        "#,
+    );
+    assert_eq!(
+        out,
+        "Expected: 2|Received promise rejected instead of resolved|\
+         Received promise resolved instead of rejected"
+    );
+}
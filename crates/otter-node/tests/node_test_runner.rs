@@ -0,0 +1,144 @@
+//! `node:test` variants: `.each`, `.only`, `.skip`, `.todo`, and summary
+//! counts.
+//!
+//! # Contents
+//! - `test.each(table)` registers one test per row with interpolated names.
+//! - A `.only` test filters out its siblings without `.only`.
+//! - `.todo` tests are counted as todo and never fail the run.
+
+use std::sync::{Arc, Mutex};
+
+use otter_node::NodeApiBuilderExt;
+use otter_runtime::{ConsoleLevel, ConsoleSink, Runtime, SourceInput};
+
+#[derive(Debug, Default)]
+struct LogCapture {
+    lines: Mutex<Vec<String>>,
+}
+
+impl ConsoleSink for LogCapture {
+    fn write(&self, level: ConsoleLevel, fields: &[String]) {
+        if matches!(level, ConsoleLevel::Log) {
+            self.lines.lock().expect("log mutex").push(fields.join(" "));
+        }
+    }
+}
+
+/// Run `source` as `main.mjs` and return its console lines plus
+/// `process.exitCode`.
+fn run_tests(source: &str) -> (Vec<String>, String) {
+    let dir = tempfile::tempdir().unwrap();
+    let main = dir.path().join("main.mjs");
+    std::fs::write(&main, source).unwrap();
+    let log = Arc::new(LogCapture::default());
+    let mut runtime = Runtime::builder()
+        .console_sink(log.clone())
+        .with_node_apis()
+        .build()
+        .unwrap();
+    runtime.run_module(&main).unwrap();
+    let exit_code = runtime
+        .run_script(
+            SourceInput::from_javascript("String(process.exitCode)"),
+            "<check>",
+        )
+        .unwrap()
+        .completion_string()
+        .to_string();
+    let lines = log.lines.lock().expect("log mutex").clone();
+    (lines, exit_code)
+}
+
+fn summary(lines: &[String], key: &str) -> String {
+    let prefix = format!("# {key} ");
+    lines
+        .iter()
+        .find_map(|line| line.strip_prefix(&prefix))
+        .unwrap_or_else(|| panic!("no `{prefix}` line in {lines:?}"))
+        .to_string()
+}
+
+#[test]
+fn each_runs_one_case_per_row_with_distinct_names() {
+    let (lines, exit_code) = run_tests(
+        r#"
+            import { test } from "node:test";
+            globalThis.sums = [];
+            test.each([[1, 2, 3], [2, 3, 5], [4, 4, 8]])("add %i + %i = %i", (a, b, sum) => {
+                if (a + b !== sum) throw new Error("bad sum");
+                globalThis.sums.push(sum);
+            });
+            test.each([{ name: "ada" }, { name: "bob" }])("greets $name (#%#)", (row) => {
+                if (typeof row.name !== "string") throw new Error("bad row");
+            });
+        "#,
+    );
+    assert_eq!(
+        lines[..5],
+        [
+            "ok 1 - add 1 + 2 = 3",
+            "ok 2 - add 2 + 3 = 5",
+            "ok 3 - add 4 + 4 = 8",
+            "ok 4 - greets ada (#0)",
+            "ok 5 - greets bob (#1)",
+        ]
+    );
+    assert_eq!(summary(&lines, "tests"), "5");
+    assert_eq!(summary(&lines, "pass"), "5");
+    assert_eq!(exit_code, "undefined");
+}
+
+#[test]
+fn only_filters_siblings_out() {
+    let (lines, exit_code) = run_tests(
+        r#"
+            import { describe, it, test } from "node:test";
+            globalThis.ran = [];
+            test("sibling", () => { globalThis.ran.push("sibling"); throw new Error("must not run"); });
+            test.only("focused", () => { globalThis.ran.push("focused"); });
+            describe("suite", () => {
+                it("inner", () => { globalThis.ran.push("inner"); });
+                it.only("inner focused", () => { globalThis.ran.push("inner focused"); });
+            });
+            describe.only("focused suite", () => {
+                it("child", () => { globalThis.ran.push("child"); });
+            });
+            test.skip("skipped", () => { throw new Error("must not run"); });
+        "#,
+    );
+    assert!(
+        lines.contains(&"ok 1 - sibling # SKIP".to_string()),
+        "{lines:?}"
+    );
+    assert!(lines.contains(&"ok 2 - focused".to_string()), "{lines:?}");
+    assert_eq!(summary(&lines, "pass"), "2");
+    assert_eq!(summary(&lines, "skipped"), "3");
+    assert_eq!(summary(&lines, "fail"), "0");
+    assert_eq!(exit_code, "undefined");
+}
+
+#[test]
+fn todo_is_counted_separately_from_failures() {
+    let (lines, exit_code) = run_tests(
+        r#"
+            import { test } from "node:test";
+            test.todo("write this later");
+            test.todo("broken for now", () => { throw new Error("not yet"); });
+            test("real", () => {});
+            test("fails", () => { throw new Error("boom"); });
+        "#,
+    );
+    assert_eq!(
+        lines[..4],
+        [
+            "ok 1 - write this later # TODO",
+            "ok 2 - broken for now # TODO",
+            "ok 3 - real",
+            "not ok 4 - fails",
+        ]
+    );
+    assert_eq!(summary(&lines, "tests"), "4");
+    assert_eq!(summary(&lines, "todo"), "2");
+    assert_eq!(summary(&lines, "fail"), "1");
+    assert_eq!(exit_code, "1");
+}
@@ -8,6 +8,8 @@
 //! # Contents
 //! - [`PackageManifest`] — typed `package.json` surface.
 //! - [`DependencySet`] — dependency buckets from npm manifests.
+//! - [`DependencyOverride`], [`OverrideSet`] — npm `overrides` and yarn
+//!   `resolutions`, normalized by [`PackageManifest::dependency_overrides`].
 //! - [`PackageBinManifest`] — `package.json#bin` representation.
 //! - [`WorkspacePackage`] — discovered workspace package.
 //! - [`discover_workspaces`] — combined npm + pnpm workspace discovery.
@@ -93,6 +95,21 @@ pub struct ManifestDiagnostic {
 /// Dependency bucket from a package manifest.
 pub type DependencySet = BTreeMap<String, String>;
 
+/// One `package.json#overrides` entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DependencyOverride {
+    /// `"name": "1.0.0"` — replace the range wherever `name` is depended on.
+    /// A `$dep` value refers to the root manifest's own spec for `dep`.
+    Spec(String),
+    /// `"name": { ".": "2.0.0", "child": "1.0.0" }` — `.` overrides `name`
+    /// itself; every other key applies only beneath `name`.
+    Nested(OverrideSet),
+}
+
+/// Override rules keyed by dependency name.
+pub type OverrideSet = BTreeMap<String, DependencyOverride>;
+
 /// JavaScript package module mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -160,6 +177,12 @@ pub struct PackageManifest {
     /// Package imports map.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub imports: Option<serde_json::Value>,
+    /// Bundled TypeScript declaration entry.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub types: Option<String>,
+    /// Legacy spelling of [`Self::types`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub typings: Option<String>,
     /// Runtime dependency ranges.
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub dependencies: DependencySet,
@@ -182,6 +205,12 @@ pub struct PackageManifest {
     /// Workspace patterns in npm format.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub workspaces: Option<PackageJsonWorkspaces>,
+    /// npm dependency overrides.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub overrides: OverrideSet,
+    /// yarn dependency resolutions, keyed by a `/`-separated package path.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub resolutions: BTreeMap<String, String>,
 }
 
 impl PackageManifest {
@@ -263,6 +292,49 @@ impl PackageManifest {
         diagnostics
     }
 
+    /// Return `overrides` merged with yarn `resolutions`.
+    ///
+    /// A resolution path `a/b` becomes the nested override `a: { b }`; leading
+    /// `**` segments are dropped, so `**/b` and `b` both apply tree-wide. On a
+    /// conflict the npm `overrides` entry wins.
+    #[must_use]
+    pub fn dependency_overrides(&self) -> OverrideSet {
+        let mut merged = OverrideSet::new();
+        for (path, spec) in &self.resolutions {
+            let mut segments = resolution_path_segments(path);
+            let Some(last) = segments.pop() else {
+                continue;
+            };
+            let mut scope = &mut merged;
+            for segment in segments {
+                let entry = scope
+                    .entry(segment)
+                    .or_insert_with(|| DependencyOverride::Nested(OverrideSet::new()));
+                if let DependencyOverride::Spec(spec) = entry {
+                    let own = DependencyOverride::Spec(std::mem::take(spec));
+                    *entry =
+                        DependencyOverride::Nested(OverrideSet::from([(".".to_string(), own)]));
+                }
+                let DependencyOverride::Nested(nested) = entry else {
+                    unreachable!("spec entries were converted to nested scopes above");
+                };
+                scope = nested;
+            }
+            match scope.get_mut(&last) {
+                Some(DependencyOverride::Nested(nested)) => {
+                    nested.insert(".".to_string(), DependencyOverride::Spec(spec.clone()));
+                }
+                _ => {
+                    scope.insert(last, DependencyOverride::Spec(spec.clone()));
+                }
+            }
+        }
+        for (name, rule) in &self.overrides {
+            merged.insert(name.clone(), rule.clone());
+        }
+        merged
+    }
+
     /// Borrow all dependency buckets in deterministic bucket order.
     #[must_use]
     pub fn dependency_buckets(&self) -> [(&'static str, &DependencySet); 4] {
@@ -275,6 +347,25 @@ impl PackageManifest {
     }
 }
 
+/// Split a yarn resolution path into package names, keeping `@scope/name`
+/// together and dropping `**` globs.
+fn resolution_path_segments(path: &str) -> Vec<String> {
+    let mut segments = Vec::new();
+    let mut open_scope = None;
+    for part in path
+        .split('/')
+        .filter(|part| !part.is_empty() && *part != "**")
+    {
+        match open_scope.take() {
+            Some(scope) => segments.push(format!("{scope}/{part}")),
+            None if part.starts_with('@') => open_scope = Some(part),
+            None => segments.push(part.to_string()),
+        }
+    }
+    segments.extend(open_scope.map(str::to_string));
+    segments
+}
+
 /// `pnpm-workspace.yaml` representation.
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PnpmWorkspace {
@@ -637,4 +728,44 @@ mod tests {
         assert_eq!(diagnostics.len(), 1);
         assert_eq!(diagnostics[0].code, "PM_MANIFEST_EMPTY_DEPENDENCY_RANGE");
     }
+
+    #[test]
+    fn overrides_parse_nested_and_merge_yarn_resolutions() {
+        let manifest = PackageManifest::parse_json(
+            r#"{
+              "overrides": { "a": { ".": "2.0.0", "b": "1.0.0" }, "c": "$c" },
+              "resolutions": {
+                "**/d": "4.0.0",
+                "@s/e/f": "5.0.0",
+                "a/b": "9.9.9"
+              }
+            }"#,
+        )
+        .unwrap();
+        let spec = |value: &str| DependencyOverride::Spec(value.to_string());
+        let overrides = manifest.dependency_overrides();
+        assert_eq!(
+            overrides,
+            OverrideSet::from([
+                (
+                    "a".to_string(),
+                    DependencyOverride::Nested(OverrideSet::from([
+                        (".".to_string(), spec("2.0.0")),
+                        ("b".to_string(), spec("1.0.0")),
+                    ])),
+                ),
+                (
+                    "@s/e".to_string(),
+                    DependencyOverride::Nested(OverrideSet::from([(
+                        "f".to_string(),
+                        spec("5.0.0")
+                    )])),
+                ),
+                ("c".to_string(), spec("$c")),
+                ("d".to_string(), spec("4.0.0")),
+            ])
+        );
+        let roundtrip = PackageManifest::parse_json(&manifest.to_stable_json().unwrap()).unwrap();
+        assert_eq!(roundtrip, manifest);
+    }
 }
@@ -7,12 +7,19 @@
 //!
 //! # Contents
 //! - [`FsPackageStore`] is the extracted package cache plus materializer.
+//! - [`InstallProgress`] receives per-package [`InstallPhase`] events.
 //! - [`ExtractedPackage`] describes a package cache entry.
 //! - [`InstalledPackage`] describes one project-local materialized package.
 //!
 //! # Invariants
 //! - Lifecycle scripts are not executed.
 //! - Install roots are refreshed through temporary directories before rename.
+//! - Download and extraction run concurrently up to the store's in-flight
+//!   limit; each distinct tarball is extracted once per pass. Linking into
+//!   `node_modules` stays sequential in package-id order.
+//! - Temporary names are unique per process and call, so concurrent writers
+//!   never share a staging path; losing an extraction rename to an
+//!   identical entry counts as reuse.
 //! - Archive paths are normalized and may not escape the package root.
 //! - Bin links point at the project-local `node_modules/.bin` layout.
 //!
@@ -20,24 +27,62 @@
 //! - [`crate::install_local_project`] for the full resolve/fetch/write flow.
 //! - [`crate::tarball`] for byte fetch and integrity verification.
 
+use std::collections::BTreeMap;
 use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
 
 use flate2::read::GzDecoder;
 use otter_pm_lockfile::{LockedPackage, Lockfile, ResolvedSource, ResolvedSourceKind};
 use otter_pm_manifest::{PACKAGE_JSON, PackageBinManifest, PackageManifest};
+use tokio::sync::Semaphore;
 
-use crate::tarball::tarball_cache_key;
+use crate::tarball::{tarball_cache_key, unique_tmp_suffix};
 use crate::{
     CachedTarball, FsTarballCache, PackageBin, PackageId, PackageManagerError, TarballFetchClient,
     TarballSource, binary_name_from_package_name, cache_key, install_fingerprint,
     package_name_path,
 };
 
+const DEFAULT_MAX_IN_FLIGHT: usize = 8;
+
+/// Per-package install phase reported through [`InstallProgress`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum InstallPhase {
+    /// Fetching or reusing the package tarball.
+    Downloading,
+    /// Unpacking the tarball into the extracted package cache.
+    Extracting,
+    /// Copying into `node_modules` and linking binaries.
+    Linking,
+}
+
+/// Receiver for install progress events.
+///
+/// Events for different packages interleave while downloads and extraction
+/// run concurrently; each package still reports its phases in order.
+pub trait InstallProgress: Send + Sync {
+    /// Called when `package_id` enters `phase`. Cached work reports too.
+    fn report(&self, package_id: &str, phase: InstallPhase);
+}
+
 /// Extracted package cache and project install materializer.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct FsPackageStore {
     tarballs: FsTarballCache,
     packages_root: PathBuf,
+    in_flight: Arc<Semaphore>,
+    progress: Option<Arc<dyn InstallProgress>>,
+}
+
+impl std::fmt::Debug for FsPackageStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FsPackageStore")
+            .field("tarballs", &self.tarballs)
+            .field("packages_root", &self.packages_root)
+            .field("in_flight", &self.in_flight)
+            .field("progress", &self.progress.is_some())
+            .finish()
+    }
 }
 
 impl FsPackageStore {
@@ -48,6 +93,28 @@ impl FsPackageStore {
         Self {
             tarballs: FsTarballCache::new(cache_root.join("tarballs")),
             packages_root: cache_root.join("packages"),
+            in_flight: Arc::new(Semaphore::new(DEFAULT_MAX_IN_FLIGHT)),
+            progress: None,
+        }
+    }
+
+    /// Limit concurrent download+extract jobs. `0` is treated as `1`.
+    #[must_use]
+    pub fn with_max_in_flight(mut self, max_in_flight: usize) -> Self {
+        self.in_flight = Arc::new(Semaphore::new(max_in_flight.max(1)));
+        self
+    }
+
+    /// Report per-package phases to `progress`.
+    #[must_use]
+    pub fn with_progress(mut self, progress: Arc<dyn InstallProgress>) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    fn report(&self, package_id: &str, phase: InstallPhase) {
+        if let Some(progress) = &self.progress {
+            progress.report(package_id, phase);
         }
     }
 
@@ -63,7 +130,22 @@ impl FsPackageStore {
         source: &TarballSource,
         client: &impl TarballFetchClient,
     ) -> Result<ExtractedPackage, PackageManagerError> {
+        self.fetch_and_extract_reporting(None, source, client).await
+    }
+
+    async fn fetch_and_extract_reporting(
+        &self,
+        package_id: Option<&str>,
+        source: &TarballSource,
+        client: &impl TarballFetchClient,
+    ) -> Result<ExtractedPackage, PackageManagerError> {
+        if let Some(id) = package_id {
+            self.report(id, InstallPhase::Downloading);
+        }
         let cached_tarball = self.tarballs.get_or_fetch(source, client).await?;
+        if let Some(id) = package_id {
+            self.report(id, InstallPhase::Extracting);
+        }
         tokio::fs::create_dir_all(&self.packages_root)
             .await
             .map_err(|err| PackageManagerError::Io {
@@ -88,12 +170,12 @@ impl FsPackageStore {
         let tmp = self.packages_root.join(format!(
             ".tmp-{}-{}",
             tarball_cache_key(source),
-            std::process::id()
+            unique_tmp_suffix()
         ));
         let archive_path = cached_tarball.path.clone();
         let root_for_task = root.clone();
         let tmp_for_task = tmp.clone();
-        tokio::task::spawn_blocking(move || {
+        let reused = tokio::task::spawn_blocking(move || {
             extract_tgz_package(&archive_path, &tmp_for_task, &root_for_task)
         })
         .await
@@ -105,10 +187,55 @@ impl FsPackageStore {
         Ok(ExtractedPackage {
             root,
             tarball: cached_tarball,
-            reused: false,
+            reused,
         })
     }
 
+    /// Download and extract every package concurrently, deduplicating shared
+    /// tarballs. Results follow the order of `packages`.
+    async fn fetch_and_extract_all(
+        &self,
+        packages: &[(String, LockedPackage, TarballSource)],
+        client: &impl TarballFetchClient,
+    ) -> Result<Vec<ExtractedPackage>, PackageManagerError> {
+        let mut leaders = BTreeMap::new();
+        for (index, (_, _, source)) in packages.iter().enumerate() {
+            leaders.entry(tarball_cache_key(source)).or_insert(index);
+        }
+        let extracted = crate::try_join_bounded(
+            Some(self.in_flight.as_ref()),
+            leaders.into_iter().map(|(key, index)| {
+                let (id, _, source) = &packages[index];
+                async move {
+                    let extracted = self
+                        .fetch_and_extract_reporting(Some(id), source, client)
+                        .await?;
+                    Ok::<_, PackageManagerError>((key, (index, extracted)))
+                }
+            }),
+        )
+        .await?;
+        let done = extracted.into_iter().collect::<BTreeMap<_, _>>();
+        packages
+            .iter()
+            .enumerate()
+            .map(|(index, (id, _, source))| {
+                let (leader, extracted) =
+                    done.get(&tarball_cache_key(source)).ok_or_else(|| {
+                        PackageManagerError::Backend {
+                            backend: "install",
+                            message: format!("no extraction result for {id}"),
+                        }
+                    })?;
+                if *leader != index {
+                    self.report(id, InstallPhase::Downloading);
+                    self.report(id, InstallPhase::Extracting);
+                }
+                Ok(extracted.clone())
+            })
+            .collect()
+    }
+
     /// Materialize all registry tarballs from `lockfile` into `node_modules`.
     pub async fn materialize_registry_packages(
         &self,
@@ -119,9 +246,10 @@ impl FsPackageStore {
         let project_root = project_root.as_ref();
         let mut packages = tarball_packages_for_project(project_root, lockfile);
         packages.sort_by(|a, b| a.0.cmp(&b.0));
+        let extracted = self.fetch_and_extract_all(&packages, client).await?;
         let mut installed = Vec::with_capacity(packages.len());
-        for (id, package, source) in packages {
-            let extracted = self.get_or_fetch_and_extract(&source, client).await?;
+        for ((id, package, source), extracted) in packages.into_iter().zip(extracted) {
+            self.report(&id, InstallPhase::Linking);
             let install_root = project_root
                 .join("node_modules")
                 .join(package_name_path(&package.name));
@@ -260,7 +388,7 @@ async fn materialize_install_root(
     let tmp_root = install_root
         .parent()
         .unwrap_or_else(|| Path::new("."))
-        .join(format!(".otter-install-tmp-{}", std::process::id()));
+        .join(format!(".otter-install-tmp-{}", unique_tmp_suffix()));
     let source_root = source_root.to_path_buf();
     let install_root = install_root.to_path_buf();
     let install_root_for_error = install_root.clone();
@@ -356,23 +484,24 @@ fn copy_dir_recursive(source: &Path, destination: &Path) -> Result<(), PackageMa
     Ok(())
 }
 
+/// Extract into `tmp_root` and rename to `final_root`. Returns `true` when
+/// `final_root` was already complete, either before extraction started (the
+/// archive is not unpacked at all) or because a concurrent writer finished
+/// first and this copy was dropped.
 fn extract_tgz_package(
     archive_path: &Path,
     tmp_root: &Path,
     final_root: &Path,
-) -> Result<(), PackageManagerError> {
+) -> Result<bool, PackageManagerError> {
+    if final_root.join(PACKAGE_JSON).is_file() {
+        return Ok(true);
+    }
     if tmp_root.exists() {
         std::fs::remove_dir_all(tmp_root).map_err(|err| PackageManagerError::Io {
             path: tmp_root.to_path_buf(),
             message: err.to_string(),
         })?;
     }
-    if final_root.exists() {
-        std::fs::remove_dir_all(final_root).map_err(|err| PackageManagerError::Io {
-            path: final_root.to_path_buf(),
-            message: err.to_string(),
-        })?;
-    }
     std::fs::create_dir_all(tmp_root).map_err(|err| PackageManagerError::Io {
         path: tmp_root.to_path_buf(),
         message: err.to_string(),
@@ -430,10 +559,27 @@ fn extract_tgz_package(
             message: err.to_string(),
         })?;
     }
-    std::fs::rename(tmp_root, final_root).map_err(|err| PackageManagerError::Io {
-        path: final_root.to_path_buf(),
-        message: err.to_string(),
-    })
+    if final_root.join(PACKAGE_JSON).is_file() {
+        let _ = std::fs::remove_dir_all(tmp_root);
+        return Ok(true);
+    }
+    if final_root.exists() {
+        std::fs::remove_dir_all(final_root).map_err(|err| PackageManagerError::Io {
+            path: final_root.to_path_buf(),
+            message: err.to_string(),
+        })?;
+    }
+    match std::fs::rename(tmp_root, final_root) {
+        Ok(()) => Ok(false),
+        Err(_) if final_root.join(PACKAGE_JSON).is_file() => {
+            let _ = std::fs::remove_dir_all(tmp_root);
+            Ok(true)
+        }
+        Err(err) => Err(PackageManagerError::Io {
+            path: final_root.to_path_buf(),
+            message: err.to_string(),
+        }),
+    }
 }
 
 fn archive_relative_path(path: &Path) -> Result<PathBuf, PackageManagerError> {
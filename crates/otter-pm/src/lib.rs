@@ -12,10 +12,16 @@
 //! - [`PackageGraph`] — read-only package graph model.
 //! - [`PackageResolver`], [`PackageCache`], [`PackageInstaller`] — backend
 //!   traits for later install slices.
+//! - [`resolve_add_spec`] — the `package.json` spec recorded by `otter add`.
+//! - [`resolve_missing_type_packages`] — `@types/*` companions for
+//!   dependencies without bundled typings.
 //!
 //! # Invariants
 //! - Graph maps are [`std::collections::BTreeMap`] values for deterministic
 //!   traversal and diagnostics.
+//! - Root `overrides` (merged with yarn `resolutions`) rewrite transitive
+//!   registry ranges before their package ids are derived, so the lockfile
+//!   records the forced version. Direct dependencies keep their declared spec.
 //! - This crate has no dependency on `crates-legacy/*`, `otter-runtime`, or
 //!   `otter-vm`.
 //! - Capability gates are not part of first-party install command plumbing;
@@ -30,6 +36,7 @@ mod install;
 mod installed_graph;
 mod registry;
 mod tarball;
+mod types;
 
 use std::collections::{BTreeMap, BTreeSet};
 use std::ffi::OsString;
@@ -42,20 +49,25 @@ use otter_pm_lockfile::{
     install_lifecycle_scripts,
 };
 use otter_pm_manifest::{
-    DependencySet, PACKAGE_JSON, PackageBinManifest, PackageManifest, discover_workspaces,
+    DependencyOverride, DependencySet, OverrideSet, PACKAGE_JSON, PackageBinManifest,
+    PackageManifest, discover_workspaces,
 };
 use serde::{Deserialize, Serialize};
 
-pub use install::{ExtractedPackage, FsPackageStore, InstalledPackage};
+pub use install::{
+    ExtractedPackage, FsPackageStore, InstallPhase, InstallProgress, InstalledPackage,
+};
 pub use installed_graph::{prune_removed_registry_packages, resolve_installed_project};
 pub use registry::{
-    FileRegistryMetadataClient, FsRegistryMetadataCache, HttpRegistryMetadataClient, NpmDist,
-    NpmPackageVersion, NpmRegistryMetadata, RegistryMetadataClient,
+    ConditionalMetadata, FileRegistryMetadataClient, FsRegistryMetadataCache,
+    HttpRegistryMetadataClient, NpmDist, NpmPackageVersion, NpmRegistryMetadata,
+    RegistryMetadataClient, RegistryRetryPolicy, RegistryValidators,
 };
 pub use tarball::{
     CachedTarball, FileTarballClient, FsTarballCache, HttpTarballClient, TarballFetchClient,
     TarballSource,
 };
+pub use types::{definitely_typed_name, resolve_missing_type_packages, ships_bundled_types};
 
 /// Package-manager error type.
 #[derive(Debug, thiserror::Error)]
@@ -936,11 +948,45 @@ pub async fn enrich_resolution_with_registry_metadata(
     client: &impl RegistryMetadataClient,
 ) -> Result<(), PackageManagerError> {
     let project_root = infer_project_root(&resolution.graph);
+    let root_manifest = root_workspace_manifest(&resolution.graph);
+    let root_overrides = root_manifest
+        .map(PackageManifest::dependency_overrides)
+        .unwrap_or_default();
+    let root_specs = root_manifest
+        .map(|manifest| {
+            manifest
+                .dependency_buckets()
+                .into_iter()
+                .flat_map(|(_, bucket)| bucket.clone())
+                .collect::<DependencySet>()
+        })
+        .unwrap_or_default();
+    // Override rules visible to each package's own dependencies. Direct
+    // dependencies start from the root rules; a package reached through
+    // several paths keeps the scope of the first path that enqueued it.
+    let mut scopes = BTreeMap::<String, OverrideSet>::new();
     let mut processed = BTreeMap::new();
+    let mut prefetched = BTreeSet::new();
     while let Some((id, name, range)) =
         next_unresolved_registry_package(&resolution.lockfile, &processed)
     {
+        if !prefetched.contains(&name) {
+            // Fetch the whole frontier at once rather than one package per
+            // iteration; the client bounds the requests in flight.
+            let frontier = unresolved_registry_names(&resolution.lockfile, &processed)
+                .into_iter()
+                .filter(|name| !prefetched.contains(name))
+                .collect::<BTreeSet<_>>();
+            cache
+                .prefetch(frontier.iter().map(String::as_str), client)
+                .await?;
+            prefetched.extend(frontier);
+        }
         processed.insert(id.clone(), ());
+        let scope = scopes
+            .remove(&id)
+            .or_else(|| nested_override_scope(&root_overrides, &name))
+            .unwrap_or_else(|| root_overrides.clone());
         let metadata = cache.get_or_fetch(&name, client).await?;
         let version = select_registry_version(&metadata, &range)?;
         let graph_id = PackageId::new(id.clone());
@@ -962,7 +1008,16 @@ pub async fn enrich_resolution_with_registry_metadata(
         });
         package.lifecycle = LifecycleMetadata::from_scripts(&version.scripts, TrustState::Trusted);
         for (dep_name, dep_range, dependency_kind) in registry_dependency_edges(&version) {
+            let (dep_range, dep_scope) =
+                apply_dependency_override(&scope, &root_specs, &dep_name, dep_range)?;
             let dep_id = PackageId::registry(&dep_name, &dep_range);
+            if let Some(dep_scope) = dep_scope {
+                scopes.entry(dep_id.to_string()).or_insert(dep_scope);
+            } else if scope != root_overrides {
+                scopes
+                    .entry(dep_id.to_string())
+                    .or_insert_with(|| scope.clone());
+            }
             package
                 .dependencies
                 .entry(dep_name.clone())
@@ -1002,6 +1057,61 @@ pub async fn enrich_resolution_with_registry_metadata(
     Ok(())
 }
 
+/// Apply the override for `name` visible in `scope` to a declared range.
+///
+/// Returns the effective range and, when the rule is a nested override, the
+/// scope for `name`'s own dependencies (the enclosing rules plus the nested
+/// ones). A `$dep` spec takes the root manifest's spec for `dep`.
+fn apply_dependency_override(
+    scope: &OverrideSet,
+    root_specs: &DependencySet,
+    name: &str,
+    declared: String,
+) -> Result<(String, Option<OverrideSet>), PackageManagerError> {
+    let (spec, nested) = match scope.get(name) {
+        None => return Ok((declared, None)),
+        Some(DependencyOverride::Spec(spec)) => (Some(spec), None),
+        Some(DependencyOverride::Nested(nested)) => {
+            let spec = match nested.get(".") {
+                Some(DependencyOverride::Spec(spec)) => Some(spec),
+                _ => None,
+            };
+            (spec, nested_override_scope(scope, name))
+        }
+    };
+    let range = match spec {
+        None => declared,
+        Some(spec) => match spec.strip_prefix('$') {
+            Some(reference) => root_specs.get(reference).cloned().ok_or_else(|| {
+                PackageManagerError::Backend {
+                    backend: "overrides",
+                    message: format!(
+                        "override `{name}: {spec}` references `{reference}`, which is not a root dependency"
+                    ),
+                }
+            })?,
+            None => spec.clone(),
+        },
+    };
+    Ok((range, nested))
+}
+
+/// The scope for `name`'s own dependencies when `scope` holds a nested rule
+/// for `name`: the enclosing rules plus the nested ones.
+fn nested_override_scope(scope: &OverrideSet, name: &str) -> Option<OverrideSet> {
+    let Some(DependencyOverride::Nested(nested)) = scope.get(name) else {
+        return None;
+    };
+    let mut inner = scope.clone();
+    inner.extend(
+        nested
+            .iter()
+            .filter(|(key, _)| key.as_str() != ".")
+            .map(|(key, rule)| (key.clone(), rule.clone())),
+    );
+    Some(inner)
+}
+
 fn registry_dependency_edges(
     version: &NpmPackageVersion,
 ) -> Vec<(String, String, PackageDependencyKind)> {
@@ -1083,6 +1193,91 @@ pub trait PackageResolver {
     >;
 }
 
+/// Resolve one `otter add` request into the spec recorded in `package.json`.
+///
+/// `spec` is the text after `name@`, or `None` for a bare name. Registry
+/// ranges and exact versions must match a published version and are recorded
+/// as given. Dist-tags, including the implicit `latest` of a bare name, are
+/// recorded as a caret range on the tagged version, as npm does. `file:`,
+/// tarball URL, and `workspace:` specifiers are recorded unchanged once the
+/// local path exists. Git specifiers are rejected: the installer has no git
+/// source.
+pub async fn resolve_add_spec(
+    project_root: impl AsRef<Path>,
+    name: &str,
+    spec: Option<&str>,
+    metadata_cache: &FsRegistryMetadataCache,
+    metadata_client: &impl RegistryMetadataClient,
+) -> Result<String, PackageManagerError> {
+    let spec = spec.map(str::trim).filter(|spec| !spec.is_empty());
+    let Some(spec) = spec else {
+        let metadata = metadata_cache.get_or_fetch(name, metadata_client).await?;
+        return tagged_caret_range(&metadata, "latest");
+    };
+    if is_git_specifier(spec) {
+        return Err(PackageManagerError::Backend {
+            backend: "add",
+            message: format!("git dependency `{spec}` is not supported by the installer"),
+        });
+    }
+    if spec.starts_with("workspace:") || spec.starts_with("http://") || spec.starts_with("https://")
+    {
+        return Ok(spec.to_string());
+    }
+    if let Some(file_path) = spec.strip_prefix("file:") {
+        let path = project_root.as_ref().join(file_path);
+        if !tokio::fs::try_exists(&path)
+            .await
+            .map_err(|err| PackageManagerError::Io {
+                path: path.clone(),
+                message: err.to_string(),
+            })?
+        {
+            return Err(PackageManagerError::Io {
+                path,
+                message: "file dependency does not exist".to_string(),
+            });
+        }
+        return Ok(spec.to_string());
+    }
+    let metadata = metadata_cache.get_or_fetch(name, metadata_client).await?;
+    if metadata.dist_tags.contains_key(spec) {
+        return tagged_caret_range(&metadata, spec);
+    }
+    select_registry_version(&metadata, spec)?;
+    Ok(spec.to_string())
+}
+
+fn tagged_caret_range(
+    metadata: &NpmRegistryMetadata,
+    tag: &str,
+) -> Result<String, PackageManagerError> {
+    metadata
+        .dist_tags
+        .get(tag)
+        .filter(|version| metadata.versions.contains_key(*version))
+        .map(|version| format!("^{version}"))
+        .ok_or_else(|| PackageManagerError::NoMatchingVersion {
+            package: metadata.name.clone(),
+            range: tag.to_string(),
+        })
+}
+
+/// npm git specifiers: hosted shorthands, `git+<url>`, `git://`, and bare
+/// `owner/repo`.
+fn is_git_specifier(spec: &str) -> bool {
+    const PREFIXES: [&str; 5] = ["git+", "git://", "github:", "gitlab:", "bitbucket:"];
+    if PREFIXES.iter().any(|prefix| spec.starts_with(prefix)) {
+        return true;
+    }
+    let repo = spec.split_once('#').map_or(spec, |(repo, _)| repo);
+    !repo.starts_with(['@', '.'])
+        && !repo.contains(':')
+        && repo.split_once('/').is_some_and(|(owner, name)| {
+            !owner.is_empty() && !name.is_empty() && !name.contains('/')
+        })
+}
+
 fn select_registry_version(
     metadata: &NpmRegistryMetadata,
     range: &str,
@@ -1090,9 +1285,9 @@ fn select_registry_version(
     if let Some(version) = metadata.versions.get(range) {
         return Ok(version.clone());
     }
-    if matches!(range, "*" | "latest")
-        && let Some(latest) = metadata.dist_tags.get("latest")
-        && let Some(version) = metadata.versions.get(latest)
+    let tag = if range == "*" { "latest" } else { range };
+    if let Some(tagged) = metadata.dist_tags.get(tag)
+        && let Some(version) = metadata.versions.get(tagged)
     {
         return Ok(version.clone());
     }
@@ -1135,6 +1330,21 @@ fn normalize_npm_range(range: &str) -> Option<String> {
     }
 }
 
+fn unresolved_registry_names(lockfile: &Lockfile, processed: &BTreeMap<String, ()>) -> Vec<String> {
+    lockfile
+        .packages
+        .iter()
+        .filter(|(id, _)| !processed.contains_key(*id))
+        .filter_map(|(_, package)| match &package.resolved {
+            Some(ResolvedSource {
+                kind: ResolvedSourceKind::Registry,
+                reference,
+            }) if !is_tarball_reference(reference) => Some(package.name.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
 fn next_unresolved_registry_package(
     lockfile: &Lockfile,
     processed: &BTreeMap<String, ()>,
@@ -1155,6 +1365,14 @@ fn next_unresolved_registry_package(
     })
 }
 
+fn root_workspace_manifest(graph: &PackageGraph) -> Option<&PackageManifest> {
+    graph.packages.iter().find_map(|(id, package)| {
+        id.as_str()
+            .ends_with("@workspace:.")
+            .then_some(&package.manifest)
+    })
+}
+
 fn infer_project_root(graph: &PackageGraph) -> PathBuf {
     graph
         .packages
@@ -1207,6 +1425,60 @@ pub(crate) fn cache_key(package: &str) -> String {
     out
 }
 
+/// Drive `futures` concurrently and collect their outputs in input order,
+/// returning the first error. With `in_flight`, each future first takes a
+/// permit, so at most that many run at once; without it the futures bound
+/// themselves (for example through their client).
+pub(crate) async fn try_join_bounded<T, F>(
+    in_flight: Option<&tokio::sync::Semaphore>,
+    futures: impl IntoIterator<Item = F>,
+) -> Result<Vec<T>, PackageManagerError>
+where
+    F: Future<Output = Result<T, PackageManagerError>>,
+{
+    let mut pending = futures
+        .into_iter()
+        .enumerate()
+        .map(|(slot, future)| {
+            let run = async move {
+                let _permit = match in_flight {
+                    Some(in_flight) => Some(in_flight.acquire().await.map_err(|err| {
+                        PackageManagerError::Backend {
+                            backend: "in-flight limit",
+                            message: err.to_string(),
+                        }
+                    })?),
+                    None => None,
+                };
+                future.await
+            };
+            (slot, Box::pin(run))
+        })
+        .collect::<Vec<_>>();
+    let mut outputs = pending.iter().map(|_| None).collect::<Vec<Option<T>>>();
+    std::future::poll_fn(|cx| {
+        let mut index = 0;
+        while index < pending.len() {
+            let slot = pending[index].0;
+            match pending[index].1.as_mut().poll(cx) {
+                std::task::Poll::Ready(Ok(output)) => {
+                    outputs[slot] = Some(output);
+                    drop(pending.swap_remove(index));
+                }
+                std::task::Poll::Ready(Err(err)) => return std::task::Poll::Ready(Err(err)),
+                std::task::Poll::Pending => index += 1,
+            }
+        }
+        if pending.is_empty() {
+            std::task::Poll::Ready(Ok(()))
+        } else {
+            std::task::Poll::Pending
+        }
+    })
+    .await?;
+    Ok(outputs.into_iter().flatten().collect())
+}
+
 async fn resolve_manifest_dependencies(
     project_root: &Path,
     graph: &mut PackageGraph,
@@ -1747,6 +2019,65 @@ mod tests {
         assert_eq!(resolution.graph.resolve_bin("left-pad").len(), 1);
     }
 
+    #[tokio::test]
+    async fn overrides_pin_transitive_versions_outside_declared_range() {
+        let tmp = tempfile::tempdir().unwrap();
+        let fixture = tmp.path().join("registry");
+        let cache = FsRegistryMetadataCache::new(tmp.path().join("cache"));
+        write(
+            &tmp.path().join("project/package.json"),
+            r#"{
+              "name": "app",
+              "dependencies": { "a": "^1.0.0", "c": "^1.0.0" },
+              "overrides": { "a": { "b": "1.0.0" }, "d": "1.0.0" }
+            }"#,
+        )
+        .await;
+        for (name, versions) in [
+            (
+                "a",
+                r#""1.0.0": { "name": "a", "version": "1.0.0", "dependencies": { "b": "^2.0.0" } }"#,
+            ),
+            (
+                "c",
+                r#""1.0.0": { "name": "c", "version": "1.0.0", "dependencies": { "b": "^2.0.0" } }"#,
+            ),
+            (
+                "b",
+                r#""1.0.0": { "name": "b", "version": "1.0.0", "dependencies": { "d": "^3.0.0" } },
+                   "2.1.0": { "name": "b", "version": "2.1.0" }"#,
+            ),
+            (
+                "d",
+                r#""1.0.0": { "name": "d", "version": "1.0.0" },
+                   "3.0.0": { "name": "d", "version": "3.0.0" }"#,
+            ),
+        ] {
+            write(
+                &fixture.join(format!("{name}.json")),
+                &format!(r#"{{ "name": "{name}", "versions": {{ {versions} }} }}"#),
+            )
+            .await;
+        }
+
+        let client = FileRegistryMetadataClient::new(&fixture);
+        let mut resolution = resolve_local_project(tmp.path().join("project"))
+            .await
+            .unwrap();
+        enrich_resolution_with_registry_metadata(&mut resolution, &cache, &client)
+            .await
+            .unwrap();
+
+        let packages = &resolution.lockfile.packages;
+        assert_eq!(packages["a@npm:^1.0.0"].dependencies["b"], "b@npm:1.0.0");
+        assert_eq!(packages["c@npm:^1.0.0"].dependencies["b"], "b@npm:^2.0.0");
+        assert_eq!(packages["b@npm:1.0.0"].version, "1.0.0");
+        assert_eq!(packages["b@npm:^2.0.0"].version, "2.1.0");
+        assert_eq!(packages["b@npm:1.0.0"].dependencies["d"], "d@npm:1.0.0");
+        assert_eq!(packages["d@npm:1.0.0"].version, "1.0.0");
+        assert!(!packages.contains_key("d@npm:^3.0.0"));
+    }
+
     #[test]
     fn manifest_lifecycle_metadata_records_implicit_node_gyp_install_hook() {
         let tmp = tempfile::tempdir().unwrap();
@@ -1976,6 +2307,70 @@ mod tests {
         assert!(cache.metadata_path("left-pad").is_file());
     }
 
+    #[tokio::test]
+    async fn add_spec_resolves_ranges_tags_and_file_dependencies() {
+        let tmp = tempfile::tempdir().unwrap();
+        let project = tmp.path().join("project");
+        let fixture = tmp.path().join("registry");
+        let cache = FsRegistryMetadataCache::new(tmp.path().join("cache"));
+        write(
+            &fixture.join("left-pad.json"),
+            r#"{
+              "name": "left-pad",
+              "dist-tags": { "latest": "1.3.0", "next": "2.0.0-beta.1" },
+              "versions": {
+                "1.2.0": { "name": "left-pad", "version": "1.2.0" },
+                "1.3.0": { "name": "left-pad", "version": "1.3.0" },
+                "2.0.0-beta.1": { "name": "left-pad", "version": "2.0.0-beta.1" }
+              }
+            }"#,
+        )
+        .await;
+        write(
+            &project.join("tools/local/package.json"),
+            r#"{"name":"local"}"#,
+        )
+        .await;
+        let client = FileRegistryMetadataClient::new(&fixture);
+        let (project, cache, client) = (project.as_path(), &cache, &client);
+        let add = move |name: &'static str, spec: Option<&'static str>| {
+            resolve_add_spec(project, name, spec, cache, client)
+        };
+
+        assert_eq!(add("left-pad", Some("^1.2.0")).await.unwrap(), "^1.2.0");
+        assert_eq!(add("left-pad", Some("1.2.0")).await.unwrap(), "1.2.0");
+        assert_eq!(
+            add("left-pad", Some("next")).await.unwrap(),
+            "^2.0.0-beta.1"
+        );
+        assert_eq!(add("left-pad", None).await.unwrap(), "^1.3.0");
+        assert_eq!(
+            add("local", Some("file:tools/local")).await.unwrap(),
+            "file:tools/local"
+        );
+        assert!(matches!(
+            add("left-pad", Some("^9.0.0")).await,
+            Err(PackageManagerError::NoMatchingVersion { .. })
+        ));
+        assert!(matches!(
+            add("missing", Some("file:tools/missing")).await,
+            Err(PackageManagerError::Io { .. })
+        ));
+        for git in [
+            "github:user/repo#v1",
+            "user/repo",
+            "git+https://example.com/r.git",
+        ] {
+            assert!(
+                matches!(
+                    add("repo", Some(git)).await,
+                    Err(PackageManagerError::Backend { backend: "add", .. })
+                ),
+                "{git} must be rejected as a git specifier"
+            );
+        }
+    }
+
     #[tokio::test]
     async fn tarball_cache_is_integrity_checked_and_reused() {
         let tmp = tempfile::tempdir().unwrap();
@@ -2288,6 +2683,94 @@ mod tests {
         assert!(project.join("node_modules/.bin/tool").is_file());
     }
 
+    #[derive(Default)]
+    struct PhaseLog(std::sync::Mutex<Vec<(String, InstallPhase)>>);
+
+    impl InstallProgress for PhaseLog {
+        fn report(&self, package_id: &str, phase: InstallPhase) {
+            self.0.lock().unwrap().push((package_id.to_string(), phase));
+        }
+    }
+
+    #[tokio::test]
+    async fn package_store_extracts_concurrently_and_reports_each_phase() {
+        let tmp = tempfile::tempdir().unwrap();
+        let fixture = tmp.path().join("tarballs");
+        let mut lockfile = Lockfile::new();
+        let names = ["pkg-a", "pkg-b", "pkg-c", "pkg-d", "pkg-e"];
+        for name in names {
+            let manifest = format!(r#"{{"name":"{name}","version":"1.0.0"}}"#);
+            let index = format!("module.exports = {name:?};\n");
+            let tarball = npm_tgz(&[
+                ("package/package.json", manifest.as_str()),
+                ("package/index.js", index.as_str()),
+            ]);
+            let url = format!("https://registry.npmjs.org/{name}/-/{name}-1.0.0.tgz");
+            write_bytes(&fixture.join(cache_key(&url)), &tarball).await;
+            lockfile.packages.insert(
+                format!("{name}@npm:^1.0.0"),
+                LockedPackage {
+                    name: name.to_string(),
+                    version: "1.0.0".to_string(),
+                    dependencies: BTreeMap::new(),
+                    integrity: Some(format!(
+                        "sha512-{}",
+                        base64::engine::general_purpose::STANDARD.encode(Sha512::digest(&tarball))
+                    )),
+                    resolved: Some(ResolvedSource {
+                        kind: ResolvedSourceKind::Registry,
+                        reference: url,
+                    }),
+                    lifecycle: LifecycleMetadata::default(),
+                },
+            );
+        }
+        let progress = std::sync::Arc::new(PhaseLog::default());
+        let store = FsPackageStore::new(tmp.path().join("cache"))
+            .with_max_in_flight(2)
+            .with_progress(progress.clone());
+        let installed = store
+            .materialize_registry_packages(
+                tmp.path().join("project"),
+                &lockfile,
+                &FileTarballClient::new(&fixture),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(installed.len(), names.len());
+        let events = progress.0.lock().unwrap().clone();
+        for name in names {
+            let index = std::fs::read_to_string(
+                tmp.path()
+                    .join(format!("project/node_modules/{name}/index.js")),
+            )
+            .unwrap();
+            assert_eq!(index, format!("module.exports = {name:?};\n"));
+            let id = format!("{name}@npm:^1.0.0");
+            let phases = events
+                .iter()
+                .filter(|(package, _)| *package == id)
+                .map(|(_, phase)| *phase)
+                .collect::<Vec<_>>();
+            assert_eq!(
+                phases,
+                [
+                    InstallPhase::Downloading,
+                    InstallPhase::Extracting,
+                    InstallPhase::Linking
+                ],
+                "{id}"
+            );
+        }
+        let staging = std::fs::read_dir(tmp.path().join("cache/packages"))
+            .unwrap()
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_name().to_string_lossy().starts_with(".tmp-"))
+            .count();
+        assert_eq!(staging, 0);
+    }
+
     #[tokio::test]
     async fn install_local_project_materializes_direct_tarball_dependency() {
         let tmp = tempfile::tempdir().unwrap();
@@ -2539,6 +3022,221 @@ mod tests {
         assert_eq!(tarball, b"tgz bytes");
     }
 
+    fn fast_retry(max_retries: u32) -> RegistryRetryPolicy {
+        RegistryRetryPolicy {
+            max_retries,
+            initial_backoff: std::time::Duration::from_millis(10),
+            max_backoff: std::time::Duration::from_millis(40),
+            max_retry_after: std::time::Duration::from_secs(5),
+        }
+    }
+
+    #[tokio::test]
+    async fn registry_prefetch_respects_max_in_flight() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let active = std::sync::Arc::new(AtomicUsize::new(0));
+        let peak = std::sync::Arc::new(AtomicUsize::new(0));
+        let (server_active, server_peak) = (active.clone(), peak.clone());
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let (active, peak) = (server_active.clone(), server_peak.clone());
+                tokio::spawn(async move {
+                    let now = active.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(now, Ordering::SeqCst);
+                    let mut request = [0_u8; 1024];
+                    let read = stream.read(&mut request).await.unwrap();
+                    let request = String::from_utf8_lossy(&request[..read]);
+                    let name = request
+                        .split_whitespace()
+                        .nth(1)
+                        .unwrap()
+                        .trim_start_matches('/')
+                        .to_string();
+                    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                    let body = format!(r#"{{"name":"{name}","versions":{{}}}}"#);
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                        body.len()
+                    );
+                    active.fetch_sub(1, Ordering::SeqCst);
+                    stream.write_all(response.as_bytes()).await.unwrap();
+                });
+            }
+        });
+
+        let tmp = tempfile::tempdir().unwrap();
+        let cache = FsRegistryMetadataCache::new(tmp.path());
+        let client = HttpRegistryMetadataClient::with_base_url(format!("http://{addr}"))
+            .with_max_in_flight(2)
+            .with_retry_policy(RegistryRetryPolicy::none());
+        let names = ["p0", "p1", "p2", "p3", "p4", "p5"];
+        cache.prefetch(names, &client).await.unwrap();
+
+        for name in names {
+            assert!(cache.metadata_path(name).is_file(), "{name} was cached");
+        }
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn registry_429_waits_for_retry_after() {
+        let (url, requests) = serve_http_sequence(vec![
+            "HTTP/1.1 429 Too Many Requests\r\nretry-after: 1\r\ncontent-length: 0\r\n",
+            "HTTP/1.1 200 OK\r\ncontent-length: 14\r\n\r\n{\"name\":\"pkg\"}",
+        ])
+        .await;
+        let client =
+            HttpRegistryMetadataClient::with_base_url(url).with_retry_policy(fast_retry(3));
+
+        let started = std::time::Instant::now();
+        let fetched = client.fetch_metadata("pkg").await.unwrap();
+
+        assert_eq!(fetched.name, "pkg");
+        assert!(started.elapsed() >= std::time::Duration::from_secs(1));
+        assert_eq!(requests.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn registry_503_retries_then_succeeds_or_reports_exhaustion() {
+        let (url, requests) = serve_http_sequence(vec![
+            "HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\n",
+            "HTTP/1.1 200 OK\r\ncontent-length: 14\r\n\r\n{\"name\":\"pkg\"}",
+        ])
+        .await;
+        let client =
+            HttpRegistryMetadataClient::with_base_url(url).with_retry_policy(fast_retry(3));
+        assert_eq!(client.fetch_metadata("pkg").await.unwrap().name, "pkg");
+        assert_eq!(requests.load(std::sync::atomic::Ordering::SeqCst), 2);
+
+        let (url, requests) = serve_http_sequence(vec![
+            "HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\n";
+            3
+        ])
+        .await;
+        let client =
+            HttpRegistryMetadataClient::with_base_url(url).with_retry_policy(fast_retry(2));
+        let err = client.fetch_metadata("pkg").await.unwrap_err();
+        assert!(
+            matches!(&err, PackageManagerError::Http { message, .. } if message.contains("after 3 attempts")),
+            "{err}"
+        );
+        assert_eq!(requests.load(std::sync::atomic::Ordering::SeqCst), 3);
+
+        let (url, requests) =
+            serve_http_sequence(vec!["HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\n"]).await;
+        let client =
+            HttpRegistryMetadataClient::with_base_url(url).with_retry_policy(fast_retry(2));
+        assert!(client.fetch_metadata("pkg").await.is_err());
+        assert_eq!(requests.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn registry_cache_revalidates_with_etag() {
+        let tmp = tempfile::tempdir().unwrap();
+        let cache = FsRegistryMetadataCache::new(tmp.path().join("cache"));
+        let (url, requests, heads) = serve_http_recording(vec![
+            "HTTP/1.1 200 OK\r\netag: \"v1\"\r\ncontent-length: 14\r\n\r\n{\"name\":\"pkg\"}",
+            "HTTP/1.1 304 Not Modified\r\ncontent-length: 0\r\n",
+            "HTTP/1.1 200 OK\r\netag: \"v2\"\r\ncontent-length: 14\r\n\r\n{\"name\":\"pkg\"}",
+        ])
+        .await;
+        let client =
+            HttpRegistryMetadataClient::with_base_url(url).with_retry_policy(fast_retry(0));
+
+        cache.get_or_fetch("pkg", &client).await.unwrap();
+        assert_eq!(
+            cache.read_validators("pkg").await.etag.as_deref(),
+            Some("\"v1\"")
+        );
+        assert_eq!(cache.revalidate("pkg", &client).await.unwrap().name, "pkg");
+        assert_eq!(cache.revalidate("pkg", &client).await.unwrap().name, "pkg");
+        assert_eq!(
+            cache.read_validators("pkg").await.etag.as_deref(),
+            Some("\"v2\"")
+        );
+        assert_eq!(requests.load(std::sync::atomic::Ordering::SeqCst), 3);
+        let heads = heads.lock().unwrap().clone();
+        assert!(!heads[0].contains("if-none-match"), "{}", heads[0]);
+        assert!(heads[1].contains("if-none-match: \"v1\""), "{}", heads[1]);
+        assert!(heads[2].contains("if-none-match: \"v1\""), "{}", heads[2]);
+    }
+
+    #[tokio::test]
+    async fn missing_type_packages_resolve_only_for_typeless_dependencies() {
+        let tmp = tempfile::tempdir().unwrap();
+        let project = tmp.path().join("project");
+        let fixture = tmp.path().join("registry");
+        write(
+            &project.join("package.json"),
+            r#"{"name":"app","dependencies":{"plain":"^1.0.0","typed":"^1.0.0","with-main":"^1.0.0","@scope/bare":"^1.0.0"}}"#,
+        )
+        .await;
+        write(
+            &project.join("node_modules/plain/package.json"),
+            r#"{"name":"plain","version":"1.0.0","main":"lib/plain.js"}"#,
+        )
+        .await;
+        write(
+            &project.join("node_modules/typed/package.json"),
+            r#"{"name":"typed","version":"1.0.0","types":"dist/typed.d.ts"}"#,
+        )
+        .await;
+        write(
+            &project.join("node_modules/with-main/package.json"),
+            r#"{"name":"with-main","version":"1.0.0","main":"./lib/index.js"}"#,
+        )
+        .await;
+        write(
+            &project.join("node_modules/with-main/lib/index.d.ts"),
+            "export {};\n",
+        )
+        .await;
+        write(
+            &project.join("node_modules/@scope/bare/package.json"),
+            r#"{"name":"@scope/bare","version":"1.0.0"}"#,
+        )
+        .await;
+        for types_package in ["@types/plain", "@types/typed", "@types/scope__bare"] {
+            write(
+                &fixture.join(format!("{}.json", cache_key(types_package))),
+                &format!(
+                    r#"{{"name":"{types_package}","dist-tags":{{"latest":"1.2.0"}},"versions":{{"1.2.0":{{"name":"{types_package}","version":"1.2.0"}}}}}}"#
+                ),
+            )
+            .await;
+        }
+
+        let cache = FsRegistryMetadataCache::new(tmp.path().join("cache"));
+        let companions = resolve_missing_type_packages(
+            &project,
+            [
+                "plain",
+                "typed",
+                "with-main",
+                "@scope/bare",
+                "not-installed",
+            ],
+            &cache,
+            &FileRegistryMetadataClient::new(&fixture),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            companions,
+            [
+                ("@types/plain".to_string(), "^1.2.0".to_string()),
+                ("@types/scope__bare".to_string(), "^1.2.0".to_string()),
+            ]
+        );
+        assert!(cache.read("@types/typed").await.unwrap().is_none());
+    }
+
     async fn write(path: &Path, text: &str) {
         if let Some(parent) = path.parent() {
             tokio::fs::create_dir_all(parent).await.unwrap();
@@ -2572,6 +3270,50 @@ mod tests {
         encoder.finish().unwrap()
     }
 
+    /// Serve one raw response per connection, in order. A response without
+    /// a blank line gets `connection: close` and an empty body appended.
+    async fn serve_http_sequence(
+        responses: Vec<&'static str>,
+    ) -> (String, std::sync::Arc<std::sync::atomic::AtomicUsize>) {
+        let (url, requests, _) = serve_http_recording(responses).await;
+        (url, requests)
+    }
+
+    async fn serve_http_recording(
+        responses: Vec<&'static str>,
+    ) -> (
+        String,
+        std::sync::Arc<std::sync::atomic::AtomicUsize>,
+        std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+    ) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let requests = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let heads = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let served = requests.clone();
+        let recorded = heads.clone();
+        tokio::spawn(async move {
+            use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+            for response in responses {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut request = [0_u8; 1024];
+                let read = stream.read(&mut request).await.unwrap();
+                recorded
+                    .lock()
+                    .unwrap()
+                    .push(String::from_utf8_lossy(&request[..read]).to_ascii_lowercase());
+                served.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                let response = match response.split_once("\r\n\r\n") {
+                    Some((head, body)) => format!("{head}\r\nconnection: close\r\n\r\n{body}"),
+                    None => format!("{response}connection: close\r\n\r\n"),
+                };
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+        (format!("http://{addr}"), requests, heads)
+    }
+
     async fn serve_http_once(body: Vec<u8>) -> String {
         let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
         let addr = listener.local_addr().unwrap();
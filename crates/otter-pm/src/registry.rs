@@ -11,21 +11,35 @@
 //! - [`FsRegistryMetadataCache`] stores deterministic on-disk metadata JSON.
 //! - [`FileRegistryMetadataClient`] and [`HttpRegistryMetadataClient`] provide
 //!   fixture-backed and network-backed client implementations.
+//! - [`RegistryRetryPolicy`] bounds HTTP retries and backoff.
+//! - [`RegistryValidators`] and [`ConditionalMetadata`] carry `ETag` /
+//!   `Last-Modified` revalidation through [`FsRegistryMetadataCache::revalidate`].
 //!
 //! # Invariants
 //! - All client/cache APIs are async-only.
 //! - Metadata JSON cache filenames use crate-level deterministic escaping.
 //! - HTTP clients are transport only; lockfile trust state is decided by the
 //!   install resolver.
+//! - One [`HttpRegistryMetadataClient`] (and its clones) never has more than
+//!   its configured number of requests in flight. Retries hold the permit, so
+//!   a backing-off fetch does not let another request jump the limit.
+//! - Only network errors, 5xx, and 429 are retried. Other statuses fail on
+//!   the first response.
+//! - Validators live in a sidecar file next to the metadata JSON, so the
+//!   metadata file format is unchanged. A 304 never rewrites the metadata.
 //!
 //! # See also
 //! - [`crate::resolve_local_project_with_registry_metadata`] for lockfile
 //!   enrichment using this metadata.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::future::Future;
 use std::path::PathBuf;
 use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::Semaphore;
 
 use otter_pm_manifest::{DependencySet, PackageBinManifest};
 use serde::{Deserialize, Serialize};
@@ -33,6 +47,7 @@ use serde::{Deserialize, Serialize};
 use crate::{PackageManagerError, cache_key};
 
 const DEFAULT_NPM_REGISTRY: &str = "https://registry.npmjs.org";
+const DEFAULT_MAX_IN_FLIGHT: usize = 16;
 
 /// npm registry metadata subset needed by Otter package resolution.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -88,6 +103,43 @@ pub struct NpmDist {
     pub shasum: Option<String>,
 }
 
+/// HTTP cache validators stored with cached registry metadata.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegistryValidators {
+    /// Response `ETag`, sent back as `If-None-Match`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+    /// Response `Last-Modified`, sent back as `If-Modified-Since`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_modified: Option<String>,
+    /// Unix seconds of the last fetch or successful revalidation.
+    #[serde(default)]
+    pub checked_at: u64,
+}
+
+impl RegistryValidators {
+    /// Whether there is anything to revalidate with.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.etag.is_none() && self.last_modified.is_none()
+    }
+}
+
+/// Outcome of [`RegistryMetadataClient::fetch_metadata_if_modified`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConditionalMetadata {
+    /// The registry confirmed the cached copy (HTTP 304).
+    NotModified,
+    /// Fresh metadata and the validators that came with it.
+    Modified {
+        /// Fetched metadata.
+        metadata: NpmRegistryMetadata,
+        /// Validators for the next revalidation.
+        validators: RegistryValidators,
+    },
+}
+
 /// Source of npm registry metadata.
 pub trait RegistryMetadataClient {
     /// Fetch metadata for `package` without blocking the async runtime.
@@ -95,6 +147,26 @@ pub trait RegistryMetadataClient {
         &'a self,
         package: &'a str,
     ) -> Pin<Box<dyn Future<Output = Result<NpmRegistryMetadata, PackageManagerError>> + Send + 'a>>;
+
+    /// Fetch metadata unless the registry confirms `validators` still match.
+    ///
+    /// The default ignores `validators` and always fetches, returning no
+    /// validators of its own.
+    fn fetch_metadata_if_modified<'a>(
+        &'a self,
+        package: &'a str,
+        validators: &'a RegistryValidators,
+    ) -> Pin<Box<dyn Future<Output = Result<ConditionalMetadata, PackageManagerError>> + Send + 'a>>
+    {
+        let _ = validators;
+        let fetch = self.fetch_metadata(package);
+        Box::pin(async move {
+            Ok(ConditionalMetadata::Modified {
+                metadata: fetch.await?,
+                validators: RegistryValidators::default(),
+            })
+        })
+    }
 }
 
 /// Deterministic filesystem cache for npm registry metadata.
@@ -116,6 +188,49 @@ impl FsRegistryMetadataCache {
         self.root.join(format!("{}.json", cache_key(package)))
     }
 
+    /// Return the validator sidecar path for one package.
+    #[must_use]
+    pub fn validators_path(&self, package: &str) -> PathBuf {
+        self.root
+            .join(format!("{}.validators.json", cache_key(package)))
+    }
+
+    /// Read stored validators; missing or unreadable sidecars read as empty.
+    pub async fn read_validators(&self, package: &str) -> RegistryValidators {
+        tokio::fs::read_to_string(self.validators_path(package))
+            .await
+            .ok()
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default()
+    }
+
+    /// Store validators for `package`, stamping `checked_at` with now.
+    pub async fn write_validators(
+        &self,
+        package: &str,
+        validators: &RegistryValidators,
+    ) -> Result<(), PackageManagerError> {
+        let validators = RegistryValidators {
+            checked_at: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs()),
+            ..validators.clone()
+        };
+        let path = self.validators_path(package);
+        let text = serde_json::to_string_pretty(&validators).map_err(|err| {
+            PackageManagerError::RegistryMetadata {
+                package: package.to_string(),
+                message: err.to_string(),
+            }
+        })?;
+        tokio::fs::write(&path, text + "\n")
+            .await
+            .map_err(|err| PackageManagerError::Io {
+                path,
+                message: err.to_string(),
+            })
+    }
+
     /// Read cached metadata if present.
     pub async fn read(
         &self,
@@ -159,6 +274,24 @@ impl FsRegistryMetadataCache {
             })
     }
 
+    /// Populate the cache for every uncached package in `packages`, fetching
+    /// concurrently. The client bounds how many requests are in flight.
+    pub async fn prefetch<'p>(
+        &self,
+        packages: impl IntoIterator<Item = &'p str>,
+        client: &impl RegistryMetadataClient,
+    ) -> Result<(), PackageManagerError> {
+        let packages = packages.into_iter().collect::<BTreeSet<_>>();
+        crate::try_join_bounded(
+            None,
+            packages
+                .into_iter()
+                .map(|package| self.get_or_fetch(package, client)),
+        )
+        .await?;
+        Ok(())
+    }
+
     /// Read from cache, otherwise fetch through `client` and cache the result.
     pub async fn get_or_fetch(
         &self,
@@ -168,9 +301,58 @@ impl FsRegistryMetadataCache {
         if let Some(metadata) = self.read(package).await? {
             return Ok(metadata);
         }
-        let metadata = client.fetch_metadata(package).await?;
-        self.write(&metadata).await?;
-        Ok(metadata)
+        self.fetch_and_store(package, &RegistryValidators::default(), client)
+            .await?
+            .ok_or_else(|| PackageManagerError::RegistryMetadata {
+                package: package.to_string(),
+                message: "registry answered 304 to an unconditional request".to_string(),
+            })
+    }
+
+    /// Refresh cached metadata through a conditional request.
+    ///
+    /// With stored validators the client sends `If-None-Match` /
+    /// `If-Modified-Since`; a 304 keeps the cached metadata and only restamps
+    /// the validators. Without a cached copy this is [`Self::get_or_fetch`].
+    pub async fn revalidate(
+        &self,
+        package: &str,
+        client: &impl RegistryMetadataClient,
+    ) -> Result<NpmRegistryMetadata, PackageManagerError> {
+        let Some(cached) = self.read(package).await? else {
+            return self.get_or_fetch(package, client).await;
+        };
+        let validators = self.read_validators(package).await;
+        match self.fetch_and_store(package, &validators, client).await? {
+            Some(metadata) => Ok(metadata),
+            None => {
+                self.write_validators(package, &validators).await?;
+                Ok(cached)
+            }
+        }
+    }
+
+    /// Fetch and cache metadata and validators; `None` on a 304.
+    async fn fetch_and_store(
+        &self,
+        package: &str,
+        validators: &RegistryValidators,
+        client: &impl RegistryMetadataClient,
+    ) -> Result<Option<NpmRegistryMetadata>, PackageManagerError> {
+        match client
+            .fetch_metadata_if_modified(package, validators)
+            .await?
+        {
+            ConditionalMetadata::NotModified => Ok(None),
+            ConditionalMetadata::Modified {
+                metadata,
+                validators,
+            } => {
+                self.write(&metadata).await?;
+                self.write_validators(&metadata.name, &validators).await?;
+                Ok(Some(metadata))
+            }
+        }
     }
 }
 
@@ -212,11 +394,56 @@ impl RegistryMetadataClient for FileRegistryMetadataClient {
     }
 }
 
+/// Retry and backoff bounds for [`HttpRegistryMetadataClient`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegistryRetryPolicy {
+    /// Retries after the first attempt.
+    pub max_retries: u32,
+    /// Delay before the first retry; doubled for each later one.
+    pub initial_backoff: Duration,
+    /// Upper bound for exponential backoff.
+    pub max_backoff: Duration,
+    /// Upper bound for a `Retry-After` delay sent with a 429.
+    pub max_retry_after: Duration,
+}
+
+impl RegistryRetryPolicy {
+    /// A policy that never retries.
+    #[must_use]
+    pub const fn none() -> Self {
+        Self {
+            max_retries: 0,
+            initial_backoff: Duration::ZERO,
+            max_backoff: Duration::ZERO,
+            max_retry_after: Duration::ZERO,
+        }
+    }
+
+    fn backoff(&self, retry: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2_u32.saturating_pow(retry))
+            .min(self.max_backoff)
+    }
+}
+
+impl Default for RegistryRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+            max_retry_after: Duration::from_secs(60),
+        }
+    }
+}
+
 /// Async HTTP-backed npm registry metadata client.
 #[derive(Debug, Clone)]
 pub struct HttpRegistryMetadataClient {
     client: reqwest::Client,
     registry_base: String,
+    in_flight: Arc<Semaphore>,
+    retry: RegistryRetryPolicy,
 }
 
 impl HttpRegistryMetadataClient {
@@ -232,9 +459,25 @@ impl HttpRegistryMetadataClient {
         Self {
             client: reqwest::Client::new(),
             registry_base: registry_base.into().trim_end_matches('/').to_string(),
+            in_flight: Arc::new(Semaphore::new(DEFAULT_MAX_IN_FLIGHT)),
+            retry: RegistryRetryPolicy::default(),
         }
     }
 
+    /// Limit concurrent metadata requests. `0` is treated as `1`.
+    #[must_use]
+    pub fn with_max_in_flight(mut self, max_in_flight: usize) -> Self {
+        self.in_flight = Arc::new(Semaphore::new(max_in_flight.max(1)));
+        self
+    }
+
+    /// Replace the retry and backoff policy.
+    #[must_use]
+    pub fn with_retry_policy(mut self, retry: RegistryRetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
     fn metadata_url(&self, package: &str) -> String {
         format!(
             "{}/{}",
@@ -242,6 +485,83 @@ impl HttpRegistryMetadataClient {
             npm_registry_package_path(package)
         )
     }
+
+    /// One request. `Err((retry_after, error))` carries the delay to wait
+    /// before retrying, or `None` when the failure is not retryable.
+    async fn fetch_once(
+        &self,
+        url: &str,
+        validators: &RegistryValidators,
+        retry: u32,
+    ) -> Result<Option<(String, RegistryValidators)>, (Option<Duration>, PackageManagerError)> {
+        let http_error = |message: String| PackageManagerError::Http {
+            url: url.to_string(),
+            message,
+        };
+        let mut request = self
+            .client
+            .get(url)
+            .header(reqwest::header::ACCEPT, "application/json");
+        if let Some(etag) = &validators.etag {
+            request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = &validators.last_modified {
+            request = request.header(reqwest::header::IF_MODIFIED_SINCE, last_modified);
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|err| (Some(self.retry.backoff(retry)), http_error(err.to_string())))?;
+        let status = response.status();
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            let delay = retry_after(&response).map_or_else(
+                || self.retry.backoff(retry),
+                |delay| delay.min(self.retry.max_retry_after),
+            );
+            return Err((
+                Some(delay),
+                http_error(format!("registry returned {status}")),
+            ));
+        }
+        if status == reqwest::StatusCode::NOT_MODIFIED && !validators.is_empty() {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            let delay = status.is_server_error().then(|| self.retry.backoff(retry));
+            return Err((delay, http_error(format!("registry returned {status}"))));
+        }
+        let header = |name| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value: &reqwest::header::HeaderValue| value.to_str().ok())
+                .map(str::to_string)
+        };
+        let validators = RegistryValidators {
+            etag: header(reqwest::header::ETAG),
+            last_modified: header(reqwest::header::LAST_MODIFIED),
+            checked_at: 0,
+        };
+        response
+            .text()
+            .await
+            .map(|text| Some((text, validators)))
+            .map_err(|err| (Some(self.retry.backoff(retry)), http_error(err.to_string())))
+    }
+}
+
+/// `Retry-After` in its delay-seconds form. The HTTP-date form falls back to
+/// exponential backoff.
+fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
 }
 
 impl Default for HttpRegistryMetadataClient {
@@ -250,6 +570,43 @@ impl Default for HttpRegistryMetadataClient {
     }
 }
 
+impl HttpRegistryMetadataClient {
+    /// Fetch under the in-flight limit, retrying per the policy. `None` is a
+    /// 304 answer to the supplied validators.
+    async fn fetch_with_retry(
+        &self,
+        package: &str,
+        validators: &RegistryValidators,
+    ) -> Result<Option<(String, RegistryValidators)>, PackageManagerError> {
+        let url = self.metadata_url(package);
+        let _permit =
+            self.in_flight
+                .acquire()
+                .await
+                .map_err(|err| PackageManagerError::Backend {
+                    backend: "registry",
+                    message: err.to_string(),
+                })?;
+        let mut retry = 0;
+        loop {
+            match self.fetch_once(&url, validators, retry).await {
+                Ok(fetched) => return Ok(fetched),
+                Err((Some(delay), _)) if retry < self.retry.max_retries => {
+                    tokio::time::sleep(delay).await;
+                    retry += 1;
+                }
+                Err((_, PackageManagerError::Http { url, message })) if retry > 0 => {
+                    return Err(PackageManagerError::Http {
+                        url,
+                        message: format!("{message} (after {} attempts)", retry + 1),
+                    });
+                }
+                Err((_, err)) => return Err(err),
+            }
+        }
+    }
+}
+
 impl RegistryMetadataClient for HttpRegistryMetadataClient {
     fn fetch_metadata<'a>(
         &'a self,
@@ -257,34 +614,33 @@ impl RegistryMetadataClient for HttpRegistryMetadataClient {
     ) -> Pin<Box<dyn Future<Output = Result<NpmRegistryMetadata, PackageManagerError>> + Send + 'a>>
     {
         Box::pin(async move {
-            let url = self.metadata_url(package);
-            let response = self
-                .client
-                .get(&url)
-                .header(reqwest::header::ACCEPT, "application/json")
-                .send()
-                .await
-                .map_err(|err| PackageManagerError::Http {
-                    url: url.clone(),
-                    message: err.to_string(),
-                })?;
-            let status = response.status();
-            if !status.is_success() {
-                return Err(PackageManagerError::Http {
-                    url,
-                    message: format!("registry returned {status}"),
-                });
-            }
-            let text = response
-                .text()
-                .await
-                .map_err(|err| PackageManagerError::Http {
-                    url: url.clone(),
-                    message: err.to_string(),
+            let (text, _) = self
+                .fetch_with_retry(package, &RegistryValidators::default())
+                .await?
+                .ok_or_else(|| PackageManagerError::RegistryMetadata {
+                    package: package.to_string(),
+                    message: "registry answered 304 to an unconditional request".to_string(),
                 })?;
             parse_registry_metadata(package, &text)
         })
     }
+
+    fn fetch_metadata_if_modified<'a>(
+        &'a self,
+        package: &'a str,
+        validators: &'a RegistryValidators,
+    ) -> Pin<Box<dyn Future<Output = Result<ConditionalMetadata, PackageManagerError>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            match self.fetch_with_retry(package, validators).await? {
+                None => Ok(ConditionalMetadata::NotModified),
+                Some((text, validators)) => Ok(ConditionalMetadata::Modified {
+                    metadata: parse_registry_metadata(package, &text)?,
+                    validators,
+                }),
+            }
+        })
+    }
 }
 
 fn parse_registry_metadata(
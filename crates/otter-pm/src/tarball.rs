@@ -216,7 +216,7 @@ impl FsTarballCache {
 
         let bytes = client.fetch_tarball(&source.url).await?;
         verify_tarball_integrity(source, &bytes)?;
-        let tmp = path.with_extension(format!("tmp-{}", std::process::id()));
+        let tmp = path.with_extension(format!("tmp-{}", unique_tmp_suffix()));
         tokio::fs::write(&tmp, &bytes)
             .await
             .map_err(|err| PackageManagerError::Io {
@@ -237,6 +237,17 @@ impl FsTarballCache {
     }
 }
 
+/// Suffix for staging paths, unique per process and per call so concurrent
+/// writers to the same cache never share a temporary file.
+pub(crate) fn unique_tmp_suffix() -> String {
+    static NEXT: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+    format!(
+        "{}-{}",
+        std::process::id(),
+        NEXT.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+    )
+}
+
 pub(crate) fn tarball_cache_key(source: &TarballSource) -> String {
     if let Some(integrity) = &source.integrity {
         return cache_key(integrity);
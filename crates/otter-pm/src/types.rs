@@ -0,0 +1,128 @@
+//! Companion `@types/*` discovery for dependencies without bundled typings.
+//!
+//! `otter add --types` uses this after an install to find direct dependencies
+//! whose installed package ships no `.d.ts` entry and to resolve the matching
+//! DefinitelyTyped package from the registry.
+//!
+//! # Contents
+//! - [`definitely_typed_name`] maps a package name to its `@types/*` name.
+//! - [`ships_bundled_types`] inspects an installed package root.
+//! - [`resolve_missing_type_packages`] returns the `@types/*` ranges to record.
+//!
+//! # Invariants
+//! - Packages that are not installed, are themselves `@types/*`, or already
+//!   have their companion declared in any dependency bucket are skipped.
+//! - A companion that cannot be fetched is treated as absent; type lookups
+//!   never fail the surrounding install.
+//!
+//! # See also
+//! - [`crate::resolve_add_spec`] for how the recorded range is chosen.
+
+use std::path::Path;
+
+use otter_pm_manifest::{PACKAGE_JSON, PackageManifest};
+
+use crate::{
+    FsRegistryMetadataCache, PackageManagerError, RegistryMetadataClient, package_name_path,
+    tagged_caret_range,
+};
+
+/// Return the DefinitelyTyped package name for `package`.
+///
+/// Scoped names fold into one segment: `@scope/pkg` becomes
+/// `@types/scope__pkg`.
+#[must_use]
+pub fn definitely_typed_name(package: &str) -> String {
+    match package
+        .strip_prefix('@')
+        .and_then(|scoped| scoped.split_once('/'))
+    {
+        Some((scope, name)) => format!("@types/{scope}__{name}"),
+        None => format!("@types/{package}"),
+    }
+}
+
+/// Whether the package installed at `package_root` ships its own typings.
+///
+/// A `types`/`typings` field, a root `index.d.ts`, or a `.d.ts` next to the
+/// `main` entry all count.
+pub async fn ships_bundled_types(package_root: &Path) -> Result<bool, PackageManagerError> {
+    let manifest = PackageManifest::read_from_dir(package_root)
+        .await
+        .map_err(|err| PackageManagerError::Io {
+            path: package_root.join(PACKAGE_JSON),
+            message: err.to_string(),
+        })?;
+    if manifest.types.is_some() || manifest.typings.is_some() {
+        return Ok(true);
+    }
+    let main_declarations = manifest.main.as_deref().map(|main| {
+        let main = main.strip_prefix("./").unwrap_or(main);
+        let stem = main
+            .strip_suffix(".js")
+            .or_else(|| main.strip_suffix(".cjs"))
+            .or_else(|| main.strip_suffix(".mjs"))
+            .unwrap_or(main);
+        format!("{stem}.d.ts")
+    });
+    for candidate in std::iter::once("index.d.ts".to_string()).chain(main_declarations) {
+        let path = package_root.join(candidate);
+        if tokio::fs::try_exists(&path)
+            .await
+            .map_err(|err| PackageManagerError::Io {
+                path: path.clone(),
+                message: err.to_string(),
+            })?
+        {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Resolve `@types/*` companions for installed `packages` that ship no types.
+///
+/// Returns `(types package, range)` pairs in input order, ready to record in
+/// `devDependencies`. Ranges are `^latest`, as `otter add` records bare names.
+pub async fn resolve_missing_type_packages<'p>(
+    project_root: impl AsRef<Path>,
+    packages: impl IntoIterator<Item = &'p str>,
+    metadata_cache: &FsRegistryMetadataCache,
+    metadata_client: &impl RegistryMetadataClient,
+) -> Result<Vec<(String, String)>, PackageManagerError> {
+    let project_root = project_root.as_ref();
+    let manifest = PackageManifest::read_from_dir(project_root)
+        .await
+        .map_err(|err| PackageManagerError::Io {
+            path: project_root.join(PACKAGE_JSON),
+            message: err.to_string(),
+        })?;
+    let mut companions = Vec::new();
+    for package in packages {
+        if package.starts_with("@types/") {
+            continue;
+        }
+        let types_package = definitely_typed_name(package);
+        let declared = manifest
+            .dependency_buckets()
+            .iter()
+            .any(|(_, dependencies)| dependencies.contains_key(&types_package));
+        if declared || companions.iter().any(|(name, _)| *name == types_package) {
+            continue;
+        }
+        let package_root = project_root
+            .join("node_modules")
+            .join(package_name_path(package));
+        if !package_root.join(PACKAGE_JSON).is_file() || ships_bundled_types(&package_root).await? {
+            continue;
+        }
+        let Ok(metadata) = metadata_cache
+            .get_or_fetch(&types_package, metadata_client)
+            .await
+        else {
+            continue;
+        };
+        companions.push((types_package, tagged_caret_range(&metadata, "latest")?));
+    }
+    Ok(companions)
+}
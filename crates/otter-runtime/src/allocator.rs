@@ -0,0 +1,53 @@
+//! Global-allocator statistics.
+//!
+//! The allocator itself is chosen by the final binary: a library cannot own
+//! `#[global_allocator]` without forcing it on every embedder. `otter-cli`
+//! installs mimalloc or jemalloc behind its own cargo features; this module
+//! only reads counters back out of the allocator the process ended up with.
+//!
+//! # Contents
+//! - [`allocator_stats`] — process-wide allocator counters, when the
+//!   allocator exposes them through a safe API.
+//! - [`AllocatorStats`] — the counters themselves.
+//!
+//! # Invariants
+//! - Stats are read only under the `jemalloc-stats` feature, and are
+//!   meaningful only when the binary installed jemalloc as its global
+//!   allocator (`otter-cli --features jemalloc` enables both together).
+//! - Stats are process-wide, not per isolate: every runtime in the process
+//!   shares the global allocator.
+//! - mimalloc's statistics are reachable only through raw FFI, which the
+//!   workspace's `unsafe_code = "forbid"` rules out, so without
+//!   `jemalloc-stats` [`allocator_stats`] returns `None`.
+
+use serde::Serialize;
+
+/// Process-wide allocator counters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct AllocatorStats {
+    /// Bytes currently allocated by the application.
+    pub allocated_bytes: u64,
+    /// Bytes in physically resident pages mapped by the allocator, including
+    /// its own metadata and unused capacity.
+    pub resident_bytes: u64,
+}
+
+/// Read fresh allocator counters, or `None` when the active allocator has no
+/// safe statistics API (see the module invariants).
+#[must_use]
+pub fn allocator_stats() -> Option<AllocatorStats> {
+    #[cfg(feature = "jemalloc-stats")]
+    {
+        use tikv_jemalloc_ctl::{epoch, stats};
+        // jemalloc caches its counters; advancing the epoch refreshes them.
+        epoch::advance().ok()?;
+        Some(AllocatorStats {
+            allocated_bytes: stats::allocated::read().ok()? as u64,
+            resident_bytes: stats::resident::read().ok()? as u64,
+        })
+    }
+    #[cfg(not(feature = "jemalloc-stats"))]
+    {
+        None
+    }
+}
@@ -0,0 +1,256 @@
+//! Pluggable time source and a clock-driven timer scheduler.
+//!
+//! Layer A embedders own their event loop, so they also own the clock
+//! that decides when a `setTimeout` deadline has elapsed. This module
+//! gives them a ready-made deadline heap behind the
+//! [`otter_vm::TimerScheduler`] boundary that reads time through a
+//! [`Clock`] instead of sleeping. Tests swap in a [`ManualClock`] and
+//! step virtual time forward; no timer ever waits on the wall clock.
+//!
+//! # Contents
+//!
+//! - [`Clock`] — monotonic time source trait.
+//! - [`SystemClock`] — real-time default backed by [`Instant`].
+//! - [`ManualClock`] — virtual time that only moves on
+//!   [`ManualClock::advance`].
+//! - [`ClockTimerScheduler`] — deadline heap that fires due timers
+//!   synchronously through [`Runtime::fire_timer`].
+//!
+//! # Invariants
+//!
+//! - Due timers fire in `(deadline, schedule order)` order, matching
+//!   HTML's "timers with equal timeouts run in the order they were
+//!   started".
+//! - While a callback runs, "now" is that timer's deadline, so a timer
+//!   scheduled from inside a callback is ordered against the other due
+//!   timers as if the clock had stepped to each deadline in turn.
+//! - The scheduler never holds its lock across [`Runtime::fire_timer`];
+//!   callbacks re-enter [`otter_vm::TimerScheduler::schedule`] and
+//!   [`otter_vm::TimerScheduler::cancel`] freely.
+//! - Only timers armed through an installed [`ClockTimerScheduler`] read
+//!   the clock. The built-in Tokio event loop behind
+//!   [`crate::RuntimeHandle`] still sleeps on `tokio::time`, so its idle
+//!   waits are real time, and `performance.now()` / `Date.now()` read the
+//!   system clock; advancing a [`ManualClock`] moves none of them.
+//!
+//! # See also
+//!
+//! - [Event loop](../../../docs/book/src/engine/event-loop.md)
+//! - [HTML timers](https://html.spec.whatwg.org/multipage/timers-and-user-prompts.html#timers)
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::{OtterError, Runtime, TimerFireOutcome};
+
+/// Monotonic time source read by [`ClockTimerScheduler`].
+///
+/// `now` is an offset from an arbitrary, fixed epoch chosen by the
+/// implementation. It must never move backwards.
+pub trait Clock: Send + Sync + 'static {
+    /// Time elapsed since this clock's epoch.
+    fn now(&self) -> Duration;
+}
+
+/// Real-time clock; the epoch is the moment it was created.
+#[derive(Debug, Clone, Copy)]
+pub struct SystemClock {
+    epoch: Instant,
+}
+
+impl SystemClock {
+    /// Create a clock whose epoch is now.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            epoch: Instant::now(),
+        }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.epoch.elapsed()
+    }
+}
+
+/// Virtual clock for deterministic tests. Starts at zero and only moves
+/// when [`Self::advance`] is called.
+#[derive(Debug, Default)]
+pub struct ManualClock {
+    now: Mutex<Duration>,
+}
+
+impl ManualClock {
+    /// Create a clock at virtual time zero.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Move virtual time forward by `by`. Timers are not fired here;
+    /// call [`ClockTimerScheduler::run_due`] to deliver them.
+    pub fn advance(&self, by: Duration) {
+        let mut now = self.now.lock().expect("manual clock poisoned");
+        *now = now.saturating_add(by);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        *self.now.lock().expect("manual clock poisoned")
+    }
+}
+
+/// One armed deadline. Ordered by `(deadline, seq)` so equal deadlines
+/// fire in schedule order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Deadline {
+    at: Duration,
+    seq: u64,
+    token: u64,
+    repeat: Option<Duration>,
+}
+
+#[derive(Debug, Default)]
+struct SchedulerState {
+    heap: BinaryHeap<Reverse<Deadline>>,
+    next_token: u64,
+    next_seq: u64,
+    /// Deadline of the timer whose callback is running, and whether the
+    /// callback cancelled its own token.
+    in_flight: Option<(Deadline, bool)>,
+}
+
+impl SchedulerState {
+    fn arm(&mut self, token: u64, at: Duration, repeat: Option<Duration>) {
+        self.next_seq += 1;
+        self.heap.push(Reverse(Deadline {
+            at,
+            seq: self.next_seq,
+            token,
+            repeat,
+        }));
+    }
+}
+
+/// Deadline heap that implements [`otter_vm::TimerScheduler`] against a
+/// [`Clock`].
+///
+/// Install it with [`Runtime::install_timer_scheduler`], then call
+/// [`Self::run_due`] whenever the embedder's loop wakes (or, in tests,
+/// after [`ManualClock::advance`]). Repeating timers are re-armed at
+/// `deadline + period` after each fire.
+pub struct ClockTimerScheduler {
+    clock: Arc<dyn Clock>,
+    state: Mutex<SchedulerState>,
+}
+
+impl std::fmt::Debug for ClockTimerScheduler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.state.lock().expect("timer heap poisoned");
+        f.debug_struct("ClockTimerScheduler")
+            .field("pending", &state.heap.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl ClockTimerScheduler {
+    /// Create an empty scheduler reading time from `clock`.
+    #[must_use]
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            clock,
+            state: Mutex::new(SchedulerState::default()),
+        }
+    }
+
+    /// Earliest armed deadline, as an offset on the scheduler's clock.
+    /// Event-loop embedders sleep until this instant.
+    #[must_use]
+    pub fn next_deadline(&self) -> Option<Duration> {
+        let state = self.state.lock().expect("timer heap poisoned");
+        state.heap.peek().map(|Reverse(deadline)| deadline.at)
+    }
+
+    /// Fire every timer whose deadline is at or before the clock's
+    /// current time, in deadline order, and return how many callbacks
+    /// ran. Timers that callbacks schedule are fired in the same call
+    /// when they also fall due.
+    ///
+    /// # Errors
+    /// Returns the first [`OtterError`] a callback raises. The failing
+    /// timer is consumed; later due timers stay armed for the next call.
+    pub fn run_due(&self, runtime: &mut Runtime) -> Result<usize, OtterError> {
+        let now = self.clock.now();
+        let mut fired = 0;
+        loop {
+            let deadline = {
+                let mut state = self.state.lock().expect("timer heap poisoned");
+                match state.heap.peek() {
+                    Some(Reverse(deadline)) if deadline.at <= now => {}
+                    _ => return Ok(fired),
+                }
+                let Reverse(deadline) = state.heap.pop().expect("peeked deadline");
+                state.in_flight = Some((deadline, false));
+                deadline
+            };
+            let outcome = runtime.fire_timer(deadline.token);
+            let mut state = self.state.lock().expect("timer heap poisoned");
+            let cancelled = state
+                .in_flight
+                .take()
+                .is_some_and(|(_, cancelled)| cancelled);
+            match outcome? {
+                TimerFireOutcome::Missing => {}
+                TimerFireOutcome::Fired { repeat } => {
+                    fired += 1;
+                    if repeat
+                        && !cancelled
+                        && let Some(period) = deadline.repeat
+                    {
+                        state.arm(deadline.token, deadline.at + period, Some(period));
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl otter_vm::TimerScheduler for ClockTimerScheduler {
+    fn schedule(&self, delay_ms: u64, repeat_ms: Option<u64>) -> u64 {
+        let mut state = self.state.lock().expect("timer heap poisoned");
+        let now = match state.in_flight {
+            Some((deadline, _)) => deadline.at,
+            None => self.clock.now(),
+        };
+        state.next_token += 1;
+        let token = state.next_token;
+        let repeat = repeat_ms.map(|period| Duration::from_millis(period.max(1)));
+        state.arm(token, now + Duration::from_millis(delay_ms), repeat);
+        token
+    }
+
+    fn cancel(&self, token: u64) -> bool {
+        let mut state = self.state.lock().expect("timer heap poisoned");
+        if let Some((deadline, cancelled)) = &mut state.in_flight
+            && deadline.token == token
+        {
+            *cancelled = true;
+            return true;
+        }
+        let before = state.heap.len();
+        state
+            .heap
+            .retain(|Reverse(deadline)| deadline.token != token);
+        state.heap.len() != before
+    }
+}
@@ -233,6 +233,18 @@ pub enum ConfigError {
         /// Detail.
         message: String,
     },
+    /// A host configuration file failed to parse or validate.
+    #[error("invalid config file {}:{line}:{column}: {message}", path.display())]
+    InvalidConfigFile {
+        /// Config file path as given by the host.
+        path: PathBuf,
+        /// 1-based line of the offending key or syntax error.
+        line: usize,
+        /// 1-based column of the offending key or syntax error.
+        column: usize,
+        /// Detail.
+        message: String,
+    },
 }
 
 /// Mapped subset of [`std::io::ErrorKind`] used by
@@ -1975,7 +1975,7 @@ impl IsolateRunner {
             match self.poll_one_tick() {
                 TickOutcome::Processed => {}
                 TickOutcome::Shutdown => return,
-                TickOutcome::Idle => match self.rx.recv() {
+                TickOutcome::Idle => match self.recv_parked() {
                     Ok(msg) => {
                         if matches!(self.process_message(msg), TickOutcome::Shutdown) {
                             return;
@@ -1987,6 +1987,14 @@ impl IsolateRunner {
         }
     }
 
+    /// Block on the inbox, reporting the wait to the CPU profiler as a park.
+    fn recv_parked(&mut self) -> Result<RuntimeMessage, std::sync::mpsc::RecvError> {
+        let parked_at = std::time::Instant::now();
+        let msg = self.rx.recv();
+        self.runtime.record_cpu_park(parked_at);
+        msg
+    }
+
     fn shutdown(&mut self) {
         self.shutdown = true;
         self.counters.shutdown.store(true, Ordering::Relaxed);
@@ -2311,7 +2319,7 @@ impl IsolateRunner {
             // Block on the next inbox item. A later public command is deferred
             // until this command's Ref'd work finishes: recursively running it
             // would interleave isolate state and diagnostics batches.
-            let msg = match self.rx.recv() {
+            let msg = match self.recv_parked() {
                 Ok(msg) => msg,
                 Err(_) => return initial,
             };
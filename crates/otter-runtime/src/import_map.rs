@@ -0,0 +1,304 @@
+//! WHATWG import maps for the module loader.
+//!
+//! An import map rewrites module specifiers before the loader's own
+//! resolution runs. `imports` holds the top-level mappings; `scopes` holds
+//! per-URL-prefix overrides that apply only to importers under that prefix.
+//! A key ending in `/` remaps every specifier that starts with it, so
+//! `"lib/": "/vendor/lib/"` turns `lib/a.js` into `/vendor/lib/a.js`.
+//!
+//! # Contents
+//! - [`ImportMap`] — parsed, normalized map plus the resolution algorithm.
+//! - [`ImportMapError`] — parse failures and blocked resolutions.
+//!
+//! # Invariants
+//! - Keys and addresses are normalized once at parse time. URL-like keys
+//!   (`/`, `./`, `../`, or an absolute URL) are stored as serialized URLs;
+//!   every other key is a bare specifier and compared verbatim.
+//! - Each specifier map and the scope list are sorted by descending code
+//!   unit order, so the first matching prefix is the longest one.
+//! - Scopes are tried from the most specific prefix outward, then the
+//!   top-level `imports`. A scope that matches the importer but has no entry
+//!   for the specifier falls through instead of failing.
+//! - An invalid address is kept as a blocking entry: a specifier that hits
+//!   it fails to resolve rather than falling back to a less specific match.
+//!
+//! # See also
+//! - <https://html.spec.whatwg.org/multipage/webappapis.html#import-maps>
+//! - <https://html.spec.whatwg.org/multipage/webappapis.html#resolving-a-module-specifier>
+
+use serde_json::{Map, Value};
+use url::Url;
+
+/// One normalized specifier map: `(key, address)` pairs, longest key first.
+/// `None` marks an entry whose address failed to parse.
+type SpecifierMap = Vec<(String, Option<Url>)>;
+
+/// Parsed import map.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportMap {
+    imports: SpecifierMap,
+    scopes: Vec<(String, SpecifierMap)>,
+}
+
+/// Import-map parse or resolution failure.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ImportMapError {
+    /// The import map text is not JSON, or a section has the wrong shape.
+    #[error("invalid import map: {message}")]
+    Parse {
+        /// What was wrong with the document.
+        message: String,
+    },
+    /// The specifier matched an entry that cannot produce a URL: an invalid
+    /// address, or a prefix remap that would escape its target directory.
+    #[error("import map entry `{key}` blocks `{specifier}`: {message}")]
+    Blocked {
+        /// Specifier being resolved, after URL normalization.
+        specifier: String,
+        /// Matching import-map key.
+        key: String,
+        /// Why the entry could not be applied.
+        message: String,
+    },
+}
+
+impl ImportMap {
+    /// Parse import-map JSON. Relative keys, addresses and scope prefixes
+    /// resolve against `base_url` — normally the URL of the map file itself.
+    ///
+    /// Entries that the spec treats as warnings (empty keys, non-string
+    /// addresses, unparsable scope prefixes) are dropped or kept as blocking
+    /// entries rather than failing the whole map.
+    ///
+    /// # Errors
+    /// Returns [`ImportMapError::Parse`] when the text is not JSON, the top
+    /// level is not an object, or `imports` / `scopes` / a scope body is not
+    /// an object.
+    pub fn parse(text: &str, base_url: &Url) -> Result<Self, ImportMapError> {
+        let document: Value =
+            serde_json::from_str(text).map_err(|error| ImportMapError::Parse {
+                message: error.to_string(),
+            })?;
+        let Value::Object(document) = document else {
+            return Err(parse_error("the top-level value must be a JSON object"));
+        };
+        let imports = match document.get("imports") {
+            None => SpecifierMap::new(),
+            Some(Value::Object(map)) => normalize_specifier_map(map, base_url),
+            Some(_) => return Err(parse_error("`imports` must be a JSON object")),
+        };
+        let mut scopes = Vec::new();
+        match document.get("scopes") {
+            None => {}
+            Some(Value::Object(map)) => {
+                for (prefix, body) in map {
+                    let Value::Object(body) = body else {
+                        return Err(parse_error(format!(
+                            "scope `{prefix}` must map to a JSON object"
+                        )));
+                    };
+                    let Ok(prefix_url) = base_url.join(prefix) else {
+                        continue;
+                    };
+                    scopes.push((
+                        prefix_url.to_string(),
+                        normalize_specifier_map(body, base_url),
+                    ));
+                }
+            }
+            Some(_) => return Err(parse_error("`scopes` must be a JSON object")),
+        }
+        scopes.sort_by(|left, right| right.0.cmp(&left.0));
+        Ok(Self { imports, scopes })
+    }
+
+    /// `true` when the map has no top-level or scoped entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.imports.is_empty() && self.scopes.is_empty()
+    }
+
+    /// Resolve `specifier` imported from `referrer` through the map.
+    ///
+    /// Returns `Ok(None)` when no entry applies, so the caller continues
+    /// with its own resolution of the original specifier.
+    ///
+    /// # Errors
+    /// Returns [`ImportMapError::Blocked`] when the best matching entry has
+    /// an invalid address or a prefix remap would leave its target.
+    pub fn resolve(&self, specifier: &str, referrer: &Url) -> Result<Option<Url>, ImportMapError> {
+        let as_url = parse_url_like_specifier(specifier, referrer);
+        let normalized = as_url
+            .as_ref()
+            .map_or_else(|| specifier.to_string(), Url::to_string);
+        let referrer = referrer.as_str();
+        for (prefix, map) in &self.scopes {
+            let in_scope =
+                prefix == referrer || (prefix.ends_with('/') && referrer.starts_with(prefix));
+            if in_scope && let Some(url) = resolve_imports_match(&normalized, as_url.as_ref(), map)?
+            {
+                return Ok(Some(url));
+            }
+        }
+        resolve_imports_match(&normalized, as_url.as_ref(), &self.imports)
+    }
+}
+
+fn parse_error(message: impl Into<String>) -> ImportMapError {
+    ImportMapError::Parse {
+        message: message.into(),
+    }
+}
+
+/// `/`, `./` and `../` specifiers resolve against `base`; anything else is
+/// URL-like only if it parses as an absolute URL on its own.
+fn parse_url_like_specifier(specifier: &str, base: &Url) -> Option<Url> {
+    if specifier.starts_with('/') || specifier.starts_with("./") || specifier.starts_with("../") {
+        return base.join(specifier).ok();
+    }
+    Url::parse(specifier).ok()
+}
+
+fn normalize_specifier_map(map: &Map<String, Value>, base_url: &Url) -> SpecifierMap {
+    let mut normalized = SpecifierMap::new();
+    for (key, value) in map {
+        if key.is_empty() {
+            continue;
+        }
+        let key = parse_url_like_specifier(key, base_url)
+            .map_or_else(|| key.clone(), |url| url.to_string());
+        let address = value
+            .as_str()
+            .and_then(|address| parse_url_like_specifier(address, base_url))
+            .filter(|address| !key.ends_with('/') || address.as_str().ends_with('/'));
+        normalized.push((key, address));
+    }
+    normalized.sort_by(|left, right| right.0.cmp(&left.0));
+    normalized
+}
+
+fn resolve_imports_match(
+    normalized: &str,
+    as_url: Option<&Url>,
+    map: &SpecifierMap,
+) -> Result<Option<Url>, ImportMapError> {
+    let blocked = |key: &str, message: &str| ImportMapError::Blocked {
+        specifier: normalized.to_string(),
+        key: key.to_string(),
+        message: message.to_string(),
+    };
+    for (key, address) in map {
+        if key == normalized {
+            return address
+                .clone()
+                .map(Some)
+                .ok_or_else(|| blocked(key, "the mapped address is not a valid URL"));
+        }
+        // Prefix remaps only apply to bare specifiers and hierarchical URLs;
+        // `data:` or `blob:`-style URLs never have path segments to remap.
+        let prefix_applies = key.ends_with('/')
+            && normalized.starts_with(key.as_str())
+            && as_url.is_none_or(|url| !url.cannot_be_a_base());
+        if !prefix_applies {
+            continue;
+        }
+        let Some(address) = address else {
+            return Err(blocked(key, "the mapped address is not a valid URL"));
+        };
+        let after_prefix = &normalized[key.len()..];
+        let url = address
+            .join(after_prefix)
+            .map_err(|error| blocked(key, &error.to_string()))?;
+        if !url.as_str().starts_with(address.as_str()) {
+            return Err(blocked(key, "the remapped URL escapes the mapped prefix"));
+        }
+        return Ok(Some(url));
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base() -> Url {
+        Url::parse("file:///app/").expect("base url")
+    }
+
+    fn resolve(map: &ImportMap, specifier: &str, referrer: &str) -> Option<String> {
+        map.resolve(specifier, &Url::parse(referrer).expect("referrer"))
+            .expect("resolve")
+            .map(|url| url.to_string())
+    }
+
+    #[test]
+    fn longest_prefix_wins_and_exact_keys_beat_prefixes() {
+        let map = ImportMap::parse(
+            r#"{ "imports": {
+                "lib/": "./vendor/lib/",
+                "lib/util/": "./vendor/util/",
+                "lib/util/index.js": "./pinned.js"
+            } }"#,
+            &base(),
+        )
+        .expect("parse");
+        let referrer = "file:///app/main.js";
+        assert_eq!(
+            resolve(&map, "lib/a.js", referrer).as_deref(),
+            Some("file:///app/vendor/lib/a.js")
+        );
+        assert_eq!(
+            resolve(&map, "lib/util/x.js", referrer).as_deref(),
+            Some("file:///app/vendor/util/x.js")
+        );
+        assert_eq!(
+            resolve(&map, "lib/util/index.js", referrer).as_deref(),
+            Some("file:///app/pinned.js")
+        );
+        assert_eq!(resolve(&map, "other", referrer), None);
+    }
+
+    #[test]
+    fn url_like_keys_are_normalized_against_the_referrer() {
+        let map = ImportMap::parse(
+            r#"{ "imports": { "/app/shim.js": "./real-shim.js" } }"#,
+            &base(),
+        )
+        .expect("parse");
+        assert_eq!(
+            resolve(&map, "../shim.js", "file:///app/src/main.js").as_deref(),
+            Some("file:///app/real-shim.js")
+        );
+    }
+
+    #[test]
+    fn invalid_addresses_block_instead_of_falling_back() {
+        let map = ImportMap::parse(
+            r#"{ "imports": { "pkg/": "./vendor/pkg", "pkg/a.js": 1, "pkg/x/": "./vendor/" } }"#,
+            &base(),
+        )
+        .expect("parse");
+        let referrer = Url::parse("file:///app/main.js").unwrap();
+        // A prefix key whose address lacks the trailing slash is invalid.
+        assert!(matches!(
+            map.resolve("pkg/b.js", &referrer),
+            Err(ImportMapError::Blocked { .. })
+        ));
+        assert!(matches!(
+            map.resolve("pkg/a.js", &referrer),
+            Err(ImportMapError::Blocked { .. })
+        ));
+        assert!(matches!(
+            map.resolve("pkg/x/../../escape.js", &referrer),
+            Err(ImportMapError::Blocked { .. })
+        ));
+    }
+
+    #[test]
+    fn malformed_sections_are_parse_errors() {
+        assert!(ImportMap::parse("[]", &base()).is_err());
+        assert!(ImportMap::parse(r#"{ "imports": [] }"#, &base()).is_err());
+        assert!(ImportMap::parse(r#"{ "scopes": { "/a/": 1 } }"#, &base()).is_err());
+        assert!(ImportMap::parse("{}", &base()).expect("empty").is_empty());
+    }
+}
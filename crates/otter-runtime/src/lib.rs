@@ -59,8 +59,10 @@
 //! - [Engine architecture](../../../docs/book/src/engine/architecture.md)
 //! - [Event loop](../../../docs/book/src/engine/event-loop.md)
 
+pub mod allocator;
 mod commonjs;
 pub use commonjs::{require_commonjs_dependency, run_builtin_cjs_shim};
+mod clock;
 pub mod compiled_program;
 pub mod diagnostics;
 pub mod embedding;
@@ -68,6 +70,7 @@ pub mod error;
 mod event_loop;
 pub mod handle;
 pub mod hooks;
+pub mod import_map;
 pub mod module_graph;
 pub mod module_loader;
 mod module_records;
@@ -115,6 +118,7 @@ use otter_syntax::{SourceKind, SyntaxDiagnostic, SyntaxError, detect_source_kind
 use otter_vm::{EvalCompileOptions, ExecutionContext, Interpreter, InterruptFlag, NativeCallInfo};
 use serde::{Deserialize, Serialize};
 
+pub use clock::{Clock, ClockTimerScheduler, ManualClock, SystemClock};
 pub use compiled_program::CompiledProgram;
 pub use diagnostics::{Diagnostic, DiagnosticCategory, DiagnosticCode, DiagnosticKind, StackFrame};
 pub use error::{ConfigError, IoErrorKind, OtterError, RealmError};
@@ -131,8 +135,8 @@ pub use otter_compiler::{
     CompiledSourceSpan, LiveBindingSlot,
 };
 pub use otter_gc;
-pub use otter_vm::CpuProfile;
 pub use otter_vm::{ConsoleLevel, ConsoleSink, ConsoleSinkHandle, StdConsoleSink};
+pub use otter_vm::{CpuProfile, OpcodeHistogram, SamplingMode};
 pub use otter_vm::{
     ExecutionContext as RuntimeExecutionContext, PersistentRootId as RuntimePersistentRootId,
 };
@@ -146,13 +150,16 @@ pub use otter_vm::{
     JitRuntimeStats, RuntimeBudget, RuntimeBudgetExceededAction, RuntimeBudgetStats,
 };
 pub use otter_vm::{NativeCtx, NativeError, Value, marshal};
+pub use process_env::parse_env_file;
 pub use realm::{RuntimeExtensionContext, RuntimeGlobalValue, RuntimeRealmContext, RuntimeRealmId};
 // Embedder-driven event loop. `Runtime::install_timer_scheduler`,
 // `install_host_completion_sink`, and `install_dynamic_import_loader`
 // are public, so the types naming their arguments must be reachable
 // without a direct `otter-vm` dependency.
 pub use otter_vm::host_completion::{HostCompletionJob, HostCompletionSink, HostKeepAlive};
-pub use otter_vm::promise_rejection::{PromiseRejectionHook, PromiseRejectionHookHandle};
+pub use otter_vm::promise_rejection::{
+    PromiseRejectionHook, PromiseRejectionHookHandle, UnhandledRejectionMode,
+};
 pub use otter_vm::{
     DynamicImportLoader, DynamicImportLoaderHandle, TimerEntry, TimerScheduler,
     TimerSchedulerHandle,
@@ -174,8 +181,8 @@ pub use surface::{
     RuntimeNativeScope, RuntimeNumberValue, RuntimePropertySpec, RuntimeSurfaceError,
     RuntimeTracedHostObjectData, RuntimeValue, runtime_accessor, runtime_alloc_object,
     runtime_arg_to_string, runtime_array_from_elements, runtime_class, runtime_constant,
-    runtime_constructor, runtime_getter, runtime_method, runtime_method_with_attrs,
-    runtime_namespace, runtime_native_dynamic, runtime_native_static,
+    runtime_constructor, runtime_decode_utf8, runtime_getter, runtime_method,
+    runtime_method_with_attrs, runtime_namespace, runtime_native_dynamic, runtime_native_static,
     runtime_optional_arg_to_string, runtime_property, runtime_set_property, runtime_string_value,
     runtime_this_object, runtime_type_error, runtime_with_host_data, runtime_with_host_data_mut,
 };
@@ -1522,9 +1529,11 @@ pub(crate) struct RuntimeConfig {
     install_worker_global: bool,
     console_sink: ConsoleSinkHandle,
     promise_rejection_hook: Option<PromiseRejectionHookHandle>,
+    unhandled_rejection_mode: UnhandledRejectionMode,
     hooks: RuntimeHooks,
     process_argv: Vec<String>,
     process_cwd: PathBuf,
+    process_env_defaults: Vec<(String, String)>,
     tracer_factory: Option<TracerFactory>,
     jit_selection: JitSelection,
     jit_osr_threshold: Option<u32>,
@@ -1780,9 +1789,11 @@ impl Default for RuntimeConfig {
             install_worker_global: true,
             console_sink: otter_vm::console::default_console_sink(),
             promise_rejection_hook: None,
+            unhandled_rejection_mode: UnhandledRejectionMode::default(),
             hooks: RuntimeHooks::default(),
             process_argv: process::default_argv(),
             process_cwd: process::default_cwd(),
+            process_env_defaults: Vec::new(),
             tracer_factory: None,
             jit_selection: JitSelection::default(),
             jit_osr_threshold: None,
@@ -1976,6 +1987,10 @@ impl RuntimeBuilder {
     /// Register a declared extension: its native classes install
     /// eagerly (with their attached JS glue), and its JS half
     /// registers as one native lazy-global group.
+    ///
+    /// The JS half is compiled and evaluated only on first touch of one of
+    /// its names, so startup pays for the eager native classes alone; there
+    /// is no pre-compiled extension state to snapshot or restore.
     #[must_use]
     pub fn extension(mut self, extension: &'static Extension) -> Self {
         self.config.extensions.push(extension);
@@ -2055,6 +2070,21 @@ impl RuntimeBuilder {
         self
     }
 
+    /// Choose what happens to a Promise rejection that is still unhandled once
+    /// the microtask queue drains, like Node's `--unhandled-rejections`.
+    ///
+    /// `Strict` fails the running `eval` / script with the rejection reason,
+    /// `Throw` does the same unless the rejection is handled, `Warn` writes a
+    /// warning through the console sink, and `None` (the default) stays
+    /// silent. A [`Self::promise_rejection_hook`] is notified in every mode
+    /// but `Strict` and keeps `Throw` from raising; with the web globals, so
+    /// does an `unhandledrejection` handler that calls `preventDefault()`.
+    #[must_use]
+    pub fn unhandled_rejections(mut self, mode: UnhandledRejectionMode) -> Self {
+        self.config.unhandled_rejection_mode = mode;
+        self
+    }
+
     /// Replace the runtime hook set.
     #[must_use]
     pub fn hooks(mut self, hooks: RuntimeHooks) -> Self {
@@ -2118,6 +2148,23 @@ impl RuntimeBuilder {
         self
     }
 
+    /// Set fallback `process.env` entries, e.g. from [`parse_env_file`].
+    ///
+    /// A default applies only when the host environment has no variable of
+    /// that name, and it passes the same `RuntimeCapability::Env` check as
+    /// host variables. Later entries override earlier ones.
+    #[must_use]
+    pub fn process_env_defaults(
+        mut self,
+        vars: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>,
+    ) -> Self {
+        self.config.process_env_defaults = vars
+            .into_iter()
+            .map(|(name, value)| (name.into(), value.into()))
+            .collect();
+        self
+    }
+
     /// Install a per-instruction step-trace factory. The factory
     /// runs once on the isolate runner thread immediately after the
     /// interpreter is constructed; its produced tracer routes every
@@ -2250,6 +2297,7 @@ impl Runtime {
         if let Some(hook) = config.promise_rejection_hook.clone() {
             interp.set_promise_rejection_hook(hook);
         }
+        interp.set_unhandled_rejection_mode(config.unhandled_rejection_mode);
         let layer_a_dynamic_imports = LayerADynamicImportQueue::default();
         // Attached class glue and extension JS are deferred until the
         // runtime is fully assembled: the sources reference globals
@@ -2375,6 +2423,7 @@ impl Runtime {
                             &config.process_cwd,
                             &config.capabilities,
                             &config.hooks,
+                            &config.process_env_defaults,
                         )?;
                     }
                     // §19.4.1 / §20.2.1.1 — wire the eval hook so `eval(src)` /
@@ -3150,6 +3199,7 @@ impl Runtime {
         }
         self.register_resolved_exports(&linked.metadata);
         self.register_module_sources(&linked.module_sources);
+        self.report_module_aliases(&linked.aliases);
         let context = self.interp.link_module(linked.module);
         for init in context.module_inits() {
             if self.interp.module_env(&init.url).is_some() {
@@ -3647,25 +3697,29 @@ impl Runtime {
         let mut args: smallvec::SmallVec<[otter_vm::Value; 8]> =
             smallvec::SmallVec::with_capacity(entry.extra_args.len());
         args.extend(entry.extra_args);
-        self.interp
-            .run_callable_sync(
-                &context,
-                &entry.callback,
-                otter_vm::Value::undefined(),
-                args,
-            )
-            .map_err(|error| {
-                // Pair the `Copy` discriminant with the in-flight detail the
-                // same way the top-level run does. Without it the embedder
-                // gets a bare "uncaught exception" and cannot tell which
-                // timer callback failed or why.
-                let detail = self.interp.take_error_detail();
-                map_vm_error(otter_vm::RunError {
-                    error,
-                    frames: Vec::new(),
-                    detail,
-                })
-            })?;
+        // The callback runs in the AsyncContext mapping captured when
+        // the timer was scheduled; microtasks it queues capture their
+        // own mapping, so the drain below runs outside it.
+        self.interp.enter_async_context(entry.async_context);
+        let result = self.interp.run_callable_sync(
+            &context,
+            &entry.callback,
+            otter_vm::Value::undefined(),
+            args,
+        );
+        self.interp.exit_async_context();
+        result.map_err(|error| {
+            // Pair the `Copy` discriminant with the in-flight detail the
+            // same way the top-level run does. Without it the embedder
+            // gets a bare "uncaught exception" and cannot tell which
+            // timer callback failed or why.
+            let detail = self.interp.take_error_detail();
+            map_vm_error(otter_vm::RunError {
+                error,
+                frames: Vec::new(),
+                detail,
+            })
+        })?;
         let outcome = self.interp.drain_microtasks(&context);
         match outcome {
             Ok(()) => Ok(TimerFireOutcome::Fired { repeat }),
@@ -3868,11 +3922,29 @@ impl Runtime {
         self.interp.take_jit_artifacts()
     }
 
-    /// Reset VM runtime budget/resource counters.
+    /// Reset VM runtime budget/resource counters, including the per-opcode
+    /// counters when they are enabled.
     pub fn reset_runtime_budget_stats(&mut self) {
         self.interp.reset_runtime_budget_stats();
     }
 
+    /// Start counting interpreted instructions per opcode.
+    pub fn enable_opcode_counters(&mut self) {
+        self.interp.enable_opcode_counters();
+    }
+
+    /// Stop counting interpreted instructions per opcode.
+    pub fn disable_opcode_counters(&mut self) {
+        self.interp.disable_opcode_counters();
+    }
+
+    /// Snapshot the per-opcode execution counts, most frequent first, or
+    /// `None` when counting is disabled.
+    #[must_use]
+    pub fn opcode_histogram(&self) -> Option<OpcodeHistogram> {
+        self.interp.opcode_histogram()
+    }
+
     /// Snapshot every property inline-cache site. See
     /// [`inspect::IcSiteSnapshot`].
     #[must_use]
@@ -3908,6 +3980,20 @@ impl Runtime {
         self.interp.enable_cpu_profiler(interval);
     }
 
+    /// Enable VM stack sampling in `mode`. [`SamplingMode::WallClock`] also
+    /// records idle samples while the isolate thread is parked in
+    /// `Atomics.wait` or waiting for host events; [`SamplingMode::OnCpu`]
+    /// samples only bytecode execution.
+    pub fn enable_cpu_profiler_with_mode(&mut self, interval: u64, mode: SamplingMode) {
+        self.interp.enable_cpu_profiler_with_mode(interval, mode);
+    }
+
+    /// Report a park of the isolate thread from `started` until now to the
+    /// CPU profiler.
+    pub(crate) fn record_cpu_park(&mut self, started: std::time::Instant) {
+        self.interp.record_cpu_park(started);
+    }
+
     /// Disable VM stack sampling without returning collected samples.
     pub fn disable_cpu_profiler(&mut self) {
         self.interp.disable_cpu_profiler();
@@ -4506,6 +4592,18 @@ impl Runtime {
         }
     }
 
+    /// Surface module aliasing found while building the graph as console
+    /// warnings; the graph already folded each alias onto one instance.
+    fn report_module_aliases(&self, aliases: &[module_graph::ModuleAlias]) {
+        if aliases.is_empty() {
+            return;
+        }
+        let sink = self.interp.console_sink();
+        for alias in aliases {
+            sink.write(ConsoleLevel::Warn, &[format!("Warning: {alias}")]);
+        }
+    }
+
     pub(crate) fn run_module_with_context(
         &mut self,
         entry_path: impl AsRef<Path>,
@@ -4564,6 +4662,7 @@ impl Runtime {
         // expose a resolution table without its target environment.
         self.register_resolved_exports(&linked.metadata);
         self.register_module_sources(&linked.module_sources);
+        self.report_module_aliases(&linked.aliases);
         self.module_records
             .for_each_record(realm_id, |url, _function_id| {
                 // Self-loop edge: <entry>'s referrer is the entry's URL
@@ -5318,6 +5417,14 @@ impl OtterBuilder {
         self
     }
 
+    /// Choose the unhandled-rejection policy. See
+    /// [`RuntimeBuilder::unhandled_rejections`].
+    #[must_use]
+    pub fn unhandled_rejections(mut self, mode: UnhandledRejectionMode) -> Self {
+        self.runtime = self.runtime.unhandled_rejections(mode);
+        self
+    }
+
     /// Set the `process.argv` snapshot installed into the runtime.
     #[must_use]
     pub fn process_argv(mut self, argv: impl IntoIterator<Item = impl Into<String>>) -> Self {
@@ -5332,6 +5439,17 @@ impl OtterBuilder {
         self
     }
 
+    /// Set fallback `process.env` entries. See
+    /// [`RuntimeBuilder::process_env_defaults`].
+    #[must_use]
+    pub fn process_env_defaults(
+        mut self,
+        vars: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>,
+    ) -> Self {
+        self.runtime = self.runtime.process_env_defaults(vars);
+        self
+    }
+
     /// Install a per-instruction step-trace factory. See
     /// [`RuntimeBuilder::tracer_factory`].
     #[must_use]
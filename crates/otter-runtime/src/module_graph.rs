@@ -28,11 +28,15 @@
 //! - `ModuleGraphBuilder -> ModuleGraph -> LinkedProgram` — transient graph
 //!   discovery followed by frozen linked output.
 //! - [`GraphError`] — distinct error enum for graph-build failures.
+//! - [`ModuleAlias`] — one physical module requested under two URLs.
 //!
 //! # Invariants
 //! - Module URLs are canonical absolute URLs supplied by the loader or
 //!   embedder (`file:`, `http:`, `https:`, or a registered host scheme).
-//! - Each module is parsed and compiled exactly once per run.
+//! - Each module is parsed and compiled exactly once per run. Local module
+//!   URLs are realpath-canonical, so a file reached through a symlinked
+//!   directory shares the node of its direct path; the second spelling is
+//!   reported as a [`ModuleAlias`] instead of becoming a duplicate instance.
 //! - Literal dynamic-import target failures are deferred into a synthetic
 //!   module init so the eventual `import()` rejects instead of failing the
 //!   entry graph.
@@ -46,7 +50,7 @@
 //!   approximate with post-order DFS + literal `import()`.
 
 use std::collections::{BTreeMap, HashMap, HashSet};
-use std::path::Path;
+use std::path::{Component, Path, PathBuf};
 use std::time::{Duration, Instant};
 
 use otter_bytecode::{
@@ -138,6 +142,34 @@ pub enum GraphError {
     },
 }
 
+/// One physical module that the graph reached under two different URLs.
+///
+/// Both requests share the node keyed by `canonical_url`; without
+/// canonicalisation each spelling would have instantiated its own copy and
+/// split any module-level state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleAlias {
+    /// Realpath-canonical URL the module is keyed by.
+    pub canonical_url: String,
+    /// URL spelling that first reached the module.
+    pub first_url: String,
+    /// Later, different spelling of the same module.
+    pub alias_url: String,
+    /// Module whose import used `alias_url`.
+    pub referrer_url: String,
+}
+
+impl std::fmt::Display for ModuleAlias {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "module `{}` imported from `{}` is the same file as `{}` (canonical `{}`); \
+             both specifiers share one module instance",
+            self.alias_url, self.referrer_url, self.first_url, self.canonical_url
+        )
+    }
+}
+
 /// One loaded + compiled module fragment, plus its resolved
 /// dependency edges.
 #[derive(Debug)]
@@ -166,6 +198,8 @@ struct ModuleGraph {
     /// module, forwarded to the interpreter so `Error.prototype.stack`
     /// and `util.getCallSites` can resolve frame spans to `(line, col)`.
     module_sources: BTreeMap<String, String>,
+    /// Aliased requests folded onto an existing node, in discovery order.
+    aliases: Vec<ModuleAlias>,
 }
 
 impl ModuleGraph {
@@ -193,6 +227,7 @@ impl ModuleGraph {
             entry_url: self.entry_url,
             metadata,
             module_sources: self.module_sources,
+            aliases: self.aliases,
         })
     }
 }
@@ -211,6 +246,9 @@ struct ModuleGraphBuilder<'a> {
     module_sources: BTreeMap<String, String>,
     timings: Option<ModulePhaseTimings>,
     interrupt: Option<otter_vm::InterruptFlag>,
+    /// `canonical URL → first requested spelling`, for alias detection.
+    spellings: HashMap<String, String>,
+    aliases: Vec<ModuleAlias>,
 }
 
 impl<'a> ModuleGraphBuilder<'a> {
@@ -229,9 +267,24 @@ impl<'a> ModuleGraphBuilder<'a> {
             module_sources: BTreeMap::new(),
             timings: None,
             interrupt: None,
+            spellings: HashMap::from([(entry_url.clone(), entry_url.clone())]),
+            aliases: Vec::new(),
         }
     }
 
+    /// Builder for an embedder-supplied entry. A `file://` entry URL is
+    /// canonicalised so the entry and a dependency importing it back share
+    /// one node; the supplied spelling is kept for alias reporting.
+    fn for_source_entry(
+        loader: &'a ModuleLoader,
+        entry: crate::module_loader::ResolvedSource,
+    ) -> Self {
+        let entry_url = canonical_entry_url(&entry.url);
+        let mut builder = Self::new(loader, entry_url.clone(), entry.kind, entry.text);
+        builder.spellings.insert(entry_url, entry.url);
+        builder
+    }
+
     fn new_profiled(
         loader: &'a ModuleLoader,
         entry_url: String,
@@ -249,6 +302,30 @@ impl<'a> ModuleGraphBuilder<'a> {
         self
     }
 
+    /// Record that `referrer_url` reached `canonical_url` as `requested_url`.
+    /// A second, different spelling of the same module becomes a
+    /// [`ModuleAlias`]; repeats of a known spelling are ignored.
+    fn record_spelling(&mut self, canonical_url: &str, requested_url: String, referrer_url: &str) {
+        let Some(first_url) = self.spellings.get(canonical_url) else {
+            self.spellings
+                .insert(canonical_url.to_string(), requested_url);
+            return;
+        };
+        if *first_url == requested_url
+            || self.aliases.iter().any(|alias| {
+                alias.canonical_url == canonical_url && alias.alias_url == requested_url
+            })
+        {
+            return;
+        }
+        self.aliases.push(ModuleAlias {
+            canonical_url: canonical_url.to_string(),
+            first_url: first_url.clone(),
+            alias_url: requested_url,
+            referrer_url: referrer_url.to_string(),
+        });
+    }
+
     fn check_interrupted(&self) -> Result<(), GraphError> {
         if self
             .interrupt
@@ -350,6 +427,7 @@ impl<'a> ModuleGraphBuilder<'a> {
                 entry_url: self.entry_url,
                 nodes: self.nodes,
                 module_sources: self.module_sources,
+                aliases: self.aliases,
             },
             self.timings,
         ))
@@ -438,6 +516,9 @@ impl<'a> ModuleGraphBuilder<'a> {
                     continue;
                 }
                 let requested_target = self.resolve(&request.specifier, Some(&url))?;
+                if let Some(spelling) = requested_file_spelling(&request.specifier, &url) {
+                    self.record_spelling(&requested_target, spelling, &url);
+                }
                 let (target, loaded) = if self.nodes.contains_key(&requested_target) {
                     (requested_target, None)
                 } else {
@@ -666,6 +747,49 @@ fn resolve_plain_relative_file(specifier: &str, referrer_url: &str) -> Option<St
     Some(format!("file://{}", canonical.display()))
 }
 
+/// The `file://` URL a path-shaped specifier names before symlinks are
+/// resolved, when it points straight at an existing file.
+///
+/// Extension-probed and bare specifiers return `None`: the loader picks
+/// their file, so there is no user-written spelling to compare against.
+fn requested_file_spelling(specifier: &str, referrer_url: &str) -> Option<String> {
+    let path = if let Some(absolute) = specifier.strip_prefix("file://") {
+        PathBuf::from(absolute)
+    } else if specifier.starts_with("./") || specifier.starts_with("../") {
+        Path::new(referrer_url.strip_prefix("file://")?)
+            .parent()?
+            .join(specifier)
+    } else if Path::new(specifier).is_absolute() {
+        PathBuf::from(specifier)
+    } else {
+        return None;
+    };
+    let mut lexical = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                lexical.pop();
+            }
+            other => lexical.push(other),
+        }
+    }
+    lexical
+        .is_file()
+        .then(|| format!("file://{}", lexical.display()))
+}
+
+/// Realpath-canonical form of an embedder-supplied `file://` URL. Other
+/// schemes, and files that do not exist on disk, are returned unchanged.
+fn canonical_entry_url(url: &str) -> String {
+    url.strip_prefix("file://")
+        .and_then(|path| std::fs::canonicalize(path).ok())
+        .map_or_else(
+            || url.to_string(),
+            |path| format!("file://{}", path.display()),
+        )
+}
+
 #[derive(Default)]
 struct ModuleRequestVisitor {
     out: Vec<ModuleRequest>,
@@ -1242,6 +1366,8 @@ pub struct LinkedProgram {
     /// module. The runtime registers these with the interpreter before
     /// evaluation so frame spans resolve to `(line, column)`.
     pub module_sources: BTreeMap<String, String>,
+    /// Requests that named an already-loaded module by a different URL.
+    pub aliases: Vec<ModuleAlias>,
 }
 
 /// Top-level entry: load the dependency graph rooted at `entry_path`,
@@ -1301,7 +1427,7 @@ pub fn load_program_source(
             message: format!("module entry URL must be absolute: {error}"),
         })
     })?;
-    let builder = ModuleGraphBuilder::new(loader, entry.url, entry.kind, entry.text);
+    let builder = ModuleGraphBuilder::for_source_entry(loader, entry);
     let (graph, _) = builder.build_with_timings()?;
     graph.link()
 }
@@ -1318,8 +1444,7 @@ pub(crate) fn load_program_source_interruptible(
             message: format!("module entry URL must be absolute: {error}"),
         })
     })?;
-    let builder = ModuleGraphBuilder::new(loader, entry.url, entry.kind, entry.text)
-        .with_interrupt(interrupt);
+    let builder = ModuleGraphBuilder::for_source_entry(loader, entry).with_interrupt(interrupt);
     let (graph, _) = builder.build_with_timings()?;
     graph.link()
 }
@@ -40,6 +40,7 @@ use std::sync::{Arc, RwLock};
 use otter_syntax::{SourceKind, remote_source_kind};
 use oxc_resolver::{ResolveOptions, Resolver, TsconfigDiscovery};
 
+use crate::import_map::ImportMap;
 use crate::package_graph_resolver;
 use crate::{CapabilityRequest, CapabilitySet, RuntimeCapability, RuntimeHooks};
 
@@ -535,6 +536,10 @@ pub struct LoaderConfig {
     pub hosted_specifiers: Vec<String>,
     /// Optional read-only installed package graph.
     pub package_graph: Option<LoaderPackageGraph>,
+    /// Optional WHATWG import map applied before any other resolution.
+    /// Scope prefixes are compared against canonical referrer URLs, so a
+    /// map built from a symlinked directory should use its real path.
+    pub import_map: Option<ImportMap>,
     /// Capability state used to gate privileged specifier shapes
     /// at resolve time. `http:` / `https:` specifiers consult
     /// `capabilities.net`; future remote-package work will
@@ -577,6 +582,7 @@ impl LoaderConfig {
             enable_node_modules: true,
             hosted_specifiers: Vec::new(),
             package_graph: None,
+            import_map: None,
             capabilities: CapabilitySet::sandbox(),
             capability_hooks: RuntimeHooks::default(),
         }
@@ -587,6 +593,9 @@ impl LoaderConfig {
 /// bare-specifier resolution.
 ///
 /// # Algorithm (resolve)
+/// 0. If an [`ImportMap`] is configured, rewrite the specifier through its
+///    scopes and top-level `imports`. A mapped result is resolved as the
+///    absolute URL it produced; an unmapped specifier continues unchanged.
 /// 1. If the specifier starts with `file://`, canonicalise the
 ///    path and return as-is.
 /// 2. If it starts with `./` or `../`, resolve against the
@@ -740,6 +749,58 @@ impl ModuleLoader {
         specifier: &str,
         referrer: Option<&str>,
         kind: ImportKind,
+    ) -> Result<String, LoaderError> {
+        if let Some(import_map) = &self.config.import_map
+            && let Some(mapped) = self.apply_import_map(import_map, specifier, referrer)?
+        {
+            return self.resolve_unmapped(&mapped, referrer, kind);
+        }
+        self.resolve_unmapped(specifier, referrer, kind)
+    }
+
+    /// Rewrite `specifier` through `import_map`. File URLs come back in the
+    /// loader's `file://<path>` shape so the regular `file://` branch
+    /// canonicalises them; other schemes are returned serialized.
+    fn apply_import_map(
+        &self,
+        import_map: &ImportMap,
+        specifier: &str,
+        referrer: Option<&str>,
+    ) -> Result<Option<String>, LoaderError> {
+        let resolve_error = |message: String| LoaderError::Resolve {
+            specifier: specifier.to_string(),
+            referrer: referrer.unwrap_or("<entry>").to_string(),
+            message,
+        };
+        let referrer_url = match referrer.and_then(|referrer| url::Url::parse(referrer).ok()) {
+            Some(url) => url,
+            None => url::Url::from_directory_path(&self.config.base_dir).map_err(|()| {
+                resolve_error(format!(
+                    "base directory `{}` is not an absolute path",
+                    self.config.base_dir.display()
+                ))
+            })?,
+        };
+        let Some(mapped) = import_map
+            .resolve(specifier, &referrer_url)
+            .map_err(|error| resolve_error(error.to_string()))?
+        else {
+            return Ok(None);
+        };
+        if mapped.scheme() != "file" {
+            return Ok(Some(mapped.to_string()));
+        }
+        let path = mapped.to_file_path().map_err(|()| {
+            resolve_error(format!("import map produced invalid file URL `{mapped}`"))
+        })?;
+        Ok(Some(format!("file://{}", path.display())))
+    }
+
+    fn resolve_unmapped(
+        &self,
+        specifier: &str,
+        referrer: Option<&str>,
+        kind: ImportKind,
     ) -> Result<String, LoaderError> {
         if self.is_hosted_url(specifier) {
             return Ok(specifier.to_string());
@@ -900,7 +961,7 @@ impl ModuleLoader {
                 referrer,
                 kind,
                 conditions,
-                e.to_string(),
+                disk_resolve_error_message(&e),
             )),
         }
     }
@@ -1082,6 +1143,19 @@ fn resolver_context_message(
     )
 }
 
+/// Render an `oxc_resolver` failure, prefixing the Node error code for an
+/// unmatched `#` specifier so tooling that keys on it keeps working.
+fn disk_resolve_error_message(error: &oxc_resolver::ResolveError) -> String {
+    if matches!(
+        error,
+        oxc_resolver::ResolveError::PackageImportNotDefined(..)
+    ) {
+        format!("ERR_PACKAGE_IMPORT_NOT_DEFINED: {error}")
+    } else {
+        error.to_string()
+    }
+}
+
 fn resolve_with_oxc(
     resolver: &Resolver,
     referrer_file: Option<&Path>,
@@ -1708,6 +1782,54 @@ mod tests {
         assert_eq!(loaded.jsx.as_deref(), Some("react-jsx"));
     }
 
+    #[test]
+    fn disk_package_imports_pick_per_kind_and_report_missing_alias() {
+        let dir = temp_dir();
+        std::fs::write(
+            dir.path().join("package.json"),
+            r##"{
+              "name": "app",
+              "imports": {
+                "#config": {
+                  "import": "./config.mjs",
+                  "require": "./config.cjs",
+                  "default": "./config.mjs"
+                }
+              }
+            }"##,
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("config.mjs"), "export let kind = 'esm';\n").unwrap();
+        std::fs::write(
+            dir.path().join("config.cjs"),
+            "module.exports = { kind: 'cjs' };\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("entry.ts"), "// entry\n").unwrap();
+        let loader = ModuleLoader::new(dir.path().to_path_buf());
+        let entry = loader.resolve("./entry.ts", None).unwrap();
+
+        let esm = loader
+            .resolve_with_kind("#config", Some(&entry), ImportKind::Esm)
+            .unwrap();
+        let cjs = loader
+            .resolve_with_kind("#config", Some(&entry), ImportKind::Cjs)
+            .unwrap();
+        assert!(esm.ends_with("config.mjs"), "esm got {esm}");
+        assert!(cjs.ends_with("config.cjs"), "cjs got {cjs}");
+
+        let err = loader
+            .resolve("#missing", Some(&entry))
+            .expect_err("unmapped package import must fail");
+        let LoaderError::Resolve { message, .. } = &err else {
+            panic!("expected a resolve error, got {err:?}");
+        };
+        assert!(
+            message.starts_with("ERR_PACKAGE_IMPORT_NOT_DEFINED"),
+            "got {message}"
+        );
+    }
+
     #[test]
     fn conditional_exports_pick_per_kind() {
         let dir = temp_dir();
@@ -1740,4 +1862,94 @@ mod tests {
         assert!(esm.ends_with("esm.js"), "esm got {esm}");
         assert!(cjs.ends_with("cjs.js"), "cjs got {cjs}");
     }
+
+    /// Loader rooted at a canonical temp dir with `map` parsed against it.
+    fn import_map_loader(root: &Path, map: &str) -> ModuleLoader {
+        let base = url::Url::from_directory_path(root).expect("base url");
+        let mut config = LoaderConfig::new(root.to_path_buf());
+        config.import_map = Some(ImportMap::parse(map, &base).expect("import map"));
+        ModuleLoader::with_config(config)
+    }
+
+    fn write_module(root: &Path, relative: &str) {
+        let path = root.join(relative);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, "export {};\n").unwrap();
+    }
+
+    #[test]
+    fn import_map_remaps_bare_specifier() {
+        let dir = temp_dir();
+        let root = dir.path().canonicalize().unwrap();
+        write_module(&root, "entry.js");
+        write_module(&root, "vendor/react.js");
+        let loader = import_map_loader(&root, r#"{ "imports": { "react": "./vendor/react.js" } }"#);
+        let entry = loader.resolve("./entry.js", None).unwrap();
+        let react = loader.resolve("react", Some(&entry)).unwrap();
+        assert_eq!(
+            react,
+            format!("file://{}", root.join("vendor/react.js").display())
+        );
+    }
+
+    #[test]
+    fn import_map_trailing_slash_remaps_prefix() {
+        let dir = temp_dir();
+        let root = dir.path().canonicalize().unwrap();
+        write_module(&root, "entry.js");
+        write_module(&root, "vendor/lib/a.js");
+        write_module(&root, "vendor/lib/nested/b.js");
+        let loader = import_map_loader(&root, r#"{ "imports": { "lib/": "./vendor/lib/" } }"#);
+        let entry = loader.resolve("./entry.js", None).unwrap();
+        assert_eq!(
+            loader.resolve("lib/a.js", Some(&entry)).unwrap(),
+            format!("file://{}", root.join("vendor/lib/a.js").display())
+        );
+        assert_eq!(
+            loader.resolve("lib/nested/b.js", Some(&entry)).unwrap(),
+            format!("file://{}", root.join("vendor/lib/nested/b.js").display())
+        );
+        // Without the trailing slash the bare `lib` itself is not mapped.
+        assert!(loader.resolve("lib", Some(&entry)).is_err());
+    }
+
+    #[test]
+    fn import_map_scope_overrides_only_under_its_prefix() {
+        let dir = temp_dir();
+        let root = dir.path().canonicalize().unwrap();
+        write_module(&root, "entry.js");
+        write_module(&root, "legacy/entry.js");
+        write_module(&root, "legacy/deep/entry.js");
+        write_module(&root, "vendor/util-v2.js");
+        write_module(&root, "vendor/util-v1.js");
+        write_module(&root, "vendor/helper.js");
+        let loader = import_map_loader(
+            &root,
+            r#"{
+              "imports": { "util": "./vendor/util-v2.js", "helper": "./vendor/helper.js" },
+              "scopes": { "./legacy/": { "util": "./vendor/util-v1.js" } }
+            }"#,
+        );
+        let url_of = |relative: &str| format!("file://{}", root.join(relative).display());
+        let entry = loader.resolve("./entry.js", None).unwrap();
+        let legacy = loader.resolve("./legacy/entry.js", None).unwrap();
+        let deep = loader.resolve("./legacy/deep/entry.js", None).unwrap();
+        assert_eq!(
+            loader.resolve("util", Some(&entry)).unwrap(),
+            url_of("vendor/util-v2.js")
+        );
+        assert_eq!(
+            loader.resolve("util", Some(&legacy)).unwrap(),
+            url_of("vendor/util-v1.js")
+        );
+        assert_eq!(
+            loader.resolve("util", Some(&deep)).unwrap(),
+            url_of("vendor/util-v1.js")
+        );
+        // A scope without the key falls back to the top-level `imports`.
+        assert_eq!(
+            loader.resolve("helper", Some(&legacy)).unwrap(),
+            url_of("vendor/helper.js")
+        );
+    }
 }
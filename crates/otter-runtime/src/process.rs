@@ -45,6 +45,7 @@ pub(crate) fn install_global(
     process_cwd: &Path,
     capabilities: &CapabilitySet,
     hooks: &RuntimeHooks,
+    env_defaults: &[(String, String)],
 ) -> Result<(), OtterError> {
     let snapshot = runtime_process_snapshot();
     let uptime_base_secs = snapshot.run_time_secs;
@@ -131,7 +132,7 @@ pub(crate) fn install_global(
                 let undefined = scope.undefined();
                 scope.set(process, "exitCode", undefined)?;
 
-                let env = crate::process_env::build(&mut scope, capabilities, hooks)?;
+                let env = crate::process_env::build(&mut scope, capabilities, hooks, env_defaults)?;
                 scope.set(process, "env", env)?;
                 let allowed_flags = crate::process_flags::build(&mut scope)?;
                 scope.set(process, "allowedNodeEnvironmentFlags", allowed_flags)?;
@@ -898,6 +899,69 @@ mod tests {
         assert_eq!(result.completion_string(), "string:undefined");
     }
 
+    #[test]
+    fn process_env_defaults_fill_gaps_without_shadowing_host() {
+        if std::env::var_os("PATH").is_none() {
+            return;
+        }
+        let otter = Otter::builder()
+            .capabilities(CapabilitySet::allow_all())
+            .process_env_defaults([
+                ("OTTER_ENV_DEFAULT_ONLY", "first"),
+                ("PATH", "shadowed"),
+                ("OTTER_ENV_DEFAULT_ONLY", "second"),
+                ("OPENAI_API_KEY", "leaked"),
+            ])
+            .build()
+            .unwrap();
+        let result = otter
+            .blocking_run_script(
+                "[process.env.OTTER_ENV_DEFAULT_ONLY, process.env.PATH === 'shadowed', \
+                 typeof process.env.OPENAI_API_KEY].join(':')",
+            )
+            .unwrap();
+        assert_eq!(result.completion_string(), "second:false:undefined");
+    }
+
+    #[test]
+    fn process_env_defaults_respect_deny_by_default() {
+        let otter = Otter::builder()
+            .process_env_defaults([("OTTER_ENV_DEFAULT_ONLY", "value")])
+            .build()
+            .unwrap();
+        let result = otter
+            .blocking_run_script("typeof process.env.OTTER_ENV_DEFAULT_ONLY")
+            .unwrap();
+        assert_eq!(result.completion_string(), "undefined");
+    }
+
+    #[test]
+    fn parse_env_file_handles_dotenv_syntax() {
+        let entries = crate::parse_env_file(
+            "# comment\n\
+             export PLAIN = value # trailing\n\
+             SINGLE='raw\\n # kept'\n\
+             DOUBLE=\"line\\nbreak\"\n\
+             MULTI=\"a\nb\"\n\
+             not a pair\n\
+             EMPTY=\n",
+        );
+        let pairs: Vec<(&str, &str)> = entries
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect();
+        assert_eq!(
+            pairs,
+            [
+                ("PLAIN", "value"),
+                ("SINGLE", "raw\\n # kept"),
+                ("DOUBLE", "line\nbreak"),
+                ("MULTI", "a\nb"),
+                ("EMPTY", ""),
+            ]
+        );
+    }
+
     #[test]
     fn process_env_coerces_values_and_deletes_properties() {
         let otter = Otter::new();
@@ -1108,6 +1172,38 @@ const diff = process.hrtime(previous);
         assert_eq!(result.completion_string(), "2:number:number");
     }
 
+    #[test]
+    fn process_hrtime_is_monotonic_with_a_small_positive_delta() {
+        let otter = Otter::new();
+        let result = otter
+            .blocking_run_script(
+                r#"
+const samples = [];
+for (let i = 0; i < 64; i++) samples.push(process.hrtime.bigint());
+const monotonic = samples.every((value, i) => i === 0 || value >= samples[i - 1]);
+const first = samples[0];
+let last = first;
+while (last === first) last = process.hrtime.bigint();
+const previous = process.hrtime();
+for (let i = 0; i < 1000; i++) {}
+const [seconds, nanos] = process.hrtime(previous);
+const deltaNs = seconds * 1e9 + nanos;
+const [tupleSeconds, tupleNanos] = process.hrtime();
+const tupleNs = BigInt(tupleSeconds) * 1000000000n + BigInt(tupleNanos);
+[
+  monotonic,
+  last > first,
+  seconds === 0,
+  deltaNs > 0 && deltaNs < 1e9,
+  nanos < 1e9,
+  tupleNs >= first && tupleNs <= process.hrtime.bigint()
+].join(":")
+"#,
+            )
+            .unwrap();
+        assert_eq!(result.completion_string(), "true:true:true:true:true:true");
+    }
+
     #[test]
     fn process_hrtime_validates_previous_tuple() {
         let otter = Otter::new();
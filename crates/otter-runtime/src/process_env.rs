@@ -6,11 +6,13 @@
 //!
 //! # Contents
 //! - [`build`] filters the host snapshot and creates the proxy.
+//! - [`parse_env_file`] parses dotenv text into embedder-supplied defaults.
 //! - Proxy `set` coercion matching Node's string-valued environment surface.
 //! - Proxy `defineProperty` validation for Node's descriptor restrictions.
 //!
 //! # Invariants
 //! - Host values cross the boundary only after `RuntimeCapability::Env` checks.
+//!   Configured defaults pass the same check and never shadow a host variable.
 //! - Built-in secret-name filters cannot be overridden by custom hooks.
 //! - All JS values are built and mutated through [`NativeCtx`] handle scopes.
 //! - JS writes remain isolate-local and cannot mutate the host environment.
@@ -29,9 +31,15 @@ pub(crate) fn build<'s>(
     scope: &mut NativeScope<'s, '_>,
     capabilities: &CapabilitySet,
     hooks: &RuntimeHooks,
+    defaults: &[(String, String)],
 ) -> Result<Local<'s>, NativeError> {
     let target = scope.object()?;
-    for (name, value) in std::env::vars() {
+    let host: Vec<(String, String)> = std::env::vars().collect();
+    let missing_defaults = defaults
+        .iter()
+        .filter(|(name, _)| !host.iter().any(|(host_name, _)| host_name == name))
+        .cloned();
+    for (name, value) in host.iter().cloned().chain(missing_defaults) {
         if crate::hooks::check_capability_with_hooks(
             hooks,
             capabilities,
@@ -54,6 +62,72 @@ pub(crate) fn build<'s>(
     scope.proxy(target, handler)
 }
 
+/// Parse dotenv-format `text` into `(name, value)` pairs in file order.
+///
+/// Accepts `NAME=value` lines with an optional `export ` prefix, `#` comment
+/// lines, and unquoted values with trailing ` #` comments. Single- and
+/// backtick-quoted values are taken verbatim; double-quoted values expand
+/// `\n`. Quoted values may span lines. Malformed lines are skipped, as Node's
+/// `--env-file` does. A name repeated later in the text overrides the earlier
+/// pair when the result is applied in order.
+pub fn parse_env_file(text: &str) -> Vec<(String, String)> {
+    let mut entries = Vec::new();
+    let mut lines = text.lines();
+    while let Some(line) = lines.next() {
+        let line = line.trim_start();
+        let line = line.strip_prefix("export ").unwrap_or(line).trim_start();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((name, value)) = line.split_once('=') else {
+            continue;
+        };
+        let name = name.trim();
+        if name.is_empty() {
+            continue;
+        }
+        let value = value.trim_start();
+        let value = match value.chars().next() {
+            Some(quote @ ('"' | '\'' | '`')) => {
+                // Scan ahead for the closing quote; an unterminated value is
+                // kept as the raw remainder of its own line.
+                let mut body = value[1..].to_string();
+                let mut lookahead = lines.clone();
+                let closed = loop {
+                    if let Some(end) = body.find(quote) {
+                        body.truncate(end);
+                        break true;
+                    }
+                    match lookahead.next() {
+                        Some(next) => {
+                            body.push('\n');
+                            body.push_str(next);
+                        }
+                        None => break false,
+                    }
+                };
+                if closed {
+                    lines = lookahead;
+                    if quote == '"' {
+                        body.replace("\\n", "\n")
+                    } else {
+                        body
+                    }
+                } else {
+                    value.trim_end().to_string()
+                }
+            }
+            _ => value
+                .split_once(" #")
+                .map_or(value, |(value, _)| value)
+                .trim_end()
+                .to_string(),
+        };
+        entries.push((name.to_string(), value));
+    }
+    entries
+}
+
 fn coded_type_error(code: &'static str, message: impl Into<String>) -> NativeError {
     NativeError::Coded {
         kind: ErrorKind::TypeError,
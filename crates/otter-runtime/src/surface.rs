@@ -152,6 +152,58 @@ pub fn runtime_string_value(
     ))
 }
 
+/// Private `(bytes, fatal, stream) => [text, consumed] | null` native shared
+/// by the host text decoders (`TextDecoder`, `StringDecoder`, `Buffer`).
+///
+/// Decodes through [`otter_vm::string::utf8::decode_utf8`]. Returns `null`
+/// when `fatal` is set and the input is invalid, so each shim can raise its
+/// own error shape. A detached buffer decodes as empty input.
+///
+/// # Errors
+/// Raises a `TypeError` when `bytes` is not a `BufferSource`.
+pub fn runtime_decode_utf8(
+    ctx: &mut RuntimeNativeCtx<'_>,
+    args: &[RuntimeValue],
+) -> Result<RuntimeValue, RuntimeNativeError> {
+    use otter_vm::string::utf8::decode_utf8;
+
+    let flag = |index: usize| {
+        args.get(index)
+            .and_then(|value| value.as_boolean())
+            .unwrap_or(false)
+    };
+    let (fatal, stream) = (flag(1), flag(2));
+    let data = args
+        .first()
+        .copied()
+        .unwrap_or_else(RuntimeValue::undefined);
+    let decoded = if let Some(ta) = data.as_typed_array(ctx.heap()) {
+        let offset = ta.byte_offset(ctx.heap());
+        let length = ta.byte_length(ctx.heap());
+        ta.buffer(ctx.heap()).with_bytes(ctx.heap(), |bytes| {
+            bytes
+                .get(offset..offset + length)
+                .map(|bytes| decode_utf8(bytes, fatal, stream))
+        })
+    } else if let Some(buffer) = data.as_array_buffer() {
+        Some(buffer.with_bytes(ctx.heap(), |bytes| decode_utf8(bytes, fatal, stream)))
+    } else {
+        return Err(runtime_type_error(
+            "decodeUtf8",
+            "input must be an ArrayBuffer or ArrayBufferView",
+        ));
+    };
+    let Ok(decoded) = decoded.unwrap_or_else(|| decode_utf8(&[], fatal, stream)) else {
+        return Ok(RuntimeValue::null());
+    };
+    let text = runtime_string_value(ctx, &decoded.text)?;
+    let consumed = RuntimeValue::number(RuntimeNumberValue::from_f64(decoded.consumed as f64));
+    let array = ctx
+        .array_from_elements([text, consumed])
+        .map_err(|err| runtime_type_error("decodeUtf8", err.to_string()))?;
+    Ok(RuntimeValue::array(array))
+}
+
 /// Return the current receiver as an object or raise a runtime type error.
 pub fn runtime_this_object(
     ctx: &RuntimeNativeCtx<'_>,
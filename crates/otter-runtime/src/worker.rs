@@ -97,9 +97,104 @@ enum WorkerEvent {
     Message(WorkerPayload),
     Error(String),
     MessageError(String),
+    Output(WorkerStdio, String),
     Closed,
 }
 
+/// Worker console stream piped to the parent by `{ stdout: true }` /
+/// `{ stderr: true }`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WorkerStdio {
+    Stdout,
+    Stderr,
+}
+
+impl WorkerStdio {
+    const fn property(self) -> &'static str {
+        match self {
+            Self::Stdout => "stdout",
+            Self::Stderr => "stderr",
+        }
+    }
+}
+
+/// How a spawned worker finds its entry script.
+enum WorkerEntry {
+    /// File path or module specifier.
+    Specifier(String),
+    /// Inline script source from `{ eval: true }`. Never resolved as a
+    /// path, even when it looks like one.
+    Source(String),
+}
+
+/// Options bag accepted by `new Worker(entry, options)`.
+#[derive(Debug, Default, Clone, Copy)]
+struct WorkerSpawnOptions {
+    eval: bool,
+    stdout: bool,
+    stderr: bool,
+}
+
+impl WorkerSpawnOptions {
+    fn parse(ctx: &NativeCtx<'_>, value: Option<&Value>) -> Self {
+        let Some(options) = value.and_then(|value| value.as_object()) else {
+            return Self::default();
+        };
+        let flag = |name: &str| {
+            object::get(options, ctx.heap(), name).is_some_and(|value| value.to_boolean(ctx.heap()))
+        };
+        Self {
+            eval: flag("eval"),
+            stdout: flag("stdout"),
+            stderr: flag("stderr"),
+        }
+    }
+
+    fn entry(self, entry: String) -> WorkerEntry {
+        if self.eval {
+            WorkerEntry::Source(entry)
+        } else {
+            WorkerEntry::Specifier(entry)
+        }
+    }
+}
+
+/// Console sink for a worker whose stdout and/or stderr is piped to the
+/// parent. Levels on a stream that is not piped still reach `fallback`.
+#[derive(Debug)]
+struct WorkerConsoleSink {
+    tx: mpsc::Sender<WorkerEvent>,
+    stdout: bool,
+    stderr: bool,
+    fallback: otter_vm::ConsoleSinkHandle,
+}
+
+impl otter_vm::ConsoleSink for WorkerConsoleSink {
+    fn write(&self, level: otter_vm::ConsoleLevel, fields: &[String]) {
+        let stream = match level {
+            otter_vm::ConsoleLevel::Log
+            | otter_vm::ConsoleLevel::Info
+            | otter_vm::ConsoleLevel::Debug => WorkerStdio::Stdout,
+            otter_vm::ConsoleLevel::Warn
+            | otter_vm::ConsoleLevel::Error
+            | otter_vm::ConsoleLevel::Trace
+            | otter_vm::ConsoleLevel::Assert => WorkerStdio::Stderr,
+        };
+        let piped = match stream {
+            WorkerStdio::Stdout => self.stdout,
+            WorkerStdio::Stderr => self.stderr,
+        };
+        if !piped {
+            self.fallback.write(level, fields);
+            return;
+        }
+        let mut line = fields.join(" ");
+        line.push('\n');
+        // A closed parent channel means nobody is listening anymore.
+        let _ = self.tx.send(WorkerEvent::Output(stream, line));
+    }
+}
+
 const WORKER_COMMAND_POLL_INTERVAL: Duration = Duration::from_millis(10);
 
 struct WorkerRecord {
@@ -232,8 +327,9 @@ fn install_worker_host_natives(
 
 fn worker_constructor_call(host: Arc<WorkerHostState>) -> NativeCall {
     let call: Arc<NativeFn> = Arc::new(move |ctx, args, _captures| {
-        let specifier = value_to_string(ctx, args.first().unwrap_or(&Value::undefined()))?;
-        let id = spawn_worker_record(&host, specifier)?;
+        let entry = value_to_string(ctx, args.first().unwrap_or(&Value::undefined()))?;
+        let options = WorkerSpawnOptions::parse(ctx, args.get(1));
+        let id = spawn_worker_record(&host, options.entry(entry), options)?;
         let post_host = host.clone();
         let post: Arc<NativeFn> = Arc::new(move |ctx, args, _captures| {
             let id = worker_id_from_this(ctx, "Worker.postMessage")?;
@@ -330,16 +426,39 @@ fn worker_constructor_call(host: Arc<WorkerHostState>) -> NativeCall {
             let listeners = scope.object()?;
             scope.set(worker, "__otterListeners", listeners)?;
 
+            let add_fn = scope.native_call("addEventListener", 2, NativeCall::Dynamic(add))?;
+            let remove_fn =
+                scope.native_call("removeEventListener", 2, NativeCall::Dynamic(remove))?;
             for (name, length, call) in [
                 ("postMessage", 1, NativeCall::Dynamic(post)),
                 ("terminate", 0, NativeCall::Dynamic(terminate)),
                 ("dispatchEvent", 1, NativeCall::Dynamic(dispatch)),
-                ("addEventListener", 2, NativeCall::Dynamic(add)),
-                ("removeEventListener", 2, NativeCall::Dynamic(remove)),
             ] {
                 let function = scope.native_call(name, length, call)?;
                 scope.set(worker, name, function)?;
             }
+            scope.set(worker, "addEventListener", add_fn)?;
+            scope.set(worker, "removeEventListener", remove_fn)?;
+
+            // Piped stdio streams reuse the worker's listener natives; they
+            // only read `this`, so each stream keeps its own listener store.
+            for (stream, piped) in [
+                (WorkerStdio::Stdout, options.stdout),
+                (WorkerStdio::Stderr, options.stderr),
+            ] {
+                let value = if piped {
+                    let target = scope.object()?;
+                    scope.set(target, "ondata", null)?;
+                    let store = scope.object()?;
+                    scope.set(target, "__otterListeners", store)?;
+                    scope.set(target, "addEventListener", add_fn)?;
+                    scope.set(target, "removeEventListener", remove_fn)?;
+                    target
+                } else {
+                    null
+                };
+                scope.set(worker, stream.property(), value)?;
+            }
             Ok::<Value, NativeError>(scope.finish(worker))
         })?;
         install_worker_poll_timer(ctx, host.clone(), worker)
@@ -347,17 +466,29 @@ fn worker_constructor_call(host: Arc<WorkerHostState>) -> NativeCall {
     NativeCall::Dynamic(call)
 }
 
-fn spawn_worker_record(host: &Arc<WorkerHostState>, specifier: String) -> Result<u64, NativeError> {
+fn spawn_worker_record(
+    host: &Arc<WorkerHostState>,
+    entry: WorkerEntry,
+    options: WorkerSpawnOptions,
+) -> Result<u64, NativeError> {
     let id = WorkerId(NEXT_WORKER_ID.fetch_add(1, Ordering::Relaxed));
     let (cmd_tx, cmd_rx) = mpsc::channel();
     let (event_tx, event_rx) = mpsc::channel();
-    let child_config = host.config.clone();
+    let mut child_config = host.config.clone();
+    if options.stdout || options.stderr {
+        child_config.console_sink = Arc::new(WorkerConsoleSink {
+            tx: event_tx.clone(),
+            stdout: options.stdout,
+            stderr: options.stderr,
+            fallback: child_config.console_sink.clone(),
+        });
+    }
     let (interrupt_tx, interrupt_rx) = mpsc::sync_channel(1);
     let thread_name = format!("otter-worker-{}", id.get());
     let join = thread::Builder::new()
         .name(thread_name)
         .spawn(move || {
-            run_js_worker(id, specifier, child_config, cmd_rx, event_tx, interrupt_tx);
+            run_js_worker(id, entry, child_config, cmd_rx, event_tx, interrupt_tx);
         })
         .map_err(|err| type_err("Worker", format!("worker spawn failed: {err}")))?;
     let interrupt = interrupt_rx.recv().map_err(|_| {
@@ -380,35 +511,10 @@ fn spawn_worker_record(host: &Arc<WorkerHostState>, specifier: String) -> Result
 
 fn worker_spawn_call(host: Arc<WorkerHostState>) -> NativeCall {
     let call: Arc<NativeFn> = Arc::new(move |ctx, args, _captures| {
-        let specifier = value_to_string(ctx, args.first().unwrap_or(&Value::undefined()))?;
-        let id = WorkerId(NEXT_WORKER_ID.fetch_add(1, Ordering::Relaxed));
-        let (cmd_tx, cmd_rx) = mpsc::channel();
-        let (event_tx, event_rx) = mpsc::channel();
-        let child_config = host.config.clone();
-        let (interrupt_tx, interrupt_rx) = mpsc::sync_channel(1);
-        let thread_name = format!("otter-worker-{}", id.get());
-        let join = thread::Builder::new()
-            .name(thread_name)
-            .spawn(move || {
-                run_js_worker(id, specifier, child_config, cmd_rx, event_tx, interrupt_tx);
-            })
-            .map_err(|err| type_err("Worker", format!("worker spawn failed: {err}")))?;
-        let interrupt = interrupt_rx.recv().map_err(|_| {
-            type_err(
-                "Worker",
-                "worker runtime stopped before exposing interrupt handle".to_string(),
-            )
-        })?;
-        let record = Arc::new(WorkerRecord {
-            id,
-            tx: cmd_tx,
-            events: Mutex::new(event_rx),
-            interrupt,
-            join: Mutex::new(Some(join)),
-            terminated: std::sync::atomic::AtomicBool::new(false),
-        });
-        host.insert(record);
-        Ok(Value::number_f64(id.get() as f64))
+        let entry = value_to_string(ctx, args.first().unwrap_or(&Value::undefined()))?;
+        let options = WorkerSpawnOptions::parse(ctx, args.get(1));
+        let id = spawn_worker_record(&host, options.entry(entry), options)?;
+        Ok(Value::number_f64(id as f64))
     });
     NativeCall::Dynamic(call)
 }
@@ -511,6 +617,10 @@ fn install_worker_poll_timer(
                 }
             }
             for event in events {
+                if let WorkerEvent::Output(stream, text) = event {
+                    dispatch_worker_output(ctx, worker, stream, &text)?;
+                    continue;
+                }
                 let event_obj = match worker_event_to_value(ctx, event) {
                     Ok(value) => value.as_object().expect("event materializes to object"),
                     Err(err) => {
@@ -545,6 +655,27 @@ fn install_worker_poll_timer(
     })
 }
 
+/// Deliver one piped console line to `worker.stdout` / `worker.stderr` as
+/// a `data` event. Output for a stream the parent did not pipe is dropped.
+fn dispatch_worker_output(
+    ctx: &mut NativeCtx<'_>,
+    worker: object::JsObject,
+    stream: WorkerStdio,
+    text: &str,
+) -> Result<(), NativeError> {
+    let Some(target) =
+        object::get(worker, ctx.heap(), stream.property()).and_then(|value| value.as_object())
+    else {
+        return Ok(());
+    };
+    let mut event = ctx.alloc_object()?;
+    let ty = string_value(ctx, "data")?;
+    object::set(&mut event, ctx.heap_mut(), "type", ty);
+    let data = string_value(ctx, text)?;
+    object::set(&mut event, ctx.heap_mut(), "data", data);
+    dispatch_event_object(ctx, target, event)
+}
+
 fn dispatch_event_object(
     ctx: &mut NativeCtx<'_>,
     worker: object::JsObject,
@@ -723,7 +854,7 @@ fn worker_reentry_error(err: NativeError) -> NativeError {
 
 fn run_js_worker(
     _id: WorkerId,
-    specifier: String,
+    entry: WorkerEntry,
     config: RuntimeConfig,
     rx: mpsc::Receiver<WorkerCommand>,
     tx: mpsc::Sender<WorkerEvent>,
@@ -744,7 +875,7 @@ fn run_js_worker(
         let _ = tx.send(WorkerEvent::Error(err.to_string()));
         return;
     }
-    let context = match run_worker_entry(&mut runtime, &specifier) {
+    let context = match run_worker_entry(&mut runtime, &entry) {
         Ok((_result, context)) => context,
         Err(err) => {
             let _ = tx.send(WorkerEvent::Error(err.to_string()));
@@ -779,13 +910,21 @@ fn run_js_worker(
 
 fn run_worker_entry(
     runtime: &mut Runtime,
-    specifier: &str,
+    entry: &WorkerEntry,
 ) -> Result<(ExecutionResult, otter_vm::ExecutionContext), OtterError> {
-    let path = PathBuf::from(specifier);
-    if path.exists() {
-        runtime.run_file_with_context(path)
-    } else {
-        runtime.run_module_with_context(path)
+    match entry {
+        WorkerEntry::Source(source) => runtime.run_script_with_context(
+            SourceInput::from_javascript(source.clone()),
+            "[worker eval]",
+        ),
+        WorkerEntry::Specifier(specifier) => {
+            let path = PathBuf::from(specifier);
+            if path.exists() {
+                runtime.run_file_with_context(path)
+            } else {
+                runtime.run_module_with_context(path)
+            }
+        }
     }
 }
 
@@ -846,6 +985,12 @@ fn worker_event_to_value(
             object::set(&mut object, ctx.heap_mut(), "type", ty);
             object::set(&mut object, ctx.heap_mut(), "message", message);
         }
+        WorkerEvent::Output(stream, text) => {
+            let ty = string_value(ctx, stream.property())?;
+            let data = string_value(ctx, &text)?;
+            object::set(&mut object, ctx.heap_mut(), "type", ty);
+            object::set(&mut object, ctx.heap_mut(), "data", data);
+        }
         WorkerEvent::Closed => {
             let ty = string_value(ctx, "close")?;
             object::set(&mut object, ctx.heap_mut(), "type", ty);
@@ -1783,6 +1928,72 @@ mod tests {
         otter.run_file(&entry).await.unwrap();
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn global_worker_eval_runs_inline_source() {
+        let dir = tempfile::tempdir().unwrap();
+        let entry = dir.path().join("entry.js");
+        // The inline source names an existing file; `eval: true` must still
+        // compile it as code instead of loading the file.
+        fs::write(dir.path().join("worker.js"), "postMessage('from file');").unwrap();
+        fs::write(
+            &entry,
+            r#"
+            let got = "pending";
+            const w = new Worker("// worker.js\npostMessage('from eval');", { eval: true });
+            w.onerror = (event) => {
+              got = "ERR:" + event.message;
+              w.terminate();
+            };
+            w.onmessage = (event) => {
+              got = event.data;
+              w.terminate();
+            };
+            setTimeout(() => {
+              if (got !== "from eval") throw "bad eval worker message: " + got;
+            }, 20);
+            "#,
+        )
+        .unwrap();
+
+        let otter = Otter::builder()
+            .capabilities(CapabilitySet::allow_all())
+            .build()
+            .unwrap();
+        otter.run_file(&entry).await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn global_worker_pipes_console_output_to_stdout_stream() {
+        let dir = tempfile::tempdir().unwrap();
+        let entry = dir.path().join("entry.js");
+        fs::write(
+            &entry,
+            r#"
+            let out = "";
+            let err = "";
+            const w = new Worker(
+              "console.log('hello', 1); console.error('oops'); postMessage('done');",
+              { eval: true, stdout: true, stderr: true },
+            );
+            if (!w.stdout || !w.stderr) throw "stdio streams missing";
+            w.stdout.addEventListener("data", (event) => { out += event.data; });
+            w.stderr.ondata = (event) => { err += event.data; };
+            w.onmessage = () => w.terminate();
+            setTimeout(() => {
+              if (out !== "hello 1\n") throw "bad stdout: " + JSON.stringify(out);
+              if (err !== "oops\n") throw "bad stderr: " + JSON.stringify(err);
+            }, 20);
+            "#,
+        )
+        .unwrap();
+
+        let otter = Otter::builder()
+            .capabilities(CapabilitySet::allow_all())
+            .build()
+            .unwrap();
+        otter.run_file(&entry).await.unwrap();
+    }
+
     /// Run with `OTTER_GC_STRESS=full` to force relocation after handler
     /// lookup, payload materialization, and event allocation.
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
@@ -0,0 +1,166 @@
+//! Accessor property definitions in object literals and classes.
+//!
+//! # Contents
+//! - `{ get x() {}, set x(v) {} }` installs one accessor descriptor holding
+//!   both functions (enumerable, configurable) instead of data properties.
+//! - Assigning to a getter-only property throws a `TypeError` in strict
+//!   code and is silently ignored in sloppy code.
+//! - Computed accessor names (`{ get [k]() {} }`) are evaluated and keyed
+//!   like any computed property, including symbol keys.
+//! - Class accessors (instance, static, computed) land on the prototype or
+//!   constructor as non-enumerable accessor descriptors.
+//!
+//! # See also
+//! - <https://tc39.es/ecma262/#sec-method-definitions-runtime-semantics-propertydefinitionevaluation>
+//! - <https://tc39.es/ecma262/#sec-ordinaryset>
+
+use otter_runtime::{Runtime, SourceInput};
+
+fn run(source: &str) -> String {
+    let mut rt = Runtime::builder().build().expect("runtime");
+    rt.run_script(SourceInput::from_javascript(source), "<accessors>")
+        .expect("script")
+        .completion_string()
+        .to_string()
+}
+
+#[test]
+fn literal_getter_setter_pair_is_one_accessor_descriptor() {
+    let out = run(r#"
+        const obj = {
+            _x: 1,
+            get x() { return this._x * 10; },
+            set x(v) { this._x = v; },
+        };
+        obj.x = 4;
+        const d = Object.getOwnPropertyDescriptor(obj, "x");
+        [
+            obj.x,
+            obj._x,
+            typeof d.get,
+            typeof d.set,
+            "value" in d,
+            "writable" in d,
+            d.enumerable,
+            d.configurable,
+            d.get.name,
+            d.set.name,
+        ].join(",");
+    "#);
+    assert_eq!(
+        out,
+        "40,4,function,function,false,false,true,true,get x,set x"
+    );
+}
+
+#[test]
+fn strict_assignment_to_getter_only_property_throws() {
+    let out = run(r#"
+        const obj = { get answer() { return 42; } };
+        function strictWrite() {
+            "use strict";
+            try {
+                obj.answer = 1;
+                return "no throw";
+            } catch (e) {
+                return e.constructor.name;
+            }
+        }
+        function sloppyWrite() {
+            obj.answer = 1;
+            return obj.answer;
+        }
+        const d = Object.getOwnPropertyDescriptor(obj, "answer");
+        [strictWrite(), sloppyWrite(), typeof d.get, String(d.set)].join(",");
+    "#);
+    assert_eq!(out, "TypeError,42,function,undefined");
+}
+
+#[test]
+fn computed_accessor_names_are_evaluated() {
+    let out = run(r#"
+        const key = "dyn";
+        const sym = Symbol("tag");
+        let store = 0;
+        const obj = {
+            get [key + "amic"]() { return "computed"; },
+            set [key + "amic"](v) { store = v; },
+            get [sym]() { return "symbol"; },
+            get [1 + 1]() { return "two"; },
+        };
+        obj.dynamic = 7;
+        const d = Object.getOwnPropertyDescriptor(obj, "dynamic");
+        [
+            obj.dynamic,
+            store,
+            obj[sym],
+            obj[2],
+            typeof d.get,
+            typeof d.set,
+            d.get.name,
+            Object.getOwnPropertyDescriptor(obj, sym).get.name,
+        ].join(",");
+    "#);
+    assert_eq!(
+        out,
+        "computed,7,symbol,two,function,function,get dynamic,get [tag]"
+    );
+}
+
+#[test]
+fn class_accessors_are_non_enumerable_accessor_descriptors() {
+    let out = run(r#"
+        const name = "label";
+        class Box {
+            #v = 1;
+            get value() { return this.#v; }
+            set value(v) { this.#v = v; }
+            static get kind() { return "box"; }
+            get [name]() { return "box#" + this.#v; }
+        }
+        const b = new Box();
+        b.value = 5;
+        const proto = Object.getOwnPropertyDescriptor(Box.prototype, "value");
+        const stat = Object.getOwnPropertyDescriptor(Box, "kind");
+        const computed = Object.getOwnPropertyDescriptor(Box.prototype, "label");
+        [
+            b.value,
+            b.label,
+            Box.kind,
+            Object.hasOwn(b, "value"),
+            typeof proto.get,
+            typeof proto.set,
+            proto.enumerable,
+            proto.configurable,
+            typeof stat.get,
+            stat.enumerable,
+            typeof computed.get,
+            "value" in computed,
+        ].join(",");
+    "#);
+    assert_eq!(
+        out,
+        "5,box#5,box,false,function,function,false,true,function,false,function,false"
+    );
+}
+
+#[test]
+fn class_getter_only_assignment_throws_in_class_body_code() {
+    // Class bodies are always strict.
+    let out = run(r#"
+        class ReadOnly {
+            get fixed() { return 1; }
+            tryWrite() {
+                try {
+                    this.fixed = 2;
+                    return "no throw";
+                } catch (e) {
+                    return e.constructor.name;
+                }
+            }
+        }
+        const r = new ReadOnly();
+        [r.tryWrite(), r.fixed].join(",");
+    "#);
+    assert_eq!(out, "TypeError,1");
+}
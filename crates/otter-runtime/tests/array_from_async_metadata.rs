@@ -3,18 +3,57 @@
 //! # Contents
 //! - `Array.fromAsync` has the finalized builtin metadata shape.
 //! - The builtin is callable but not constructible.
-//! - Direct calls still report the intentionally missing async
-//!   collection implementation.
+//! - Async generators, sync iterables of promises, and array-likes collect
+//!   into arrays, with `mapFn` results awaited.
+//! - A rejection mid-iteration rejects the result and closes the source.
 //!
 //! # Invariants
 //! - Static Array methods are installed through the shared JS surface
 //!   builder so `.name`, `.length`, property flags, extensibility, and
 //!   `[[Construct]]` agree with other builtins.
+//! - Direct calls return a promise; argument errors reject it rather
+//!   than throwing synchronously.
 //!
 //! # See also
 //! - <https://tc39.es/ecma262/#sec-array.fromasync>
 
-use otter_runtime::{OtterError, Runtime, SourceInput};
+use std::sync::{Arc, Mutex};
+
+use otter_runtime::{ConsoleLevel, ConsoleSink, Otter, OtterError, Runtime, SourceInput};
+
+#[derive(Debug, Default)]
+struct LogCapture {
+    events: Mutex<Vec<String>>,
+}
+
+impl LogCapture {
+    fn snapshot(&self) -> Vec<String> {
+        self.events.lock().expect("log mutex").clone()
+    }
+}
+
+impl ConsoleSink for LogCapture {
+    fn write(&self, level: ConsoleLevel, fields: &[String]) {
+        if matches!(level, ConsoleLevel::Log) {
+            self.events
+                .lock()
+                .expect("log mutex")
+                .push(fields.join(" "));
+        }
+    }
+}
+
+fn run_logging(source: &str) -> Vec<String> {
+    let capture = Arc::new(LogCapture::default());
+    let otter = Otter::builder()
+        .console_sink(capture.clone())
+        .build()
+        .expect("otter build");
+    otter
+        .blocking_run_typescript(source)
+        .expect("script must succeed");
+    capture.snapshot()
+}
 
 fn run(source: &str) -> String {
     let mut rt = Runtime::builder().build().expect("runtime");
@@ -80,16 +119,82 @@ fn array_from_async_is_not_constructible() {
 }
 
 #[test]
-fn array_from_async_direct_call_reports_missing_algorithm() {
-    let err = run_throwing("Array.fromAsync([]);");
-    let OtterError::Runtime { diagnostic } = err else {
-        panic!("expected Runtime error, got {err:?}");
-    };
-    assert!(
-        diagnostic
-            .message
-            .contains("Array.fromAsync collection is not implemented"),
-        "expected not-implemented diagnostic, got {:?}",
-        diagnostic.message
+fn array_from_async_collects_async_generator_with_awaited_map() {
+    let events = run_logging(
+        r#"
+            async function* source() {
+                yield 1;
+                await null;
+                yield 2;
+                yield 3;
+            }
+            const pending = Array.fromAsync(source(), async (value, index) => value * 10 + index);
+            console.log(pending instanceof Promise);
+            pending.then((values) => console.log(Array.isArray(values), values.join(",")));
+        "#,
+    );
+    assert_eq!(events, vec!["true", "true 10,21,32"]);
+}
+
+#[test]
+fn array_from_async_awaits_sync_iterable_and_array_like_values() {
+    let mut events = run_logging(
+        r#"
+            const iterable = new Set([Promise.resolve("a"), "b", Promise.resolve("c")]);
+            Array.fromAsync(iterable).then((values) => console.log("set", values.join(",")));
+            const arrayLike = { length: 2, 0: Promise.resolve(4), 1: 5 };
+            Array.fromAsync(arrayLike, (value) => value + 1)
+                .then((values) => console.log("like", values.join(",")));
+            Array.fromAsync([1], 42).catch((error) => console.log("map", error instanceof TypeError));
+        "#,
+    );
+    events.sort();
+    assert_eq!(events, vec!["like 5,6", "map true", "set a,b,c"]);
+}
+
+#[test]
+fn array_from_async_rejection_closes_source_iterator() {
+    let mut events = run_logging(
+        r#"
+            let closed = false;
+            const source = {
+                [Symbol.asyncIterator]() {
+                    let index = 0;
+                    return {
+                        next() {
+                            index += 1;
+                            return Promise.resolve({ value: index, done: index > 5 });
+                        },
+                        return() {
+                            closed = true;
+                            return Promise.resolve({ done: true });
+                        },
+                    };
+                },
+            };
+            Array.fromAsync(source, (value) => {
+                if (value === 2) return Promise.reject(new Error("boom"));
+                return value;
+            }).then(
+                () => console.log("resolved"),
+                (error) => console.log("rejected", error.message, closed),
+            );
+
+            let syncClosed = false;
+            const syncSource = {
+                [Symbol.iterator]() {
+                    return {
+                        next: () => ({ value: Promise.reject("bad"), done: false }),
+                        return() {
+                            syncClosed = true;
+                            return {};
+                        },
+                    };
+                },
+            };
+            Array.fromAsync(syncSource).catch((reason) => console.log("sync", reason, syncClosed));
+        "#,
     );
+    events.sort();
+    assert_eq!(events, vec!["rejected boom true", "sync bad true"]);
 }
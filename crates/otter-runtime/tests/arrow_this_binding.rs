@@ -0,0 +1,87 @@
+//! Lexical `this`, `arguments`, and `new.target` in arrow functions.
+//!
+//! # Contents
+//! - An arrow created inside a method keeps the instance as `this`, even
+//!   when it is detached, called with another receiver, or `bind`-ed.
+//! - Arrow-valued class fields capture the instance (instance fields) or
+//!   the class itself (static fields).
+//! - `arguments` inside an arrow is the enclosing function's object, and
+//!   `new.target` is the enclosing constructor call's.
+//!
+//! # See also
+//! - <https://tc39.es/ecma262/#sec-arrow-function-definitions-runtime-semantics-evaluation>
+//! - <https://tc39.es/ecma262/#sec-runtime-semantics-classfielddefinitionevaluation>
+
+use otter_runtime::{Runtime, SourceInput};
+
+fn run(source: &str) -> String {
+    let mut rt = Runtime::builder().build().expect("runtime");
+    rt.run_script(SourceInput::from_javascript(source), "<arrow-this>")
+        .expect("script")
+        .completion_string()
+        .to_string()
+}
+
+#[test]
+fn arrow_inside_method_keeps_instance_this() {
+    let out = run(r#"
+        class Counter {
+            constructor() { this.count = 0; }
+            incrementer() { return () => ++this.count; }
+            later() { return [1, 2, 3].map((n) => this.count * n).join("/"); }
+        }
+        const c = new Counter();
+        const inc = c.incrementer();
+        inc();
+        inc.call({ count: 100 });
+        inc.bind({ count: 200 })();
+        const detached = { inc };
+        detached.inc();
+        [c.count, c.later(), inc.apply(null)].join(",");
+    "#);
+    assert_eq!(out, "4,4/8/12,5");
+}
+
+#[test]
+fn class_field_arrows_capture_instance_and_class() {
+    let out = run(r#"
+        class Button {
+            label = "ok";
+            onClick = () => this.label;
+            static kind = "button";
+            static describe = () => this.kind + ":" + (this === Button);
+        }
+        const a = new Button();
+        const b = new Button();
+        b.label = "cancel";
+        const { onClick } = a;
+        const { describe } = Button;
+        [
+            onClick(),
+            b.onClick.call(a),
+            a.onClick === b.onClick,
+            describe(),
+            describe.call({ kind: "other" }),
+        ].join(",");
+    "#);
+    assert_eq!(out, "ok,cancel,false,button:true,button:true");
+}
+
+#[test]
+fn arrow_sees_enclosing_arguments_and_new_target() {
+    let out = run(r#"
+        function outer() {
+            const first = () => arguments[0];
+            const count = (...rest) => arguments.length + rest.length;
+            return [first("ignored"), count(1, 2, 3)].join("/");
+        }
+        function Made() {
+            const target = () => new.target;
+            this.same = target() === Made;
+        }
+        const made = new Made();
+        const plain = (() => typeof arguments)();
+        [outer("a", "b"), made.same, plain].join(",");
+    "#);
+    assert_eq!(out, "a/5,true,undefined");
+}
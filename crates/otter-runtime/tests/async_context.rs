@@ -0,0 +1,190 @@
+//! `AsyncContext.Variable` / `AsyncContext.Snapshot` propagation.
+//!
+//! # Contents
+//! - `variable.run` binds a value for the synchronous call and every
+//!   continuation it schedules: `await`, `then`, `queueMicrotask`, and
+//!   `setTimeout`.
+//! - Two interleaved async flows each observe only their own binding.
+//! - A snapshot re-enters the mapping it captured, even after the `run`
+//!   that produced it has returned.
+//! - Construction options, defaults, and receiver brand checks.
+//!
+//! # See also
+//! - <https://tc39.es/proposal-async-context/>
+
+use std::sync::{Arc, Mutex};
+
+use otter_runtime::{ConsoleLevel, ConsoleSink, Otter, Runtime, SourceInput};
+
+#[derive(Debug, Default)]
+struct LogCapture {
+    events: Mutex<Vec<String>>,
+}
+
+impl LogCapture {
+    fn snapshot(&self) -> Vec<String> {
+        self.events.lock().expect("log mutex").clone()
+    }
+}
+
+impl ConsoleSink for LogCapture {
+    fn write(&self, level: ConsoleLevel, fields: &[String]) {
+        if matches!(level, ConsoleLevel::Log) {
+            self.events
+                .lock()
+                .expect("log mutex")
+                .push(fields.join(" "));
+        }
+    }
+}
+
+async fn run_logged(source: &str) -> Vec<String> {
+    let log = Arc::new(LogCapture::default());
+    let otter = Otter::builder()
+        .console_sink(log.clone())
+        .build()
+        .expect("otter build");
+    otter.run_script(source).await.expect("script");
+    log.snapshot()
+}
+
+fn run(source: &str) -> String {
+    let mut rt = Runtime::builder().build().expect("runtime");
+    rt.run_script(SourceInput::from_javascript(source), "<async-context>")
+        .expect("script")
+        .completion_string()
+        .to_string()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn binding_survives_await_and_promise_jobs() {
+    let log = run_logged(
+        r#"
+            const v = new AsyncContext.Variable();
+            v.run("outer", async () => {
+                console.log("sync " + v.get());
+                await null;
+                console.log("await " + v.get());
+                Promise.resolve().then(() => console.log("then " + v.get()));
+                queueMicrotask(() => console.log("microtask " + v.get()));
+            });
+            console.log("after run " + v.get());
+        "#,
+    )
+    .await;
+    assert_eq!(
+        log,
+        [
+            "sync outer",
+            "after run undefined",
+            "await outer",
+            "then outer",
+            "microtask outer",
+        ]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn timers_run_in_the_scheduling_mapping() {
+    let log = run_logged(
+        r#"
+            const v = new AsyncContext.Variable({ defaultValue: "none" });
+            v.run("timeout", () => {
+                setTimeout(() => {
+                    console.log("timer " + v.get());
+                    Promise.resolve().then(() => console.log("timer job " + v.get()));
+                }, 1);
+            });
+            setTimeout(() => console.log("plain " + v.get()), 5);
+        "#,
+    )
+    .await;
+    assert_eq!(log, ["timer timeout", "timer job timeout", "plain none"]);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn interleaved_flows_stay_isolated() {
+    let log = run_logged(
+        r#"
+            const v = new AsyncContext.Variable();
+            async function flow(label) {
+                for (let i = 0; i < 3; i++) {
+                    await null;
+                    console.log(label + " " + v.get());
+                }
+            }
+            v.run("a", flow, "first");
+            v.run("b", flow, "second");
+        "#,
+    )
+    .await;
+    assert_eq!(
+        log,
+        [
+            "first a", "second b", "first a", "second b", "first a", "second b",
+        ]
+    );
+}
+
+#[test]
+fn snapshot_reenters_the_captured_mapping() {
+    let out = run(r#"
+        const v = new AsyncContext.Variable();
+        const w = new AsyncContext.Variable();
+        const snapshot = v.run(1, () => w.run(2, () => new AsyncContext.Snapshot()));
+        const seen = [];
+        v.run("live", () => {
+            seen.push(snapshot.run((suffix) => `${v.get()},${w.get()}${suffix}`, "!"));
+            seen.push(v.get());
+        });
+        seen.push(snapshot.run(() => v.get()), String(v.get()));
+        seen.join("|");
+    "#);
+    assert_eq!(out, "1,2!|live|1|undefined");
+}
+
+#[test]
+fn nested_runs_shadow_and_restore_after_throw() {
+    let out = run(r#"
+        const v = new AsyncContext.Variable();
+        const seen = [];
+        v.run("outer", () => {
+            v.run("inner", () => seen.push(v.get()));
+            try {
+                v.run("throwing", () => { throw new Error("boom"); });
+            } catch (e) {
+                seen.push(e.message);
+            }
+            seen.push(v.get());
+        });
+        seen.push(String(v.get()));
+        seen.join(",");
+    "#);
+    assert_eq!(out, "inner,boom,outer,undefined");
+}
+
+#[test]
+fn options_defaults_and_brand_checks() {
+    let out = run(r#"
+        const named = new AsyncContext.Variable({ name: "requestId", defaultValue: 0 });
+        const plain = new AsyncContext.Variable();
+        const errors = [];
+        try { AsyncContext.Variable(); } catch (e) { errors.push(e.constructor.name); }
+        try { AsyncContext.Variable.prototype.get.call({}); } catch (e) { errors.push(e.constructor.name); }
+        try { AsyncContext.Snapshot.prototype.run.call(named, () => 1); } catch (e) { errors.push(e.constructor.name); }
+        [
+            named.name,
+            named.get(),
+            JSON.stringify(plain.name),
+            String(plain.get()),
+            Object.prototype.toString.call(named),
+            Object.prototype.toString.call(new AsyncContext.Snapshot()),
+            errors.join("/"),
+        ].join(",");
+    "#);
+    assert_eq!(
+        out,
+        "requestId,0,\"\",undefined,[object AsyncContext.Variable],\
+         [object AsyncContext.Snapshot],TypeError/TypeError/TypeError"
+    );
+}
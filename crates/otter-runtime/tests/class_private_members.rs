@@ -0,0 +1,120 @@
+//! Runtime regression coverage for class private fields, methods, and
+//! accessors on the interpreter path.
+//!
+//! # Contents
+//! - Private field reads and writes through `this`.
+//! - `#name in obj` brand checks for fields and methods.
+//! - Private method and accessor calls.
+//! - Wrong-brand reads, writes, and calls throw `TypeError`.
+//!
+//! # Invariants
+//! - Private methods and accessors live behind the instance brand: they are
+//!   never reflected as prototype or own properties, and every instance
+//!   shares the same method function.
+//!
+//! # See also
+//! - <https://tc39.es/ecma262/#sec-privatefieldget>
+//! - <https://tc39.es/ecma262/#sec-privatebrandcheck>
+//! - `jit_private_access.rs` for the template-tier counterpart.
+
+use otter_runtime::{Runtime, SourceInput};
+
+fn run(source: &str) -> String {
+    let mut rt = Runtime::builder().build().expect("runtime");
+    rt.run_script(
+        SourceInput::from_javascript(source),
+        "<class-private-members>",
+    )
+    .expect("script")
+    .completion_string()
+    .to_string()
+}
+
+#[test]
+fn private_fields_read_and_write() {
+    let completion = run(r#"
+        class Counter {
+            #count = 1;
+            static #instances = 0;
+            constructor() { Counter.#instances++; }
+            bump() { this.#count += 2; return this.#count; }
+            static instances() { return Counter.#instances; }
+        }
+        const counter = new Counter();
+        new Counter();
+        [counter.bump(), counter.bump(), Counter.instances(), Object.keys(counter).length].join("|");
+        "#);
+    assert_eq!(completion, "3|5|2|0");
+}
+
+#[test]
+fn brand_check_in_operator() {
+    let completion = run(r#"
+        class A {
+            #x;
+            #m() {}
+            static hasX(o) { return #x in o; }
+            static hasM(o) { return #m in o; }
+        }
+        class B { #x; }
+        let primitive;
+        try {
+            A.hasX(1);
+            primitive = "no throw";
+        } catch (error) {
+            primitive = error instanceof TypeError;
+        }
+        [A.hasX(new A()), A.hasX(new B()), A.hasM(new A()), A.hasM({}), primitive].join("|");
+        "#);
+    assert_eq!(completion, "true|false|true|false|true");
+}
+
+#[test]
+fn private_methods_and_accessors_stay_off_the_prototype() {
+    let completion = run(r#"
+        class Temperature {
+            #celsius = 20;
+            #describe(unit) { return this.#fahrenheit + unit; }
+            get #fahrenheit() { return this.#celsius * 9 / 5 + 32; }
+            set #fahrenheit(value) { this.#celsius = (value - 32) * 5 / 9; }
+            warm() { this.#fahrenheit = 212; return this.#describe("F"); }
+            method() { return this.#describe; }
+        }
+        const a = new Temperature();
+        const b = new Temperature();
+        [
+            a.warm(),
+            a.method() === b.method(),
+            Object.getOwnPropertyNames(Temperature.prototype).join(","),
+            Reflect.ownKeys(a).length,
+        ].join("|");
+        "#);
+    assert_eq!(completion, "212F|true|constructor,warm,method|0");
+}
+
+#[test]
+fn wrong_brand_access_throws_type_error() {
+    let completion = run(r#"
+        class Box {
+            #value = 1;
+            #secret() { return 2; }
+            static read(o) { return o.#value; }
+            static write(o) { o.#value = 3; }
+            static call(o) { return o.#secret(); }
+        }
+        const outcome = (f) => {
+            try {
+                return "returned " + f();
+            } catch (error) {
+                return error instanceof TypeError ? "TypeError" : String(error);
+            }
+        };
+        [
+            outcome(() => Box.read(new Box())),
+            outcome(() => Box.read({})),
+            outcome(() => Box.write({})),
+            outcome(() => Box.call(Object.create(Box.prototype))),
+        ].join("|");
+        "#);
+    assert_eq!(completion, "returned 1|TypeError|TypeError|TypeError");
+}
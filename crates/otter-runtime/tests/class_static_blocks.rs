@@ -0,0 +1,83 @@
+//! Runtime regression coverage for class static initialization blocks and
+//! static private members.
+//!
+//! # Contents
+//! - A `static { ... }` block initializes a static field with `this` bound to
+//!   the class.
+//! - Static blocks and static field initializers run interleaved in source
+//!   order at class-definition time.
+//! - Static private fields and methods are reachable from static blocks and
+//!   static methods.
+//!
+//! # See also
+//! - <https://tc39.es/ecma262/#sec-class-static-block-definition-evaluation>
+//! - <https://tc39.es/ecma262/#sec-runtime-semantics-classdefinitionevaluation>
+
+use otter_runtime::{Runtime, SourceInput};
+
+fn run(source: &str) -> String {
+    let mut rt = Runtime::builder().build().expect("runtime");
+    rt.run_script(SourceInput::from_javascript(source), "<class-static-blocks>")
+        .expect("script")
+        .completion_string()
+        .to_string()
+}
+
+#[test]
+fn static_block_initializes_static_field() {
+    let completion = run(r#"
+        class Config {
+            static defaults;
+            static {
+                this.defaults = { retries: 3 };
+                Config.ready = this === Config;
+            }
+        }
+        [Config.defaults.retries, Config.ready].join("|");
+        "#);
+    assert_eq!(completion, "3|true");
+}
+
+#[test]
+fn static_blocks_and_fields_run_in_source_order() {
+    let completion = run(r#"
+        const log = [];
+        log.push("before");
+        class Ordered {
+            static a = log.push("field a");
+            static { log.push("block 1 sees a=" + this.a); }
+            static b = log.push("field b");
+            static { log.push("block 2 sees b=" + this.b); }
+        }
+        log.push("after");
+        log.join(",");
+        "#);
+    assert_eq!(
+        completion,
+        "before,field a,block 1 sees a=2,field b,block 2 sees b=4,after"
+    );
+}
+
+#[test]
+fn static_privates_are_reachable_from_blocks_and_methods() {
+    let completion = run(r#"
+        class Registry {
+            static #entries = [];
+            static #seed() { return "seed"; }
+            static { Registry.#entries.push(this.#seed()); }
+            static add(name) { this.#entries.push(name); return this; }
+            static list() { return Registry.#entries.join(","); }
+            static owns(o) { return #entries in o; }
+        }
+        Registry.add("a").add("b");
+        let wrongReceiver;
+        try {
+            Registry.add.call({}, "x");
+            wrongReceiver = "no throw";
+        } catch (error) {
+            wrongReceiver = error instanceof TypeError;
+        }
+        [Registry.list(), Registry.owns(Registry), Registry.owns({}), wrongReceiver].join("|");
+        "#);
+    assert_eq!(completion, "seed,a,b|true|false|true");
+}
@@ -0,0 +1,124 @@
+//! `console.time` / `timeLog` / `timeEnd` report label-keyed durations.
+//!
+//! # Contents
+//! - `timeEnd` prints `label: <ms>ms` with a duration at least as long as
+//!   the measured work, and stops the label.
+//! - `timeLog` prints intermediate timing followed by its extra data and
+//!   keeps the label running.
+//! - Unknown labels and duplicate `time` calls warn instead of throwing.
+
+use std::sync::{Arc, Mutex};
+
+use otter_runtime::{ConsoleLevel, ConsoleSink, Runtime, SourceInput};
+
+#[derive(Debug, Default)]
+struct Capture {
+    events: Mutex<Vec<(ConsoleLevel, String)>>,
+}
+
+impl ConsoleSink for Capture {
+    fn write(&self, level: ConsoleLevel, fields: &[String]) {
+        self.events
+            .lock()
+            .expect("console mutex")
+            .push((level, fields.join(" ")));
+    }
+}
+
+fn run(source: &str) -> Vec<(ConsoleLevel, String)> {
+    let capture = Arc::new(Capture::default());
+    let mut runtime = Runtime::builder()
+        .console_sink(capture.clone())
+        .build()
+        .expect("runtime");
+    runtime
+        .run_script(SourceInput::from_javascript(source), "<console-timers>")
+        .expect("script");
+    capture.events.lock().expect("console mutex").clone()
+}
+
+/// Parse the millisecond count out of `"<label>: <ms>ms<rest>"`.
+fn duration_ms(line: &str, label: &str) -> f64 {
+    let rest = line
+        .strip_prefix(&format!("{label}: "))
+        .unwrap_or_else(|| panic!("unexpected timer line {line:?}"));
+    let end = rest.find("ms").expect("ms suffix");
+    rest[..end].parse().expect("numeric duration")
+}
+
+#[test]
+fn time_end_prints_the_measured_duration() {
+    let events = run(r#"
+        console.time("work");
+        const until = Date.now() + 20;
+        while (Date.now() < until) {}
+        console.timeEnd("work");
+        console.timeEnd("work");
+        console.time();
+        console.timeEnd();
+        "#);
+    assert_eq!(events.len(), 3, "{events:?}");
+    assert_eq!(events[0].0, ConsoleLevel::Log);
+    assert!(
+        duration_ms(&events[0].1, "work") >= 20.0,
+        "duration too short: {}",
+        events[0].1
+    );
+    // The first `timeEnd` stopped the label.
+    assert_eq!(
+        events[1],
+        (
+            ConsoleLevel::Warn,
+            "Warning: No such label 'work' for console.timeEnd()".to_string()
+        )
+    );
+    assert!(duration_ms(&events[2].1, "default") >= 0.0);
+}
+
+#[test]
+fn time_log_prints_intermediate_timing_with_data() {
+    let events = run(r#"
+        console.time("phase");
+        console.timeLog("phase", "loaded", 42);
+        console.timeLog("phase");
+        console.timeEnd("phase");
+        "#);
+    assert_eq!(events.len(), 3, "{events:?}");
+    assert!(events.iter().all(|(level, _)| *level == ConsoleLevel::Log));
+    assert!(
+        events[0].1.ends_with("ms loaded 42"),
+        "missing appended data: {}",
+        events[0].1
+    );
+    let first = duration_ms(&events[0].1, "phase");
+    let second = duration_ms(&events[1].1, "phase");
+    let total = duration_ms(&events[2].1, "phase");
+    assert!(first <= second && second <= total, "{events:?}");
+}
+
+#[test]
+fn unknown_and_duplicate_labels_warn() {
+    let events = run(r#"
+        console.timeEnd("missing");
+        console.timeLog("missing");
+        console.time("dup");
+        console.time("dup");
+        "#);
+    assert_eq!(
+        events,
+        vec![
+            (
+                ConsoleLevel::Warn,
+                "Warning: No such label 'missing' for console.timeEnd()".to_string()
+            ),
+            (
+                ConsoleLevel::Warn,
+                "Warning: No such label 'missing' for console.timeLog()".to_string()
+            ),
+            (
+                ConsoleLevel::Warn,
+                "Warning: Label 'dup' already exists for console.time()".to_string()
+            ),
+        ]
+    );
+}
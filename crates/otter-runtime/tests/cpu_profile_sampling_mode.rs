@@ -0,0 +1,84 @@
+//! Wall-clock vs on-CPU CPU-profile sampling.
+//!
+//! # Contents
+//! - An I/O-bound script (short bursts of work between blocking
+//!   `Atomics.wait` timeouts) records one idle sample per park, spanning the
+//!   parked time, in wall-clock mode and no idle samples in on-CPU mode.
+//! - A compute-only script records no idle samples in either mode.
+//!
+//! Runtimes run interpreter-only so every instruction passes the
+//! dispatch-loop sampler.
+
+use otter_runtime::{CpuProfile, JitSelection, Runtime, SamplingMode, SourceInput};
+
+const IO_BOUND: &str = r#"
+    const cell = new Int32Array(new SharedArrayBuffer(4));
+    let sum = 0;
+    for (let round = 0; round < 6; round++) {
+        for (let i = 0; i < 200; i++) sum += i;
+        Atomics.wait(cell, 0, 0, 25);
+    }
+    sum;
+"#;
+
+const COMPUTE_ONLY: &str = r#"
+    let sum = 0;
+    for (let i = 0; i < 20000; i++) sum += i % 7;
+    sum;
+"#;
+
+fn profile(source: &str, mode: SamplingMode) -> CpuProfile {
+    let mut runtime = Runtime::builder()
+        .allow_blocking_atomics_wait(true)
+        .jit_selection(JitSelection::InterpreterOnly)
+        .build()
+        .expect("runtime");
+    runtime.enable_cpu_profiler_with_mode(1_000, mode);
+    runtime
+        .run_script(SourceInput::from_javascript(source), "<sampling-mode>")
+        .expect("script");
+    runtime.take_cpu_profile().expect("profile")
+}
+
+fn idle_samples(profile: &CpuProfile) -> usize {
+    profile
+        .samples
+        .iter()
+        .filter(|stack| stack.is_empty())
+        .count()
+}
+
+#[test]
+fn on_cpu_mode_skips_parked_time() {
+    let wall = profile(IO_BOUND, SamplingMode::WallClock);
+    let on_cpu = profile(IO_BOUND, SamplingMode::OnCpu);
+
+    // 6 × 25ms parked, one idle sample per park covering its duration.
+    assert_eq!(idle_samples(&wall), 6);
+    assert_eq!(idle_samples(&on_cpu), 0);
+    let idle_us: u64 = wall
+        .samples
+        .iter()
+        .zip(&wall.time_deltas_us)
+        .filter(|(stack, _)| stack.is_empty())
+        .map(|(_, delta)| delta)
+        .sum();
+    assert!(idle_us >= 150_000, "idle total {idle_us}us");
+    // On-CPU deltas exclude the ~150ms spent parked.
+    let on_cpu_us: u64 = on_cpu.time_deltas_us.iter().sum();
+    let wall_us: u64 = wall.time_deltas_us.iter().sum();
+    assert!(wall_us >= 150_000, "wall-clock total {wall_us}us");
+    assert!(
+        on_cpu_us + 100_000 < wall_us,
+        "on-CPU total {on_cpu_us}us vs wall-clock {wall_us}us"
+    );
+}
+
+#[test]
+fn compute_only_scripts_record_no_idle_samples() {
+    for mode in [SamplingMode::WallClock, SamplingMode::OnCpu] {
+        let profile = profile(COMPUTE_ONLY, mode);
+        assert!(profile.sample_count() > 0, "{mode:?}");
+        assert_eq!(idle_samples(&profile), 0, "{mode:?}");
+    }
+}
@@ -0,0 +1,61 @@
+//! Runtime regression coverage for `Date.prototype.toTemporalInstant`.
+//!
+//! # Contents
+//! - A known Date converts to an Instant with matching epoch nanoseconds,
+//!   milliseconds, and ISO string.
+//! - Pre-epoch Dates keep their sign; Invalid Dates throw `RangeError`.
+//!
+//! # Invariants
+//! - The result is a real `Temporal.Instant` (prototype and brand), not a
+//!   Date-shaped stand-in.
+//!
+//! # See also
+//! - <https://tc39.es/proposal-temporal/#sec-date.prototype.totemporalinstant>
+
+use otter_runtime::{Runtime, SourceInput};
+
+fn run(source: &str) -> String {
+    let mut rt = Runtime::builder().build().expect("runtime");
+    rt.run_script(
+        SourceInput::from_javascript(source),
+        "<date-to-temporal-instant>",
+    )
+    .expect("script")
+    .completion_string()
+    .to_string()
+}
+
+#[test]
+fn date_converts_to_instant_with_matching_epoch() {
+    let completion = run(r#"
+        const date = new Date(Date.UTC(2020, 0, 1, 0, 0, 0, 123));
+        const instant = date.toTemporalInstant();
+        [
+            instant instanceof Temporal.Instant,
+            instant.epochNanoseconds === 1577836800123000000n,
+            instant.epochNanoseconds === BigInt(date.getTime()) * 1000000n,
+            instant.epochMilliseconds,
+            instant.toString(),
+        ].join("|");
+        "#);
+    assert_eq!(
+        completion,
+        "true|true|true|1577836800123|2020-01-01T00:00:00.123Z"
+    );
+}
+
+#[test]
+fn pre_epoch_and_invalid_dates() {
+    let completion = run(r#"
+        const before = new Date(-1).toTemporalInstant();
+        let invalid;
+        try {
+            new Date(NaN).toTemporalInstant();
+            invalid = "no throw";
+        } catch (error) {
+            invalid = error instanceof RangeError;
+        }
+        [String(before.epochNanoseconds), before.toString(), invalid].join("|");
+        "#);
+    assert_eq!(completion, "-1000000|1969-12-31T23:59:59.999Z|true");
+}
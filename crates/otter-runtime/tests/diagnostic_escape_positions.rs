@@ -0,0 +1,59 @@
+//! Compile diagnostics for malformed literal escapes point at the escape.
+//!
+//! Editors underline `Diagnostic::range`, so an invalid escape deep inside
+//! a long string must not be reported at the string's opening quote.
+//!
+//! # Contents
+//! - Out-of-range `\u{110000}` and malformed `\x` escapes in string
+//!   literals.
+//! - A strict-mode legacy octal escape.
+
+use otter_runtime::{Diagnostic, OtterError, Runtime, SourceInput};
+
+fn compile_error(source: &str) -> Diagnostic {
+    let mut runtime = Runtime::builder().build().expect("runtime");
+    let err = runtime
+        .run_script(SourceInput::from_javascript(source), "<escape-positions>")
+        .expect_err("source must not compile");
+    match err {
+        OtterError::Compile { mut diagnostics } => diagnostics.remove(0),
+        other => panic!("expected compile error, got {other:?}"),
+    }
+}
+
+/// Assert the diagnostic range starts at the backslash of `escape` and
+/// stays within it.
+fn assert_points_at(source: &str, escape: &str) {
+    let diagnostic = compile_error(source);
+    let escape_start = source.find(escape).expect("escape in source") as u32;
+    let escape_end = escape_start + escape.len() as u32;
+    let (start, end) = diagnostic
+        .range
+        .unwrap_or_else(|| panic!("diagnostic has no range: {diagnostic:?}"));
+    assert_eq!(
+        start, escape_start,
+        "range should start at the escape, got {start}..{end} for {diagnostic:?}"
+    );
+    assert!(
+        end > start && end <= escape_end,
+        "range should stay inside the escape, got {start}..{end} for {diagnostic:?}"
+    );
+}
+
+#[test]
+fn out_of_range_unicode_escape_points_at_the_escape() {
+    assert_points_at(
+        "let ok = 1;\nlet s = \"prefix text \\u{110000} suffix\";\n",
+        "\\u{110000}",
+    );
+}
+
+#[test]
+fn invalid_hex_escape_points_at_the_escape() {
+    assert_points_at("let ok = 1;\nlet s = 'some text then \\xZ1';\n", "\\xZ");
+}
+
+#[test]
+fn strict_legacy_octal_escape_points_at_the_escape() {
+    assert_points_at("'use strict';\nlet s = \"abc\\07 def\";\n", "\\07");
+}
@@ -0,0 +1,106 @@
+//! Runtime regression coverage for user subclasses of the Error family.
+//!
+//! # Contents
+//! - `class X extends Error` keeps the subclass prototype, `name`, `message`,
+//!   and a construction-site `stack`.
+//! - `AggregateError` subclasses and plain instances expose `.errors`.
+//! - `Error.captureStackTrace` installs `.stack` on arbitrary objects and
+//!   honours the `constructorOpt` frame cut.
+//!
+//! # Invariants
+//! - The Error constructors reuse the receiver that
+//!   OrdinaryCreateFromConstructor allocated from `new.target`, so the
+//!   subclass prototype chain survives `super(...)`.
+//!
+//! # See also
+//! - <https://tc39.es/ecma262/#sec-error-constructor>
+//! - <https://tc39.es/ecma262/#sec-aggregate-error>
+
+use otter_runtime::{Runtime, SourceInput};
+
+fn run(source: &str) -> String {
+    let mut rt = Runtime::builder().build().expect("runtime");
+    rt.run_script(SourceInput::from_javascript(source), "<error-subclassing>")
+        .expect("script")
+        .completion_string()
+        .to_string()
+}
+
+#[test]
+fn error_subclass_keeps_name_message_stack_and_prototype() {
+    let completion = run(r#"
+        class ValidationError extends Error {
+            constructor(message, field) {
+                super(message, { cause: field });
+                this.field = field;
+            }
+        }
+        ValidationError.prototype.name = "ValidationError";
+        function raise() {
+            return new ValidationError("bad input", "email");
+        }
+        const err = raise();
+        [
+            err instanceof ValidationError,
+            err instanceof Error,
+            Object.getPrototypeOf(err) === ValidationError.prototype,
+            Error.isError(err),
+            err.name,
+            err.message,
+            err.cause,
+            err.field,
+            String(err),
+            err.stack.startsWith("ValidationError: bad input"),
+            err.stack.includes("raise"),
+        ].join("|");
+        "#);
+    assert_eq!(
+        completion,
+        "true|true|true|true|ValidationError|bad input|email|email|\
+         ValidationError: bad input|true|true"
+    );
+}
+
+#[test]
+fn aggregate_error_and_subclasses_expose_errors() {
+    let completion = run(r#"
+        class BatchError extends AggregateError {}
+        const plain = new AggregateError([new TypeError("a"), new RangeError("b")], "many");
+        const batch = new BatchError(new Set([1, 2, 3]), "batch");
+        [
+            plain.errors.map((e) => e.name + ":" + e.message).join(","),
+            plain.message,
+            plain instanceof Error,
+            batch instanceof BatchError,
+            batch instanceof AggregateError,
+            batch.name,
+            batch.errors.join(","),
+        ].join("|");
+        "#);
+    assert_eq!(
+        completion,
+        "TypeError:a,RangeError:b|many|true|true|true|AggregateError|1,2,3"
+    );
+}
+
+#[test]
+fn capture_stack_trace_populates_stack_and_honours_constructor_opt() {
+    let completion = run(r#"
+        function hidden() {
+            const target = { name: "Custom", message: "captured" };
+            Error.captureStackTrace(target, hidden);
+            return target;
+        }
+        function visible() {
+            return hidden();
+        }
+        const target = visible();
+        [
+            typeof target.stack,
+            target.stack.startsWith("Custom: captured"),
+            target.stack.includes("visible"),
+            target.stack.includes("hidden"),
+        ].join("|");
+        "#);
+    assert_eq!(completion, "string|true|true|false");
+}
@@ -0,0 +1,109 @@
+//! §19.2.1 direct vs indirect `eval` scope.
+//!
+//! # Contents
+//! - A direct `eval(code)` reads and writes the caller's locals, and a
+//!   sloppy direct eval's `var` lands in the caller's function scope.
+//! - Indirect forms — `(0, eval)(code)`, an aliased `eval`, and
+//!   `globalThis.eval` — run in the global scope and cannot see locals.
+//! - Strict direct eval (strict caller or `"use strict"` eval code) keeps
+//!   its `var` / function declarations in its own variable environment.
+//!
+//! # See also
+//! - <https://tc39.es/ecma262/#sec-function-calls-runtime-semantics-evaluation>
+//! - <https://tc39.es/ecma262/#sec-performeval>
+
+use otter_runtime::{Runtime, SourceInput};
+
+fn run(source: &str) -> String {
+    let mut rt = Runtime::builder().build().expect("runtime");
+    rt.run_script(SourceInput::from_javascript(source), "<eval-scope>")
+        .expect("script")
+        .completion_string()
+        .to_string()
+}
+
+#[test]
+fn direct_eval_reads_and_declares_caller_locals() {
+    let out = run(r#"
+        var x = "global";
+        function f() {
+            var x = "local";
+            const read = eval("x");
+            eval("x = x + '!'");
+            eval("var added = 42");
+            return [read, x, typeof added, added].join(",");
+        }
+        [f(), typeof added].join("|");
+    "#);
+    assert_eq!(out, "local,local!,number,42|undefined");
+}
+
+#[test]
+fn indirect_eval_runs_in_global_scope() {
+    let out = run(r#"
+        var x = "global";
+        function f() {
+            var x = "local";
+            const alias = eval;
+            return [
+                (0, eval)("x"),
+                alias("x"),
+                globalThis.eval("x"),
+                (0, eval)("typeof onlyLocal"),
+            ].join(",");
+        }
+        function g() {
+            var onlyLocal = 1;
+            return (0, eval)("typeof onlyLocal");
+        }
+        [f(), g()].join("|");
+    "#);
+    assert_eq!(out, "global,global,global,undefined|undefined");
+}
+
+#[test]
+fn indirect_eval_var_declares_a_global() {
+    let out = run(r#"
+        function f() {
+            (0, eval)("var leaked = 'from indirect'");
+            return typeof leaked;
+        }
+        [f(), globalThis.leaked].join(",");
+    "#);
+    assert_eq!(out, "string,from indirect");
+}
+
+#[test]
+fn strict_direct_eval_isolates_declarations() {
+    let out = run(r#"
+        function strictCaller() {
+            "use strict";
+            var seen = "outer";
+            eval("var seen = 'inner'; function helper() {}");
+            return [seen, typeof helper].join(",");
+        }
+        function strictCode() {
+            eval("'use strict'; var hidden = 1");
+            return typeof hidden;
+        }
+        function sloppy() {
+            eval("var visible = 1");
+            return typeof visible;
+        }
+        [strictCaller(), strictCode(), sloppy()].join("|");
+    "#);
+    assert_eq!(out, "outer,undefined|undefined|number");
+}
+
+#[test]
+fn strict_direct_eval_still_reads_caller_locals() {
+    let out = run(r#"
+        "use strict";
+        function f() {
+            let local = 7;
+            return eval("local * 6");
+        }
+        f();
+    "#);
+    assert_eq!(out, "42");
+}
@@ -0,0 +1,88 @@
+//! The `**` operator: right-associativity and the unary-base early error.
+//!
+//! # Contents
+//! - `a ** b ** c` groups as `a ** (b ** c)`, including through `**=`.
+//! - A parenthesized unary base (`(-2) ** 2`) and a unary applied to the
+//!   whole power (`-(2 ** 2)`) evaluate normally.
+//! - An unparenthesized unary base (`-2 ** 2`, `typeof x ** 2`) is a
+//!   compile-time `SyntaxError`: nothing in the script runs, and `eval`
+//!   throws a catchable `SyntaxError`.
+//!
+//! # See also
+//! - <https://tc39.es/ecma262/#sec-exp-operator>
+
+use otter_runtime::{Runtime, SourceInput};
+
+fn run(source: &str) -> String {
+    let mut rt = Runtime::builder().build().expect("runtime");
+    rt.run_script(SourceInput::from_javascript(source), "<exponent>")
+        .expect("script")
+        .completion_string()
+        .to_string()
+}
+
+#[test]
+fn exponentiation_is_right_associative() {
+    let out = run(r#"
+        let acc = 2;
+        acc **= 3 ** 2;
+        const base = 2, mid = 3, top = 2;
+        [
+            2 ** 3 ** 2,
+            (2 ** 3) ** 2,
+            base ** mid ** top,
+            acc,
+            2 ** -1,
+            2n ** 3n ** 2n,
+        ].join(",");
+    "#);
+    assert_eq!(out, "512,64,512,512,0.5,512");
+}
+
+#[test]
+fn parenthesized_unary_base_evaluates() {
+    let out = run(r#"
+        const n = 2;
+        [(-2) ** 2, (-n) ** 3, -(2 ** 2), (+n) ** 0].join(",");
+    "#);
+    assert_eq!(out, "4,-8,-4,1");
+}
+
+#[test]
+fn unary_base_is_a_compile_time_syntax_error() {
+    for source in [
+        "globalThis.ran = true; -2 ** 2;",
+        "globalThis.ran = true; let x = 2; typeof x ** 2;",
+        "globalThis.ran = true; let x = 2; !x ** 2;",
+    ] {
+        let mut rt = Runtime::builder().build().expect("runtime");
+        assert!(
+            rt.run_script(SourceInput::from_javascript(source), "<exponent>")
+                .is_err(),
+            "{source} must be rejected"
+        );
+        let ran = rt
+            .run_script(
+                SourceInput::from_javascript("typeof globalThis.ran"),
+                "<check>",
+            )
+            .expect("script")
+            .completion_string()
+            .to_string();
+        assert_eq!(ran, "undefined", "{source} must not run any statement");
+    }
+
+    let out = run(r#"
+        const seen = [];
+        for (const src of ["-2 ** 2", "+1 ** 2", "void 0 ** 2", "delete o.x ** 2"]) {
+            try {
+                eval(src);
+                seen.push("accepted");
+            } catch (e) {
+                seen.push(e instanceof SyntaxError);
+            }
+        }
+        seen.join(",");
+    "#);
+    assert_eq!(out, "true,true,true,true");
+}
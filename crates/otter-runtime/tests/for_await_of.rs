@@ -0,0 +1,154 @@
+//! Runtime regression coverage for `for await…of` iteration.
+//!
+//! # Contents
+//! - Async generators and hand-written `[@@asyncIterator]` objects.
+//! - Sync iterables whose values are awaited (the async-from-sync path).
+//! - AsyncIteratorClose on `break`, and sync closes on a thrown body.
+//!
+//! # Invariants
+//! - `[@@asyncIterator]` wins over `[@@iterator]` when both exist.
+//! - Exhausting the iterator never calls `return`.
+//!
+//! # See also
+//! - <https://tc39.es/ecma262/#sec-for-in-and-for-of-statements>
+//! - <https://tc39.es/ecma262/#sec-createasyncfromsynciterator>
+
+use std::sync::{Arc, Mutex};
+
+use otter_runtime::{ConsoleLevel, ConsoleSink, Otter};
+
+#[derive(Debug, Default)]
+struct LogCapture {
+    events: Mutex<Vec<String>>,
+}
+
+impl ConsoleSink for LogCapture {
+    fn write(&self, level: ConsoleLevel, fields: &[String]) {
+        if matches!(level, ConsoleLevel::Log) {
+            self.events
+                .lock()
+                .expect("log mutex")
+                .push(fields.join(" "));
+        }
+    }
+}
+
+fn run_capturing(source: &str) -> Vec<String> {
+    let capture = Arc::new(LogCapture::default());
+    let otter = Otter::builder()
+        .console_sink(capture.clone())
+        .build()
+        .expect("otter build");
+    otter
+        .blocking_run_typescript(source)
+        .expect("script must succeed");
+    capture.events.lock().expect("log mutex").clone()
+}
+
+#[test]
+fn for_await_walks_async_generators_and_prefers_async_iterator() {
+    let log = run_capturing(
+        r#"
+        async function* numbers() {
+            yield 1;
+            yield await Promise.resolve(2);
+            yield 3;
+        }
+        const both = {
+            [Symbol.iterator]() { throw new Error("sync iterator used"); },
+            [Symbol.asyncIterator]() {
+                let i = 0;
+                return {
+                    next() {
+                        i += 1;
+                        return Promise.resolve({ value: "a" + i, done: i > 2 });
+                    },
+                };
+            },
+        };
+        await (async () => {
+            const seen = [];
+            for await (const n of numbers()) seen.push(n);
+            for await (const s of both) seen.push(s);
+            console.log(seen.join(","));
+        })();
+        "#,
+    );
+    assert_eq!(log, vec!["1,2,3,a1,a2"]);
+}
+
+#[test]
+fn for_await_awaits_values_of_sync_iterables() {
+    let log = run_capturing(
+        r#"
+        const delayed = (value) => new Promise((resolve) => setTimeout(() => resolve(value), 1));
+        await (async () => {
+            const seen = [];
+            for await (const v of [delayed("x"), "y", Promise.resolve("z")]) {
+                seen.push(typeof v + ":" + v);
+            }
+            let rejected = "";
+            try {
+                for await (const v of [Promise.reject(new Error("nope"))]) seen.push(v);
+            } catch (error) {
+                rejected = error.message;
+            }
+            console.log(seen.join(","), rejected);
+        })();
+        "#,
+    );
+    assert_eq!(log, vec!["string:x,string:y,string:z nope"]);
+}
+
+#[test]
+fn for_await_early_exit_closes_the_iterator() {
+    let log = run_capturing(
+        r#"
+        const events = [];
+        async function* source(tag) {
+            try {
+                yield 1;
+                yield 2;
+            } finally {
+                await null;
+                events.push(tag + ":cleanup");
+            }
+        }
+        function syncSource(tag) {
+            return {
+                [Symbol.iterator]() {
+                    let i = 0;
+                    return {
+                        next() { i += 1; return { value: i, done: i > 3 }; },
+                        return() { events.push(tag + ":return"); return { done: true }; },
+                    };
+                },
+            };
+        }
+        await (async () => {
+            for await (const v of source("break")) {
+                break;
+            }
+            events.push("after-break");
+            try {
+                for await (const v of syncSource("throw")) {
+                    throw new Error("boom");
+                }
+            } catch (error) {
+                events.push("caught:" + error.message);
+            }
+            for await (const v of syncSource("done")) {}
+            outer: for (const round of [1]) {
+                for await (const v of source("labelled")) {
+                    continue outer;
+                }
+            }
+            console.log(events.join(","));
+        })();
+        "#,
+    );
+    assert_eq!(
+        log,
+        vec!["break:cleanup,after-break,throw:return,caught:boom,labelled:cleanup"]
+    );
+}
@@ -8,6 +8,9 @@
 //! - Concise methods, getters, and setters return their
 //!   `MethodDefinition` source; a plain `key: function(){}` keeps the
 //!   function-expression source.
+//! - Async and generator functions keep their prefixes, comments, and
+//!   default initializers, so argument-introspection parsers see the
+//!   parameter list exactly as written.
 //! - Native and bound functions render in the `NativeFunction` form.
 
 use otter_runtime::{Runtime, SourceInput};
@@ -88,6 +91,42 @@ fn class_instance_method_returns_method_definition() {
     );
 }
 
+#[test]
+fn async_function_keeps_comments_and_defaults() {
+    assert_eq!(
+        run("async function load(url, /* opts */ retries = 3) { return url; } load.toString();"),
+        "async function load(url, /* opts */ retries = 3) { return url; }"
+    );
+}
+
+#[test]
+fn generator_function_keeps_star() {
+    assert_eq!(
+        run("function* gen(a) { yield a; } gen.toString();"),
+        "function* gen(a) { yield a; }"
+    );
+}
+
+#[test]
+fn parameter_names_can_be_parsed_from_source() {
+    assert_eq!(
+        run(r#"
+            function inject(service, $http, _cache) {}
+            const params = inject.toString().match(/^function\s*[^(]*\(([^)]*)\)/)[1];
+            params.split(",").map((name) => name.trim()).join("|");
+        "#),
+        "service|$http|_cache"
+    );
+}
+
+#[test]
+fn native_function_via_call_uses_native_form() {
+    assert_eq!(
+        run("Function.prototype.toString.call(Math.max);"),
+        "function max() { [native code] }"
+    );
+}
+
 #[test]
 fn native_function_uses_native_form() {
     assert_eq!(
@@ -64,6 +64,7 @@ fn global_this_default_snapshot() {
 AggregateError w.c function
 Array w.c function
 ArrayBuffer w.c function
+AsyncContext w.c object
 Atomics w.c object
 BigInt w.c function
 BigInt64Array w.c function
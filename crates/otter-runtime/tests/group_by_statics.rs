@@ -0,0 +1,68 @@
+//! Runtime regression coverage for the ES2024 grouping statics.
+//!
+//! # Contents
+//! - `Object.groupBy` returns a null-prototype object keyed by
+//!   ToPropertyKey of the callback result.
+//! - `Map.groupBy` keeps callback results as-is, including object keys.
+//!
+//! # See also
+//! - <https://tc39.es/ecma262/#sec-object.groupby>
+//! - <https://tc39.es/ecma262/#sec-map.groupby>
+
+use otter_runtime::{Runtime, SourceInput};
+
+fn run(source: &str) -> String {
+    let mut rt = Runtime::builder().build().expect("runtime");
+    rt.run_script(SourceInput::from_javascript(source), "<group-by-statics>")
+        .expect("script")
+        .completion_string()
+        .to_string()
+}
+
+#[test]
+fn object_and_map_group_by_split_even_and_odd() {
+    let completion = run(r#"
+        const parity = (n) => (n % 2 === 0 ? "even" : "odd");
+        const indices = [];
+        const grouped = Object.groupBy([1, 2, 3, 4, 5], (n, i) => {
+            indices.push(i);
+            return parity(n);
+        });
+        const byNumber = Object.groupBy([1, 2, 3], (n) => n % 2);
+        const map = Map.groupBy([1, 2, 3, 4, 5], parity);
+        [
+            Object.getPrototypeOf(grouped) === null,
+            Object.keys(grouped).join(","),
+            grouped.odd.join(","),
+            grouped.even.join(","),
+            indices.join(","),
+            Object.keys(byNumber).join(","),
+            map instanceof Map,
+            [...map.keys()].join(","),
+            map.get("odd").join(","),
+            map.get("even").join(","),
+        ].join("|");
+        "#);
+    assert_eq!(
+        completion,
+        "true|odd,even|1,3,5|2,4|0,1,2,3,4|0,1|true|odd,even|1,3,5|2,4"
+    );
+}
+
+#[test]
+fn map_group_by_keeps_object_keys_by_identity() {
+    let completion = run(r#"
+        const small = { label: "small" };
+        const large = { label: "large" };
+        const sizes = Map.groupBy([3, 30, 7, 70], (n) => (n < 10 ? small : large));
+        const lookalike = { label: "small" };
+        [
+            sizes.size,
+            sizes.get(small).join(","),
+            sizes.get(large).join(","),
+            sizes.has(lookalike),
+            [...sizes.keys()].map((k) => k.label).join(","),
+        ].join("|");
+        "#);
+    assert_eq!(completion, "2|3,7|30,70|false|small,large");
+}
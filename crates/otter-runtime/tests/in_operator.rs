@@ -0,0 +1,110 @@
+//! Runtime regression coverage for the relational `in` operator.
+//!
+//! # Contents
+//! - `key in obj` over own, inherited, index, and symbol keys, plus the
+//!   `TypeError` for a primitive right-hand side.
+//! - `#name in obj` brand checks, which never consult the prototype chain
+//!   or a proxy's handler.
+//! - `in` over a `Proxy`, invoking the `has` trap with the target and the
+//!   property key.
+//! - The `[[HasProperty]]` trap invariants that turn a `false` result into a
+//!   `TypeError`.
+//!
+//! # See also
+//! - <https://tc39.es/ecma262/#sec-relational-operators-runtime-semantics-evaluation>
+//! - <https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-hasproperty-p>
+//! - `class_private_members.rs` for the private-name surface itself.
+
+use otter_runtime::{Runtime, SourceInput};
+
+fn run(source: &str) -> String {
+    let mut rt = Runtime::builder().build().expect("runtime");
+    rt.run_script(SourceInput::from_javascript(source), "<in-operator>")
+        .expect("script")
+        .completion_string()
+        .to_string()
+}
+
+#[test]
+fn in_checks_own_inherited_index_and_symbol_keys() {
+    let completion = run(r#"
+        const sym = Symbol("s");
+        const proto = { inherited: 1 };
+        const obj = Object.create(proto);
+        obj.own = undefined;
+        obj[sym] = 0;
+        let primitive;
+        try { "length" in "text"; } catch (e) { primitive = e instanceof TypeError; }
+        [
+            "own" in obj,
+            "inherited" in obj,
+            "missing" in obj,
+            sym in obj,
+            1 in [0, 1],
+            2 in [0, 1],
+            "1" in [0, , 2],
+            primitive,
+        ].join(",");
+        "#);
+    assert_eq!(completion, "true,true,false,true,true,false,false,true");
+}
+
+#[test]
+fn private_brand_check_ignores_prototypes_and_proxies() {
+    let completion = run(r#"
+        class Branded {
+            #field = 1;
+            static has(value) { return #field in value; }
+        }
+        const instance = new Branded();
+        let primitive;
+        try { Branded.has(1); } catch (e) { primitive = e instanceof TypeError; }
+        let trapped = false;
+        const proxy = new Proxy(instance, { has() { trapped = true; return true; } });
+        [
+            Branded.has(instance),
+            Branded.has({}),
+            Branded.has(Object.create(instance)),
+            Branded.has(proxy),
+            trapped,
+            primitive,
+        ].join(",");
+        "#);
+    assert_eq!(completion, "true,false,false,false,false,true");
+}
+
+#[test]
+fn proxy_has_trap_receives_target_and_key() {
+    let completion = run(r#"
+        const target = { real: 1 };
+        const seen = [];
+        const proxy = new Proxy(target, {
+            has(t, key) {
+                seen.push((t === target) + ":" + String(key));
+                return key === "virtual" || key in t;
+            },
+        });
+        const results = ["virtual" in proxy, "real" in proxy, "other" in proxy, 0 in proxy];
+        const passthrough = "real" in new Proxy(target, {});
+        results.join(",") + "|" + seen.join(",") + "|" + passthrough;
+        "#);
+    assert_eq!(
+        completion,
+        "true,true,false,false|true:virtual,true:real,true:other,true:0|true"
+    );
+}
+
+#[test]
+fn proxy_has_trap_false_for_non_configurable_or_non_extensible_throws() {
+    let completion = run(r#"
+        function attempt(target) {
+            const proxy = new Proxy(target, { has() { return false; } });
+            try { return "x" in proxy; } catch (e) { return e.constructor.name; }
+        }
+        const locked = {};
+        Object.defineProperty(locked, "x", { value: 1, configurable: false });
+        const sealed = Object.preventExtensions({ x: 1 });
+        [attempt(locked), attempt(sealed), attempt({ x: 1 }), attempt({})].join(",");
+        "#);
+    assert_eq!(completion, "TypeError,TypeError,false,false");
+}
@@ -11,8 +11,8 @@ use std::sync::{Arc, Mutex};
 use otter_runtime::{
     Runtime, SourceInput,
     inspect::{
-        FrameSnapshot, IcEntryVariant, IcSiteKind, IcSiteState, ShapeTransitionEvent,
-        ShapeTransitionObserver, StepEvent, StepTracer,
+        FrameSnapshot, FunctionTimingTracer, IcEntryVariant, IcSiteKind, IcSiteState,
+        ShapeTransitionEvent, ShapeTransitionObserver, StepEvent, StepTracer,
     },
 };
 
@@ -308,3 +308,73 @@ fn frame_snapshot_carries_register_window_for_main_frame() {
         "expected the computed `42` to appear in some captured register"
     );
 }
+
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl std::io::Write for SharedBuffer {
+    fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+        self.0
+            .lock()
+            .expect("buffer mutex")
+            .extend_from_slice(bytes);
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn function_timing_tracer_reports_calls_and_times_per_function() {
+    let buffer = SharedBuffer::default();
+    let mut runtime = build_runtime();
+    runtime.set_tracer(Some(Box::new(FunctionTimingTracer::new(buffer.clone()))));
+
+    run(
+        &mut runtime,
+        r#"
+        function square(n) {
+            let acc = 0;
+            for (let i = 0; i < n * 20; i++) acc += n;
+            return acc;
+        }
+        function sumSquares(count) {
+            let total = 0;
+            for (let i = 1; i <= count; i++) total += square(i);
+            return total;
+        }
+        sumSquares(3) + sumSquares(2);
+        "#,
+    );
+    // Replacing the tracer drops it, which emits the flat profile.
+    runtime.set_tracer(None);
+
+    let report =
+        String::from_utf8(buffer.0.lock().expect("buffer mutex").clone()).expect("utf-8 report");
+    assert!(
+        report.starts_with("; otter function timing"),
+        "unexpected report: {report}"
+    );
+    let row = |name: &str| -> (u64, f64, f64) {
+        let line = report
+            .lines()
+            .find(|line| line.split_whitespace().last() == Some(name))
+            .unwrap_or_else(|| panic!("no row for {name} in {report}"));
+        let columns: Vec<&str> = line.split_whitespace().collect();
+        (
+            columns[0].parse().expect("calls"),
+            columns[1].parse().expect("total_ms"),
+            columns[2].parse().expect("self_ms"),
+        )
+    };
+    let (square_calls, square_total, square_self) = row("square");
+    let (sum_calls, sum_total, sum_self) = row("sumSquares");
+    assert_eq!(square_calls, 5, "{report}");
+    assert_eq!(sum_calls, 2, "{report}");
+    for (total, self_ms) in [(square_total, square_self), (sum_total, sum_self)] {
+        assert!(self_ms > 0.0 && total >= self_ms, "{report}");
+    }
+    assert!(sum_total >= square_total, "{report}");
+}
@@ -7,6 +7,8 @@
 //!   generated-method guard chain.
 //! - A monomorphic production-tier method site whose guarded splice removes
 //!   the compiled-call boundary entirely.
+//! - A monomorphic plain call to a tiny numeric helper spliced into its
+//!   caller's loop, plus the identity-guard exit when the helper changes.
 //! - A prototype-held method whose inline body reads an own receiver property.
 //! - An inline method whose hoisted local observes function-entry `undefined`.
 //! - Compact scratch reuse across two arguments, assigned locals, and an
@@ -342,6 +344,100 @@ fn production_method_inline_eliminates_compiled_call_boundary() {
     );
 }
 
+const OPTIMIZING_PLAIN_INLINE: &str = r#"
+function add(a, b) {
+  return a + b;
+}
+function sumWith(helper, n) {
+  let total = 0;
+  for (let i = 0; i < n; i++) {
+    total = helper(total, i);
+  }
+  return total;
+}
+
+for (let i = 0; i < 5000; i++) {
+  sumWith(add, 4);
+}
+JSON.stringify([sumWith(add, 100), sumWith(add, 10)]);
+"#;
+
+#[cfg(target_arch = "aarch64")]
+#[test]
+fn production_plain_inline_eliminates_compiled_call_boundary() {
+    let oracle = run(
+        OPTIMIZING_PLAIN_INLINE,
+        "jit-call-optimizing-plain-inline.js",
+        JitSelection::InterpreterOnly,
+    );
+    let compiled = run(
+        OPTIMIZING_PLAIN_INLINE,
+        "jit-call-optimizing-plain-inline.js",
+        JitSelection::ProductionTiered,
+    );
+
+    assert_eq!(compiled.completion, oracle.completion);
+    assert_eq!(compiled.completion, "[4950,45]");
+    assert!(compiled.compile_attempts > 0, "fixture must compile");
+    assert_eq!(compiled.osr_attempts, 0, "fixture must not use loop OSR");
+    assert_eq!(
+        compiled.generated_calls, 0,
+        "spliced helper calls must eliminate the generated call boundary"
+    );
+}
+
+const PLAIN_INLINE_SETUP: &str = r#"
+globalThis.__jitPlainInlineFixture = (() => {
+  function add(a, b) {
+    return a + b;
+  }
+  function sumWith(helper, n) {
+    let total = 0;
+    for (let i = 0; i < n; i++) {
+      total = helper(total, i);
+    }
+    return total;
+  }
+
+  for (let i = 0; i < 5000; i++) {
+    sumWith(add, 4);
+  }
+  return { add, sumWith };
+})();
+"#;
+
+#[cfg(target_arch = "aarch64")]
+#[test]
+fn plain_inline_hit_avoids_runtime_transition() {
+    assert_inline_method_probe(
+        PLAIN_INLINE_SETUP,
+        r#"
+const fixture = globalThis.__jitPlainInlineFixture;
+JSON.stringify([fixture.sumWith(fixture.add, 4), fixture.sumWith(fixture.add, 8)]);
+"#,
+        "jit-inline-plain-hit",
+        "[6,28]",
+        true,
+    );
+}
+
+#[cfg(target_arch = "aarch64")]
+#[test]
+fn plain_inline_side_exits_after_callee_identity_change() {
+    assert_inline_method_probe(
+        PLAIN_INLINE_SETUP,
+        r#"
+const fixture = globalThis.__jitPlainInlineFixture;
+JSON.stringify(fixture.sumWith(function subtract(a, b) {
+  return a - b;
+}, 4));
+"#,
+        "jit-inline-plain-identity-miss",
+        "-6",
+        false,
+    );
+}
+
 const INLINE_METHOD_SETUP: &str = r#"
 globalThis.__jitInlineMethodFixture = (() => {
   function apply(value) {
@@ -0,0 +1,115 @@
+//! One physical module reached under two URLs is instantiated once.
+//!
+//! # Contents
+//! - A module imported through its real directory and through a symlinked
+//!   directory shares state, and the alias is reported as a warning.
+//! - An embedder entry URL through a symlink is canonicalised, so a
+//!   dependency importing the entry back does not evaluate it twice.
+
+#![cfg(unix)]
+
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use otter_runtime::{ConsoleLevel, ConsoleSink, Runtime, SourceInput};
+
+#[derive(Debug, Default)]
+struct WarnCapture {
+    lines: Mutex<Vec<String>>,
+}
+
+impl ConsoleSink for WarnCapture {
+    fn write(&self, level: ConsoleLevel, fields: &[String]) {
+        if matches!(level, ConsoleLevel::Warn) {
+            self.lines
+                .lock()
+                .expect("warn mutex")
+                .push(fields.join(" "));
+        }
+    }
+}
+
+fn eval(runtime: &mut Runtime, source: &str) -> String {
+    runtime
+        .eval(SourceInput::from_javascript(source))
+        .expect("eval")
+        .completion_string()
+        .to_string()
+}
+
+fn write_counter(dir: &Path) {
+    std::fs::create_dir(dir.join("real")).expect("mkdir real");
+    std::fs::write(
+        dir.join("real/counter.js"),
+        "export let count = 0;\nexport function bump() { count += 1; }\n",
+    )
+    .expect("write counter");
+    std::os::unix::fs::symlink(dir.join("real"), dir.join("alias")).expect("symlink alias");
+}
+
+#[test]
+fn symlinked_path_shares_the_direct_module_instance() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let root = tmp.path().canonicalize().expect("canonical tempdir");
+    write_counter(&root);
+    std::fs::write(
+        root.join("main.js"),
+        r#"
+import * as direct from "./real/counter.js";
+import * as aliased from "./alias/counter.js";
+direct.bump();
+aliased.bump();
+globalThis.aliasResult = [direct.count, aliased.count, direct === aliased].join(",");
+"#,
+    )
+    .expect("write main");
+
+    let capture = Arc::new(WarnCapture::default());
+    let mut runtime = Runtime::builder()
+        .console_sink(capture.clone())
+        .build()
+        .expect("runtime");
+    runtime.run_file(root.join("main.js")).expect("run main");
+    assert_eq!(eval(&mut runtime, "globalThis.aliasResult"), "2,2,true");
+
+    let warnings = capture.lines.lock().expect("warn mutex").clone();
+    assert_eq!(
+        warnings.len(),
+        1,
+        "expected one alias warning: {warnings:?}"
+    );
+    let alias_url = format!("file://{}", root.join("alias/counter.js").display());
+    let real_url = format!("file://{}", root.join("real/counter.js").display());
+    assert!(
+        warnings[0].contains(&alias_url) && warnings[0].contains(&real_url),
+        "warning should name both spellings: {}",
+        warnings[0]
+    );
+}
+
+#[test]
+fn symlinked_entry_url_is_canonicalised() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let root = tmp.path().canonicalize().expect("canonical tempdir");
+    write_counter(&root);
+    let entry_source = r#"
+import { count } from "./counter.js";
+globalThis.entryRuns = (globalThis.entryRuns ?? 0) + 1;
+export const entryCount = count;
+"#;
+    std::fs::write(root.join("real/entry.js"), entry_source).expect("write entry");
+    std::fs::write(
+        root.join("real/counter.js"),
+        "import \"./entry.js\";\nexport let count = 0;\n",
+    )
+    .expect("write cyclic counter");
+
+    let mut runtime = Runtime::builder().build().expect("runtime");
+    runtime
+        .run_module_source(
+            SourceInput::from_javascript(entry_source),
+            &format!("file://{}", root.join("alias/entry.js").display()),
+        )
+        .expect("run entry through symlink");
+    assert_eq!(eval(&mut runtime, "globalThis.entryRuns"), "1");
+}
@@ -0,0 +1,118 @@
+//! Evaluation order of module graphs containing top-level `await`.
+//!
+//! # Contents
+//! - A module importing a dependency that awaits a timer runs its body only
+//!   after that await settles.
+//! - A synchronous sibling dependency does not wait for the async one: it
+//!   runs while the async dependency is parked, in import order.
+//! - A module that only transitively depends on an async module still waits
+//!   for it (`[[AsyncEvaluation]]` propagates to dependents).
+//!
+//! # Invariants
+//! - Every body logs exactly once; the full log is compared, so a module
+//!   that never resumes fails the test rather than passing silently.
+//!
+//! # See also
+//! - <https://tc39.es/ecma262/#sec-innermoduleevaluation>
+//! - <https://tc39.es/ecma262/#sec-async-module-execution-fulfilled>
+//! - `module_cycle_and_lifecycle.rs` — post-order evaluation of cycles.
+
+use std::sync::{Arc, Mutex};
+
+use otter_runtime::{ConsoleLevel, ConsoleSink, Otter};
+
+#[derive(Debug, Default)]
+struct LogCapture {
+    events: Mutex<Vec<String>>,
+}
+
+impl ConsoleSink for LogCapture {
+    fn write(&self, level: ConsoleLevel, fields: &[String]) {
+        if matches!(level, ConsoleLevel::Log) {
+            self.events
+                .lock()
+                .expect("log mutex")
+                .push(fields.join(" "));
+        }
+    }
+}
+
+async fn run_graph(files: &[(&str, &str)]) -> Vec<String> {
+    let dir = tempfile::tempdir().expect("tempdir");
+    for (name, source) in files {
+        std::fs::write(dir.path().join(name), source).expect("write module");
+    }
+    let log = Arc::new(LogCapture::default());
+    let otter = Otter::builder()
+        .console_sink(log.clone())
+        .build()
+        .expect("otter build");
+    otter
+        .run_module(dir.path().join(files[0].0))
+        .await
+        .expect("run entry");
+    log.events.lock().expect("log mutex").clone()
+}
+
+const SLOW_DEPENDENCY: &str = r#"
+    console.log("b:start");
+    export const value = await new Promise((resolve) => {
+        setTimeout(() => {
+            console.log("b:timer");
+            resolve("ready");
+        }, 5);
+    });
+    console.log("b:end");
+"#;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn importer_runs_after_dependency_top_level_await() {
+    let log = run_graph(&[
+        (
+            "a.mjs",
+            r#"
+                import { value } from "./b.mjs";
+                console.log("a:" + value);
+            "#,
+        ),
+        ("b.mjs", SLOW_DEPENDENCY),
+    ])
+    .await;
+    assert_eq!(log, ["b:start", "b:timer", "b:end", "a:ready"]);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn sync_sibling_runs_while_async_dependency_is_parked() {
+    let log = run_graph(&[
+        (
+            "a.mjs",
+            r#"
+                import { value } from "./b.mjs";
+                import { name } from "./c.mjs";
+                import { relayed } from "./d.mjs";
+                console.log(["a", value, name, relayed].join(":"));
+            "#,
+        ),
+        ("b.mjs", SLOW_DEPENDENCY),
+        (
+            "c.mjs",
+            r#"
+                console.log("c");
+                export const name = "c";
+            "#,
+        ),
+        (
+            "d.mjs",
+            r#"
+                import { value } from "./b.mjs";
+                console.log("d:" + value);
+                export const relayed = "d";
+            "#,
+        ),
+    ])
+    .await;
+    assert_eq!(
+        log,
+        ["b:start", "c", "b:timer", "b:end", "d:ready", "a:ready:c:d"]
+    );
+}
@@ -0,0 +1,95 @@
+//! Numeric literal forms accepted and rejected by the compiler.
+//!
+//! # Contents
+//! - Numeric separators in decimal, binary, octal, hex and exponent forms.
+//! - BigInt literals in every radix, with separators.
+//! - Legacy octal (`0777`) and non-octal-decimal (`089`) integers: accepted
+//!   in sloppy code, an early `SyntaxError` in strict code.
+//!
+//! # See also
+//! - <https://tc39.es/ecma262/#sec-literals-numeric-literals>
+//! - <https://tc39.es/ecma262/#sec-literals-numeric-literals-static-semantics-early-errors>
+
+use otter_runtime::{Runtime, SourceInput};
+
+fn run(source: &str) -> String {
+    let mut rt = Runtime::builder().build().expect("runtime");
+    rt.run_script(SourceInput::from_javascript(source), "<numeric-literals>")
+        .expect("script")
+        .completion_string()
+        .to_string()
+}
+
+#[test]
+fn separated_numeric_literals_compile_to_their_values() {
+    let completion = run(r#"
+        [
+            1_000_000,
+            0b1010_1010,
+            0o7_7_7,
+            0xFF_FF,
+            1_0.2_5,
+            1e1_0,
+            .5_5,
+        ].join(",");
+        "#);
+    assert_eq!(completion, "1000000,170,511,65535,10.25,10000000000,0.55");
+}
+
+#[test]
+fn bigint_literals_compile_in_every_radix() {
+    let completion = run(r#"
+        [
+            10n,
+            0n,
+            1_000_000_000_000_000_000n,
+            0b1_0n,
+            0o1_7n,
+            0xF_Fn,
+            typeof 10n,
+            10n === 0xAn,
+        ].join(",");
+        "#);
+    assert_eq!(
+        completion,
+        "10,0,1000000000000000000000,2,15,255,bigint,true"
+    );
+}
+
+#[test]
+fn legacy_octal_is_accepted_in_sloppy_code() {
+    assert_eq!(run("[0777, 010, 089].join(',')"), "511,8,89");
+}
+
+#[test]
+fn legacy_octal_is_a_syntax_error_in_strict_code() {
+    let mut rt = Runtime::builder().build().expect("runtime");
+    let err = rt
+        .run_script(
+            SourceInput::from_javascript("'use strict';\n0777;\n"),
+            "<numeric-literals>",
+        )
+        .expect_err("strict legacy octal must not compile");
+    let rendered = format!("{err:?}");
+    assert!(
+        rendered.contains("legacy octal") && rendered.contains("0777"),
+        "unexpected error: {rendered}"
+    );
+
+    // The same early error is catchable as a `SyntaxError` through `eval`,
+    // for both legacy octal and non-octal-decimal forms and inside a
+    // strict function of otherwise sloppy code.
+    let completion = run(r#"
+        function kind(source) {
+            try { eval(source); return "ok"; } catch (e) { return e.constructor.name; }
+        }
+        [
+            kind("'use strict'; 0777"),
+            kind("'use strict'; 089"),
+            kind("(function () { 'use strict'; return 010; })"),
+            kind("0777"),
+            kind("'use strict'; 0o777"),
+        ].join(",");
+        "#);
+    assert_eq!(completion, "SyntaxError,SyntaxError,SyntaxError,ok,ok");
+}
@@ -0,0 +1,82 @@
+//! Per-opcode execution counters.
+//!
+//! # Contents
+//! - Counting is off by default and reports `None`.
+//! - An enabled histogram is sorted by descending count and covers the
+//!   opcodes a loop executes.
+//! - `reset_runtime_budget_stats` zeroes the counters between evals.
+//!
+//! # Invariants
+//! - Runtimes run interpreter-only: JIT-compiled code bypasses the dispatch
+//!   loop and would make the loop counts depend on tier-up timing.
+
+use otter_runtime::{JitSelection, Runtime, SourceInput};
+
+const LOOP: &str = "let sum = 0; for (let i = 0; i < 500; i++) { sum += i; } sum";
+
+fn runtime() -> Runtime {
+    Runtime::builder()
+        .jit_selection(JitSelection::InterpreterOnly)
+        .build()
+        .expect("runtime")
+}
+
+fn eval(runtime: &mut Runtime, source: &str) -> String {
+    runtime
+        .eval(SourceInput::from_javascript(source))
+        .expect("eval")
+        .completion_string()
+        .to_string()
+}
+
+#[test]
+fn counters_are_off_by_default() {
+    let mut runtime = runtime();
+    eval(&mut runtime, LOOP);
+    assert_eq!(runtime.opcode_histogram(), None);
+}
+
+#[test]
+fn histogram_is_sorted_and_counts_the_loop() {
+    let mut runtime = runtime();
+    runtime.enable_opcode_counters();
+    assert_eq!(eval(&mut runtime, LOOP), "124750");
+
+    let histogram = runtime.opcode_histogram().expect("histogram");
+    let entries = histogram.entries();
+    assert!(!entries.is_empty());
+    assert!(
+        entries.windows(2).all(|pair| pair[0].1 >= pair[1].1),
+        "{entries:?}"
+    );
+    assert!(entries.iter().all(|(_, count)| *count > 0));
+    assert!(histogram.total() >= 500, "{entries:?}");
+    // The loop body dominates the script's straight-line setup.
+    assert!(entries[0].1 >= 500, "{entries:?}");
+    assert_eq!(histogram.count(entries[0].0), entries[0].1);
+
+    runtime.disable_opcode_counters();
+    assert_eq!(runtime.opcode_histogram(), None);
+}
+
+#[test]
+fn reset_zeroes_counters_between_evals() {
+    let mut runtime = runtime();
+    runtime.enable_opcode_counters();
+    eval(&mut runtime, LOOP);
+    let first = runtime.opcode_histogram().expect("histogram").total();
+    assert!(first > 0);
+
+    runtime.reset_runtime_budget_stats();
+    assert!(
+        runtime
+            .opcode_histogram()
+            .expect("histogram")
+            .entries()
+            .is_empty()
+    );
+
+    eval(&mut runtime, "1 + 1");
+    let second = runtime.opcode_histogram().expect("histogram").total();
+    assert!(second > 0 && second < first, "{second} vs {first}");
+}
@@ -0,0 +1,82 @@
+//! Directive-pragma optimization hints reaching the JIT.
+//!
+//! # Contents
+//! - A `"use optimize"` function compiles on its first call instead of
+//!   waiting for the tier-up threshold.
+//! - The same body without the pragma stays interpreted after one call.
+//! - A `"use noinline"` callee computes the same result as the interpreter.
+//!
+//! # See also
+//! - `otter_bytecode::Function::optimization_hint`
+
+use otter_runtime::{JitSelection, Runtime, SourceInput};
+
+fn run(selection: JitSelection, source: &str) -> (String, u64) {
+    let mut runtime = Runtime::builder()
+        .jit_selection(selection)
+        .jit_osr_threshold(u32::MAX)
+        .build()
+        .expect("runtime");
+    let completion = runtime
+        .run_script(
+            SourceInput::from_javascript(source.to_string()),
+            "optimization-hints.js",
+        )
+        .expect("script")
+        .completion_string()
+        .to_owned();
+    (completion, runtime.execution_stats().jit_compile_attempts)
+}
+
+#[test]
+fn force_compile_hint_compiles_on_first_call() {
+    let (hinted, hinted_attempts) = run(
+        JitSelection::Template,
+        r#"
+            function add(a, b) {
+                "use optimize";
+                return a + b;
+            }
+            add(2, 3);
+        "#,
+    );
+    let (plain, plain_attempts) = run(
+        JitSelection::Template,
+        r#"
+            function add(a, b) {
+                return a + b;
+            }
+            add(2, 3);
+        "#,
+    );
+    assert_eq!(hinted, "5");
+    assert_eq!(plain, "5");
+    assert!(
+        hinted_attempts > 0,
+        "a force-compile body must compile on its first entry"
+    );
+    assert_eq!(plain_attempts, 0, "one call stays below the threshold");
+}
+
+#[test]
+fn never_inline_callee_matches_interpreter() {
+    let source = r#"
+        function leaf(x) {
+            "use noinline";
+            return x * 2 + 1;
+        }
+        function caller(n) {
+            let sum = 0;
+            for (let i = 0; i < n; i++) sum += leaf(i);
+            return sum;
+        }
+        let total = 0;
+        for (let i = 0; i < 200; i++) total += caller(10);
+        total;
+    "#;
+    let (oracle, _) = run(JitSelection::InterpreterOnly, source);
+    let (compiled, attempts) = run(JitSelection::Template, source);
+    assert_eq!(compiled, oracle);
+    assert_eq!(compiled, "20000");
+    assert!(attempts > 0, "fixture must tier up the caller");
+}
@@ -0,0 +1,91 @@
+//! Runtime regression coverage for pre-sized array and object literals.
+//!
+//! # Contents
+//! - A 1000-element array literal, past the dense `NewArray` operand cap,
+//!   built through `NewArrayWithCapacity` plus the `ArrayPush` fill sequence.
+//! - Spread and hole elements mixed into a pre-sized array literal.
+//! - A many-property object literal built through `NewObjectWithCapacity`.
+//!
+//! # Invariants
+//! - The capacity hint is storage-only: length, holes, key order, and
+//!   property values match the unsized lowering exactly.
+//!
+//! # See also
+//! - <https://tc39.es/ecma262/#sec-array-initializer>
+//! - <https://tc39.es/ecma262/#sec-object-initializer>
+
+use otter_runtime::{Runtime, SourceInput};
+
+fn run(source: &str) -> String {
+    let mut rt = Runtime::builder().build().expect("runtime");
+    rt.run_script(SourceInput::from_javascript(source), "<presized-literals>")
+        .expect("script")
+        .completion_string()
+        .to_string()
+}
+
+#[test]
+fn thousand_element_array_literal_keeps_every_element() {
+    let elements = (0..1000)
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    let completion = run(&format!(
+        r#"
+        const values = [{elements}];
+        let sum = 0;
+        for (const value of values) sum += value;
+        [
+            values.length,
+            values[0],
+            values[999],
+            sum,
+            values.every((value, index) => value === index),
+        ].join("|");
+        "#
+    ));
+    assert_eq!(completion, "1000|0|999|499500|true");
+}
+
+#[test]
+fn presized_array_literal_keeps_spread_and_holes() {
+    let tail = vec!["7"; 250].join(",");
+    let completion = run(&format!(
+        r#"
+        const middle = ["a", "b"];
+        const values = [1, , ...middle, {tail}];
+        [
+            values.length,
+            1 in values,
+            values[2] + values[3],
+            values[4],
+            values[values.length - 1],
+        ].join("|");
+        "#
+    ));
+    assert_eq!(completion, "254|false|ab|7|7");
+}
+
+#[test]
+fn presized_object_literal_keeps_key_order_and_values() {
+    let properties = (0..40)
+        .map(|i| format!("k{i}: {i}"))
+        .collect::<Vec<_>>()
+        .join(",");
+    let completion = run(&format!(
+        r#"
+        const spread = {{ extra: true }};
+        const object = {{ {properties}, ...spread, get last() {{ return "g"; }} }};
+        const keys = Object.keys(object);
+        [
+            keys.length,
+            keys[0],
+            keys[39],
+            keys[40],
+            object.k0 + object.k39,
+            object.last,
+        ].join("|");
+        "#
+    ));
+    assert_eq!(completion, "42|k0|k39|extra|39|g");
+}
@@ -0,0 +1,93 @@
+//! Runtime regression coverage for revoked `Proxy.revocable` proxies.
+//!
+//! # Contents
+//! - Every internal-method entry point on a revoked proxy throws a
+//!   catchable `TypeError` naming the operation.
+//! - Calling `revoke` again is a no-op.
+//!
+//! # Invariants
+//! - `typeof` does not consult the handler, so it keeps reporting the
+//!   proxy's original callability after revocation (§13.5.3).
+//!
+//! # See also
+//! - <https://tc39.es/ecma262/#sec-proxy.revocable>
+//! - <https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots>
+
+use otter_runtime::{Runtime, SourceInput};
+
+fn run(source: &str) -> String {
+    let mut rt = Runtime::builder().build().expect("runtime");
+    rt.run_script(SourceInput::from_javascript(source), "<proxy-revocation>")
+        .expect("script")
+        .completion_string()
+        .to_string()
+}
+
+#[test]
+fn revoked_proxy_throws_type_error_from_every_trap() {
+    let completion = run(r#"
+        const { proxy, revoke } = Proxy.revocable({ x: 1 }, {});
+        const { proxy: fn, revoke: revokeFn } = Proxy.revocable(function () {}, {});
+        revoke();
+        revokeFn();
+        const outcome = (f) => {
+            try {
+                f();
+                return "no throw";
+            } catch (error) {
+                return error instanceof TypeError ? "TypeError" : String(error);
+            }
+        };
+        [
+            outcome(() => proxy.x),
+            outcome(() => { proxy.x = 2; }),
+            outcome(() => "x" in proxy),
+            outcome(() => delete proxy.x),
+            outcome(() => Object.keys(proxy)),
+            outcome(() => Object.getPrototypeOf(proxy)),
+            outcome(() => Object.defineProperty(proxy, "y", { value: 1 })),
+            outcome(() => fn()),
+            outcome(() => new fn()),
+            typeof proxy,
+            typeof fn,
+        ].join("|");
+        "#);
+    assert_eq!(
+        completion,
+        "TypeError|TypeError|TypeError|TypeError|TypeError|TypeError|TypeError|TypeError|TypeError|object|function"
+    );
+}
+
+#[test]
+fn revoked_proxy_error_names_the_operation() {
+    let completion = run(r#"
+        const { proxy, revoke } = Proxy.revocable({}, {});
+        revoke();
+        let message;
+        try {
+            proxy.x;
+        } catch (error) {
+            message = error.message;
+        }
+        message.includes("get") && message.includes("revoked");
+        "#);
+    assert_eq!(completion, "true");
+}
+
+#[test]
+fn revoking_twice_is_harmless() {
+    let completion = run(r#"
+        const { proxy, revoke } = Proxy.revocable({ x: 1 }, {});
+        const before = proxy.x;
+        const first = revoke();
+        const second = revoke();
+        let threw = false;
+        try {
+            proxy.x;
+        } catch (error) {
+            threw = error instanceof TypeError;
+        }
+        [before, first, second, threw].join("|");
+        "#);
+    assert_eq!(completion, "1|||true");
+}
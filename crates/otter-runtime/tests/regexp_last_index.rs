@@ -0,0 +1,92 @@
+//! `lastIndex` state across global / sticky match loops.
+//!
+//! # Contents
+//! - `while ((m = re.exec(s)))` over a global regex visits every match,
+//!   advances `lastIndex` to each match end, and resets it to 0 on failure.
+//! - Sticky matching only succeeds at `lastIndex` and resets it on a miss;
+//!   a non-global, non-sticky regex ignores and preserves `lastIndex`.
+//! - `String.prototype.matchAll` yields capture groups and terminates on
+//!   zero-width patterns by stepping past empty matches, by code point under
+//!   the `u` flag.
+//!
+//! # See also
+//! - <https://tc39.es/ecma262/#sec-regexpbuiltinexec>
+//! - <https://tc39.es/ecma262/#sec-%regexpstringiteratorprototype%.next>
+
+use otter_runtime::{Runtime, SourceInput};
+
+fn run(source: &str) -> String {
+    let mut rt = Runtime::builder().build().expect("runtime");
+    rt.run_script(SourceInput::from_javascript(source), "<regexp-last-index>")
+        .expect("script")
+        .completion_string()
+        .to_string()
+}
+
+#[test]
+fn global_exec_loop_advances_and_resets_last_index() {
+    let completion = run(r#"
+        const re = /o(\w)/g;
+        const s = "foo boa bob";
+        const seen = [];
+        let m;
+        while ((m = re.exec(s))) {
+            seen.push(m[0] + "@" + m.index + "->" + re.lastIndex + ":" + m[1]);
+        }
+        seen.push("end:" + re.lastIndex);
+        seen.join(" ");
+        "#);
+    assert_eq!(completion, "oo@1->3:o oa@5->7:a ob@9->11:b end:0");
+}
+
+#[test]
+fn sticky_and_plain_exec_respect_last_index() {
+    let completion = run(r#"
+        const sticky = /a/y;
+        const out = [];
+        sticky.lastIndex = 1;
+        out.push(String(sticky.exec("aab") && sticky.lastIndex));
+        out.push(String(sticky.exec("aab")), String(sticky.lastIndex));
+
+        const plain = /b/;
+        plain.lastIndex = 5;
+        out.push(String(plain.exec("abc").index), String(plain.lastIndex));
+
+        const global = /a/g;
+        global.lastIndex = 10;
+        out.push(String(global.exec("aaa")), String(global.lastIndex));
+        out.join(",");
+        "#);
+    assert_eq!(completion, "2,null,0,1,5,null,0");
+}
+
+#[test]
+fn match_all_yields_capture_groups() {
+    let completion = run(r#"
+        const re = /(?<key>\w+)=(\d+)/g;
+        const parts = [];
+        for (const m of "a=1, bb=22, ccc=333".matchAll(re)) {
+            parts.push(m.index + ":" + m.groups.key + "/" + m[2]);
+        }
+        parts.push("lastIndex:" + re.lastIndex);
+        parts.join(" ");
+        "#);
+    assert_eq!(completion, "0:a/1 5:bb/22 12:ccc/333 lastIndex:0");
+}
+
+#[test]
+fn zero_width_match_all_terminates() {
+    let completion = run(r#"
+        const indices = (iter) => Array.from(iter, (m) => m.index).join("|");
+        [
+            indices("abc".matchAll(/(?:)/g)),
+            indices("".matchAll(/(?:)/g)),
+            indices("a,b".matchAll(/\b/g)),
+            indices("\u{1F600}x".matchAll(/(?:)/g)),
+            indices("\u{1F600}x".matchAll(/(?:)/gu)),
+            "abc".match(/(?:)/g).length,
+            "abc".replace(/(?:)/g, "-"),
+        ].join(" ");
+        "#);
+    assert_eq!(completion, "0|1|2|3 0 0|1|2|3 0|1|2|3 0|2|3 4 -a-b-c-");
+}
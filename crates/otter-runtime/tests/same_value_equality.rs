@@ -0,0 +1,56 @@
+//! Runtime regression coverage for SameValue vs SameValueZero call sites.
+//!
+//! # Contents
+//! - `Map` / `Set` keys and `Array.prototype.includes` use SameValueZero.
+//! - `Object.is` uses SameValue.
+//!
+//! # See also
+//! - <https://tc39.es/ecma262/#sec-samevalue>
+//! - <https://tc39.es/ecma262/#sec-samevaluezero>
+
+use otter_runtime::{Runtime, SourceInput};
+
+fn run(source: &str) -> String {
+    let mut rt = Runtime::builder().build().expect("runtime");
+    rt.run_script(
+        SourceInput::from_javascript(source),
+        "<same-value-equality>",
+    )
+    .expect("script")
+    .completion_string()
+    .to_string()
+}
+
+#[test]
+fn collections_and_includes_use_same_value_zero() {
+    let completion = run(r#"
+        const set = new Set([NaN, 0]);
+        const map = new Map([[-0, "negative zero"]]);
+        map.set(+0, "positive zero");
+        [
+            set.has(NaN),
+            set.has(-0),
+            set.size,
+            map.size,
+            map.get(-0),
+            Object.is([...map.keys()][0], -0),
+            [NaN].includes(NaN),
+            [NaN].indexOf(NaN),
+            [-0].includes(+0),
+        ].join("|");
+        "#);
+    assert_eq!(completion, "true|true|2|1|positive zero|false|true|-1|true");
+}
+
+#[test]
+fn object_is_uses_same_value() {
+    let completion = run(r#"
+        [
+            Object.is(-0, +0),
+            Object.is(NaN, NaN),
+            Object.is(0, 0),
+            -0 === +0,
+        ].join("|");
+        "#);
+    assert_eq!(completion, "false|true|true|true");
+}
@@ -0,0 +1,149 @@
+//! Stack-depth limits surfacing as a catchable `RangeError`.
+//!
+//! # Contents
+//! - Unbounded JS recursion throws `RangeError: Maximum call stack size
+//!   exceeded` that a surrounding `try`/`catch` handles, and the runtime
+//!   keeps recursing normally afterwards.
+//! - The configured `max_stack_depth` bounds how deep the recursion gets.
+//! - Recursion through native callbacks (`Array.prototype.map`) hits the
+//!   re-entry limit and throws the same class.
+//! - `JSON.stringify` of a deeply nested object throws a `RangeError`
+//!   instead of aborting.
+//! - An overflow nothing catches still fails the run.
+//!
+//! # See also
+//! - `otter_vm::STACK_OVERFLOW_MESSAGE`
+
+use otter_runtime::{Runtime, SourceInput};
+
+fn run_with(runtime: &mut Runtime, source: &str) -> String {
+    runtime
+        .run_script(SourceInput::from_javascript(source), "<stack-overflow>")
+        .expect("script")
+        .completion_string()
+        .to_string()
+}
+
+/// Run `test` on a stack the size of the isolate thread's: native
+/// re-entry and the JSON depth cap bound recursion against *that* stack,
+/// and the default test-thread stack is an order of magnitude smaller.
+fn on_isolate_stack<T: Send + 'static>(test: impl FnOnce() -> T + Send + 'static) -> T {
+    std::thread::Builder::new()
+        .stack_size(16 * 1024 * 1024)
+        .spawn(test)
+        .expect("spawn")
+        .join()
+        .expect("test thread")
+}
+
+fn run(source: &'static str) -> String {
+    on_isolate_stack(|| {
+        let mut runtime = Runtime::builder().build().expect("runtime");
+        run_with(&mut runtime, source)
+    })
+}
+
+#[test]
+fn deep_js_recursion_throws_catchable_range_error() {
+    let out = run(r#"
+        function down(n) { return down(n + 1) + 1; }
+        function count(n) { return n === 0 ? 0 : 1 + count(n - 1); }
+        let caught;
+        try {
+            down(0);
+        } catch (e) {
+            caught = e;
+        }
+        [
+            caught instanceof RangeError,
+            caught.message,
+            count(100),
+        ].join(",");
+    "#);
+    assert_eq!(out, "true,Maximum call stack size exceeded,100");
+}
+
+#[test]
+fn configured_stack_depth_bounds_recursion() {
+    let out = on_isolate_stack(|| {
+        let mut runtime = Runtime::builder()
+            .max_stack_depth(64)
+            .build()
+            .expect("runtime");
+        run_with(
+            &mut runtime,
+            r#"
+                let deepest = 0;
+                function down(n) { deepest = n; return down(n + 1); }
+                let name;
+                try { down(1); } catch (e) { name = e.name; }
+                [name, deepest > 32 && deepest <= 64].join(",");
+            "#,
+        )
+    });
+    assert_eq!(out, "RangeError,true");
+}
+
+#[test]
+fn recursion_through_native_callbacks_throws_range_error() {
+    let out = run(r#"
+        function viaMap(n) { return [n].map((x) => viaMap(x + 1))[0]; }
+        let result;
+        try {
+            viaMap(0);
+            result = "no throw";
+        } catch (e) {
+            result = [
+                e instanceof RangeError,
+                /Maximum call stack size exceeded/.test(e.message),
+            ].join(",");
+        }
+        result + "," + [1, 2, 3].map((x) => x * 2).join("");
+    "#);
+    assert_eq!(out, "true,true,246");
+}
+
+#[test]
+fn deeply_nested_json_stringify_throws_range_error() {
+    let out = run(r#"
+        let nested = {};
+        for (let i = 0; i < 5000; i++) nested = { next: nested };
+        let arrays = [];
+        for (let i = 0; i < 5000; i++) arrays = [arrays];
+        const seen = [];
+        for (const value of [nested, arrays]) {
+            try {
+                JSON.stringify(value);
+                seen.push("no throw");
+            } catch (e) {
+                seen.push(e instanceof RangeError);
+                seen.push(/Maximum call stack size exceeded/.test(e.message));
+            }
+        }
+        let shallow = {};
+        for (let i = 0; i < 10; i++) shallow = { next: shallow };
+        seen.push(JSON.stringify(shallow).length);
+        seen.join(",");
+    "#);
+    assert_eq!(out, "true,true,true,true,92");
+}
+
+#[test]
+fn uncaught_overflow_still_fails_the_run() {
+    let error = on_isolate_stack(|| {
+        let mut runtime = Runtime::builder().build().expect("runtime");
+        runtime
+            .run_script(
+                SourceInput::from_javascript("function down() { return down(); } down();"),
+                "<stack-overflow>",
+            )
+            .expect_err("an uncaught overflow must fail the run")
+            .to_string()
+    });
+    assert!(
+        error
+            .to_ascii_lowercase()
+            .contains("maximum call stack size exceeded"),
+        "unexpected error: {error}"
+    );
+}
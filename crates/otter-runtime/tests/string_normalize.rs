@@ -0,0 +1,70 @@
+//! Runtime regression coverage for `String.prototype.normalize`.
+//!
+//! # Contents
+//! - Composed and decomposed sequences agree under NFC / NFD.
+//! - Compatibility forms fold ligatures and width variants under NFKC / NFKD.
+//! - Unknown forms throw `RangeError`.
+//!
+//! # Invariants
+//! - `undefined` (or a missing argument) selects NFC.
+//! - Lone surrogates survive normalization unchanged.
+//!
+//! # See also
+//! - <https://tc39.es/ecma262/#sec-string.prototype.normalize>
+
+use otter_runtime::{Runtime, SourceInput};
+
+fn run(source: &str) -> String {
+    let mut rt = Runtime::builder().build().expect("runtime");
+    rt.run_script(SourceInput::from_javascript(source), "<string-normalize>")
+        .expect("script")
+        .completion_string()
+        .to_string()
+}
+
+#[test]
+fn normalize_makes_composed_and_decomposed_sequences_equal() {
+    let completion = run(r#"
+        const composed = "\u00e9";
+        const decomposed = "e\u0301";
+        [
+            composed === decomposed,
+            composed.normalize() === decomposed.normalize("NFC"),
+            decomposed.normalize(undefined).length,
+            composed.normalize("NFD") === decomposed,
+            composed.normalize("NFD").length,
+            "\ud800x".normalize() === "\ud800x",
+        ].join("|");
+        "#);
+    assert_eq!(completion, "false|true|1|true|2|true");
+}
+
+#[test]
+fn normalize_compatibility_forms_fold_ligatures() {
+    let completion = run(r#"
+        [
+            "\ufb01".normalize("NFC") === "\ufb01",
+            "\ufb01".normalize("NFKC"),
+            "\uff21\u2460".normalize("NFKC"),
+            "\u1e9b\u0323".normalize("NFKD") === "s\u0323\u0307",
+        ].join("|");
+        "#);
+    assert_eq!(completion, "true|fi|A1|true");
+}
+
+#[test]
+fn normalize_rejects_unknown_forms_with_range_error() {
+    let completion = run(r#"
+        const out = [];
+        for (const form of ["nfc", "NFX", ""]) {
+            try {
+                "a".normalize(form);
+                out.push("no-throw");
+            } catch (e) {
+                out.push(e instanceof RangeError);
+            }
+        }
+        out.join("|");
+        "#);
+    assert_eq!(completion, "true|true|true");
+}
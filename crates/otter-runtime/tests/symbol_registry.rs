@@ -0,0 +1,80 @@
+//! `Symbol.for` / `Symbol.keyFor` over the global symbol registry.
+//!
+//! # Contents
+//! - `Symbol.for(key)` returns one symbol per key, after `ToString` on the
+//!   key, and distinct keys (including lone surrogates) stay distinct.
+//! - `Symbol.keyFor` round-trips registered symbols and returns
+//!   `undefined` for `Symbol()` symbols and well-known symbols.
+//! - Non-symbol `keyFor` arguments throw a `TypeError`.
+//!
+//! # See also
+//! - <https://tc39.es/ecma262/#sec-symbol.for>
+//! - <https://tc39.es/ecma262/#sec-symbol.keyfor>
+
+use otter_runtime::{Runtime, SourceInput};
+
+fn run(source: &str) -> String {
+    let mut rt = Runtime::builder().build().expect("runtime");
+    rt.run_script(SourceInput::from_javascript(source), "<symbol-registry>")
+        .expect("script")
+        .completion_string()
+        .to_string()
+}
+
+#[test]
+fn symbol_for_returns_one_symbol_per_key() {
+    let out = run(r#"
+        const a = Symbol.for("app.key");
+        const keyObject = { toString() { return "app.key"; } };
+        [
+            a === Symbol.for("app.key"),
+            a === Symbol.for(keyObject),
+            a === Symbol.for("app.other"),
+            a === Symbol("app.key"),
+            Symbol.for() === Symbol.for("undefined"),
+            Symbol.for(1) === Symbol.for("1"),
+            Symbol.for("\uD800") === Symbol.for("\uDC00"),
+            Symbol.for("\uD800") === Symbol.for("\uFFFD"),
+            a.description,
+        ].join(",");
+    "#);
+    assert_eq!(out, "true,true,false,false,true,true,false,false,app.key");
+}
+
+#[test]
+fn key_for_round_trips_registered_symbols() {
+    let out = run(r#"
+        const keys = ["app.key", "", "\uD800tail", "undefined"];
+        const roundTrips = keys.map((k) => Symbol.keyFor(Symbol.for(k)) === k);
+        [
+            roundTrips.join("/"),
+            Symbol.keyFor(Symbol.for()),
+            typeof Symbol.keyFor(Symbol.for("")),
+        ].join(",");
+    "#);
+    assert_eq!(out, "true/true/true/true,undefined,string");
+}
+
+#[test]
+fn key_for_unregistered_symbols_is_undefined() {
+    let out = run(r#"
+        Symbol.for("shadow");
+        const errors = [];
+        for (const bad of ["shadow", 1, {}, undefined]) {
+            try { Symbol.keyFor(bad); } catch (e) { errors.push(e.constructor.name); }
+        }
+        [
+            Symbol.keyFor(Symbol()),
+            Symbol.keyFor(Symbol("shadow")),
+            Symbol.keyFor(Symbol.iterator),
+            Symbol.keyFor(Symbol.asyncIterator),
+            Symbol.keyFor(Symbol.hasInstance),
+            errors.join("/"),
+        ].map(String).join(",");
+    "#);
+    assert_eq!(
+        out,
+        "undefined,undefined,undefined,undefined,undefined,\
+         TypeError/TypeError/TypeError/TypeError"
+    );
+}
@@ -0,0 +1,87 @@
+//! Runtime regression coverage for `Temporal.Instant`.
+//!
+//! # Contents
+//! - `from` parses ISO strings with a UTC offset onto the UTC timeline.
+//! - `compare` orders instants; `equals` agrees with it.
+//! - `add` / `subtract` accept time-unit durations; calendar units throw
+//!   `RangeError`.
+//! - `until` / `since` and `toZonedDateTimeISO` round-trip through
+//!   `temporal_rs`.
+//!
+//! # See also
+//! - <https://tc39.es/proposal-temporal/#sec-temporal-instant-objects>
+
+use otter_runtime::{Runtime, SourceInput};
+
+fn run(source: &str) -> String {
+    let mut rt = Runtime::builder().build().expect("runtime");
+    rt.run_script(SourceInput::from_javascript(source), "<temporal-instant>")
+        .expect("script")
+        .completion_string()
+        .to_string()
+}
+
+#[test]
+fn from_parses_offset_strings_onto_utc() {
+    let completion = run(r#"
+        const instant = Temporal.Instant.from("2020-01-01T02:30:00+02:30");
+        [
+            instant.toString(),
+            instant.epochMilliseconds,
+            Temporal.Instant.fromEpochMilliseconds(1577836800000).equals(instant),
+            Temporal.Instant.fromEpochNanoseconds(1577836800000000000n).toString(),
+        ].join("|");
+        "#);
+    assert_eq!(
+        completion,
+        "2020-01-01T00:00:00Z|1577836800000|true|2020-01-01T00:00:00Z"
+    );
+}
+
+#[test]
+fn compare_orders_instants() {
+    let completion = run(r#"
+        const early = Temporal.Instant.from("1999-12-31T23:59:59Z");
+        const late = Temporal.Instant.from("2000-01-01T00:00:00Z");
+        const sorted = [late, early].sort(Temporal.Instant.compare).map(String);
+        [
+            Temporal.Instant.compare(early, late),
+            Temporal.Instant.compare(late, early),
+            Temporal.Instant.compare(early, "1999-12-31T23:59:59Z"),
+            sorted.join(","),
+        ].join("|");
+        "#);
+    assert_eq!(
+        completion,
+        "-1|1|0|1999-12-31T23:59:59Z,2000-01-01T00:00:00Z"
+    );
+}
+
+#[test]
+fn arithmetic_accepts_time_units_only() {
+    let completion = run(r#"
+        const start = Temporal.Instant.from("2020-01-01T00:00:00Z");
+        const later = start.add({ hours: 1 });
+        const rangeError = (f) => {
+            try {
+                f();
+                return "no throw";
+            } catch (error) {
+                return error instanceof RangeError;
+            }
+        };
+        [
+            later.toString(),
+            later.subtract(Temporal.Duration.from("PT90M")).toString(),
+            start.until(later).toString(),
+            later.since(start, { largestUnit: "minutes" }).toString(),
+            rangeError(() => start.add({ months: 1 })),
+            rangeError(() => start.subtract({ years: 1 })),
+            later.toZonedDateTimeISO("UTC").hour,
+        ].join("|");
+        "#);
+    assert_eq!(
+        completion,
+        "2020-01-01T01:00:00Z|2019-12-31T23:30:00Z|PT3600S|PT60M|true|true|1"
+    );
+}
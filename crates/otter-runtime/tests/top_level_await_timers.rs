@@ -0,0 +1,125 @@
+//! Top-level `await` on timer-backed promises through the isolate runner.
+//!
+//! # Contents
+//! - A snippet that awaits a `setTimeout`-resolved promise resumes after the
+//!   timer task, with the microtask checkpoint between them.
+//! - A late rejection of the awaited promise reaches the embedder's
+//!   [`PromiseRejectionHook`] with its original reason.
+//!
+//! # Invariants
+//! - The snippet's entry promise is still pending when the synchronous run
+//!   returns; the runner keeps driving Ref'd timers until idle, so the resumed
+//!   body and its rejection are observed before the reply is sent.
+//!
+//! # See also
+//! - `microtask_ordering.rs` — microtask/timer interleaving without `await`.
+//! - `promise_rejection_hook.rs` — the hook contract on the direct runtime.
+
+use std::sync::{Arc, Mutex};
+
+use otter_runtime::{
+    ConsoleLevel, ConsoleSink, NativeCtx, NativeError, Otter, PromiseRejectionHook, Value,
+};
+
+#[derive(Debug, Default)]
+struct LogCapture {
+    events: Mutex<Vec<String>>,
+}
+
+impl LogCapture {
+    fn snapshot(&self) -> Vec<String> {
+        self.events.lock().expect("log mutex").clone()
+    }
+}
+
+impl ConsoleSink for LogCapture {
+    fn write(&self, level: ConsoleLevel, fields: &[String]) {
+        if matches!(level, ConsoleLevel::Log) {
+            self.events
+                .lock()
+                .expect("log mutex")
+                .push(fields.join(" "));
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct Rejections(Arc<Mutex<Vec<(bool, String)>>>);
+
+impl Rejections {
+    fn snapshot(&self) -> Vec<(bool, String)> {
+        self.0.lock().expect("rejection mutex").clone()
+    }
+}
+
+impl PromiseRejectionHook for Rejections {
+    fn notify(
+        &self,
+        ctx: &mut NativeCtx<'_>,
+        _promise: Value,
+        reason: Value,
+        handled: bool,
+    ) -> Result<(), NativeError> {
+        self.0
+            .lock()
+            .expect("rejection mutex")
+            .push((handled, reason.display_string(ctx.heap())));
+        Ok(())
+    }
+}
+
+async fn run_snippet(source: &str) -> (Vec<String>, Vec<(bool, String)>) {
+    let log = Arc::new(LogCapture::default());
+    let rejections = Rejections::default();
+    let otter = Otter::builder()
+        .console_sink(log.clone())
+        .promise_rejection_hook(rejections.clone())
+        .build()
+        .expect("otter build");
+    let _ = otter.run_script(source).await;
+    (log.snapshot(), rejections.snapshot())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn top_level_await_resumes_after_timer_task() {
+    let (log, rejections) = run_snippet(
+        r#"
+            console.log("before");
+            const value = await new Promise((resolve) => {
+                setTimeout(() => {
+                    console.log("timer");
+                    queueMicrotask(() => console.log("checkpoint"));
+                    resolve("done");
+                }, 1);
+            });
+            console.log("after " + value);
+        "#,
+    )
+    .await;
+    assert_eq!(log, ["before", "timer", "checkpoint", "after done"]);
+    assert!(
+        rejections.is_empty(),
+        "unexpected rejections: {rejections:?}"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn late_top_level_rejection_reaches_rejection_hook() {
+    let (log, rejections) = run_snippet(
+        r#"
+            console.log("before");
+            await new Promise((_, reject) => {
+                setTimeout(() => reject(new Error("late boom")), 1);
+            });
+            console.log("unreachable");
+        "#,
+    )
+    .await;
+    assert_eq!(log, ["before"]);
+    assert!(
+        rejections
+            .iter()
+            .any(|(handled, reason)| !handled && reason.contains("late boom")),
+        "rejection reason must reach the hook, got {rejections:?}"
+    );
+}
@@ -0,0 +1,154 @@
+//! `RuntimeBuilder::unhandled_rejections` picks the policy for a rejection
+//! nobody handles.
+//!
+//! # Contents
+//! - `Throw` fails `eval` with the rejection reason.
+//! - `Warn` writes a console warning and lets `eval` succeed; a late
+//!   handler adds a second warning.
+//! - `None` stays silent.
+//! - An installed rejection hook keeps `Throw` from raising, but not
+//!   `Strict`.
+
+use std::sync::{Arc, Mutex};
+
+use otter_runtime::{
+    ConsoleLevel, ConsoleSink, NativeCtx, NativeError, OtterError, PromiseRejectionHook, Runtime,
+    SourceInput, UnhandledRejectionMode, Value,
+};
+
+const REJECTING: &str = "globalThis.rejected = Promise.reject(new Error('unhandled-boom')); 1";
+
+#[derive(Debug, Default)]
+struct Capture {
+    events: Mutex<Vec<(ConsoleLevel, String)>>,
+}
+
+impl ConsoleSink for Capture {
+    fn write(&self, level: ConsoleLevel, fields: &[String]) {
+        self.events
+            .lock()
+            .expect("console mutex")
+            .push((level, fields.join(" ")));
+    }
+}
+
+impl Capture {
+    fn snapshot(&self) -> Vec<(ConsoleLevel, String)> {
+        self.events.lock().expect("console mutex").clone()
+    }
+}
+
+fn runtime(mode: UnhandledRejectionMode) -> (Runtime, Arc<Capture>) {
+    let capture = Arc::new(Capture::default());
+    let runtime = Runtime::builder()
+        .console_sink(capture.clone())
+        .unhandled_rejections(mode)
+        .build()
+        .expect("runtime");
+    (runtime, capture)
+}
+
+fn eval(runtime: &mut Runtime, source: &str) -> Result<String, OtterError> {
+    runtime
+        .eval(SourceInput::from_javascript(source))
+        .map(|value| value.completion_string().to_string())
+}
+
+#[test]
+fn throw_mode_fails_eval_with_the_reason() {
+    let (mut runtime, capture) = runtime(UnhandledRejectionMode::Throw);
+    let err = eval(&mut runtime, REJECTING).expect_err("unhandled rejection must throw");
+    assert!(
+        err.to_string().contains("unhandled-boom"),
+        "unexpected error: {err}"
+    );
+    assert!(capture.snapshot().is_empty());
+
+    // A handled rejection is not affected, and the runtime stays usable.
+    assert_eq!(
+        eval(&mut runtime, "Promise.reject(1).catch(() => {}); 2").expect("handled"),
+        "2"
+    );
+}
+
+#[test]
+fn warn_mode_logs_and_resolves() {
+    let (mut runtime, capture) = runtime(UnhandledRejectionMode::Warn);
+    assert_eq!(eval(&mut runtime, REJECTING).expect("warn resolves"), "1");
+    let events = capture.snapshot();
+    assert_eq!(events.len(), 1, "{events:?}");
+    assert_eq!(events[0].0, ConsoleLevel::Warn);
+    assert!(
+        events[0]
+            .1
+            .starts_with("Warning: Unhandled promise rejection: ")
+            && events[0].1.contains("unhandled-boom"),
+        "unexpected warning: {}",
+        events[0].1
+    );
+
+    eval(&mut runtime, "rejected.catch(() => {})").expect("late handler");
+    let events = capture.snapshot();
+    assert_eq!(events.len(), 2, "{events:?}");
+    assert!(
+        events[1]
+            .1
+            .starts_with("Warning: Promise rejection was handled asynchronously: "),
+        "unexpected warning: {}",
+        events[1].1
+    );
+}
+
+#[test]
+fn none_mode_is_silent() {
+    let (mut runtime, capture) = runtime(UnhandledRejectionMode::None);
+    assert_eq!(eval(&mut runtime, REJECTING).expect("none resolves"), "1");
+    eval(&mut runtime, "rejected.catch(() => {})").expect("late handler");
+    assert!(capture.snapshot().is_empty());
+}
+
+#[derive(Clone, Default)]
+struct Observed(Arc<Mutex<usize>>);
+
+impl PromiseRejectionHook for Observed {
+    fn notify(
+        &self,
+        _ctx: &mut NativeCtx<'_>,
+        _promise: Value,
+        _reason: Value,
+        handled: bool,
+    ) -> Result<(), NativeError> {
+        if !handled {
+            *self.0.lock().expect("hook mutex") += 1;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn hook_observes_instead_of_throwing() {
+    let observed = Observed::default();
+    let mut runtime = Runtime::builder()
+        .promise_rejection_hook(observed.clone())
+        .unhandled_rejections(UnhandledRejectionMode::Throw)
+        .build()
+        .expect("runtime");
+    assert_eq!(eval(&mut runtime, REJECTING).expect("hook observes"), "1");
+    assert_eq!(*observed.0.lock().expect("hook mutex"), 1);
+}
+
+#[test]
+fn strict_mode_raises_past_a_hook() {
+    let observed = Observed::default();
+    let mut runtime = Runtime::builder()
+        .promise_rejection_hook(observed.clone())
+        .unhandled_rejections(UnhandledRejectionMode::Strict)
+        .build()
+        .expect("runtime");
+    let err = eval(&mut runtime, REJECTING).expect_err("strict must throw");
+    assert!(
+        err.to_string().contains("unhandled-boom"),
+        "unexpected error: {err}"
+    );
+    assert_eq!(*observed.0.lock().expect("hook mutex"), 0);
+}
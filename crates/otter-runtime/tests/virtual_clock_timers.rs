@@ -0,0 +1,122 @@
+//! Virtual-time timer delivery through [`ClockTimerScheduler`].
+//!
+//! A [`ManualClock`] stands in for the wall clock, so every test steps time
+//! forward explicitly and asserts which callbacks ran, in which order,
+//! without sleeping.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use otter_runtime::{ClockTimerScheduler, ManualClock, Runtime, SourceInput};
+
+fn runtime_with_clock() -> (Runtime, Arc<ManualClock>, Arc<ClockTimerScheduler>) {
+    let clock = Arc::new(ManualClock::new());
+    let scheduler = Arc::new(ClockTimerScheduler::new(clock.clone()));
+    let mut runtime = Runtime::builder().build().expect("runtime builds");
+    runtime.install_timer_scheduler(scheduler.clone());
+    (runtime, clock, scheduler)
+}
+
+fn eval(runtime: &mut Runtime, source: &str) -> String {
+    runtime
+        .eval(SourceInput::from_javascript(source))
+        .expect("script runs")
+        .completion_string()
+        .to_string()
+}
+
+#[test]
+fn advancing_the_clock_fires_due_timers_in_deadline_order() {
+    let (mut runtime, clock, scheduler) = runtime_with_clock();
+    eval(
+        &mut runtime,
+        "globalThis.order = [];
+         setTimeout(() => order.push('c30'), 30);
+         setTimeout(() => order.push('a10'), 10);
+         setTimeout(() => order.push('b10'), 10);
+         setTimeout(() => order.push('d50'), 50);",
+    );
+    assert_eq!(scheduler.next_deadline(), Some(Duration::from_millis(10)));
+
+    assert_eq!(scheduler.run_due(&mut runtime).expect("nothing due"), 0);
+    assert_eq!(eval(&mut runtime, "order.join(',')"), "");
+
+    clock.advance(Duration::from_millis(30));
+    assert_eq!(scheduler.run_due(&mut runtime).expect("timers fire"), 3);
+    assert_eq!(
+        eval(&mut runtime, "order.join(',')"),
+        "a10,b10,c30",
+        "equal deadlines keep schedule order"
+    );
+    assert!(runtime.has_pending_work(), "the 50ms timer is still armed");
+
+    clock.advance(Duration::from_millis(20));
+    assert_eq!(scheduler.run_due(&mut runtime).expect("last timer"), 1);
+    assert_eq!(eval(&mut runtime, "order.join(',')"), "a10,b10,c30,d50");
+    assert!(!runtime.has_pending_work());
+    assert_eq!(scheduler.next_deadline(), None);
+}
+
+#[test]
+fn timers_scheduled_from_callbacks_are_ordered_by_virtual_time() {
+    let (mut runtime, clock, scheduler) = runtime_with_clock();
+    eval(
+        &mut runtime,
+        "globalThis.order = [];
+         setTimeout(() => {
+             order.push('outer@10');
+             setTimeout(() => order.push('inner@15'), 5);
+         }, 10);
+         setTimeout(() => order.push('late@20'), 20);",
+    );
+
+    clock.advance(Duration::from_millis(100));
+    assert_eq!(scheduler.run_due(&mut runtime).expect("timers fire"), 3);
+    assert_eq!(
+        eval(&mut runtime, "order.join(',')"),
+        "outer@10,inner@15,late@20",
+        "a nested timer is due at its parent's deadline plus its own delay"
+    );
+}
+
+#[test]
+fn intervals_rearm_on_each_period_until_cleared() {
+    let (mut runtime, clock, scheduler) = runtime_with_clock();
+    eval(
+        &mut runtime,
+        "globalThis.ticks = 0;
+         globalThis.handle = setInterval(() => {
+             ticks += 1;
+             if (ticks === 3) clearInterval(handle);
+         }, 10);",
+    );
+
+    clock.advance(Duration::from_millis(25));
+    assert_eq!(scheduler.run_due(&mut runtime).expect("two periods"), 2);
+    assert_eq!(eval(&mut runtime, "ticks"), "2");
+
+    clock.advance(Duration::from_millis(100));
+    assert_eq!(
+        scheduler.run_due(&mut runtime).expect("third period"),
+        1,
+        "clearInterval from inside the callback stops re-arming"
+    );
+    assert_eq!(eval(&mut runtime, "ticks"), "3");
+    assert_eq!(scheduler.next_deadline(), None);
+}
+
+#[test]
+fn cleared_timeouts_never_fire() {
+    let (mut runtime, clock, scheduler) = runtime_with_clock();
+    eval(
+        &mut runtime,
+        "globalThis.fired = [];
+         const keep = setTimeout(() => fired.push('keep'), 5);
+         const drop = setTimeout(() => fired.push('drop'), 5);
+         clearTimeout(drop);",
+    );
+
+    clock.advance(Duration::from_millis(5));
+    assert_eq!(scheduler.run_due(&mut runtime).expect("one timer"), 1);
+    assert_eq!(eval(&mut runtime, "fired.join(',')"), "keep");
+}
@@ -0,0 +1,76 @@
+//! Runtime regression coverage for sloppy-mode `with` statements.
+//!
+//! # Contents
+//! - Identifier resolution and assignment through the object environment.
+//! - Fallback to outer bindings and `Symbol.unscopables`.
+//! - Strict-mode `with` is a SyntaxError at compile time.
+//!
+//! # See also
+//! - <https://tc39.es/ecma262/#sec-with-statement>
+
+use otter_runtime::{Runtime, SourceInput};
+
+fn run(source: &str) -> String {
+    let mut rt = Runtime::builder().build().expect("runtime");
+    rt.run_script(SourceInput::from_javascript(source), "<with-statement>")
+        .expect("script")
+        .completion_string()
+        .to_string()
+}
+
+#[test]
+fn with_resolves_and_assigns_through_the_object() {
+    let completion = run(r#"
+        var outer = "outer";
+        var scope = { a: 1, hidden: "from-object", [Symbol.unscopables]: { hidden: true } };
+        var hidden = "from-outer";
+        var seen = [];
+        with (scope) {
+            seen.push(a, outer, hidden);
+            a = 2;
+            fresh = "global";
+        }
+        with ("text") {
+            seen.push(length);
+        }
+        var captured;
+        with ({ x: 10 }) {
+            captured = function () { return x; };
+        }
+        seen.push(scope.a, typeof scope.fresh, globalThis.fresh, captured());
+        seen.join("|");
+        "#);
+    assert_eq!(completion, "1|outer|from-outer|4|2|undefined|global|10");
+}
+
+#[test]
+fn with_is_a_syntax_error_in_strict_code() {
+    let completion = run(r#"
+        let kind = "none";
+        try {
+            eval("'use strict'; with ({}) {}");
+        } catch (error) {
+            kind = error.constructor.name;
+        }
+        let fnKind = "none";
+        try {
+            Function("'use strict'; with ({}) {}");
+        } catch (error) {
+            fnKind = error.constructor.name;
+        }
+        kind + "|" + fnKind;
+        "#);
+    assert_eq!(completion, "SyntaxError|SyntaxError");
+}
+
+#[test]
+fn with_on_nullish_throws_type_error() {
+    let mut rt = Runtime::builder().build().expect("runtime");
+    let err = rt
+        .run_script(
+            SourceInput::from_javascript("with (null) { 1; }"),
+            "<with-statement>",
+        )
+        .expect_err("with (null) throws");
+    assert!(err.to_string().contains("TypeError"), "{err}");
+}
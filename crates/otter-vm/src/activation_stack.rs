@@ -373,6 +373,7 @@ mod tests {
                 spans: Vec::<SpanEntry>::new(),
                 number_hint_sites: Vec::new(),
                 class_hint_sites: Vec::new(),
+                optimization_hint: Default::default(),
             }],
             constants: Vec::new(),
             module_resolutions: Vec::new(),
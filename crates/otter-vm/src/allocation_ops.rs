@@ -735,6 +735,23 @@ impl Interpreter {
         Ok(())
     }
 
+    pub(crate) fn run_new_object_with_capacity_reg(
+        &mut self,
+        stack: &mut ActivationStack,
+        top_idx: usize,
+        dst: u16,
+        property_count: u32,
+    ) -> Result<(), VmError> {
+        let value = self.allocate_object_literal_value()?;
+        if let Some(obj) = value.as_object() {
+            crate::object::reserve_string_slots(obj, &mut self.gc_heap, property_count as usize);
+        }
+        let frame = &mut stack[top_idx];
+        write_register(frame, dst, value)?;
+        frame.advance_pc()?;
+        Ok(())
+    }
+
     /// Allocate the ordinary object created by `Op::NewObject` without tying
     /// allocation semantics to an interpreter frame representation.
     ///
@@ -813,6 +830,46 @@ impl Interpreter {
         Ok(Value::array(array))
     }
 
+    pub(crate) fn run_new_array_with_capacity_reg(
+        &mut self,
+        stack: &mut ActivationStack,
+        top_idx: usize,
+        dst: u16,
+        capacity: u32,
+    ) -> Result<(), VmError> {
+        let value = self.allocate_array_with_capacity_value(capacity as usize)?;
+        let frame = &mut stack[top_idx];
+        write_register(frame, dst, value)?;
+        frame.advance_pc()?;
+        Ok(())
+    }
+
+    /// Allocate the empty, pre-sized array created by
+    /// `Op::NewArrayWithCapacity`.
+    ///
+    /// Only dense storage is reserved; `length` stays `0` until the
+    /// literal's `ArrayPush` fill sequence appends the elements.
+    pub(crate) fn allocate_array_with_capacity_value(
+        &mut self,
+        capacity: usize,
+    ) -> Result<Value, VmError> {
+        let _runtime_roots_guard = self.scope_runtime_roots_guard();
+        let prototype = self.current_array_prototype_override();
+        let mut external_visit = |visitor: &mut dyn FnMut(*mut RawGc)| {
+            if let Some(prototype) = &prototype {
+                prototype.trace_value_slots(visitor);
+            }
+        };
+        let array = crate::array::alloc_array_with_capacity_with_roots(
+            &mut self.gc_heap,
+            capacity,
+            &mut external_visit,
+        )
+        .map_err(VmError::from)?;
+        self.register_array_prototype_override(array);
+        Ok(Value::array(array))
+    }
+
     pub(crate) fn run_load_regexp_reg(
         &mut self,
         context: &ExecutionContext,
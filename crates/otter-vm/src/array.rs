@@ -471,6 +471,24 @@ pub(crate) fn from_vec_with_roots(
     alloc_body_with_adopted_elements(heap, body, external_visit)
 }
 
+/// Allocate a fresh empty array whose dense storage already has room for
+/// `capacity` elements.
+///
+/// Backs `Op::NewArrayWithCapacity`: the literal's `ArrayPush` fill sequence
+/// then appends without regrowing the vector. The reserved backing store is
+/// accounted up front exactly like an adopted element vector.
+pub(crate) fn alloc_array_with_capacity_with_roots(
+    heap: &mut GcHeap,
+    capacity: usize,
+    external_visit: &mut RootSlotVisitor<'_>,
+) -> Result<JsArray, otter_gc::OutOfMemory> {
+    let body = ArrayBody {
+        elements: Vec::with_capacity(capacity),
+        ..Default::default()
+    };
+    alloc_body_with_adopted_elements(heap, body, external_visit)
+}
+
 /// Allocate an [`ArrayBody`] whose dense element vector has already been
 /// materialized and moved into the body.
 ///
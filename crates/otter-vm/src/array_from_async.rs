@@ -0,0 +1,566 @@
+//! `Array.fromAsync(items, mapFn?, thisArg?)` collection loop.
+//!
+//! The spec phrases `Array.fromAsync` as an async abstract closure started
+//! by `AsyncFunctionStart`. Otter runs the synchronous prefix (argument
+//! validation, `@@asyncIterator` / `@@iterator` lookup, iterator and target
+//! construction, the first step) inside the native call, then resumes the
+//! loop from a pair of native promise reactions after every `Await`.
+//!
+//! # Contents
+//! - [`native_from_async`] — the JS-visible static installed by
+//!   [`crate::array_statics`].
+//!
+//! # Invariants
+//! - Every abrupt completion settles the returned promise; the call itself
+//!   only fails when the promise capability cannot be allocated.
+//! - Heap values the loop needs across awaits (resolving functions, target,
+//!   iterator, `next`, `mapFn`, `thisArg`) live in the reactions' traced
+//!   captures. Only the index and the pending phase live in Rust state.
+//! - A throwing or rejecting `mapFn`, a failed `CreateDataPropertyOrThrow`,
+//!   or a rejected sync-iterator element closes the source iterator before
+//!   the result promise rejects. A failing `next()` does not close it.
+//!
+//! # See also
+//! - <https://tc39.es/ecma262/#sec-array.fromasync>
+//! - <https://tc39.es/ecma262/#sec-createasyncfromsynciterator>
+//! - <https://tc39.es/ecma262/#sec-asynciteratorclose>
+
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use smallvec::SmallVec;
+
+use crate::native_function::vm_to_native_error;
+use crate::{
+    ExecutionContext, Local, NativeCtx, NativeError, NativeScope, Value, VmError, VmGetOutcome,
+    VmPropertyKey, symbol,
+};
+
+const NAME: &str = "Array.fromAsync";
+
+// Capture slots shared by every continuation of one `Array.fromAsync` call.
+const RESOLVE: usize = 0;
+const REJECT: usize = 1;
+const TARGET: usize = 2;
+const SOURCE: usize = 3;
+const NEXT: usize = 4;
+const MAP_FN: usize = 5;
+const THIS_ARG: usize = 6;
+const SLOT_COUNT: usize = 7;
+
+type Slots<'s> = [Local<'s>; SLOT_COUNT];
+
+/// Where the loop draws its values from.
+#[derive(Clone, Copy)]
+enum Source {
+    /// `items[@@asyncIterator]()`; every `next()` result is awaited.
+    Async,
+    /// `items[@@iterator]()` seen through CreateAsyncFromSyncIterator;
+    /// every element value is awaited.
+    Sync,
+    /// Array-like fallback; the length is read once up front.
+    ArrayLike(usize),
+}
+
+/// The `Await` the loop is currently suspended on.
+#[derive(Clone, Copy)]
+enum Phase {
+    /// An async iterator's `next()` result.
+    Next,
+    /// An element value (sync iterator or array-like).
+    Value,
+    /// The result of `mapFn`.
+    Mapped,
+}
+
+struct LoopState {
+    source: Source,
+    mapping: bool,
+    k: usize,
+    phase: Phase,
+}
+
+type SharedState = Arc<Mutex<LoopState>>;
+
+/// How an abrupt completion inside the loop settles the result promise.
+enum Abrupt {
+    /// Reject without touching the source (`next()` failed, or the source
+    /// is already exhausted).
+    Reject(NativeError),
+    /// Close the source iterator first (`IfAbruptCloseAsyncIterator`).
+    Close(NativeError),
+}
+
+/// §23.1.2.2 `Array.fromAsync(items, mapFn?, thisArg?)`.
+///
+/// Returns a promise for a new `this`-constructed (or plain) array filled
+/// from an async iterable, a sync iterable whose values are awaited, or an
+/// array-like. `mapFn(value, index)` results are awaited before they are
+/// stored.
+pub(crate) fn native_from_async(
+    ctx: &mut NativeCtx<'_>,
+    args: &[Value],
+) -> Result<Value, NativeError> {
+    let this_value = *ctx.this_value();
+    let exec = execution_context(ctx)?;
+    ctx.scope(|mut scope| {
+        let constructor = scope.value(this_value);
+        let items = scope.argument(args, 0);
+        let map_fn = scope.argument(args, 1);
+        let this_arg = scope.argument(args, 2);
+        let (promise, resolve, reject) = scope.context().promise_capability()?;
+        let promise = scope.value(promise);
+        let resolve = scope.value(resolve);
+        let reject = scope.value(reject);
+        let mapping = !scope.is_undefined(map_fn);
+        match start(&mut scope, &exec, constructor, items, map_fn) {
+            Ok((source, target, iterable, next)) => {
+                let slots = [resolve, reject, target, iterable, next, map_fn, this_arg];
+                let state = Arc::new(Mutex::new(LoopState {
+                    source,
+                    mapping,
+                    k: 0,
+                    phase: Phase::Next,
+                }));
+                if let Err(abrupt) = advance(&mut scope, &exec, &slots, &state) {
+                    fail(&mut scope, &exec, &slots, &state, abrupt)?;
+                }
+            }
+            Err(err) => {
+                let reason = error_reason(&mut scope, err);
+                let undefined = scope.undefined();
+                scope.call(reject, undefined, &[reason])?;
+            }
+        }
+        Ok(scope.finish(promise))
+    })
+}
+
+/// Synchronous prefix: validate `mapFn`, pick the source, and build the
+/// iterator (or read the array-like length) and the target.
+fn start<'s>(
+    scope: &mut NativeScope<'s, '_>,
+    exec: &ExecutionContext,
+    constructor: Local<'_>,
+    items: Local<'_>,
+    map_fn: Local<'_>,
+) -> Result<(Source, Local<'s>, Local<'s>, Local<'s>), NativeError> {
+    if !scope.is_undefined(map_fn) && !scope.is_callable(map_fn) {
+        return Err(type_error("mapFn must be callable"));
+    }
+    if scope.is_undefined(items) || scope.is_null(items) {
+        return Err(type_error("items must be an iterable or array-like"));
+    }
+    let method = match get_method(scope, exec, items, symbol::WellKnown::AsyncIterator)? {
+        Some(method) => Some((Source::Async, method)),
+        None => get_method(scope, exec, items, symbol::WellKnown::Iterator)?
+            .map(|method| (Source::Sync, method)),
+    };
+    if let Some((source, method)) = method {
+        let iterator = scope.call(method, items, &[])?;
+        if !scope.is_object(iterator) {
+            return Err(type_error("iterator method did not return an object"));
+        }
+        let next = get(scope, exec, iterator, &VmPropertyKey::String("next"))?;
+        let target = make_target(scope, exec, constructor, None)?;
+        return Ok((source, target, iterator, next));
+    }
+    let length = get(scope, exec, items, &VmPropertyKey::String("length"))?;
+    let length = scope.raw(length);
+    let len = crate::to_length(&length, scope.context().heap())
+        .map_err(|err| vm_to_native_error(scope.context().interp_mut(), err, NAME))?;
+    let target = make_target(scope, exec, constructor, Some(len))?;
+    let items = scope.value(scope.raw(items));
+    let next = scope.undefined();
+    Ok((Source::ArrayLike(len), target, items, next))
+}
+
+/// Take the next step: call `next()` or read the next array-like element
+/// and suspend on it, or finish once the source is exhausted.
+fn advance(
+    scope: &mut NativeScope<'_, '_>,
+    exec: &ExecutionContext,
+    slots: &Slots<'_>,
+    state: &SharedState,
+) -> Result<(), Abrupt> {
+    let (source, k) = {
+        let state = lock(state);
+        (state.source, state.k)
+    };
+    match source {
+        Source::Async => {
+            let result = scope
+                .call(slots[NEXT], slots[SOURCE], &[])
+                .map_err(Abrupt::Reject)?;
+            suspend(scope, slots, state, Phase::Next, result).map_err(Abrupt::Reject)
+        }
+        Source::Sync => {
+            let result = scope
+                .call(slots[NEXT], slots[SOURCE], &[])
+                .map_err(Abrupt::Reject)?;
+            match iterator_step(scope, exec, result).map_err(Abrupt::Reject)? {
+                None => finish(scope, exec, slots, k),
+                Some(value) => {
+                    suspend(scope, slots, state, Phase::Value, value).map_err(Abrupt::Close)
+                }
+            }
+        }
+        Source::ArrayLike(len) => {
+            if k >= len {
+                return finish(scope, exec, slots, len);
+            }
+            let key = k.to_string();
+            let value = get(scope, exec, slots[SOURCE], &VmPropertyKey::String(&key))
+                .map_err(Abrupt::Reject)?;
+            suspend(scope, slots, state, Phase::Value, value).map_err(Abrupt::Reject)
+        }
+    }
+}
+
+/// Continuation for a settled `Await`.
+fn resume(
+    ctx: &mut NativeCtx<'_>,
+    args: &[Value],
+    captures: &[Value],
+    state: &SharedState,
+    fulfilled: bool,
+) -> Result<Value, NativeError> {
+    let exec = execution_context(ctx)?;
+    ctx.scope(|mut scope| {
+        let slots: Slots<'_> = std::array::from_fn(|index| {
+            scope.value(captures.get(index).copied().unwrap_or_else(Value::undefined))
+        });
+        let argument = scope.argument(args, 0);
+        let phase = lock(state).phase;
+        if !fulfilled {
+            // A rejected `next()` leaves the iterator done; a rejected
+            // element or mapped value still has a live source to close.
+            match phase {
+                Phase::Next => reject_with(&mut scope, &slots, argument)?,
+                Phase::Value | Phase::Mapped => {
+                    close_then_reject(&mut scope, &exec, &slots, state, argument)?;
+                }
+            }
+            let undefined = scope.undefined();
+            return Ok(scope.finish(undefined));
+        }
+        let outcome = match phase {
+            Phase::Next => match iterator_step(&mut scope, &exec, argument) {
+                Ok(None) => {
+                    let k = lock(state).k;
+                    finish(&mut scope, &exec, &slots, k)
+                }
+                Ok(Some(value)) => store_or_map(&mut scope, &exec, &slots, state, value),
+                Err(err) => Err(Abrupt::Reject(err)),
+            },
+            Phase::Value => store_or_map(&mut scope, &exec, &slots, state, argument),
+            Phase::Mapped => store(&mut scope, &exec, &slots, state, argument),
+        };
+        if let Err(abrupt) = outcome {
+            fail(&mut scope, &exec, &slots, state, abrupt)?;
+        }
+        let undefined = scope.undefined();
+        Ok(scope.finish(undefined))
+    })
+}
+
+/// Run `mapFn(value, k)` and await its result, or store `value` directly.
+fn store_or_map(
+    scope: &mut NativeScope<'_, '_>,
+    exec: &ExecutionContext,
+    slots: &Slots<'_>,
+    state: &SharedState,
+    value: Local<'_>,
+) -> Result<(), Abrupt> {
+    let (mapping, k) = {
+        let state = lock(state);
+        (state.mapping, state.k)
+    };
+    if !mapping {
+        return store(scope, exec, slots, state, value);
+    }
+    let index = scope.number(k as f64);
+    let mapped = scope
+        .call(slots[MAP_FN], slots[THIS_ARG], &[value, index])
+        .map_err(Abrupt::Close)?;
+    suspend(scope, slots, state, Phase::Mapped, mapped).map_err(Abrupt::Close)
+}
+
+/// `CreateDataPropertyOrThrow(A, k, value)`, then take the next step.
+fn store(
+    scope: &mut NativeScope<'_, '_>,
+    exec: &ExecutionContext,
+    slots: &Slots<'_>,
+    state: &SharedState,
+    value: Local<'_>,
+) -> Result<(), Abrupt> {
+    let k = lock(state).k;
+    let target = slots[TARGET];
+    scope
+        .with_turn_parts(|interp, stack| {
+            let target = interp.escape_scoped(target);
+            let value = interp.escape_scoped(value);
+            interp
+                .create_data_property_or_throw(stack, exec, target, &k.to_string(), value)
+                .map_err(|err| vm_to_native_error(interp, err, NAME))
+        })
+        .map_err(Abrupt::Close)?;
+    lock(state).k = k + 1;
+    advance(scope, exec, slots, state)
+}
+
+/// `Set(A, "length", len, true)` and resolve the result promise with `A`.
+fn finish(
+    scope: &mut NativeScope<'_, '_>,
+    exec: &ExecutionContext,
+    slots: &Slots<'_>,
+    len: usize,
+) -> Result<(), Abrupt> {
+    let target = slots[TARGET];
+    scope
+        .with_turn_parts(|interp, stack| {
+            let target = interp.escape_scoped(target);
+            interp
+                .array_set_property_throwing(
+                    stack,
+                    exec,
+                    target,
+                    "length",
+                    Value::number_f64(len as f64),
+                )
+                .map_err(|err| vm_to_native_error(interp, err, NAME))
+        })
+        .map_err(Abrupt::Reject)?;
+    let undefined = scope.undefined();
+    scope
+        .call(slots[RESOLVE], undefined, &[target])
+        .map_err(Abrupt::Reject)?;
+    Ok(())
+}
+
+/// Suspend the loop on `Await(value)`, resuming in [`resume`].
+fn suspend(
+    scope: &mut NativeScope<'_, '_>,
+    slots: &Slots<'_>,
+    state: &SharedState,
+    phase: Phase,
+    value: Local<'_>,
+) -> Result<(), NativeError> {
+    lock(state).phase = phase;
+    let fulfilled_state = Arc::clone(state);
+    let on_fulfilled = scope.native_closure("", 1, slots, move |ctx, args, captures| {
+        resume(ctx, args, captures, &fulfilled_state, true)
+    })?;
+    let rejected_state = Arc::clone(state);
+    let on_rejected = scope.native_closure("", 1, slots, move |ctx, args, captures| {
+        resume(ctx, args, captures, &rejected_state, false)
+    })?;
+    let value = scope.raw(value);
+    let on_fulfilled = scope.raw(on_fulfilled);
+    let on_rejected = scope.raw(on_rejected);
+    crate::promise_dispatch::await_native(scope.context(), value, on_fulfilled, on_rejected)
+}
+
+fn fail(
+    scope: &mut NativeScope<'_, '_>,
+    exec: &ExecutionContext,
+    slots: &Slots<'_>,
+    state: &SharedState,
+    abrupt: Abrupt,
+) -> Result<(), NativeError> {
+    match abrupt {
+        Abrupt::Reject(err) => {
+            let reason = error_reason(scope, err);
+            reject_with(scope, slots, reason)
+        }
+        Abrupt::Close(err) => {
+            let reason = error_reason(scope, err);
+            close_then_reject(scope, exec, slots, state, reason)
+        }
+    }
+}
+
+/// Close the source iterator, then reject with `reason`. The close's own
+/// completion is discarded: the original throw completion wins.
+fn close_then_reject(
+    scope: &mut NativeScope<'_, '_>,
+    exec: &ExecutionContext,
+    slots: &Slots<'_>,
+    state: &SharedState,
+    reason: Local<'_>,
+) -> Result<(), NativeError> {
+    let source = lock(state).source;
+    match source {
+        Source::ArrayLike(_) => {}
+        Source::Sync => {
+            let iterator = slots[SOURCE];
+            let _ = scope.with_turn_parts(|interp, stack| {
+                let iterator = interp.escape_scoped(iterator);
+                interp.iterator_close_sync(stack, exec, &iterator)
+            });
+        }
+        Source::Async => {
+            // AsyncIteratorClose: await `return()`'s result before the
+            // rejection becomes observable.
+            if let Ok(Some(result)) = call_return(scope, exec, slots[SOURCE]) {
+                let settle = scope.native_closure(
+                    "",
+                    1,
+                    &[slots[REJECT], reason],
+                    |ctx, _args, captures| {
+                        ctx.scope(|mut scope| {
+                            let reject = scope.value(captures[0]);
+                            let reason = scope.value(captures[1]);
+                            let undefined = scope.undefined();
+                            scope.call(reject, undefined, &[reason])?;
+                            Ok(scope.finish(undefined))
+                        })
+                    },
+                )?;
+                let result = scope.raw(result);
+                let settle = scope.raw(settle);
+                return crate::promise_dispatch::await_native(
+                    scope.context(),
+                    result,
+                    settle,
+                    settle,
+                );
+            }
+        }
+    }
+    reject_with(scope, slots, reason)
+}
+
+/// Call `iterator.return()` when present.
+fn call_return<'s>(
+    scope: &mut NativeScope<'s, '_>,
+    exec: &ExecutionContext,
+    iterator: Local<'_>,
+) -> Result<Option<Local<'s>>, NativeError> {
+    let method = get(scope, exec, iterator, &VmPropertyKey::String("return"))?;
+    if scope.is_undefined(method) || scope.is_null(method) {
+        return Ok(None);
+    }
+    scope.call(method, iterator, &[]).map(Some)
+}
+
+fn reject_with(
+    scope: &mut NativeScope<'_, '_>,
+    slots: &Slots<'_>,
+    reason: Local<'_>,
+) -> Result<(), NativeError> {
+    let undefined = scope.undefined();
+    scope.call(slots[REJECT], undefined, &[reason])?;
+    Ok(())
+}
+
+/// IteratorComplete / IteratorValue over one iterator result object.
+/// `None` once the iterator reports `done`.
+fn iterator_step<'s>(
+    scope: &mut NativeScope<'s, '_>,
+    exec: &ExecutionContext,
+    result: Local<'_>,
+) -> Result<Option<Local<'s>>, NativeError> {
+    if !scope.is_object(result) {
+        return Err(type_error("iterator result is not an object"));
+    }
+    let done = get(scope, exec, result, &VmPropertyKey::String("done"))?;
+    if scope.raw(done).to_boolean(scope.context().heap()) {
+        return Ok(None);
+    }
+    get(scope, exec, result, &VmPropertyKey::String("value")).map(Some)
+}
+
+/// `GetMethod(items, @@which)`.
+fn get_method<'s>(
+    scope: &mut NativeScope<'s, '_>,
+    exec: &ExecutionContext,
+    items: Local<'_>,
+    which: symbol::WellKnown,
+) -> Result<Option<Local<'s>>, NativeError> {
+    let sym = scope.context().interp_mut().well_known_symbols.get(which);
+    let method = get(scope, exec, items, &VmPropertyKey::Symbol(sym))?;
+    if scope.is_undefined(method) || scope.is_null(method) {
+        return Ok(None);
+    }
+    if !scope.is_callable(method) {
+        return Err(type_error("iterator method is not callable"));
+    }
+    Ok(Some(method))
+}
+
+/// `Get(receiver, key)` through the ordinary property ladder, so
+/// primitives, proxies and accessors behave as they do for `Array.from`.
+fn get<'s>(
+    scope: &mut NativeScope<'s, '_>,
+    exec: &ExecutionContext,
+    receiver: Local<'_>,
+    key: &VmPropertyKey<'_>,
+) -> Result<Local<'s>, NativeError> {
+    let value = scope.with_turn_parts(|interp, stack| {
+        let base = interp.escape_scoped(receiver);
+        let outcome = interp.ordinary_get_value(stack, exec, base, base, key, 0);
+        let value = match outcome {
+            Ok(VmGetOutcome::Value(value)) => Ok(value),
+            Ok(VmGetOutcome::InvokeGetter { getter }) => {
+                let base = interp.escape_scoped(receiver);
+                interp.run_callable_sync_rooted(stack, exec, &getter, base, SmallVec::new())
+            }
+            Err(err) => Err(err),
+        };
+        value.map_err(|err: VmError| vm_to_native_error(interp, err, NAME))
+    })?;
+    Ok(scope.value(value))
+}
+
+/// `Construct(C)` / `Construct(C, «len»)` when `this` is a constructor,
+/// else `ArrayCreate(0)`.
+fn make_target<'s>(
+    scope: &mut NativeScope<'s, '_>,
+    exec: &ExecutionContext,
+    constructor: Local<'_>,
+    len: Option<usize>,
+) -> Result<Local<'s>, NativeError> {
+    let target = scope.with_turn_parts(|interp, stack| {
+        let constructor = interp.escape_scoped(constructor);
+        let use_ctor = !constructor.is_undefined()
+            && crate::abstract_ops::is_constructor(&constructor, exec, interp.gc_heap());
+        interp
+            .array_from_make_target(stack, exec, use_ctor, &constructor, len)
+            .map_err(|err| vm_to_native_error(interp, err, NAME))
+    })?;
+    Ok(scope.value(target))
+}
+
+/// Recover the JS value a failed step completed with: the thrown value
+/// itself, or a fresh error instance for a native failure.
+fn error_reason<'s>(scope: &mut NativeScope<'s, '_>, err: NativeError) -> Local<'s> {
+    let reason = scope.with_turn_parts(|interp, _| {
+        if matches!(err, NativeError::Thrown { .. })
+            && let Some(value) = interp.take_pending_uncaught_throw()
+        {
+            return value;
+        }
+        let vm_error = crate::native_to_vm_error(interp, err);
+        interp
+            .take_pending_uncaught_throw()
+            .unwrap_or_else(|| crate::error_ops::vm_err_to_value(interp, &vm_error))
+    });
+    scope.value(reason)
+}
+
+fn execution_context(ctx: &NativeCtx<'_>) -> Result<ExecutionContext, NativeError> {
+    ctx.execution_context()
+        .cloned()
+        .ok_or_else(|| type_error("missing execution context"))
+}
+
+fn type_error(reason: &str) -> NativeError {
+    NativeError::TypeError {
+        name: NAME,
+        reason: reason.to_string(),
+    }
+}
+
+fn lock(state: &SharedState) -> MutexGuard<'_, LoopState> {
+    state.lock().expect("Array.fromAsync loop state")
+}
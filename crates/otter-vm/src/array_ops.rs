@@ -443,10 +443,10 @@ impl Interpreter {
         })
     }
 
-    /// Allocate the Array.from result object: `Construct(C)` (optionally
-    /// with a forwarded `len`) when `C` is a constructor, else a fresh
-    /// ordinary Array.
-    fn array_from_make_target(
+    /// Allocate the Array.from / Array.fromAsync result object:
+    /// `Construct(C)` (optionally with a forwarded `len`) when `C` is a
+    /// constructor, else a fresh ordinary Array.
+    pub(crate) fn array_from_make_target(
         &mut self,
         stack: &mut crate::ActivationStack,
         context: &ExecutionContext,
@@ -578,6 +578,7 @@ mod tests {
                 spans: Vec::<SpanEntry>::new(),
                 number_hint_sites: Vec::new(),
                 class_hint_sites: Vec::new(),
+                optimization_hint: Default::default(),
             }],
             constants: Vec::new(),
             module_resolutions: Vec::new(),
@@ -621,6 +622,7 @@ mod tests {
                 spans: Vec::<SpanEntry>::new(),
                 number_hint_sites: Vec::new(),
                 class_hint_sites: Vec::new(),
+                optimization_hint: Default::default(),
             }],
             constants: Vec::new(),
             module_resolutions: Vec::new(),
@@ -4831,6 +4831,7 @@ mod tests {
             spans,
             number_hint_sites: Vec::new(),
             class_hint_sites: Vec::new(),
+            optimization_hint: Default::default(),
         }
     }
 
@@ -4878,6 +4879,7 @@ mod tests {
             spans,
             number_hint_sites: Vec::new(),
             class_hint_sites: Vec::new(),
+            optimization_hint: Default::default(),
         }
     }
 
@@ -12,8 +12,8 @@
 //! # Contents
 //! - [`ARRAY_STATIC_METHODS`] — methods installed on the `Array`
 //!   constructor during bootstrap.
-//! - Minimal `Array.fromAsync` metadata surface; the async collection
-//!   algorithm is intentionally separate from this descriptor hook.
+//! - `Array.fromAsync` is installed here; its async collection loop
+//!   lives in [`crate::array_from_async`].
 //!
 //! # Invariants
 //! - The compiler's [`otter_bytecode::Op::ArrayOf`] /
@@ -58,7 +58,7 @@ pub static ARRAY_STATIC_METHODS: &[MethodSpec] = &[
         name: "fromAsync",
         length: 1,
         attrs: Attr::builtin_function(),
-        call: NativeCall::Static(native_from_async),
+        call: NativeCall::Static(crate::array_from_async::native_from_async),
     },
 ];
 
@@ -118,20 +118,6 @@ fn native_from(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeE
     })
 }
 
-/// §23.1.2.2 `Array.fromAsync(items, mapFn?, thisArg?)`.
-///
-/// Otter does not yet implement the async iterator / promise
-/// collection algorithm, but Test262 exposes the finalized builtin's
-/// reflective surface independently. Installing the real callable
-/// shape keeps descriptor conformance visible while a direct call
-/// still reports the missing runtime semantics.
-fn native_from_async(_ctx: &mut NativeCtx<'_>, _args: &[Value]) -> Result<Value, NativeError> {
-    Err(NativeError::TypeError {
-        name: "Array.fromAsync",
-        reason: "Array.fromAsync collection is not implemented".to_string(),
-    })
-}
-
 fn vm_to_native_array_static(
     interp: &crate::Interpreter,
     name: &'static str,
@@ -0,0 +1,376 @@
+//! TC39 AsyncContext: `AsyncContext.Variable` and `AsyncContext.Snapshot`.
+//!
+//! An async context *mapping* binds variables to values. It is either
+//! `undefined` (the empty mapping) or an immutable frame object binding
+//! one [`Variable`](VariableData) to a value on top of a parent mapping;
+//! `variable.get()` walks the chain from the innermost frame. The
+//! interpreter owns the current mapping, and every job source captures
+//! it when the job is scheduled and re-enters it while the job runs, so
+//! a binding follows the logical flow of control across `await`, promise
+//! reactions, `queueMicrotask`, and timers.
+//!
+//! # Contents
+//! - [`AsyncContextState`] — the interpreter's current mapping plus the
+//!   mappings saved by nested entries.
+//! - [`Intrinsic`] — the `AsyncContext` namespace with its `Variable` and
+//!   `Snapshot` classes.
+//!
+//! # Invariants
+//! - Frames are never mutated after construction, so capturing a mapping
+//!   is copying one value, and a snapshot can never observe a later
+//!   `run`.
+//! - Every [`AsyncContextState::enter`] is paired with an
+//!   [`AsyncContextState::exit`] on both normal and abrupt completion.
+//! - Captured mappings live only in traced slots: promise reactions,
+//!   microtasks, timer entries, snapshot payloads, and the saved stack.
+//! - Finalization callbacks run in the empty mapping.
+//!
+//! # See also
+//! - <https://tc39.es/proposal-async-context/>
+//! - [`crate::promise::PromiseReaction`] — captures the mapping when a
+//!   reaction (including `await`) is registered.
+
+#![allow(missing_docs)]
+
+use otter_gc::raw::RawGc;
+
+use crate::bootstrap::BootstrapFeatures;
+use crate::intrinsic_install::BuiltinIntrinsic;
+use crate::js_surface::{Attr, JsSurfaceError, NamespaceBuilder, NamespaceSpec};
+use crate::object::{self, HostDataTracer, HostValueSlot, JsObject, TracedHostObjectData};
+use crate::{Local, NativeCtx, NativeError, NativeScope, Value};
+
+/// Current mapping and the mappings to restore when nested entries exit.
+#[derive(Debug)]
+pub(crate) struct AsyncContextState {
+    current: Value,
+    saved: Vec<Value>,
+}
+
+impl AsyncContextState {
+    /// Start in the empty mapping.
+    #[must_use]
+    pub(crate) fn new() -> Self {
+        Self {
+            current: Value::undefined(),
+            saved: Vec::new(),
+        }
+    }
+
+    /// Mapping visible to the running code.
+    #[must_use]
+    pub(crate) fn current(&self) -> Value {
+        self.current
+    }
+
+    /// Make `mapping` current, saving the previous one.
+    pub(crate) fn enter(&mut self, mapping: Value) {
+        self.saved
+            .push(std::mem::replace(&mut self.current, mapping));
+    }
+
+    /// Restore the mapping saved by the matching [`Self::enter`].
+    pub(crate) fn exit(&mut self) {
+        if let Some(previous) = self.saved.pop() {
+            self.current = previous;
+        }
+    }
+
+    /// Trace the current and saved mappings.
+    pub(crate) fn trace_gc_slots(&self, visitor: &mut dyn FnMut(*mut RawGc)) {
+        self.current.trace_value_slots(visitor);
+        for mapping in &self.saved {
+            mapping.trace_value_slots(visitor);
+        }
+    }
+}
+
+/// One binding in a mapping chain.
+#[derive(Default)]
+struct MappingFrame {
+    variable: HostValueSlot,
+    value: HostValueSlot,
+    parent: HostValueSlot,
+}
+
+impl TracedHostObjectData for MappingFrame {
+    fn trace_gc_slots(&mut self, tracer: &mut HostDataTracer<'_>) {
+        tracer.trace(&mut self.variable);
+        tracer.trace(&mut self.value);
+        tracer.trace(&mut self.parent);
+    }
+}
+
+/// `AsyncContext.Variable` instance payload.
+#[derive(Default)]
+struct VariableData {
+    name: HostValueSlot,
+    default_value: HostValueSlot,
+}
+
+impl TracedHostObjectData for VariableData {
+    fn trace_gc_slots(&mut self, tracer: &mut HostDataTracer<'_>) {
+        tracer.trace(&mut self.name);
+        tracer.trace(&mut self.default_value);
+    }
+}
+
+/// `AsyncContext.Snapshot` instance payload.
+#[derive(Default)]
+struct SnapshotData {
+    mapping: HostValueSlot,
+}
+
+impl TracedHostObjectData for SnapshotData {
+    fn trace_gc_slots(&mut self, tracer: &mut HostDataTracer<'_>) {
+        tracer.trace(&mut self.mapping);
+    }
+}
+
+otter_macros::couch! {
+    name = "Variable",
+    feature = CORE,
+    intrinsic = VariableIntrinsic,
+    constructor = (length = 0, call = variable_ctor_call),
+    prototype = {
+        methods = {
+            "get" / 0 => variable_get,
+            "run" / 2 => variable_run,
+        },
+        accessors = [
+            ("name", get = variable_name),
+        ],
+    },
+    install_on = async_context_host,
+    string_tag = "AsyncContext.Variable",
+}
+
+otter_macros::couch! {
+    name = "Snapshot",
+    feature = CORE,
+    intrinsic = SnapshotIntrinsic,
+    constructor = (length = 0, call = snapshot_ctor_call),
+    prototype = {
+        methods = {
+            "run" / 1 => snapshot_run,
+        },
+    },
+    install_on = async_context_host,
+    string_tag = "AsyncContext.Snapshot",
+}
+
+const ASYNC_CONTEXT_SPEC: NamespaceSpec = NamespaceSpec {
+    name: "AsyncContext",
+    methods: &[],
+    accessors: &[],
+    constants: &[],
+    attrs: Attr::global_binding(),
+};
+
+/// `BuiltinIntrinsic` driver for the `AsyncContext` namespace and its
+/// nested classes.
+pub struct Intrinsic;
+
+impl BuiltinIntrinsic for Intrinsic {
+    const NAME: &'static str = "AsyncContext";
+    const FEATURE: BootstrapFeatures = BootstrapFeatures::CORE;
+
+    fn install(heap: &mut otter_gc::GcHeap, global: JsObject) -> Result<(), JsSurfaceError> {
+        let namespace = NamespaceBuilder::from_spec_with_value_roots(
+            heap,
+            &ASYNC_CONTEXT_SPEC,
+            vec![Value::object(global)],
+        )?
+        .build()?;
+        crate::bootstrap::define_global_value(global, heap, Self::NAME, Value::object(namespace));
+        VariableIntrinsic::install(heap, global)?;
+        SnapshotIntrinsic::install(heap, global)?;
+        Ok(())
+    }
+
+    fn install_well_knowns(
+        heap: &mut otter_gc::GcHeap,
+        global: JsObject,
+        well_known: &crate::symbol::WellKnownSymbols,
+    ) -> Result<(), JsSurfaceError> {
+        VariableIntrinsic::install_well_knowns(heap, global, well_known)?;
+        SnapshotIntrinsic::install_well_knowns(heap, global, well_known)
+    }
+}
+
+fn async_context_host(global: JsObject, heap: &mut otter_gc::GcHeap) -> JsObject {
+    object::get(global, heap, "AsyncContext")
+        .and_then(|v| v.as_object())
+        .expect("AsyncContext namespace must be installed before its classes")
+}
+
+// ---------------------------------------------------------------
+// AsyncContext.Variable
+// ---------------------------------------------------------------
+
+fn variable_ctor_call(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
+    const NAME: &str = "AsyncContext.Variable";
+    if !ctx.is_construct_call() {
+        return Err(type_error(NAME, "constructor requires 'new'"));
+    }
+    let prototype = crate::bootstrap::native_new_target_prototype(ctx, NAME)?;
+    ctx.scope(|mut scope| {
+        let prototype = prototype.map(|proto| scope.value(proto));
+        let options = scope.argument(args, 0);
+        let (name, default_value) = if scope.raw(options).is_object_type() {
+            (
+                scope.get(options, "name")?,
+                scope.get(options, "defaultValue")?,
+            )
+        } else {
+            (scope.undefined(), scope.undefined())
+        };
+        let name = if scope.is_undefined(name) {
+            scope.string("")?
+        } else if scope.is_string(name) {
+            name
+        } else {
+            let text = scope.display_string(name);
+            scope.string(&text)?
+        };
+        let variable = scope.traced_host_object(VariableData::default())?;
+        scope.set_host_data_value::<VariableData>(variable, name, |data| &mut data.name)?;
+        scope.set_host_data_value::<VariableData>(variable, default_value, |data| {
+            &mut data.default_value
+        })?;
+        if prototype.is_some() {
+            scope.set_prototype(variable, prototype)?;
+        }
+        Ok(scope.finish(variable))
+    })
+}
+
+/// `AsyncContext.Variable.prototype.get()` — the innermost binding for
+/// the receiver in the current mapping, else its default value.
+fn variable_get(ctx: &mut NativeCtx<'_>, _args: &[Value]) -> Result<Value, NativeError> {
+    const NAME: &str = "AsyncContext.Variable.prototype.get";
+    let variable = receiver::<VariableData>(ctx, NAME)?;
+    let mut mapping = ctx.interp_mut().async_context.current();
+    let heap = ctx.heap();
+    while let Some(frame) = mapping.as_object() {
+        let (bound, value, parent) = object::with_host_data::<MappingFrame, _>(frame, heap, |f| {
+            (f.variable.value(), f.value.value(), f.parent.value())
+        })
+        .map_err(|error| type_error(NAME, &error.to_string()))?;
+        if bound == Value::object(variable) {
+            return Ok(value);
+        }
+        mapping = parent;
+    }
+    object::with_host_data::<VariableData, _>(variable, heap, |data| data.default_value.value())
+        .map_err(|error| type_error(NAME, &error.to_string()))
+}
+
+/// `AsyncContext.Variable.prototype.run(value, fn, ...args)` — call `fn`
+/// with the receiver bound to `value` on top of the current mapping.
+fn variable_run(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
+    receiver::<VariableData>(ctx, "AsyncContext.Variable.prototype.run")?;
+    let parent = ctx.interp_mut().async_context.current();
+    ctx.scope(|mut scope| {
+        let parent = scope.value(parent);
+        let variable = scope.this();
+        let value = scope.argument(args, 0);
+        let callback = scope.argument(args, 1);
+        let call_args: Vec<Local<'_>> = (2..args.len())
+            .map(|index| scope.argument(args, index))
+            .collect();
+        let frame = scope.traced_host_object(MappingFrame::default())?;
+        scope.set_host_data_value::<MappingFrame>(frame, variable, |f| &mut f.variable)?;
+        scope.set_host_data_value::<MappingFrame>(frame, value, |f| &mut f.value)?;
+        scope.set_host_data_value::<MappingFrame>(frame, parent, |f| &mut f.parent)?;
+        run_in_mapping(&mut scope, frame, callback, &call_args)
+    })
+}
+
+/// `get AsyncContext.Variable.prototype.name`.
+fn variable_name(ctx: &mut NativeCtx<'_>, _args: &[Value]) -> Result<Value, NativeError> {
+    const NAME: &str = "get AsyncContext.Variable.prototype.name";
+    let variable = receiver::<VariableData>(ctx, NAME)?;
+    object::with_host_data::<VariableData, _>(variable, ctx.heap(), |data| data.name.value())
+        .map_err(|error| type_error(NAME, &error.to_string()))
+}
+
+// ---------------------------------------------------------------
+// AsyncContext.Snapshot
+// ---------------------------------------------------------------
+
+fn snapshot_ctor_call(ctx: &mut NativeCtx<'_>, _args: &[Value]) -> Result<Value, NativeError> {
+    const NAME: &str = "AsyncContext.Snapshot";
+    if !ctx.is_construct_call() {
+        return Err(type_error(NAME, "constructor requires 'new'"));
+    }
+    let prototype = crate::bootstrap::native_new_target_prototype(ctx, NAME)?;
+    let mapping = ctx.interp_mut().async_context.current();
+    ctx.scope(|mut scope| {
+        let prototype = prototype.map(|proto| scope.value(proto));
+        let mapping = scope.value(mapping);
+        let snapshot = scope.traced_host_object(SnapshotData::default())?;
+        scope.set_host_data_value::<SnapshotData>(snapshot, mapping, |data| &mut data.mapping)?;
+        if prototype.is_some() {
+            scope.set_prototype(snapshot, prototype)?;
+        }
+        Ok(scope.finish(snapshot))
+    })
+}
+
+/// `AsyncContext.Snapshot.prototype.run(fn, ...args)` — call `fn` in the
+/// mapping captured when the snapshot was constructed.
+fn snapshot_run(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
+    const NAME: &str = "AsyncContext.Snapshot.prototype.run";
+    let snapshot = receiver::<SnapshotData>(ctx, NAME)?;
+    let mapping = object::with_host_data::<SnapshotData, _>(snapshot, ctx.heap(), |data| {
+        data.mapping.value()
+    })
+    .map_err(|error| type_error(NAME, &error.to_string()))?;
+    ctx.scope(|mut scope| {
+        let mapping = scope.value(mapping);
+        let callback = scope.argument(args, 0);
+        let call_args: Vec<Local<'_>> = (1..args.len())
+            .map(|index| scope.argument(args, index))
+            .collect();
+        run_in_mapping(&mut scope, mapping, callback, &call_args)
+    })
+}
+
+// ---------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------
+
+/// Call `callback(...args)` with `mapping` current, restoring the
+/// previous mapping however the call completes.
+fn run_in_mapping(
+    scope: &mut NativeScope<'_, '_>,
+    mapping: Local<'_>,
+    callback: Local<'_>,
+    args: &[Local<'_>],
+) -> Result<Value, NativeError> {
+    let this_value = scope.undefined();
+    let mapping = scope.raw(mapping);
+    scope.context().interp_mut().async_context.enter(mapping);
+    let result = scope.call(callback, this_value, args);
+    scope.context().interp_mut().async_context.exit();
+    result.map(|value| scope.raw(value))
+}
+
+/// Brand-check the receiver as an instance carrying `T`.
+fn receiver<T: TracedHostObjectData>(
+    ctx: &NativeCtx<'_>,
+    name: &'static str,
+) -> Result<JsObject, NativeError> {
+    ctx.this_value()
+        .as_object()
+        .filter(|obj| object::with_host_data::<T, _>(*obj, ctx.heap(), |_| ()).is_ok())
+        .ok_or_else(|| type_error(name, "receiver is not the expected AsyncContext object"))
+}
+
+fn type_error(name: &'static str, reason: &str) -> NativeError {
+    NativeError::TypeError {
+        name,
+        reason: reason.to_string(),
+    }
+}
@@ -140,6 +140,7 @@ impl Interpreter {
             capability,
             None,
             Some(context.clone()),
+            self.async_context.current(),
         );
         if let Some(job) = outcome.immediate_job {
             self.microtasks.enqueue(job);
@@ -178,6 +179,7 @@ impl Interpreter {
             capability,
             Some(owner),
             Some(context.clone()),
+            self.async_context.current(),
         );
         if let Some(job) = outcome.immediate_job {
             self.microtasks.enqueue(job);
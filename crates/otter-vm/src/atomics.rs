@@ -44,7 +44,7 @@ use crate::binary::{JsTypedArray, TypedArrayKind};
 use crate::number::NumberValue;
 use crate::number::parse::to_integer_or_infinity;
 use crate::string::JsString;
-use crate::{NativeCtx, NativeError, Value, VmError};
+use crate::{InterruptFlag, NativeCtx, NativeError, Value, VmError};
 use std::sync::{LazyLock, Mutex};
 use std::time::{Duration, Instant};
 
@@ -748,7 +748,10 @@ fn do_wait(ctx: &mut NativeCtx<'_>, args: &[Value], is_async: bool) -> Result<Va
             Some(Duration::from_millis(ms))
         };
         let interrupt = ctx.interp_mut().interrupt_handle();
-        match atomics_wait::park_until_notified(buf_id, idx, dur, Some(&interrupt)) {
+        let parked_at = Instant::now();
+        let outcome = atomics_wait::park_until_notified(buf_id, idx, dur, Some(&interrupt));
+        ctx.interp_mut().record_cpu_park(parked_at);
+        match outcome {
             WaitOutcome::Ok => "ok",
             WaitOutcome::TimedOut => "timed-out",
             WaitOutcome::Interrupted | WaitOutcome::Cancelled => {
@@ -764,6 +767,13 @@ fn wait_async_sleep_for_timeout(ctx: &mut NativeCtx<'_>, ms: u64) -> Result<(),
         .checked_add(Duration::from_millis(ms))
         .unwrap_or_else(Instant::now);
     let interrupt = ctx.interp_mut().interrupt_handle();
+    let parked_at = Instant::now();
+    let slept = sleep_until_deadline(deadline, &interrupt);
+    ctx.interp_mut().record_cpu_park(parked_at);
+    slept
+}
+
+fn sleep_until_deadline(deadline: Instant, interrupt: &InterruptFlag) -> Result<(), NativeError> {
     while Instant::now() < deadline {
         if interrupt.is_set() {
             return Err(NativeError::Interrupted);
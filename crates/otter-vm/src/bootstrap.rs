@@ -340,6 +340,7 @@ pub static BOOTSTRAP_ENTRIES: &[BootstrapEntry] = &[
     crate::bootstrap_entry!(crate::intrinsics::placeholders::TemporalIntrinsic),
     crate::bootstrap_entry!(crate::intrinsics::placeholders::AggregateErrorIntrinsic),
     crate::bootstrap_entry!(crate::bootstrap_weak_refs::FinalizationRegistryIntrinsic),
+    crate::bootstrap_entry!(crate::async_context::Intrinsic),
     crate::bootstrap_entry!(crate::intrinsics::iterator::IteratorIntrinsic),
     crate::bootstrap_entry!(crate::console::Intrinsic),
     crate::bootstrap_entry!(crate::timers::Intrinsic),
@@ -903,18 +903,6 @@ fn map_proto_entries(ctx: &mut NativeCtx<'_>, _args: &[Value]) -> Result<Value,
     make_map_iterator(ctx, m, MapIterKind::Entries)
 }
 
-/// CanonicalizeKeyedCollectionKey — normalize a key's `-0` to `+0`;
-/// every other value passes through with its identity intact.
-fn canonicalize_collection_key(key: Value) -> Value {
-    if let Some(n) = key.as_number() {
-        let f = n.as_f64();
-        if f == 0.0 && f.is_sign_negative() {
-            return Value::number(crate::number::NumberValue::from_f64(0.0));
-        }
-    }
-    key
-}
-
 /// Map.prototype.getOrInsert(key, value) — Map.prototype.upsert
 /// proposal. Returns the existing value for `key`, otherwise inserts
 /// `value` and returns it.
@@ -965,8 +953,9 @@ fn map_proto_get_or_insert_computed(
     ctx.interp_mut().push_iteration_anchor(key);
     ctx.interp_mut().push_iteration_anchor(callback);
     let result = (|| {
-        let canonical =
-            canonicalize_collection_key(ctx.interp_mut().iteration_anchor(anchor_base + KEY));
+        let canonical = collections::canonicalize_collection_key(
+            ctx.interp_mut().iteration_anchor(anchor_base + KEY),
+        );
         let callback = ctx.interp_mut().iteration_anchor(anchor_base + CALLBACK);
         let value = ctx.call(callback, Value::undefined(), &[canonical])?;
         ctx.interp_mut().push_iteration_anchor(value);
@@ -2059,7 +2059,8 @@ impl Interpreter {
                     .expect("checked direct construct proxy");
                 if proxy.is_revoked(&self.gc_heap) {
                     return Err(self.err_type(
-                        ("Cannot perform 'construct' on a revoked proxy".to_string()).into(),
+                        ("Cannot perform 'construct' on a proxy that has been revoked".to_string())
+                            .into(),
                     ));
                 }
                 // Proxy.[[Construct]] captures [[ProxyTarget]] before the
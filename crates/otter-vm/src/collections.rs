@@ -160,6 +160,21 @@ impl MapKey {
     }
 }
 
+/// CanonicalizeKeyedCollectionKey — normalize a key's `-0` to `+0`;
+/// every other value passes through with its identity intact.
+///
+/// [`MapKey`] already collapses signed zeros for lookup; this keeps the
+/// *stored* key observable through iteration spec-correct as well.
+pub(crate) fn canonicalize_collection_key(key: Value) -> Value {
+    if let Some(n) = key.as_number() {
+        let f = n.as_f64();
+        if f == 0.0 && f.is_sign_negative() {
+            return Value::number(crate::number::NumberValue::from_f64(0.0));
+        }
+    }
+    key
+}
+
 /// Failure modes for collection mutations.
 #[derive(Debug, Clone, thiserror::Error)]
 #[non_exhaustive]
@@ -455,9 +470,11 @@ pub fn map_has(map: JsMap, heap: &otter_gc::GcHeap, key: &Value) -> bool {
 pub fn map_set(
     mut map: JsMap,
     heap: &mut otter_gc::GcHeap,
-    mut key: Value,
+    key: Value,
     mut value: Value,
 ) -> Result<(), otter_gc::OutOfMemory> {
+    // §24.1.3.9 step 5 — a `-0` key is stored as `+0`.
+    let mut key = canonicalize_collection_key(key);
     let lookup_key = MapKey::from_value(&key, heap);
     let needs_insert = heap.read_payload(map, |body| {
         map_find_entry(body, &lookup_key, heap).is_none()
@@ -496,10 +513,11 @@ pub fn map_set(
 pub(crate) fn map_set_with_roots(
     map: &mut JsMap,
     heap: &mut otter_gc::GcHeap,
-    mut key: Value,
+    key: Value,
     mut value: Value,
     external_visit: &mut RootSlotVisitor<'_>,
 ) -> Result<(), otter_gc::OutOfMemory> {
+    let mut key = canonicalize_collection_key(key);
     let lookup_key = MapKey::from_value(&key, heap);
     let needs_insert = heap.read_payload(*map, |body| {
         map_find_entry(body, &lookup_key, heap).is_none()
@@ -795,11 +813,13 @@ pub fn set_has(set: JsSet, heap: &otter_gc::GcHeap, value: &Value) -> bool {
 pub fn set_add(
     mut set: JsSet,
     heap: &mut otter_gc::GcHeap,
-    mut value: Value,
+    value: Value,
 ) -> Result<(), otter_gc::OutOfMemory> {
     if set_is_readonly(set, heap) {
         return Ok(());
     }
+    // §24.2.4.1 step 3 — a `-0` value is stored as `+0`.
+    let mut value = canonicalize_collection_key(value);
     let lookup_key = MapKey::from_value(&value, heap);
     let needs_insert = heap.read_payload(set, |body| {
         set_find_entry(body, &lookup_key, heap).is_none()
@@ -829,12 +849,13 @@ pub fn set_add(
 pub(crate) fn set_add_with_roots(
     set: &mut JsSet,
     heap: &mut otter_gc::GcHeap,
-    mut value: Value,
+    value: Value,
     external_visit: &mut RootSlotVisitor<'_>,
 ) -> Result<(), otter_gc::OutOfMemory> {
     if set_is_readonly(*set, heap) {
         return Ok(());
     }
+    let mut value = canonicalize_collection_key(value);
     let lookup_key = MapKey::from_value(&value, heap);
     let needs_insert = heap.read_payload(*set, |body| {
         set_find_entry(body, &lookup_key, heap).is_none()
@@ -1724,6 +1745,45 @@ mod tests {
         Value::object(crate::object::alloc_object_with_roots(heap, &mut no_roots).unwrap())
     }
 
+    #[test]
+    fn map_key_matches_agree_with_same_value_zero() {
+        // `MapKey` is the hot-path projection of SameValueZero; it must
+        // never disagree with the canonical `abstract_ops` helper.
+        let mut heap = otter_gc::GcHeap::new().expect("gc heap");
+        let object = young_object_value(&mut heap);
+        let other_object = young_object_value(&mut heap);
+        let hello = Value::string(crate::string::JsString::from_str("hello", &mut heap).unwrap());
+        let hello_again =
+            Value::string(crate::string::JsString::from_str("hello", &mut heap).unwrap());
+        let values = [
+            Value::undefined(),
+            Value::null(),
+            Value::boolean(true),
+            Value::boolean(false),
+            n(0),
+            n(1),
+            Value::number(NumberValue::from_f64(1.0)),
+            Value::number(NumberValue::from_f64(-0.0)),
+            Value::number(NumberValue::from_f64(f64::NAN)),
+            Value::number(NumberValue::from_f64(-f64::NAN)),
+            hello,
+            hello_again,
+            object,
+            other_object,
+        ];
+        for a in &values {
+            for b in &values {
+                let projected =
+                    MapKey::from_value(a, &heap).matches(&MapKey::from_value(b, &heap), &heap);
+                assert_eq!(
+                    projected,
+                    crate::abstract_ops::same_value_zero(a, b, &heap),
+                    "{a:?} vs {b:?}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn map_insertion_order_preserved() {
         let mut heap = otter_gc::GcHeap::new().expect("gc heap");
@@ -1790,6 +1850,21 @@ mod tests {
         assert_eq!(v, Some(n(7)));
     }
 
+    #[test]
+    fn collections_store_negative_zero_as_positive_zero() {
+        let mut heap = otter_gc::GcHeap::new().expect("gc heap");
+        let m = alloc_map(&mut heap).unwrap();
+        let neg_zero = Value::number(NumberValue::from_f64(-0.0));
+        map_set(m, &mut heap, neg_zero, n(1)).unwrap();
+        let key = map_keys(m, &heap)[0].as_number().unwrap().as_f64();
+        assert!(key == 0.0 && key.is_sign_positive());
+
+        let s = alloc_set(&mut heap).unwrap();
+        set_add(s, &mut heap, neg_zero).unwrap();
+        let value = set_values(s, &heap)[0].as_number().unwrap().as_f64();
+        assert!(value == 0.0 && value.is_sign_positive());
+    }
+
     #[test]
     fn map_samevaluezero_nan_matches() {
         let mut heap = otter_gc::GcHeap::new().expect("gc heap");
@@ -3,13 +3,16 @@
 //! The console surface is intentionally small and host-facing:
 //! `console.log` / `info` / `debug` write to stdout, `warn` /
 //! `error` / `assert` write to stderr, and every method returns
-//! `undefined`.
+//! `undefined`. `time` / `timeLog` / `timeEnd` keep label-keyed
+//! monotonic start times per isolate and report elapsed milliseconds
+//! on the log level.
 //!
 //! # Contents
 //! - [`CONSOLE_SPEC`] — static namespace spec used by bootstrap.
 //! - [`install`] — allocate and attach the `console` object through
 //!   the JS surface builder backend.
 //! - Native method bodies for the common console methods.
+//! - [`ConsoleTimers`] — per-isolate `console.time` label table.
 //! - Formatting helpers shared by stdout and stderr paths.
 //!
 //! # Invariants
@@ -18,6 +21,8 @@
 //! - Console methods use static native function pointers and keep no
 //!   hidden JS handles. The installed functions are strongly
 //!   reachable only through `globalThis`.
+//! - Timer labels use a monotonic clock, so wall-clock adjustments
+//!   never produce negative or skewed durations.
 //! - Error-shaped objects render through the same
 //!   `Error.prototype.toString` helper used by uncaught exception
 //!   diagnostics.
@@ -25,7 +30,9 @@
 //! # See also
 //! - <https://console.spec.whatwg.org/>
 
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Instant;
 
 use crate::{NativeCtx, NativeError, Value, error_classes, object};
 
@@ -52,6 +59,9 @@ otter_macros::holt! {
         "error"  / 0 => console_error,
         "trace"  / 0 => console_trace,
         "assert" / 0 => console_assert,
+        "time"    / 0 => console_time,
+        "timeLog" / 0 => console_time_log,
+        "timeEnd" / 0 => console_time_end,
     },
 }
 
@@ -108,6 +118,41 @@ impl ConsoleSink for StdConsoleSink {
     }
 }
 
+/// Label-keyed start times behind `console.time` / `timeLog` /
+/// `timeEnd`. One table per isolate; labels are never shared across
+/// realms on different threads.
+#[derive(Debug, Default)]
+pub struct ConsoleTimers {
+    started: HashMap<String, Instant>,
+}
+
+impl ConsoleTimers {
+    /// Start `label`. Returns `false` (leaving the original start in
+    /// place) when the label is already running.
+    pub fn start(&mut self, label: &str) -> bool {
+        if self.started.contains_key(label) {
+            return false;
+        }
+        self.started.insert(label.to_string(), Instant::now());
+        true
+    }
+
+    /// Milliseconds since `label` started, if it is running.
+    #[must_use]
+    pub fn elapsed_ms(&self, label: &str) -> Option<f64> {
+        self.started
+            .get(label)
+            .map(|start| start.elapsed().as_secs_f64() * 1000.0)
+    }
+
+    /// Stop `label` and return its elapsed milliseconds.
+    pub fn end(&mut self, label: &str) -> Option<f64> {
+        self.started
+            .remove(label)
+            .map(|start| start.elapsed().as_secs_f64() * 1000.0)
+    }
+}
+
 /// Build the default stdout/stderr console sink.
 #[must_use]
 pub fn default_console_sink() -> ConsoleSinkHandle {
@@ -163,6 +208,68 @@ fn console_assert(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, Nati
     Ok(Value::undefined())
 }
 
+fn console_time(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
+    let label = timer_label(ctx, args);
+    if !ctx.interp_mut().console_timers.start(&label) {
+        warn_timer(
+            ctx,
+            format!("Label '{label}' already exists for console.time()"),
+        );
+    }
+    Ok(Value::undefined())
+}
+
+fn console_time_log(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
+    let label = timer_label(ctx, args);
+    let Some(elapsed) = ctx.interp_mut().console_timers.elapsed_ms(&label) else {
+        warn_timer(
+            ctx,
+            format!("No such label '{label}' for console.timeLog()"),
+        );
+        return Ok(Value::undefined());
+    };
+    let mut values = vec![format_timer(&label, elapsed)];
+    values.extend(format_args(ctx, args.get(1..).unwrap_or(&[])));
+    ctx.interp_mut()
+        .console_sink()
+        .write(ConsoleLevel::Log, &values);
+    Ok(Value::undefined())
+}
+
+fn console_time_end(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
+    let label = timer_label(ctx, args);
+    let Some(elapsed) = ctx.interp_mut().console_timers.end(&label) else {
+        warn_timer(
+            ctx,
+            format!("No such label '{label}' for console.timeEnd()"),
+        );
+        return Ok(Value::undefined());
+    };
+    ctx.interp_mut()
+        .console_sink()
+        .write(ConsoleLevel::Log, &[format_timer(&label, elapsed)]);
+    Ok(Value::undefined())
+}
+
+/// Console § Timing — an omitted or `undefined` label is `"default"`.
+fn timer_label(ctx: &NativeCtx<'_>, args: &[Value]) -> String {
+    match args.first() {
+        Some(value) if !value.is_undefined() => value.display_string(ctx.heap()),
+        _ => "default".to_string(),
+    }
+}
+
+/// Node's rendering: `label: 1.234ms`.
+fn format_timer(label: &str, elapsed_ms: f64) -> String {
+    format!("{label}: {elapsed_ms:.3}ms")
+}
+
+fn warn_timer(ctx: &mut NativeCtx<'_>, message: String) {
+    ctx.interp_mut()
+        .console_sink()
+        .write(ConsoleLevel::Warn, &[format!("Warning: {message}")]);
+}
+
 fn write(ctx: &mut NativeCtx<'_>, level: ConsoleLevel, args: &[Value]) {
     let values = format_args(ctx, args);
     ctx.interp_mut().console_sink().write(level, &values);
@@ -208,6 +208,7 @@ mod tests {
                 spans: Vec::new(),
                 number_hint_sites: Vec::new(),
                 class_hint_sites: Vec::new(),
+                optimization_hint: Default::default(),
             }],
             constants: vec![
                 string_constant("x"),
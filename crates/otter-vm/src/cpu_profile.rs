@@ -7,17 +7,35 @@
 //!
 //! # Contents
 //! - [`CpuProfiler`] — dispatch-loop sampler.
-//! - [`CpuProfile`] — owned sample data returned to embedders.
+//! - [`SamplingMode`] — wall-clock (parked time shows up as idle samples) or
+//!   on-CPU (only bytecode execution is sampled).
+//! - [`CpuProfile`] — owned sample data returned to embedders, plus the
+//!   self-contained, interactive flamegraph SVG rendering (hover details and
+//!   a search that highlights matching frames).
 //!
 //! # Invariants
 //! - Disabled profilers cost only an `Option` check in the dispatch loop.
 //! - Samples contain owned frame metadata, never borrowed frames/registers.
 //! - `time_deltas_us` has one entry per sample and uses wall-clock deltas between
-//!   sample points so Chrome profile consumers can render a timeline.
+//!   sample points so Chrome profile consumers can render a timeline. In
+//!   on-CPU mode those deltas exclude time the VM thread spent parked.
+//! - Parks are reported by whoever blocks the VM thread (`Atomics.wait`, the
+//!   isolate runner's inbox) through `Interpreter::record_cpu_park`; the
+//!   dispatch-loop sampler itself never runs while parked.
+//! - Flamegraph output is a pure function of the samples: siblings are ordered
+//!   by name and colors hash the frame name, so re-rendering the same profile
+//!   yields byte-identical SVG.
+//! - Flamegraph stacks deeper than [`FLAMEGRAPH_MAX_DEPTH`] frames fold their
+//!   remaining callees into one `...` frame at the last row.
 //!
 //! # See also
 //! - [`crate::error_ops::snapshot_frames`]
 //! - [`crate::run_control::StackFrameSnapshot`]
+//! - <https://github.com/brendangregg/FlameGraph> — the layout and palette the
+//!   SVG follows.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
 
 use serde::{Deserialize, Serialize};
 
@@ -42,12 +60,327 @@ impl CpuProfile {
     pub fn sample_count(&self) -> usize {
         self.samples.len()
     }
+
+    /// Render the samples as a self-contained flamegraph SVG.
+    ///
+    /// Stacks grow upward from an `all` root; each frame's width is
+    /// proportional to the samples that include it. Every frame carries a
+    /// `<title>` tooltip with its sample count and share, mirrored into a
+    /// details line on hover. An embedded script adds a "Search" control that
+    /// highlights frames matching a regular expression and reports the share
+    /// of samples they cover. An empty profile renders a valid SVG with a
+    /// "No samples" note.
+    #[must_use]
+    pub fn to_flamegraph_svg(&self) -> String {
+        let tree = FlameTree::from_samples(&self.samples);
+        let total = tree.nodes[0].samples;
+        let rows = tree.max_depth + 1;
+        let height = FLAMEGRAPH_TOP + rows as f64 * FLAMEGRAPH_ROW + FLAMEGRAPH_BOTTOM;
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r##"<?xml version="1.0" encoding="UTF-8" standalone="no"?>
+<svg version="1.1" xmlns="http://www.w3.org/2000/svg" width="{FLAMEGRAPH_WIDTH}" height="{height}" viewBox="0 0 {FLAMEGRAPH_WIDTH} {height}">
+<rect x="0" y="0" width="100%" height="100%" fill="#f8f8f8"/>
+<text x="{center}" y="24" font-family="Verdana" font-size="17" text-anchor="middle">Flame Graph</text>
+<text id="search" x="{search_x}" y="24" font-family="Verdana" font-size="12" style="cursor:pointer">Search</text>
+<text id="matched" x="{search_x}" y="{footer_y}" font-family="Verdana" font-size="12"> </text>
+<text id="details" x="{FLAMEGRAPH_PAD}" y="{footer_y}" font-family="Verdana" font-size="12"> </text>"##,
+            center = FLAMEGRAPH_WIDTH / 2.0,
+            search_x = FLAMEGRAPH_WIDTH - FLAMEGRAPH_PAD - 100.0,
+            footer_y = height - FLAMEGRAPH_PAD,
+        );
+        if total == 0 {
+            let _ = writeln!(
+                svg,
+                r#"<text x="{}" y="{}" font-family="Verdana" font-size="12" text-anchor="middle">No samples</text>"#,
+                FLAMEGRAPH_WIDTH / 2.0,
+                FLAMEGRAPH_TOP + FLAMEGRAPH_ROW,
+            );
+            svg.push_str("</svg>\n");
+            return svg;
+        }
+
+        let per_sample = (FLAMEGRAPH_WIDTH - 2.0 * FLAMEGRAPH_PAD) / total as f64;
+        // (node, depth, x of the frame's left edge)
+        let mut pending = vec![(0usize, 0usize, FLAMEGRAPH_PAD)];
+        while let Some((index, depth, x)) = pending.pop() {
+            let node = &tree.nodes[index];
+            let width = node.samples as f64 * per_sample;
+            if width < FLAMEGRAPH_MIN_WIDTH {
+                continue;
+            }
+            let y = height - FLAMEGRAPH_BOTTOM - (depth + 1) as f64 * FLAMEGRAPH_ROW;
+            write_flamegraph_frame(&mut svg, &node.name, node.samples, total, x, y, width);
+            let mut child_x = x;
+            for &child in node.children.values() {
+                pending.push((child, depth + 1, child_x));
+                child_x += tree.nodes[child].samples as f64 * per_sample;
+            }
+        }
+        svg.push_str(FLAMEGRAPH_SCRIPT);
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+/// Most flamegraph rows, counting the `all` root. Deeper callees are merged
+/// into one `...` frame on the last row.
+const FLAMEGRAPH_MAX_DEPTH: usize = 256;
+
+const FLAMEGRAPH_WIDTH: f64 = 1200.0;
+const FLAMEGRAPH_ROW: f64 = 16.0;
+const FLAMEGRAPH_TOP: f64 = 40.0;
+const FLAMEGRAPH_PAD: f64 = 10.0;
+/// Space under the root row for the details and match-share line.
+const FLAMEGRAPH_BOTTOM: f64 = 34.0;
+/// Frames narrower than this many pixels are omitted, like `flamegraph.pl`.
+const FLAMEGRAPH_MIN_WIDTH: f64 = 0.1;
+/// Approximate glyph width of the 12px label font.
+const FLAMEGRAPH_CHAR_WIDTH: f64 = 7.0;
+
+/// Samples merged into a call tree. Node 0 is the synthetic `all` root.
+struct FlameTree {
+    nodes: Vec<FlameNode>,
+    max_depth: usize,
+}
+
+struct FlameNode {
+    name: String,
+    /// Samples whose stack passes through this node.
+    samples: u64,
+    /// Callees keyed by label, so layout order does not depend on sample order.
+    children: BTreeMap<String, usize>,
+}
+
+impl FlameTree {
+    fn from_samples(samples: &[Vec<StackFrameSnapshot>]) -> Self {
+        let mut tree = Self {
+            nodes: vec![FlameNode::new("all".to_string())],
+            max_depth: 0,
+        };
+        for sample in samples {
+            tree.nodes[0].samples += 1;
+            let mut labels: Vec<String> = sample.iter().rev().map(flamegraph_label).collect();
+            if labels.is_empty() {
+                labels.push("(idle)".to_string());
+            }
+            // Row 0 is `all`, so a stack keeps FLAMEGRAPH_MAX_DEPTH - 1 real
+            // frames and the last row reads `...` for everything deeper.
+            if labels.len() >= FLAMEGRAPH_MAX_DEPTH {
+                labels.truncate(FLAMEGRAPH_MAX_DEPTH - 1);
+                labels[FLAMEGRAPH_MAX_DEPTH - 2] = "...".to_string();
+            }
+            let mut current = 0;
+            for label in labels {
+                current = tree.child(current, label);
+                tree.nodes[current].samples += 1;
+            }
+            tree.max_depth = tree
+                .max_depth
+                .max(sample.len().clamp(1, FLAMEGRAPH_MAX_DEPTH - 1));
+        }
+        tree
+    }
+
+    fn child(&mut self, parent: usize, label: String) -> usize {
+        if let Some(&index) = self.nodes[parent].children.get(&label) {
+            return index;
+        }
+        let index = self.nodes.len();
+        self.nodes.push(FlameNode::new(label.clone()));
+        self.nodes[parent].children.insert(label, index);
+        index
+    }
+}
+
+impl FlameNode {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            samples: 0,
+            children: BTreeMap::new(),
+        }
+    }
+}
+
+fn flamegraph_label(frame: &StackFrameSnapshot) -> String {
+    if frame.module.is_empty() {
+        frame.function_name.clone()
+    } else {
+        format!("{} [{}]", frame.function_name, frame.module)
+    }
+}
+
+fn write_flamegraph_frame(
+    svg: &mut String,
+    name: &str,
+    samples: u64,
+    total: u64,
+    x: f64,
+    y: f64,
+    width: f64,
+) {
+    let percent = samples as f64 * 100.0 / total as f64;
+    let plural = if samples == 1 { "" } else { "s" };
+    let _ = write!(
+        svg,
+        r#"<g class="frame"><title>{} ({samples} sample{plural}, {percent:.2}%)</title><rect x="{x:.2}" y="{y:.1}" width="{width:.2}" height="{h:.1}" fill="{}" rx="2" ry="2"/>"#,
+        xml_escape(name),
+        flamegraph_color(name),
+        h = FLAMEGRAPH_ROW - 1.0,
+    );
+    // Labels are clipped to whole characters; frames too narrow for a
+    // couple of glyphs keep only the tooltip.
+    let fits = ((width - 6.0) / FLAMEGRAPH_CHAR_WIDTH).floor() as usize;
+    if fits >= 3 {
+        let label: String = if name.chars().count() <= fits {
+            name.to_string()
+        } else {
+            name.chars().take(fits - 2).chain("..".chars()).collect()
+        };
+        let _ = write!(
+            svg,
+            r#"<text x="{:.2}" y="{:.1}" font-family="Verdana" font-size="12">{}</text>"#,
+            x + 3.0,
+            y + FLAMEGRAPH_ROW - 4.5,
+            xml_escape(&label),
+        );
+    }
+    svg.push_str("</g>\n");
+}
+
+/// Hover and search behaviour, kept free of `]]>` so it embeds as CDATA.
+///
+/// Hovering a frame copies its `<title>` into `#details`. "Search" prompts for
+/// a regular expression, paints matching frames magenta, and reports in
+/// `#matched` the share of root width they cover (overlapping ancestor and
+/// descendant matches counted once); clicking it again resets the view.
+const FLAMEGRAPH_SCRIPT: &str = r#"<script type="text/ecmascript"><![CDATA[
+(function () {
+  var frames = Array.prototype.slice.call(document.querySelectorAll("g.frame"));
+  var details = document.getElementById("details");
+  var matched = document.getElementById("matched");
+  var search = document.getElementById("search");
+  var searching = false;
+  function frameName(frame) {
+    return frame.querySelector("title").textContent.replace(/ \([^()]*\)$/, "");
+  }
+  frames.forEach(function (frame) {
+    var rect = frame.querySelector("rect");
+    rect.setAttribute("data-fill", rect.getAttribute("fill"));
+    frame.addEventListener("mouseover", function () {
+      details.textContent = frame.querySelector("title").textContent;
+    });
+    frame.addEventListener("mouseout", function () {
+      details.textContent = " ";
+    });
+  });
+  function reset() {
+    frames.forEach(function (frame) {
+      var rect = frame.querySelector("rect");
+      rect.setAttribute("fill", rect.getAttribute("data-fill"));
+    });
+    matched.textContent = " ";
+    search.textContent = "Search";
+    searching = false;
+  }
+  search.addEventListener("click", function () {
+    if (searching) {
+      reset();
+      return;
+    }
+    var term = window.prompt("Search frames (regular expression):", "");
+    if (!term) {
+      return;
+    }
+    var pattern;
+    try {
+      pattern = new RegExp(term);
+    } catch (error) {
+      window.alert(String(error));
+      return;
+    }
+    var spans = [];
+    var rootWidth = 0;
+    frames.forEach(function (frame) {
+      var rect = frame.querySelector("rect");
+      var x = parseFloat(rect.getAttribute("x"));
+      var width = parseFloat(rect.getAttribute("width"));
+      rootWidth = Math.max(rootWidth, width);
+      if (pattern.test(frameName(frame))) {
+        rect.setAttribute("fill", "rgb(230,0,230)");
+        spans.push([x, x + width]);
+      }
+    });
+    spans.sort(function (a, b) { return a[0] - b[0]; });
+    var covered = 0;
+    var end = -Infinity;
+    spans.forEach(function (span) {
+      if (span[1] > end) {
+        covered += span[1] - Math.max(span[0], end);
+        end = span[1];
+      }
+    });
+    var share = rootWidth > 0 ? (100 * covered / rootWidth).toFixed(1) : "0.0";
+    matched.textContent = "Matched: " + share + "%";
+    search.textContent = "Reset Search";
+    searching = true;
+  });
+})();
+]]></script>
+"#;
+
+/// Warm `flamegraph.pl`-style color from an FNV-1a hash of the frame name.
+fn flamegraph_color(name: &str) -> String {
+    let hash = name.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    let red = 205 + (hash % 51);
+    let green = (hash >> 8) % 231;
+    let blue = (hash >> 16) % 56;
+    format!("rgb({red},{green},{blue})")
+}
+
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// Shortest park [`SamplingMode::WallClock`] records as an idle sample;
+/// shorter parks stay in the next sample's time delta.
+pub const WALL_CLOCK_MIN_IDLE_PARK: std::time::Duration = std::time::Duration::from_millis(1);
+
+/// What the sampler counts as profiled time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SamplingMode {
+    /// Elapsed time, including time the VM thread spends parked in native or
+    /// async waits. Each park is recorded as one empty-stack idle sample whose
+    /// time delta covers the park.
+    #[default]
+    WallClock,
+    /// Only time the VM thread spends running bytecode. Parked time records no
+    /// samples and is excluded from the next sample's time delta.
+    OnCpu,
 }
 
 /// Dispatch-loop VM stack sampler.
 #[derive(Debug)]
 pub(crate) struct CpuProfiler {
     interval: u64,
+    mode: SamplingMode,
     ticks_until_sample: u64,
     samples: Vec<Vec<StackFrameSnapshot>>,
     time_deltas_us: Vec<u64>,
@@ -55,12 +388,21 @@ pub(crate) struct CpuProfiler {
 }
 
 impl CpuProfiler {
-    /// Create a profiler that samples every `interval` bytecode ticks.
+    /// Create a wall-clock profiler that samples every `interval` bytecode
+    /// ticks.
     #[must_use]
     pub(crate) fn new(interval: u64) -> Self {
+        Self::with_mode(interval, SamplingMode::WallClock)
+    }
+
+    /// Create a profiler in `mode` that samples every `interval` bytecode
+    /// ticks.
+    #[must_use]
+    pub(crate) fn with_mode(interval: u64, mode: SamplingMode) -> Self {
         let interval = interval.max(1);
         Self {
             interval,
+            mode,
             ticks_until_sample: interval,
             samples: Vec::new(),
             time_deltas_us: Vec::new(),
@@ -91,6 +433,35 @@ impl CpuProfiler {
         self.time_deltas_us.push(delta);
     }
 
+    /// Account for a park of the VM thread that began at `started` and ended
+    /// now.
+    ///
+    /// Wall-clock mode records the park as a single idle sample whose time
+    /// delta is the park's duration; parks shorter than
+    /// [`WALL_CLOCK_MIN_IDLE_PARK`] are left to the next sample's delta.
+    /// On-CPU mode records nothing. Both modes shift the delta baseline past
+    /// the park.
+    pub(crate) fn record_park(&mut self, started: std::time::Instant) {
+        // A profiler enabled mid-park only accounts for the part it saw.
+        let started = started.max(self.last_sample_at);
+        let parked = started.elapsed();
+        match self.mode {
+            SamplingMode::WallClock => {
+                if parked < WALL_CLOCK_MIN_IDLE_PARK {
+                    return;
+                }
+                // The running time between the previous sample and the start
+                // of the park stays in the baseline, so it carries over into
+                // the next sample's delta instead of inflating this one.
+                self.samples.push(Vec::new());
+                self.time_deltas_us
+                    .push(u64::try_from(parked.as_micros()).unwrap_or(u64::MAX));
+                self.last_sample_at += parked;
+            }
+            SamplingMode::OnCpu => self.last_sample_at += parked,
+        }
+    }
+
     /// Consume the sampler and return the owned profile.
     #[must_use]
     pub(crate) fn finish(self) -> CpuProfile {
@@ -101,3 +472,175 @@ impl CpuProfiler {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(name: &str) -> StackFrameSnapshot {
+        StackFrameSnapshot {
+            function_id: 0,
+            function_name: name.to_string(),
+            module: String::new(),
+            span: (0, 0),
+        }
+    }
+
+    /// Build a profile from root-first stacks.
+    fn profile(stacks: &[&[&str]]) -> CpuProfile {
+        let samples: Vec<Vec<StackFrameSnapshot>> = stacks
+            .iter()
+            .map(|stack| stack.iter().rev().map(|name| frame(name)).collect())
+            .collect();
+        CpuProfile {
+            interval: 1,
+            time_deltas_us: vec![1; samples.len()],
+            samples,
+        }
+    }
+
+    fn rect_width(svg: &str, title_prefix: &str) -> f64 {
+        let start = svg
+            .find(&format!("<title>{title_prefix}"))
+            .unwrap_or_else(|| panic!("no frame {title_prefix:?} in {svg}"));
+        let rest = &svg[start..];
+        let width = &rest[rest.find("width=\"").expect("rect width") + 7..];
+        width[..width.find('"').expect("closing quote")]
+            .parse()
+            .expect("numeric width")
+    }
+
+    /// Minimal XML well-formedness check: every element closes in order and
+    /// the whole document is one `<svg>` root. Skips the prolog and CDATA.
+    fn assert_well_formed(svg: &str) {
+        let mut open: Vec<&str> = Vec::new();
+        let mut roots = 0;
+        let mut rest = svg.trim();
+        rest = rest.strip_prefix("<?xml").map_or(rest, |tail| {
+            &tail[tail.find("?>").expect("prolog end") + 2..]
+        });
+        while let Some(start) = rest.find('<') {
+            rest = &rest[start..];
+            if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+                rest = &cdata[cdata.find("]]>").expect("CDATA end") + 3..];
+                continue;
+            }
+            let end = rest.find('>').expect("tag end");
+            let tag = &rest[1..end];
+            rest = &rest[end + 1..];
+            if let Some(name) = tag.strip_prefix('/') {
+                assert_eq!(open.pop(), Some(name), "mismatched </{name}>");
+            } else if !tag.ends_with('/') {
+                let name = tag.split_whitespace().next().expect("tag name");
+                if open.is_empty() {
+                    roots += 1;
+                    assert_eq!(name, "svg");
+                }
+                open.push(name);
+            }
+        }
+        assert!(open.is_empty(), "unclosed elements {open:?}");
+        assert_eq!(roots, 1);
+    }
+
+    #[test]
+    fn two_stacks_render_both_frames_with_search_and_tooltips() {
+        let svg = profile(&[&["main", "parse"], &["main", "render"]]).to_flamegraph_svg();
+        assert_well_formed(&svg);
+        assert!(svg.contains("<title>parse (1 sample, 50.00%)</title>"));
+        assert!(svg.contains("<title>render (1 sample, 50.00%)</title>"));
+        assert!(svg.contains(r#"<text id="search""#));
+        assert!(svg.contains(r#"<text id="details""#));
+        assert!(svg.contains("<script"));
+        assert_well_formed(&profile(&[]).to_flamegraph_svg());
+    }
+
+    #[test]
+    fn empty_profile_renders_a_valid_svg() {
+        let svg = profile(&[]).to_flamegraph_svg();
+        assert!(svg.starts_with("<?xml"));
+        assert!(svg.contains("No samples"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert!(!svg.contains("<title>"));
+    }
+
+    #[test]
+    fn frame_width_is_proportional_to_samples() {
+        let svg = profile(&[
+            &["main", "hot"],
+            &["main", "hot"],
+            &["main", "hot"],
+            &["main", "cold"],
+        ])
+        .to_flamegraph_svg();
+        assert!(svg.contains("<title>main (4 samples, 100.00%)</title>"));
+        let hot = rect_width(&svg, "hot (3 samples");
+        let cold = rect_width(&svg, "cold (1 sample,");
+        assert!((hot - 3.0 * cold).abs() < 0.05, "hot={hot} cold={cold}");
+    }
+
+    #[test]
+    fn output_and_colors_are_deterministic() {
+        let first = profile(&[&["main", "b"], &["main", "a"]]).to_flamegraph_svg();
+        let second = profile(&[&["main", "a"], &["main", "b"]]).to_flamegraph_svg();
+        assert_eq!(first, second);
+        assert_eq!(flamegraph_color("main"), flamegraph_color("main"));
+    }
+
+    #[test]
+    fn deep_recursion_is_clamped_with_a_marker() {
+        let names: Vec<String> = (0..1000).map(|depth| format!("f{depth}")).collect();
+        let stack: Vec<&str> = names.iter().map(String::as_str).collect();
+        let svg = profile(&[&stack]).to_flamegraph_svg();
+        assert!(svg.contains("<title>... (1 sample, 100.00%)</title>"));
+        assert!(svg.contains("<title>f253 (1 sample"));
+        assert!(!svg.contains("<title>f254 "));
+        let expected_height =
+            FLAMEGRAPH_TOP + FLAMEGRAPH_MAX_DEPTH as f64 * FLAMEGRAPH_ROW + FLAMEGRAPH_BOTTOM;
+        assert!(svg.contains(&format!("height=\"{expected_height}\"")));
+    }
+
+    #[test]
+    fn labels_are_xml_escaped() {
+        let svg = profile(&[&["<main>", "a&b"]]).to_flamegraph_svg();
+        assert!(svg.contains("&lt;main&gt;"));
+        assert!(svg.contains("a&amp;b"));
+        assert!(!svg.contains("<main>"));
+    }
+
+    fn parked_for(ms: u64) -> std::time::Instant {
+        std::time::Instant::now() - std::time::Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn wall_clock_park_records_one_idle_sample() {
+        let mut profiler = CpuProfiler::new(1);
+        profiler.last_sample_at = parked_for(15);
+        profiler.record_park(parked_for(5));
+        // The ~10ms that ran before the park stays in the baseline.
+        assert!(profiler.last_sample_at < parked_for(8));
+        let profile = profiler.finish();
+        assert_eq!(profile.sample_count(), 1, "{profile:?}");
+        assert!(profile.samples[0].is_empty());
+        let delta = profile.time_deltas_us[0];
+        assert!((5_000..10_000).contains(&delta), "{delta}us");
+    }
+
+    #[test]
+    fn on_cpu_park_records_nothing_and_skips_the_parked_time() {
+        let mut profiler = CpuProfiler::with_mode(1, SamplingMode::OnCpu);
+        profiler.last_sample_at = parked_for(60);
+        profiler.record_park(parked_for(50));
+        // The baseline moved ~50ms forward: the next delta holds only the
+        // ~10ms that ran before the park.
+        assert!(profiler.last_sample_at > parked_for(30));
+        assert_eq!(profiler.finish().sample_count(), 0);
+    }
+
+    #[test]
+    fn short_parks_stay_in_the_next_delta() {
+        let mut profiler = CpuProfiler::new(1);
+        profiler.record_park(std::time::Instant::now());
+        assert_eq!(profiler.finish().sample_count(), 0);
+    }
+}
@@ -201,7 +201,9 @@ fn get_timezone_offset_impl(
     )))
 }
 
-/// §B.2.4 (Temporal proposal) — `Date.prototype.toTemporalInstant()`.
+/// Temporal proposal — `Date.prototype.toTemporalInstant()`. The
+/// `[[DateValue]]` milliseconds become the `Temporal.Instant`'s epoch
+/// nanoseconds; an Invalid Date throws `RangeError`.
 fn to_temporal_instant_impl(
     ctx: &mut NativeCtx<'_>,
     _args: &[Value],
@@ -27,8 +27,8 @@ use smallvec::SmallVec;
 
 use crate::{
     ActiveFrameMut, ErrorKind, ExecutionContext, Frame, Interpreter, JsString, NativeError,
-    StackFrameSnapshot, Value, VmError, error_classes, object, read_register, symbol_dispatch,
-    write_register,
+    STACK_OVERFLOW_MESSAGE, StackFrameSnapshot, Value, VmError, error_classes, object,
+    read_register, symbol_dispatch, write_register,
 };
 
 impl Interpreter {
@@ -214,7 +214,7 @@ impl Interpreter {
     }
 
     /// `Error` instance. Returns `None` for variants that should
-    /// keep propagating as host errors (StackOverflow, etc.).
+    /// keep propagating as host errors (Interrupted, etc.).
     pub(crate) fn vm_error_to_throwable_with_stack_roots(
         &mut self,
         context: Option<&ExecutionContext>,
@@ -329,6 +329,11 @@ impl Interpreter {
                 error_classes::ErrorKind::TypeError,
                 "unknown intrinsic method",
             ),
+            // Both the JS frame limit and the native re-entry limit
+            // surface as V8's catchable `RangeError`.
+            VmError::StackOverflow { .. } => {
+                (error_classes::ErrorKind::RangeError, STACK_OVERFLOW_MESSAGE)
+            }
             VmError::OutOfMemory { .. } => {
                 dynamic_message = err.to_string();
                 (
@@ -41,7 +41,7 @@ pub(crate) mod code_block_cfg;
 
 use otter_bytecode::{
     ArgumentBindingStorage, ArgumentsObjectKind, BytecodeModule, Function, FunctionCode,
-    FunctionCodeBuilder, Op, Operand, SpanEntry,
+    FunctionCodeBuilder, Op, Operand, OptimizationHint, SpanEntry,
     encoding::{
         FunctionLayout, layout_wordcode_function, measure_wordcode_function,
         translate_spans_to_byte_pcs,
@@ -399,6 +399,7 @@ impl CodeBlock {
             byte_spans: Box::new([]),
             number_hints: Box::new([]),
             class_hints: Box::new([]),
+            optimization_hint: OptimizationHint::None,
         })
     }
 
@@ -893,6 +894,9 @@ pub struct CodeBlock {
     /// compile instead of refusing it for lack of a profile; a wrong annotation
     /// misses the guard the site already emits.
     pub(crate) class_hints: Box<[(u32, u32)]>,
+    /// Mirrors [`otter_bytecode::Function::optimization_hint`]. Consulted by
+    /// the tier-up threshold and the inliner; never by execution itself.
+    pub(crate) optimization_hint: OptimizationHint,
 }
 
 impl Clone for CodeBlock {
@@ -928,6 +932,7 @@ impl Clone for CodeBlock {
             byte_spans: self.byte_spans.clone(),
             number_hints: self.number_hints.clone(),
             class_hints: self.class_hints.clone(),
+            optimization_hint: self.optimization_hint,
         }
     }
 }
@@ -1122,6 +1127,7 @@ impl CodeBlock {
             byte_spans,
             number_hints,
             class_hints,
+            optimization_hint: function.optimization_hint,
         }
     }
 
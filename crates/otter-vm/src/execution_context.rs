@@ -650,6 +650,7 @@ mod tests {
                 spans: Vec::new(),
                 number_hint_sites: Vec::new(),
                 class_hint_sites: Vec::new(),
+                optimization_hint: Default::default(),
             }],
             constants,
             module_resolutions: Vec::new(),
@@ -11,6 +11,8 @@
 //! - [`StepEvent`] — per-instruction event payload.
 //! - [`format_event`] / [`format_header`] — canonical text writers.
 //! - [`WriterTracer`] — flushes one line per event to a `Write` sink.
+//! - [`FunctionTimingTracer`] — aggregates per-function call counts and
+//!   total/self time into a [`FunctionProfile`] instead of emitting lines.
 //!
 //! # Invariants
 //! - Mnemonics come from [`otter_bytecode::Op::mnemonic`]; renaming an
@@ -42,8 +44,10 @@
 //! - [`FrameSnapshot`] / [`RegisterSnapshot`] — frame and
 //!   register window inspection from inside a step-tracer hook.
 
+use std::collections::HashMap;
 use std::fmt::Write as _;
 use std::io::Write;
+use std::time::{Duration, Instant};
 
 use otter_bytecode::{Op, Operand};
 
@@ -182,6 +186,218 @@ fn format_operand(out: &mut String, operand: &Operand) {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Function timing — aggregated per-function profile built from step events.
+// ---------------------------------------------------------------------------
+
+/// Aggregated timing for one bytecode function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionTiming {
+    /// VM-local function id.
+    pub function_id: u32,
+    /// Source-declared function name. `<main>` for module entry.
+    pub function_name: String,
+    /// Number of observed activations.
+    pub calls: u64,
+    /// Inclusive wall time: from activation entry until its return,
+    /// callees on the same dispatch stack included. Recursive
+    /// activations are charged once, at the outermost level.
+    pub total: Duration,
+    /// Wall time spent dispatching this function's own instructions,
+    /// including native builtins they call.
+    pub self_time: Duration,
+}
+
+/// Flat per-function profile, one row per function that dispatched at
+/// least one instruction, sorted by descending self time.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FunctionProfile {
+    /// Per-function rows.
+    pub functions: Vec<FunctionTiming>,
+}
+
+impl FunctionProfile {
+    /// Row for the first function named `name`, if any.
+    #[must_use]
+    pub fn function(&self, name: &str) -> Option<&FunctionTiming> {
+        self.functions.iter().find(|row| row.function_name == name)
+    }
+
+    /// Render the profile as a text table — one line per function,
+    /// columns: `calls`, `total_ms`, `self_ms`, `function`.
+    #[must_use]
+    pub fn render_text(&self) -> String {
+        let mut out = String::with_capacity(64 + self.functions.len() * 48);
+        let _ = writeln!(
+            out,
+            "; otter function timing — functions={}",
+            self.functions.len()
+        );
+        let _ = writeln!(out, "     calls    total_ms     self_ms  function");
+        for row in &self.functions {
+            let _ = writeln!(
+                out,
+                "  {:>8}  {:>10.3}  {:>10.3}  {}",
+                row.calls,
+                row.total.as_secs_f64() * 1000.0,
+                row.self_time.as_secs_f64() * 1000.0,
+                row.function_name,
+            );
+        }
+        out
+    }
+}
+
+/// Step tracer that aggregates call counts plus total and self time per
+/// function instead of recording every instruction.
+///
+/// The interval between two consecutive step events is charged as self
+/// time to the function that dispatched the earlier one. An activation
+/// starts when a function dispatches its entry PC from a different
+/// frame (or right after returning), and ends on the event after its
+/// return opcode. Exception unwinding closes every activation above the
+/// function that resumes. The profile is written to the sink, formatted
+/// by [`FunctionProfile::render_text`], when the tracer is dropped — for
+/// example when the runtime is torn down or the tracer is replaced.
+///
+/// Like the step trace this observes interpreter dispatch only: time
+/// spent in a JIT-compiled body is charged to the interpreter frame that
+/// entered it.
+pub struct FunctionTimingTracer<W: Write> {
+    writer: W,
+    /// One row per `(function_id, function_name)`; ids are local to an
+    /// execution context, so the name keeps scripts that reuse an id apart.
+    rows: Vec<FunctionTiming>,
+    rows_by_id: HashMap<u32, Vec<usize>>,
+    /// Open activations as `(row, entered_at)`, innermost last.
+    activations: Vec<(usize, Instant)>,
+    /// Row and frame depth of the previous event.
+    last: Option<(usize, usize)>,
+    last_at: Instant,
+    /// Row whose return opcode was the previous event.
+    pending_return: Option<usize>,
+}
+
+impl<W: Write> FunctionTimingTracer<W> {
+    /// Aggregate into a fresh profile and write it to `writer` on drop.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            rows: Vec::new(),
+            rows_by_id: HashMap::new(),
+            activations: Vec::new(),
+            last: None,
+            last_at: Instant::now(),
+            pending_return: None,
+        }
+    }
+
+    /// Snapshot the profile aggregated so far. Activations still open
+    /// are charged their inclusive time up to the latest step.
+    #[must_use]
+    pub fn profile(&self) -> FunctionProfile {
+        let mut functions = self.rows.clone();
+        let mut open: Vec<usize> = Vec::new();
+        for &(row, entered_at) in &self.activations {
+            if open.contains(&row) {
+                continue;
+            }
+            open.push(row);
+            functions[row].total += self.last_at.saturating_duration_since(entered_at);
+        }
+        functions.sort_by(|a, b| {
+            b.self_time
+                .cmp(&a.self_time)
+                .then_with(|| a.function_id.cmp(&b.function_id))
+        });
+        FunctionProfile { functions }
+    }
+
+    fn row_for(&mut self, event: &StepEvent<'_>) -> usize {
+        if let Some((row, _)) = self.last
+            && self.rows[row].function_id == event.function_id
+            && self.rows[row].function_name == event.function_name
+        {
+            return row;
+        }
+        let candidates = self.rows_by_id.entry(event.function_id).or_default();
+        if let Some(&row) = candidates
+            .iter()
+            .find(|&&row| self.rows[row].function_name == event.function_name)
+        {
+            return row;
+        }
+        let row = self.rows.len();
+        candidates.push(row);
+        self.rows.push(FunctionTiming {
+            function_id: event.function_id,
+            function_name: event.function_name.to_string(),
+            calls: 0,
+            total: Duration::ZERO,
+            self_time: Duration::ZERO,
+        });
+        row
+    }
+
+    fn close_top(&mut self, now: Instant) {
+        let Some((row, entered_at)) = self.activations.pop() else {
+            return;
+        };
+        // Charge recursion once: only the outermost activation adds its
+        // inclusive span.
+        if self.activations.iter().any(|&(open, _)| open == row) {
+            return;
+        }
+        self.rows[row].total += now.saturating_duration_since(entered_at);
+    }
+}
+
+impl<W: Write> StepTracer for FunctionTimingTracer<W> {
+    fn on_step(&mut self, event: &StepEvent<'_>) {
+        let now = Instant::now();
+        if let Some((previous, _)) = self.last {
+            self.rows[previous].self_time += now.saturating_duration_since(self.last_at);
+        }
+        let returned = self.pending_return.take();
+        if let Some(returning) = returned
+            && self.activations.last().map(|&(row, _)| row) == Some(returning)
+        {
+            self.close_top(now);
+        }
+        let row = self.row_for(event);
+        let current = (row, event.frame_depth);
+        let entered = event.byte_pc == 0 && (self.last != Some(current) || returned == Some(row));
+        if entered {
+            self.rows[row].calls += 1;
+            self.activations.push((row, now));
+        } else if self.activations.iter().any(|&(open, _)| open == row) {
+            while self
+                .activations
+                .last()
+                .is_some_and(|&(open, _)| open != row)
+            {
+                self.close_top(now);
+            }
+        }
+        if matches!(
+            event.op,
+            Op::Return | Op::ReturnValue | Op::ReturnUndefined | Op::TailCall
+        ) {
+            self.pending_return = Some(row);
+        }
+        self.last = Some(current);
+        self.last_at = now;
+    }
+}
+
+impl<W: Write> Drop for FunctionTimingTracer<W> {
+    fn drop(&mut self) {
+        let report = self.profile().render_text();
+        let _ = self.writer.write_all(report.as_bytes());
+        let _ = self.writer.flush();
+    }
+}
+
 // ---------------------------------------------------------------------------
 // IC, shape, and frame snapshots — point-in-time dumps for inspector tooling.
 // ---------------------------------------------------------------------------
@@ -909,12 +1125,14 @@ mod tests {
         Op::ReturnValue,
         Op::ReturnUndefined,
         Op::NewObject,
+        Op::NewObjectWithCapacity,
         Op::LoadProperty,
         Op::StoreProperty,
         Op::DeleteProperty,
         Op::GetPrototype,
         Op::SetPrototype,
         Op::NewArray,
+        Op::NewArrayWithCapacity,
         Op::LoadElement,
         Op::StoreElement,
         Op::ArrayLength,
@@ -65,17 +65,18 @@ impl Interpreter {
         // In the default Observe mode this collapses to a not-taken branch.
         let enforce_budget = self.runtime_budget.rejects_on_exceedance();
         // Like `enforce_budget`, these installation states are fixed for the
-        // duration of a dispatch run — a JIT hook, CPU profiler, or step tracer
-        // is attached between turns, never mid-loop. Hoisting the `is_some`
-        // probes into loop-invariant bools turns three per-instruction
-        // `self`-field loads into register-resident branches that collapse to
-        // not-taken in the common (no hook / no profiler / no tracer) case.
+        // duration of a dispatch run — a JIT hook, CPU profiler, opcode
+        // counter, or step tracer is attached between turns, never mid-loop.
+        // Hoisting the `is_some` probes into loop-invariant bools turns the
+        // per-instruction `self`-field loads into register-resident branches
+        // that collapse to not-taken in the common (no hook) case.
         let jit_installed = self.jit_hook.is_some();
         let has_profiler = self.cpu_profiler.is_some();
+        let has_opcode_counters = self.opcode_counters.is_some();
         let has_tracer = self.tracer.is_some();
         // Union of the per-instruction hooks, tested once per dispatched
-        // instruction in place of three separate not-taken branches.
-        let has_hooks = enforce_budget || has_profiler || has_tracer;
+        // instruction in place of separate not-taken branches.
+        let has_hooks = enforce_budget || has_profiler || has_opcode_counters || has_tracer;
         // Per-frame dispatch cache. The owning chunk context, the executable
         // function body, and the dense instruction index are invariants of the
         // top frame — they change only when the frame does (call / return /
@@ -217,11 +218,11 @@ impl Interpreter {
             // branch in the default Observe mode).
             self.runtime_budget_stats
                 .record_reductions(instr.reductions());
-            // Budget enforcement, CPU sampling, and step tracing are each
-            // installed between turns, never mid-loop. Testing their union once
-            // keeps the common no-hook instruction at a single not-taken branch
-            // instead of three; the individual tests only run when some hook is
-            // actually present.
+            // Budget enforcement, CPU sampling, opcode counting, and step
+            // tracing are each installed between turns, never mid-loop. Testing
+            // their union once keeps the common no-hook instruction at a single
+            // not-taken branch instead of four; the individual tests only run
+            // when some hook is actually present.
             if has_hooks {
                 if enforce_budget {
                     self.enforce_runtime_budget_checkpoint()?;
@@ -229,6 +230,9 @@ impl Interpreter {
                 if has_profiler && let Some(profiler) = self.cpu_profiler.as_mut() {
                     profiler.maybe_sample(context, stack);
                 }
+                if has_opcode_counters && let Some(counters) = self.opcode_counters.as_mut() {
+                    counters.record(op);
+                }
                 // The body is kept out of line so building the event costs the
                 // hot loop nothing.
                 if has_tracer {
@@ -1157,11 +1161,28 @@ impl Interpreter {
                     self.run_new_object_reg(&mut *stack, top_idx, dst)?;
                     continue;
                 }
+                Op::NewObjectWithCapacity => {
+                    let dst = instr.reg(0);
+                    let property_count = instr.const_word(1);
+                    self.run_new_object_with_capacity_reg(
+                        &mut *stack,
+                        top_idx,
+                        dst,
+                        property_count,
+                    )?;
+                    continue;
+                }
                 Op::NewArray => {
                     let operands = function.operand_view(instr);
                     self.run_new_array_operands(&mut *stack, top_idx, operands)?;
                     continue;
                 }
+                Op::NewArrayWithCapacity => {
+                    let dst = instr.reg(0);
+                    let capacity = instr.const_word(1);
+                    self.run_new_array_with_capacity_reg(&mut *stack, top_idx, dst, capacity)?;
+                    continue;
+                }
                 Op::LoadRegExp => {
                     let dst = instr.reg(0);
                     let idx = instr.const_word(1);
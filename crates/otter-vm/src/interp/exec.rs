@@ -68,6 +68,7 @@ impl Interpreter {
                 args,
                 context: job.context,
                 result_capability: None,
+                async_context: Value::undefined(),
                 kind: MicrotaskKind::FinalizationCallback,
             });
         }
@@ -284,11 +285,23 @@ impl Interpreter {
     /// Invoke one microtask top-level. Builds a fresh frame stack
     /// containing just the task's callee; runs `dispatch_loop`
     /// until it returns. Errors include the snapshot of frames
-    /// the task accumulated when it failed.
+    /// the task accumulated when it failed. The task runs in the
+    /// AsyncContext mapping captured when it was queued.
     pub(crate) fn invoke_microtask(
         &mut self,
         context: &ExecutionContext,
         task: Microtask,
+    ) -> Result<(), RunError> {
+        self.async_context.enter(task.async_context);
+        let result = self.invoke_microtask_in_context(context, task);
+        self.async_context.exit();
+        result
+    }
+
+    fn invoke_microtask_in_context(
+        &mut self,
+        context: &ExecutionContext,
+        task: Microtask,
     ) -> Result<(), RunError> {
         let _window_rollback = self.register_window_rollback();
         // Reaction-mode rejection forwarding (§27.2.1.3.2) reads the
@@ -694,6 +707,7 @@ impl Interpreter {
             args,
             context: Some(context.clone()),
             result_capability: None,
+            async_context: self.async_context.current(),
             kind: microtask::MicrotaskKind::Call,
         });
     }
@@ -853,13 +867,14 @@ impl Interpreter {
 
     /// Drive the dispatch loop, converting convertible `VmError`
     /// variants (TypeMismatch, NotCallable, TemporalDeadZone,
-    /// OutOfMemory, etc.)
+    /// OutOfMemory, StackOverflow, etc.)
     /// into typed `Error` instances that flow through `unwind_throw`
     /// — so user code can `try { … } catch (e) { e instanceof
     /// TypeError }` and observe the same shape it would in any
-    /// spec-conforming engine. Variants that aren't user-recoverable
-    /// (StackOverflow, Interrupted, Uncaught, MissingReturn,
-    /// InvalidOperand) propagate as-is.
+    /// spec-conforming engine. OutOfMemory, StackOverflow, and
+    /// JsonError keep their original variant when nothing catches
+    /// them. Variants that aren't user-recoverable (Interrupted,
+    /// Uncaught, MissingReturn, InvalidOperand) propagate as-is.
     ///
     /// # See also
     /// - <https://tc39.es/ecma262/#sec-error-objects>
@@ -909,7 +924,9 @@ impl Interpreter {
                         {
                             let uncaught = if matches!(
                                 err,
-                                VmError::OutOfMemory { .. } | VmError::JsonError
+                                VmError::OutOfMemory { .. }
+                                    | VmError::StackOverflow { .. }
+                                    | VmError::JsonError
                             ) {
                                 Some(err)
                             } else {
@@ -98,6 +98,20 @@ impl Interpreter {
         self.promise_rejection_hook.clone()
     }
 
+    /// Choose what the checkpoint does with unobserved unhandled rejections.
+    pub fn set_unhandled_rejection_mode(
+        &mut self,
+        mode: crate::promise_rejection::UnhandledRejectionMode,
+    ) {
+        self.unhandled_rejection_mode = mode;
+    }
+
+    /// The configured unhandled-rejection policy.
+    #[must_use]
+    pub fn unhandled_rejection_mode(&self) -> crate::promise_rejection::UnhandledRejectionMode {
+        self.unhandled_rejection_mode
+    }
+
     /// Mutable handle to the timer-callback registry.
     pub fn timer_callbacks_mut(&mut self) -> &mut timers::TimerCallbacks {
         &mut self.timer_callbacks
@@ -122,6 +136,25 @@ impl Interpreter {
         tokens.len()
     }
 
+    /// AsyncContext mapping visible to the running code. Hosts that
+    /// queue their own jobs capture it at scheduling time.
+    #[must_use]
+    pub fn async_context_mapping(&self) -> Value {
+        self.async_context.current()
+    }
+
+    /// Enter a captured AsyncContext mapping before running a host
+    /// job. Every call must be paired with [`Self::exit_async_context`].
+    pub fn enter_async_context(&mut self, mapping: Value) {
+        self.async_context.enter(mapping);
+    }
+
+    /// Restore the mapping saved by the matching
+    /// [`Self::enter_async_context`].
+    pub fn exit_async_context(&mut self) {
+        self.async_context.exit();
+    }
+
     /// Insert a generic persistent root and return its id.
     pub fn persistent_root_insert(&mut self, value: Value) -> persistent_roots::PersistentRootId {
         self.persistent_roots.insert(value)
@@ -378,6 +411,22 @@ impl Interpreter {
         self.cpu_profiler = Some(cpu_profile::CpuProfiler::new(interval));
     }
 
+    /// Enable the VM stack profiler in `mode`, sampling every `interval`
+    /// bytecode ticks.
+    pub fn enable_cpu_profiler_with_mode(&mut self, interval: u64, mode: SamplingMode) {
+        self.cpu_profiler = Some(cpu_profile::CpuProfiler::with_mode(interval, mode));
+    }
+
+    /// Report that this interpreter's thread was parked (blocked in a native
+    /// or host wait) from `started` until now. Hosts that block the VM thread
+    /// outside bytecode dispatch call this when the wait returns so the CPU
+    /// profiler can account for it; a no-op when profiling is off.
+    pub fn record_cpu_park(&mut self, started: std::time::Instant) {
+        if let Some(profiler) = self.cpu_profiler.as_mut() {
+            profiler.record_park(started);
+        }
+    }
+
     /// Disable the VM stack profiler without returning its samples.
     pub fn disable_cpu_profiler(&mut self) {
         self.cpu_profiler = None;
@@ -391,6 +440,28 @@ impl Interpreter {
             .map(cpu_profile::CpuProfiler::finish)
     }
 
+    /// Start counting executed instructions per opcode. Counters already
+    /// enabled keep their counts.
+    pub fn enable_opcode_counters(&mut self) {
+        if self.opcode_counters.is_none() {
+            self.opcode_counters = Some(Box::new(opcode_histogram::OpcodeCounters::new()));
+        }
+    }
+
+    /// Stop counting executed instructions per opcode and drop the counts.
+    pub fn disable_opcode_counters(&mut self) {
+        self.opcode_counters = None;
+    }
+
+    /// Snapshot the per-opcode execution counts, or `None` when counting is
+    /// disabled.
+    #[must_use]
+    pub fn opcode_histogram(&self) -> Option<OpcodeHistogram> {
+        self.opcode_counters
+            .as_ref()
+            .map(|counters| counters.histogram())
+    }
+
     /// Whether a step tracer is installed.
     #[must_use]
     pub fn has_tracer(&self) -> bool {
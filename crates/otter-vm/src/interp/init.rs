@@ -278,10 +278,13 @@ impl Interpreter {
             non_gc_exotic_user_props: std::collections::HashMap::new(),
             persistent_roots: persistent_roots::PersistentRoots::new(),
             console_sink: console::default_console_sink(),
+            console_timers: console::ConsoleTimers::default(),
             timer_scheduler: None,
             host_completion_sink: None,
             promise_rejection_hook: None,
+            unhandled_rejection_mode: crate::promise_rejection::UnhandledRejectionMode::default(),
             timer_callbacks: timers::TimerCallbacks::new(),
+            async_context: async_context::AsyncContextState::new(),
             dynamic_import_loader: None,
             dynamic_import_registry: dynamic_import::DynamicImportRegistry::new(),
             array_iterator_prototype: crate::gc_trace::RootCell::new(None),
@@ -300,6 +303,7 @@ impl Interpreter {
             regex_compile_cache: regexp::RegexCompileCache::default(),
             tracer: None,
             cpu_profiler: None,
+            opcode_counters: None,
         };
         // Cache typed handles for the well-known constructors and
         // prototypes. Subsequent runtime lookups read the slots and
@@ -690,7 +690,13 @@ impl Interpreter {
         let code = if let Some(slot) = self.jit_code.get(&fid) {
             slot.clone()
         } else {
-            if count < Self::JIT_TIER_UP_THRESHOLD {
+            // A `"use optimize"` body skips the warm-up: it compiles on its
+            // first entry instead of waiting to become hot.
+            if count < Self::JIT_TIER_UP_THRESHOLD
+                && !context.exec_function(fid).is_some_and(|function| {
+                    function.optimization_hint == otter_bytecode::OptimizationHint::ForceCompile
+                })
+            {
                 return None;
             }
             let compiled = self.compile_jit_function(context, fid, None);
@@ -17,6 +17,11 @@
 //! exact isolate-epoch dependency snapshot.
 //! Eager direct-target compilation is bounded to one edge level; recursively
 //! compiling an observed call graph is forbidden.
+//! Compilation is synchronous on the entry or back-edge that crosses a
+//! function's hotness threshold. There is no deferred request queue, so
+//! functions compile in the order they become hot; a `"use optimize"` body
+//! counts as hot on its first entry. A `"use noinline"` callee is never baked
+//! into a caller's inline tables.
 #![allow(unused_imports)]
 use crate::*;
 
@@ -981,6 +986,16 @@ impl Interpreter {
                 1,
                 direct_call_outcome,
             );
+            if callee.optimization_hint == otter_bytecode::OptimizationHint::NeverInline {
+                self.record_jit_inline_candidate(
+                    fid,
+                    instruction_pc,
+                    tier,
+                    Some(callee_fid),
+                    Some(jit_debug::JitInlineRejectionReason::NeverInline),
+                );
+                continue;
+            }
             let Some(callee_view) = context.jit_compile_snapshot(callee_fid) else {
                 self.record_jit_inline_candidate(
                     fid,
@@ -1266,6 +1281,7 @@ impl Interpreter {
             || method.contains_direct_eval
             || method.is_derived_constructor
             || method.makes_function
+            || method.optimization_hint == otter_bytecode::OptimizationHint::NeverInline
         {
             return None;
         }
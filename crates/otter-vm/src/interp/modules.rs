@@ -242,6 +242,7 @@ impl Interpreter {
             Some(on_rejected),
             capability,
             Some(context.clone()),
+            self.async_context.current(),
         );
         if let Some(job) = outcome.immediate_job {
             self.microtasks.enqueue(job);
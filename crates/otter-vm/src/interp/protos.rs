@@ -385,16 +385,16 @@ impl Interpreter {
         &self.symbol_registry
     }
 
-    /// Look up or register a symbol for `key`. Splits borrows over the
-    /// registry, the GC heap, and the string heap so callers do not
-    /// need to juggle them manually.
+    /// Look up or register a symbol for the WTF-16 `key`. Splits
+    /// borrows over the registry, the GC heap, and the string heap so
+    /// callers do not need to juggle them manually.
     ///
     /// # Errors
     /// Surfaces [`crate::symbol::SymbolRegistryError`] (string or GC
     /// out-of-memory).
     pub fn symbol_for_key(
         &mut self,
-        key: &str,
+        key: &[u16],
     ) -> Result<JsSymbol, crate::symbol::SymbolRegistryError> {
         self.symbol_registry.for_key(&mut self.gc_heap, key)
     }
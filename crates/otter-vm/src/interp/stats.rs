@@ -34,6 +34,9 @@ impl Interpreter {
         self.runtime_budget_depth = 0;
         self.runtime_budget_turn_started_at = None;
         self.runtime_budget_heap_start = None;
+        if let Some(counters) = self.opcode_counters.as_mut() {
+            counters.reset();
+        }
     }
 
     pub(crate) fn begin_runtime_budget_turn(&mut self) {
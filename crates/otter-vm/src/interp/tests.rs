@@ -53,6 +53,7 @@ fn test_function(
         spans,
         number_hint_sites: Vec::new(),
         class_hint_sites: Vec::new(),
+        optimization_hint: Default::default(),
     }
 }
 
@@ -4452,6 +4453,7 @@ fn unwind_throw_pops_frames_until_handler_or_uncaught() {
         }],
         number_hint_sites: Vec::new(),
         class_hint_sites: Vec::new(),
+        optimization_hint: Default::default(),
     };
     let mut interp = Interpreter::new();
     let mut stack: ActivationStack = ActivationStack::new();
@@ -4521,6 +4523,7 @@ fn unwind_throw_lands_in_catch_handler() {
         }],
         number_hint_sites: Vec::new(),
         class_hint_sites: Vec::new(),
+        optimization_hint: Default::default(),
     };
     let mut interp = Interpreter::new();
     let mut stack: ActivationStack = ActivationStack::new();
@@ -5053,6 +5056,7 @@ fn arrow_closure_overrides_call_site_this() {
         }],
         number_hint_sites: Vec::new(),
         class_hint_sites: Vec::new(),
+        optimization_hint: Default::default(),
     };
     let arrow = Function {
         id: 1,
@@ -5100,6 +5104,7 @@ fn arrow_closure_overrides_call_site_this() {
         }],
         number_hint_sites: Vec::new(),
         class_hint_sites: Vec::new(),
+        optimization_hint: Default::default(),
     };
     let module = BytecodeModule {
         module: "arrow.ts".to_string(),
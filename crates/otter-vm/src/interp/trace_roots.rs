@@ -227,6 +227,13 @@ impl Interpreter {
         &self.rejection_tracker
     }
 
+    /// Borrow the AsyncContext state (current and saved mappings) for
+    /// GC root tracing.
+    #[must_use]
+    pub(crate) fn async_context_for_trace(&self) -> &crate::async_context::AsyncContextState {
+        &self.async_context
+    }
+
     /// Push a value onto the iteration-anchor stack. Returns the
     /// new stack depth so the matching pop can sanity-check.
     pub(crate) fn push_iteration_anchor(&mut self, value: Value) -> usize {
@@ -115,9 +115,10 @@ fn symbol_ctor_call(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, Na
 // ---------------------------------------------------------------
 
 fn symbol_for_call(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, NativeError> {
-    let key = match args.first() {
-        None => "undefined".to_string(),
-        Some(v) if v.is_undefined() => "undefined".to_string(),
+    // Keyed on WTF-16 units so lone surrogates survive `ToString`.
+    let key: Vec<u16> = match args.first() {
+        None => "undefined".encode_utf16().collect(),
+        Some(v) if v.is_undefined() => "undefined".encode_utf16().collect(),
         Some(other) => {
             let context =
                 ctx.execution_context()
@@ -126,8 +127,9 @@ fn symbol_for_call(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, Nat
                         name: "Symbol.for",
                         reason: "missing execution context".to_string(),
                     })?;
-            let result = ctx
-                .with_turn_parts(|interp, stack| interp.coerce_to_string(stack, &context, other));
+            let result = ctx.with_turn_parts(|interp, stack| {
+                crate::coerce::to_js_string_units(interp, stack, Some(&context), other)
+            });
             match result {
                 Ok(s) => s,
                 Err(crate::VmError::TypeError) => {
@@ -179,12 +181,13 @@ fn symbol_key_for_call(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value,
     let key = ctx.interp_mut().symbol_registry().key_for(sym);
     match key {
         Some(key) => {
-            let value = crate::string::JsString::from_str(&key, ctx.heap_mut()).map_err(|_| {
-                NativeError::TypeError {
-                    name: "Symbol.keyFor",
-                    reason: "out of memory".to_string(),
-                }
-            })?;
+            let value =
+                crate::string::JsString::from_utf16_units(&key, ctx.heap_mut()).map_err(|_| {
+                    NativeError::TypeError {
+                        name: "Symbol.keyFor",
+                        reason: "out of memory".to_string(),
+                    }
+                })?;
             Ok(Value::string(value))
         }
         None => Ok(Value::undefined()),
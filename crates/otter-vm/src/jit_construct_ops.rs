@@ -2,8 +2,9 @@
 //!
 //! # Contents
 //! - `CollectRest`, `NewError`, `NewBuiltinError`, `ArrayPush`, `NewWeakRef`,
-//!   `NewFinalizationRegistry`, and `NewCollection` completion through the VM's
-//!   allocating construction helpers.
+//!   `NewFinalizationRegistry`, `NewCollection`, and the pre-sized literal
+//!   allocations `NewObjectWithCapacity` / `NewArrayWithCapacity` completion
+//!   through the VM's allocating construction helpers.
 //!
 //! # Invariants
 //! - No construction semantics are duplicated in JIT code; each opcode calls the
@@ -55,6 +56,17 @@ impl Interpreter {
                     arg2 as u16,
                 )?;
             }
+            value if value == Op::NewObjectWithCapacity as u8 => {
+                self.run_new_object_with_capacity_reg(
+                    stack,
+                    frame_index,
+                    arg0 as u16,
+                    arg1 as u32,
+                )?;
+            }
+            value if value == Op::NewArrayWithCapacity as u8 => {
+                self.run_new_array_with_capacity_reg(stack, frame_index, arg0 as u16, arg1 as u32)?;
+            }
             value if value == Op::ArrayPush as u8 => {
                 self.run_array_push_regs(stack, frame_index, arg0 as u16, arg1 as u16)?;
             }
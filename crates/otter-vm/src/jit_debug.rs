@@ -197,6 +197,9 @@ pub enum JitInlineRejectionReason {
     },
     /// No immutable compile snapshot exists for the candidate.
     MissingSnapshot,
+    /// The callee opted out of inlining with a `"use noinline"` pragma. It is
+    /// still reached through direct-call linkage.
+    NeverInline,
 }
 
 /// Why one observed call target could not bake compiler-generated native
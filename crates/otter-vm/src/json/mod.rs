@@ -68,7 +68,8 @@ otter_macros::holt! {
 /// Hard cap on nesting depth. Both stringify and parse abort with
 /// `JsonError::TooDeep` once exceeded — keeps adversarial input
 /// from blowing the host stack and matches V8's `JSON.stringify`
-/// behaviour for very deep objects.
+/// behaviour for very deep objects: stringify throws
+/// `RangeError: Maximum call stack size exceeded`.
 pub const MAX_NESTING_DEPTH: usize = 1024;
 
 /// Failure modes for [`call`].
@@ -235,6 +236,10 @@ fn vm_to_native_parse(interp: &crate::Interpreter, err: VmError) -> NativeError
                 reason: message.into(),
             }
         }
+        VmError::StackOverflow { .. } => NativeError::RangeError {
+            name: "parse",
+            reason: crate::STACK_OVERFLOW_MESSAGE.to_string(),
+        },
         VmError::Exit { code } => NativeError::Exit { code },
         VmError::TypeError => {
             let message = match interp.take_error_detail() {
@@ -382,8 +387,9 @@ fn native_stringify(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value, Na
 /// Map a serializer [`VmError`] to the native error surface. A user
 /// exception thrown from a getter / `toJSON` / replacer must surface
 /// verbatim (`VmError::Uncaught` → `NativeError::Thrown`) so its
-/// original constructor is observable; only the serializer's own
-/// synthetic failures (cyclic, BigInt, depth) become `TypeError`s.
+/// original constructor is observable; the serializer's own synthetic
+/// failures become `TypeError`s (cyclic, BigInt) or a `RangeError`
+/// (depth, including a `toJSON` that overflows the call stack).
 fn vm_to_native_stringify(interp: &crate::Interpreter, err: VmError) -> NativeError {
     match err {
         VmError::Uncaught => {
@@ -416,6 +422,10 @@ fn vm_to_native_stringify(interp: &crate::Interpreter, err: VmError) -> NativeEr
                 reason: message.into(),
             }
         }
+        VmError::StackOverflow { .. } => NativeError::RangeError {
+            name: "stringify",
+            reason: crate::STACK_OVERFLOW_MESSAGE.to_string(),
+        },
         VmError::Exit { code } => NativeError::Exit { code },
         VmError::TypeError => {
             let message = match interp.take_error_detail() {
@@ -457,14 +467,19 @@ fn native_json_call(
     )
     .map_err(|err| {
         // §25.5.1 step 5 — `JSON.parse` reports malformed input as
-        // `SyntaxError`. Every other failure (cycles, BigInt,
-        // depth overflow, bad arguments, OOM) flows through
-        // `TypeError` per spec.
+        // `SyntaxError`. Depth overflow is a stack overflow and
+        // throws V8's `RangeError`. Every other failure (cycles,
+        // BigInt, bad arguments, OOM) flows through `TypeError` per
+        // spec.
         match err {
             JsonError::ParseFailed { message, position } => NativeError::SyntaxError {
                 name: method.name(),
                 reason: format!("JSON Parse error: {message} at byte {position}"),
             },
+            JsonError::TooDeep { .. } => NativeError::RangeError {
+                name: method.name(),
+                reason: crate::STACK_OVERFLOW_MESSAGE.to_string(),
+            },
             other => NativeError::TypeError {
                 name: method.name(),
                 reason: other.to_string(),
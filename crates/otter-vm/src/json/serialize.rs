@@ -17,8 +17,8 @@
 //! - **Cycle / depth guard.** `state.stack` holds the identity
 //!   pointer of every object/array currently being serialised.
 //!   Revisiting one raises a `TypeError`; exceeding
-//!   [`MAX_NESTING_DEPTH`] does too, which also bounds host-stack
-//!   recursion.
+//!   [`MAX_NESTING_DEPTH`] raises V8's `RangeError: Maximum call stack
+//!   size exceeded`, which also bounds host-stack recursion.
 //! - **Diagnostic parity.** Cyclic and BigInt failures reuse the
 //!   exact messages the heap-only path emits so existing runtime
 //!   assertions keep matching.
@@ -804,8 +804,7 @@ impl Interpreter {
     /// revisits (§25.5.2.4/.5 step 1) and over-deep nesting.
     fn json_enter(&self, state: &mut JsonState, value: &Value) -> Result<(), VmError> {
         if state.stack.len() >= MAX_NESTING_DEPTH {
-            return Err(self
-                .err_type((format!("JSON nesting exceeded {MAX_NESTING_DEPTH} levels.")).into()));
+            return Err(self.err_range(crate::STACK_OVERFLOW_MESSAGE.into()));
         }
         let id = self.json_identity(value);
         if !id.is_null() && state.stack.contains(&id) {
@@ -55,9 +55,11 @@ mod argument_window;
 pub mod arguments_object;
 mod arithmetic_dispatch;
 pub mod array;
+mod array_from_async;
 mod array_ops;
 pub mod array_prototype;
 pub mod array_statics;
+pub(crate) mod async_context;
 mod async_ops;
 pub mod atomics;
 pub mod atomics_wait;
@@ -167,6 +169,7 @@ pub mod number;
 pub mod object;
 mod object_internal_ops;
 pub mod object_statics;
+mod opcode_histogram;
 mod operand_decode;
 pub mod pelt;
 pub mod persistent_roots;
@@ -215,7 +218,7 @@ mod test_support;
 
 pub use active_frame::{ActiveFrameError, ActiveFrameMut, ActiveFrameRef, ActiveFrameStorage};
 pub use arithmetic_dispatch::NumericRuntimeOp;
-pub use cpu_profile::CpuProfile;
+pub use cpu_profile::{CpuProfile, SamplingMode, WALL_CLOCK_MIN_IDLE_PARK};
 pub use execution_context::{CallFeedbackStats, ExecutionContext};
 pub use frame_state::{
     AsyncFrameState, Frame, PendingBindFunction, PendingBindStage, PendingGetIterator,
@@ -226,7 +229,7 @@ pub use jit_runtime_ops::{UnaryCoercionOp, UnaryPrimitiveHint};
 pub use property_ic::PropertyIcStats;
 pub use run_control::{
     DEFAULT_MAX_STACK_DEPTH, DEFAULT_MAX_SYNC_REENTRY_DEPTH, ErrorDetail, InterruptFlag,
-    NO_HANDLER_OFFSET, RunError, StackFrameSnapshot, VmError,
+    NO_HANDLER_OFFSET, RunError, STACK_OVERFLOW_MESSAGE, StackFrameSnapshot, VmError,
 };
 pub use runtime_activation::RuntimeCall;
 
@@ -301,6 +304,7 @@ pub use native_function::{
 };
 pub use number::{NumberValue, NumericOrdering};
 pub use object::JsObject;
+pub use opcode_histogram::OpcodeHistogram;
 pub use persistent_roots::{PersistentRootId, PersistentRoots};
 pub use promise::{
     JsPromise, JsPromiseHandle, PromiseCapability, PromiseReaction, PromiseSettleJobs,
@@ -315,7 +319,7 @@ pub use timers::{TimerCallbacks, TimerEntry, TimerScheduler, TimerSchedulerHandl
 pub use weak_refs::{JsFinalizationRegistry, JsWeakRef};
 
 // Eight-byte tagged value. Canonical `Value` export.
-pub use value::{Value, ValueKind};
+pub use value::{Value, ValueDebug, ValueKind};
 
 /// Opaque stable identity for an additional interpreter realm.
 ///
@@ -1264,6 +1268,8 @@ pub struct Interpreter {
     /// Defaults to `println!` / `eprintln!` via
     /// [`console::StdConsoleSink`].
     console_sink: console::ConsoleSinkHandle,
+    /// Running `console.time` labels for this isolate.
+    console_timers: console::ConsoleTimers,
     /// Host-side timer scheduler. Wired by the runtime layer so
     /// `setTimeout` / `clearTimeout` / `setInterval` /
     /// `clearInterval` natives can talk to the event loop without
@@ -1279,11 +1285,16 @@ pub struct Interpreter {
     /// rejections. Browser and server embedders use this instead of relying on
     /// a magic JavaScript global.
     promise_rejection_hook: Option<promise_rejection::PromiseRejectionHookHandle>,
+    /// Policy for rejections still unhandled at the checkpoint.
+    unhandled_rejection_mode: promise_rejection::UnhandledRejectionMode,
     /// Per-isolate map from host-issued timer token to JS callback +
     /// extra arguments. Populated by `setTimeout` / `setInterval`,
     /// drained by the runtime layer when a `TimerFired` inbox
     /// message arrives.
     timer_callbacks: timers::TimerCallbacks,
+    /// TC39 AsyncContext mapping for the running code, plus the
+    /// mappings saved by nested `run` calls and job entries.
+    async_context: async_context::AsyncContextState,
     /// Host-side dynamic-import scheduler. Wired by the runtime
     /// layer so `Op::ImportNamespaceDynamic` can register a
     /// pending promise and schedule on-demand module loading
@@ -1351,6 +1362,9 @@ pub struct Interpreter {
     /// Optional VM stack sampler used by CLI/debug tooling to emit Chrome
     /// `.cpuprofile` and folded-stack artifacts.
     cpu_profiler: Option<cpu_profile::CpuProfiler>,
+    /// Optional per-opcode execution counters for `OpcodeHistogram`
+    /// snapshots.
+    opcode_counters: Option<Box<opcode_histogram::OpcodeCounters>>,
 }
 
 impl std::fmt::Debug for Interpreter {
@@ -91,6 +91,10 @@ pub struct Microtask {
     /// chain (and a thrown error rejects it). `None` for plain
     /// `queueMicrotask(fn)` callbacks.
     pub result_capability: Option<MicrotaskCapability>,
+    /// AsyncContext mapping captured when the task was scheduled;
+    /// the drain makes it current while the task runs. `undefined`
+    /// is the empty mapping.
+    pub async_context: Value,
     /// What flavour of work this task represents. Defaults to
     /// [`MicrotaskKind::Call`] (the `queueMicrotask(fn, args...)`
     /// shape). `Op::Await` enqueues [`MicrotaskKind::AsyncResume`]
@@ -308,6 +312,7 @@ impl Microtask {
     pub(crate) fn trace_gc_slots(&self, visitor: &mut dyn FnMut(*mut RawGc)) {
         self.callee.trace_value_slots(visitor);
         self.this_value.trace_value_slots(visitor);
+        self.async_context.trace_value_slots(visitor);
         for arg in &self.args {
             arg.trace_value_slots(visitor);
         }
@@ -360,6 +365,7 @@ mod tests {
             args: SmallVec::new(),
             context: None,
             result_capability: None,
+            async_context: Value::undefined(),
             kind: MicrotaskKind::Call,
         }
     }
@@ -781,6 +781,7 @@ impl Interpreter {
             Some(on_rejected),
             capability,
             Some(context.clone()),
+            self.async_context.current(),
         );
         if let Some(job) = outcome.immediate_job {
             self.microtasks.enqueue(job);
@@ -1073,6 +1074,7 @@ impl Interpreter {
             args: smallvec::SmallVec::new(),
             context: Some(context.clone()),
             result_capability: None,
+            async_context: self.async_context.current(),
             kind: crate::microtask::MicrotaskKind::Call,
         });
         Ok(())
@@ -1139,6 +1141,7 @@ impl Interpreter {
             Some(on_rejected),
             capability,
             Some(context.clone()),
+            self.async_context.current(),
         );
         if let Some(job) = outcome.immediate_job {
             self.microtasks.enqueue(job);
@@ -1508,6 +1508,12 @@ pub fn vm_to_native_error(
             }
         }
         crate::VmError::Interrupted => NativeError::Interrupted,
+        // A callback that overflowed the JS or re-entry depth limit stays a
+        // `RangeError` so the catch site sees the same class either way.
+        crate::VmError::StackOverflow { .. } => NativeError::RangeError {
+            name,
+            reason: crate::STACK_OVERFLOW_MESSAGE.to_string(),
+        },
         // Heap exhaustion must keep its identity across the native
         // boundary: a generic `TypeError` fallback would render OOM as
         // an uncatchable-looking type error and lose the host's
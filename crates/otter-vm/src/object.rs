@@ -1574,6 +1574,28 @@ pub(crate) fn initialize_shaped_data_slots(obj: JsObject, heap: &mut GcHeap, val
     }
 }
 
+/// Reserve out-of-line slab storage on a fresh, slotless object for
+/// `property_count` string-keyed properties.
+///
+/// Backs `Op::NewObjectWithCapacity`. Counts that fit the inline slab need no
+/// reservation. The reserved vector stays empty, so the slab remains inline
+/// until the spill past [`INLINE_SLOT_CAP`], which then moves into the
+/// reserved buffer instead of regrowing it property by property.
+pub(crate) fn reserve_string_slots(obj: JsObject, heap: &mut GcHeap, property_count: usize) {
+    if property_count <= INLINE_SLOT_CAP {
+        return;
+    }
+    heap.with_payload(obj, |body| {
+        debug_assert!(
+            body.slab_len == 0,
+            "slot reservation requires a fresh object"
+        );
+        if body.slab_is_inline() {
+            body.values.reserve_exact(property_count);
+        }
+    });
+}
+
 /// Replace the root hidden class on a fresh, slotless object before bulk
 /// constructor initialization.
 pub(crate) fn set_fresh_object_shape(obj: JsObject, heap: &mut GcHeap, shape: ShapeHandle) {
@@ -154,7 +154,9 @@ impl Interpreter {
                 .as_proxy()
                 .ok_or(VmError::TypeMismatch)?;
             if proxy.is_revoked(&interp.gc_heap) {
-                return Err(VmError::TypeMismatch);
+                return Err(interp.err_type(
+                    format!("Cannot perform '{trap}' on a proxy that has been revoked").into(),
+                ));
             }
             let handler_handle = interp.scoped_value(scope, proxy.handler(&interp.gc_heap));
             let trap_key = VmPropertyKey::String(trap);
@@ -0,0 +1,134 @@
+//! Opt-in per-opcode execution counters.
+//!
+//! Aggregate runtime stats say how much bytecode ran, not which opcodes
+//! dominate. When enabled, the dispatch loop bumps one counter per executed
+//! instruction in a dense array indexed by the opcode's wire byte; embedders
+//! read it back as an [`OpcodeHistogram`].
+//!
+//! # Contents
+//! - [`OpcodeCounters`] — the interpreter-owned counter array.
+//! - [`OpcodeHistogram`] — owned snapshot, sorted by descending count.
+//!
+//! # Invariants
+//! - Disabled counters cost only the dispatch loop's shared hook branch.
+//! - Counters are plain `u64`s owned by the interpreter, which never leaves
+//!   its thread mid-run, so bumping needs no atomics or locks.
+//! - Only interpreted instructions are counted; code running in JIT tiers
+//!   does not pass through the dispatch loop.
+//! - `Interpreter::reset_runtime_budget_stats` zeroes the counters along
+//!   with the other per-run counters.
+//!
+//! # See also
+//! - [`otter_bytecode::opcode_schema`] — the opcode ↔ byte mapping the array
+//!   is indexed by.
+
+use otter_bytecode::Op;
+use otter_bytecode::opcode_schema::{OP_BYTE_TABLE, opcode_schema};
+use serde::Serialize;
+
+/// Dense per-opcode execution counters.
+#[derive(Debug)]
+pub(crate) struct OpcodeCounters {
+    counts: Box<[u64]>,
+}
+
+impl OpcodeCounters {
+    /// Create zeroed counters for every opcode.
+    #[must_use]
+    pub(crate) fn new() -> Self {
+        Self {
+            counts: vec![0; OP_BYTE_TABLE.len()].into_boxed_slice(),
+        }
+    }
+
+    /// Count one execution of `op`.
+    #[inline]
+    pub(crate) fn record(&mut self, op: Op) {
+        let slot = &mut self.counts[opcode_schema(op).byte as usize];
+        *slot = slot.wrapping_add(1);
+    }
+
+    /// Zero every counter.
+    pub(crate) fn reset(&mut self) {
+        self.counts.fill(0);
+    }
+
+    /// Snapshot the non-zero counters.
+    #[must_use]
+    pub(crate) fn histogram(&self) -> OpcodeHistogram {
+        let mut entries: Vec<(Op, u64)> = OP_BYTE_TABLE
+            .iter()
+            .zip(self.counts.iter())
+            .filter(|(_, count)| **count > 0)
+            .map(|((op, _), count)| (*op, *count))
+            .collect();
+        // Ties break on the wire byte so snapshots are deterministic.
+        entries.sort_by(|(a_op, a), (b_op, b)| {
+            b.cmp(a)
+                .then_with(|| opcode_schema(*a_op).byte.cmp(&opcode_schema(*b_op).byte))
+        });
+        OpcodeHistogram { entries }
+    }
+}
+
+/// Per-opcode execution counts, most frequent first.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct OpcodeHistogram {
+    entries: Vec<(Op, u64)>,
+}
+
+impl OpcodeHistogram {
+    /// `(opcode, executions)` pairs sorted by descending count. Opcodes that
+    /// never ran are omitted.
+    #[must_use]
+    pub fn entries(&self) -> &[(Op, u64)] {
+        &self.entries
+    }
+
+    /// Executions of `op`.
+    #[must_use]
+    pub fn count(&self, op: Op) -> u64 {
+        self.entries
+            .iter()
+            .find(|(candidate, _)| *candidate == op)
+            .map_or(0, |(_, count)| *count)
+    }
+
+    /// Total counted instructions.
+    #[must_use]
+    pub fn total(&self) -> u64 {
+        self.entries.iter().map(|(_, count)| count).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn histogram_sorts_descending_and_omits_zero_counts() {
+        let mut counters = OpcodeCounters::new();
+        for _ in 0..3 {
+            counters.record(Op::LoadUndefined);
+        }
+        counters.record(Op::Nop);
+        counters.record(Op::ReturnValue);
+        counters.record(Op::ReturnValue);
+
+        let histogram = counters.histogram();
+        assert_eq!(
+            histogram.entries(),
+            &[(Op::LoadUndefined, 3), (Op::ReturnValue, 2), (Op::Nop, 1)]
+        );
+        assert_eq!(histogram.total(), 6);
+        assert_eq!(histogram.count(Op::Call), 0);
+    }
+
+    #[test]
+    fn reset_zeroes_every_counter() {
+        let mut counters = OpcodeCounters::new();
+        counters.record(Op::Nop);
+        counters.reset();
+        assert!(counters.histogram().entries().is_empty());
+    }
+}
@@ -98,6 +98,10 @@ pub struct PromiseReaction {
     /// time so host-driven settlement (e.g. cross-thread promise
     /// resolution) can resume on the right module.
     pub context: Option<ExecutionContext>,
+    /// AsyncContext mapping current when the reaction was registered.
+    /// The reaction job runs in it, so `await` and `then` callbacks
+    /// observe the bindings of the code that registered them.
+    pub async_context: Value,
 }
 
 impl crate::pelt::PeltField for PromiseReaction {
@@ -106,6 +110,7 @@ impl crate::pelt::PeltField for PromiseReaction {
         self.capability.resolve.trace_value_slot_mut(visitor);
         self.capability.reject.trace_value_slot_mut(visitor);
         self.handler.trace_value_slots_mut(visitor);
+        self.async_context.trace_value_slot_mut(visitor);
     }
 }
 
@@ -113,6 +118,7 @@ impl otter_gc::GcStore for PromiseReaction {
     fn visit_gc_edges(&self, visitor: &mut dyn FnMut(otter_gc::GcEdge)) {
         self.capability.visit_gc_edges(visitor);
         self.handler.visit_gc_edges(visitor);
+        self.async_context.visit_gc_edges(visitor);
     }
 }
 
@@ -288,7 +294,8 @@ pub trait JsPromise: std::fmt::Debug {
     ) -> PromiseThenOutcome;
 
     /// `PerformPromiseThen` with explicit context ownership for the
-    /// queued reaction job.
+    /// queued reaction job. `async_context` is the AsyncContext
+    /// mapping the reaction job runs in.
     fn perform_then_with_context(
         &self,
         heap: &mut otter_gc::GcHeap,
@@ -296,6 +303,7 @@ pub trait JsPromise: std::fmt::Debug {
         on_rejected: Option<Value>,
         capability: PromiseCapability,
         context: Option<ExecutionContext>,
+        async_context: Value,
     ) -> PromiseThenOutcome;
 
     /// `true` once any reaction has been attached.
@@ -510,6 +518,7 @@ impl PurePromise {
     }
 
     /// Attach explicit parked-frame reactions for `await`.
+    #[allow(clippy::too_many_arguments)]
     pub fn perform_async_resume_then(
         &self,
         heap: &mut otter_gc::GcHeap,
@@ -518,23 +527,25 @@ impl PurePromise {
         capability: PromiseCapability,
         owner: Option<crate::generator::JsGenerator>,
         context: Option<ExecutionContext>,
+        async_context: Value,
     ) -> PromiseThenOutcome {
-        let outcome = self.perform_then_internal(heap, capability, context, |kind| {
-            let fulfilled = kind == ReactionKind::Fulfill;
-            match owner {
-                Some(owner) => PromiseReactionHandler::AsyncGenResume {
-                    parked,
-                    await_dst,
-                    owner,
-                    fulfilled,
-                },
-                None => PromiseReactionHandler::AsyncResume {
-                    parked,
-                    await_dst,
-                    fulfilled,
-                },
-            }
-        });
+        let outcome =
+            self.perform_then_internal(heap, capability, context, async_context, |kind| {
+                let fulfilled = kind == ReactionKind::Fulfill;
+                match owner {
+                    Some(owner) => PromiseReactionHandler::AsyncGenResume {
+                        parked,
+                        await_dst,
+                        owner,
+                        fulfilled,
+                    },
+                    None => PromiseReactionHandler::AsyncResume {
+                        parked,
+                        await_dst,
+                        fulfilled,
+                    },
+                }
+            });
         self.finish_then_outcome(heap, outcome)
     }
 
@@ -543,6 +554,7 @@ impl PurePromise {
         heap: &mut otter_gc::GcHeap,
         capability: PromiseCapability,
         context: Option<ExecutionContext>,
+        async_context: Value,
         mut handler_for: impl FnMut(ReactionKind) -> PromiseReactionHandler,
     ) -> ThenOutcomeInternal {
         let reaction_context = context.or_else(|| capability.context.clone());
@@ -555,12 +567,14 @@ impl PurePromise {
                         handler: handler_for(ReactionKind::Fulfill),
                         kind: ReactionKind::Fulfill,
                         context: reaction_context.clone(),
+                        async_context,
                     };
                     let reject = PromiseReaction {
                         capability,
                         handler: handler_for(ReactionKind::Reject),
                         kind: ReactionKind::Reject,
                         context: reaction_context,
+                        async_context,
                     };
                     body.fulfill_reactions.push(fulfill.clone());
                     body.reject_reactions.push(reject.clone());
@@ -575,6 +589,7 @@ impl PurePromise {
                         handler: handler_for(ReactionKind::Fulfill),
                         kind: ReactionKind::Fulfill,
                         context: reaction_context,
+                        async_context,
                     };
                     ThenOutcomeInternal {
                         immediate_reaction: Some((reaction, value)),
@@ -587,6 +602,7 @@ impl PurePromise {
                         handler: handler_for(ReactionKind::Reject),
                         kind: ReactionKind::Reject,
                         context: reaction_context,
+                        async_context,
                     };
                     ThenOutcomeInternal {
                         immediate_reaction: Some((reaction, reason)),
@@ -673,7 +689,14 @@ impl JsPromise for PurePromise {
         on_rejected: Option<Value>,
         capability: PromiseCapability,
     ) -> PromiseThenOutcome {
-        self.perform_then_with_context(heap, on_fulfilled, on_rejected, capability, None)
+        self.perform_then_with_context(
+            heap,
+            on_fulfilled,
+            on_rejected,
+            capability,
+            None,
+            Value::undefined(),
+        )
     }
 
     fn perform_then_with_context(
@@ -683,11 +706,18 @@ impl JsPromise for PurePromise {
         on_rejected: Option<Value>,
         capability: PromiseCapability,
         context: Option<ExecutionContext>,
+        async_context: Value,
     ) -> PromiseThenOutcome {
-        let outcome = self.perform_then_internal(heap, capability, context, |kind| match kind {
-            ReactionKind::Fulfill => PromiseReactionHandler::Call(on_fulfilled),
-            ReactionKind::Reject => PromiseReactionHandler::Call(on_rejected),
-        });
+        let outcome = self.perform_then_internal(
+            heap,
+            capability,
+            context,
+            async_context,
+            |kind| match kind {
+                ReactionKind::Fulfill => PromiseReactionHandler::Call(on_fulfilled),
+                ReactionKind::Reject => PromiseReactionHandler::Call(on_rejected),
+            },
+        );
         self.finish_then_outcome(heap, outcome)
     }
 
@@ -869,12 +899,20 @@ impl JsPromiseHandle {
         owner: Option<crate::generator::JsGenerator>,
     ) -> PromiseThenOutcome {
         self.perform_async_resume_then_with_context(
-            heap, parked, await_dst, capability, owner, None,
+            heap,
+            parked,
+            await_dst,
+            capability,
+            owner,
+            None,
+            Value::undefined(),
         )
     }
 
     /// Attach parked-frame reactions for `await` with explicit
-    /// context ownership.
+    /// context ownership. The frame resumes in `async_context`, the
+    /// AsyncContext mapping current at the `await`.
+    #[allow(clippy::too_many_arguments)]
     pub fn perform_async_resume_then_with_context(
         &self,
         heap: &mut otter_gc::GcHeap,
@@ -883,11 +921,18 @@ impl JsPromiseHandle {
         capability: PromiseCapability,
         owner: Option<crate::generator::JsGenerator>,
         context: Option<ExecutionContext>,
+        async_context: Value,
     ) -> PromiseThenOutcome {
         match self.inner {
-            PromiseRepr::Pure(p) => {
-                p.perform_async_resume_then(heap, parked, await_dst, capability, owner, context)
-            }
+            PromiseRepr::Pure(p) => p.perform_async_resume_then(
+                heap,
+                parked,
+                await_dst,
+                capability,
+                owner,
+                context,
+                async_context,
+            ),
         }
     }
 }
@@ -930,11 +975,17 @@ impl JsPromise for JsPromiseHandle {
         on_rejected: Option<Value>,
         capability: PromiseCapability,
         context: Option<ExecutionContext>,
+        async_context: Value,
     ) -> PromiseThenOutcome {
         match self.inner {
-            PromiseRepr::Pure(p) => {
-                p.perform_then_with_context(heap, on_fulfilled, on_rejected, capability, context)
-            }
+            PromiseRepr::Pure(p) => p.perform_then_with_context(
+                heap,
+                on_fulfilled,
+                on_rejected,
+                capability,
+                context,
+                async_context,
+            ),
         }
     }
 
@@ -1002,6 +1053,7 @@ pub fn new_promise_reaction_job(
                 this_value: Value::undefined(),
                 args: smallvec![value],
                 context: reaction.context,
+                async_context: reaction.async_context,
                 result_capability,
                 kind: MicrotaskKind::Call,
             })
@@ -1017,6 +1069,7 @@ pub fn new_promise_reaction_job(
                 this_value: Value::undefined(),
                 args: smallvec![value],
                 context: reaction.context,
+                async_context: reaction.async_context,
                 result_capability: None,
                 kind: MicrotaskKind::AsyncResume {
                     frame,
@@ -1038,6 +1091,7 @@ pub fn new_promise_reaction_job(
                 this_value: Value::undefined(),
                 args: smallvec![value],
                 context: reaction.context,
+                async_context: reaction.async_context,
                 result_capability: None,
                 kind: MicrotaskKind::AsyncGenResume {
                     frame,
@@ -3047,12 +3047,14 @@ fn method_then(
         let on_fulfilled = on_fulfilled.map(|value| interp.escape_scoped(value));
         let on_rejected = on_rejected.map(|value| interp.escape_scoped(value));
         let capability = capability_handles.current(interp, context.clone());
+        let async_context = interp.async_context.current();
         let outcome = promise.perform_then_with_context(
             interp.gc_heap_mut(),
             on_fulfilled,
             on_rejected,
             capability,
             context.clone(),
+            async_context,
         );
         if let Some(job) = outcome.immediate_job {
             interp.microtasks_mut().enqueue(job);
@@ -3101,12 +3103,14 @@ fn perform_then_with_handlers(
         let on_fulfilled = on_fulfilled.map(|value| interp.escape_scoped(value));
         let on_rejected = on_rejected.map(|value| interp.escape_scoped(value));
         let capability = capability_handles.current(interp, context.clone());
+        let async_context = interp.async_context.current();
         let outcome: PromiseThenOutcome = promise.perform_then_with_context(
             interp.gc_heap_mut(),
             on_fulfilled,
             on_rejected,
             capability,
             context.clone(),
+            async_context,
         );
         if let Some(job) = outcome.immediate_job {
             interp.microtasks_mut().enqueue(job);
@@ -3143,12 +3147,14 @@ fn attach_then(
         let on_fulfilled = on_fulfilled.map(|value| interp.escape_scoped(value));
         let on_rejected = on_rejected.map(|value| interp.escape_scoped(value));
         let capability = capability_handles.current(interp, context.clone());
+        let async_context = interp.async_context.current();
         let outcome = promise.perform_then_with_context(
             interp.gc_heap_mut(),
             on_fulfilled,
             on_rejected,
             capability,
             context,
+            async_context,
         );
         if let Some(job) = outcome.immediate_job {
             interp.microtasks_mut().enqueue(job);
@@ -3156,6 +3162,52 @@ fn attach_then(
     });
 }
 
+/// `Await(value)` for builtins specified as async abstract closures
+/// (`Array.fromAsync`): `PerformPromiseThen(? PromiseResolve(%Promise%,
+/// value), onFulfilled, onRejected)`.
+///
+/// The reactions run on a later microtask under `ctx`'s execution
+/// context, so a native continuation can keep calling into JS. The
+/// caller keeps any loop state in the reactions' traced captures.
+///
+/// # See also
+/// - <https://tc39.es/ecma262/#await>
+pub(crate) fn await_native(
+    ctx: &mut NativeCtx<'_>,
+    value: Value,
+    on_fulfilled: Value,
+    on_rejected: Value,
+) -> Result<(), NativeError> {
+    let context = ctx.execution_context().cloned();
+    ctx.scope(|mut scope| {
+        let value = scope.value(value);
+        let on_fulfilled = scope.value(on_fulfilled);
+        let on_rejected = scope.value(on_rejected);
+        let value = scope.raw(value);
+        let resolve_context = context.clone();
+        let promise = scope.with_turn_parts(|interp, stack| {
+            let constructor = builtin_promise_constructor(interp)?;
+            static_resolve(interp, stack, resolve_context, constructor, &[value])
+        })?;
+        let promise = promise.as_promise().ok_or_else(|| NativeError::TypeError {
+            name: "Await",
+            reason: "PromiseResolve did not produce a promise".to_string(),
+        })?;
+        let on_fulfilled = scope.raw(on_fulfilled);
+        let on_rejected = scope.raw(on_rejected);
+        scope.with_turn_parts(|interp, _| {
+            attach_then(
+                interp,
+                context,
+                &promise,
+                Some(on_fulfilled),
+                Some(on_rejected),
+            );
+        });
+        Ok(())
+    })
+}
+
 /// Read the settled promise handle from a settle-native's GC-traced captures.
 ///
 /// The promise is stored as `captures[0]` (a `Value::promise`) so the moving
@@ -3360,18 +3412,17 @@ fn resolve_native_body(
                 )?;
                 let job = scope.value(job);
                 let job = scope.raw(job);
-                scope
-                    .context()
-                    .interp_mut()
-                    .microtasks_mut()
-                    .enqueue(crate::Microtask {
-                        callee: job,
-                        this_value: Value::undefined(),
-                        args: SmallVec::new(),
-                        context: Some(exec),
-                        result_capability: None,
-                        kind: crate::microtask::MicrotaskKind::Call,
-                    });
+                let interp = scope.context().interp_mut();
+                let async_context = interp.async_context.current();
+                interp.microtasks_mut().enqueue(crate::Microtask {
+                    callee: job,
+                    this_value: Value::undefined(),
+                    args: SmallVec::new(),
+                    context: Some(exec),
+                    result_capability: None,
+                    async_context,
+                    kind: crate::microtask::MicrotaskKind::Call,
+                });
                 return Ok(Value::undefined());
             }
         }
@@ -67,6 +67,7 @@ impl Interpreter {
             args,
             context: Some(context.clone()),
             result_capability: None,
+            async_context: self.async_context.current(),
             kind: microtask::MicrotaskKind::Call,
         });
         Ok(())
@@ -8,6 +8,8 @@
 //!   `rejectionhandled`).
 //! - [`PromiseRejectionHook`] — the embedder-owned callback used by browser
 //!   hosts to materialize rejection events on the isolate thread.
+//! - [`UnhandledRejectionMode`] — the embedder's policy for rejections nothing
+//!   handles (Node's `--unhandled-rejections`).
 //! - [`Interpreter::run_promise_rejection_checkpoint`] — run once each time the
 //!   microtask queue drains empty. Re-reads each tracked promise's live
 //!   `[[PromiseIsHandled]]` and dispatches through the Rust hook or JS reporter.
@@ -25,11 +27,16 @@
 //!   handle would otherwise be reclaimed while the reason is still pending
 //!   report.
 //! - Firing is a no-op (and both lists are cleared) when neither a Rust hook nor
-//!   a JS reporter is installed — a bare VM realm has no event target, so
+//!   a JS reporter is installed and the mode is
+//!   [`UnhandledRejectionMode::None`] — a bare VM realm has no event target, so
 //!   accumulating handles there would leak.
+//! - [`UnhandledRejectionMode::Throw`] raises unless the notification was
+//!   handled: a Rust hook always handles it (like a Node `unhandledRejection`
+//!   listener), the JS reporter only when its `unhandledrejection` event was
+//!   canceled. [`UnhandledRejectionMode::Strict`] raises before notifying.
 //!
 //! # See also
-//! `crates/otter-web/src/web_bootstrap.js` (`__otterFirePromiseRejection`) — the
+//! `crates/otter-web/src/globals.rs` (`__otterFirePromiseRejection`) — the
 //! reporter that builds the `PromiseRejectionEvent`, invokes `globalThis.on*`,
 //! and falls back to `reportError`.
 use std::sync::Arc;
@@ -84,8 +91,33 @@ impl std::fmt::Debug for PromiseRejectionHookHandle {
     }
 }
 
+/// What the checkpoint does with a rejection that is still unhandled.
+///
+/// Mirrors Node's `--unhandled-rejections=strict|throw|warn|none`. An
+/// installed [`PromiseRejectionHook`] or JS reporter is notified in every mode
+/// except `Strict`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnhandledRejectionMode {
+    /// Surface the rejection reason as an uncaught exception from the drain
+    /// without notifying a hook or reporter.
+    Strict,
+    /// Surface the rejection reason as an uncaught exception from the drain,
+    /// unless a hook observes it or the reporter's `unhandledrejection` event
+    /// is canceled.
+    Throw,
+    /// Write a warning through the console sink, and a second one if a
+    /// handler is attached later.
+    Warn,
+    /// Report only to an installed hook or reporter.
+    #[default]
+    None,
+}
+
 /// Global name of the JS reporter the web layer installs. The VM invokes it at
-/// the checkpoint with `(promise, reason, wasHandled)`.
+/// the checkpoint with `(promise, reason, wasHandled, willThrow)`; it returns
+/// `true` when the `unhandledrejection` event was canceled. `willThrow` tells
+/// it the VM raises an uncanceled rejection itself, so it must not also report
+/// it.
 const REPORTER_GLOBAL: &str = "__otterFirePromiseRejection";
 
 /// The HTML "about-to-be-notified rejected promises" and
@@ -164,11 +196,13 @@ impl Interpreter {
         context: &ExecutionContext,
     ) -> Result<(), RunError> {
         // A Rust hook takes precedence over the compatibility JS reporter.
-        // With neither installed there is no host to deliver to, so drop the
-        // tracked handles rather than leak.
+        // With neither installed and a silent mode there is nothing to
+        // deliver to, so drop the tracked handles rather than leak.
         let has_hook = self.promise_rejection_hook().is_some();
         let reporter = crate::object::get(self.global_this, &self.gc_heap, REPORTER_GLOBAL);
-        if !has_hook && !reporter.is_some_and(|r| r.is_callable()) {
+        let has_observer = has_hook || reporter.is_some_and(|r| r.is_callable());
+        let mode = self.unhandled_rejection_mode;
+        if !has_observer && mode == UnhandledRejectionMode::None {
             self.rejection_tracker.clear();
             return Ok(());
         }
@@ -183,10 +217,24 @@ impl Interpreter {
                 continue;
             }
             self.rejection_tracker.pending.swap_remove(idx);
+            if mode == UnhandledRejectionMode::Strict {
+                return Err(self.unhandled_rejection_error(promise));
+            }
+            if mode == UnhandledRejectionMode::Warn {
+                self.warn_promise_rejection(promise, "Unhandled promise rejection");
+            }
             // Retain in `notified` (a GC root) before firing so the handle
             // survives any collection the reporter triggers.
+            let slot = self.rejection_tracker.notified.len();
             self.rejection_tracker.notified.push(promise);
-            self.fire_promise_rejection(context, promise, false);
+            let throws = mode == UnhandledRejectionMode::Throw;
+            let handled = self.fire_promise_rejection(context, promise, false, throws);
+            if throws
+                && !handled
+                && let Some(&promise) = self.rejection_tracker.notified.get(slot)
+            {
+                return Err(self.unhandled_rejection_error(promise));
+            }
         }
 
         // Notified → handled. A late `.then`/`.catch` flips the live flag.
@@ -195,7 +243,13 @@ impl Interpreter {
             let promise = self.rejection_tracker.notified[jdx];
             if promise.is_handled(&self.gc_heap) {
                 self.rejection_tracker.notified.swap_remove(jdx);
-                self.fire_promise_rejection(context, promise, true);
+                if mode == UnhandledRejectionMode::Warn {
+                    self.warn_promise_rejection(
+                        promise,
+                        "Promise rejection was handled asynchronously",
+                    );
+                }
+                self.fire_promise_rejection(context, promise, true, false);
                 continue;
             }
             jdx += 1;
@@ -203,20 +257,54 @@ impl Interpreter {
         Ok(())
     }
 
-    /// Invoke the JS reporter for one promise. `handled` selects the event type
-    /// (`rejectionhandled` vs `unhandledrejection`). Reporter errors are
-    /// swallowed — a rejection notification must never abort the drain.
+    /// [`UnhandledRejectionMode::Throw`] / [`UnhandledRejectionMode::Strict`]:
+    /// stop tracking and surface `promise`'s reason as an uncaught exception.
+    /// The reason is parked as the pending uncaught throw so the host can
+    /// recover its `cause` chain.
+    fn unhandled_rejection_error(&mut self, promise: crate::promise::JsPromiseHandle) -> RunError {
+        self.rejection_tracker.clear();
+        let reason = match promise.state(&self.gc_heap) {
+            crate::promise::PromiseState::Rejected(reason) => reason,
+            _ => Value::undefined(),
+        };
+        self.pending_uncaught_throw = Some(reason);
+        let error = self.err_uncaught(self.render_thrown(&reason).into());
+        RunError {
+            error,
+            frames: Vec::new(),
+            detail: self.take_error_detail(),
+        }
+    }
+
+    /// [`UnhandledRejectionMode::Warn`]: write `Warning: <what>: <reason>` at
+    /// warn level.
+    fn warn_promise_rejection(&self, promise: crate::promise::JsPromiseHandle, what: &str) {
+        let crate::promise::PromiseState::Rejected(reason) = promise.state(&self.gc_heap) else {
+            return;
+        };
+        let line = format!("Warning: {what}: {}", self.render_thrown(&reason));
+        self.console_sink()
+            .write(crate::console::ConsoleLevel::Warn, &[line]);
+    }
+
+    /// Notify the Rust hook or JS reporter about one promise. `handled`
+    /// selects the event type (`rejectionhandled` vs `unhandledrejection`);
+    /// `will_throw` is forwarded to the reporter. Returns whether the
+    /// notification was handled: always with a hook, and with the reporter
+    /// only when it reports a canceled event. Observer errors are swallowed —
+    /// a rejection notification must never abort the drain.
     fn fire_promise_rejection(
         &mut self,
         context: &ExecutionContext,
         promise: crate::promise::JsPromiseHandle,
         handled: bool,
-    ) {
+        will_throw: bool,
+    ) -> bool {
         let reason = match promise.state(&self.gc_heap) {
             crate::promise::PromiseState::Rejected(reason) => reason,
             // Only rejected promises are tracked; a settled-elsewhere handle is
             // stale bookkeeping, skip it.
-            _ => return,
+            _ => return true,
         };
         let promise_value = Value::promise(promise);
         if let Some(hook) = self.promise_rejection_hook() {
@@ -226,21 +314,26 @@ impl Interpreter {
                 Some(context),
                 |ctx| hook.notify(ctx, promise_value, reason, handled),
             );
-            return;
+            return true;
         }
 
         // Re-fetch per call: the reporter Value is not rooted across the
         // reentrant dispatch a previous fire may have moved it through.
         let Some(reporter) = crate::object::get(self.global_this, &self.gc_heap, REPORTER_GLOBAL)
         else {
-            return;
+            return false;
         };
         if !reporter.is_callable() {
-            return;
+            return false;
         }
         let this = Value::object(self.global_this);
-        let args: smallvec::SmallVec<[Value; 8]> =
-            smallvec::smallvec![promise_value, reason, Value::boolean(handled)];
-        let _ = self.run_callable_sync(context, &reporter, this, args);
+        let args: smallvec::SmallVec<[Value; 8]> = smallvec::smallvec![
+            promise_value,
+            reason,
+            Value::boolean(handled),
+            Value::boolean(will_throw)
+        ];
+        self.run_callable_sync(context, &reporter, this, args)
+            .is_ok_and(|result| result.as_boolean() == Some(true))
     }
 }
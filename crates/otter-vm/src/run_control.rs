@@ -192,9 +192,10 @@ pub enum VmError {
     /// more than once. §13.3.7.3 / §10.2.2 — a `ReferenceError`. Detail in
     /// [`ErrorDetail::Message`].
     ThisUninitialized,
-    /// JS call-stack depth exceeded the configured limit. Catchable
-    /// per foundation plan §M7 ("stack-depth limit returns a
-    /// catchable JS error").
+    /// JS call-stack depth or native re-entry depth exceeded the
+    /// configured limit. Catchable per foundation plan §M7
+    /// ("stack-depth limit returns a catchable JS error") as a
+    /// `RangeError` carrying [`STACK_OVERFLOW_MESSAGE`].
     StackOverflow {
         /// Maximum depth that was about to be exceeded.
         limit: u32,
@@ -305,6 +306,10 @@ pub const DEFAULT_MAX_STACK_DEPTH: u32 = 1024;
 /// Default synchronous re-entry limit for host-driven JS callbacks.
 pub const DEFAULT_MAX_SYNC_REENTRY_DEPTH: u32 = 256;
 
+/// Message of the `RangeError` a caught [`VmError::StackOverflow`] becomes.
+/// Matches V8 so scripts that sniff the text keep working.
+pub const STACK_OVERFLOW_MESSAGE: &str = "Maximum call stack size exceeded";
+
 /// Re-export of the bytecode-defined sentinel for "this try block
 /// has no catch / finally clause". Kept on the VM surface so
 /// embedders that want to hand-build EnterTry operands have one
@@ -1622,12 +1622,14 @@ impl<'rt> NativeCtx<'rt> {
                 name: "NativeCtx::queue_microtask",
                 reason: "missing execution context".to_string(),
             })?;
+        let async_context = self.cx.interp.async_context.current();
         self.cx.interp.microtasks_mut().enqueue(crate::Microtask {
             callee,
             this_value: Value::undefined(),
             args: args.into_iter().collect(),
             context: Some(context),
             result_capability: None,
+            async_context,
             kind: crate::microtask::MicrotaskKind::Call,
         });
         Ok(())
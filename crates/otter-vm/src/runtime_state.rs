@@ -219,6 +219,9 @@ impl<'a> RuntimeState<'a> {
         // 8c) Promise-rejection tracker: rejected promises awaiting the
         //     unhandled-rejection checkpoint are roots until reported.
         interp.rejection_tracker_for_trace().trace(visitor);
+        // 8d) AsyncContext mappings: the current one and those saved by
+        //     nested `run` / job entries.
+        interp.async_context_for_trace().trace_gc_slots(visitor);
         // 9) Active call frames are NOT enumerated here. The
         //    frame stack lives on the call stack of
         //    `Interpreter::run_inner` (`ActivationStack`),
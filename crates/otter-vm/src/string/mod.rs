@@ -37,6 +37,7 @@
 //! - [`prototype`] — `String.prototype.*` intrinsic implementations.
 //! - [`statics`] — JS-visible static method specs installed on the
 //!   `String` constructor object (`fromCharCode`, `fromCodePoint`).
+//! - [`utf8`] — shared UTF-8 byte decoding for host text decoders.
 
 pub mod dispatch;
 pub(crate) mod exotic;
@@ -45,6 +46,7 @@ pub mod intrinsic;
 pub mod ops;
 pub mod prototype;
 pub mod statics;
+pub mod utf8;
 
 use otter_gc::{GcHeap, OutOfMemory};
 
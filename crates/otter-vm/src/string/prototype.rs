@@ -2613,13 +2613,10 @@ fn impl_locale_compare(
     Ok(Value::number(crate::number::NumberValue::from_i32(cmp)))
 }
 
-/// §22.1.3.13 String.prototype.normalize(form?). Foundation accepts
-/// `"NFC"` / `"NFD"` / `"NFKC"` / `"NFKD"` (default `"NFC"`) and
-/// returns the receiver string itself — the foundation slice does
-/// not perform full Unicode normalisation but mirrors the spec
-/// surface so call sites that depend on the method existing keep
-/// working. Real normalisation lands alongside the ICU integration
-/// follow-up.
+/// §22.1.3.13 String.prototype.normalize(form?). Accepts `"NFC"` /
+/// `"NFD"` / `"NFKC"` / `"NFKD"` (default `"NFC"`), throws `RangeError`
+/// for any other form, and normalises through `icu_normalizer`'s
+/// compiled data directly over the UTF-16 code units.
 fn impl_normalize(
     ctx: &mut NativeCtx<'_>,
     receiver: &Value,
@@ -0,0 +1,141 @@
+//! Shared UTF-8 → string decoding for host byte decoders.
+//!
+//! `TextDecoder`, Node's `StringDecoder`, and `Buffer#toString('utf8')` all
+//! decode untrusted bytes. They route through [`decode_utf8`] so the
+//! validation rules and the replacement policy live in one place.
+//!
+//! # Contents
+//! - [`decode_utf8`] — validate and decode one chunk, with `fatal` and
+//!   `stream` modes.
+//! - [`Utf8Decoded`] / [`InvalidUtf8`] — decode result and fatal error.
+//!
+//! # Invariants
+//! - Validation is `core::str::from_utf8` for the all-valid fast path, which
+//!   skips ASCII runs a word at a time, and `<[u8]>::utf8_chunks` once an
+//!   error is found; both are safe code.
+//! - Overlong encodings, UTF-16 surrogate code points (`ED A0..BF`), and
+//!   scalars past U+10FFFF are invalid.
+//! - Each maximal invalid subpart becomes exactly one U+FFFD, matching
+//!   Encoding § UTF-8 decoder and Unicode's "best practice" substitution.
+//!
+//! # See also
+//! - <https://encoding.spec.whatwg.org/#utf-8-decoder>
+
+/// Decoded text plus how many input bytes it consumed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Utf8Decoded {
+    /// Decoded text.
+    pub text: String,
+    /// Bytes consumed. Smaller than the input only in `stream` mode, where
+    /// an incomplete trailing sequence is left for the next chunk.
+    pub consumed: usize,
+}
+
+/// A `fatal` decode met an invalid sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidUtf8 {
+    /// Byte offset of the first invalid sequence.
+    pub offset: usize,
+}
+
+/// Decode `bytes` as UTF-8.
+///
+/// With `fatal`, the first invalid sequence fails the whole decode;
+/// otherwise it is replaced by U+FFFD. With `stream`, a sequence cut off by
+/// the end of `bytes` is not consumed so the caller can prepend it to the
+/// next chunk; without it, the cut-off tail decodes to a single U+FFFD.
+///
+/// # Errors
+/// Returns [`InvalidUtf8`] when `fatal` is set and `bytes` holds an invalid
+/// sequence (including a cut-off tail outside `stream` mode).
+pub fn decode_utf8(bytes: &[u8], fatal: bool, stream: bool) -> Result<Utf8Decoded, InvalidUtf8> {
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        return Ok(Utf8Decoded {
+            text: text.to_owned(),
+            consumed: bytes.len(),
+        });
+    }
+    let mut text = String::with_capacity(bytes.len());
+    let mut at = 0;
+    for chunk in bytes.utf8_chunks() {
+        text.push_str(chunk.valid());
+        at += chunk.valid().len();
+        let invalid = chunk.invalid();
+        if invalid.is_empty() {
+            continue;
+        }
+        // Only the last chunk can hold a sequence cut off by the end of
+        // `bytes`; re-validating its few bytes tells it from a bad sequence.
+        let cut_off = at + invalid.len() == bytes.len()
+            && std::str::from_utf8(invalid).is_err_and(|error| error.error_len().is_none());
+        if cut_off && stream {
+            return Ok(Utf8Decoded { text, consumed: at });
+        }
+        if fatal {
+            return Err(InvalidUtf8 { offset: at });
+        }
+        text.push(char::REPLACEMENT_CHARACTER);
+        at += invalid.len();
+    }
+    Ok(Utf8Decoded {
+        text,
+        consumed: bytes.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InvalidUtf8, decode_utf8};
+
+    fn lossy(bytes: &[u8]) -> String {
+        decode_utf8(bytes, false, false).expect("lossy").text
+    }
+
+    #[test]
+    fn ascii_and_multibyte_decode_unchanged() {
+        let text = "plain ascii, héllo, ✓, 😀";
+        let decoded = decode_utf8(text.as_bytes(), true, false).expect("valid");
+        assert_eq!(decoded.text, text);
+        assert_eq!(decoded.consumed, text.len());
+    }
+
+    #[test]
+    fn overlongs_and_surrogates_are_replaced_per_maximal_subpart() {
+        // Overlong `/` (C0 AF): C0 is never a valid lead, AF never a lead.
+        assert_eq!(lossy(b"a\xC0\xAFb"), "a\u{FFFD}\u{FFFD}b");
+        // Overlong 3-byte NUL (E0 80 80): E0 requires A0..BF next.
+        assert_eq!(lossy(b"\xE0\x80\x80"), "\u{FFFD}\u{FFFD}\u{FFFD}");
+        // Encoded surrogate U+D800 (ED A0 80): ED requires 80..9F next.
+        assert_eq!(lossy(b"\xED\xA0\x80"), "\u{FFFD}\u{FFFD}\u{FFFD}");
+        // Past U+10FFFF (F4 90 80 80).
+        assert_eq!(
+            lossy(b"\xF4\x90\x80\x80"),
+            "\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}"
+        );
+        // A truncated sequence followed by ASCII is one replacement.
+        assert_eq!(lossy(b"\xE2\x9Cx"), "\u{FFFD}x");
+    }
+
+    #[test]
+    fn fatal_reports_the_first_invalid_offset() {
+        assert_eq!(
+            decode_utf8(b"ok\xFFmore", true, false),
+            Err(InvalidUtf8 { offset: 2 })
+        );
+        assert_eq!(
+            decode_utf8(b"ok\xE2\x9C", true, false),
+            Err(InvalidUtf8 { offset: 2 })
+        );
+    }
+
+    #[test]
+    fn stream_leaves_a_cut_off_tail_unconsumed() {
+        let decoded = decode_utf8(b"ab\xF0\x9F\x98", true, true).expect("stream");
+        assert_eq!(decoded.text, "ab");
+        assert_eq!(decoded.consumed, 2);
+
+        let flushed = decode_utf8(b"ab\xF0\x9F\x98", false, false).expect("flush");
+        assert_eq!(flushed.text, "ab\u{FFFD}");
+        assert_eq!(flushed.consumed, 5);
+    }
+}
@@ -522,17 +522,21 @@ pub enum WellKnownInitError {
 /// Global symbol registry backing `Symbol.for(key)` /
 /// `Symbol.keyFor(sym)`. ECMA-262 §20.4.2.4 / §20.4.2.6.
 ///
-/// Foundation choice: a flat `Vec<(String, JsSymbol)>`. The registry
+/// Foundation choice: a flat `Vec<(Vec<u16>, JsSymbol)>`. The registry
 /// is rarely used (the spec calls it the *GlobalSymbolRegistry* and
 /// real engines back it with a hashmap, but conformance fixtures
-/// touch it sparsely). The vector keeps insertion order so
-/// `keyFor` returns the original key as-is.
+/// touch it sparsely). Keys are the WTF-16 code units of the
+/// `ToString(key)` result, so keys that differ only in lone surrogates
+/// stay distinct and `keyFor` returns the original key unit for unit.
+/// One registry serves every realm of the interpreter, so
+/// `Symbol.for(k)` is the same symbol in a `ShadowRealm` or other
+/// realm as in the main one.
 ///
 /// # See also
 /// - <https://tc39.es/ecma262/#sec-globalsymbolregistry>
 #[derive(Debug, Default)]
 pub struct SymbolRegistry {
-    entries: RefCell<Vec<(String, JsSymbol)>>,
+    entries: RefCell<Vec<(Vec<u16>, JsSymbol)>>,
 }
 
 impl SymbolRegistry {
@@ -554,22 +558,27 @@ impl SymbolRegistry {
     pub fn for_key(
         &self,
         gc_heap: &mut otter_gc::GcHeap,
-        key: &str,
+        key: &[u16],
     ) -> Result<JsSymbol, SymbolRegistryError> {
         if let Some(sym) = self.lookup(key) {
             return Ok(sym);
         }
-        let desc = JsString::from_str(key, gc_heap)?;
+        let desc = JsString::from_utf16_units(key, gc_heap)?;
         let sym = JsSymbol::registered(gc_heap, desc)?;
-        self.entries.borrow_mut().push((key.to_string(), sym));
+        self.entries.borrow_mut().push((key.to_vec(), sym));
         Ok(sym)
     }
 
     /// Spec §20.4.2.6 `Symbol.keyFor(sym)`: return the registry key
     /// for the given symbol, or `None` if `sym` is not registered.
+    /// Well-known, private-name, and `Symbol()` symbols never carry
+    /// the registered flag, so they short-circuit before the walk.
     /// Identity comparison via [`JsSymbol::ptr_eq`].
     #[must_use]
-    pub fn key_for(&self, sym: JsSymbol) -> Option<String> {
+    pub fn key_for(&self, sym: JsSymbol) -> Option<Vec<u16>> {
+        if !sym.is_registered() {
+            return None;
+        }
         self.entries
             .borrow()
             .iter()
@@ -578,7 +587,7 @@ impl SymbolRegistry {
     }
 
     /// Internal lookup by key. Returns the registered symbol if any.
-    fn lookup(&self, key: &str) -> Option<JsSymbol> {
+    fn lookup(&self, key: &[u16]) -> Option<JsSymbol> {
         self.entries
             .borrow()
             .iter()
@@ -627,10 +636,34 @@ mod tests {
     fn registry_dedupes_by_key() {
         let mut gc = fresh_gc_heap();
         let reg = SymbolRegistry::new();
-        let a = reg.for_key(&mut gc, "k").unwrap();
-        let b = reg.for_key(&mut gc, "k").unwrap();
+        let a = reg.for_key(&mut gc, &[0x6b]).unwrap();
+        let b = reg.for_key(&mut gc, &[0x6b]).unwrap();
         assert!(a.ptr_eq(b));
-        assert_eq!(reg.key_for(a).as_deref(), Some("k"));
+        assert_eq!(reg.key_for(a).as_deref(), Some(&[0x6b][..]));
+    }
+
+    #[test]
+    fn registry_keys_keep_lone_surrogates_distinct() {
+        let mut gc = fresh_gc_heap();
+        let reg = SymbolRegistry::new();
+        let high = reg.for_key(&mut gc, &[0xd800]).unwrap();
+        let low = reg.for_key(&mut gc, &[0xdc00]).unwrap();
+        let replacement = reg.for_key(&mut gc, &[0xfffd]).unwrap();
+        assert!(!high.ptr_eq(low));
+        assert!(!high.ptr_eq(replacement));
+        assert_eq!(reg.key_for(low).as_deref(), Some(&[0xdc00][..]));
+    }
+
+    #[test]
+    fn unregistered_symbols_have_no_key() {
+        let mut gc = fresh_gc_heap();
+        let reg = SymbolRegistry::new();
+        let table = WellKnownSymbols::new(&mut gc).unwrap();
+        let desc = JsString::from_str("k", &mut gc).unwrap();
+        let plain = JsSymbol::new(&mut gc, Some(desc)).unwrap();
+        reg.for_key(&mut gc, &[0x6b]).unwrap();
+        assert_eq!(reg.key_for(plain), None);
+        assert_eq!(reg.key_for(table.get(WellKnown::Iterator)), None);
     }
 
     #[test]
@@ -187,29 +187,29 @@ fn symbol_key_for(interp: &mut Interpreter, args: &[Value]) -> Result<Value, Sym
         })?;
     match interp.symbol_registry().key_for(sym) {
         Some(key) => {
-            let s = JsString::from_str(&key, interp.gc_heap_mut())?;
+            let s = JsString::from_utf16_units(&key, interp.gc_heap_mut())?;
             Ok(Value::string(s))
         }
         None => Ok(Value::undefined()),
     }
 }
 
-/// Coerce the first argument of a registry call to a Rust string.
+/// Coerce the first argument of a registry call to WTF-16 code units.
 /// Spec invokes ToString; foundation accepts strings and
 /// non-`Symbol` primitives directly.
 fn key_argument(
     args: &[Value],
     heap: &otter_gc::GcHeap,
     name: &'static str,
-) -> Result<String, SymbolError> {
+) -> Result<Vec<u16>, SymbolError> {
     let Some(first) = args.first() else {
-        return Ok("undefined".to_string());
+        return Ok("undefined".encode_utf16().collect());
     };
     if first.is_undefined() {
-        return Ok("undefined".to_string());
+        return Ok("undefined".encode_utf16().collect());
     }
     if let Some(s) = first.as_string(heap) {
-        return Ok(s.to_lossy_string(heap));
+        return Ok(s.to_utf16_vec(heap));
     }
     if first.is_symbol() {
         return Err(SymbolError::BadArgument {
@@ -218,5 +218,5 @@ fn key_argument(
             reason: "key must not be a symbol",
         });
     }
-    Ok(first.display_string(heap))
+    Ok(first.display_string(heap).encode_utf16().collect())
 }
@@ -1,5 +1,16 @@
 //! `Temporal.Instant` — point on the UTC timeline.
 //!
+//! # Contents
+//! - Constructor plus `from`, `fromEpochMilliseconds`,
+//!   `fromEpochNanoseconds`, and `compare` statics.
+//! - [`INSTANT_PROTOTYPE_METHODS`] — formatting, time-unit arithmetic,
+//!   `until` / `since`, `round`, and `toZonedDateTimeISO`, all backed by
+//!   `temporal_rs::Instant`.
+//!
+//! # Invariants
+//! - `add` / `subtract` reject durations with calendar units (years,
+//!   months, weeks, days) with `RangeError`, as `temporal_rs` does.
+//!
 //! # See also
 //! - <https://tc39.es/proposal-temporal/#sec-temporal-instant-objects>
 
@@ -106,6 +106,9 @@ pub struct TimerEntry {
     /// field to keep the entry alive after firing instead of
     /// removing it.
     pub repeat_ms: Option<u64>,
+    /// AsyncContext mapping current when the timer was scheduled.
+    /// The host enters it around each callback invocation.
+    pub async_context: Value,
 }
 
 impl TimerEntry {
@@ -115,6 +118,7 @@ impl TimerEntry {
         for arg in &self.extra_args {
             arg.trace_value_slots(visitor);
         }
+        self.async_context.trace_value_slots(visitor);
     }
 }
 
@@ -304,6 +308,7 @@ fn schedule_timer_common(
     interp.record_runtime_host_op_enqueued();
     let token = scheduler.schedule(delay_ms, repeat.then_some(delay_ms));
     let realm_id = interp.active_host_realm_id();
+    let async_context = interp.async_context.current();
     interp.timer_callbacks_mut().insert(
         token,
         TimerEntry {
@@ -312,6 +317,7 @@ fn schedule_timer_common(
             extra_args: extra,
             context,
             repeat_ms: repeat.then_some(delay_ms),
+            async_context,
         },
     );
     Ok(Value::number_f64(token as f64))
@@ -381,6 +387,7 @@ fn set_immediate_native(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value
     interp.record_runtime_host_op_enqueued();
     let token = scheduler.schedule(0, None);
     let realm_id = interp.active_host_realm_id();
+    let async_context = interp.async_context.current();
     interp.timer_callbacks_mut().insert(
         token,
         TimerEntry {
@@ -389,6 +396,7 @@ fn set_immediate_native(ctx: &mut NativeCtx<'_>, args: &[Value]) -> Result<Value
             extra_args: extra,
             context,
             repeat_ms: None,
+            async_context,
         },
     );
     Ok(Value::number_f64(token as f64))
@@ -0,0 +1,218 @@
+//! Diagnostic formatting for [`Value`].
+//!
+//! # Contents
+//! - `impl Debug for Value` — heap-free: decodes the NaN-box tag and prints
+//!   immediates by value (`Number(3)`, `Bool(true)`, `Undefined`) and cells
+//!   by pointer family and compressed offset (`Object#0x00001230`).
+//! - [`ValueDebug`] — the heap-aware view from [`Value::debug_in`], which
+//!   also names the body kind (`Array#…`, `Closure#…`) and prints string,
+//!   symbol, and BigInt contents, truncating long strings.
+//!
+//! # Invariants
+//! - Formatting never allocates on the GC heap, runs JavaScript, or mutates
+//!   a body, so it is safe from panic messages and from inside the collector's
+//!   callers. Only plain Rust `String`s are built.
+//! - `Debug for Value` never dereferences a cell. A stale or fabricated
+//!   pointer still formats; only [`ValueDebug`] reads headers and payloads,
+//!   and it requires the heap that owns the value.
+
+use super::*;
+
+/// Longest string prefix, in UTF-16 code units, that [`ValueDebug`] prints
+/// before truncating.
+pub const DEBUG_STRING_LIMIT: usize = 64;
+
+impl std::fmt::Debug for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(result) = fmt_immediate(*self, f) {
+            return result;
+        }
+        let family = match self.kind() {
+            ValueKind::PtrObject => "Object",
+            ValueKind::PtrString => "String",
+            ValueKind::PtrFunction => "Function",
+            _ => "Cell",
+        };
+        write!(f, "{family}#0x{:08x}", cell_offset(self.0))
+    }
+}
+
+/// Format every non-cell value; `None` for heap cells.
+fn fmt_immediate(value: Value, f: &mut std::fmt::Formatter<'_>) -> Option<std::fmt::Result> {
+    Some(match value.kind() {
+        ValueKind::Int32 => write!(f, "Number({})", value.as_i32()?),
+        ValueKind::Number => write!(f, "Number({:?})", value.as_f64()?),
+        ValueKind::FunctionId => write!(f, "FunctionId({})", value.as_function_id()?),
+        ValueKind::Special => f.write_str(match value.0 {
+            x if x == Value::UNDEFINED.0 => "Undefined",
+            x if x == Value::NULL.0 => "Null",
+            x if x == Value::HOLE.0 => "Hole",
+            x if x == Value::TRUE.0 => "Bool(true)",
+            x if x == Value::FALSE.0 => "Bool(false)",
+            _ => "Special(?)",
+        }),
+        ValueKind::PtrObject
+        | ValueKind::PtrString
+        | ValueKind::PtrFunction
+        | ValueKind::PtrOther => return None,
+    })
+}
+
+/// Heap-aware diagnostic view of a [`Value`]. `Debug` and `Display` print
+/// the same text.
+#[derive(Clone, Copy)]
+pub struct ValueDebug<'heap> {
+    value: Value,
+    heap: &'heap otter_gc::GcHeap,
+}
+
+impl Value {
+    /// Diagnostic view that reads body kinds and string contents from
+    /// `heap`. `heap` must be the heap that owns this value.
+    #[must_use]
+    pub fn debug_in(self, heap: &otter_gc::GcHeap) -> ValueDebug<'_> {
+        ValueDebug { value: self, heap }
+    }
+}
+
+impl std::fmt::Debug for ValueDebug<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = self.value;
+        if let Some(result) = fmt_immediate(value, f) {
+            return result;
+        }
+        let offset = cell_offset(value.0);
+        match value.kind() {
+            ValueKind::PtrString => match value.as_string(self.heap) {
+                Some(string) => fmt_string(string, self.heap, f),
+                None => write!(f, "String#0x{offset:08x}"),
+            },
+            ValueKind::PtrFunction => match value.function_family_kind() {
+                Some(FunctionFamilyKind::Unknown) | None => write!(f, "Function#0x{offset:08x}"),
+                Some(kind) => write!(f, "{kind:?}#0x{offset:08x}"),
+            },
+            ValueKind::PtrOther => match value.other_family_kind() {
+                Some(OtherFamilyKind::Symbol) => match value.as_symbol(self.heap) {
+                    Some(symbol) => f.write_str(&symbol.descriptive_string(self.heap)),
+                    None => write!(f, "Symbol#0x{offset:08x}"),
+                },
+                Some(OtherFamilyKind::BigInt) => match value.as_big_int() {
+                    Some(big_int) => write!(f, "BigInt({})", big_int.to_decimal_string(self.heap)),
+                    None => write!(f, "BigInt#0x{offset:08x}"),
+                },
+                Some(OtherFamilyKind::Unknown) | None => write!(f, "Cell#0x{offset:08x}"),
+            },
+            _ => match value.object_family_kind() {
+                Some(ObjectFamilyKind::Unknown) | None => write!(f, "Object#0x{offset:08x}"),
+                Some(kind) => write!(f, "{kind:?}#0x{offset:08x}"),
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for ValueDebug<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+/// `String("…")`, or `String("prefix…", len=N)` past [`DEBUG_STRING_LIMIT`].
+/// The cut never splits a surrogate pair.
+fn fmt_string(
+    string: crate::string::JsString,
+    heap: &otter_gc::GcHeap,
+    f: &mut std::fmt::Formatter<'_>,
+) -> std::fmt::Result {
+    let len = string.len() as usize;
+    if len <= DEBUG_STRING_LIMIT {
+        return write!(f, "String({:?})", string.to_lossy_string(heap));
+    }
+    let prefix = string.with_utf16(heap, |units| {
+        let mut end = DEBUG_STRING_LIMIT;
+        if (0xD800..0xDC00).contains(&units[end - 1]) {
+            end -= 1;
+        }
+        String::from_utf16_lossy(&units[..end])
+    });
+    write!(f, "String({:?}, len={len})", format!("{prefix}…"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::alloc_object_with_roots;
+    use crate::string::JsString;
+    use otter_gc::GcHeap;
+    use otter_gc::raw::RawGc;
+
+    #[test]
+    fn immediates_format_by_value() {
+        let cases = [
+            (Value::number_i32(3), "Number(3)"),
+            (Value::number_i32(-7), "Number(-7)"),
+            (Value::number_f64(1.5), "Number(1.5)"),
+            (Value::number_f64(f64::NAN), "Number(NaN)"),
+            (Value::number_f64(-0.0), "Number(-0.0)"),
+            (Value::boolean(true), "Bool(true)"),
+            (Value::boolean(false), "Bool(false)"),
+            (Value::undefined(), "Undefined"),
+            (Value::null(), "Null"),
+            (Value::hole(), "Hole"),
+        ];
+        let heap = GcHeap::new().expect("heap");
+        for (value, expected) in cases {
+            assert_eq!(format!("{value:?}"), expected);
+            assert_eq!(value.debug_in(&heap).to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn cells_format_by_family_without_a_heap() {
+        let mut heap = GcHeap::new().expect("heap");
+        let mut roots = |_v: &mut dyn FnMut(*mut RawGc)| {};
+        let object = alloc_object_with_roots(&mut heap, &mut roots).expect("alloc");
+        let value = Value::object(object);
+        let expected = format!("Object#0x{:08x}", object.raw().0);
+        assert_eq!(format!("{value:?}"), expected);
+        assert_eq!(format!("{:?}", value.debug_in(&heap)), expected);
+
+        let string = Value::string(JsString::from_str("hi", &mut heap).expect("string"));
+        assert!(format!("{string:?}").starts_with("String#0x"));
+    }
+
+    #[test]
+    fn strings_print_contents_and_truncate() {
+        let mut heap = GcHeap::new().expect("heap");
+        let short = Value::string(JsString::from_str("say \"hi\"", &mut heap).expect("string"));
+        assert_eq!(short.debug_in(&heap).to_string(), r#"String("say \"hi\"")"#);
+
+        let long = "x".repeat(1000);
+        let long = Value::string(JsString::from_str(&long, &mut heap).expect("string"));
+        let expected = format!("String(\"{}…\", len=1000)", "x".repeat(DEBUG_STRING_LIMIT));
+        assert_eq!(long.debug_in(&heap).to_string(), expected);
+
+        // A pair straddling the limit is dropped whole rather than split.
+        let straddle = format!(
+            "{}😀tail{}",
+            "a".repeat(DEBUG_STRING_LIMIT - 1),
+            "z".repeat(8)
+        );
+        let straddle = Value::string(JsString::from_str(&straddle, &mut heap).expect("string"));
+        let expected = format!(
+            "String(\"{}…\", len={})",
+            "a".repeat(DEBUG_STRING_LIMIT - 1),
+            DEBUG_STRING_LIMIT + 1 + 4 + 8
+        );
+        assert_eq!(straddle.debug_in(&heap).to_string(), expected);
+    }
+
+    #[test]
+    fn symbols_name_their_description() {
+        let mut heap = GcHeap::new().expect("heap");
+        let description = JsString::from_str("tag", &mut heap).expect("string");
+        let symbol = crate::symbol::JsSymbol::new(&mut heap, Some(description)).expect("symbol");
+        let value = Value::symbol(symbol);
+        assert_eq!(value.debug_in(&heap).to_string(), "Symbol(tag)");
+        assert!(format!("{value:?}").starts_with("Cell#0x"));
+    }
+}
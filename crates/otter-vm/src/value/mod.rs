@@ -26,6 +26,9 @@
 //! `Value::Object(…)` enum form is unsupported — call sites move to
 //! accessors.
 //!
+//! For diagnostics, `{:?}` decodes the tag without touching the heap and
+//! [`Value::debug_in`] adds body kinds and string contents (see [`debug`]).
+//!
 //! # Invariants
 //!
 //! - `size_of::<Value>() == 8` and `align_of::<Value>() == 8` (static
@@ -46,8 +49,11 @@
 //!   — the value-model section.
 
 pub mod compressed;
+mod debug;
 pub mod tag;
 
+pub use debug::{DEBUG_STRING_LIMIT, ValueDebug};
+
 use crate::array::{ArrayBody, JsArray};
 use crate::bigint::{BigIntBody, BigIntHandle};
 use crate::binary::{
@@ -1986,35 +1992,6 @@ impl otter_gc::GcStore for Value {
     }
 }
 
-impl std::fmt::Debug for Value {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self.kind() {
-            ValueKind::Number => write!(f, "Value::Number({:?})", self.as_number().unwrap()),
-            ValueKind::Int32 => write!(f, "Value::Int32({})", self.as_i32().unwrap()),
-            ValueKind::Special => {
-                let s = match self.0 {
-                    x if x == Self::UNDEFINED.0 => "undefined",
-                    x if x == Self::NULL.0 => "null",
-                    x if x == Self::HOLE.0 => "<hole>",
-                    x if x == Self::TRUE.0 => "true",
-                    x if x == Self::FALSE.0 => "false",
-                    _ => "<special?>",
-                };
-                write!(f, "Value::{}", s)
-            }
-            ValueKind::FunctionId => {
-                write!(f, "Value::FunctionId({})", self.as_function_id().unwrap())
-            }
-            ValueKind::PtrObject => write!(f, "Value::PtrObject(0x{:08x})", cell_offset(self.0)),
-            ValueKind::PtrString => write!(f, "Value::PtrString(0x{:08x})", cell_offset(self.0)),
-            ValueKind::PtrFunction => {
-                write!(f, "Value::PtrFunction(0x{:08x})", cell_offset(self.0))
-            }
-            ValueKind::PtrOther => write!(f, "Value::PtrOther(0x{:08x})", cell_offset(self.0)),
-        }
-    }
-}
-
 // ---------------------------------------------------------------------------
 // Unit tests
 // ---------------------------------------------------------------------------
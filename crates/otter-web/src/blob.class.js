@@ -0,0 +1,37 @@
+// Blob.prototype.stream — the JS half of the native Blob class.
+//
+// The Blob record (immutable bytes) is native; `stream()` needs a
+// ReadableStream, which is a lazy JS global, so the wrapper lives here.
+// The stream is a byte stream: the first pull snapshots the bytes
+// through the async `bytes()` member, then each pull enqueues one
+// bounded chunk so readers see backpressure-friendly Uint8Arrays
+// instead of one buffer the size of the blob.
+(function () {
+  'use strict';
+  const CHUNK_SIZE = 65536;
+  Object.defineProperty(Blob.prototype, 'stream', {
+    configurable: true,
+    enumerable: true,
+    writable: true,
+    value: function stream() {
+      const blob = this;
+      let bytes = null;
+      let offset = 0;
+      // Constructed lazily so the ReadableStream lazy global
+      // materializes only when stream() is actually called.
+      return new ReadableStream({
+        type: 'bytes',
+        async pull(controller) {
+          if (bytes === null) bytes = await blob.bytes();
+          if (offset >= bytes.byteLength) {
+            controller.close();
+            return;
+          }
+          const end = Math.min(offset + CHUNK_SIZE, bytes.byteLength);
+          controller.enqueue(bytes.slice(offset, end));
+          offset = end;
+        },
+      });
+    },
+  });
+})();
@@ -6,7 +6,9 @@
 //! `Symbol.toStringTag` / JS-subclass `new.target` handling come from
 //! the declaration. `File` is a native subclass — its data embeds the
 //! `Blob` record and the ancestry walk lets every `Blob.prototype`
-//! member run on `File` instances unmodified.
+//! member run on `File` instances unmodified. `stream()` lives in the
+//! attached `blob.class.js` glue because it returns a (lazy, JS-defined)
+//! `ReadableStream`.
 //!
 //! # Contents
 //! - [`Blob`] — bytes + normalized MIME type; the Rust-side record is
@@ -14,6 +16,7 @@
 //! - [`File`] — `File extends Blob` with `name` / `lastModified`.
 //! - [`BlobPart`] / [`BlobPropertyBag`] / [`FilePropertyBag`] — WebIDL
 //!   argument shapes for the constructors.
+//! - `blob.class.js` — `Blob.prototype.stream()` over `bytes()`.
 //!
 //! # Invariants
 //! - Blob bytes are immutable snapshots (`Arc<[u8]>`): every part is
@@ -136,7 +139,7 @@ impl Blob {
     }
 }
 
-#[js_class(name = "Blob", feature = WEB)]
+#[js_class(name = "Blob", feature = WEB, js = "blob.class.js")]
 impl Blob {
     #[constructor]
     fn js_new(parts: Option<Sequence<BlobPart>>, options: Option<BlobPropertyBag>) -> Blob {
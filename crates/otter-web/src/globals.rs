@@ -59,6 +59,9 @@ fn install(runtime: &mut RuntimeExtensionContext<'_>) -> Result<(), OtterError>
         RuntimeNativeCall::Dynamic(fetch_call),
     )?;
     runtime.install_native_global("__otterStreamCodec", 3, stream_codec)?;
+    // Shared UTF-8 decoder behind `TextDecoder`; consumed and deleted by
+    // `web_bootstrap.js` like the other private members above.
+    runtime.install_native_global("__otterDecodeUtf8", 3, otter_runtime::runtime_decode_utf8)?;
     install_navigator(runtime)?;
     install_self(runtime)?;
     install_promise_rejection_handling(runtime)?;
@@ -83,8 +86,11 @@ fn install_promise_rejection_handling(
     runtime: &mut RuntimeExtensionContext<'_>,
 ) -> Result<(), OtterError> {
     // `handled` is false for the `unhandledrejection` notification and true for
-    // the follow-up `rejectionhandled`. Defensive throughout: a throwing
-    // handler must never abort the microtask drain the VM invokes this from.
+    // the follow-up `rejectionhandled`. The return value tells the VM whether
+    // the `unhandledrejection` event was canceled; with `willThrow` the VM
+    // raises an uncanceled rejection itself, so `reportError` is skipped.
+    // Defensive throughout: a throwing handler must never abort the microtask
+    // drain the VM invokes this from.
     let shim = "\
         (function (g) {\n\
           function replaceable(name) {\n\
@@ -96,22 +102,23 @@ fn install_promise_rejection_handling(
           replaceable('onrejectionhandled');\n\
           replaceable('onerror');\n\
           Object.defineProperty(g, '__otterFirePromiseRejection', {\n\
-            value: function (promise, reason, handled) {\n\
+            value: function (promise, reason, handled, willThrow) {\n\
               var type = handled ? 'rejectionhandled' : 'unhandledrejection';\n\
               var event;\n\
               try {\n\
                 event = new g.PromiseRejectionEvent(type, {\n\
                   promise: promise, reason: reason, cancelable: !handled,\n\
                 });\n\
-              } catch (_) { return; }\n\
+              } catch (_) { return false; }\n\
               var handler = g['on' + type];\n\
               if (typeof handler === 'function') {\n\
                 try { handler.call(g, event); }\n\
                 catch (e) { try { g.reportError(e); } catch (_) {} }\n\
               }\n\
-              if (!handled && !event.defaultPrevented) {\n\
+              if (!handled && !event.defaultPrevented && !willThrow) {\n\
                 try { g.reportError(reason); } catch (_) {}\n\
               }\n\
+              return event.defaultPrevented;\n\
             },\n\
             writable: true, enumerable: false, configurable: true,\n\
           });\n\
@@ -34,15 +34,46 @@
   // before any lazy Web global is touched.
 
   // `reportError(e)` (HTML §report-the-exception). Otter's global object is not
-  // a Window/Worker EventTarget, so there is no global "error" event to
-  // dispatch; the documented alternative reporting mechanism routes the value
-  // to the console. Full ErrorEvent/uncaught-exception wiring is a later slice.
-  def('reportError', function reportError(e) {
+  // a Window/Worker EventTarget, so the "error" event goes to the replaceable
+  // `onerror` global installed in Rust (see `install_promise_rejection_handling`),
+  // with an `ErrorEvent` argument as in workers. The error value is forwarded
+  // untouched so its stack survives. Unless the handler cancels the event
+  // (`preventDefault()` or a `true` return, as for Window), the value is
+  // logged through `console.error`. Never throws.
+  let reportingError = false;
+  function logReported(e) {
     try {
       console.error(e);
     } catch (_) {
       /* console may be unavailable in a bare realm */
     }
+  }
+  def('reportError', function reportError(e) {
+    const handler = globalThis.onerror;
+    if (typeof handler !== 'function' || reportingError) {
+      logReported(e);
+      return;
+    }
+    let event;
+    try {
+      const message =
+        e !== null && typeof e === 'object' && 'message' in e ? String(e.message) : String(e);
+      event = new ErrorEvent('error', { message, error: e, cancelable: true });
+    } catch (_) {
+      logReported(e);
+      return;
+    }
+    reportingError = true;
+    let cancelled = false;
+    try {
+      cancelled = handler.call(globalThis, event) === true;
+    } catch (handlerError) {
+      // A throwing handler is itself reported, without re-entering it.
+      logReported(handlerError);
+    } finally {
+      reportingError = false;
+    }
+    if (!cancelled && !event.defaultPrevented) logReported(e);
   });
 
   // ---- DOMException (§ WebIDL) ----
@@ -352,39 +383,86 @@
     }
   }
 
+  // Next scalar value of `str` at index `i` as [codePoint, unitsRead]; lone
+  // surrogates become U+FFFD (USVString conversion).
+  function scalarAt(str, i) {
+    const c = str.charCodeAt(i);
+    if (c >= 0xD800 && c <= 0xDBFF && i + 1 < str.length) {
+      const c2 = str.charCodeAt(i + 1);
+      if (c2 >= 0xDC00 && c2 <= 0xDFFF) {
+        return [0x10000 + ((c - 0xD800) << 10) + (c2 - 0xDC00), 2];
+      }
+    }
+    if (c >= 0xD800 && c <= 0xDFFF) return [0xFFFD, 1];
+    return [c, 1];
+  }
+
+  function utf8Length(cp) {
+    if (cp < 0x80) return 1;
+    if (cp < 0x800) return 2;
+    if (cp < 0x10000) return 3;
+    return 4;
+  }
+
+  function writeUtf8(cp, out, at) {
+    if (cp < 0x80) { out[at] = cp; return; }
+    if (cp < 0x800) {
+      out[at] = 0xC0 | (cp >> 6);
+      out[at + 1] = 0x80 | (cp & 0x3F);
+      return;
+    }
+    if (cp < 0x10000) {
+      out[at] = 0xE0 | (cp >> 12);
+      out[at + 1] = 0x80 | ((cp >> 6) & 0x3F);
+      out[at + 2] = 0x80 | (cp & 0x3F);
+      return;
+    }
+    out[at] = 0xF0 | (cp >> 18);
+    out[at + 1] = 0x80 | ((cp >> 12) & 0x3F);
+    out[at + 2] = 0x80 | ((cp >> 6) & 0x3F);
+    out[at + 3] = 0x80 | (cp & 0x3F);
+  }
+
   class TextEncoder {
     constructor() {}
     get encoding() { return 'utf-8'; }
     encode(input = '') {
       const str = String(input);
-      const out = [];
-      for (let i = 0; i < str.length; i++) {
-        let c = str.charCodeAt(i);
-        if (c >= 0xD800 && c <= 0xDBFF && i + 1 < str.length) {
-          const c2 = str.charCodeAt(i + 1);
-          if (c2 >= 0xDC00 && c2 <= 0xDFFF) {
-            c = 0x10000 + ((c - 0xD800) << 10) + (c2 - 0xDC00);
-            i++;
-          }
-        }
-        if (c < 0x80) out.push(c);
-        else if (c < 0x800) { out.push(0xC0 | (c >> 6), 0x80 | (c & 0x3F)); }
-        else if (c < 0x10000) {
-          if (c >= 0xD800 && c <= 0xDFFF) c = 0xFFFD;
-          out.push(0xE0 | (c >> 12), 0x80 | ((c >> 6) & 0x3F), 0x80 | (c & 0x3F));
-        } else {
-          out.push(0xF0 | (c >> 18), 0x80 | ((c >> 12) & 0x3F),
-                   0x80 | ((c >> 6) & 0x3F), 0x80 | (c & 0x3F));
-        }
+      let length = 0;
+      for (let i = 0; i < str.length;) {
+        const [cp, units] = scalarAt(str, i);
+        length += utf8Length(cp);
+        i += units;
+      }
+      const out = new Uint8Array(length);
+      let at = 0;
+      for (let i = 0; i < str.length;) {
+        const [cp, units] = scalarAt(str, i);
+        writeUtf8(cp, out, at);
+        at += utf8Length(cp);
+        i += units;
       }
-      return Uint8Array.from(out);
+      return out;
     }
-    encodeInto(source, dest) {
-      const encoded = this.encode(source);
-      const n = Math.min(encoded.length, dest.length);
-      dest.set(encoded.subarray(0, n));
-      // `read` is an approximation (assumes 1:1 below the truncation point).
-      return { read: n === encoded.length ? String(source).length : n, written: n };
+    // Encoding § encodeInto: writes whole scalar values only — a code point
+    // that does not fit stops the encode — and reports UTF-16 code units read
+    // alongside bytes written.
+    encodeInto(source, destination) {
+      if (!(destination instanceof Uint8Array)) {
+        throw new TypeError('TextEncoder.encodeInto: destination must be a Uint8Array');
+      }
+      const str = String(source);
+      let read = 0;
+      let written = 0;
+      while (read < str.length) {
+        const [cp, units] = scalarAt(str, read);
+        const length = utf8Length(cp);
+        if (written + length > destination.length) break;
+        writeUtf8(cp, destination, written);
+        written += length;
+        read += units;
+      }
+      return { read, written };
     }
   }
   tagged(TextEncoder.prototype, 'TextEncoder');
@@ -400,39 +478,21 @@
     throw new TypeError('The provided value is not of type ArrayBuffer or ArrayBufferView');
   }
 
-  function decodeUtf8(bytes, fatal) {
-    let out = '';
-    let i = 0;
-    const n = bytes.length;
-    while (i < n) {
-      const b0 = bytes[i++];
-      if (b0 < 0x80) { out += String.fromCharCode(b0); continue; }
-      let cp, extra, min;
-      if ((b0 & 0xE0) === 0xC0) { cp = b0 & 0x1F; extra = 1; min = 0x80; }
-      else if ((b0 & 0xF0) === 0xE0) { cp = b0 & 0x0F; extra = 2; min = 0x800; }
-      else if ((b0 & 0xF8) === 0xF0) { cp = b0 & 0x07; extra = 3; min = 0x10000; }
-      else { if (fatal) throw new TypeError('The encoded data was not valid'); out += '�'; continue; }
-      let ok = true;
-      for (let k = 0; k < extra; k++) {
-        if (i >= n) { ok = false; break; }
-        const b = bytes[i];
-        if ((b & 0xC0) !== 0x80) { ok = false; break; }
-        cp = (cp << 6) | (b & 0x3F);
-        i++;
-      }
-      if (!ok || cp < min || cp > 0x10FFFF || (cp >= 0xD800 && cp <= 0xDFFF)) {
-        if (fatal) throw new TypeError('The encoded data was not valid');
-        out += '�';
-        continue;
-      }
-      if (cp > 0xFFFF) {
-        cp -= 0x10000;
-        out += String.fromCharCode(0xD800 + (cp >> 10), 0xDC00 + (cp & 0x3FF));
-      } else {
-        out += String.fromCharCode(cp);
-      }
-    }
-    return out;
+  function invalidData() {
+    return new TypeError('The encoded data was not valid');
+  }
+
+  // Decode UTF-8 into [text, consumed] through the shared native decoder
+  // (validation, overlong/surrogate rejection, U+FFFD substitution). With
+  // `stream`, a sequence cut off by the end of the input stays unconsumed so
+  // the next chunk can complete it.
+  const nativeDecodeUtf8 = global.__otterDecodeUtf8;
+  delete global.__otterDecodeUtf8;
+
+  function decodeUtf8(bytes, fatal, stream) {
+    const result = nativeDecodeUtf8(bytes, fatal, stream);
+    if (result === null) throw invalidData();
+    return result;
   }
 
   function decodeCp1252(bytes) {
@@ -442,20 +502,59 @@
       if (b < 0x80 || b >= 0xA0) out += String.fromCharCode(b);
       else out += String.fromCharCode(CP1252_HIGH[b - 0x80]);
     }
-    return out;
+    return [out, bytes.length];
   }
 
-  function decodeUtf16(bytes, littleEndian) {
+  // Decode UTF-16 into [text, consumed]. Unpaired surrogates and a dangling
+  // odd byte are errors (U+FFFD, or a TypeError when fatal); with `stream`, a
+  // trailing odd byte or high surrogate waits for the next chunk instead.
+  function decodeUtf16(bytes, littleEndian, fatal, stream) {
     let out = '';
-    for (let i = 0; i + 1 < bytes.length; i += 2) {
-      const unit = littleEndian
-        ? bytes[i] | (bytes[i + 1] << 8)
-        : (bytes[i] << 8) | bytes[i + 1];
-      out += String.fromCharCode(unit);
+    let i = 0;
+    const unitAt = (at) => (littleEndian
+      ? bytes[at] | (bytes[at + 1] << 8)
+      : (bytes[at] << 8) | bytes[at + 1]);
+    while (i + 1 < bytes.length) {
+      const unit = unitAt(i);
+      if (unit >= 0xD800 && unit <= 0xDBFF) {
+        if (i + 3 < bytes.length) {
+          const next = unitAt(i + 2);
+          if (next >= 0xDC00 && next <= 0xDFFF) {
+            out += String.fromCharCode(unit, next);
+            i += 4;
+            continue;
+          }
+        } else if (stream) {
+          return [out, i];
+        }
+      }
+      if (unit >= 0xD800 && unit <= 0xDFFF) {
+        if (fatal) throw invalidData();
+        out += '�';
+      } else {
+        out += String.fromCharCode(unit);
+      }
+      i += 2;
     }
-    return out;
+    if (i < bytes.length) {
+      if (stream) return [out, i];
+      if (fatal) throw invalidData();
+      out += '�';
+    }
+    return [out, bytes.length];
   }
 
+  const BOMS = {
+    'utf-8': [0xEF, 0xBB, 0xBF],
+    'utf-16le': [0xFF, 0xFE],
+    'utf-16be': [0xFE, 0xFF],
+  };
+
+  // Encoding § TextDecoder. Streaming state lives in two hidden slots:
+  // `_pending` holds the bytes of an incomplete trailing sequence carried to
+  // the next `decode` call, and `_bomSeen` records that the start of the
+  // stream (where a BOM may appear) has been processed. A non-streaming call
+  // flushes the pending bytes and resets both.
   class TextDecoder {
     constructor(label = 'utf-8', options = {}) {
       const enc = labelToEncoding(label);
@@ -466,32 +565,56 @@
       Object.defineProperty(this, '_encoding', { value: enc, enumerable: false });
       Object.defineProperty(this, '_fatal', { value: Boolean(opts.fatal), enumerable: false });
       Object.defineProperty(this, '_ignoreBOM', { value: Boolean(opts.ignoreBOM), enumerable: false });
+      Object.defineProperty(this, '_pending', {
+        value: new Uint8Array(0), writable: true, enumerable: false,
+      });
+      Object.defineProperty(this, '_bomSeen', { value: false, writable: true, enumerable: false });
     }
     get encoding() { return this._encoding; }
     get fatal() { return this._fatal; }
     get ignoreBOM() { return this._ignoreBOM; }
     decode(input, options) {
-      let bytes = bytesOf(input);
-      // BOM handling (default: strip).
-      if (!this._ignoreBOM) {
-        if (this._encoding === 'utf-8' && bytes.length >= 3 &&
-            bytes[0] === 0xEF && bytes[1] === 0xBB && bytes[2] === 0xBF) {
-          bytes = bytes.subarray(3);
-        } else if (this._encoding === 'utf-16le' && bytes.length >= 2 &&
-                   bytes[0] === 0xFF && bytes[1] === 0xFE) {
-          bytes = bytes.subarray(2);
-        } else if (this._encoding === 'utf-16be' && bytes.length >= 2 &&
-                   bytes[0] === 0xFE && bytes[1] === 0xFF) {
-          bytes = bytes.subarray(2);
+      const stream = Boolean(options && options.stream);
+      const chunk = bytesOf(input);
+      let bytes = chunk;
+      if (this._pending.length > 0) {
+        bytes = new Uint8Array(this._pending.length + chunk.length);
+        bytes.set(this._pending, 0);
+        bytes.set(chunk, this._pending.length);
+      }
+      const bom = BOMS[this._encoding];
+      if (!this._bomSeen && !this._ignoreBOM && bom !== undefined) {
+        // A stream may split the BOM itself; wait until it can be decided.
+        if (stream && bytes.length < bom.length &&
+            bom.slice(0, bytes.length).every((b, k) => bytes[k] === b)) {
+          this._pending = bytes.slice();
+          return '';
+        }
+        if (bytes.length >= bom.length && bom.every((b, k) => bytes[k] === b)) {
+          bytes = bytes.subarray(bom.length);
+        }
+      }
+      this._bomSeen = bytes.length > 0 || this._bomSeen;
+      let result;
+      try {
+        switch (this._encoding) {
+          case 'windows-1252': result = decodeCp1252(bytes); break;
+          case 'utf-16le': result = decodeUtf16(bytes, true, this._fatal, stream); break;
+          case 'utf-16be': result = decodeUtf16(bytes, false, this._fatal, stream); break;
+          default: result = decodeUtf8(bytes, this._fatal, stream); break;
         }
+      } catch (error) {
+        this._pending = new Uint8Array(0);
+        this._bomSeen = false;
+        throw error;
       }
-      switch (this._encoding) {
-        case 'utf-8': return decodeUtf8(bytes, this._fatal);
-        case 'windows-1252': return decodeCp1252(bytes);
-        case 'utf-16le': return decodeUtf16(bytes, true);
-        case 'utf-16be': return decodeUtf16(bytes, false);
-        default: return decodeUtf8(bytes, this._fatal);
+      if (stream) {
+        this._pending = bytes.slice(result[1]);
+      } else {
+        this._pending = new Uint8Array(0);
+        this._bomSeen = false;
       }
+      return result[0];
     }
   }
   tagged(TextDecoder.prototype, 'TextDecoder');
@@ -849,13 +972,20 @@
   // ---- FormData (XHR § FormData) ----
   const kEntries = Symbol('entries');
 
+  // XHR § create an entry: a bare Blob becomes a File named "blob", and an
+  // explicit filename re-wraps the bytes in a File carrying that name, so
+  // `get()` and the multipart encoder always see the entry's final filename.
   function toFormValue(value, filename) {
     const isBlob = typeof global.Blob !== 'undefined' && value instanceof global.Blob;
     if (isBlob) {
-      // A bare Blob appended to FormData becomes a File named "blob".
-      const name = filename !== undefined ? String(filename)
-        : (typeof value.name === 'string' ? value.name : 'blob');
-      return { value, filename: name };
+      const isFile = typeof global.File !== 'undefined' && value instanceof global.File;
+      if (!isFile || filename !== undefined) {
+        const name = filename !== undefined ? String(filename) : 'blob';
+        const options = { type: value.type };
+        if (isFile) options.lastModified = value.lastModified;
+        value = new global.File([value], name, options);
+      }
+      return { value, filename: value.name };
     }
     return { value: String(value), filename: undefined };
   }
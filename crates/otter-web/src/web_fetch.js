@@ -298,14 +298,14 @@
         body.buffer.slice(body.byteOffset, body.byteOffset + body.byteLength));
       return null;
     }
-    if (typeof global.Blob === 'function' && body instanceof global.Blob) {
-      target[kBodyText] = body.text();
+    if (isBlob(body)) {
+      target[kBodyStream] = partsStream([body]);
       const type = body.type;
       return type === '' ? null : type;
     }
     if (typeof global.FormData === 'function' && body instanceof global.FormData) {
       const encoded = encodeMultipart(body);
-      target[kBodyBytes] = encoded.bytes;
+      target[kBodyStream] = encoded.stream;
       return encoded.type;
     }
     if (typeof global.ReadableStream === 'function' && body instanceof global.ReadableStream) {
@@ -316,6 +316,34 @@
     return 'text/plain;charset=UTF-8';
   }
 
+  function isBlob(value) {
+    return typeof global.Blob === 'function' && value instanceof global.Blob;
+  }
+
+  // A byte stream over a fixed list of parts. `Uint8Array` parts enqueue as-is;
+  // Blob parts are read through the async `Blob.prototype.bytes()` only when the
+  // stream reaches them, so blob bytes flow through unmodified (no text decode)
+  // and are never buffered before the body is consumed.
+  function partsStream(parts) {
+    let index = 0;
+    return new global.ReadableStream({
+      type: 'bytes',
+      async pull(controller) {
+        // Skip empty parts here: a pull that enqueues nothing would leave a
+        // pending read unsatisfied.
+        while (index < parts.length) {
+          const part = parts[index++];
+          const bytes = part instanceof Uint8Array ? part : await part.bytes();
+          if (bytes.byteLength > 0) {
+            controller.enqueue(bytes);
+            return;
+          }
+        }
+        controller.close();
+      },
+    });
+  }
+
   function multipartBoundary() {
     let tail = '';
     for (let i = 0; i < 24; i++) {
@@ -324,26 +352,41 @@
     return '----OtterFormBoundary' + tail;
   }
 
+  // HTML § multipart/form-data encoding: field names and filenames escape
+  // `"`, CR, and LF as percent-encoded bytes inside the quoted header values.
+  function escapeMultipartName(name) {
+    return name.replace(/\r/g, '%0D').replace(/\n/g, '%0A').replace(/"/g, '%22');
+  }
+
+  // Encode a FormData as a multipart/form-data byte stream. The entry list is
+  // snapshotted now (later FormData mutation must not reach the body); each
+  // Blob/File part contributes its raw bytes under a `filename` disposition
+  // and its own Content-Type (`application/octet-stream` when untyped).
   function encodeMultipart(formData) {
     const boundary = multipartBoundary();
-    let out = '';
+    const parts = [];
     for (const [name, value] of formData) {
-      out += `--${boundary}\r\n`;
-      if (typeof global.Blob === 'function' && value instanceof global.Blob) {
-        const filename = typeof value.name === 'string' ? value.name : 'blob';
+      const field = escapeMultipartName(name);
+      if (isBlob(value)) {
+        const filename = escapeMultipartName(
+          typeof value.name === 'string' ? value.name : 'blob');
         const type = value.type === '' ? 'application/octet-stream' : value.type;
-        out += `Content-Disposition: form-data; name="${name}"; filename="${filename}"\r\n`;
-        out += `Content-Type: ${type}\r\n\r\n`;
-        out += value.text();
-        out += '\r\n';
+        parts.push(utf8Encode(
+          `--${boundary}\r\n` +
+          `Content-Disposition: form-data; name="${field}"; filename="${filename}"\r\n` +
+          `Content-Type: ${type}\r\n\r\n`));
+        parts.push(value);
+        parts.push(utf8Encode('\r\n'));
       } else {
-        out += `Content-Disposition: form-data; name="${name}"\r\n\r\n`;
-        out += `${value}\r\n`;
+        parts.push(utf8Encode(
+          `--${boundary}\r\n` +
+          `Content-Disposition: form-data; name="${field}"\r\n\r\n` +
+          `${value}\r\n`));
       }
     }
-    out += `--${boundary}--\r\n`;
+    parts.push(utf8Encode(`--${boundary}--\r\n`));
     return {
-      bytes: utf8Encode(out),
+      stream: partsStream(parts),
       type: 'multipart/form-data; boundary=' + boundary,
     };
   }
@@ -419,7 +462,7 @@
       const filename = /filename="([^"]*)"/i.exec(rawHeaders);
       if (filename) {
         const typeMatch = /content-type:\s*([^\r\n]+)/i.exec(rawHeaders);
-        const file = new global.File(value, filename[1], {
+        const file = new global.File([value], filename[1], {
           type: typeMatch ? typeMatch[1].trim() : '',
         });
         form.append(name, file, filename[1]);
@@ -472,9 +515,9 @@
 
     async blob() {
       markBodyUsed(this, 'blob');
-      const text = await collectBodyText(this);
+      const bytes = await collectBodyBytes(this);
       const type = this[kHeaders].get('content-type') || '';
-      return new global.Blob(text, type);
+      return new global.Blob([bytes], { type });
     },
 
     async formData() {
@@ -795,9 +838,15 @@
   const nativeFetch = global.__nativeFetch;
   delete global.__nativeFetch;
 
-  function requestBodyBytes(request) {
+  // Stream bodies (Blob, FormData, user ReadableStreams) are drained here:
+  // the native transport takes one buffered byte array.
+  async function requestBodyBytes(request) {
     if (request[kBodyBytes] !== null) return request[kBodyBytes];
     if (request[kBodyText] !== null) return utf8Encode(request[kBodyText]);
+    if (request[kBodyStream] !== null) {
+      markBodyUsed(request, 'fetch');
+      return collectBodyBytes(request);
+    }
     return null;
   }
 
@@ -850,7 +899,7 @@
     for (const [name, value] of request.headers) {
       flatHeaders.push(name, value);
     }
-    const body = requestBodyBytes(request);
+    const body = await requestBodyBytes(request);
     const call = nativeFetch(request.method, request.url, flatHeaders, body, request.redirect);
     const toResponse = (parts) =>
       makeResponse(parts[0], parts[1], parts[2], parts[3], parts[4]);
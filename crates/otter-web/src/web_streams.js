@@ -677,7 +677,7 @@
       const decoder = new TextDecoder(label, options);
       super({
         transform(chunk, controller) {
-          const text = decoder.decode(chunk);
+          const text = decoder.decode(chunk, { stream: true });
           if (text) controller.enqueue(text);
         },
         flush(controller) {
@@ -4,7 +4,8 @@
 //! the request on the `net` capability, drives the reqwest transport off-thread
 //! through the async completion protocol, and resolves with a real `Response`.
 //! These tests exercise a live loopback server (buffered GET with a forwarded
-//! header, and a POST whose body round-trips) and the deny-by-default gate.
+//! header, a POST whose body round-trips, and a multipart FormData upload) and
+//! the deny-by-default gate.
 
 use std::io::{Read, Write};
 use std::net::TcpListener;
@@ -60,9 +61,7 @@ where
     let addr = listener.local_addr().expect("local addr");
     let join = thread::spawn(move || {
         let (mut stream, _) = listener.accept().expect("accept");
-        let mut buf = [0u8; 8192];
-        let read = stream.read(&mut buf).unwrap_or(0);
-        let request = String::from_utf8_lossy(&buf[..read]).into_owned();
+        let request = read_request(&mut stream);
         let response = handler(&request);
         stream
             .write_all(response.as_bytes())
@@ -72,6 +71,34 @@ where
     (format!("http://{addr}/"), join)
 }
 
+/// Read one request: the head plus a `Content-Length` body, which the client
+/// may deliver over several socket reads.
+fn read_request(stream: &mut impl Read) -> String {
+    let mut raw = Vec::new();
+    let mut buf = [0u8; 8192];
+    loop {
+        let read = stream.read(&mut buf).unwrap_or(0);
+        if read == 0 {
+            break;
+        }
+        raw.extend_from_slice(&buf[..read]);
+        let text = String::from_utf8_lossy(&raw);
+        let Some(head_end) = text.find("\r\n\r\n") else {
+            continue;
+        };
+        let content_length = text[..head_end]
+            .lines()
+            .filter_map(|line| line.split_once(':'))
+            .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+            .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+            .unwrap_or(0);
+        if raw.len() >= head_end + 4 + content_length {
+            break;
+        }
+    }
+    String::from_utf8_lossy(&raw).into_owned()
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn fetch_performs_buffered_get() -> Result<(), OtterError> {
     let (url, server) = spawn_one_shot(|request| {
@@ -275,6 +302,68 @@ async fn fetch_forwards_request_body_on_post() -> Result<(), OtterError> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn fetch_encodes_form_data_as_multipart() -> Result<(), OtterError> {
+    let (url, server) = spawn_one_shot(|request| {
+        let (head, body) = request.split_once("\r\n\r\n").unwrap_or((request, ""));
+        let boundary = head
+            .lines()
+            .find_map(|line| {
+                line.to_ascii_lowercase()
+                    .starts_with("content-type: multipart/form-data; boundary=")
+                    .then(|| line.rsplit('=').next().unwrap_or("").to_string())
+            })
+            .unwrap_or_else(|| panic!("multipart content type missing: {request}"));
+        let expected = format!(
+            "--{boundary}\r\n\
+             Content-Disposition: form-data; name=\"title\"\r\n\r\n\
+             otter\r\n\
+             --{boundary}\r\n\
+             Content-Disposition: form-data; name=\"upload\"; filename=\"notes.txt\"\r\n\
+             Content-Type: text/plain\r\n\r\n\
+             blob-bytes\r\n\
+             --{boundary}--\r\n"
+        );
+        assert_eq!(body, expected, "unexpected multipart body");
+        let reply = "stored";
+        format!(
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}",
+            reply.len(),
+            reply
+        )
+    });
+
+    let capture = LogCapture::new();
+    let otter = Otter::builder()
+        .with_web_apis()
+        .capabilities(allow_net())
+        .console_sink(capture.clone())
+        .build()?;
+    otter
+        .handle()
+        .run_script(
+            SourceInput::from_javascript(format!(
+                r#"
+                const form = new FormData();
+                form.append("title", "otter");
+                form.append("upload", new Blob(["blob-", "bytes"], {{ type: "text/plain" }}), "notes.txt");
+                fetch("{url}", {{ method: "POST", body: form }})
+                  .then((response) =>
+                    response.text().then((text) =>
+                      console.log("ok:" + response.status + ":" + text)
+                    )
+                  )
+                  .catch((error) => console.log("err:" + error));
+                "#
+            )),
+            "<fetch-form-data>",
+        )
+        .await?;
+    server.join().expect("server thread");
+    assert_eq!(capture.snapshot(), vec!["ok:200:stored".to_string()]);
+    Ok(())
+}
+
 /// A one-shot server that answers with a 302 redirect to `location`.
 fn spawn_redirect_server(location: &'static str) -> (String, thread::JoinHandle<()>) {
     spawn_one_shot(move |_request| {
@@ -1,4 +1,4 @@
-use otter_runtime::{Runtime, SourceInput};
+use otter_runtime::{Runtime, SourceInput, UnhandledRejectionMode};
 use otter_web::blob::Blob;
 use otter_web::url::WebUrl;
 use otter_web::{WebApiBuilderExt, web_api_classes};
@@ -81,6 +81,42 @@ fn blob_constructor_assembles_parts_and_async_reads() {
     assert_eq!(after, "7|text/plain|true|true|7|ab-efcd");
 }
 
+#[test]
+fn blob_slices_relative_indices_streams_and_file_keeps_name() {
+    let mut runtime = Runtime::builder().with_web_apis().build().unwrap();
+    eval_string(
+        &mut runtime,
+        r#"
+        globalThis.out = "pending";
+        const blob = new Blob(["hello ", new TextEncoder().encode("world")], { type: "text/plain" });
+        // Negative indices count from the end; the content type is replaced.
+        const tail = blob.slice(-5, undefined, "TEXT/X-TAIL");
+        const middle = blob.slice(2, -6);
+        const file = new File([blob.slice(0, 5)], "greeting.txt", { lastModified: 42 });
+        (async () => {
+          const reader = blob.stream().getReader();
+          let streamed = "";
+          for (;;) {
+            const { done, value } = await reader.read();
+            if (done) break;
+            streamed += (value instanceof Uint8Array) + ":" + new TextDecoder().decode(value);
+          }
+          globalThis.out = [
+            await tail.text(), tail.type, tail.size, await middle.text(), streamed,
+            file.name, file.lastModified, file.size, await file.text(),
+            (file instanceof Blob) && typeof file.stream === "function",
+            await new Response(blob).blob().then((b) => b.text()),
+          ].join("|");
+        })().catch((err) => { globalThis.out = "ERR:" + err; });
+        "#,
+    );
+    let after = eval_string(&mut runtime, "out");
+    assert_eq!(
+        after,
+        "world|text/x-tail|5|llo|true:hello world|greeting.txt|42|5|hello|true|hello world"
+    );
+}
+
 #[test]
 fn native_host_instances_link_class_prototype() {
     let mut runtime = Runtime::builder().with_web_apis().build().unwrap();
@@ -499,6 +535,105 @@ fn request_form_data_parses_urlencoded_and_multipart() {
     assert_eq!(after, "1|two words|otter");
 }
 
+#[test]
+fn text_encoder_encode_into_reports_read_and_written() {
+    let mut runtime = Runtime::builder().with_web_apis().build().unwrap();
+    let result = eval_string(
+        &mut runtime,
+        r#"
+        const encoder = new TextEncoder();
+        // "a" (1 byte) + "é" (2 bytes) + "😀" (4 bytes, 2 code units): only the
+        // first two fit in 4 bytes; the emoji is never split.
+        const small = new Uint8Array(4);
+        const partial = encoder.encodeInto("aé😀", small);
+        const full = encoder.encodeInto("aé😀", new Uint8Array(8));
+        // A lone surrogate encodes as U+FFFD (3 bytes) and reads 1 unit.
+        const lone = encoder.encodeInto("\uD800", new Uint8Array(3));
+        [partial.read, partial.written, Array.from(small.subarray(0, 3)).join(","),
+         full.read, full.written, lone.read, lone.written].join("|")
+        "#,
+    );
+    assert_eq!(result, "2|3|97,195,169|4|7|1|3");
+}
+
+#[test]
+fn text_decoder_streams_split_sequences_and_honors_fatal() {
+    let mut runtime = Runtime::builder().with_web_apis().build().unwrap();
+    let result = eval_string(
+        &mut runtime,
+        r#"
+        const out = [];
+        // "€" is E2 82 AC; split it across three streamed chunks.
+        const decoder = new TextDecoder();
+        out.push(decoder.decode(new Uint8Array([0x61, 0xE2]), { stream: true }));
+        out.push(decoder.decode(new Uint8Array([0x82]), { stream: true }));
+        out.push(decoder.decode(new Uint8Array([0xAC, 0x62])));
+        // A truncated sequence at the end of a non-streaming call is replaced.
+        out.push(decoder.decode(new Uint8Array([0xE2, 0x82])) === "�");
+        // utf-16le: the odd byte and the split surrogate pair wait for more input.
+        const utf16 = new TextDecoder("utf-16le");
+        out.push(utf16.decode(new Uint8Array([0x3D, 0xD8, 0x00]), { stream: true }).length);
+        out.push(utf16.decode(new Uint8Array([0xDE])) === "😀");
+        // The BOM is stripped once per stream, even when split across chunks.
+        const bom = new TextDecoder();
+        out.push(bom.decode(new Uint8Array([0xEF, 0xBB]), { stream: true }) +
+          bom.decode(new Uint8Array([0xBF, 0x6F, 0x6B])));
+        out.push(new TextDecoder("latin1").decode(new Uint8Array([0x80, 0xE9])));
+        for (const [label, bytes] of [["utf-8", [0xC3, 0x28]], ["utf-16le", [0x00, 0xDC]]]) {
+          try {
+            new TextDecoder(label, { fatal: true }).decode(new Uint8Array(bytes));
+            out.push("no-throw");
+          } catch (e) {
+            out.push(e instanceof TypeError);
+          }
+        }
+        out.join("|")
+        "#,
+    );
+    assert_eq!(result, "a||€b|true|0|true|ok|€é|true|true");
+}
+
+#[test]
+fn text_decoder_large_inputs_and_invalid_sequences() {
+    let mut runtime = Runtime::builder().with_web_apis().build().unwrap();
+    let result = eval_string(
+        &mut runtime,
+        r#"
+        const out = [];
+        const encoder = new TextEncoder();
+        // 1 MiB of ASCII with a rare multibyte character mixed in.
+        const ascii = "otter ".repeat(174763).slice(0, 1 << 20);
+        const asciiBytes = encoder.encode(ascii);
+        out.push(new TextDecoder().decode(asciiBytes) === ascii);
+        const mixed = ("lorem ipsum é ✓ 😀 ").repeat(20000);
+        const mixedBytes = encoder.encode(mixed);
+        out.push(new TextDecoder("utf-8", { fatal: true }).decode(mixedBytes) === mixed);
+        // Streamed in odd-sized chunks that split multibyte sequences.
+        const streaming = new TextDecoder();
+        let streamed = "";
+        for (let at = 0; at < mixedBytes.length; at += 4093) {
+          streamed += streaming.decode(mixedBytes.subarray(at, at + 4093), { stream: true });
+        }
+        streamed += streaming.decode();
+        out.push(streamed === mixed);
+        // Overlong "/", an encoded surrogate, and a scalar past U+10FFFF.
+        const invalid = [[0xC0, 0xAF], [0xED, 0xA0, 0x80], [0xF4, 0x90, 0x80, 0x80]];
+        for (const bytes of invalid) {
+          out.push(new TextDecoder().decode(new Uint8Array([0x61, ...bytes, 0x62]))
+            === "a" + "\uFFFD".repeat(bytes.length) + "b");
+          try {
+            new TextDecoder("utf-8", { fatal: true }).decode(new Uint8Array(bytes));
+            out.push("no-throw");
+          } catch (e) {
+            out.push(e instanceof TypeError);
+          }
+        }
+        out.join("|")
+        "#,
+    );
+    assert_eq!(result, "true|true|true|true|true|true|true|true|true");
+}
+
 #[test]
 fn fetch_internals_round_trip_for_server_glue() {
     let mut runtime = Runtime::builder().with_web_apis().build().unwrap();
@@ -1089,6 +1224,38 @@ fn global_scope_shell_self_and_report_error() {
     assert_eq!(result, "true|true|true|function|false|false");
 }
 
+#[test]
+fn report_error_dispatches_to_onerror_and_continues() {
+    let mut runtime = Runtime::builder().with_web_apis().build().unwrap();
+    let result = eval_string(
+        &mut runtime,
+        r#"
+        const seen = [];
+        const err = new RangeError("detached callback failed");
+        globalThis.onerror = (event) => {
+          seen.push(event instanceof ErrorEvent);
+          seen.push(event.type + ":" + event.message);
+          seen.push(event.error === err);
+          seen.push(typeof event.error.stack === "string" && event.error.stack.includes("detached"));
+          event.preventDefault();
+        };
+        reportError(err);
+        seen.push("continued");
+        // A throwing handler is contained; reportError still returns normally.
+        globalThis.onerror = () => { throw new Error("handler failed"); };
+        let threw = false;
+        try { reportError("plain"); } catch (_) { threw = true; }
+        seen.push(threw);
+        globalThis.onerror = null;
+        seen.join("|")
+        "#,
+    );
+    assert_eq!(
+        result,
+        "true|error:detached callback failed|true|true|continued|false"
+    );
+}
+
 #[test]
 fn unhandled_rejection_notifies_and_rejectionhandled_follows() {
     let mut runtime = Runtime::builder().with_web_apis().build().unwrap();
@@ -1324,3 +1491,46 @@ fn crypto_subtle_digest_matches_known_vectors_and_rejects_unknowns() {
          da39a3ee5e6b4b0d3255bfef95601890afd80709|NotSupportedError"
     );
 }
+
+#[test]
+fn throw_mode_raises_unless_unhandledrejection_is_canceled() {
+    let mut runtime = Runtime::builder()
+        .with_web_apis()
+        .unhandled_rejections(UnhandledRejectionMode::Throw)
+        .build()
+        .unwrap();
+    let err = runtime
+        .eval(SourceInput::from_javascript(
+            "Promise.reject(new Error('web-boom')); 1",
+        ))
+        .expect_err("an uncanceled rejection must throw");
+    assert!(
+        err.to_string().contains("web-boom"),
+        "unexpected error: {err}"
+    );
+
+    assert_eq!(
+        eval_string(
+            &mut runtime,
+            "globalThis.seen = [];\n\
+             globalThis.onunhandledrejection = (event) => {\n\
+               seen.push(event.reason.message);\n\
+               event.preventDefault();\n\
+             };\n\
+             Promise.reject(new Error('canceled')); 1",
+        ),
+        "1"
+    );
+    assert_eq!(eval_string(&mut runtime, "seen.join()"), "canceled");
+
+    let err = runtime
+        .eval(SourceInput::from_javascript(
+            "globalThis.onunhandledrejection = () => {};\n\
+             Promise.reject(new Error('observed-only')); 1",
+        ))
+        .expect_err("a listener that does not cancel still throws");
+    assert!(
+        err.to_string().contains("observed-only"),
+        "unexpected error: {err}"
+    );
+}